@@ -0,0 +1,161 @@
+"""Benchmarks for the micron renderer.
+
+Times parsing micron pages, parsing single lines and converting colours
+over small, medium, pathological and large inputs. Results can be saved
+as a baseline and later runs compared against it, failing when anything
+got slower than the allowed margin, so regressions are caught before a
+release.
+
+Run from the repository root::
+
+    python -m benchmarks.bench_micron
+    python -m benchmarks.bench_micron --save baseline.json
+    python -m benchmarks.bench_micron --compare baseline.json --margin 0.25
+"""
+
+import argparse
+import contextlib
+import io
+import json
+import sys
+import timeit
+from pathlib import Path
+
+from ren_browser.renderer.micron import (
+    _parse_blocks,
+    hex_to_rgb,
+    parse_micron_line,
+    render_micron,
+)
+
+SMALL_PAGE = """>Welcome
+`!Bold`! and `*italic`* text with `F0f0colour`f.
+`[Home`:/page/index.mu]
+"""
+
+MEDIUM_LINE = (
+    "`!Status`! `F0f0online`f, `_uptime`_ `B222`Fff012 days`f`b "
+    "`*since the last restart`* `[log`:/page/log.mu]"
+)
+
+
+def large_page(sections: int = 200) -> str:
+    """Return a large page mixing headings, art, links and styled text."""
+    banner = "\n".join(
+        "`F" + f"{i % 16:x}" * 3 + "#" * 80 + "`f" for i in range(16)
+    )
+    parts = []
+    for n in range(sections):
+        parts += [
+            f">Section {n}",
+            f">>Details of {n}",
+            banner,
+            "-",
+            f"`!Item {n}`! `*noted`* `_underlined`_ `B333`Fe80styled`f`b text",
+            f"`[Page {n}`:/page/{n}.mu] `[Next`:/page/{n + 1}.mu]",
+            "`c" + "centred text " * 6,
+            "",
+        ]
+    return "\n".join(parts)
+
+
+PATHOLOGICAL = {
+    "unclosed_backticks": "`" * 20_000,
+    "toggle_storm": "`!`*`_" * 5_000,
+    "long_line": "word " * 50_000,
+    "deep_headings": "\n".join(">" * depth + " heading" for depth in range(1, 500)),
+}
+# An invalid colour code fails the parse, so this times the plaintext fallback
+BAD_COLOURS = "`Fzz`Bxx" * 5_000
+
+
+def quiet_render(content: str):
+    """Render a page without the message printed when falling back."""
+    with contextlib.redirect_stdout(io.StringIO()):
+        return render_micron(content)
+
+CASES = {
+    "parse_line/small": (parse_micron_line, "`!Bold`! text"),
+    "parse_line/medium": (parse_micron_line, MEDIUM_LINE),
+    "parse_line/long": (parse_micron_line, MEDIUM_LINE * 200),
+    "parse_page/small": (_parse_blocks, SMALL_PAGE),
+    "parse_page/medium": (_parse_blocks, large_page(10)),
+    "parse_page/large": (_parse_blocks, large_page()),
+    **{
+        f"parse_page/{name}": (_parse_blocks, page)
+        for name, page in PATHOLOGICAL.items()
+    },
+    "render/medium": (render_micron, large_page(10)),
+    "render/bad_colours": (quiet_render, BAD_COLOURS),
+    "hex_to_rgb/valid": (hex_to_rgb, "f80"),
+    "hex_to_rgb/invalid": (hex_to_rgb, "zz"),
+}
+
+
+def run_case(function, argument, budget: float) -> float:
+    """Return the best time of one call in seconds.
+
+    The number of calls per round is picked so a round takes about
+    ``budget`` seconds, and the fastest of five rounds is kept.
+    """
+    timer = timeit.Timer(lambda: function(argument))
+    number, elapsed = timer.autorange()
+    number = max(int(number * budget / max(elapsed, 1e-9)), 1)
+    return min(timer.repeat(repeat=5, number=number)) / number
+
+
+def run(names: list[str] | None = None, budget: float = 0.2) -> dict[str, float]:
+    """Run the benchmarks, all of them unless names are given."""
+    results = {}
+    for name, (function, argument) in CASES.items():
+        if names and not any(name.startswith(prefix) for prefix in names):
+            continue
+        results[name] = run_case(function, argument, budget)
+        print(f"{name:36} {results[name] * 1e6:12.1f} µs")
+    return results
+
+
+def compare(results: dict, baseline: dict, margin: float) -> list[str]:
+    """Return the benchmarks slower than their baseline by more than margin."""
+    slower = []
+    for name, seconds in results.items():
+        before = baseline.get(name)
+        if before and seconds > before * (1 + margin):
+            slower.append(f"{name}: {before * 1e6:.1f} µs -> {seconds * 1e6:.1f} µs")
+    return slower
+
+
+def main(argv: list[str] | None = None) -> int:
+    """Run the benchmarks from the command line."""
+    parser = argparse.ArgumentParser(description="Micron renderer benchmarks")
+    parser.add_argument("names", nargs="*", help="Only run benchmarks starting so")
+    parser.add_argument("--save", type=Path, help="Write the results to a file")
+    parser.add_argument("--compare", type=Path, help="Compare with saved results")
+    parser.add_argument(
+        "--margin",
+        type=float,
+        default=0.2,
+        help="Allowed slowdown against the baseline (default: 0.2)",
+    )
+    parser.add_argument(
+        "--budget",
+        type=float,
+        default=0.2,
+        help="Seconds per timing round (default: 0.2)",
+    )
+    args = parser.parse_args(argv)
+    results = run(args.names, args.budget)
+    if args.save:
+        args.save.write_text(json.dumps(results, indent=2), encoding="utf-8")
+    if args.compare:
+        baseline = json.loads(args.compare.read_text(encoding="utf-8"))
+        slower = compare(results, baseline, args.margin)
+        for line in slower:
+            print(f"Slower: {line}")
+        if slower:
+            return 1
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,53 @@
+"""Screen reader labels for Ren Browser.
+
+Flutter exposes the interface to NVDA, Orca, VoiceOver and TalkBack through
+its semantics tree. Buttons with a tooltip are announced by it, but the tab
+strip, the node list and links on pages only had their visible text, which
+does not say what they are or which tab is open. These helpers build the
+labels read out for them instead.
+"""
+
+
+def tab_label(
+    title: str,
+    position: int,
+    count: int,
+    *,
+    active: bool = False,
+    private: bool = False,
+    loading: bool = False,
+) -> str:
+    """Describe a tab in the tab strip.
+
+    Args:
+        title: Title of the tab.
+        position: Index of the tab, from 0.
+        count: Number of open tabs.
+        active: Whether the tab is the one shown.
+        private: Whether the tab is a private tab.
+        loading: Whether the tab's page is loading.
+
+    Returns:
+        A label like "Tab 2 of 3, Home, selected".
+
+    """
+    parts = [f"Tab {position + 1} of {count}", title]
+    if private:
+        parts.append("private")
+    if loading:
+        parts.append("loading")
+    if active:
+        parts.append("selected")
+    return ", ".join(parts)
+
+
+def node_label(name: str | None, destination_hash: str) -> str:
+    """Describe a node in the node list, which opens its index page."""
+    return f"Node {name or destination_hash}, opens its index page"
+
+
+def link_label(text: str, url: str) -> str:
+    """Describe a link on a page."""
+    if not text or text == url:
+        return f"Link to {url}"
+    return f"Link, {text}"
@@ -0,0 +1,180 @@
+"""Page annotations for Ren Browser.
+
+Passages of a page can be highlighted, with a note if wanted, and the
+highlights are shown again on the page every time it is opened, like
+marking up a long technical page from a node. Annotations are kept by
+page address in the browser's storage directory.
+"""
+
+import time
+from dataclasses import asdict, dataclass
+
+from ren_browser.i18n.i18n import tr
+
+# Colours a highlight can have, each with the ``r,g,b`` it is drawn in and
+# the label it is offered under
+HIGHLIGHT_COLORS = {
+    "yellow": ("255,235,59", "Yellow"),
+    "green": ("165,214,167", "Green"),
+    "blue": ("144,202,249", "Blue"),
+    "pink": ("244,143,177", "Pink"),
+}
+MAX_QUOTE_LENGTH = 2000
+
+
+@dataclass(eq=False)
+class Annotation:
+    """A highlighted passage of a page.
+
+    Contains the page address and title, the passage, the note made on it,
+    the highlight colour and when it was made.
+    """
+
+    address: str
+    title: str
+    quote: str
+    note: str = ""
+    color: str = "yellow"
+    created_at: float = 0.0
+
+    @classmethod
+    def from_dict(cls, data) -> "Annotation | None":
+        """Build an annotation from stored data, or None if it is malformed."""
+        if not isinstance(data, dict):
+            return None
+        address, quote = data.get("address"), data.get("quote")
+        if not isinstance(address, str) or not isinstance(quote, str):
+            return None
+        if not address or not quote.strip():
+            return None
+        try:
+            created_at = float(data.get("created_at") or 0)
+        except (TypeError, ValueError):
+            created_at = 0.0
+        note, title = data.get("note"), data.get("title")
+        color = data.get("color")
+        return cls(
+            address=address,
+            title=title if isinstance(title, str) and title else address,
+            quote=quote,
+            note=note if isinstance(note, str) else "",
+            color=color if color in HIGHLIGHT_COLORS else "yellow",
+            created_at=created_at,
+        )
+
+
+def highlight_rgb(annotation: Annotation) -> str:
+    """Return the ``r,g,b`` colour an annotation is highlighted in."""
+    return HIGHLIGHT_COLORS.get(annotation.color, HIGHLIGHT_COLORS["yellow"])[0]
+
+
+def quote_error(quote: str, page_text: str) -> str | None:
+    """Return why a passage cannot be highlighted on a page, or None if it can.
+
+    Every line of the passage has to be on the page, as it is matched line
+    by line when the page is shown.
+    """
+    lines = [line.strip() for line in quote.splitlines() if line.strip()]
+    if not lines:
+        return tr("Enter the passage to highlight")
+    if len(quote) > MAX_QUOTE_LENGTH:
+        return tr("Use at most {count} characters", count=MAX_QUOTE_LENGTH)
+    if any(line not in page_text for line in lines):
+        return tr("This passage is not on the page")
+    return None
+
+
+class AnnotationStore:
+    """Annotations of every page, persisted through storage."""
+
+    def __init__(self, storage):
+        """Load the annotations.
+
+        Args:
+            storage: StorageManager used to load and save the annotations.
+
+        """
+        self.storage = storage
+        self._annotations: list[Annotation] = []
+        try:
+            stored = storage.load_annotations()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            annotation = Annotation.from_dict(data)
+            if annotation is not None:
+                self._annotations.append(annotation)
+
+    def __len__(self) -> int:
+        """Return how many annotations there are."""
+        return len(self._annotations)
+
+    def for_page(self, address: str) -> list[Annotation]:
+        """Return the annotations of a page, in the order they were made."""
+        return [a for a in self._annotations if a.address == address]
+
+    def pages(self) -> list[tuple[str, list[Annotation]]]:
+        """Return the annotated pages, the most recently annotated first."""
+        pages: dict[str, list[Annotation]] = {}
+        for annotation in sorted(
+            self._annotations,
+            key=lambda a: a.created_at,
+            reverse=True,
+        ):
+            pages.setdefault(annotation.address, []).append(annotation)
+        return [
+            (address, sorted(annotations, key=lambda a: a.created_at))
+            for address, annotations in pages.items()
+        ]
+
+    def add(
+        self,
+        address: str,
+        title: str,
+        quote: str,
+        note: str = "",
+        color: str = "yellow",
+    ) -> Annotation:
+        """Highlight a passage of a page.
+
+        Args:
+            address: Normalized address of the page.
+            title: Title of the page, shown where annotations are listed.
+            quote: Passage highlighted.
+            note: Note made on the passage.
+            color: Key of HIGHLIGHT_COLORS the passage is highlighted in.
+
+        Returns:
+            Annotation: The new annotation.
+
+        """
+        annotation = Annotation(
+            address,
+            title or address,
+            quote.strip(),
+            note.strip(),
+            color if color in HIGHLIGHT_COLORS else "yellow",
+            time.time(),
+        )
+        self._annotations.append(annotation)
+        self._save()
+        return annotation
+
+    def update(self, annotation: Annotation, note: str, color: str) -> None:
+        """Change the note and colour of an annotation."""
+        annotation.note = note.strip()
+        if color in HIGHLIGHT_COLORS:
+            annotation.color = color
+        self._save()
+
+    def remove(self, annotation: Annotation) -> None:
+        """Delete an annotation."""
+        if annotation in self._annotations:
+            self._annotations.remove(annotation)
+            self._save()
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_annotations([asdict(a) for a in self._annotations])
+        except Exception:  # noqa: BLE001
+            pass
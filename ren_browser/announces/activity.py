@@ -0,0 +1,212 @@
+"""Announce activity history for Ren Browser.
+
+Counts the announces heard from each Nomad Network node by the hour and
+keeps the counts for a month in the browser's storage directory, so the
+times parts of the mesh wake up can be seen, such as solar nodes that are
+only on the air during the day. Counts are saved at most once a minute as
+announces arrive, and when the browser quits.
+"""
+
+import threading
+import time
+from dataclasses import dataclass
+from datetime import datetime
+
+from ren_browser.announces.announces import NODE_ASPECT, Announce
+
+HOUR = 3600
+RETENTION_HOURS = 30 * 24
+SAVE_INTERVAL = 60
+
+
+@dataclass
+class NodeActivity:
+    """How often a node was heard over a span of time."""
+
+    destination_hash: str
+    name: str | None
+    count: int
+    last_heard: int
+
+
+def hour_of_day(hour: int) -> int:
+    """Return the local hour of day an hour since the epoch falls in."""
+    return datetime.fromtimestamp(hour * HOUR).hour
+
+
+class AnnounceActivity:
+    """Announces heard from each node by the hour, persisted through storage."""
+
+    def __init__(self, storage):
+        """Load the announce counts.
+
+        Args:
+            storage: StorageManager used to load and save the counts.
+
+        """
+        self.storage = storage
+        # Announces heard from each node, by hours since the epoch
+        self._hours: dict[str, dict[int, int]] = {}
+        self._names: dict[str, str] = {}
+        self._saved_at: float | None = None
+        self._dirty = False
+        self._lock = threading.Lock()
+        try:
+            stored = storage.load_announce_activity()
+        except Exception:  # noqa: BLE001
+            stored = {}
+        nodes = stored.get("nodes") if isinstance(stored, dict) else None
+        for destination_hash, data in (nodes or {}).items():
+            if not isinstance(data, dict) or not isinstance(data.get("hours"), dict):
+                continue
+            hours = {}
+            for hour, count in data["hours"].items():
+                try:
+                    hours[int(hour)] = int(count)
+                except (TypeError, ValueError):
+                    continue
+            if hours:
+                self._hours[destination_hash] = hours
+            if isinstance(data.get("name"), str) and data["name"]:
+                self._names[destination_hash] = data["name"]
+
+    def __len__(self) -> int:
+        """Return how many nodes have been heard."""
+        return len(self._hours)
+
+    def record(self, announce: Announce) -> None:
+        """Count an announce, if it is from a Nomad Network node."""
+        if announce.aspect != NODE_ASPECT:
+            return
+        hour = announce.timestamp // HOUR
+        with self._lock:
+            hours = self._hours.setdefault(announce.destination_hash, {})
+            hours[hour] = hours.get(hour, 0) + 1
+            if announce.display_name:
+                self._names[announce.destination_hash] = announce.display_name
+            self._prune(hour - RETENTION_HOURS)
+            self._dirty = True
+        now = time.monotonic()
+        if self._saved_at is None or now - self._saved_at >= SAVE_INTERVAL:
+            self.save()
+
+    def name(self, destination_hash: str) -> str | None:
+        """Return the name a node last announced, if it announced one."""
+        return self._names.get(destination_hash)
+
+    def timeline(
+        self,
+        start: float,
+        end: float,
+        bucket: int,
+        destination_hash: str | None = None,
+    ) -> list[int]:
+        """Return how many announces were heard in each span of a period.
+
+        Args:
+            start: Start of the period, in seconds since the epoch.
+            end: End of the period, in seconds since the epoch.
+            bucket: Seconds each count covers, a multiple of an hour.
+            destination_hash: Node to count, or None to count every node.
+
+        Returns:
+            One count per bucket from start, the last one ending at end.
+
+        """
+        hours_per_bucket = max(bucket // HOUR, 1)
+        first = int(start) // HOUR
+        buckets = max(-(-(int(end) // HOUR - first + 1) // hours_per_bucket), 1)
+        counts = [0] * buckets
+        for hour, count in self._counts(destination_hash).items():
+            index = (hour - first) // hours_per_bucket
+            if 0 <= index < buckets:
+                counts[index] += count
+        return counts
+
+    def by_hour_of_day(
+        self,
+        since: float,
+        destination_hash: str | None = None,
+    ) -> list[int]:
+        """Return how many announces were heard in each local hour of the day.
+
+        Args:
+            since: Only count announces heard after this, in seconds since
+                the epoch.
+            destination_hash: Node to count, or None to count every node.
+
+        """
+        counts = [0] * 24
+        first = int(since) // HOUR
+        for hour, count in self._counts(destination_hash).items():
+            if hour >= first:
+                counts[hour_of_day(hour)] += count
+        return counts
+
+    def nodes(self, since: float) -> list[NodeActivity]:
+        """Return the nodes heard after a time, the most active first."""
+        first = int(since) // HOUR
+        with self._lock:
+            snapshot = {d: dict(hours) for d, hours in self._hours.items()}
+        nodes = []
+        for destination_hash, hours in snapshot.items():
+            heard = [hour for hour in hours if hour >= first]
+            if not heard:
+                continue
+            nodes.append(
+                NodeActivity(
+                    destination_hash,
+                    self._names.get(destination_hash),
+                    sum(hours[hour] for hour in heard),
+                    max(heard) * HOUR,
+                ),
+            )
+        return sorted(nodes, key=lambda n: (-n.count, -n.last_heard))
+
+    def clear(self) -> None:
+        """Forget every announce counted."""
+        with self._lock:
+            self._hours = {}
+            self._names = {}
+            self._dirty = True
+        self.save()
+
+    def save(self) -> None:
+        """Save the counts, if they changed since they were last saved."""
+        with self._lock:
+            if not self._dirty:
+                return
+            data = {
+                "nodes": {
+                    destination_hash: {
+                        "name": self._names.get(destination_hash),
+                        "hours": {str(h): c for h, c in hours.items()},
+                    }
+                    for destination_hash, hours in self._hours.items()
+                },
+            }
+            self._dirty = False
+        self._saved_at = time.monotonic()
+        try:
+            self.storage.save_announce_activity(data)
+        except Exception:  # noqa: BLE001
+            pass
+
+    def _counts(self, destination_hash: str | None) -> dict[int, int]:
+        with self._lock:
+            if destination_hash is not None:
+                return dict(self._hours.get(destination_hash, {}))
+            counts: dict[int, int] = {}
+            for hours in self._hours.values():
+                for hour, count in hours.items():
+                    counts[hour] = counts.get(hour, 0) + count
+            return counts
+
+    def _prune(self, oldest: int) -> None:
+        for destination_hash in list(self._hours):
+            hours = self._hours[destination_hash]
+            for hour in [h for h in hours if h < oldest]:
+                del hours[hour]
+            if not hours:
+                del self._hours[destination_hash]
+                self._names.pop(destination_hash, None)
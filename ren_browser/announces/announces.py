@@ -5,27 +5,132 @@ announces from the Reticulum network.
 """
 
 import time
+from collections import deque
+from collections.abc import Callable
 from dataclasses import dataclass
 
 import RNS
 
+ANNOUNCE_LOG_SIZE = 500
+NODE_ASPECT = "nomadnetwork.node"
+ANNOUNCE_ASPECTS = {
+    NODE_ASPECT: "Pages",
+    "lxmf.delivery": "Messaging",
+    "lxmf.propagation": "Propagation",
+}
+
 
 @dataclass
 class Announce:
     """Represents a Reticulum network announce.
 
-    Contains destination hash, display name, and timestamp.
+    Contains destination hash, display name, and timestamp, along with the
+    announcing identity, the aspect it was heard on, when the node was first
+    heard this session and how many times it has announced since.
     """
 
     destination_hash: str
     display_name: str | None
     timestamp: int
+    aspect: str = NODE_ASPECT
+    identity_hash: str | None = None
+    first_seen: int = 0
+    announce_count: int = 1
+
+
+@dataclass
+class PathInfo:
+    """What Reticulum knows about the path to a destination."""
+
+    hops: int | None = None
+    interface: str | None = None
+
+
+def path_info(destination_hash: str) -> PathInfo:
+    """Return the known path to a destination, if there is one.
+
+    Args:
+        destination_hash: Hex destination hash.
+
+    Returns:
+        PathInfo: Hop count and next-hop interface, or empty if unknown.
+
+    """
+    try:
+        dest_bytes = bytes.fromhex(destination_hash)
+        if not RNS.Transport.has_path(dest_bytes):
+            return PathInfo()
+        hops = RNS.Transport.hops_to(dest_bytes)
+        interface = RNS.Transport.next_hop_interface(dest_bytes)
+    except Exception:  # noqa: BLE001
+        return PathInfo()
+    return PathInfo(
+        hops=hops if isinstance(hops, int) else None,
+        interface=str(interface) if interface is not None else None,
+    )
+
+
+def request_path(destination_hash: str) -> bool:
+    """Ask the network for a path to a destination, without waiting for it.
+
+    Returns:
+        True if the request was sent.
+
+    """
+    try:
+        RNS.Transport.request_path(bytes.fromhex(destination_hash))
+    except Exception:  # noqa: BLE001
+        return False
+    return True
+
+
+def decode_display_name(app_data: bytes | None) -> str | None:
+    """Return the display name carried in an announce's app data.
+
+    Nomad Network nodes announce their name as UTF-8 text, while LXMF
+    destinations announce a msgpack list whose first item is the name.
+    """
+    if not app_data:
+        return None
+    if 0x91 <= app_data[0] <= 0x9F and len(app_data) > 1:
+        marker = app_data[1]
+        if 0xA0 <= marker <= 0xBF:
+            start, length = 2, marker - 0xA0
+        elif marker in (0xC4, 0xD9) and len(app_data) > 2:
+            start, length = 3, app_data[2]
+        else:
+            return None
+        app_data = app_data[start : start + length]
+    try:
+        return app_data.decode("utf-8") or None
+    except UnicodeDecodeError:
+        return None
+
+
+class _AspectHandler:
+    """Announce handler passing one aspect's announces to the service."""
+
+    def __init__(self, service: "AnnounceService", aspect: str):
+        self.service = service
+        self.aspect_filter = aspect
+        self.receive_path_responses = True
+
+    def received_announce(self, destination_hash, announced_identity, app_data):
+        self.service.received_announce(
+            destination_hash,
+            announced_identity,
+            app_data,
+            aspect=self.aspect_filter,
+        )
 
 
 class AnnounceService:
     """Service to listen for Reticulum announces and collect them.
 
-    Calls update_callback whenever a new announce is received.
+    Listens for Nomad Network nodes as well as the other aspects in
+    ANNOUNCE_ASPECTS. Calls update_callback whenever a new announce is
+    received, and keeps a log of the latest announces, repeats included,
+    for the live ticker.
     """
 
     def __init__(self, update_callback):
@@ -35,40 +140,82 @@ class AnnounceService:
             update_callback: Function called when new announces are received.
 
         """
-        self.aspect_filter = "nomadnetwork.node"
+        self.aspect_filter = NODE_ASPECT
         self.receive_path_responses = True
         self.announces: list[Announce] = []
+        self.log: deque[Announce] = deque(maxlen=ANNOUNCE_LOG_SIZE)
+        self.listeners: list[Callable[[Announce], None]] = []
         self.update_callback = update_callback
         # RNS should already be initialized by main app
         RNS.Transport.register_announce_handler(self)
-        RNS.log("AnnounceService: registered announce handler")
-
-    def received_announce(self, destination_hash, announced_identity, app_data):
+        self.handlers = [
+            _AspectHandler(self, aspect)
+            for aspect in ANNOUNCE_ASPECTS
+            if aspect != NODE_ASPECT
+        ]
+        for handler in self.handlers:
+            RNS.Transport.register_announce_handler(handler)
+        RNS.log("AnnounceService: registered announce handlers")
+
+    def received_announce(
+        self,
+        destination_hash,
+        announced_identity,
+        app_data,
+        aspect: str | None = None,
+    ):
         """Handle received announce from Reticulum network.
 
         Args:
             destination_hash: Hash of the announcing destination.
             announced_identity: Identity of the announcer.
             app_data: Optional application data from the announce.
+            aspect: Aspect the announce was heard on, Nomad Network nodes
+                if not given.
 
         """
         RNS.log(f"AnnounceService: received announce from {destination_hash.hex()}")
         ts = int(time.time())
-        display_name = None
-        if app_data:
-            try:
-                display_name = app_data.decode("utf-8")
-            except UnicodeDecodeError:
-                pass
-        announce = Announce(destination_hash.hex(), display_name, ts)
-        self.announces = [
-            ann
-            for ann in self.announces
-            if ann.destination_hash != announce.destination_hash
-        ]
+        display_name = decode_display_name(app_data)
+        identity_hash = getattr(announced_identity, "hash", None)
+        announce = Announce(
+            destination_hash.hex(),
+            display_name,
+            ts,
+            aspect=aspect or self.aspect_filter,
+            identity_hash=(
+                identity_hash.hex() if isinstance(identity_hash, bytes) else None
+            ),
+            first_seen=ts,
+        )
+        previous = next(
+            (
+                ann
+                for ann in self.announces
+                if ann.destination_hash == announce.destination_hash
+            ),
+            None,
+        )
+        if previous is not None:
+            announce.first_seen = previous.first_seen or previous.timestamp
+            announce.announce_count = previous.announce_count + 1
+            self.announces.remove(previous)
         self.announces.insert(0, announce)
+        self.log.append(announce)
         if self.update_callback:
             self.update_callback(self.announces)
+        for listener in list(self.listeners):
+            listener(announce)
+
+    def add_listener(self, listener: Callable[[Announce], None]) -> None:
+        """Call listener with every announce received from now on."""
+        if listener not in self.listeners:
+            self.listeners.append(listener)
+
+    def remove_listener(self, listener: Callable[[Announce], None]) -> None:
+        """Stop calling a listener added with add_listener."""
+        if listener in self.listeners:
+            self.listeners.remove(listener)
 
     def get_announces(self) -> list[Announce]:
         """Return collected announces."""
@@ -5,8 +5,10 @@ Ren Browser, a browser for the Reticulum Network built with Flet.
 """
 
 import argparse
+import asyncio
 import logging
 import os
+import sys
 from pathlib import Path
 
 import flet as ft
@@ -14,12 +16,37 @@ import RNS
 from flet import AppView, Page
 
 from ren_browser import rns
-from ren_browser.storage.storage import initialize_storage
+from ren_browser.cli.fetch import fetch
+from ren_browser.cli.remote import remote
+from ren_browser.crash.crash import CrashHandler, open_addresses, take_pending_report
+from ren_browser.deeplink.deeplink import HandoffServer, hand_off, register_url_scheme
+from ren_browser.logs import configure_log_file, log_file_options
+from ren_browser.pages.address import find_address
+from ren_browser.profiler.metrics import MetricsServer, metrics_text
+from ren_browser.remote.remote import RemoteControlServer, browser_methods
+from ren_browser.storage.portable import set_portable
+from ren_browser.storage.profiles import (
+    DEFAULT_PROFILE,
+    ProfileError,
+    check_profile_name,
+)
+from ren_browser.storage.storage import (
+    get_storage_manager,
+    initialize_storage,
+    set_profile,
+)
+from ren_browser.ui.crash_prompt import CrashPrompt
 from ren_browser.ui.ui import build_ui
 
 RENDERER = "plaintext"
 RNS_CONFIG_DIR = None
 RNS_INSTANCE = None
+START_ADDRESSES: list[str] = []
+HANDOFF_SERVER = None
+REMOTE_SERVER = None
+METRICS_PORT = None
+METRICS_SERVER = None
+CRASH_HANDLER = None
 logger = logging.getLogger(__name__)
 
 
@@ -55,9 +82,18 @@ async def main(page: Page):
     page.add(loader)
     page.update()
 
-    initialize_storage(page)
+    storage = initialize_storage(page)
+    if storage.profile != DEFAULT_PROFILE:
+        page.title = f"Ren Browser ({storage.profile})"
+    # The log file is opened before Reticulum starts so that failing to
+    # start it is written down too
+    configure_log_file(
+        storage.get_logs_path(),
+        *log_file_options(storage.load_app_settings()),
+    )
+    crash_handler = _install_crash_handler(storage.get_crashes_path())
 
-    config_override = RNS_CONFIG_DIR
+    config_override = rns_config_dir()
 
     print("Initializing Reticulum Network...")
     try:
@@ -67,7 +103,9 @@ async def main(page: Page):
     except Exception:
         logger.exception("Unable to configure RNS logging")
 
-    success = rns.initialize_reticulum(config_override)
+    # Reticulum is started on a worker thread so the loading screen is drawn
+    # and stays responsive while interfaces come up.
+    success = await asyncio.to_thread(rns.initialize_reticulum, config_override)
     if not success:
         error_text = rns.get_last_error() or "Unknown error"
         print(f"Error initializing Reticulum: {error_text}")
@@ -86,10 +124,103 @@ async def main(page: Page):
         print("RNS initialized successfully")
 
     page.controls.clear()
-    build_ui(page)
+    tab_manager = build_ui(page)
+    for address in START_ADDRESSES:
+        tab_manager.open_link_in_new_tab(address)
+    _open_route(tab_manager, page.route)
+    page.on_route_change = lambda e: _open_route(tab_manager, e.route)
+    _start_handoff_server(tab_manager)
+    _start_remote_server(tab_manager)
+    _start_metrics_server(tab_manager)
+    crash_handler.tabs = lambda: open_addresses(tab_manager)
+    report = take_pending_report(storage.get_crashes_path())
+    if report is not None:
+        CrashPrompt(page, tab_manager, report).show()
     page.update()
 
 
+def _install_crash_handler(directory: Path) -> CrashHandler:
+    """Write a crash report for errors nothing caught from now on."""
+    global CRASH_HANDLER
+    if CRASH_HANDLER is not None:
+        CRASH_HANDLER.uninstall()
+    CRASH_HANDLER = CrashHandler(directory)
+    CRASH_HANDLER.install()
+    asyncio.get_running_loop().set_exception_handler(
+        CRASH_HANDLER.handle_loop_exception,
+    )
+    return CRASH_HANDLER
+
+
+def rns_config_dir() -> str | None:
+    """Return the Reticulum config directory to use, None for the default.
+
+    A directory given on the command line wins. Portable browsers and
+    profiles other than the default keep their Reticulum identity in their
+    own folder.
+    """
+    if RNS_CONFIG_DIR:
+        return RNS_CONFIG_DIR
+    storage = get_storage_manager()
+    if storage.profile == DEFAULT_PROFILE and not storage.portable:
+        return None
+    return str(storage.get_reticulum_config_path())
+
+
+def _open_route(tab_manager, route: str | None) -> None:
+    """Open the address in the route the app was sent to, if it has one.
+
+    The route is the URL path in the web build, so a link such as
+    ``http://127.0.0.1:8550/<hash>/page/index.mu`` opens that page, and a
+    route changed while running opens in a new tab of the same window.
+    """
+    address = find_address(route or "")
+    if address:
+        tab_manager.open_link_in_new_tab(address)
+
+
+def _start_handoff_server(tab_manager) -> None:
+    """Open addresses handed over by later launches in new tabs."""
+    global HANDOFF_SERVER
+    if HANDOFF_SERVER is not None:
+        HANDOFF_SERVER.stop()
+    HANDOFF_SERVER = HandoffServer(
+        get_storage_manager().get_instance_path(),
+        on_address=tab_manager.open_link_in_new_tab,
+    )
+    if not HANDOFF_SERVER.start():
+        logger.warning("Unable to listen for ren:// links from other launches")
+
+
+def _start_remote_server(tab_manager) -> None:
+    """Answer remote control requests from scripts and window managers."""
+    global REMOTE_SERVER
+    if REMOTE_SERVER is not None:
+        REMOTE_SERVER.stop()
+    REMOTE_SERVER = RemoteControlServer(
+        get_storage_manager().get_remote_path(),
+        browser_methods(tab_manager),
+    )
+    if not REMOTE_SERVER.start():
+        logger.warning("Unable to listen for remote control requests")
+
+
+def _start_metrics_server(tab_manager) -> None:
+    """Serve metrics on the port given on the command line, if any."""
+    global METRICS_SERVER
+    if METRICS_SERVER is not None:
+        METRICS_SERVER.stop()
+        METRICS_SERVER = None
+    if METRICS_PORT is None:
+        return
+    server = MetricsServer(METRICS_PORT, lambda: metrics_text(tab_manager))
+    if not server.start():
+        logger.warning("Unable to serve metrics on port %s", METRICS_PORT)
+        return
+    METRICS_SERVER = server
+    print(f"Serving metrics on http://127.0.0.1:{server.port}/metrics")
+
+
 async def reload_reticulum(page: Page, on_complete=None):
     """Hot reload Reticulum with updated configuration.
 
@@ -98,8 +229,6 @@ async def reload_reticulum(page: Page, on_complete=None):
         on_complete: Optional callback to run when reload is complete
 
     """
-    import asyncio
-
     try:
         global RNS_INSTANCE
 
@@ -118,7 +247,7 @@ async def reload_reticulum(page: Page, on_complete=None):
 
         await asyncio.sleep(0.5)
 
-        success = rns.initialize_reticulum(RNS_CONFIG_DIR)
+        success = rns.initialize_reticulum(rns_config_dir())
         if success:
             RNS_INSTANCE = rns.get_reticulum_instance()
             if on_complete:
@@ -135,10 +264,51 @@ async def reload_reticulum(page: Page, on_complete=None):
             on_complete(False, str(e))
 
 
+def _profile_name(value: str) -> str:
+    try:
+        return check_profile_name(value)
+    except ProfileError as exc:
+        raise argparse.ArgumentTypeError(str(exc)) from exc
+
+
+def _hand_off_all(addresses: list[str]) -> bool:
+    """Open addresses in the running instance, if there is one.
+
+    Returns:
+        bool: Whether a running instance took the addresses, so this
+        launch has nothing left to do.
+
+    """
+    path = get_storage_manager().get_instance_path()
+    if not hand_off(path, addresses[0]):
+        return False
+    print(f"Opened {addresses[0]} in the running Ren Browser")
+    for address in addresses[1:]:
+        if hand_off(path, address):
+            print(f"Opened {address} in the running Ren Browser")
+        else:
+            print(f"Could not open {address} in the running Ren Browser")
+    return True
+
+
 def run():
     """Run Ren Browser with command line argument parsing."""
-    global RENDERER, RNS_CONFIG_DIR
-    parser = argparse.ArgumentParser(description="Ren Browser")
+    global RENDERER, RNS_CONFIG_DIR, START_ADDRESSES, METRICS_PORT
+    if sys.argv[1:2] == ["fetch"]:
+        sys.exit(fetch(sys.argv[2:]))
+    if sys.argv[1:2] == ["remote"]:
+        sys.exit(remote(sys.argv[2:]))
+    parser = argparse.ArgumentParser(
+        description="Ren Browser",
+        epilog="Run 'ren-browser fetch --help' to fetch a page without the window, "
+        "or 'ren-browser remote --help' to control the running browser.",
+    )
+    parser.add_argument(
+        "address",
+        nargs="*",
+        default=[],
+        help="Addresses or ren:// links to open, each in its own tab",
+    )
     parser.add_argument(
         "-r",
         "--renderer",
@@ -166,8 +336,45 @@ def run():
         default=None,
         help="RNS config directory (default: ~/.reticulum/)",
     )
+    parser.add_argument(
+        "--profile",
+        type=_profile_name,
+        default=None,
+        help="Profile to use, created if it does not exist "
+        "(default: the one chosen in settings)",
+    )
+    parser.add_argument(
+        "--portable",
+        action="store_true",
+        help="Keep all data in a folder beside the executable",
+    )
+    parser.add_argument(
+        "--register-scheme",
+        action="store_true",
+        help="Register Ren Browser as the handler for ren:// links and exit",
+    )
+    parser.add_argument(
+        "--metrics-port",
+        type=int,
+        default=None,
+        help="Serve Prometheus metrics on this port of 127.0.0.1",
+    )
     args = parser.parse_args()
     RENDERER = args.renderer
+    METRICS_PORT = args.metrics_port
+    set_portable(args.portable)
+    set_profile(args.profile)
+
+    if args.register_scheme:
+        try:
+            print(register_url_scheme())
+        except OSError as e:
+            print(f"Could not register ren:// links: {e}")
+        return
+
+    START_ADDRESSES = list(args.address)
+    if START_ADDRESSES and _hand_off_all(START_ADDRESSES):
+        return
 
     # Set RNS config directory
     if args.config_dir:
@@ -0,0 +1,204 @@
+"""Bookmarks for Ren Browser.
+
+Keeps saved pages organized into folders and tags and persists them in the
+browser's storage directory.
+"""
+
+import time
+from dataclasses import asdict, dataclass, field
+
+UNSORTED = ""
+
+
+@dataclass
+class Bookmark:
+    """A saved page.
+
+    Contains the address, title, folder, tags and the time it was saved.
+    """
+
+    address: str
+    title: str
+    folder: str = UNSORTED
+    tags: list[str] = field(default_factory=list)
+    created: float = 0.0
+
+    @classmethod
+    def from_dict(cls, data) -> "Bookmark | None":
+        """Build a bookmark from stored data, or None if it is malformed."""
+        if isinstance(data, str) and data:
+            return cls(address=data, title=data)
+        if not isinstance(data, dict) or not data.get("address"):
+            return None
+        tags = data.get("tags")
+        try:
+            created = float(data.get("created", 0))
+        except (TypeError, ValueError):
+            created = 0.0
+        return cls(
+            address=data["address"],
+            title=data.get("title") or data["address"],
+            folder=str(data.get("folder") or UNSORTED),
+            tags=parse_tags(tags) if isinstance(tags, (list, str)) else [],
+            created=created,
+        )
+
+
+def parse_tags(tags) -> list[str]:
+    """Normalize tags given as a comma separated string or a list.
+
+    Tags are stripped and lowercased, and duplicates are dropped while keeping
+    the original order.
+    """
+    if isinstance(tags, str):
+        tags = tags.split(",")
+    result: list[str] = []
+    for tag in tags:
+        tag = str(tag).strip().lower()
+        if tag and tag not in result:
+            result.append(tag)
+    return result
+
+
+def folder_label(folder: str) -> str:
+    """Return the heading shown for a folder."""
+    return folder or "Unsorted"
+
+
+class BookmarkManager:
+    """Keeps the bookmarks and persists them through the storage manager."""
+
+    def __init__(self, storage):
+        """Load the stored bookmarks.
+
+        Args:
+            storage: StorageManager used to load and save the bookmark list.
+
+        """
+        self.storage = storage
+        self._bookmarks: list[Bookmark] = []
+        try:
+            stored = storage.load_bookmarks()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            bookmark = Bookmark.from_dict(data)
+            if bookmark is not None and self.get(bookmark.address) is None:
+                self._bookmarks.append(bookmark)
+
+    def get(self, address: str) -> Bookmark | None:
+        """Return the bookmark for an address, if there is one."""
+        for bookmark in self._bookmarks:
+            if bookmark.address == address:
+                return bookmark
+        return None
+
+    def add(
+        self,
+        address: str,
+        title: str,
+        folder: str = UNSORTED,
+        tags=None,
+    ) -> Bookmark:
+        """Bookmark an address, or update the existing bookmark for it.
+
+        Args:
+            address: Normalized address of the page.
+            title: Title shown for the bookmark.
+            folder: Folder to file the bookmark under.
+            tags: Tags as a list or a comma separated string.
+
+        Returns:
+            Bookmark: The new or updated bookmark.
+
+        """
+        bookmark = self.get(address)
+        if bookmark is None:
+            bookmark = Bookmark(address=address, title=title, created=time.time())
+            self._bookmarks.append(bookmark)
+        bookmark.title = title.strip() or address
+        bookmark.folder = folder.strip()
+        bookmark.tags = parse_tags(tags or [])
+        self._save()
+        return bookmark
+
+    def merge(self, bookmarks: list[Bookmark]) -> int:
+        """Add bookmarks that are not saved yet, such as imported ones.
+
+        Bookmarks for addresses that are already saved are left untouched.
+
+        Returns:
+            int: Number of bookmarks added.
+
+        """
+        added = 0
+        for bookmark in bookmarks:
+            if self.get(bookmark.address) is None:
+                self._bookmarks.append(bookmark)
+                added += 1
+        if added:
+            self._save()
+        return added
+
+    def move(self, address: str, folder: str) -> None:
+        """Move a bookmark to another folder."""
+        bookmark = self.get(address)
+        if bookmark is not None:
+            bookmark.folder = folder.strip()
+            self._save()
+
+    def remove(self, address: str) -> None:
+        """Delete the bookmark for an address, if any."""
+        self._bookmarks = [b for b in self._bookmarks if b.address != address]
+        self._save()
+
+    def clear(self, since: float | None = None) -> None:
+        """Delete bookmarks.
+
+        Args:
+            since: Only delete bookmarks created at or after this time.
+
+        """
+        if since is None:
+            self._bookmarks = []
+        else:
+            self._bookmarks = [b for b in self._bookmarks if b.created < since]
+        self._save()
+
+    def bookmarks(self, tag: str | None = None) -> list[Bookmark]:
+        """Return the bookmarks, optionally only those with a tag.
+
+        Returns:
+            Bookmarks ordered by folder, then title.
+
+        """
+        bookmarks = [b for b in self._bookmarks if tag is None or tag in b.tags]
+        return sorted(bookmarks, key=lambda b: (b.folder.lower(), b.title.lower()))
+
+    def folders(self) -> list[str]:
+        """Return the names of all folders in use, sorted."""
+        return sorted({b.folder for b in self._bookmarks if b.folder}, key=str.lower)
+
+    def tags(self) -> list[str]:
+        """Return every tag in use, sorted."""
+        return sorted({tag for b in self._bookmarks for tag in b.tags})
+
+    def by_folder(self, tag: str | None = None) -> list[tuple[str, list[Bookmark]]]:
+        """Group bookmarks by folder, unsorted bookmarks first."""
+        groups: list[tuple[str, list[Bookmark]]] = []
+        for bookmark in self.bookmarks(tag):
+            if groups and groups[-1][0] == bookmark.folder:
+                groups[-1][1].append(bookmark)
+            else:
+                groups.append((bookmark.folder, [bookmark]))
+        return groups
+
+    def as_dicts(self) -> list[dict]:
+        """Return the bookmarks in their stored form."""
+        return [asdict(bookmark) for bookmark in self.bookmarks()]
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_bookmarks(self.as_dicts())
+        except Exception:  # noqa: BLE001
+            pass
@@ -0,0 +1,188 @@
+"""Bookmark import and export for Ren Browser.
+
+Reads and writes bookmarks as JSON and in the Netscape bookmark file format
+understood by most browsers, so node lists can be backed up and shared.
+"""
+
+import html
+import json
+import time
+from dataclasses import asdict
+from html.parser import HTMLParser
+
+from ren_browser.bookmarks.bookmarks import Bookmark, parse_tags
+
+JSON_FORMAT = "ren-browser-bookmarks"
+JSON_VERSION = 1
+FOLDER_SEPARATOR = "/"
+
+
+class BookmarkFormatError(ValueError):
+    """Raised when a bookmarks file cannot be read."""
+
+
+def to_json(bookmarks: list[Bookmark]) -> str:
+    """Serialize bookmarks to the JSON export format."""
+    return json.dumps(
+        {
+            "format": JSON_FORMAT,
+            "version": JSON_VERSION,
+            "bookmarks": [asdict(bookmark) for bookmark in bookmarks],
+        },
+        indent=2,
+    )
+
+
+def from_json(text: str) -> list[Bookmark]:
+    """Read bookmarks from JSON.
+
+    Accepts the export format as well as a bare list of bookmarks, which is
+    how they are kept in the storage directory.
+
+    Raises:
+        BookmarkFormatError: If the text is not valid bookmark JSON.
+
+    """
+    try:
+        data = json.loads(text)
+    except json.JSONDecodeError as exc:
+        raise BookmarkFormatError(f"Not a valid JSON file: {exc}") from exc
+    if isinstance(data, dict):
+        data = data.get("bookmarks")
+    if not isinstance(data, list):
+        raise BookmarkFormatError("The file does not contain a list of bookmarks.")
+    bookmarks = [Bookmark.from_dict(entry) for entry in data]
+    return [bookmark for bookmark in bookmarks if bookmark is not None]
+
+
+def to_netscape_html(bookmarks: list[Bookmark]) -> str:
+    """Serialize bookmarks to the Netscape bookmark file format.
+
+    Folders become ``<H3>`` headings, with ``/`` in a folder name written as
+    nested folders.
+    """
+    lines = [
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>",
+        '<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">',
+        "<TITLE>Bookmarks</TITLE>",
+        "<H1>Bookmarks</H1>",
+        "<DL><p>",
+    ]
+    open_folders: list[str] = []
+    for bookmark in sorted(bookmarks, key=lambda b: (b.folder.lower(), b.title.lower())):
+        parts = [part for part in bookmark.folder.split(FOLDER_SEPARATOR) if part]
+        common = 0
+        while (
+            common < min(len(parts), len(open_folders))
+            and parts[common] == open_folders[common]
+        ):
+            common += 1
+        while len(open_folders) > common:
+            open_folders.pop()
+            lines.append(f"{_indent(len(open_folders) + 1)}</DL><p>")
+        for part in parts[common:]:
+            indent = _indent(len(open_folders) + 1)
+            lines.append(f"{indent}<DT><H3>{html.escape(part)}</H3>")
+            lines.append(f"{indent}<DL><p>")
+            open_folders.append(part)
+        attributes = f'HREF="{html.escape(bookmark.address)}"'
+        if bookmark.created:
+            attributes += f' ADD_DATE="{int(bookmark.created)}"'
+        if bookmark.tags:
+            attributes += f' TAGS="{html.escape(",".join(bookmark.tags))}"'
+        lines.append(
+            f"{_indent(len(open_folders) + 1)}<DT><A {attributes}>"
+            f"{html.escape(bookmark.title)}</A>",
+        )
+    while open_folders:
+        open_folders.pop()
+        lines.append(f"{_indent(len(open_folders) + 1)}</DL><p>")
+    lines.append("</DL><p>")
+    return "\n".join(lines) + "\n"
+
+
+def _indent(depth: int) -> str:
+    return "    " * depth
+
+
+class _NetscapeParser(HTMLParser):
+    def __init__(self):
+        super().__init__()
+        self.bookmarks: list[Bookmark] = []
+        self.folders: list[str | None] = []
+        self.pending_folder: str | None = None
+        self.heading: list[str] | None = None
+        self.link: dict | None = None
+        self.link_text: list[str] = []
+
+    def handle_starttag(self, tag, attrs):
+        attrs = {name.lower(): value or "" for name, value in attrs}
+        if tag == "dl":
+            self.folders.append(self.pending_folder)
+            self.pending_folder = None
+        elif tag == "h3":
+            self.heading = []
+        elif tag == "a" and attrs.get("href"):
+            self.link = attrs
+            self.link_text = []
+
+    def handle_endtag(self, tag):
+        if tag == "dl" and self.folders:
+            self.folders.pop()
+        elif tag == "h3" and self.heading is not None:
+            self.pending_folder = "".join(self.heading).strip()
+            self.heading = None
+        elif tag == "a" and self.link is not None:
+            self._add_link()
+
+    def handle_data(self, data):
+        if self.heading is not None:
+            self.heading.append(data)
+        elif self.link is not None:
+            self.link_text.append(data)
+
+    def _add_link(self):
+        link, self.link = self.link, None
+        address = link["href"].strip()
+        try:
+            created = float(link.get("add_date") or 0)
+        except ValueError:
+            created = 0.0
+        self.bookmarks.append(
+            Bookmark(
+                address=address,
+                title="".join(self.link_text).strip() or address,
+                folder=FOLDER_SEPARATOR.join(name for name in self.folders if name),
+                tags=parse_tags(link.get("tags", "")),
+                created=created or time.time(),
+            ),
+        )
+
+
+def from_netscape_html(text: str) -> list[Bookmark]:
+    """Read bookmarks from a Netscape bookmark file.
+
+    Nested folders are joined with ``/`` into a single folder name.
+
+    Raises:
+        BookmarkFormatError: If the file contains no bookmarks.
+
+    """
+    parser = _NetscapeParser()
+    parser.feed(text)
+    parser.close()
+    if not parser.bookmarks and "NETSCAPE-Bookmark-file" not in text:
+        raise BookmarkFormatError("The file is not a bookmarks file.")
+    return parser.bookmarks
+
+
+def parse_bookmarks(text: str) -> list[Bookmark]:
+    """Read bookmarks from either supported format, detected from the content.
+
+    Raises:
+        BookmarkFormatError: If the text is in neither format.
+
+    """
+    if text.lstrip().startswith(("{", "[")):
+        return from_json(text)
+    return from_netscape_html(text)
@@ -0,0 +1,123 @@
+"""Headless page fetching for Ren Browser.
+
+``ren-browser fetch <address>`` starts Reticulum without the window,
+fetches one page or file and writes it to stdout, so pages can be used in
+scripts and connectivity can be checked on headless gateways. With
+``--render`` micron pages are printed as the text they read as. Messages
+from Reticulum and the progress shown with ``--verbose`` go to stderr, so
+stdout holds only the page.
+"""
+
+import argparse
+import contextlib
+import sys
+
+from ren_browser import rns
+from ren_browser.downloads.downloads import is_file_address
+from ren_browser.pages.address import AddressError, parse_address
+from ren_browser.pages.page_request import PageFetcher, PageRequest, phase_label
+from ren_browser.renderer.micron import micron_to_text
+
+EXIT_FAILED = 1
+EXIT_USAGE = 2
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Return the parser for the arguments of the fetch command."""
+    parser = argparse.ArgumentParser(
+        prog="ren-browser fetch",
+        description="Fetch a page from a node and print it without the window",
+    )
+    parser.add_argument(
+        "address",
+        help="Address or ren:// link of the page or file",
+    )
+    parser.add_argument(
+        "--render",
+        action="store_true",
+        help="Print micron pages as plain text instead of their markup",
+    )
+    parser.add_argument(
+        "-c",
+        "--config-dir",
+        type=str,
+        default=None,
+        help="RNS config directory (default: ~/.reticulum/)",
+    )
+    parser.add_argument(
+        "--refresh-path",
+        action="store_true",
+        help="Request a new path to the node even if one is known",
+    )
+    parser.add_argument(
+        "-v",
+        "--verbose",
+        action="store_true",
+        help="Report the load phases on stderr",
+    )
+    return parser
+
+
+def fetch(argv: list[str], stdout=None, stderr=None) -> int:
+    """Run the fetch command.
+
+    Args:
+        argv: Arguments after ``fetch``.
+        stdout: Stream the page is written to, sys.stdout by default.
+        stderr: Stream for messages, sys.stderr by default.
+
+    Returns:
+        int: The exit status, 0 once the page was written.
+
+    """
+    stdout = stdout or sys.stdout
+    stderr = stderr or sys.stderr
+    args = build_parser().parse_args(argv)
+    try:
+        address = parse_address(args.address)
+    except AddressError as exc:
+        print(f"ren-browser fetch: {exc}", file=stderr)
+        return EXIT_USAGE
+
+    def on_progress(phase, fraction):
+        if args.verbose:
+            print(phase_label(phase, fraction), file=stderr)
+
+    req = PageRequest(
+        destination_hash=address.destination_hash,
+        page_path=address.page_path,
+    )
+    # Reticulum logs to stdout, which must hold nothing but the page
+    with contextlib.redirect_stdout(stderr):
+        if not rns.initialize_reticulum(args.config_dir):
+            error = rns.get_last_error() or "Unknown error"
+            print(f"ren-browser fetch: Reticulum did not start: {error}", file=stderr)
+            return EXIT_FAILED
+        try:
+            if is_file_address(address.page_path):
+                data, _ = PageFetcher.fetch_file(
+                    req,
+                    on_progress=on_progress,
+                    refresh_path=args.refresh_path,
+                )
+            else:
+                content = PageFetcher.fetch_page(
+                    req,
+                    on_progress=on_progress,
+                    refresh_path=args.refresh_path,
+                )
+                if args.render and address.page_path.endswith(".mu"):
+                    content = micron_to_text(content)
+                data = content.encode("utf-8")
+        except Exception as exc:  # noqa: BLE001
+            print(f"ren-browser fetch: {exc}", file=stderr)
+            return EXIT_FAILED
+        finally:
+            rns.shutdown_reticulum()
+    output = getattr(stdout, "buffer", None)
+    if output is not None:
+        output.write(data)
+        output.flush()
+    else:
+        stdout.write(data.decode("utf-8", errors="replace"))
+    return 0
@@ -0,0 +1,101 @@
+"""Remote control commands for Ren Browser.
+
+``ren-browser remote <method>`` calls a method of the browser that is
+already running and prints its result as JSON, so window managers and
+scripts can open addresses, list and reload tabs and check the status
+without writing a JSON-RPC client of their own.
+"""
+
+import argparse
+import json
+import sys
+
+from ren_browser.remote.remote import NOT_RUNNING, RemoteError, call
+from ren_browser.storage.portable import set_portable
+from ren_browser.storage.profiles import ProfileError, check_profile_name
+from ren_browser.storage.storage import get_storage_manager, set_profile
+
+EXIT_FAILED = 1
+EXIT_NOT_RUNNING = 3
+
+
+def _profile_name(value: str) -> str:
+    try:
+        return check_profile_name(value)
+    except ProfileError as exc:
+        raise argparse.ArgumentTypeError(str(exc)) from exc
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Return the parser for the arguments of the remote command."""
+    parser = argparse.ArgumentParser(
+        prog="ren-browser remote",
+        description="Control the running Ren Browser",
+    )
+    parser.add_argument(
+        "--profile",
+        type=_profile_name,
+        default=None,
+        help="Profile the browser was started with",
+    )
+    parser.add_argument(
+        "--portable",
+        action="store_true",
+        help="Reach a browser started with --portable",
+    )
+    methods = parser.add_subparsers(dest="method", required=True)
+    open_parser = methods.add_parser("open", help="Open an address in a new tab")
+    open_parser.add_argument("address", help="Address or ren:// link to open")
+    open_parser.add_argument(
+        "--background",
+        action="store_true",
+        help="Leave the active tab selected",
+    )
+    methods.add_parser("tabs", help="List the open tabs")
+    reload_parser = methods.add_parser("reload", help="Reload a tab")
+    reload_parser.add_argument(
+        "tab",
+        nargs="?",
+        type=int,
+        default=None,
+        help="Index of the tab (default: the active one)",
+    )
+    reload_parser.add_argument(
+        "--hard",
+        action="store_true",
+        help="Request a new path to the node before fetching",
+    )
+    methods.add_parser("status", help="Describe the browser and its active tab")
+    return parser
+
+
+def remote(argv: list[str], stdout=None, stderr=None) -> int:
+    """Run the remote command.
+
+    Args:
+        argv: Arguments after ``remote``.
+        stdout: Stream the result is written to, sys.stdout by default.
+        stderr: Stream for messages, sys.stderr by default.
+
+    Returns:
+        int: The exit status, 0 once the method succeeded.
+
+    """
+    stdout = stdout or sys.stdout
+    stderr = stderr or sys.stderr
+    args = build_parser().parse_args(argv)
+    set_portable(args.portable)
+    set_profile(args.profile)
+    if args.method == "open":
+        params = {"address": args.address, "background": args.background}
+    elif args.method == "reload":
+        params = {"tab": args.tab, "hard": args.hard}
+    else:
+        params = {}
+    try:
+        result = call(get_storage_manager().get_remote_path(), args.method, params)
+    except RemoteError as exc:
+        print(f"ren-browser remote: {exc}", file=stderr)
+        return EXIT_NOT_RUNNING if exc.code == NOT_RUNNING else EXIT_FAILED
+    print(json.dumps(result, indent=2), file=stdout)
+    return 0
@@ -0,0 +1,259 @@
+"""Address bar autocomplete for Ren Browser.
+
+Collects address suggestions from history, bookmarks, petnames and
+announced nodes and presents them in a keyboard navigable dropdown below
+the address bar.
+"""
+
+from collections.abc import Callable
+from dataclasses import dataclass
+
+import flet as ft
+
+SOURCE_ICONS = {
+    "bookmark": ft.Icons.BOOKMARK,
+    "petname": ft.Icons.BADGE_OUTLINED,
+    "history": ft.Icons.HISTORY,
+    "node": ft.Icons.LANGUAGE,
+}
+SOURCE_PRIORITY = {"bookmark": 0, "petname": 1, "history": 2, "node": 3}
+
+
+@dataclass
+class Suggestion:
+    """A single address suggestion.
+
+    Contains the address to open, a label to display, where it came from and
+    any extra text, such as the node name, it can be found by.
+    """
+
+    address: str
+    label: str
+    source: str
+    keywords: str = ""
+
+
+def _entry_suggestions(entries: list, source: str) -> list[Suggestion]:
+    """Convert stored history or bookmark entries into suggestions."""
+    suggestions = []
+    for entry in entries:
+        if isinstance(entry, str):
+            suggestions.append(Suggestion(entry, entry, source))
+        elif isinstance(entry, dict) and entry.get("address"):
+            label = entry.get("title") or entry["address"]
+            suggestions.append(Suggestion(entry["address"], label, source))
+    return suggestions
+
+
+def history_suggestions(
+    history: list,
+    node_name: Callable[[str], str | None] | None = None,
+) -> list[Suggestion]:
+    """Build suggestions from stored history entries.
+
+    Args:
+        history: Stored history entries.
+        node_name: Optional lookup from destination hash to node name, so
+            visits can also be found by the name of the node that served them.
+
+    Returns:
+        The suggestions.
+
+    """
+    suggestions = []
+    for entry in history:
+        node_hash = entry.get("node_hash") if isinstance(entry, dict) else None
+        for suggestion in _entry_suggestions([entry], "history"):
+            if node_name and node_hash:
+                suggestion.keywords = node_name(node_hash) or ""
+            suggestions.append(suggestion)
+    return suggestions
+
+
+def bookmark_suggestions(bookmarks: list) -> list[Suggestion]:
+    """Build suggestions from stored bookmarks."""
+    return _entry_suggestions(bookmarks, "bookmark")
+
+
+def petname_suggestions(petnames: list) -> list[Suggestion]:
+    """Build ``~name`` suggestions from petnames, found by their notes too."""
+    return [
+        Suggestion(f"~{p.name}", f"~{p.name}", "petname", keywords=p.note)
+        for p in petnames
+    ]
+
+
+def node_suggestions(announces: list) -> list[Suggestion]:
+    """Build suggestions for the index page of every announced node."""
+    return [
+        Suggestion(
+            f"{ann.destination_hash}:/page/index.mu",
+            ann.display_name or ann.destination_hash,
+            "node",
+        )
+        for ann in announces
+    ]
+
+
+def rank_suggestions(
+    query: str,
+    candidates: list[Suggestion],
+    limit: int = 8,
+) -> list[Suggestion]:
+    """Filter and order suggestions matching a query.
+
+    Prefix matches rank above substring matches, then bookmarks above
+    petnames above history above nodes. Duplicate addresses keep their best
+    ranked entry.
+
+    Args:
+        query: Text typed into the address bar.
+        candidates: Suggestions to choose from.
+        limit: Maximum number of suggestions to return.
+
+    Returns:
+        The best matching suggestions, best first.
+
+    """
+    needle = query.strip().lower()
+    if not needle:
+        return []
+
+    scored = []
+    for order, candidate in enumerate(candidates):
+        address = candidate.address.lower()
+        label = candidate.label.lower()
+        keywords = candidate.keywords.lower()
+        if address.startswith(needle) or label.startswith(needle):
+            match_rank = 0
+        elif needle in address or needle in label or needle in keywords:
+            match_rank = 1
+        else:
+            continue
+        priority = SOURCE_PRIORITY.get(candidate.source, len(SOURCE_PRIORITY))
+        scored.append(((match_rank, priority, order), candidate))
+
+    scored.sort(key=lambda item: item[0])
+    results = []
+    seen = set()
+    for _, candidate in scored:
+        if candidate.address in seen:
+            continue
+        seen.add(candidate.address)
+        results.append(candidate)
+        if len(results) >= limit:
+            break
+    return results
+
+
+class AddressAutocomplete:
+    """Dropdown of address suggestions shown while typing in the address bar."""
+
+    def __init__(self, page: ft.Page, on_select: Callable[[str], None]):
+        """Initialize the autocomplete dropdown.
+
+        Args:
+            page: Flet page instance for UI updates.
+            on_select: Called with the chosen address when a suggestion is picked.
+
+        """
+        self.page = page
+        self.on_select = on_select
+        self.sources: list[Callable[[], list[Suggestion]]] = []
+        self.suggestions: list[Suggestion] = []
+        self.selected = -1
+        self.list_column = ft.Column(spacing=2)
+        self.panel = ft.Container(
+            content=self.list_column,
+            visible=False,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+            border_radius=8,
+            padding=ft.padding.all(4),
+            margin=ft.margin.symmetric(horizontal=8),
+        )
+
+    @property
+    def is_open(self) -> bool:
+        """Return whether the dropdown is currently showing suggestions."""
+        return bool(self.panel.visible and self.suggestions)
+
+    def add_source(self, source: Callable[[], list[Suggestion]]) -> None:
+        """Register a callable that returns candidate suggestions."""
+        self.sources.append(source)
+
+    def _candidates(self) -> list[Suggestion]:
+        candidates = []
+        for source in self.sources:
+            try:
+                candidates.extend(source())
+            except Exception:  # noqa: BLE001
+                continue
+        return candidates
+
+    def update_query(self, query: str) -> None:
+        """Refresh the suggestions for the current address bar text."""
+        self.suggestions = rank_suggestions(query, self._candidates())
+        self.selected = -1
+        self._render()
+
+    def move(self, delta: int) -> None:
+        """Move the highlighted suggestion up or down."""
+        if not self.suggestions:
+            return
+        self.selected = (self.selected + delta) % len(self.suggestions)
+        self._render()
+
+    def accept(self) -> str | None:
+        """Return the highlighted address, if any, and close the dropdown."""
+        address = None
+        if 0 <= self.selected < len(self.suggestions):
+            address = self.suggestions[self.selected].address
+        self.dismiss()
+        return address
+
+    def dismiss(self) -> None:
+        """Hide the dropdown and forget the current suggestions."""
+        self.suggestions = []
+        self.selected = -1
+        self._render()
+
+    def _on_click(self, address: str) -> None:
+        self.dismiss()
+        self.on_select(address)
+
+    def _render(self) -> None:
+        self.list_column.controls = [
+            ft.Container(
+                content=ft.Row(
+                    controls=[
+                        ft.Icon(
+                            SOURCE_ICONS.get(suggestion.source, ft.Icons.LINK),
+                            size=16,
+                            color=ft.Colors.PRIMARY,
+                        ),
+                        ft.Text(
+                            suggestion.label,
+                            size=13,
+                            weight=ft.FontWeight.W_500,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                        ft.Text(
+                            suggestion.address,
+                            size=11,
+                            color=ft.Colors.ON_SURFACE_VARIANT,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                            expand=True,
+                        ),
+                    ],
+                    spacing=8,
+                ),
+                padding=ft.padding.symmetric(horizontal=12, vertical=8),
+                border_radius=6,
+                bgcolor=ft.Colors.PRIMARY_CONTAINER if i == self.selected else None,
+                ink=True,
+                on_click=lambda e, address=suggestion.address: self._on_click(address),
+            )
+            for i, suggestion in enumerate(self.suggestions)
+        ]
+        self.panel.visible = bool(self.suggestions)
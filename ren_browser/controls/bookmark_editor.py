@@ -0,0 +1,115 @@
+"""Inline bookmark editor for Ren Browser.
+
+Shown under the tab bar when the current page is bookmarked, so its title,
+folder and tags can be adjusted without leaving the page.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+
+class BookmarkEditor:
+    """Panel for editing the bookmark of the active page."""
+
+    def __init__(self, page: ft.Page, bookmarks, on_change: Callable[[], None]):
+        """Initialize the bookmark editor.
+
+        Args:
+            page: Flet page instance for UI updates.
+            bookmarks: BookmarkManager the edits are saved to.
+            on_change: Called after a bookmark is saved or removed.
+
+        """
+        self.page = page
+        self.bookmarks = bookmarks
+        self.on_change = on_change
+        self.address: str | None = None
+        self.title_field = ft.TextField(
+            label="Name",
+            dense=True,
+            expand=True,
+            on_submit=lambda e: self.save(),
+        )
+        self.folder_field = ft.TextField(
+            label="Folder",
+            dense=True,
+            width=180,
+            on_submit=lambda e: self.save(),
+        )
+        self.tags_field = ft.TextField(
+            label="Tags",
+            hint_text="comma separated",
+            dense=True,
+            width=200,
+            on_submit=lambda e: self.save(),
+        )
+        self.panel = ft.Container(
+            visible=False,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+            border_radius=8,
+            padding=ft.padding.all(8),
+            margin=ft.margin.symmetric(horizontal=8),
+            content=ft.Row(
+                spacing=8,
+                controls=[
+                    ft.Icon(ft.Icons.STAR, color=ft.Colors.AMBER_400),
+                    self.title_field,
+                    self.folder_field,
+                    self.tags_field,
+                    ft.FilledButton("Done", on_click=lambda e: self.save()),
+                    ft.TextButton("Remove", on_click=lambda e: self.remove()),
+                ],
+            ),
+        )
+
+    @property
+    def is_open(self) -> bool:
+        """Return whether the editor is currently showing."""
+        return bool(self.panel.visible)
+
+    def open(self, address: str, title: str) -> None:
+        """Bookmark an address if needed and show the editor for it.
+
+        Args:
+            address: Address of the page to bookmark.
+            title: Name to pre-fill when the page is not bookmarked yet.
+
+        """
+        bookmark = self.bookmarks.get(address)
+        if bookmark is None:
+            bookmark = self.bookmarks.add(address, title)
+            self.on_change()
+        self.address = address
+        self.title_field.value = bookmark.title
+        self.folder_field.value = bookmark.folder
+        self.tags_field.value = ", ".join(bookmark.tags)
+        self.panel.visible = True
+        self.page.update()
+        self.title_field.focus()
+
+    def save(self) -> None:
+        """Save the edited title, folder and tags and close the editor."""
+        if self.address is not None:
+            self.bookmarks.add(
+                self.address,
+                self.title_field.value or "",
+                self.folder_field.value or "",
+                self.tags_field.value or "",
+            )
+            self.on_change()
+        self.close()
+
+    def remove(self) -> None:
+        """Delete the bookmark being edited and close the editor."""
+        if self.address is not None:
+            self.bookmarks.remove(self.address)
+            self.on_change()
+        self.close()
+
+    def close(self) -> None:
+        """Hide the editor without further changes."""
+        self.address = None
+        self.panel.visible = False
+        self.page.update()
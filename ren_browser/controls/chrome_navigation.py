@@ -0,0 +1,135 @@
+"""Keyboard navigation of the browser window for Ren Browser.
+
+F6 and Shift+F6 move the keyboard between the parts of the window: the
+page, the address bar, the tab bar and the node list in the sidebar. The
+part that has the keyboard is outlined. In the tab bar the arrow keys,
+Home and End switch tabs and Delete closes the one shown. In the node list
+the arrow keys and Page Up and Down move through the nodes, and Enter
+opens the node or toggles the group outlined. Enter in the tab bar and
+Escape in either go back to the page.
+"""
+
+import flet as ft
+
+REGIONS = ("page", "address", "tabs", "nodes")
+PAGE_STEP = 10
+
+
+class ChromeNavigator:
+    """Moves the keyboard between the page and the browser's own controls."""
+
+    def __init__(self, tab_manager):
+        """Navigate the window of a tab manager.
+
+        Args:
+            tab_manager: Tab manager whose tab bar and sidebar are navigated.
+
+        """
+        self.tab_manager = tab_manager
+        self.region = "page"
+
+    def regions(self) -> list[str]:
+        """Return the parts of the window that can take the keyboard."""
+        if self.tab_manager.announce_list is None:
+            return [region for region in REGIONS if region != "nodes"]
+        return list(REGIONS)
+
+    def cycle(self, step: int) -> None:
+        """Move the keyboard to the next part of the window, or the previous."""
+        regions = self.regions()
+        index = regions.index(self.region) if self.region in regions else 0
+        self.enter(regions[(index + step) % len(regions)])
+
+    def enter(self, region: str) -> None:
+        """Give the keyboard to a part of the window and outline it."""
+        self._leave()
+        self.region = region
+        tab_manager = self.tab_manager
+        if region == "address":
+            tab_manager.reveal_address_bar()
+            tab_manager.manager.tabs[tab_manager.manager.index]["url_field"].focus()
+        elif region == "tabs":
+            self._outline_tab(True)
+        elif region == "nodes":
+            if tab_manager.sidebar is not None:
+                tab_manager.sidebar.set_collapsed(False)
+            tab_manager.announce_list.move_highlight(0)
+
+    def handle_key(self, key: str) -> bool:
+        """Act on a key pressed without modifiers.
+
+        Returns:
+            bool: Whether the key was used, False on the page and in the
+            address bar, where keys are left to the focused control.
+
+        """
+        if self.region not in ("tabs", "nodes"):
+            return False
+        keyboard = self.tab_manager.keyboard
+        if keyboard is not None and keyboard.focused is not None:
+            return False
+        if key == "Escape":
+            self.enter("page")
+            return True
+        if self.region == "tabs":
+            return self._tab_key(key)
+        return self._node_key(key)
+
+    def _tab_key(self, key: str) -> bool:
+        tab_manager = self.tab_manager
+        index = tab_manager.manager.index
+        count = len(tab_manager.manager.tabs)
+        targets = {
+            "Arrow Left": index - 1,
+            "Arrow Right": index + 1,
+            "Home": 0,
+            "End": count - 1,
+        }
+        if key in targets:
+            tab_manager.select_tab(targets[key] % count)
+        elif key == "Delete":
+            tab_manager._on_close_click(None)
+        elif key == "Enter":
+            self.enter("page")
+            return True
+        else:
+            return False
+        self._outline_tab(True)
+        return True
+
+    def _node_key(self, key: str) -> bool:
+        announce_list = self.tab_manager.announce_list
+        steps = {
+            "Arrow Down": 1,
+            "Arrow Up": -1,
+            "Page Down": PAGE_STEP,
+            "Page Up": -PAGE_STEP,
+        }
+        if key in steps:
+            announce_list.move_highlight(steps[key])
+        elif key == "Enter":
+            announce_list.activate_highlight()
+        else:
+            return False
+        return True
+
+    def _outline_tab(self, outlined: bool) -> None:
+        # The shown tab is edged in the accent colour, and outlined more
+        # boldly while the tab bar has the keyboard
+        tab_bar = self.tab_manager.tab_bar
+        tab_containers = [
+            c for c in tab_bar.content.controls if isinstance(c, ft.Container)
+        ]
+        index = self.tab_manager.manager.index
+        if index < len(tab_containers):
+            tab_containers[index].border = (
+                ft.border.all(3, ft.Colors.ON_SURFACE)
+                if outlined
+                else ft.border.all(2, ft.Colors.PRIMARY)
+            )
+
+    def _leave(self) -> None:
+        if self.region == "tabs":
+            self._outline_tab(False)
+        elif self.region == "nodes":
+            self.tab_manager.announce_list.clear_highlight()
@@ -0,0 +1,228 @@
+"""Content context menu for Ren Browser.
+
+Shows a right-click menu over rendered pages with clipboard, link, source,
+print, reading list, speed dial, watch, follow and QR code actions. Flet
+does not expose the current text selection, so Copy Page as Text and Select
+All both place the readable text of the whole page on the clipboard. A
+selection inside a line is copied with the usual Ctrl+C of the selectable
+text.
+"""
+
+from dataclasses import dataclass
+
+import flet as ft
+
+from ren_browser.pages.address import resolve_link
+
+MENU_WIDTH = 220
+
+
+@dataclass
+class MenuAction:
+    """A single context menu entry.
+
+    Contains the label, icon, action key, and whether it can be chosen.
+    """
+
+    label: str
+    icon: str
+    action: str
+    enabled: bool = True
+
+
+def build_actions(
+    link: str | None,
+    has_source: bool,
+    watched: bool = False,
+    following: bool = False,
+) -> list[MenuAction]:
+    """Return the menu entries for a right-click.
+
+    Args:
+        link: Link under the pointer, if any.
+        has_source: Whether the page has fetched source to show.
+        watched: Whether the page is being watched for changes.
+        following: Whether the node serving the page is followed.
+
+    Returns:
+        The entries in display order.
+
+    """
+    return [
+        MenuAction("Copy Page as Text", ft.Icons.CONTENT_COPY, "copy"),
+        MenuAction("Copy Link Address", ft.Icons.LINK, "copy_link", bool(link)),
+        MenuAction(
+            "Open Link in New Tab",
+            ft.Icons.OPEN_IN_NEW,
+            "open_link",
+            bool(link),
+        ),
+        MenuAction("Select All", ft.Icons.SELECT_ALL, "select_all"),
+        MenuAction("View Source", ft.Icons.CODE, "view_source", has_source),
+        MenuAction("Text Encoding…", ft.Icons.TRANSLATE, "encoding", has_source),
+        MenuAction("Save Page As…", ft.Icons.SAVE, "save_page", has_source),
+        MenuAction(
+            "Export as PDF…",
+            ft.Icons.PICTURE_AS_PDF,
+            "export_pdf",
+            has_source,
+        ),
+        MenuAction("Print…", ft.Icons.PRINT, "print", has_source),
+        MenuAction("Read Later", ft.Icons.BOOKMARK_ADD, "read_later", has_source),
+        MenuAction(
+            "Highlight Passage…",
+            ft.Icons.BORDER_COLOR,
+            "annotate",
+            has_source,
+        ),
+        MenuAction(
+            "Pin to Speed Dial",
+            ft.Icons.PUSH_PIN,
+            "pin_speed_dial",
+            has_source,
+        ),
+        MenuAction(
+            "Stop Watching" if watched else "Watch for Changes",
+            ft.Icons.VISIBILITY_OFF if watched else ft.Icons.VISIBILITY,
+            "watch",
+            has_source,
+        ),
+        MenuAction(
+            "Unfollow Node" if following else "Follow Node",
+            ft.Icons.PERSON_REMOVE if following else ft.Icons.RSS_FEED,
+            "follow",
+            has_source,
+        ),
+        MenuAction("Show QR Code", ft.Icons.QR_CODE, "show_qr", has_source),
+    ]
+
+
+class ContextMenu:
+    """Right-click menu for the page content area."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the context menu.
+
+        Args:
+            page: Flet page instance the menu is overlaid on.
+            tab_manager: Tab manager whose active page the actions apply to.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.link: str | None = None
+        self.menu = ft.Container(
+            width=MENU_WIDTH,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+            border_radius=8,
+            padding=ft.padding.symmetric(vertical=4),
+        )
+        self.overlay = ft.Stack(
+            expand=True,
+            visible=False,
+            controls=[
+                ft.Container(
+                    expand=True,
+                    bgcolor=ft.Colors.TRANSPARENT,
+                    on_click=lambda e: self.dismiss(),
+                ),
+                self.menu,
+            ],
+        )
+        page.overlay.append(self.overlay)
+
+    @property
+    def is_open(self) -> bool:
+        """Return whether the menu is currently showing."""
+        return bool(self.overlay.visible)
+
+    def wrap(self, control: ft.Control) -> ft.GestureDetector:
+        """Wrap the content area so right-clicks open the menu."""
+        return ft.GestureDetector(
+            content=control,
+            expand=True,
+            on_secondary_tap_down=self._on_secondary_tap,
+        )
+
+    def _on_secondary_tap(self, e) -> None:  # type: ignore
+        self.show(e.global_x, e.global_y)
+
+    def show(self, x: float, y: float) -> None:
+        """Open the menu at a position for whatever is under the pointer."""
+        manager = self.tab_manager.manager
+        tab = manager.tabs[manager.index]
+        self.link = self.tab_manager.hovered_link
+        actions = build_actions(
+            self.link,
+            tab.get("source") is not None,
+            self.tab_manager.watcher.get(tab.get("address")) is not None,
+            self.tab_manager.feed.get(self.tab_manager.current_hash(tab)) is not None,
+        )
+        self.menu.content = ft.Column(
+            spacing=0,
+            controls=[self._build_item(action) for action in actions],
+        )
+        self.menu.left = x
+        self.menu.top = y
+        self.overlay.visible = True
+        self.page.update()
+
+    def dismiss(self) -> None:
+        """Close the menu."""
+        self.overlay.visible = False
+        self.page.update()
+
+    def _build_item(self, action: MenuAction) -> ft.Control:
+        color = ft.Colors.ON_SURFACE if action.enabled else ft.Colors.GREY_600
+        return ft.Container(
+            content=ft.Row(
+                controls=[
+                    ft.Icon(action.icon, size=16, color=color),
+                    ft.Text(action.label, size=13, color=color),
+                ],
+                spacing=10,
+            ),
+            padding=ft.padding.symmetric(horizontal=12, vertical=8),
+            ink=action.enabled,
+            disabled=not action.enabled,
+            on_click=lambda e, key=action.action: self.run(key),
+        )
+
+    def run(self, action: str) -> None:
+        """Carry out a menu action on the active tab and close the menu."""
+        tabs = self.tab_manager
+        idx = tabs.manager.index
+        tab = tabs.manager.tabs[idx]
+        link = resolve_link(self.link, tabs.current_hash(tab)) if self.link else None
+        self.overlay.visible = False
+
+        if action in ("copy", "select_all"):
+            tabs.copy_page_text(idx)
+        elif action == "copy_link" and link:
+            self.page.set_clipboard(link)
+        elif action == "open_link" and link:
+            tabs.open_link_in_new_tab(link)
+        elif action == "encoding":
+            tabs.choose_encoding(idx)
+        elif action == "view_source":
+            tabs.view_source(idx)
+        elif action == "save_page":
+            tabs.save_page_as(idx)
+        elif action == "export_pdf":
+            tabs.export_pdf(idx)
+        elif action == "print":
+            tabs.print_page(idx)
+        elif action == "read_later":
+            tabs.read_later(idx)
+        elif action == "annotate":
+            tabs.annotate_page(idx)
+        elif action == "pin_speed_dial":
+            tabs.pin_to_speed_dial(idx)
+        elif action == "watch":
+            tabs.toggle_watch(idx)
+        elif action == "follow":
+            tabs.toggle_follow(idx)
+        elif action == "show_qr":
+            tabs.show_qr_code(idx)
+        self.page.update()
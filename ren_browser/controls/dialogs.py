@@ -0,0 +1,36 @@
+"""Dialogs for Ren Browser.
+
+Flutter closes a dialog on Escape only when a click beside it would close
+it too, which is not the case for modal dialogs. Dialogs are opened here
+instead, so the one on top can be closed with Escape as well.
+"""
+
+from weakref import WeakKeyDictionary
+
+import flet as ft
+
+_opened: WeakKeyDictionary = WeakKeyDictionary()
+
+
+def open_dialog(page: ft.Page, dialog: ft.AlertDialog) -> None:
+    """Show a dialog on a page, above any dialog already shown."""
+    stack = [shown for shown in _opened.get(page, []) if shown is not dialog]
+    _opened[page] = [*stack, dialog]
+    page.open(dialog)
+
+
+def close_top_dialog(page: ft.Page) -> bool:
+    """Close the dialog shown on top of a page.
+
+    Returns:
+        bool: False when no dialog is shown.
+
+    """
+    # Dialogs closed by their own buttons are no longer open
+    stack = [dialog for dialog in _opened.get(page, []) if dialog.open]
+    if not stack:
+        _opened.pop(page, None)
+        return False
+    _opened[page] = stack[:-1]
+    page.close(stack[-1])
+    return True
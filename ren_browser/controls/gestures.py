@@ -0,0 +1,97 @@
+"""Touch gestures for Ren Browser.
+
+Swiping the page sideways goes back or forward in the tab's history,
+swiping the tab bar switches tabs and pulling the page down past its top
+reloads it, so the browser can be used on a touch screen without the
+toolbar buttons or keyboard shortcuts.
+"""
+
+import flet as ft
+
+# Pixels per second a drag has to end with to count as a swipe, so slow
+# drags such as selecting text do not navigate.
+SWIPE_VELOCITY = 600
+# Pixels the page has to be pulled past its top to reload.
+PULL_DISTANCE = 96
+
+
+def swipe_direction(e) -> int:
+    """Return 1 for a swipe to the right, -1 to the left and 0 for none."""
+    velocity = e.primary_velocity or 0
+    if abs(velocity) < SWIPE_VELOCITY:
+        return 0
+    return 1 if velocity > 0 else -1
+
+
+class TouchGestures:
+    """Turns swipes and pulls into browser navigation."""
+
+    def __init__(self, tab_manager):
+        """Create the gestures for a tab manager.
+
+        Args:
+            tab_manager: Tab manager whose tabs are navigated.
+
+        """
+        self.tab_manager = tab_manager
+        self._pulled = 0.0
+        self._pull_done = False
+
+    def wrap_content(self, control: ft.Control) -> ft.GestureDetector:
+        """Wrap the content area so sideways swipes go back and forward."""
+        return ft.GestureDetector(
+            content=control,
+            expand=True,
+            on_horizontal_drag_end=self._on_content_swipe,
+        )
+
+    def wrap_tab_bar(self, control: ft.Control) -> ft.GestureDetector:
+        """Wrap the tab bar so sideways swipes switch tabs.
+
+        While the tabs overflow the bar, swipes scroll it instead.
+        """
+        return ft.GestureDetector(
+            content=control,
+            on_horizontal_drag_end=self._on_tab_bar_swipe,
+        )
+
+    def _on_content_swipe(self, e) -> None:  # type: ignore
+        direction = swipe_direction(e)
+        idx = self.tab_manager.manager.index
+        # Like turning pages, swiping to the right brings back the last one
+        if direction > 0:
+            self.tab_manager.go_back(idx)
+        elif direction < 0:
+            self.tab_manager.go_forward(idx)
+
+    def _on_tab_bar_swipe(self, e) -> None:  # type: ignore
+        direction = swipe_direction(e)
+        if not direction:
+            return
+        manager = self.tab_manager.manager
+        self.tab_manager.select_tab((manager.index - direction) % len(manager.tabs))
+        self.tab_manager.page.update()
+
+    def on_scroll(self, e) -> None:  # type: ignore
+        """Reload the active tab once its page is pulled down past the top.
+
+        Pulling past the top is reported as overscroll on Android and as a
+        negative scroll position on iOS.
+        """
+        event_type = getattr(e, "event_type", None)
+        if event_type in ("start", "end") or e.pixels > 0:
+            self._pulled = 0.0
+            self._pull_done = False
+            return
+        if event_type == "over" and (e.overscroll or 0) < 0:
+            self._pulled -= e.overscroll
+        elif e.pixels < 0:
+            self._pulled = max(self._pulled, -e.pixels)
+        else:
+            return
+        if self._pull_done or self._pulled < PULL_DISTANCE:
+            return
+        # One reload per pull, however far the page is dragged
+        self._pull_done = True
+        self.tab_manager.reload(self.tab_manager.manager.index)
+        self.tab_manager.page.update()
@@ -0,0 +1,88 @@
+"""Keyboard link navigation for Ren Browser.
+
+Tab and Shift+Tab move the focus through the links of the page in the
+active tab, wrapping around at either end, and scroll the focused link
+into view. Enter follows it. Long pages built a chunk at a time get their
+next chunk when Tab passes the last link shown. While a field on the page
+has focus, Tab is left to move between the fields.
+"""
+
+from functools import partial
+
+import flet as ft
+
+from ren_browser.renderer.micron import MicronView
+
+
+def is_link(control: ft.Control) -> bool:
+    """Return whether a control is a link rendered on a page."""
+    return isinstance(control, ft.TextButton) and isinstance(control.data, str)
+
+
+def page_links(control: ft.Control) -> list[ft.Control]:
+    """Return the links in a control tree, in reading order."""
+    if is_link(control):
+        return [control]
+    children = list(getattr(control, "controls", None) or [])
+    content = getattr(control, "content", None)
+    if isinstance(content, ft.Control):
+        children.append(content)
+    return [link for child in children for link in page_links(child)]
+
+
+class LinkNavigator:
+    """Moves the keyboard focus between the links of the active page."""
+
+    def __init__(self, tab_manager):
+        """Navigate the pages of a tab manager.
+
+        Args:
+            tab_manager: Tab manager whose active tab is navigated.
+
+        """
+        self.tab_manager = tab_manager
+
+    def links(self, tab: dict) -> list[ft.Control]:
+        """Return the links of a tab's page, remembering which gets focus."""
+        links = page_links(tab["content_control"])
+        for link in links:
+            link.on_focus = partial(self._on_link_focus, tab, link)
+        return links
+
+    def move(self, step: int) -> bool:
+        """Focus the next link, or the previous one for a negative step.
+
+        Returns:
+            bool: False when the page has no links or a field has focus.
+
+        """
+        keyboard = self.tab_manager.keyboard
+        if keyboard is not None and keyboard.focused is not None:
+            return False
+        tab = self.tab_manager.manager.tabs[self.tab_manager.manager.index]
+        links = self.links(tab)
+        current = tab.get("focused_link")
+        index = next((i for i, link in enumerate(links) if link is current), None)
+        if index is None:
+            index = -1 if step > 0 else len(links)
+        target = index + step
+        control = tab["content_control"]
+        if target >= len(links) and isinstance(control, MicronView):
+            if control.render_more():
+                links = self.links(tab)
+        if not links:
+            return False
+        self.focus(tab, links[target % len(links)])
+        return True
+
+    def focus(self, tab: dict, link: ft.Control) -> None:
+        """Give a link the keyboard focus and scroll it into view."""
+        if link.key is None:
+            link.key = f"link-{id(link)}"
+        tab["focused_link"] = link
+        link.focus()
+        tab["content"].scroll_to(key=link.key, duration=200)
+
+    def _on_link_focus(self, tab: dict, link: ft.Control, e) -> None:  # type: ignore
+        # Clicking a link or tabbing to it natively moves the focus as well
+        tab["focused_link"] = link
@@ -1,11 +1,235 @@
 """Keyboard shortcuts handling for Ren Browser.
 
-Provides keyboard event handling and delegation to tab manager
-and UI components.
+Maps key chords such as ``Ctrl+Shift+T`` to browser actions and dispatches
+keyboard events to the tab manager and UI components. The default chords
+can be changed in the settings, which store only the actions the user
+rebound. ACTIONS lists every action the browser can run this way, and the
+command palette offers the same list.
 """
 
 import flet as ft
 
+from ren_browser.controls.dialogs import close_top_dialog
+
+MODIFIERS = ("Ctrl", "Alt", "Shift")
+_MODIFIER_NAMES = {
+    "ctrl": "Ctrl",
+    "control": "Ctrl",
+    "cmd": "Ctrl",
+    "meta": "Ctrl",
+    "alt": "Alt",
+    "option": "Alt",
+    "shift": "Shift",
+}
+
+# Action, its label in the settings and the command palette and its default
+# chords, in the order they are listed.
+ACTIONS = {
+    "command_palette": ("Command palette", ("Ctrl+Shift+P",)),
+    "new_tab": ("New tab", ("Ctrl+T",)),
+    "new_private_tab": ("New private tab", ("Ctrl+Shift+N",)),
+    "close_tab": ("Close tab", ("Ctrl+W",)),
+    "reopen_tab": ("Reopen closed tab", ("Ctrl+Shift+T",)),
+    "add_to_group": ("Add tab to group", ()),
+    "remove_from_group": ("Remove tab from group", ()),
+    "save_workspace": ("Save tabs as workspace", ()),
+    "open_workspace": ("Open workspace", ()),
+    "next_tab": ("Next tab", ("Ctrl+Tab",)),
+    "previous_tab": ("Previous tab", ("Ctrl+Shift+Tab",)),
+    "focus_address": ("Focus address bar", ("Ctrl+L",)),
+    "paste_and_go": ("Paste and go", ("Ctrl+Shift+V",)),
+    "go_home": ("Go to home page", ("Alt+Home",)),
+    "back": ("Back", ("Alt+Arrow Left",)),
+    "forward": ("Forward", ("Alt+Arrow Right",)),
+    "next_link": ("Next link on page", ("Tab",)),
+    "previous_link": ("Previous link on page", ("Shift+Tab",)),
+    "next_region": ("Move to next part of the window", ("F6",)),
+    "previous_region": ("Move to previous part of the window", ("Shift+F6",)),
+    "reload": ("Reload page", ("Ctrl+R", "F5")),
+    "hard_reload": ("Hard refresh", ("Ctrl+Shift+R",)),
+    "bookmark_page": ("Bookmark page", ("Ctrl+D",)),
+    "annotate_page": ("Highlight passage", ("Ctrl+Shift+H",)),
+    "bookmarks": ("Open bookmarks", ("Ctrl+Shift+O",)),
+    "history": ("Open history", ("Ctrl+H",)),
+    "search_pages": ("Search saved pages", ("Ctrl+Shift+F",)),
+    "annotations": ("Open annotations", ()),
+    "petnames": ("Open petname directory", ()),
+    "announce_activity": ("Open announce activity", ()),
+    "downloads": ("Show downloads", ("Ctrl+J",)),
+    "view_source": ("View page source", ("Ctrl+U",)),
+    "text_encoding": ("Change text encoding", ()),
+    "show_changes": ("Show what changed on the page", ()),
+    "copy_page_text": ("Copy page as text", ("Ctrl+Shift+C",)),
+    "open_file": ("Open file", ("Ctrl+O",)),
+    "save_page": ("Save page as", ("Ctrl+S",)),
+    "export_pdf": ("Export page as PDF", ("Ctrl+P",)),
+    "print_page": ("Print page", ()),
+    "show_announces": ("Show announces", ("Ctrl+A",)),
+    "show_interfaces": ("Show network interfaces", ()),
+    "toggle_sidebar": ("Toggle sidebar", ("Ctrl+B",)),
+    "zoom_in": ("Zoom in", ("Ctrl+=", "Ctrl++", "Ctrl+Equal", "Ctrl+Numpad Add")),
+    "zoom_out": ("Zoom out", ("Ctrl+-", "Ctrl+Minus", "Ctrl+Numpad Subtract")),
+    "zoom_reset": ("Reset zoom", ("Ctrl+0", "Ctrl+Numpad 0")),
+    "fullscreen": ("Toggle fullscreen", ("F11",)),
+    "performance_overlay": ("Toggle performance overlay", ("Ctrl+Shift+F12",)),
+    "devtools": ("Toggle developer tools", ("F12", "Ctrl+Shift+I")),
+    "shortcut_help": ("Show keyboard shortcuts", ("F1",)),
+    "open_settings": ("Open settings", ()),
+    "switch_renderer": ("Switch renderer", ()),
+    "clear_cache": ("Clear page cache", ()),
+}
+
+# Categories the actions are grouped in where the shortcuts are listed, each
+# action in exactly one.
+ACTION_CATEGORIES = {
+    "General": ("command_palette", "shortcut_help", "open_settings"),
+    "Tabs": (
+        "new_tab",
+        "new_private_tab",
+        "close_tab",
+        "reopen_tab",
+        "add_to_group",
+        "remove_from_group",
+        "save_workspace",
+        "open_workspace",
+        "next_tab",
+        "previous_tab",
+    ),
+    "Navigation": (
+        "focus_address",
+        "paste_and_go",
+        "go_home",
+        "back",
+        "forward",
+        "reload",
+        "hard_reload",
+        "next_link",
+        "previous_link",
+        "next_region",
+        "previous_region",
+    ),
+    "Page": (
+        "bookmark_page",
+        "annotate_page",
+        "view_source",
+        "text_encoding",
+        "show_changes",
+        "copy_page_text",
+        "switch_renderer",
+        "open_file",
+        "save_page",
+        "export_pdf",
+        "print_page",
+    ),
+    "Window": (
+        "bookmarks",
+        "history",
+        "search_pages",
+        "annotations",
+        "petnames",
+        "downloads",
+        "show_announces",
+        "announce_activity",
+        "show_interfaces",
+        "toggle_sidebar",
+        "zoom_in",
+        "zoom_out",
+        "zoom_reset",
+        "fullscreen",
+    ),
+    "Developer": ("devtools", "performance_overlay", "clear_cache"),
+}
+
+
+def _normalize_key(key: str) -> str:
+    key = key.strip()
+    if len(key) == 1:
+        return key.upper()
+    return " ".join(word.capitalize() for word in key.split())
+
+
+def parse_chord(text: str) -> str:
+    """Return a chord in its normal form, such as ``Ctrl+Shift+T``.
+
+    Modifiers may be written in any case and order, and Cmd or Meta mean
+    Ctrl.
+
+    Raises:
+        ValueError: If the chord has no key or an unknown modifier, or no
+            modifier for a key that types a character.
+
+    """
+    text = text.strip()
+    head = text[:-1].rstrip()
+    if text == "+" or (text.endswith("+") and head.endswith("+")):
+        names, key = head[:-1], "+"
+    else:
+        names, _, key = text.rpartition("+")
+    modifiers = set()
+    for name in names.split("+") if names.strip() else []:
+        modifier = _MODIFIER_NAMES.get(name.strip().lower())
+        if modifier is None:
+            raise ValueError(f"{name.strip() or '+'} is not a modifier key.")
+        modifiers.add(modifier)
+    if not key.strip():
+        raise ValueError(f"{text or 'A shortcut'} has no key.")
+    key = _normalize_key(key)
+    if len(key) == 1 and not modifiers & {"Ctrl", "Alt"}:
+        raise ValueError(f"{text} needs Ctrl or Alt, or it blocks typing.")
+    return "+".join([*(m for m in MODIFIERS if m in modifiers), key])
+
+
+def split_chord(chord: str) -> tuple[list[str], str]:
+    """Split a chord in normal form into its modifiers and its key."""
+    if chord.endswith("+"):
+        return [m for m in chord[:-1].split("+") if m], "+"
+    *modifiers, key = chord.split("+")
+    return modifiers, key
+
+
+def event_chord(e: ft.KeyboardEvent) -> str:
+    """Return the chord of a keyboard event, Meta counting as Ctrl."""
+    pressed = (("Ctrl", e.ctrl or e.meta), ("Alt", e.alt), ("Shift", e.shift))
+    modifiers = [name for name, down in pressed if down]
+    return "+".join([*modifiers, _normalize_key(e.key)])
+
+
+def keybindings(overrides) -> dict[str, list[str]]:
+    """Return the chords of every action, with the user's overrides applied.
+
+    Args:
+        overrides: Stored ``keybindings`` setting mapping actions to lists
+            of chords. Unknown actions and invalid chords are ignored.
+
+    """
+    bindings = {action: list(chords) for action, (_, chords) in ACTIONS.items()}
+    if not isinstance(overrides, dict):
+        return bindings
+    for action, chords in overrides.items():
+        if action not in bindings or not isinstance(chords, list):
+            continue
+        parsed = []
+        for chord in chords:
+            try:
+                parsed.append(parse_chord(chord))
+            except (AttributeError, ValueError):
+                continue
+        bindings[action] = list(dict.fromkeys(parsed))
+    return bindings
+
+
+def find_conflicts(bindings: dict[str, list[str]]) -> dict[str, list[str]]:
+    """Return the chords bound to more than one action, with those actions."""
+    actions_by_chord: dict[str, list[str]] = {}
+    for action, chords in bindings.items():
+        for chord in chords:
+            actions_by_chord.setdefault(chord, []).append(action)
+    return {
+        chord: actions
+        for chord, actions in actions_by_chord.items()
+        if len(actions) > 1
+    }
+
 
 class Shortcuts:
     """Handles keyboard shortcuts for the Ren Browser.
@@ -23,8 +247,37 @@ class Shortcuts:
         """
         self.page = page
         self.tab_manager = tab_manager
+        self._overrides = None
+        self._actions: dict[str, str] = {}
         page.on_keyboard_event = self.on_keyboard
 
+    def action_for(self, chord: str) -> str | None:
+        """Return the action bound to a chord.
+
+        Modifiers no binding uses are ignored, so Shift with Ctrl+= still
+        zooms in and Alt does not stop Ctrl+T opening a tab.
+        """
+        overrides = self.tab_manager.settings.get("keybindings")
+        if overrides != self._overrides or not self._actions:
+            self._overrides = overrides
+            self._actions = {}
+            for action, chords in keybindings(overrides).items():
+                for bound in chords:
+                    self._actions.setdefault(bound, action)
+        modifiers, key = split_chord(chord)
+        candidates = [modifiers]
+        for optional in ("Alt", "Shift"):
+            candidates += [
+                [m for m in mods if m != optional]
+                for mods in candidates
+                if optional in mods
+            ]
+        for mods in candidates:
+            action = self._actions.get("+".join([*mods, key]))
+            if action is not None:
+                return action
+        return None
+
     def on_keyboard(self, e: ft.KeyboardEvent):
         """Handle keyboard events and execute corresponding actions.
 
@@ -34,29 +287,151 @@ class Shortcuts:
         """
         # Support Ctrl (and Meta on macOS)
         ctrl = e.ctrl or e.meta
-        if not ctrl:
+        autocomplete = getattr(self.tab_manager, "autocomplete", None)
+        if not ctrl and autocomplete is not None and autocomplete.is_open:
+            # Navigate address suggestions: Up/Down to move, Escape to close
+            if e.key == "Arrow Down":
+                autocomplete.move(1)
+            elif e.key == "Arrow Up":
+                autocomplete.move(-1)
+            elif e.key == "Escape":
+                autocomplete.dismiss()
+            else:
+                return
+            self.page.update()
+            return
+        if not ctrl and e.key == "Escape" and close_top_dialog(self.page):
+            return
+        navigator = getattr(self.tab_manager, "chrome_navigator", None)
+        if (
+            not ctrl
+            and not e.alt
+            and navigator is not None
+            and navigator.handle_key(e.key)
+        ):
+            self.page.update()
             return
-        key = e.key
-        # New tab: Ctrl+T
-        if key.lower() == "t":
-            self.tab_manager._on_add_click(None)
-        # Close tab: Ctrl+W
-        elif key.lower() == "w":
-            self.tab_manager._on_close_click(None)
-        # Focus URL bar: Ctrl+L
-        elif key.lower() == "l":
-            idx = self.tab_manager.manager.index
-            field = self.tab_manager.manager.tabs[idx]["url_field"]
-            field.focus()
-        # Show announces drawer: Ctrl+A
-        elif key.lower() == "a":
-            self.page.drawer.open = True
-        # Cycle through tabs: Ctrl+Tab / Ctrl+Shift+Tab
-        elif key == "Tab":
-            idx = self.tab_manager.manager.index
-            count = len(self.tab_manager.manager.tabs)
-            new_idx = (idx - 1) % count if e.shift else (idx + 1) % count
-            self.tab_manager.select_tab(new_idx)
+        if not ctrl and e.key == "Escape":
+            editor = getattr(self.tab_manager, "bookmark_editor", None)
+            # Close the bookmark editor first, then leave fullscreen
+            if editor is not None and editor.is_open:
+                editor.close()
+            elif self.tab_manager.fullscreen:
+                self.tab_manager.set_fullscreen(False)
+            return
+        action = self.action_for(event_chord(e))
+        if action is not None:
+            self.dispatch(action)
+
+    def dispatch(self, action: str) -> None:
+        """Run an action of ACTIONS and update the page."""
+        tab_manager = self.tab_manager
+        idx = tab_manager.manager.index
+        count = len(tab_manager.manager.tabs)
+        if action == "command_palette":
+            tab_manager.command_palette.open()
+        elif action == "shortcut_help":
+            tab_manager.shortcut_sheet.open()
+        elif action == "new_tab":
+            tab_manager._on_add_click(None)
+        elif action == "new_private_tab":
+            tab_manager.open_private_tab()
+        elif action == "close_tab":
+            tab_manager._on_close_click(None)
+        elif action == "reopen_tab":
+            tab_manager.reopen_closed_tab()
+        elif action == "add_to_group":
+            tab_manager.group_dialog.add_tab()
+        elif action == "remove_from_group":
+            tab_manager.remove_from_group(idx)
+        elif action == "save_workspace":
+            tab_manager.workspaces.choose_save()
+        elif action == "open_workspace":
+            tab_manager.workspaces.choose_open()
+        elif action == "next_tab":
+            tab_manager.select_tab((idx + 1) % count)
+        elif action == "previous_tab":
+            tab_manager.select_tab((idx - 1) % count)
+        elif action == "focus_address":
+            tab_manager.reveal_address_bar()
+            tab_manager.manager.tabs[idx]["url_field"].focus()
+        elif action == "paste_and_go":
+            tab_manager.paste_and_go()
+        elif action == "go_home":
+            tab_manager.go_home()
+        elif action == "back":
+            tab_manager.go_back(idx)
+        elif action == "forward":
+            tab_manager.go_forward(idx)
+        elif action in ("next_link", "previous_link"):
+            if not tab_manager.link_navigator.move(1 if action == "next_link" else -1):
+                return
+        elif action in ("next_region", "previous_region"):
+            tab_manager.chrome_navigator.cycle(1 if action == "next_region" else -1)
+        elif action == "reload":
+            tab_manager.reload(idx)
+        elif action == "hard_reload":
+            tab_manager.reload(idx, hard=True)
+        elif action == "bookmark_page":
+            tab_manager.bookmark_current_page()
+        elif action == "annotate_page":
+            tab_manager.annotate_page(idx)
+        elif action == "bookmarks":
+            tab_manager.open_link_in_new_tab("about:bookmarks")
+        elif action == "history":
+            tab_manager.open_link_in_new_tab("about:history")
+        elif action == "search_pages":
+            tab_manager.open_link_in_new_tab("about:search")
+        elif action == "annotations":
+            tab_manager.open_link_in_new_tab("about:annotations")
+        elif action == "petnames":
+            tab_manager.open_link_in_new_tab("about:petnames")
+        elif action == "announce_activity":
+            tab_manager.open_link_in_new_tab("about:activity")
+        elif action == "downloads":
+            tab_manager.downloads_panel.toggle()
+        elif action == "view_source":
+            tab_manager.view_source(idx)
+        elif action == "text_encoding":
+            tab_manager.choose_encoding(idx)
+        elif action == "show_changes":
+            tab_manager.toggle_changes(idx)
+        elif action == "copy_page_text":
+            tab_manager.copy_page_text(idx)
+        elif action == "open_file":
+            tab_manager.file_opener.choose()
+        elif action == "save_page":
+            tab_manager.save_page_as(idx)
+        elif action == "export_pdf":
+            tab_manager.export_pdf(idx)
+        elif action == "print_page":
+            tab_manager.print_page(idx)
+        elif action == "show_announces":
+            tab_manager.sidebar.set_collapsed(False)
+        elif action == "show_interfaces" and tab_manager.status_bar:
+            tab_manager.status_bar.show_interfaces()
+        elif action == "toggle_sidebar":
+            tab_manager.sidebar.toggle()
+        elif action == "zoom_in":
+            tab_manager.zoom.zoom_in()
+        elif action == "zoom_out":
+            tab_manager.zoom.zoom_out()
+        elif action == "zoom_reset":
+            tab_manager.zoom.reset()
+        elif action == "fullscreen":
+            tab_manager.toggle_fullscreen()
+        elif action == "performance_overlay":
+            tab_manager.performance_overlay.toggle()
+        elif action == "devtools":
+            tab_manager.devtools.toggle()
+        elif action == "open_settings":
+            from ren_browser.ui.settings import open_settings_tab
+
+            open_settings_tab(self.page, tab_manager)
+        elif action == "switch_renderer":
+            tab_manager.toggle_plain_text(idx)
+        elif action == "clear_cache":
+            tab_manager.clear_page_cache()
         else:
             return
         # Apply UI updates
@@ -0,0 +1,215 @@
+"""Crash reports for Ren Browser.
+
+Errors nothing caught, on the main thread, in the event loop or in any
+other thread, are written to a report in the crashes folder of the storage
+directory. A report has the traceback, the browser and Python versions,
+the last log messages and, when allowed under Settings → Privacy, the
+addresses of the open tabs. The next launch finds the report and offers
+to restore the tabs that were open.
+"""
+
+import datetime
+import logging
+import platform
+import sys
+import threading
+import traceback
+from collections.abc import Callable
+from importlib.metadata import PackageNotFoundError, version
+from pathlib import Path
+
+from ren_browser.logs import recent_logs
+
+# Log messages included in a report, the newest kept
+MAX_LOG_LINES = 200
+# Reports kept in the crashes folder, the oldest removed first
+MAX_REPORTS = 10
+# Names the report the next launch has not offered to recover from yet
+PENDING_FILE = "pending"
+logger = logging.getLogger(__name__)
+
+
+def browser_version() -> str:
+    """Return the installed version of Ren Browser, "unknown" if not installed."""
+    try:
+        return version("ren-browser")
+    except PackageNotFoundError:
+        return "unknown"
+
+
+def open_addresses(tab_manager) -> list[str] | None:
+    """Return the addresses of the open tabs for a report.
+
+    Returns:
+        list[str] | None: Addresses of the tabs that are not private, or
+        None when reports may not include them.
+
+    """
+    if not tab_manager.settings.get("crash_report_tabs", False):
+        return None
+    return [
+        tab["address"]
+        for tab in tab_manager.manager.tabs
+        if tab.get("address") and not tab.get("private")
+    ]
+
+
+def format_report(
+    exc_type,
+    exc,
+    tb,
+    tabs: list[str] | None,
+    now: datetime.datetime | None = None,
+) -> str:
+    """Describe a crash as the text of a report.
+
+    Args:
+        exc_type: Type of the error nothing caught.
+        exc: The error.
+        tb: Traceback of the error.
+        tabs: Addresses of the open tabs, None if they may not be included.
+        now: Time of the crash, the current time by default.
+
+    Returns:
+        str: The report.
+
+    """
+    now = now or datetime.datetime.now()
+    lines = [
+        "Ren Browser crash report",
+        f"Time: {now.isoformat(timespec='seconds')}",
+        f"Version: {browser_version()}",
+        f"Python: {platform.python_version()} on {platform.platform()}",
+        "",
+        "".join(traceback.format_exception(exc_type, exc, tb)).rstrip(),
+        "",
+        "Open tabs:",
+    ]
+    if tabs is None:
+        lines.append("  (not included, see Settings → Privacy)")
+    else:
+        lines.extend(f"  {address}" for address in tabs)
+        if not tabs:
+            lines.append("  (none)")
+    lines.extend(["", "Recent log messages:"])
+    entries = recent_logs()[-MAX_LOG_LINES:]
+    lines.extend(f"  {entry.format()}" for entry in entries)
+    if not entries:
+        lines.append("  (none)")
+    return "\n".join(lines) + "\n"
+
+
+def write_report(
+    directory: Path,
+    text: str,
+    now: datetime.datetime | None = None,
+) -> Path:
+    """Save a report and mark it for the next launch to offer recovery.
+
+    Args:
+        directory: Crashes folder of the storage directory.
+        text: The report.
+        now: Time of the crash, naming the file.
+
+    Returns:
+        Path: The report file.
+
+    """
+    now = now or datetime.datetime.now()
+    directory.mkdir(parents=True, exist_ok=True)
+    path = directory / f"crash-{now:%Y%m%d-%H%M%S}.txt"
+    path.write_text(text, encoding="utf-8")
+    (directory / PENDING_FILE).write_text(path.name, encoding="utf-8")
+    for old in sorted(directory.glob("crash-*.txt"))[:-MAX_REPORTS]:
+        old.unlink(missing_ok=True)
+    return path
+
+
+def take_pending_report(directory: Path) -> Path | None:
+    """Return the report of a crash not yet offered for recovery, once.
+
+    Returns:
+        Path | None: The report written by the last crash, None if the
+        browser has been started since or never crashed.
+
+    """
+    pending = directory / PENDING_FILE
+    try:
+        name = pending.read_text(encoding="utf-8").strip()
+        pending.unlink()
+    except OSError:
+        return None
+    path = directory / name
+    return path if name and path.is_file() else None
+
+
+class CrashHandler:
+    """Writes a report for every error nothing caught."""
+
+    def __init__(self, directory: Path):
+        """Prepare the handler.
+
+        Args:
+            directory: Crashes folder of the storage directory.
+
+        """
+        self.directory = Path(directory)
+        # Returns the open tabs for a report, set once the tabs exist
+        self.tabs: Callable[[], list[str] | None] = lambda: None
+        self._previous_hook = None
+        self._previous_thread_hook = None
+
+    def install(self) -> None:
+        """Report errors nothing caught on the main thread and other threads."""
+        if self._previous_hook is not None:
+            return
+        self._previous_hook = sys.excepthook
+        self._previous_thread_hook = threading.excepthook
+        sys.excepthook = self._on_exception
+        threading.excepthook = self._on_thread_exception
+
+    def uninstall(self) -> None:
+        """Put back the hooks that were there before install."""
+        if self._previous_hook is None:
+            return
+        sys.excepthook = self._previous_hook
+        threading.excepthook = self._previous_thread_hook
+        self._previous_hook = None
+        self._previous_thread_hook = None
+
+    def report(self, exc_type, exc, tb) -> Path | None:
+        """Write a report for an error, None if it could not be written.
+
+        Interrupting the browser with Ctrl+C is not a crash and is not
+        reported.
+        """
+        if issubclass(exc_type, KeyboardInterrupt):
+            return None
+        try:
+            tabs = self.tabs()
+        except Exception:  # noqa: BLE001
+            tabs = None
+        try:
+            return write_report(
+                self.directory,
+                format_report(exc_type, exc, tb, tabs),
+            )
+        except OSError:
+            logger.exception("Unable to write a crash report")
+            return None
+
+    def handle_loop_exception(self, loop, context: dict) -> None:
+        """Report an error nothing caught in the asyncio event loop."""
+        exc = context.get("exception")
+        if exc is not None:
+            self.report(type(exc), exc, exc.__traceback__)
+        loop.default_exception_handler(context)
+
+    def _on_exception(self, exc_type, exc, tb) -> None:
+        self.report(exc_type, exc, tb)
+        self._previous_hook(exc_type, exc, tb)
+
+    def _on_thread_exception(self, args) -> None:
+        if args.exc_type is not SystemExit:
+            self.report(args.exc_type, args.exc_value, args.exc_traceback)
+        self._previous_thread_hook(args)
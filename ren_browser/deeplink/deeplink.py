@@ -0,0 +1,216 @@
+"""Deep linking for Ren Browser.
+
+Lets other applications open ``ren://<hash>/<path>`` addresses. The first
+instance listens on a local socket whose port and token are written to the
+storage directory; later launches hand their address to it and exit, so
+links open as tabs in the browser that is already running.
+"""
+
+import contextlib
+import json
+import os
+import secrets
+import shutil
+import socket
+import subprocess
+import sys
+import threading
+from collections.abc import Callable
+from pathlib import Path
+
+SCHEME = "ren"
+HANDOFF_TIMEOUT = 2.0
+MAX_MESSAGE = 4096
+
+DESKTOP_ENTRY = """[Desktop Entry]
+Type=Application
+Name=Ren Browser
+Comment=A browser for the Reticulum Network
+Exec={command} %U
+Terminal=false
+Categories=Network;
+MimeType=x-scheme-handler/{scheme};
+"""
+
+
+def read_message(conn: socket.socket) -> dict | None:
+    """Read one newline terminated JSON object, None if it is not one."""
+    data = b""
+    while len(data) < MAX_MESSAGE:
+        chunk = conn.recv(MAX_MESSAGE)
+        if not chunk:
+            break
+        data += chunk
+        if data.endswith(b"\n"):
+            break
+    try:
+        message = json.loads(data.decode("utf-8"))
+    except (UnicodeDecodeError, json.JSONDecodeError):
+        return None
+    return message if isinstance(message, dict) else None
+
+
+class HandoffServer:
+    """Receives addresses from later launches of the browser."""
+
+    def __init__(self, path: Path, on_address: Callable[[str], None]):
+        """Prepare the server.
+
+        Args:
+            path: File the port and token are published in.
+            on_address: Called from the server thread with each address received.
+
+        """
+        self.path = Path(path)
+        self.on_address = on_address
+        self.token = secrets.token_hex(16)
+        self._sock: socket.socket | None = None
+        self._thread: threading.Thread | None = None
+
+    @property
+    def port(self) -> int | None:
+        """Return the port being listened on, or None before start."""
+        return self._sock.getsockname()[1] if self._sock else None
+
+    def start(self) -> bool:
+        """Start listening and publish where to reach this instance.
+
+        Returns:
+            bool: Whether the server is running.
+
+        """
+        try:
+            self._sock = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+            self._sock.bind(("127.0.0.1", 0))
+            self._sock.listen()
+            self.path.write_text(
+                json.dumps({"port": self.port, "token": self.token}),
+                encoding="utf-8",
+            )
+            with contextlib.suppress(OSError):
+                os.chmod(self.path, 0o600)
+        except OSError:
+            self.stop()
+            return False
+        self._thread = threading.Thread(target=self._serve, daemon=True)
+        self._thread.start()
+        return True
+
+    def stop(self) -> None:
+        """Stop listening and withdraw the published port."""
+        sock, self._sock = self._sock, None
+        if sock is not None:
+            with contextlib.suppress(OSError):
+                sock.close()
+        with contextlib.suppress(OSError, json.JSONDecodeError):
+            published = json.loads(self.path.read_text(encoding="utf-8"))
+            if published.get("token") == self.token:
+                self.path.unlink()
+
+    def _serve(self) -> None:
+        while self._sock is not None:
+            try:
+                conn, _ = self._sock.accept()
+            except OSError:
+                return
+            with conn:
+                conn.settimeout(HANDOFF_TIMEOUT)
+                try:
+                    message = read_message(conn)
+                    if (
+                        message is None
+                        or message.get("token") != self.token
+                        or not isinstance(message.get("address"), str)
+                    ):
+                        conn.sendall(b"denied\n")
+                        continue
+                    conn.sendall(b"ok\n")
+                except OSError:
+                    continue
+            self.on_address(message["address"])
+
+
+def hand_off(path: Path, address: str, timeout: float = HANDOFF_TIMEOUT) -> bool:
+    """Pass an address to an already running instance.
+
+    Args:
+        path: File the running instance published its port and token in.
+        address: Address to open.
+        timeout: Seconds to wait for the running instance.
+
+    Returns:
+        bool: Whether a running instance accepted the address.
+
+    """
+    try:
+        published = json.loads(Path(path).read_text(encoding="utf-8"))
+        port = int(published["port"])
+        token = published["token"]
+    except (OSError, ValueError, KeyError, TypeError):
+        return False
+    message = json.dumps({"token": token, "address": address}) + "\n"
+    try:
+        with socket.create_connection(("127.0.0.1", port), timeout=timeout) as conn:
+            conn.sendall(message.encode("utf-8"))
+            return conn.recv(16).strip() == b"ok"
+    except OSError:
+        return False
+
+
+def _launch_command() -> str:
+    command = shutil.which("ren-browser")
+    if command:
+        return command
+    return f"{sys.executable} -m ren_browser.app"
+
+
+def register_url_scheme() -> str:
+    """Register Ren Browser as the handler for ren:// links.
+
+    Linux desktops get a ``.desktop`` entry set as the scheme handler, and
+    Windows gets a per-user protocol key. macOS only picks up schemes
+    declared by an app bundle, so packaged builds handle it there.
+
+    Returns:
+        str: Message describing what was registered.
+
+    Raises:
+        OSError: If the handler could not be registered.
+
+    """
+    command = _launch_command()
+    if sys.platform == "win32":
+        import winreg
+
+        key_path = rf"Software\Classes\{SCHEME}"
+        with winreg.CreateKey(winreg.HKEY_CURRENT_USER, key_path) as key:
+            winreg.SetValueEx(key, "", 0, winreg.REG_SZ, "URL:Ren Browser")
+            winreg.SetValueEx(key, "URL Protocol", 0, winreg.REG_SZ, "")
+        with winreg.CreateKey(
+            winreg.HKEY_CURRENT_USER,
+            rf"{key_path}\shell\open\command",
+        ) as key:
+            winreg.SetValueEx(key, "", 0, winreg.REG_SZ, f'{command} "%1"')
+        return f"Registered {SCHEME}:// links for the current user."
+    if sys.platform == "darwin":
+        raise OSError(
+            f"{SCHEME}:// links are registered by the packaged macOS app bundle.",
+        )
+
+    data_home = Path(
+        os.environ.get("XDG_DATA_HOME") or Path.home() / ".local" / "share",
+    )
+    applications = data_home / "applications"
+    applications.mkdir(parents=True, exist_ok=True)
+    entry = applications / "ren-browser.desktop"
+    entry.write_text(
+        DESKTOP_ENTRY.format(command=command, scheme=SCHEME),
+        encoding="utf-8",
+    )
+    xdg_mime = shutil.which("xdg-mime")
+    if xdg_mime:
+        subprocess.run(
+            [xdg_mime, "default", entry.name, f"x-scheme-handler/{SCHEME}"],
+            check=False,
+        )
+    return f"Registered {SCHEME}:// links with {entry}."
@@ -0,0 +1,311 @@
+"""Download manager for Ren Browser.
+
+Downloads files served by nodes in the background, tracks their progress and
+keeps a history of finished transfers across sessions.
+"""
+
+import itertools
+import threading
+import time
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from pathlib import Path, PurePosixPath
+
+from ren_browser.pages.address import parse_address
+from ren_browser.pages.page_request import PageFetcher, PageFetchError, PageRequest
+
+ACTIVE = ("downloading",)
+FINISHED = ("completed", "failed", "cancelled")
+
+
+def is_file_address(page_path: str) -> bool:
+    """Return whether a page path points at a downloadable file."""
+    return page_path.startswith("/file/")
+
+
+def unique_path(directory: Path, name: str) -> Path:
+    """Return a path in directory for name that does not overwrite a file."""
+    path = directory / name
+    stem, suffix = path.stem, path.suffix
+    for n in itertools.count(1):
+        if not path.exists():
+            return path
+        path = directory / f"{stem} ({n}){suffix}"
+    return path
+
+
+@dataclass
+class Download:
+    """A file transfer.
+
+    Contains the address, file name, status, progress and where the file was
+    saved. Status is one of "downloading", "paused", "completed", "failed" or
+    "cancelled". Target is the file chosen to save to before the transfer
+    started, if any.
+    """
+
+    id: int
+    address: str
+    name: str
+    status: str = "downloading"
+    progress: float = 0.0
+    size: int | None = None
+    path: str | None = None
+    target: str | None = None
+    error: str | None = None
+    started: float = 0.0
+    finished: float | None = None
+    cancel: threading.Event = field(
+        default_factory=threading.Event,
+        repr=False,
+        compare=False,
+    )
+
+    def to_dict(self) -> dict:
+        """Return the download in its stored form."""
+        return {
+            name: getattr(self, name)
+            for name in self.__dataclass_fields__
+            if name != "cancel"
+        }
+
+
+class DownloadManager:
+    """Runs file downloads and keeps their history."""
+
+    def __init__(
+        self,
+        storage,
+        directory: Path,
+        run_thread: Callable[[Callable[[], None]], None],
+        fetch_file=PageFetcher.fetch_file,
+    ):
+        """Load the download history.
+
+        Args:
+            storage: StorageManager used to load and save the history.
+            directory: Default directory finished downloads are written to.
+            run_thread: Runs a callable in a background thread.
+            fetch_file: Function performing the transfer, see
+                PageFetcher.fetch_file.
+
+        """
+        self.storage = storage
+        self.default_directory = Path(directory)
+        self.directory = self.default_directory
+        self.run_thread = run_thread
+        self.fetch_file = fetch_file
+        self.listeners: list[Callable[[], None]] = []
+        self.completion_listeners: list[Callable[[Download], None]] = []
+        self.failure_listeners: list[Callable[[Download], None]] = []
+        self._downloads: list[Download] = []
+        self._lock = threading.Lock()
+        try:
+            stored = storage.load_downloads()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            download = self._from_dict(data)
+            if download is not None:
+                self._downloads.append(download)
+        self._next_id = max((d.id for d in self._downloads), default=0) + 1
+
+    def _from_dict(self, data) -> Download | None:
+        if not isinstance(data, dict) or not data.get("address"):
+            return None
+        fields = {k: data[k] for k in Download.__dataclass_fields__ if k in data}
+        fields.pop("cancel", None)
+        try:
+            download = Download(**{"name": data["address"], "id": 0, **fields})
+        except TypeError:
+            return None
+        if download.status not in FINISHED:
+            # The transfer did not survive the previous session
+            download.status = "failed"
+            download.error = "Interrupted"
+        return download
+
+    def configure(self, directory: str) -> None:
+        """Write finished downloads to a directory, or the default if empty."""
+        if directory:
+            self.directory = Path(directory).expanduser()
+        else:
+            self.directory = self.default_directory
+
+    def add_listener(self, listener: Callable[[], None]) -> None:
+        """Register a callable run whenever a download changes."""
+        self.listeners.append(listener)
+
+    def add_completion_listener(self, listener: Callable[[Download], None]) -> None:
+        """Register a callable run with each download once it completes."""
+        self.completion_listeners.append(listener)
+
+    def add_failure_listener(self, listener: Callable[[Download], None]) -> None:
+        """Register a callable run with each download that fails."""
+        self.failure_listeners.append(listener)
+
+    def downloads(self) -> list[Download]:
+        """Return all downloads, most recent first."""
+        with self._lock:
+            return sorted(self._downloads, key=lambda d: d.started, reverse=True)
+
+    def get(self, download_id: int) -> Download | None:
+        """Return the download with an id, if any."""
+        with self._lock:
+            for download in self._downloads:
+                if download.id == download_id:
+                    return download
+        return None
+
+    def start(self, address: str, target: Path | None = None) -> Download:
+        """Start downloading the file at an address.
+
+        Args:
+            address: Address of the file.
+            target: File to save to, replacing it if it exists. By default
+                the file is saved under its own name in the directory.
+
+        Raises:
+            AddressError: If the address cannot be parsed.
+
+        """
+        parsed = parse_address(address)
+        name = PurePosixPath(parsed.page_path).name or "download"
+        with self._lock:
+            download = Download(
+                id=self._next_id,
+                address=str(parsed),
+                name=Path(target).name if target else name,
+                target=str(target) if target else None,
+                started=time.time(),
+            )
+            self._next_id += 1
+            self._downloads.append(download)
+        self._run(download)
+        return download
+
+    def pause(self, download_id: int) -> None:
+        """Stop an active download so it can be resumed later.
+
+        Reticulum cannot continue a partial transfer, so resuming downloads the
+        file again from the start.
+        """
+        download = self.get(download_id)
+        if download is not None and download.status in ACTIVE:
+            download.status = "paused"
+            download.cancel.set()
+            self._changed()
+
+    def resume(self, download_id: int) -> None:
+        """Restart a paused, failed or cancelled download."""
+        download = self.get(download_id)
+        if download is None or download.status in (*ACTIVE, "completed"):
+            return
+        download.status = "downloading"
+        download.progress = 0.0
+        download.error = None
+        download.finished = None
+        download.cancel = threading.Event()
+        self._run(download)
+
+    def cancel(self, download_id: int) -> None:
+        """Abort a download."""
+        download = self.get(download_id)
+        if download is not None and download.status in (*ACTIVE, "paused"):
+            download.status = "cancelled"
+            download.finished = time.time()
+            download.cancel.set()
+            self._changed()
+
+    def remove(self, download_id: int) -> None:
+        """Remove a finished download from the history, keeping the file."""
+        with self._lock:
+            self._downloads = [
+                d
+                for d in self._downloads
+                if d.id != download_id or d.status in (*ACTIVE, "paused")
+            ]
+        self._changed()
+
+    def clear_finished(self, since: float | None = None) -> None:
+        """Remove finished downloads from the history, keeping the files.
+
+        Args:
+            since: Only remove downloads started at or after this time.
+
+        """
+        with self._lock:
+            self._downloads = [
+                d
+                for d in self._downloads
+                if d.status not in FINISHED or (since is not None and d.started < since)
+            ]
+        self._changed()
+
+    def _run(self, download: Download) -> None:
+        self._changed()
+        cancel = download.cancel
+        parsed = parse_address(download.address)
+
+        def on_progress(phase, fraction):
+            if phase == "transfer" and fraction is not None:
+                download.progress = min(max(fraction, 0.0), 1.0)
+                self._notify()
+
+        def worker():
+            req = PageRequest(parsed.destination_hash, parsed.page_path)
+            try:
+                data, name = self.fetch_file(
+                    req,
+                    on_progress=on_progress,
+                    cancel=cancel,
+                )
+                if cancel.is_set():
+                    return
+                if download.target:
+                    path = Path(download.target)
+                else:
+                    name = Path(name or download.name).name
+                    path = unique_path(self.directory, name)
+                path.parent.mkdir(parents=True, exist_ok=True)
+                path.write_bytes(data)
+            except PageFetchError as exc:
+                if exc.kind != "cancelled" and not cancel.is_set():
+                    self._fail(download, str(exc))
+                return
+            except OSError as exc:
+                self._fail(download, str(exc))
+                return
+            download.name = path.name
+            download.path = str(path)
+            download.size = len(data)
+            download.progress = 1.0
+            download.status = "completed"
+            download.finished = time.time()
+            self._changed()
+            for listener in list(self.completion_listeners):
+                listener(download)
+
+        self.run_thread(worker)
+
+    def _fail(self, download: Download, error: str) -> None:
+        download.status = "failed"
+        download.error = error
+        download.finished = time.time()
+        self._changed()
+        for listener in list(self.failure_listeners):
+            listener(download)
+
+    def _changed(self) -> None:
+        self._save()
+        self._notify()
+
+    def _notify(self) -> None:
+        for listener in list(self.listeners):
+            listener()
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_downloads([d.to_dict() for d in self.downloads()])
+        except Exception:  # noqa: BLE001
+            pass
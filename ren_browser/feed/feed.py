@@ -0,0 +1,285 @@
+"""Followed-nodes feed for Ren Browser.
+
+Every so often the index page of each followed node is fetched together
+with the pages it links to on the same node. Pages that appeared since the
+last check, or whose content changed, are added to a chronological feed.
+"""
+
+import hashlib
+import threading
+import time
+from collections.abc import Callable
+from dataclasses import asdict, dataclass, field
+
+from ren_browser.pages.address import resolve_link
+from ren_browser.renderer.micron import micron_links
+from ren_browser.renderer.title import extract_title
+
+FEED_INTERVAL = 1800
+MAX_PAGES_PER_NODE = 10
+MAX_ITEMS = 200
+INDEX_PATH = "/page/index.mu"
+
+
+def _hash(content: str) -> str:
+    return hashlib.sha256(content.encode("utf-8")).hexdigest()
+
+
+def node_pages(destination_hash: str, index: str, limit: int) -> list[tuple[str, str]]:
+    """Return the pages of a node linked from its index page.
+
+    Args:
+        destination_hash: Hash of the node the index belongs to.
+        index: Micron source of the index page.
+        limit: Most pages to return.
+
+    Returns:
+        ``(address, label)`` pairs in the order they are linked, without
+        duplicates, links to other nodes or links to files.
+
+    """
+    prefix = f"{destination_hash}:/page/"
+    index_address = f"{destination_hash}:{INDEX_PATH}"
+    pages: list[tuple[str, str]] = []
+    seen = {index_address}
+    for label, target in micron_links(index):
+        address = resolve_link(target, destination_hash)
+        if not address.startswith(prefix) or address in seen:
+            continue
+        seen.add(address)
+        pages.append((address, label.strip() or address))
+        if len(pages) == limit:
+            break
+    return pages
+
+
+@dataclass
+class FeedItem:
+    """A new or changed page found on a followed node."""
+
+    address: str
+    title: str
+    node_hash: str
+    kind: str
+    found_at: float
+
+    @classmethod
+    def from_dict(cls, data) -> "FeedItem | None":
+        """Build an item from stored data, or None if it is malformed."""
+        if not isinstance(data, dict) or not data.get("address"):
+            return None
+        try:
+            found_at = float(data.get("found_at") or 0)
+        except (TypeError, ValueError):
+            found_at = 0.0
+        return cls(
+            address=data["address"],
+            title=data.get("title") or data["address"],
+            node_hash=data.get("node_hash") or data["address"].split(":", 1)[0],
+            kind="new" if data.get("kind") == "new" else "changed",
+            found_at=found_at,
+        )
+
+
+@dataclass
+class FollowedNode:
+    """A node whose pages are followed.
+
+    Contains the hash, display name, a content hash per known page and when
+    the node was last checked.
+    """
+
+    destination_hash: str
+    name: str
+    pages: dict[str, str] = field(default_factory=dict)
+    checked_at: float = 0.0
+    error: str | None = None
+
+    @classmethod
+    def from_dict(cls, data) -> "FollowedNode | None":
+        """Build a followed node from stored data, or None if it is malformed."""
+        if not isinstance(data, dict) or not data.get("destination_hash"):
+            return None
+        pages = data.get("pages")
+        try:
+            checked_at = float(data.get("checked_at") or 0)
+        except (TypeError, ValueError):
+            checked_at = 0.0
+        return cls(
+            destination_hash=data["destination_hash"],
+            name=data.get("name") or data["destination_hash"],
+            pages=dict(pages) if isinstance(pages, dict) else {},
+            checked_at=checked_at,
+        )
+
+
+class NodeFeed:
+    """Follows nodes and collects their new and changed pages."""
+
+    def __init__(
+        self,
+        storage,
+        fetch: Callable[[str], str],
+        interval: float = FEED_INTERVAL,
+    ):
+        """Load the followed nodes and feed.
+
+        Args:
+            storage: StorageManager used to load and save the feed.
+            fetch: Called with an address to download its current content.
+            interval: Seconds between background checks.
+
+        """
+        self.storage = storage
+        self.fetch = fetch
+        self.interval = interval
+        self._nodes: list[FollowedNode] = []
+        self._items: list[FeedItem] = []
+        self.read_at = 0.0
+        self._lock = threading.Lock()
+        try:
+            stored = storage.load_feed()
+        except Exception:  # noqa: BLE001
+            stored = {}
+        if not isinstance(stored, dict):
+            stored = {}
+        for data in stored.get("nodes") or []:
+            node = FollowedNode.from_dict(data)
+            if node is not None and self.get(node.destination_hash) is None:
+                self._nodes.append(node)
+        for data in stored.get("items") or []:
+            item = FeedItem.from_dict(data)
+            if item is not None:
+                self._items.append(item)
+        try:
+            self.read_at = float(stored.get("read_at") or 0)
+        except (TypeError, ValueError):
+            self.read_at = 0.0
+
+    def get(self, destination_hash: str) -> FollowedNode | None:
+        """Return the followed node with a hash, if any."""
+        return next(
+            (n for n in self._nodes if n.destination_hash == destination_hash),
+            None,
+        )
+
+    def nodes(self) -> list[FollowedNode]:
+        """Return the followed nodes by name."""
+        return sorted(self._nodes, key=lambda n: n.name.lower())
+
+    def items(self) -> list[FeedItem]:
+        """Return the feed, newest first."""
+        return sorted(self._items, key=lambda item: -item.found_at)
+
+    def unread_count(self) -> int:
+        """Return how many items were found since the feed was last read."""
+        return sum(1 for item in self._items if item.found_at > self.read_at)
+
+    def mark_read(self, now: float | None = None) -> None:
+        """Record that the feed has been read."""
+        self.read_at = now if now is not None else time.time()
+        self._save()
+
+    def follow(self, destination_hash: str, name: str | None = None) -> FollowedNode:
+        """Start following a node."""
+        node = self.get(destination_hash)
+        if node is None:
+            node = FollowedNode(destination_hash, name or destination_hash)
+            self._nodes.append(node)
+        elif name:
+            node.name = name
+        self._save()
+        return node
+
+    def unfollow(self, destination_hash: str) -> None:
+        """Stop following a node and drop its items from the feed."""
+        node = self.get(destination_hash)
+        if node is None:
+            return
+        self._nodes.remove(node)
+        self._items = [i for i in self._items if i.node_hash != destination_hash]
+        self._save()
+
+    def check(self, destination_hash: str, now: float | None = None) -> list[FeedItem]:
+        """Fetch a followed node's pages and add new or changed ones to the feed.
+
+        The first check of a node only records what is there, so following
+        a node does not flood the feed with its existing pages.
+
+        Args:
+            destination_hash: Hash of the followed node.
+            now: Current time, for tests.
+
+        Returns:
+            The items added to the feed.
+
+        """
+        node = self.get(destination_hash)
+        if node is None:
+            return []
+        index_address = f"{destination_hash}:{INDEX_PATH}"
+        try:
+            index = self.fetch(index_address)
+        except Exception as exc:  # noqa: BLE001
+            node.error = str(exc)
+            return []
+        node.error = None
+        now = now if now is not None else time.time()
+        first_check = not node.pages
+        index_title = extract_title(index, INDEX_PATH) or node.name
+        contents = {index_address: (index_title, index)}
+        for address, label in node_pages(destination_hash, index, MAX_PAGES_PER_NODE):
+            try:
+                content = self.fetch(address)
+            except Exception:  # noqa: BLE001
+                continue
+            path = address.split(":", 1)[1]
+            contents[address] = (extract_title(content, path) or label, content)
+
+        found: list[FeedItem] = []
+        for address, (title, content) in contents.items():
+            digest = _hash(content)
+            known = node.pages.get(address)
+            node.pages[address] = digest
+            if first_check or known == digest:
+                continue
+            kind = "new" if known is None else "changed"
+            found.append(FeedItem(address, title, destination_hash, kind, now))
+        node.checked_at = now
+        self._items = [
+            item
+            for item in self._items
+            if item.address not in {f.address for f in found}
+        ]
+        self._items.extend(found)
+        self._items = self.items()[:MAX_ITEMS]
+        self._save()
+        return found
+
+    def check_all(self, now: float | None = None) -> list[FeedItem]:
+        """Check every followed node, one at a time.
+
+        Returns:
+            The items added to the feed.
+
+        """
+        with self._lock:
+            found: list[FeedItem] = []
+            for node in list(self._nodes):
+                found.extend(self.check(node.destination_hash, now))
+            return found
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_feed(
+                {
+                    "nodes": [
+                        {k: v for k, v in asdict(n).items() if k != "error"}
+                        for n in self._nodes
+                    ],
+                    "items": [asdict(item) for item in self._items],
+                    "read_at": self.read_at,
+                },
+            )
+        except Exception:  # noqa: BLE001
+            pass
@@ -0,0 +1,86 @@
+"""Node file areas for Ren Browser.
+
+NomadNet nodes serve the files they share under ``/file/``, beside their
+pages, but offer no request that lists them. The file area of a node is
+therefore gathered from the ``/file/`` links on the node's pages the
+browser has fetched. Sizes are known for files downloaded before.
+"""
+
+from dataclasses import dataclass
+from pathlib import PurePosixPath
+
+from ren_browser.downloads.downloads import is_file_address
+from ren_browser.pages.address import AddressError, parse_address, resolve_link
+from ren_browser.renderer.markdown import parse_markdown
+from ren_browser.renderer.micron import micron_links
+
+
+@dataclass(frozen=True)
+class NodeFile:
+    """A file shared by a node.
+
+    Label is the text of the first link to it, which often describes the
+    file better than its name. Size is None until it has been downloaded.
+    """
+
+    address: str
+    name: str
+    label: str = ""
+    size: int | None = None
+
+
+def page_links(content: str, page_path: str) -> list[tuple[str, str]]:
+    """Return the ``(label, target)`` of every link on a page."""
+    if page_path.endswith(".md"):
+        return [
+            part
+            for block in parse_markdown(content, page_path)
+            for part in block.parts
+            if isinstance(part, tuple)
+        ]
+    return micron_links(content)
+
+
+def node_files(destination_hash: str, pages, downloads=()) -> list[NodeFile]:
+    """Return the files a node links to from its pages, sorted by name.
+
+    Args:
+        destination_hash: Node whose files are listed.
+        pages: ``(address, content)`` of fetched pages, from any node.
+        downloads: Downloads, whose completed ones give the file sizes.
+
+    Returns:
+        list[NodeFile]: Each file once, with the label of its first link.
+
+    """
+    sizes = {
+        download.address: download.size
+        for download in downloads
+        if download.status == "completed" and download.size is not None
+    }
+    files: dict[str, NodeFile] = {}
+    for url, content in pages:
+        try:
+            page = parse_address(url)
+        except AddressError:
+            continue
+        if page.destination_hash != destination_hash:
+            continue
+        for label, target in page_links(content, page.page_path):
+            try:
+                address = parse_address(resolve_link(target, destination_hash))
+            except AddressError:
+                continue
+            if address.destination_hash != destination_hash or not is_file_address(
+                address.page_path,
+            ):
+                continue
+            key = str(address)
+            if key not in files:
+                files[key] = NodeFile(
+                    key,
+                    PurePosixPath(address.page_path).name,
+                    label.strip(),
+                    sizes.get(key),
+                )
+    return sorted(files.values(), key=lambda file: file.name.lower())
@@ -0,0 +1,235 @@
+"""Gemini protocol client for Ren Browser.
+
+Fetches ``gemini://`` pages over TLS so smolweb content can be read beside
+pages from nodes. Gemini servers mostly use self-signed certificates, so
+certificates are trusted on first use: the fingerprint seen on the first
+visit to a host is remembered, and a different one later is refused until
+the user trusts it.
+"""
+
+import hashlib
+import socket
+import ssl
+import urllib.parse
+from collections.abc import Callable
+from dataclasses import dataclass
+
+from ren_browser.pages.charset import decode_text
+
+GEMINI_SCHEME = "gemini://"
+DEFAULT_PORT = 1965
+TIMEOUT = 15
+MAX_REDIRECTS = 5
+MAX_RESPONSE = 8 * 1024 * 1024
+
+# urljoin only resolves relative links for schemes it knows
+for _schemes in (urllib.parse.uses_relative, urllib.parse.uses_netloc):
+    if "gemini" not in _schemes:
+        _schemes.append("gemini")
+
+
+class GeminiError(Exception):
+    """Raised when a Gemini page cannot be fetched.
+
+    The kind attribute names the failure for the error page: "gemini",
+    "timeout" or "certificate". The message is meant to be shown as-is.
+    """
+
+    def __init__(self, message: str, kind: str = "gemini"):
+        """Initialize the error with a message and its kind."""
+        super().__init__(message)
+        self.kind = kind
+
+
+class GeminiInput(Exception):
+    """Raised when a page asks for input before it can be shown."""
+
+    def __init__(self, url: str, prompt: str, sensitive: bool = False):
+        """Initialize with the address asking and what it asks for."""
+        super().__init__(prompt)
+        self.url = url
+        self.prompt = prompt
+        self.sensitive = sensitive
+
+
+@dataclass
+class GeminiResponse:
+    """A page served by a Gemini server, after any redirects."""
+
+    url: str
+    mime: str
+    body: str
+
+
+def is_gemini_address(text: str) -> bool:
+    """Return whether text is a ``gemini://`` address."""
+    return text.strip().lower().startswith(GEMINI_SCHEME)
+
+
+def join_url(base: str, link: str) -> str:
+    """Resolve a link on a Gemini page against the page's address."""
+    return urllib.parse.urljoin(base, link.strip()) if base else link.strip()
+
+
+def host_key(url: str) -> str:
+    """Return the ``host:port`` a certificate is remembered for."""
+    parsed = urllib.parse.urlsplit(url)
+    return f"{parsed.hostname or ''}:{parsed.port or DEFAULT_PORT}"
+
+
+def parse_header(header: bytes) -> tuple[int, str]:
+    """Split a response header into its status and meta.
+
+    Raises:
+        GeminiError: If the header is not a valid Gemini header.
+
+    """
+    try:
+        text = header.decode("utf-8").rstrip("\r\n")
+        status, _, meta = text.partition(" ")
+        if len(status) != 2:
+            raise ValueError(status)
+        return int(status), meta.strip()
+    except ValueError as exc:
+        raise GeminiError("The server sent an invalid response.") from exc
+
+
+def _charset(mime: str) -> str | None:
+    for param in mime.split(";")[1:]:
+        name, _, value = param.strip().partition("=")
+        if name.lower() == "charset" and value:
+            return value.strip('"')
+    return None
+
+
+class GeminiClient:
+    """Fetches pages from Gemini servers."""
+
+    def __init__(
+        self,
+        known_hosts: dict[str, str] | None = None,
+        on_trust: Callable[[dict[str, str]], None] | None = None,
+        timeout: float = TIMEOUT,
+    ):
+        """Initialize the client.
+
+        Args:
+            known_hosts: Certificate fingerprints by ``host:port``.
+            on_trust: Called with the fingerprints whenever one is added.
+            timeout: Seconds to wait for a server.
+
+        """
+        self.known_hosts = dict(known_hosts or {})
+        self.on_trust = on_trust
+        self.timeout = timeout
+
+    def trust(self, key: str, fingerprint: str) -> None:
+        """Remember the certificate fingerprint of a host."""
+        self.known_hosts[key] = fingerprint
+        if self.on_trust:
+            self.on_trust(dict(self.known_hosts))
+
+    def forget(self, key: str) -> None:
+        """Trust whatever certificate the host shows on the next visit."""
+        if self.known_hosts.pop(key, None) is not None and self.on_trust:
+            self.on_trust(dict(self.known_hosts))
+
+    def fetch(self, url: str, encoding: str | None = None) -> GeminiResponse:
+        """Fetch a page, following redirects.
+
+        Text is decoded in the charset the server names, or the one it is
+        detected to be in when it names none.
+
+        Args:
+            url: Address of the page.
+            encoding: Encoding text is decoded in instead, or None.
+
+        Raises:
+            GeminiError: If the page cannot be fetched.
+            GeminiInput: If the page asks for input first.
+
+        """
+        for _ in range(MAX_REDIRECTS + 1):
+            status, meta, body = self.request(url)
+            if 10 <= status < 20:
+                raise GeminiInput(url, meta or "Input", sensitive=status == 11)
+            if 20 <= status < 30:
+                mime = meta or "text/gemini; charset=utf-8"
+                text = decode_text(body, encoding or _charset(mime))
+                return GeminiResponse(url, mime, text)
+            if 30 <= status < 40:
+                target = join_url(url, meta)
+                if not is_gemini_address(target):
+                    raise GeminiError(f"The page moved to {target}.")
+                url = target
+                continue
+            if 60 <= status < 70:
+                raise GeminiError(
+                    f"The server asks for a client certificate ({status} {meta}).",
+                )
+            raise GeminiError(f"The server answered {status} {meta}".strip())
+        raise GeminiError("The page redirected too many times.")
+
+    def request(self, url: str) -> tuple[int, str, bytes]:
+        """Send one request and return the status, meta and body.
+
+        Raises:
+            GeminiError: If the server cannot be reached or its
+                certificate does not match the remembered one.
+
+        """
+        parsed = urllib.parse.urlsplit(url)
+        if not parsed.hostname:
+            raise GeminiError(f"{url} has no host.")
+        context = ssl.SSLContext(ssl.PROTOCOL_TLS_CLIENT)
+        context.minimum_version = ssl.TLSVersion.TLSv1_2
+        # Certificates are checked against the remembered fingerprint
+        context.check_hostname = False
+        context.verify_mode = ssl.CERT_NONE
+        try:
+            with (
+                socket.create_connection(
+                    (parsed.hostname, parsed.port or DEFAULT_PORT),
+                    timeout=self.timeout,
+                ) as raw,
+                context.wrap_socket(raw, server_hostname=parsed.hostname) as tls,
+            ):
+                self._check_certificate(url, tls.getpeercert(binary_form=True))
+                tls.sendall(f"{url}\r\n".encode())
+                data = self._read(tls)
+        except TimeoutError as exc:
+            raise GeminiError(
+                f"{parsed.hostname} did not answer in time.",
+                "timeout",
+            ) from exc
+        except (OSError, ssl.SSLError) as exc:
+            raise GeminiError(f"Cannot reach {parsed.hostname}: {exc}") from exc
+        header, _, body = data.partition(b"\n")
+        status, meta = parse_header(header)
+        return status, meta, body
+
+    def _check_certificate(self, url: str, certificate: bytes | None) -> None:
+        fingerprint = hashlib.sha256(certificate or b"").hexdigest()
+        key = host_key(url)
+        known = self.known_hosts.get(key)
+        if known is None:
+            self.trust(key, fingerprint)
+        elif known != fingerprint:
+            raise GeminiError(
+                f"The certificate of {key} changed since it was first seen. "
+                "This can be a renewed certificate or someone in between.",
+                "certificate",
+            )
+
+    def _read(self, tls) -> bytes:
+        chunks = []
+        size = 0
+        while True:
+            chunk = tls.recv(65536)
+            if not chunk:
+                break
+            chunks.append(chunk)
+            size += len(chunk)
+            if size > MAX_RESPONSE:
+                raise GeminiError("The page is too large.")
+        return b"".join(chunks)
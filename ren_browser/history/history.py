@@ -0,0 +1,243 @@
+"""Browsing history for Ren Browser.
+
+Records every page the user successfully opens and keeps the list in the
+browser's storage directory so it survives restarts.
+"""
+
+import time
+from collections.abc import Callable
+from dataclasses import asdict, dataclass
+from datetime import date, datetime, timedelta
+
+from ren_browser.i18n.i18n import ntr, tr
+
+MAX_ENTRIES = 1000
+
+
+@dataclass
+class HistoryEntry:
+    """A single visited page.
+
+    Contains the address, page title, visit time and the node's hash.
+    """
+
+    address: str
+    title: str
+    timestamp: float
+    node_hash: str | None = None
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "HistoryEntry | None":
+        """Build an entry from stored data, or None if it is malformed."""
+        if not isinstance(data, dict) or not data.get("address"):
+            return None
+        try:
+            timestamp = float(data.get("timestamp", 0))
+        except (TypeError, ValueError):
+            timestamp = 0.0
+        return cls(
+            address=data["address"],
+            title=data.get("title") or data["address"],
+            timestamp=timestamp,
+            node_hash=data.get("node_hash"),
+        )
+
+
+@dataclass
+class NodeStats:
+    """How often and how recently the pages of one node were visited."""
+
+    visits: int = 0
+    last_visit: float = 0.0
+
+
+class HistoryManager:
+    """Keeps the browsing history and persists it through the storage manager."""
+
+    def __init__(self, storage, max_entries: int = MAX_ENTRIES):
+        """Load the stored history.
+
+        Args:
+            storage: StorageManager used to load and save the history list.
+            max_entries: Number of most recent visits to keep.
+
+        """
+        self.storage = storage
+        self.max_entries = max_entries
+        self._entries: list[HistoryEntry] = []
+        try:
+            stored = storage.load_history()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            entry = HistoryEntry.from_dict(data)
+            if entry is not None:
+                self._entries.append(entry)
+
+    def record(
+        self,
+        address: str,
+        title: str,
+        node_hash: str | None = None,
+        timestamp: float | None = None,
+    ) -> HistoryEntry:
+        """Record a visit to a page and save the history.
+
+        Args:
+            address: Normalized address of the page.
+            title: Title shown for the page.
+            node_hash: Destination hash of the node serving the page.
+            timestamp: Visit time, defaulting to now.
+
+        Returns:
+            HistoryEntry: The recorded entry.
+
+        """
+        entry = HistoryEntry(
+            address=address,
+            title=title or address,
+            timestamp=timestamp if timestamp is not None else time.time(),
+            node_hash=node_hash,
+        )
+        self._entries.append(entry)
+        if len(self._entries) > self.max_entries:
+            self._entries = self._entries[-self.max_entries :]
+        self._save()
+        return entry
+
+    def entries(self) -> list[HistoryEntry]:
+        """Return all entries, most recent first."""
+        return sorted(self._entries, key=lambda entry: entry.timestamp, reverse=True)
+
+    def search(
+        self,
+        query: str = "",
+        start: date | None = None,
+        end: date | None = None,
+        node_name: Callable[[str], str | None] | None = None,
+    ) -> list[HistoryEntry]:
+        """Find visits matching a query and date range.
+
+        Every word of the query must appear in the title, address or node name
+        of an entry, ignoring case.
+
+        Args:
+            query: Words to look for.
+            start: First local day to include.
+            end: Last local day to include.
+            node_name: Optional lookup from destination hash to node name.
+
+        Returns:
+            Matching entries, most recent first.
+
+        """
+        words = query.lower().split()
+        results = []
+        for entry in self.entries():
+            day = datetime.fromtimestamp(entry.timestamp).date()
+            if (start and day < start) or (end and day > end):
+                continue
+            name = node_name(entry.node_hash) if node_name and entry.node_hash else None
+            haystack = " ".join(
+                part for part in (entry.title, entry.address, name) if part
+            ).lower()
+            if all(word in haystack for word in words):
+                results.append(entry)
+        return results
+
+    def node_stats(self) -> dict[str, NodeStats]:
+        """Return visit statistics keyed by destination hash."""
+        stats: dict[str, NodeStats] = {}
+        for entry in self._entries:
+            if not entry.node_hash:
+                continue
+            node = stats.setdefault(entry.node_hash, NodeStats())
+            node.visits += 1
+            node.last_visit = max(node.last_visit, entry.timestamp)
+        return stats
+
+    def as_dicts(self) -> list[dict]:
+        """Return the entries, most recent first, in their stored form."""
+        return [asdict(entry) for entry in self.entries()]
+
+    def clear(self, since: float | None = None) -> None:
+        """Forget history.
+
+        Args:
+            since: Only forget visits at or after this time.
+
+        """
+        if since is None:
+            self._entries = []
+        else:
+            self._entries = [e for e in self._entries if e.timestamp < since]
+        self._save()
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_history([asdict(entry) for entry in self._entries])
+        except Exception:  # noqa: BLE001
+            pass
+
+
+def group_by_day(entries: list[HistoryEntry]) -> list[tuple[date, list[HistoryEntry]]]:
+    """Group entries by the local calendar day they were visited.
+
+    Args:
+        entries: Entries ordered most recent first.
+
+    Returns:
+        List of (day, entries) pairs, most recent day first.
+
+    """
+    groups: list[tuple[date, list[HistoryEntry]]] = []
+    for entry in entries:
+        day = datetime.fromtimestamp(entry.timestamp).date()
+        if groups and groups[-1][0] == day:
+            groups[-1][1].append(entry)
+        else:
+            groups.append((day, [entry]))
+    return groups
+
+
+def parse_day(text: str) -> date | None:
+    """Parse a ``YYYY-MM-DD`` day typed into the history filter.
+
+    Returns:
+        The day, or None for empty text.
+
+    Raises:
+        ValueError: If the text is not a valid day.
+
+    """
+    text = text.strip()
+    if not text:
+        return None
+    return date.fromisoformat(text)
+
+
+def visit_summary(stats: NodeStats | None, now: float | None = None) -> str | None:
+    """Describe a node's visit statistics, or None if it was never visited."""
+    if stats is None or not stats.visits:
+        return None
+    visits = ntr("1 visit", "{count} visits", stats.visits)
+    ago = max((now if now is not None else time.time()) - stats.last_visit, 0)
+    if ago < 60:
+        last = tr("just now")
+    elif ago < 3600:
+        last = ntr("1 min ago", "{count} min ago", int(ago // 60))
+    elif ago < 86400:
+        last = ntr("1 h ago", "{count} h ago", int(ago // 3600))
+    else:
+        last = ntr("1 d ago", "{count} d ago", int(ago // 86400))
+    return tr("{visits}, last {last}", visits=visits, last=last)
+
+
+def day_label(day: date, today: date | None = None) -> str:
+    """Return a heading for a day of history."""
+    today = today or date.today()
+    if day == today:
+        return tr("Today")
+    if day == today - timedelta(days=1):
+        return tr("Yesterday")
+    return day.strftime("%A, %d %B %Y")
@@ -0,0 +1,103 @@
+"""Translations for Ren Browser.
+
+Interface strings are written in English in the code and passed through
+``tr``, which looks them up in the catalog of the chosen language. Each
+language is a TOML file in the ``locales`` folder mapping the English text
+to its translation, so a missing translation shows the English text.
+Strings that depend on a count are tables with ``one`` and ``other`` forms,
+looked up with ``ntr``.
+"""
+
+import locale
+import os
+import tomllib
+from pathlib import Path
+
+LOCALES_DIR = Path(__file__).parent / "locales"
+DEFAULT_LANGUAGE = "en"
+SYSTEM_LANGUAGE = ""
+
+_catalog: dict = {}
+_language = DEFAULT_LANGUAGE
+
+
+def available_languages() -> dict[str, str]:
+    """Return the languages there are translations for, by code.
+
+    English, the language of the code, is always included. The names are
+    each language's own name for itself.
+    """
+    languages = {DEFAULT_LANGUAGE: "English"}
+    for file in sorted(LOCALES_DIR.glob("*.toml")):
+        catalog = load_catalog(file.stem)
+        name = catalog.get("meta", {}).get("name")
+        languages[file.stem] = name if isinstance(name, str) else file.stem
+    return languages
+
+
+def load_catalog(language: str) -> dict:
+    """Read the catalog of a language, empty if it has none or it is invalid."""
+    try:
+        with (LOCALES_DIR / f"{language}.toml").open("rb") as file:
+            return tomllib.load(file)
+    except (OSError, UnicodeDecodeError, tomllib.TOMLDecodeError):
+        return {}
+
+
+def system_language() -> str:
+    """Return the code of the system's language, such as ``de``."""
+    name = os.environ.get("LANGUAGE") or os.environ.get("LC_ALL")
+    name = name or os.environ.get("LC_MESSAGES") or os.environ.get("LANG")
+    if not name:
+        try:
+            name = locale.getlocale()[0]
+        except ValueError:
+            name = None
+    return (name or DEFAULT_LANGUAGE).split(":")[0].split(".")[0].split("_")[0]
+
+
+def set_language(language: str) -> str:
+    """Translate strings into a language from now on.
+
+    Args:
+        language: Language code, or SYSTEM_LANGUAGE for the system's
+            language. Languages without translations fall back to English.
+
+    Returns:
+        The code of the language used.
+
+    """
+    global _catalog, _language
+    language = language or system_language()
+    if language not in available_languages():
+        language = DEFAULT_LANGUAGE
+    _language = language
+    _catalog = {} if language == DEFAULT_LANGUAGE else load_catalog(language)
+    return language
+
+
+def current_language() -> str:
+    """Return the code of the language strings are translated into."""
+    return _language
+
+
+def tr(message: str, **values) -> str:
+    """Translate a string, then fill in its ``{placeholders}``."""
+    translated = _catalog.get(message)
+    text = translated if isinstance(translated, str) else message
+    return text.format(**values) if values else text
+
+
+def ntr(singular: str, plural: str, count: int, **values) -> str:
+    """Translate a string that depends on a count.
+
+    The count is available to the string as ``{count}``.
+    """
+    forms = _catalog.get(singular)
+    if isinstance(forms, dict):
+        text = forms.get("one" if count == 1 else "other")
+    else:
+        text = None
+    if not isinstance(text, str):
+        text = singular if count == 1 else plural
+    return text.format(count=count, **values)
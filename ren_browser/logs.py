@@ -1,10 +1,18 @@
 """Logging system for Ren Browser.
 
 Provides centralized logging for application events, errors, and
-Reticulum network activities.
+Reticulum network activities. The most recent messages are also kept with
+their level and source for the ``about:logs`` page, and can be written to a
+log file that is rotated once it grows too large, so a crash on a phone or
+a headless box leaves something to look into.
 """
 
 import datetime
+import logging
+import logging.handlers
+from collections import deque
+from dataclasses import dataclass
+from pathlib import Path
 
 import RNS
 
@@ -13,6 +21,164 @@ ERROR_LOGS: list[str] = []
 RET_LOGS: list[str] = []
 _original_rns_log = RNS.log
 
+MAX_RECENT = 1000
+LEVELS = {
+    "error": "Errors",
+    "warning": "Warnings",
+    "info": "Info",
+    "debug": "Debug",
+}
+
+
+@dataclass(frozen=True)
+class LogEntry:
+    """A logged message with its level and whether the app or Reticulum sent it."""
+
+    timestamp: str
+    level: str
+    source: str
+    message: str
+
+    def format(self) -> str:
+        """Return the entry as one line of text."""
+        return f"[{self.timestamp}] {self.level.upper()} {self.source}: {self.message}"
+
+
+RECENT: deque[LogEntry] = deque(maxlen=MAX_RECENT)
+
+LOG_FILE_NAME = "ren_browser.log"
+# Lowest level written to the log file, "off" writing none
+LOG_FILE_LEVELS = {"off": "Off", **LEVELS}
+LOG_FILE_SIZES = {256: "256 KB", 1024: "1 MB", 5120: "5 MB"}
+LOG_FILE_COUNTS = {1: "1 old file", 3: "3 old files", 10: "10 old files"}
+DEFAULT_LOG_FILE_LEVEL = "warning"
+DEFAULT_LOG_FILE_KB = 1024
+DEFAULT_LOG_FILE_COUNT = 3
+
+_log_file: logging.handlers.RotatingFileHandler | None = None
+_log_file_level = "off"
+_log_file_options: tuple | None = None
+
+
+def log_file_options(settings: dict) -> tuple[str, int, int]:
+    """Return the log file settings, the defaults for unknown values.
+
+    Returns:
+        ``(level, max_kb, count)`` for configure_log_file.
+
+    """
+    level = settings.get("log_file_level", DEFAULT_LOG_FILE_LEVEL)
+    if level not in LOG_FILE_LEVELS:
+        level = DEFAULT_LOG_FILE_LEVEL
+    max_kb = settings.get("log_file_kb", DEFAULT_LOG_FILE_KB)
+    if max_kb not in LOG_FILE_SIZES:
+        max_kb = DEFAULT_LOG_FILE_KB
+    count = settings.get("log_file_count", DEFAULT_LOG_FILE_COUNT)
+    if count not in LOG_FILE_COUNTS:
+        count = DEFAULT_LOG_FILE_COUNT
+    return level, max_kb, count
+
+
+def configure_log_file(
+    directory: Path,
+    level: str = DEFAULT_LOG_FILE_LEVEL,
+    max_kb: int = DEFAULT_LOG_FILE_KB,
+    count: int = DEFAULT_LOG_FILE_COUNT,
+) -> Path | None:
+    """Write log messages to ``ren_browser.log`` in a directory.
+
+    Once the file reaches its size it is renamed to ``ren_browser.log.1``,
+    older files moving up by one, and only so many old files are kept.
+
+    Args:
+        directory: Directory to write the log file in.
+        level: Least important level written, or "off" to write no file.
+        max_kb: Size in kilobytes at which the file is rotated.
+        count: Number of old log files kept besides the one written to.
+
+    Returns:
+        The path of the log file, or None when none is written.
+
+    """
+    global _log_file, _log_file_level, _log_file_options
+    options = (Path(directory), level, max_kb, count)
+    if options == _log_file_options:
+        return log_file_path()
+    close_log_file()
+    _log_file_options = options
+    _log_file_level = level
+    if level == "off":
+        return None
+    path = Path(directory) / LOG_FILE_NAME
+    try:
+        path.parent.mkdir(parents=True, exist_ok=True)
+        _log_file = logging.handlers.RotatingFileHandler(
+            path,
+            maxBytes=max_kb * 1024,
+            backupCount=count,
+            encoding="utf-8",
+            delay=True,
+        )
+    except OSError as exc:
+        log_error(f"Failed to open the log file {path}: {exc}")
+        return None
+    return path
+
+
+def log_file_path() -> Path | None:
+    """Return the path of the log file written to, None when there is none."""
+    return Path(_log_file.baseFilename) if _log_file is not None else None
+
+
+def close_log_file() -> None:
+    """Stop writing to the log file."""
+    global _log_file, _log_file_options
+    if _log_file is not None:
+        _log_file.close()
+    _log_file = None
+    _log_file_options = None
+
+
+def _record(entry: LogEntry) -> None:
+    RECENT.append(entry)
+    if _log_file is None:
+        return
+    levels = list(LEVELS)
+    if levels.index(entry.level) <= levels.index(_log_file_level):
+        _log_file.handle(logging.makeLogRecord({"msg": entry.format()}))
+
+
+def _rns_level(args: tuple, kwargs: dict) -> str:
+    # RNS.log takes its level second, from RNS.LOG_CRITICAL (0) through
+    # LOG_ERROR, LOG_WARNING, LOG_NOTICE and LOG_INFO to LOG_EXTREME (7)
+    level = args[0] if args else kwargs.get("level", 3)
+    if not isinstance(level, int):
+        return "info"
+    if level <= 1:
+        return "error"
+    if level == 2:
+        return "warning"
+    if level <= 4:
+        return "info"
+    return "debug"
+
+
+def recent_logs(levels=None, query: str = "") -> list[LogEntry]:
+    """Return the recent log entries, oldest first.
+
+    Args:
+        levels: Levels of LEVELS to include, or None for all of them.
+        query: Text the entries must contain, in any case.
+
+    """
+    query = query.strip().lower()
+    return [
+        entry
+        for entry in RECENT
+        if (levels is None or entry.level in levels)
+        and query in entry.message.lower()
+    ]
+
 
 def log_ret(msg, *args, **kwargs):
     """Log Reticulum messages with timestamp.
@@ -25,6 +191,7 @@ def log_ret(msg, *args, **kwargs):
     """
     timestamp = datetime.datetime.now().isoformat()
     RET_LOGS.append(f"[{timestamp}] {msg}")
+    _record(LogEntry(timestamp, _rns_level(args, kwargs), "reticulum", str(msg)))
     return _original_rns_log(msg, *args, **kwargs)
 
 
@@ -47,6 +214,7 @@ def log_error(msg: str):
     timestamp = datetime.datetime.now().isoformat()
     ERROR_LOGS.append(f"[{timestamp}] {msg}")
     APP_LOGS.append(f"[{timestamp}] ERROR: {msg}")
+    _record(LogEntry(timestamp, "error", "app", msg))
 
 
 def log_app(msg: str):
@@ -58,3 +226,4 @@ def log_app(msg: str):
     """
     timestamp = datetime.datetime.now().isoformat()
     APP_LOGS.append(f"[{timestamp}] {msg}")
+    _record(LogEntry(timestamp, "info", "app", msg))
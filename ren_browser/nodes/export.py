@@ -0,0 +1,111 @@
+"""Node list export for Ren Browser.
+
+Writes the nodes heard this session, along with the ones the user has
+recorded something about, as JSON or CSV for backup or analysis.
+"""
+
+import csv
+import io
+import json
+from datetime import datetime
+
+JSON_FORMAT = "ren-browser-nodes"
+JSON_VERSION = 1
+FIELDS = (
+    "destination_hash",
+    "name",
+    "alias",
+    "aspect",
+    "identity_hash",
+    "first_seen",
+    "last_announce",
+    "announce_count",
+    "favorite",
+    "blocked",
+    "group",
+    "note",
+)
+
+
+def _iso(timestamp: float) -> str | None:
+    if not timestamp:
+        return None
+    return datetime.fromtimestamp(timestamp).isoformat(timespec="seconds")
+
+
+def node_records(announces, nodes) -> list[dict]:
+    """Return one record per known node, in FIELDS order.
+
+    Args:
+        announces: Announces heard this session.
+        nodes: NodeMetadata holding the user's aliases, notes and groups.
+
+    Returns:
+        The heard nodes in the order given, then the nodes only known from
+        the metadata, such as favorites that have not announced.
+
+    """
+    announces = list(announces)
+    heard = {ann.destination_hash for ann in announces}
+    known = [
+        (destination_hash, info)
+        for destination_hash, info in nodes.known()
+        if destination_hash not in heard
+    ]
+    records = []
+    for ann in announces:
+        info = nodes.get(ann.destination_hash)
+        records.append(
+            {
+                "destination_hash": ann.destination_hash,
+                "name": ann.display_name,
+                "alias": info.alias,
+                "aspect": ann.aspect,
+                "identity_hash": ann.identity_hash,
+                "first_seen": _iso(ann.first_seen or ann.timestamp),
+                "last_announce": _iso(ann.timestamp),
+                "announce_count": ann.announce_count,
+                "favorite": info.favorite,
+                "blocked": info.blocked,
+                "group": info.group,
+                "note": info.note,
+            },
+        )
+    for destination_hash, info in known:
+        records.append(
+            {
+                "destination_hash": destination_hash,
+                "name": info.name,
+                "alias": info.alias,
+                "aspect": None,
+                "identity_hash": None,
+                "first_seen": None,
+                "last_announce": None,
+                "announce_count": 0,
+                "favorite": info.favorite,
+                "blocked": info.blocked,
+                "group": info.group,
+                "note": info.note,
+            },
+        )
+    return records
+
+
+def to_json(records: list[dict]) -> str:
+    """Serialize node records to the JSON export format."""
+    return json.dumps(
+        {"format": JSON_FORMAT, "version": JSON_VERSION, "nodes": records},
+        indent=2,
+    )
+
+
+def to_csv(records: list[dict]) -> str:
+    """Serialize node records as CSV with a header row."""
+    output = io.StringIO()
+    writer = csv.DictWriter(output, fieldnames=FIELDS, lineterminator="\n")
+    writer.writeheader()
+    for record in records:
+        writer.writerow(
+            {key: "" if value is None else value for key, value in record.items()},
+        )
+    return output.getvalue()
@@ -0,0 +1,457 @@
+"""Node metadata for Ren Browser.
+
+Keeps what the user has recorded about nodes, such as which ones are
+favorites or blocked, local aliases, which group they were filed under,
+free-text notes, whether they were added by hash and how their pages are
+shown, keyed by destination hash so it survives restarts and nodes that
+have not announced yet this session.
+"""
+
+from dataclasses import asdict, dataclass, field
+
+from ren_browser.pages.address import HASH_LENGTH, is_destination_hash
+
+
+@dataclass
+class NodeOverrides:
+    """How pages from a node are loaded and shown, overriding the settings.
+
+    Pages can be forced to render as plain text, shown at their own zoom
+    and always fetched from the node instead of the page cache.
+    """
+
+    plain_text: bool = False
+    zoom: float | None = None
+    bypass_cache: bool = False
+
+    @classmethod
+    def from_dict(cls, data) -> "NodeOverrides":
+        """Build overrides from stored data, ignoring malformed values."""
+        if not isinstance(data, dict):
+            return cls()
+        zoom = data.get("zoom")
+        if isinstance(zoom, bool) or not isinstance(zoom, int | float) or zoom <= 0:
+            zoom = None
+        return cls(
+            plain_text=bool(data.get("plain_text", False)),
+            zoom=float(zoom) if zoom else None,
+            bypass_cache=bool(data.get("bypass_cache", False)),
+        )
+
+    def is_empty(self) -> bool:
+        """Return whether nothing is overridden."""
+        return not (self.plain_text or self.zoom or self.bypass_cache)
+
+
+@dataclass
+class NodeInfo:
+    """What the user has recorded about a node.
+
+    Contains the last display name seen for it, the alias the user shows
+    instead of it, whether it is a favorite or blocked, the group it was
+    filed under, the user's note about it, whether the user added it to
+    the sidebar by hash and how its pages are shown.
+    """
+
+    name: str | None = None
+    favorite: bool = False
+    group: str | None = None
+    note: str | None = None
+    blocked: bool = False
+    added: bool = False
+    alias: str | None = None
+    overrides: NodeOverrides = field(default_factory=NodeOverrides)
+
+    @classmethod
+    def from_dict(cls, data) -> "NodeInfo | None":
+        """Build node info from stored data, or None if it is malformed."""
+        if not isinstance(data, dict):
+            return None
+        name = data.get("name")
+        group = data.get("group")
+        note = data.get("note")
+        alias = data.get("alias")
+        return cls(
+            name=name if isinstance(name, str) and name else None,
+            favorite=bool(data.get("favorite", False)),
+            group=group if isinstance(group, str) and group else None,
+            note=note if isinstance(note, str) and note else None,
+            blocked=bool(data.get("blocked", False)),
+            added=bool(data.get("added", False)),
+            alias=alias if isinstance(alias, str) and alias else None,
+            overrides=NodeOverrides.from_dict(data.get("overrides")),
+        )
+
+    def is_empty(self) -> bool:
+        """Return whether nothing worth keeping is recorded."""
+        return not (
+            self.favorite
+            or self.blocked
+            or self.added
+            or self.alias
+            or self.group
+            or self.note
+            or not self.overrides.is_empty()
+        )
+
+
+@dataclass
+class NodeGroup:
+    """A user-defined group of nodes in the sidebar."""
+
+    name: str
+    collapsed: bool = False
+
+
+class NodeMetadata:
+    """Node metadata persisted through the storage manager."""
+
+    def __init__(self, storage):
+        """Load the stored node metadata.
+
+        Args:
+            storage: StorageManager used to load and save the metadata.
+
+        """
+        self.storage = storage
+        self._nodes: dict[str, NodeInfo] = {}
+        self._groups: list[NodeGroup] = []
+        try:
+            stored = storage.load_node_metadata()
+        except Exception:  # noqa: BLE001
+            stored = {}
+        if not isinstance(stored, dict):
+            stored = {}
+        for data in stored.get("groups") or []:
+            if isinstance(data, dict) and isinstance(data.get("name"), str):
+                name = data["name"].strip()
+                if name and self.group(name) is None:
+                    self._groups.append(
+                        NodeGroup(name, bool(data.get("collapsed", False))),
+                    )
+        nodes = stored.get("nodes")
+        if not isinstance(nodes, dict):
+            nodes = {}
+        for destination_hash, data in nodes.items():
+            info = NodeInfo.from_dict(data)
+            if info is not None and isinstance(destination_hash, str):
+                if info.group is not None and self.group(info.group) is None:
+                    info.group = None
+                self._nodes[destination_hash] = info
+
+    def get(self, destination_hash: str) -> NodeInfo:
+        """Return what is recorded about a node, or empty info if nothing is."""
+        return self._nodes.get(destination_hash) or NodeInfo()
+
+    def known(self) -> list[tuple[str, NodeInfo]]:
+        """Return every node something is recorded about."""
+        return list(self._nodes.items())
+
+    def is_favorite(self, destination_hash: str) -> bool:
+        """Return whether a node is a favorite."""
+        return self.get(destination_hash).favorite
+
+    def favorites(self) -> list[tuple[str, NodeInfo]]:
+        """Return the favorite nodes, by name."""
+        return sorted(
+            (
+                (destination_hash, info)
+                for destination_hash, info in self._nodes.items()
+                if info.favorite
+            ),
+            key=lambda item: (item[1].name or item[0]).lower(),
+        )
+
+    def set_favorite(
+        self,
+        destination_hash: str,
+        favorite: bool,
+        name: str | None = None,
+    ) -> None:
+        """Mark or unmark a node as a favorite.
+
+        Args:
+            destination_hash: Hash of the node.
+            favorite: Whether the node is a favorite.
+            name: Display name to show for the node until it announces again.
+
+        """
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.favorite = favorite
+        if name:
+            info.name = name
+        self._save()
+
+    def is_blocked(self, destination_hash: str) -> bool:
+        """Return whether a node is blocked."""
+        return self.get(destination_hash).blocked
+
+    def blocked(self) -> list[tuple[str, NodeInfo]]:
+        """Return the blocked nodes, by name."""
+        return sorted(
+            (
+                (destination_hash, info)
+                for destination_hash, info in self._nodes.items()
+                if info.blocked
+            ),
+            key=lambda item: (item[1].name or item[0]).lower(),
+        )
+
+    def set_blocked(
+        self,
+        destination_hash: str,
+        blocked: bool,
+        name: str | None = None,
+    ) -> None:
+        """Block or unblock a node.
+
+        Args:
+            destination_hash: Hash of the node.
+            blocked: Whether the node is blocked.
+            name: Display name to show for the node in the blocklist.
+
+        """
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.blocked = blocked
+        if name:
+            info.name = name
+        self._save()
+
+    def added(self) -> list[tuple[str, NodeInfo]]:
+        """Return the nodes added by hash, in the order they were added."""
+        return [
+            (destination_hash, info)
+            for destination_hash, info in self._nodes.items()
+            if info.added
+        ]
+
+    def add_node(self, destination_hash: str, name: str | None = None) -> str:
+        """Add a node to the sidebar by hash, before it has announced.
+
+        Args:
+            destination_hash: Hex destination hash of the node.
+            name: Name to show for the node until it announces.
+
+        Returns:
+            The hash, in lowercase.
+
+        Raises:
+            ValueError: If the hash is not a full destination hash.
+
+        """
+        destination_hash = destination_hash.strip().lower()
+        if not is_destination_hash(destination_hash):
+            raise ValueError(
+                f"Destination hashes are {HASH_LENGTH} hexadecimal characters.",
+            )
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.added = True
+        name = (name or "").strip()
+        if name:
+            info.name = name
+        self._save()
+        return destination_hash
+
+    def remove_node(self, destination_hash: str) -> None:
+        """Remove a node added by hash from the sidebar."""
+        info = self._nodes.get(destination_hash)
+        if info is not None and info.added:
+            info.added = False
+            self._save()
+
+    def set_note(
+        self,
+        destination_hash: str,
+        note: str | None,
+        name: str | None = None,
+    ) -> None:
+        """Attach a free-text note to a node, or remove it if blank.
+
+        Args:
+            destination_hash: Hash of the node.
+            note: Text of the note.
+            name: Display name to show for the node until it announces again.
+
+        """
+        note = (note or "").strip() or None
+        if self.get(destination_hash).note == note:
+            return
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.note = note
+        if name:
+            info.name = name
+        self._save()
+
+    def aliases(self) -> dict[str, str]:
+        """Return the local aliases, keyed by destination hash."""
+        return {
+            destination_hash: info.alias
+            for destination_hash, info in self._nodes.items()
+            if info.alias
+        }
+
+    def display_name(self, destination_hash: str, announced: str | None) -> str | None:
+        """Return the name to show for a node: its alias, else the announced one."""
+        return self.get(destination_hash).alias or announced
+
+    def set_alias(
+        self,
+        destination_hash: str,
+        alias: str | None,
+        name: str | None = None,
+    ) -> None:
+        """Show a node under a local alias, or its announced name again if blank.
+
+        Args:
+            destination_hash: Hash of the node.
+            alias: Name to show instead of the announced one.
+            name: Announced display name of the node.
+
+        """
+        alias = (alias or "").strip() or None
+        if self.get(destination_hash).alias == alias:
+            return
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.alias = alias
+        if name:
+            info.name = name
+        self._save()
+
+    def overrides(self, destination_hash: str) -> NodeOverrides:
+        """Return how pages from a node are shown."""
+        return self.get(destination_hash).overrides
+
+    def set_overrides(
+        self,
+        destination_hash: str,
+        overrides: NodeOverrides,
+        name: str | None = None,
+    ) -> None:
+        """Change how pages from a node are shown.
+
+        Args:
+            destination_hash: Hash of the node.
+            overrides: Overrides to use for the node's pages.
+            name: Display name to show for the node until it announces again.
+
+        """
+        if self.get(destination_hash).overrides == overrides:
+            return
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.overrides = overrides
+        if name:
+            info.name = name
+        self._save()
+
+    def groups(self) -> list[NodeGroup]:
+        """Return the groups in the order they were created."""
+        return list(self._groups)
+
+    def group(self, name: str) -> NodeGroup | None:
+        """Return the group with a name, if any."""
+        return next((g for g in self._groups if g.name == name), None)
+
+    def members(self, group: str | None) -> list[tuple[str, NodeInfo]]:
+        """Return the nodes filed under a group, or under no group for None."""
+        return [
+            (destination_hash, info)
+            for destination_hash, info in self._nodes.items()
+            if info.group == group
+        ]
+
+    def add_group(self, name: str) -> NodeGroup:
+        """Create a group.
+
+        Raises:
+            ValueError: If the name is empty or already used.
+
+        """
+        name = name.strip()
+        if not name:
+            raise ValueError("Enter a name for the group.")
+        if self.group(name) is not None:
+            raise ValueError(f"There is already a group called {name}.")
+        group = NodeGroup(name)
+        self._groups.append(group)
+        self._save()
+        return group
+
+    def rename_group(self, old: str, new: str) -> None:
+        """Rename a group, keeping its nodes.
+
+        Raises:
+            ValueError: If the new name is empty or already used.
+
+        """
+        group = self.group(old)
+        new = new.strip()
+        if group is None or new == old:
+            return
+        if not new:
+            raise ValueError("Enter a name for the group.")
+        if self.group(new) is not None:
+            raise ValueError(f"There is already a group called {new}.")
+        group.name = new
+        for info in self._nodes.values():
+            if info.group == old:
+                info.group = new
+        self._save()
+
+    def remove_group(self, name: str) -> None:
+        """Delete a group, leaving its nodes ungrouped."""
+        group = self.group(name)
+        if group is None:
+            return
+        self._groups.remove(group)
+        for info in self._nodes.values():
+            if info.group == name:
+                info.group = None
+        self._save()
+
+    def set_collapsed(self, name: str, collapsed: bool) -> None:
+        """Collapse or expand a group's section in the sidebar."""
+        group = self.group(name)
+        if group is not None:
+            group.collapsed = collapsed
+            self._save()
+
+    def set_group(
+        self,
+        destination_hash: str,
+        group: str | None,
+        name: str | None = None,
+    ) -> None:
+        """File a node under a group, or under no group for None.
+
+        Args:
+            destination_hash: Hash of the node.
+            group: Name of an existing group, or None.
+            name: Display name to show for the node until it announces again.
+
+        """
+        if group is not None and self.group(group) is None:
+            return
+        info = self._nodes.setdefault(destination_hash, NodeInfo())
+        info.group = group
+        if name:
+            info.name = name
+        self._save()
+
+    def _save(self) -> None:
+        self._nodes = {
+            destination_hash: info
+            for destination_hash, info in self._nodes.items()
+            if not info.is_empty()
+        }
+        try:
+            self.storage.save_node_metadata(
+                {
+                    "groups": [asdict(group) for group in self._groups],
+                    "nodes": {
+                        destination_hash: asdict(info)
+                        for destination_hash, info in self._nodes.items()
+                    },
+                },
+            )
+        except Exception:  # noqa: BLE001
+            pass
@@ -0,0 +1,101 @@
+"""Notification center for Ren Browser.
+
+Keeps the events the browser told the user about in passing, like
+finished downloads, changed watched pages and favorite nodes coming back,
+so they can be looked up after the snack bar is gone. Events are kept for
+the session only.
+"""
+
+import threading
+import time
+from collections.abc import Callable
+from dataclasses import dataclass
+
+MAX_NOTIFICATIONS = 100
+
+# Kinds of events, each with the label they are listed under
+KINDS = {
+    "downloads": "Download",
+    "watched": "Watched page",
+    "feed": "Feed",
+    "favorites": "Favorite node",
+    "error": "Error",
+}
+
+
+@dataclass(frozen=True)
+class Notification:
+    """An event told to the user.
+
+    Kind is a key of KINDS. Address is opened when the event is clicked,
+    if there is one.
+    """
+
+    kind: str
+    message: str
+    timestamp: float
+    address: str | None = None
+
+
+class NotificationCenter:
+    """Thread-safe list of the latest events, newest first."""
+
+    def __init__(self, max_entries: int = MAX_NOTIFICATIONS):
+        """Initialize an empty notification center.
+
+        Args:
+            max_entries: Most events kept; older ones are dropped.
+
+        """
+        self.max_entries = max_entries
+        self._entries: list[Notification] = []
+        self.unread = 0
+        self.listeners: list[Callable[[], None]] = []
+        self._lock = threading.Lock()
+
+    def add_listener(self, listener: Callable[[], None]) -> None:
+        """Call listener whenever the events change."""
+        self.listeners.append(listener)
+
+    def add(
+        self,
+        kind: str,
+        message: str,
+        address: str | None = None,
+        now: float | None = None,
+    ) -> Notification:
+        """Record an event and return it."""
+        entry = Notification(
+            kind,
+            message,
+            time.time() if now is None else now,
+            address,
+        )
+        with self._lock:
+            self._entries.insert(0, entry)
+            del self._entries[self.max_entries :]
+            self.unread = min(self.unread + 1, len(self._entries))
+        self._notify()
+        return entry
+
+    def entries(self) -> list[Notification]:
+        """Return the events, newest first."""
+        with self._lock:
+            return list(self._entries)
+
+    def mark_read(self) -> None:
+        """Count every event as seen."""
+        if self.unread:
+            self.unread = 0
+            self._notify()
+
+    def clear(self) -> None:
+        """Forget every event."""
+        with self._lock:
+            self._entries.clear()
+            self.unread = 0
+        self._notify()
+
+    def _notify(self) -> None:
+        for listener in list(self.listeners):
+            listener()
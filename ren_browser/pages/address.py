@@ -0,0 +1,296 @@
+"""Address parsing and normalization for Ren Browser.
+
+Turns the many ways people write nomadnetwork addresses into the canonical
+``<destination hash>:<page path>`` form used for fetching, and recognizes
+``file://`` addresses of pages on disk.
+"""
+
+import re
+import string
+import urllib.parse
+import urllib.request
+from collections.abc import Callable
+from dataclasses import dataclass
+from pathlib import Path, PurePosixPath
+
+HASH_LENGTH = 32
+DEFAULT_PAGE_PATH = "/page/index.mu"
+INTERNAL_SCHEME = "about:"
+URL_SCHEME = "ren://"
+FILE_SCHEME = "file://"
+# Longest clipboard text offered as an address to open
+MAX_CLIPBOARD_ADDRESS = 300
+# LXMF addresses on pages, for contacting a node's operator
+_LXMF_RE = re.compile(
+    rf"\s*lxmf(?:@|://|:)\s*<?\s*([0-9a-fA-F]{{{HASH_LENGTH}}})\s*>?\s*",
+    re.IGNORECASE,
+)
+# Pages a node serves from its pages folder are under this path
+PAGES_PREFIX = "/page/"
+_HEX_DIGITS = set(string.hexdigits)
+# A hash as Reticulum programs like Sideband print it, in angle brackets or
+# as colon separated bytes, at the start of an address
+_PRINTED_HASH_RE = re.compile(
+    r"<?\s*((?:[0-9a-fA-F]{2}:){15}[0-9a-fA-F]{2}|[0-9a-fA-F]{32})\s*>?",
+)
+_EMBEDDED_ADDRESS_RE = re.compile(
+    rf"(?<![0-9a-fA-F])[0-9a-fA-F]{{{HASH_LENGTH}}}(?![0-9a-fA-F])(?:[:/][^\s\"'<>`]*)?",
+)
+
+
+class AddressError(ValueError):
+    """Raised when text cannot be turned into a page address.
+
+    The message is meant to be shown to the user as-is.
+    """
+
+
+@dataclass(frozen=True)
+class Address:
+    """A normalized page address on the Reticulum network.
+
+    Contains the lowercase destination hash and an absolute page path.
+    """
+
+    destination_hash: str
+    page_path: str
+
+    def __str__(self) -> str:
+        """Return the canonical ``hash:/path`` form."""
+        return f"{self.destination_hash}:{self.page_path}"
+
+    def url(self) -> str:
+        """Return the ``ren://hash/path`` link form."""
+        return f"{URL_SCHEME}{self.destination_hash}{self.page_path}"
+
+
+def lxmf_address(text: str) -> str | None:
+    """Return the destination hash of an ``lxmf@`` or ``lxmf:`` link, or None.
+
+    Such links name someone to message rather than a page, so they are not
+    fetched like other addresses.
+    """
+    match = _LXMF_RE.fullmatch(text)
+    return match.group(1).lower() if match else None
+
+
+def internal_page_name(text: str) -> str | None:
+    """Return the page name of an ``about:`` address, or None for other text."""
+    text = text.strip().lower()
+    if not text.startswith(INTERNAL_SCHEME):
+        return None
+    return text[len(INTERNAL_SCHEME) :]
+
+
+def local_file_path(text: str) -> Path | None:
+    """Return the file a ``file://`` address points to, or None for other text."""
+    text = text.strip()
+    if not text.lower().startswith(FILE_SCHEME):
+        return None
+    parsed = urllib.parse.urlsplit(text)
+    if parsed.netloc not in ("", "localhost") or not parsed.path:
+        return None
+    return Path(urllib.request.url2pathname(parsed.path))
+
+
+def file_address(path: Path) -> str:
+    """Return the ``file://`` address of a file on disk."""
+    return Path(path).resolve().as_uri()
+
+
+def local_link(link: str, directory: Path) -> str | None:
+    """Resolve a link on a page opened from disk to a file beside it.
+
+    A node serves its pages folder under ``/page/``, so ``:/page/about.mu``
+    on a local copy of that folder points to ``about.mu`` in it.
+
+    Args:
+        link: Link target from the page markup.
+        directory: Folder of the page containing the link.
+
+    Returns:
+        The ``file://`` address of the linked page, or None if the link
+        goes to another node or outside the pages folder.
+
+    """
+    link = link.strip()
+    if link.startswith(":"):
+        link = link[1:]
+    if not link.startswith(PAGES_PREFIX):
+        return None
+    parts = PurePosixPath(link[len(PAGES_PREFIX) :]).parts
+    if not parts or ".." in parts:
+        return None
+    return file_address(directory.joinpath(*parts))
+
+
+def is_destination_hash(text: str) -> bool:
+    """Return whether text is a full hexadecimal destination hash."""
+    return len(text) == HASH_LENGTH and all(c in _HEX_DIGITS for c in text)
+
+
+def _normalize_path(path: str) -> str:
+    path = path.strip()
+    if not path or path == "/":
+        return DEFAULT_PAGE_PATH
+    if any(c.isspace() for c in path):
+        raise AddressError("Page paths cannot contain spaces.")
+    if not path.startswith("/"):
+        path = f"/{path}"
+    return path
+
+
+def _check_hash(candidate: str) -> str:
+    if all(c in _HEX_DIGITS for c in candidate):
+        raise AddressError(
+            f"Destination hashes are {HASH_LENGTH} hexadecimal characters, "
+            f"this one has {len(candidate)}.",
+        )
+    raise AddressError(
+        f"'{candidate}' is not a destination hash or a known node name.",
+    )
+
+
+def parse_address(
+    text: str,
+    resolve_name: Callable[[str], str | None] | None = None,
+) -> Address:
+    """Parse user input into a normalized address.
+
+    Accepts bare hashes, ``hash:/page/...``, ``hash/page/...``,
+    ``ren://hash/page/...`` and node names (resolved through resolve_name),
+    optionally followed by a path. Hashes may be written ``<hash>`` or as
+    colon separated bytes, as Reticulum programs print them.
+
+    Args:
+        text: Address as typed or clicked.
+        resolve_name: Optional lookup from node display name to destination hash.
+
+    Returns:
+        Address: The normalized address.
+
+    Raises:
+        AddressError: If the text cannot be understood as an address.
+
+    """
+    text = text.strip()
+    if text.lower().startswith(URL_SCHEME):
+        text = text[len(URL_SCHEME) :]
+    if not text:
+        raise AddressError("Enter a destination hash or node name.")
+
+    if resolve_name:
+        resolved = resolve_name(text)
+        if resolved:
+            return Address(resolved.lower(), DEFAULT_PAGE_PATH)
+
+    printed = _PRINTED_HASH_RE.match(text)
+    if printed and text[printed.end() :][:1] in ("", ":", "/"):
+        path = text[printed.end() :]
+        return Address(
+            printed.group(1).replace(":", "").lower(),
+            _normalize_path(path.removeprefix(":")),
+        )
+
+    if ":" in text:
+        node, path = text.split(":", 1)
+    elif "/" in text:
+        node, path = text.split("/", 1)
+    else:
+        node, path = text, ""
+    node = node.strip()
+
+    if not node:
+        raise AddressError("The address is missing a destination hash.")
+
+    if is_destination_hash(node):
+        return Address(node.lower(), _normalize_path(path))
+
+    if resolve_name:
+        resolved = resolve_name(node)
+        if resolved:
+            return Address(resolved.lower(), _normalize_path(path))
+
+    return _check_hash(node)
+
+
+def normalize_address(
+    text: str,
+    resolve_name: Callable[[str], str | None] | None = None,
+) -> str:
+    """Return the canonical string form of an address.
+
+    Raises:
+        AddressError: If the text cannot be understood as an address.
+
+    """
+    return str(parse_address(text, resolve_name))
+
+
+def resolve_link(link: str, current_hash: str | None = None) -> str:
+    """Resolve a link found on a page against the node it was served from.
+
+    Args:
+        link: Link target from the page markup.
+        current_hash: Destination hash of the page containing the link.
+
+    Returns:
+        The link target with relative ``:/path`` links made absolute.
+
+    """
+    link = link.strip()
+    if link.startswith(":/") and current_hash:
+        return f"{current_hash}{link}"
+    return link
+
+
+def find_address(text: str) -> str | None:
+    """Return the first destination hash in text with its path, if any.
+
+    Text shared through chat often wraps the address in a sentence or
+    brackets, which are left out.
+    """
+    match = _EMBEDDED_ADDRESS_RE.search(text)
+    return match.group(0).rstrip(".,;)]") if match else None
+
+
+def extract_address(text: str) -> str:
+    """Pick the address out of pasted text.
+
+    The address found by find_address is used when there is one. Otherwise
+    the trimmed text is returned unchanged, as it may be a node name.
+
+    Args:
+        text: Clipboard or other pasted text.
+
+    Returns:
+        The address found in the text.
+
+    """
+    return find_address(text) or text.strip()
+
+
+def clipboard_address(text: str) -> str | None:
+    """Return the page address copied text points to, if it looks like one.
+
+    Only short text holding a destination hash or ``ren://`` link counts,
+    not a long message that happens to contain one, and ``lxmf`` addresses
+    name someone to message rather than a page.
+
+    Args:
+        text: Text on the clipboard.
+
+    Returns:
+        The normalized address, or None if the text is not an address.
+
+    """
+    text = text.strip()
+    if not text or len(text) > MAX_CLIPBOARD_ADDRESS or lxmf_address(text):
+        return None
+    found = find_address(text)
+    if found is None:
+        return None
+    try:
+        return str(parse_address(found))
+    except AddressError:
+        return None
@@ -0,0 +1,60 @@
+"""Address aliases for Ren Browser.
+
+An alias is a short keyword, like ``weather``, that the address bar
+expands to a full address, like ``abc123…:/page/wx/index.mu``, so often
+used pages get a name that is easy to type. Aliases are kept in the
+``address_aliases`` setting, keyword to address.
+"""
+
+import re
+
+from ren_browser.i18n.i18n import tr
+
+MAX_KEYWORD_LENGTH = 32
+
+_KEYWORD_RE = re.compile(r"^[a-z0-9][a-z0-9_.-]*$")
+_HASH_RE = re.compile(r"^[0-9a-f]{32}$")
+
+
+def normalize_keyword(keyword: str) -> str:
+    """Return a keyword as it is stored and matched, trimmed and lowercased."""
+    return keyword.strip().lower()
+
+
+def keyword_error(keyword: str) -> str | None:
+    """Return why a keyword cannot be an alias, or None if it can."""
+    keyword = normalize_keyword(keyword)
+    if not keyword:
+        return tr("Enter a keyword")
+    if len(keyword) > MAX_KEYWORD_LENGTH:
+        return tr("Use at most {count} characters", count=MAX_KEYWORD_LENGTH)
+    if not _KEYWORD_RE.match(keyword):
+        return tr("Use letters, digits, dots, dashes and underscores only")
+    if _HASH_RE.match(keyword):
+        return tr("A destination hash cannot be an alias")
+    return None
+
+
+def aliases(settings: dict) -> dict[str, str]:
+    """Return the valid aliases of the settings, keyword to address."""
+    stored = settings.get("address_aliases")
+    if not isinstance(stored, dict):
+        return {}
+    return {
+        normalize_keyword(keyword): address.strip()
+        for keyword, address in stored.items()
+        if isinstance(keyword, str)
+        and isinstance(address, str)
+        and address.strip()
+        and keyword_error(keyword) is None
+    }
+
+
+def expand_alias(text: str, settings: dict) -> str | None:
+    """Return the address an alias typed in the address bar stands for.
+
+    Returns:
+        The address, or None if the text is not a keyword of an alias.
+
+    """
+    return aliases(settings).get(normalize_keyword(text))
@@ -0,0 +1,285 @@
+"""Page cache for Ren Browser.
+
+Keeps recently fetched pages in memory so revisiting them does not go back
+out over the mesh, and so a stale copy can be shown when a fetch fails. The
+number of pages and their total size are capped, dropping the least recently
+used pages first. Pages can also be kept in a DiskCache, so they are still
+cached after a restart. Each page keeps a hash of its content, so a page
+fetched again after it expired can be checked against the cached copy.
+Pages are held compressed and the size limit counts the compressed bytes.
+"""
+
+import hashlib
+import threading
+import time
+from dataclasses import dataclass
+
+from ren_browser.pages.compression import compress, decompress
+
+DEFAULT_MAX_AGE = 300
+DEFAULT_MAX_ENTRIES = 500
+DEFAULT_MAX_BYTES = 20_000_000
+
+
+def content_digest(content: str) -> str:
+    """Return the hash cached pages are compared by."""
+    return hashlib.sha256(content.encode("utf-8")).hexdigest()
+
+
+def cache_limits(settings: dict) -> tuple[float, int, int]:
+    """Return the cache limits from the app settings.
+
+    Missing, negative or non-numeric values use the defaults.
+
+    Returns:
+        ``(max_age, max_entries, max_bytes)`` for PageCache.configure.
+
+    """
+
+    def number(key, default):
+        try:
+            value = int(settings.get(key, default))
+        except (TypeError, ValueError):
+            return default
+        return value if value >= 0 else default
+
+    return (
+        number("cache_max_age", DEFAULT_MAX_AGE),
+        number("cache_max_pages", DEFAULT_MAX_ENTRIES),
+        number("cache_max_mb", DEFAULT_MAX_BYTES // 1_000_000) * 1_000_000,
+    )
+
+
+@dataclass(init=False)
+class CachedPage:
+    """A fetched page held in the cache.
+
+    Contains the stored page content, the time it was fetched, the hash of
+    the content and how often it was served from the cache.
+    """
+
+    data: bytes
+    compressed: bool
+    fetched_at: float
+    digest: str
+    hits: int
+
+    def __init__(
+        self,
+        content: str,
+        fetched_at: float,
+        digest: str = "",
+        hits: int = 0,
+    ):
+        """Store a page, compressing it if that makes it smaller.
+
+        Args:
+            content: Page content.
+            fetched_at: When the page was fetched.
+            digest: Hash of the content, computed if not given.
+            hits: How often the page was served from the cache.
+
+        """
+        self.data, self.compressed = compress(content)
+        self.fetched_at = fetched_at
+        self.digest = digest or content_digest(content)
+        self.hits = hits
+
+    @property
+    def content(self) -> str:
+        """The page content, decompressed."""
+        return decompress(self.data, self.compressed)
+
+    @property
+    def size(self) -> int:
+        """Bytes the page takes up in the cache."""
+        return len(self.data)
+
+    def age(self, now: float | None = None) -> float:
+        """Return how many seconds ago the page was fetched."""
+        return (now if now is not None else time.time()) - self.fetched_at
+
+
+class PageCache:
+    """Thread-safe cache of page content keyed by normalized address."""
+
+    def __init__(
+        self,
+        max_age: float = DEFAULT_MAX_AGE,
+        max_entries: int = DEFAULT_MAX_ENTRIES,
+        max_bytes: int = DEFAULT_MAX_BYTES,
+        disk=None,
+    ):
+        """Initialize an empty cache.
+
+        Args:
+            max_age: Seconds a page stays fresh enough to be served directly.
+            max_entries: Most pages kept in memory, or 0 for no limit.
+            max_bytes: Most bytes of page content kept in memory, or 0 for no
+                limit.
+            disk: Optional DiskCache pages are also stored in and read back
+                from when they are not in memory.
+
+        """
+        self.max_age = max_age
+        self.max_entries = max_entries
+        self.max_bytes = max_bytes
+        self.enabled = True
+        self.disk = disk
+        self._entries: dict[str, CachedPage] = {}
+        self._bytes = 0
+        self.hits = 0
+        self.misses = 0
+        self._lock = threading.Lock()
+
+    def __len__(self) -> int:
+        """Return the number of pages cached in memory."""
+        with self._lock:
+            return len(self._entries)
+
+    def size(self) -> int:
+        """Return the total size of the pages cached in memory in bytes."""
+        with self._lock:
+            return self._bytes
+
+    def entries(self) -> list[tuple[str, CachedPage]]:
+        """Return the pages cached in memory, most recently used first."""
+        with self._lock:
+            return list(reversed(self._entries.items()))
+
+    def hit_ratio(self) -> float | None:
+        """Return the share of lookups served from the cache, None if none."""
+        lookups = self.hits + self.misses
+        return self.hits / lookups if lookups else None
+
+    def configure(self, max_age: float, max_entries: int, max_bytes: int) -> None:
+        """Change the cache limits, dropping pages that no longer fit."""
+        with self._lock:
+            self.max_age = max_age
+            self.max_entries = max_entries
+            self.max_bytes = max_bytes
+            self._evict()
+
+    def set_enabled(self, enabled: bool) -> None:
+        """Turn caching on or off, forgetting every page when turned off."""
+        self.enabled = enabled
+        if not enabled:
+            self.clear()
+
+    def put(self, address: str, content: str) -> None:
+        """Store freshly fetched content for an address, if caching is on."""
+        if not self.enabled:
+            return
+        entry = CachedPage(content, time.time())
+        self._keep(address, entry)
+        if self.disk is not None:
+            self.disk.put(address, content, entry.fetched_at)
+
+    def revalidate(self, address: str, content: str) -> bool:
+        """Store a page fetched again, keeping the cached copy if it is the same.
+
+        Returns:
+            Whether the content differs from the cached copy, True if the
+            page was not cached.
+
+        """
+        if not self.enabled:
+            return True
+        digest = content_digest(content)
+        now = time.time()
+        with self._lock:
+            entry = self._entries.get(address)
+            if entry is not None and entry.digest == digest:
+                entry.fetched_at = now
+        if entry is None or entry.digest != digest:
+            self.put(address, content)
+            return True
+        if self.disk is not None:
+            self.disk.touch(address, now)
+        return False
+
+    def _keep(self, address: str, entry: CachedPage) -> None:
+        with self._lock:
+            old = self._entries.pop(address, None)
+            if old is not None:
+                self._bytes -= old.size
+            self._entries[address] = entry
+            self._bytes += entry.size
+            self._evict()
+
+    def get(
+        self,
+        address: str,
+        allow_stale: bool = False,
+        record: bool = True,
+    ) -> CachedPage | None:
+        """Return the cached page for an address.
+
+        Args:
+            address: Normalized page address.
+            allow_stale: Also return entries older than max_age.
+            record: Count the lookup as a hit or miss. Stale lookups are
+                never counted.
+
+        Returns:
+            The cached page, or None if missing or expired.
+
+        """
+        with self._lock:
+            entry = self._entries.pop(address, None)
+            if entry is not None:
+                # Move the page to the end, as the most recently used.
+                self._entries[address] = entry
+        if entry is None and self.enabled and self.disk is not None:
+            stored = self.disk.get(address)
+            if stored is not None:
+                content, fetched_at = stored
+                entry = CachedPage(content, fetched_at)
+                self._keep(address, entry)
+        if entry is not None and (allow_stale or entry.age() <= self.max_age):
+            if record and not allow_stale:
+                entry.hits += 1
+                self.hits += 1
+            return entry
+        if record and not allow_stale:
+            self.misses += 1
+        return None
+
+    def remove(self, address: str) -> None:
+        """Forget the cached copy of an address, if any."""
+        with self._lock:
+            entry = self._entries.pop(address, None)
+            if entry is not None:
+                self._bytes -= entry.size
+        if self.disk is not None:
+            self.disk.remove(address)
+
+    def clear(self, since: float | None = None) -> None:
+        """Forget cached pages.
+
+        Args:
+            since: Only forget pages fetched at or after this time.
+
+        """
+        with self._lock:
+            if since is None:
+                self._entries.clear()
+            else:
+                self._entries = {
+                    address: entry
+                    for address, entry in self._entries.items()
+                    if entry.fetched_at < since
+                }
+            self._bytes = sum(entry.size for entry in self._entries.values())
+        if self.disk is not None:
+            self.disk.clear(since)
+
+    def _evict(self) -> None:
+        # Called with the lock held. A single page larger than max_bytes is
+        # still kept so the page being viewed can fall back to it.
+        while len(self._entries) > 1 and (
+            (self.max_entries and len(self._entries) > self.max_entries)
+            or (self.max_bytes and self._bytes > self.max_bytes)
+        ):
+            address = next(iter(self._entries))
+            self._bytes -= self._entries.pop(address).size
@@ -0,0 +1,69 @@
+"""Character set detection for Ren Browser.
+
+Pages are meant to be UTF-8, but some older nodes serve Latin-1 or another
+legacy encoding. Text is decoded as UTF-8 when it is valid UTF-8, and as
+Windows-1252 or Latin-1 otherwise, which decode every byte so no page
+fails to load. A tab can be told to decode its pages in another encoding
+instead, replacing what does not decode in it.
+"""
+
+import codecs
+
+# Encodings a tab can be switched to, by Python codec name, with their labels
+ENCODINGS = {
+    "utf-8": "Unicode (UTF-8)",
+    "cp1252": "Western (Windows-1252)",
+    "iso-8859-1": "Western (ISO-8859-1)",
+    "iso-8859-15": "Western (ISO-8859-15)",
+    "cp1250": "Central European (Windows-1250)",
+    "iso-8859-2": "Central European (ISO-8859-2)",
+    "cp1251": "Cyrillic (Windows-1251)",
+    "koi8-r": "Cyrillic (KOI8-R)",
+    "iso-8859-7": "Greek (ISO-8859-7)",
+    "cp1254": "Turkish (Windows-1254)",
+    "shift_jis": "Japanese (Shift_JIS)",
+    "euc-jp": "Japanese (EUC-JP)",
+    "gbk": "Chinese, Simplified (GBK)",
+    "big5": "Chinese, Traditional (Big5)",
+    "euc-kr": "Korean (EUC-KR)",
+}
+_BOMS = (
+    (codecs.BOM_UTF8, "utf-8-sig"),
+    (codecs.BOM_UTF16_LE, "utf-16"),
+    (codecs.BOM_UTF16_BE, "utf-16"),
+)
+
+
+def detect_charset(data: bytes) -> str:
+    """Return the encoding bytes are most likely in.
+
+    A byte order mark wins, then UTF-8 if the bytes are valid UTF-8, then
+    Windows-1252, and Latin-1 for the few bytes Windows-1252 leaves out.
+    """
+    for bom, encoding in _BOMS:
+        if data.startswith(bom):
+            return encoding
+    for encoding in ("utf-8", "cp1252"):
+        try:
+            data.decode(encoding)
+        except UnicodeDecodeError:
+            continue
+        return encoding
+    return "iso-8859-1"
+
+
+def decode_text(data: bytes, encoding: str | None = None) -> str:
+    """Decode page content, replacing what does not decode.
+
+    Args:
+        data: Content as received.
+        encoding: Encoding to decode in, or None to detect it. Unknown
+            encodings are detected instead.
+
+    """
+    if encoding is not None:
+        try:
+            codecs.lookup(encoding)
+        except LookupError:
+            encoding = None
+    return data.decode(encoding or detect_charset(data), errors="replace")
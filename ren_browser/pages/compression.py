@@ -0,0 +1,37 @@
+"""Page compression for Ren Browser's caches.
+
+Cached pages are kept compressed in memory and on disk, so more of them fit
+in the same budget. Pages full of ASCII art and repeated markup shrink
+several times over. Pages too small to gain anything are kept as they are.
+"""
+
+import zlib
+
+# Fast enough to compress a large page in a few milliseconds.
+COMPRESSION_LEVEL = 6
+
+
+def compress(content: str) -> tuple[bytes, bool]:
+    """Encode a page for storage.
+
+    Returns:
+        The stored bytes, and whether they are compressed. A page that does
+        not get smaller is stored as UTF-8.
+
+    """
+    data = content.encode("utf-8")
+    packed = zlib.compress(data, COMPRESSION_LEVEL)
+    if len(packed) < len(data):
+        return packed, True
+    return data, False
+
+
+def decompress(data: bytes, compressed: bool) -> str:
+    """Decode a page stored by compress.
+
+    Raises:
+        zlib.error: If compressed data is corrupt.
+        UnicodeDecodeError: If the page is not valid UTF-8.
+
+    """
+    return (zlib.decompress(data) if compressed else data).decode("utf-8")
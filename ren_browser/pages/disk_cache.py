@@ -0,0 +1,283 @@
+"""Disk cache for Ren Browser.
+
+Keeps fetched pages in files so they survive a restart instead of being
+fetched again over the mesh. An index records when each page was fetched
+and last used, and once the files pass the size cap the least recently used
+pages are dropped first. Files and the index are written by a background
+thread so fetching and showing pages never waits on the disk. Pages are
+stored compressed when that makes them smaller.
+"""
+
+import hashlib
+import json
+import queue
+import threading
+import time
+import zlib
+from collections.abc import Callable
+from dataclasses import dataclass
+from pathlib import Path
+
+from ren_browser.pages.compression import compress, decompress
+
+INDEX_FILE = "index.json"
+DEFAULT_MAX_DISK_BYTES = 100_000_000
+
+
+def disk_cache_limit(settings: dict) -> int:
+    """Return the most bytes kept on disk from the app settings.
+
+    Missing, negative or non-numeric values use the default, and 0 keeps
+    pages in memory only.
+    """
+    default = DEFAULT_MAX_DISK_BYTES // 1_000_000
+    try:
+        value = int(settings.get("cache_disk_mb", default))
+    except (TypeError, ValueError):
+        value = default
+    return (value if value >= 0 else default) * 1_000_000
+
+
+@dataclass
+class DiskEntry:
+    """A page stored in the disk cache."""
+
+    fetched_at: float
+    size: int
+    used: float
+    compressed: bool = False
+
+
+class DiskCache:
+    """Pages kept in files, keyed by normalized address."""
+
+    def __init__(self, directory: Path, max_bytes: int = DEFAULT_MAX_DISK_BYTES):
+        """Read the index of the pages stored in a directory.
+
+        Args:
+            directory: Directory holding the page files and the index.
+            max_bytes: Most bytes of pages kept, or 0 to keep none.
+
+        """
+        self.directory = Path(directory)
+        self.max_bytes = max_bytes
+        self._entries: dict[str, DiskEntry] = {}
+        self._bytes = 0
+        self._pending: dict[str, bytes] = {}
+        self._lock = threading.Lock()
+        self._jobs: queue.Queue[Callable[[], None]] = queue.Queue()
+        self._worker: threading.Thread | None = None
+        self._load_index()
+        if not max_bytes:
+            self.clear()
+
+    def _load_index(self) -> None:
+        try:
+            stored = json.loads((self.directory / INDEX_FILE).read_text("utf-8"))
+        except (OSError, ValueError):
+            return
+        entries = []
+        for address, data in stored.items() if isinstance(stored, dict) else []:
+            try:
+                entry = DiskEntry(
+                    float(data["fetched_at"]),
+                    int(data["size"]),
+                    float(data["used"]),
+                    bool(data.get("compressed", False)),
+                )
+            except (KeyError, TypeError, ValueError):
+                continue
+            entries.append((address, entry))
+        entries.sort(key=lambda item: item[1].used)
+        self._entries = dict(entries)
+        self._bytes = sum(entry.size for entry in self._entries.values())
+
+    def __len__(self) -> int:
+        """Return the number of pages on disk."""
+        with self._lock:
+            return len(self._entries)
+
+    def size(self) -> int:
+        """Return the total size of the pages on disk in bytes."""
+        with self._lock:
+            return self._bytes
+
+    def path(self, address: str) -> Path:
+        """Return the file a page is stored in."""
+        return self.directory / hashlib.sha256(address.encode()).hexdigest()
+
+    def configure(self, max_bytes: int) -> None:
+        """Change the size cap, dropping pages that no longer fit."""
+        with self._lock:
+            self.max_bytes = max_bytes
+            removed = self._evict()
+        if not max_bytes:
+            self.clear()
+        else:
+            self._schedule(removed)
+
+    def put(self, address: str, content: str, fetched_at: float) -> None:
+        """Store a page, replacing any older copy."""
+        if not self.max_bytes:
+            return
+        data, compressed = compress(content)
+        with self._lock:
+            old = self._entries.pop(address, None)
+            if old is not None:
+                self._bytes -= old.size
+            self._entries[address] = DiskEntry(
+                fetched_at,
+                len(data),
+                time.time(),
+                compressed,
+            )
+            self._bytes += len(data)
+            self._pending[address] = data
+            removed = self._evict()
+        path = self.path(address)
+
+        def write():
+            _write(path, data)
+            with self._lock:
+                if self._pending.get(address) is data:
+                    del self._pending[address]
+
+        self._schedule(removed, write)
+
+    def addresses(self) -> list[str]:
+        """Return the addresses of the stored pages, most recently used first."""
+        with self._lock:
+            return list(reversed(self._entries))
+
+    def get(self, address: str, use: bool = True) -> tuple[str, float] | None:
+        """Read a stored page.
+
+        Args:
+            address: Normalized address of the page.
+            use: Whether reading counts as a use of the page, keeping it from
+                being evicted. Searching the stored pages does not.
+
+        Returns:
+            The page content and when it was fetched, or None if the page is
+            not stored or its file could not be read.
+
+        """
+        with self._lock:
+            entry = self._entries.get(address)
+            if entry is None:
+                return None
+            if use:
+                entry.used = time.time()
+                self._entries[address] = self._entries.pop(address)
+            data = self._pending.get(address)
+        if data is not None:
+            if use:
+                self._schedule([])
+            return decompress(data, entry.compressed), entry.fetched_at
+        try:
+            content = decompress(self.path(address).read_bytes(), entry.compressed)
+        except (OSError, UnicodeDecodeError, zlib.error):
+            self.remove(address)
+            return None
+        if use:
+            self._schedule([])
+        return content, entry.fetched_at
+
+    def touch(self, address: str, fetched_at: float) -> None:
+        """Record that a stored page was fetched again without changing."""
+        with self._lock:
+            entry = self._entries.get(address)
+            if entry is None:
+                return
+            entry.fetched_at = fetched_at
+        self._schedule([])
+
+    def remove(self, address: str) -> None:
+        """Forget the stored copy of an address, if any."""
+        with self._lock:
+            entry = self._entries.pop(address, None)
+            if entry is None:
+                return
+            self._bytes -= entry.size
+        self._schedule([address])
+
+    def clear(self, since: float | None = None) -> None:
+        """Forget stored pages.
+
+        Args:
+            since: Only forget pages fetched at or after this time.
+
+        """
+        with self._lock:
+            removed = [
+                address
+                for address, entry in self._entries.items()
+                if since is None or entry.fetched_at >= since
+            ]
+            for address in removed:
+                self._bytes -= self._entries.pop(address).size
+        self._schedule(removed)
+
+    def flush(self) -> None:
+        """Wait until every pending write has reached the disk."""
+        self._jobs.join()
+
+    def _evict(self) -> list[str]:
+        # Called with the lock held. As in memory, a single page larger than
+        # the cap is still kept.
+        removed = []
+        while len(self._entries) > 1 and self._bytes > self.max_bytes:
+            address = next(iter(self._entries))
+            self._bytes -= self._entries.pop(address).size
+            removed.append(address)
+        return removed
+
+    def _schedule(self, removed: list[str], write=None) -> None:
+        with self._lock:
+            for address in removed:
+                self._pending.pop(address, None)
+        paths = [self.path(address) for address in removed]
+
+        def job():
+            self.directory.mkdir(parents=True, exist_ok=True)
+            if write is not None:
+                write()
+            for path in paths:
+                path.unlink(missing_ok=True)
+            self._save_index()
+
+        self._jobs.put(job)
+        with self._lock:
+            if self._worker is None:
+                self._worker = threading.Thread(target=self._work, daemon=True)
+                self._worker.start()
+
+    def _work(self) -> None:
+        while True:
+            job = self._jobs.get()
+            try:
+                job()
+            except OSError:
+                pass
+            finally:
+                self._jobs.task_done()
+
+    def _save_index(self) -> None:
+        with self._lock:
+            index = {
+                address: {
+                    "fetched_at": entry.fetched_at,
+                    "size": entry.size,
+                    "used": entry.used,
+                    "compressed": entry.compressed,
+                }
+                for address, entry in self._entries.items()
+            }
+        _write(self.directory / INDEX_FILE, json.dumps(index).encode("utf-8"))
+
+
+def _write(path: Path, data: bytes) -> None:
+    # Written beside the target first so a crash never leaves half a file
+    temp = path.with_name(f"{path.name}.tmp")
+    temp.write_bytes(data)
+    temp.replace(path)
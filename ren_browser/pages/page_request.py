@@ -6,10 +6,64 @@ the nomadnetwork protocol.
 
 import threading
 import time
+from collections.abc import Callable
 from dataclasses import dataclass
 
 import RNS
 
+from ren_browser.i18n.i18n import tr
+from ren_browser.pages.charset import decode_text
+
+# Load phases in order, with the share of the overall progress bar they cover
+# and the label shown while they run.
+LOAD_PHASES = {
+    "path": (0.0, 0.15, "Requesting path"),
+    "link": (0.15, 0.25, "Establishing link"),
+    "transfer": (0.25, 0.85, "Receiving page"),
+    "parse": (0.85, 0.95, "Parsing"),
+    "render": (0.95, 1.0, "Rendering"),
+}
+# Seconds to wait for a path to the node, and for a page once a link is asked for
+PATH_TIMEOUT = 30
+PAGE_TIMEOUT = 15
+
+
+def phase_progress(phase: str, fraction: float | None = None) -> float:
+    """Map progress within a load phase onto the overall 0..1 range.
+
+    Args:
+        phase: One of the LOAD_PHASES keys.
+        fraction: Progress within the phase (0..1), or None at its start.
+
+    Returns:
+        float: Overall load progress.
+
+    """
+    start, end, _ = LOAD_PHASES.get(phase, (0.0, 0.0, ""))
+    fraction = min(max(fraction or 0.0, 0.0), 1.0)
+    return start + (end - start) * fraction
+
+
+def phase_label(phase: str, fraction: float | None = None) -> str:
+    """Return a human readable description of a load phase."""
+    label = tr(LOAD_PHASES.get(phase, (0.0, 0.0, "Loading"))[2])
+    if fraction is not None and phase == "transfer":
+        return f"{label}... {int(min(max(fraction, 0.0), 1.0) * 100)}%"
+    return f"{label}..."
+
+
+class PageFetchError(Exception):
+    """Raised when a page cannot be fetched.
+
+    The kind attribute names the failure so the UI can explain it: one of
+    "no_path", "unknown_identity", "link_failed", "timeout" or "cancelled".
+    """
+
+    def __init__(self, kind: str, message: str):
+        """Initialize the error with its kind and a log message."""
+        super().__init__(message)
+        self.kind = kind
+
 
 @dataclass
 class PageRequest:
@@ -31,67 +85,184 @@ class PageFetcher:
         # RNS should already be initialized by main app
 
     @staticmethod
-    def fetch_page(req: PageRequest) -> str:
+    def fetch_page(
+        req: PageRequest,
+        on_progress: Callable[[str, float | None], None] | None = None,
+        refresh_path: bool = False,
+        encoding: str | None = None,
+    ) -> str:
         """Download page content for the given PageRequest.
 
         Args:
             req: PageRequest containing destination and path information.
+            on_progress: Optional callback(phase, fraction) reporting the
+                current LOAD_PHASES phase and progress within it.
+            refresh_path: Send a new path request even if a path is known.
+            encoding: Encoding the page is decoded in, or None to detect it.
 
         Returns:
             str: The downloaded page content.
 
         Raises:
-            Exception: If no path to destination or identity not found.
+            PageFetchError: If there is no path, the identity is unknown, or the
+                request fails or times out.
 
         """
         RNS.log(
             f"PageFetcher: starting fetch of {req.page_path} from {req.destination_hash}",
         )
-        dest_bytes = bytes.fromhex(req.destination_hash)
-        if not RNS.Transport.has_path(dest_bytes):
-            RNS.Transport.request_path(dest_bytes)
-            start = time.time()
-            while not RNS.Transport.has_path(dest_bytes):
-                if time.time() - start > 30:
-                    raise Exception(f"No path to destination {req.destination_hash}")
-                time.sleep(0.1)
-        identity = RNS.Identity.recall(dest_bytes)
-        if not identity:
-            raise Exception("Identity not found")
-        destination = RNS.Destination(
-            identity,
-            RNS.Destination.OUT,
-            RNS.Destination.SINGLE,
-            "nomadnetwork",
-            "node",
-        )
-        link = RNS.Link(destination)
-
-        result = {"data": None}
-        ev = threading.Event()
-
-        def on_response(receipt):
-            data = receipt.response
-            if isinstance(data, bytes):
-                result["data"] = data.decode("utf-8")
-            else:
-                result["data"] = str(data)
-            ev.set()
-
-        def on_failed(_):
-            ev.set()
-
-        link.set_link_established_callback(
-            lambda link: link.request(
-                req.page_path,
-                req.field_data,
-                response_callback=on_response,
-                failed_callback=on_failed,
-            ),
-        )
-        ev.wait(timeout=15)
-        data_str = result["data"] or "No content received"
+        data = _request(req, on_progress, refresh_path, timeout=PAGE_TIMEOUT)
+        if isinstance(data, bytes):
+            data_str = decode_text(data, encoding)
+        elif data is None:
+            data_str = "No content received"
+        else:
+            data_str = str(data)
         RNS.log(
             f"PageFetcher: received data for {req.destination_hash}:{req.page_path}",
         )
-        return data_str
+        return data_str or "No content received"
+
+    @staticmethod
+    def fetch_file(
+        req: PageRequest,
+        on_progress: Callable[[str, float | None], None] | None = None,
+        cancel: threading.Event | None = None,
+        refresh_path: bool = False,
+        timeout: float = 600,
+    ) -> tuple[bytes, str | None]:
+        """Download a file served by a node, such as one under ``/file/``.
+
+        Args:
+            req: PageRequest containing destination and file path.
+            on_progress: Optional callback(phase, fraction) reporting progress.
+            cancel: Event that aborts the transfer when set.
+            refresh_path: Send a new path request even if a path is known.
+            timeout: Seconds to wait for the whole file.
+
+        Returns:
+            The file content and the file name sent by the node, if any.
+
+        Raises:
+            PageFetchError: If the download fails, times out or is cancelled,
+                the latter with the kind "cancelled".
+
+        """
+        RNS.log(
+            f"PageFetcher: starting download of {req.page_path} "
+            f"from {req.destination_hash}",
+        )
+        data = _request(req, on_progress, refresh_path, timeout, cancel)
+        name = None
+        # Nodes may answer with the file and a metadata dict, as [data, metadata]
+        if isinstance(data, (list, tuple)) and len(data) == 2:
+            data, metadata = data
+            if isinstance(metadata, dict):
+                name = metadata.get("name") or metadata.get(b"name")
+                if isinstance(name, bytes):
+                    name = name.decode("utf-8", errors="replace")
+        if hasattr(data, "read"):
+            data = data.read()
+        if isinstance(data, str):
+            data = data.encode("utf-8")
+        if not isinstance(data, bytes):
+            raise PageFetchError(
+                "link_failed",
+                f"No file content received for {req.page_path}",
+            )
+        return data, name
+
+
+def _request(
+    req: PageRequest,
+    on_progress: Callable[[str, float | None], None] | None,
+    refresh_path: bool,
+    timeout: float,
+    cancel: threading.Event | None = None,
+):
+    """Send a request to a node over a new link and return the raw response.
+
+    Raises:
+        PageFetchError: If there is no path, the identity is unknown, or the
+            request fails, times out or is cancelled.
+
+    """
+
+    def report(phase, fraction=None):
+        if on_progress:
+            on_progress(phase, fraction)
+
+    def cancelled():
+        return cancel is not None and cancel.is_set()
+
+    dest_bytes = bytes.fromhex(req.destination_hash)
+    if refresh_path or not RNS.Transport.has_path(dest_bytes):
+        report("path")
+        RNS.Transport.request_path(dest_bytes)
+        start = time.time()
+        while not RNS.Transport.has_path(dest_bytes):
+            if cancelled():
+                raise PageFetchError("cancelled", "Request cancelled")
+            if time.time() - start > PATH_TIMEOUT:
+                raise PageFetchError(
+                    "no_path",
+                    f"No path to destination {req.destination_hash}",
+                )
+            time.sleep(0.1)
+    identity = RNS.Identity.recall(dest_bytes)
+    if not identity:
+        raise PageFetchError("unknown_identity", "Identity not found")
+    destination = RNS.Destination(
+        identity,
+        RNS.Destination.OUT,
+        RNS.Destination.SINGLE,
+        "nomadnetwork",
+        "node",
+    )
+    report("link")
+    link = RNS.Link(destination)
+
+    result = {"data": None, "failed": False}
+    ev = threading.Event()
+
+    def on_response(receipt):
+        result["data"] = receipt.response
+        ev.set()
+
+    def on_failed(_):
+        result["failed"] = True
+        ev.set()
+
+    def on_request_progress(receipt):
+        report("transfer", getattr(receipt, "progress", None))
+
+    def on_established(link):
+        report("transfer", 0.0)
+        link.request(
+            req.page_path,
+            req.field_data,
+            response_callback=on_response,
+            failed_callback=on_failed,
+            progress_callback=on_request_progress,
+        )
+
+    link.set_link_established_callback(on_established)
+    link.set_link_closed_callback(on_failed)
+    deadline = time.time() + timeout
+    received = False
+    while not received and time.time() < deadline:
+        if cancelled():
+            link.teardown()
+            raise PageFetchError("cancelled", "Request cancelled")
+        received = ev.wait(timeout=min(0.25, max(deadline - time.time(), 0)))
+    if result["failed"] and result["data"] is None:
+        raise PageFetchError(
+            "link_failed",
+            f"Request for {req.page_path} failed",
+        )
+    if not received:
+        raise PageFetchError(
+            "timeout",
+            f"Timed out waiting for {req.page_path}",
+        )
+    return result["data"]
@@ -0,0 +1,156 @@
+"""Link prefetching for Ren Browser.
+
+After a page loads, quietly fetches the pages it links to on the same node
+into the page cache, so following one of those links shows the page at
+once. Only a few pages are fetched at a time, and the links of a page stop
+being fetched once they add up to a byte budget. Loading another page drops
+the links of the previous one that were not fetched yet.
+"""
+
+import threading
+from collections import deque
+from collections.abc import Callable
+
+from ren_browser.downloads.downloads import is_file_address
+from ren_browser.pages.address import AddressError, parse_address, resolve_link
+from ren_browser.renderer.micron import micron_links
+
+MAX_CONCURRENT = 2
+MAX_LINKS = 20
+DEFAULT_BUDGET_BYTES = 500_000
+
+
+def prefetch_budget(settings: dict) -> int:
+    """Return the bytes prefetched per page from the app settings.
+
+    Returns:
+        The budget, 0 if prefetching is off. Missing, negative or
+        non-numeric sizes use the default.
+
+    """
+    if not settings.get("prefetch_links", False):
+        return 0
+    default = DEFAULT_BUDGET_BYTES // 1000
+    try:
+        value = int(settings.get("prefetch_kb", default))
+    except (TypeError, ValueError):
+        value = default
+    return (value if value >= 0 else default) * 1000
+
+
+def prefetch_targets(address, content: str) -> list[str]:
+    """Return the pages on the same node a micron page links to.
+
+    Args:
+        address: Parsed address of the page.
+        content: Micron source of the page.
+
+    Returns:
+        Normalized addresses in the order they appear, without the page
+        itself, files or duplicates, and at most ``MAX_LINKS`` of them.
+
+    """
+    targets = []
+    for _, link in micron_links(content):
+        try:
+            target = parse_address(resolve_link(link, address.destination_hash))
+        except AddressError:
+            continue
+        url = str(target)
+        if (
+            target.destination_hash != address.destination_hash
+            or is_file_address(target.page_path)
+            or url == str(address)
+            or url in targets
+        ):
+            continue
+        targets.append(url)
+    return targets[:MAX_LINKS]
+
+
+class Prefetcher:
+    """Fetches pages into a PageCache in the background."""
+
+    def __init__(
+        self,
+        fetch: Callable[[str], str],
+        cache,
+        run_thread: Callable[[Callable[[], None]], None] | None = None,
+        budget: int = 0,
+        max_concurrent: int = MAX_CONCURRENT,
+    ):
+        """Initialize a prefetcher with nothing to fetch.
+
+        Args:
+            fetch: Function returning the content at an address, raising if
+                it cannot be fetched.
+            cache: PageCache the pages are stored in.
+            run_thread: Function running a callable on a worker thread.
+                Defaults to starting a daemon thread.
+            budget: Most bytes fetched for the links of one page, or 0 to
+                fetch nothing.
+            max_concurrent: Most pages fetched at the same time.
+
+        """
+        self.fetch = fetch
+        self.cache = cache
+        self.budget = budget
+        self.max_concurrent = max_concurrent
+        self._run_thread = run_thread or (
+            lambda target: threading.Thread(target=target, daemon=True).start()
+        )
+        self._queue: deque[str] = deque()
+        self._spent = 0
+        self._generation = object()
+        self._workers = 0
+        self._lock = threading.Lock()
+
+    def configure(self, budget: int) -> None:
+        """Change the byte budget, stopping prefetching when it is 0."""
+        self.budget = budget
+        if not budget:
+            self.cancel()
+
+    def prefetch(self, addresses: list[str]) -> None:
+        """Fetch pages that are not cached yet, replacing any still queued."""
+        if not self.budget or not self.cache.enabled:
+            return
+        with self._lock:
+            self._queue = deque(addresses)
+            self._spent = 0
+            self._generation = object()
+            start = min(self.max_concurrent, len(self._queue)) - self._workers
+            self._workers += max(start, 0)
+        for _ in range(start):
+            self._run_thread(self._work)
+
+    def cancel(self) -> None:
+        """Drop every page not fetched yet."""
+        with self._lock:
+            self._queue.clear()
+            self._generation = object()
+
+    def pending(self) -> int:
+        """Return the number of pages waiting to be fetched."""
+        with self._lock:
+            return len(self._queue)
+
+    def _work(self) -> None:
+        while True:
+            with self._lock:
+                if not self._queue or self._spent >= self.budget:
+                    self._queue.clear()
+                    self._workers -= 1
+                    return
+                address = self._queue.popleft()
+                generation = self._generation
+            if self.cache.get(address, record=False) is not None:
+                continue
+            try:
+                content = self.fetch(address)
+            except Exception:  # noqa: BLE001
+                continue
+            with self._lock:
+                if generation is self._generation:
+                    self._spent += len(content.encode("utf-8"))
+            self.cache.put(address, content)
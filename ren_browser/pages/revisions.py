@@ -0,0 +1,99 @@
+"""Page revisions for Ren Browser.
+
+Keeps the last version of each page fetched from the network and, once it
+changes, the version before, so a revisited page such as a bulletin board
+can be shown with what changed since it was last seen. The versions are
+kept in two disk caches beside the page cache, with their own size cap.
+"""
+
+import difflib
+import time
+from dataclasses import dataclass
+from pathlib import Path
+
+from ren_browser.pages.disk_cache import DiskCache
+
+DEFAULT_MAX_REVISION_BYTES = 20_000_000
+ADDED = "added"
+REMOVED = "removed"
+SAME = "same"
+
+
+@dataclass
+class Revision:
+    """An earlier version of a page and when it was fetched."""
+
+    content: str
+    fetched_at: float
+
+
+def diff_lines(old: str, new: str) -> list[tuple[str, str]]:
+    """Compare two versions of a text line by line.
+
+    Returns:
+        ``(kind, line)`` pairs in reading order, kind being ADDED, REMOVED
+        or SAME. Removed lines come before the lines that replaced them.
+
+    """
+    old_lines, new_lines = old.splitlines(), new.splitlines()
+    lines: list[tuple[str, str]] = []
+    matcher = difflib.SequenceMatcher(None, old_lines, new_lines, autojunk=False)
+    for tag, i1, i2, j1, j2 in matcher.get_opcodes():
+        if tag == "equal":
+            lines.extend((SAME, line) for line in new_lines[j1:j2])
+            continue
+        lines.extend((REMOVED, line) for line in old_lines[i1:i2])
+        lines.extend((ADDED, line) for line in new_lines[j1:j2])
+    return lines
+
+
+class PageRevisions:
+    """Latest and previous versions of pages, keyed by normalized address."""
+
+    def __init__(self, directory: Path, max_bytes: int = DEFAULT_MAX_REVISION_BYTES):
+        """Read the stored versions.
+
+        Args:
+            directory: Directory the versions are kept in.
+            max_bytes: Most bytes kept of each of the latest and previous
+                versions.
+
+        """
+        self.latest = DiskCache(Path(directory) / "latest", max_bytes)
+        self.earlier = DiskCache(Path(directory) / "previous", max_bytes)
+
+    def record(self, address: str, content: str, now: float | None = None) -> bool:
+        """Remember a version of a page just fetched.
+
+        Returns:
+            True if it differs from the version last fetched.
+
+        """
+        now = time.time() if now is None else now
+        latest = self.latest.get(address, use=False)
+        if latest is not None and latest[0] == content:
+            self.latest.touch(address, now)
+            return False
+        if latest is not None:
+            self.earlier.put(address, *latest)
+        else:
+            self.earlier.remove(address)
+        self.latest.put(address, content, now)
+        return latest is not None
+
+    def previous(self, address: str, content: str) -> Revision | None:
+        """Return the version a page had before it changed to content.
+
+        None is returned when there is no earlier version, or content is
+        not the version last fetched, as for an old cached copy.
+        """
+        latest = self.latest.get(address, use=False)
+        if latest is None or latest[0] != content:
+            return None
+        earlier = self.earlier.get(address)
+        return Revision(*earlier) if earlier is not None else None
+
+    def clear(self, since: float | None = None) -> None:
+        """Forget the versions fetched at or after a time, or all of them."""
+        self.latest.clear(since)
+        self.earlier.clear(since)
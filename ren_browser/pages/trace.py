@@ -0,0 +1,78 @@
+"""Page load traces for Ren Browser.
+
+Each page load in a tab is traced for the developer tools: the request
+sent to the node, what came back, how long every load phase took and
+which renderer showed the page.
+"""
+
+import json
+import time
+from dataclasses import dataclass, field
+
+from ren_browser.pages.page_request import LOAD_PHASES
+
+
+@dataclass
+class PageTrace:
+    """What happened while a tab loaded a page."""
+
+    address: str
+    request: dict
+    started: float = field(default_factory=time.monotonic)
+    # Seconds spent in each phase, in the order they ran
+    phases: dict[str, float] = field(default_factory=dict)
+    response: dict | None = None
+    renderer: str | None = None
+    finished: float | None = None
+    phase: str | None = None
+    phase_started: float | None = None
+
+    def enter(self, phase: str | None, now: float | None = None) -> None:
+        """Record that the load reached a phase, None once it finished."""
+        if phase == self.phase and phase is not None:
+            return
+        now = time.monotonic() if now is None else now
+        if self.phase is not None:
+            spent = now - self.phase_started
+            self.phases[self.phase] = self.phases.get(self.phase, 0.0) + spent
+        self.phase = phase
+        self.phase_started = now
+        if phase is None:
+            self.finished = now
+
+    def respond(self, origin: str, content: str) -> None:
+        """Record the page that came back and where from."""
+        self.response = {
+            "origin": origin,
+            "bytes": len(content.encode("utf-8")),
+            "lines": content.count("\n") + 1,
+        }
+
+    def fail(self, error: Exception) -> None:
+        """Record the error the load ended with."""
+        self.response = {
+            "error": getattr(error, "kind", type(error).__name__),
+            "message": str(error),
+        }
+
+    def timings(self) -> list[tuple[str, float]]:
+        """Return the seconds each phase took, in load order.
+
+        Phases the load skipped, such as the path request for a node with
+        a known path, are left out.
+        """
+        return [
+            (phase, self.phases[phase]) for phase in LOAD_PHASES if phase in self.phases
+        ]
+
+    def total(self, now: float | None = None) -> float:
+        """Return the seconds the load took, or has taken so far."""
+        end = self.finished
+        if end is None:
+            end = time.monotonic() if now is None else now
+        return end - self.started
+
+
+def to_json(data: dict | None) -> str:
+    """Format a request or response as indented JSON."""
+    return json.dumps(data, indent=2, sort_keys=True, default=str)
@@ -0,0 +1,247 @@
+"""Petnames for Ren Browser.
+
+A petname is a name the user gives a destination, like ``alice``, that the
+address bar accepts in place of its hash as ``~alice`` or
+``~alice/page/index.mu``. Unlike announced names, petnames are chosen
+locally and cannot be claimed by someone else. Petnames are kept in the
+browser's storage directory and can be exported to a plain text naming
+file and imported from one, so a small community can share a set of names.
+"""
+
+import re
+from dataclasses import asdict, dataclass
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.pages.address import (
+    DEFAULT_PAGE_PATH,
+    URL_SCHEME,
+    AddressError,
+    is_destination_hash,
+)
+
+PETNAME_PREFIX = "~"
+MAX_PETNAME_LENGTH = 32
+FILE_HEADER = (
+    "# Ren Browser petnames\n"
+    "# One per line: name, destination hash, then an optional note.\n"
+)
+
+_NAME_RE = re.compile(r"^[a-z0-9][a-z0-9_.-]*$")
+# ``~name`` with an optional page path, written after a slash or a colon
+_ADDRESS_RE = re.compile(r"~([^:/\s]+):?(/\S*)?$")
+
+
+class PetnameFormatError(ValueError):
+    """Raised when a naming file cannot be read."""
+
+
+@dataclass
+class Petname:
+    """A local name for a destination, with a note about it."""
+
+    name: str
+    destination_hash: str
+    note: str = ""
+
+    @classmethod
+    def from_dict(cls, data) -> "Petname | None":
+        """Build a petname from stored data, or None if it is malformed."""
+        if not isinstance(data, dict):
+            return None
+        name, destination_hash = data.get("name"), data.get("destination_hash")
+        if not isinstance(name, str) or petname_error(name) is not None:
+            return None
+        if not isinstance(destination_hash, str):
+            return None
+        if not is_destination_hash(destination_hash):
+            return None
+        note = data.get("note")
+        return cls(
+            normalize_petname(name),
+            destination_hash.lower(),
+            note.strip() if isinstance(note, str) else "",
+        )
+
+
+def normalize_petname(name: str) -> str:
+    """Return a petname as it is stored and matched."""
+    return name.strip().removeprefix(PETNAME_PREFIX).lower()
+
+
+def petname_error(name: str) -> str | None:
+    """Return why a name cannot be a petname, or None if it can."""
+    name = normalize_petname(name)
+    if not name:
+        return tr("Enter a name")
+    if len(name) > MAX_PETNAME_LENGTH:
+        return tr("Use at most {count} characters", count=MAX_PETNAME_LENGTH)
+    if not _NAME_RE.match(name):
+        return tr("Use letters, digits, dots, dashes and underscores only")
+    if is_destination_hash(name):
+        return tr("A destination hash cannot be a petname")
+    return None
+
+
+def to_text(petnames: list[Petname]) -> str:
+    """Serialize petnames to a naming file."""
+    lines = [
+        f"{p.name} {p.destination_hash}" + (f" {p.note}" if p.note else "")
+        for p in petnames
+    ]
+    return FILE_HEADER + "".join(f"{line}\n" for line in lines)
+
+
+def from_text(text: str) -> list[Petname]:
+    """Read the petnames of a naming file.
+
+    Blank lines and lines starting with ``#`` are skipped, and so are lines
+    that are not a valid name followed by a destination hash.
+
+    Raises:
+        PetnameFormatError: If the file has lines but no petnames.
+
+    """
+    petnames = []
+    lines = 0
+    for line in text.splitlines():
+        line = line.strip()
+        if not line or line.startswith("#"):
+            continue
+        lines += 1
+        fields = line.split(maxsplit=2)
+        if len(fields) < 2:
+            continue
+        petname = Petname.from_dict(
+            {
+                "name": fields[0],
+                "destination_hash": fields[1],
+                "note": fields[2] if len(fields) > 2 else "",
+            },
+        )
+        if petname is not None:
+            petnames.append(petname)
+    if lines and not petnames:
+        raise PetnameFormatError(tr("The file does not contain any petnames."))
+    return petnames
+
+
+class PetnameDirectory:
+    """Petnames of destinations, persisted through storage."""
+
+    def __init__(self, storage):
+        """Load the petnames.
+
+        Args:
+            storage: StorageManager used to load and save the petnames.
+
+        """
+        self.storage = storage
+        self._petnames: dict[str, Petname] = {}
+        try:
+            stored = storage.load_petnames()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            petname = Petname.from_dict(data)
+            if petname is not None:
+                self._petnames.setdefault(petname.name, petname)
+
+    def __len__(self) -> int:
+        """Return how many petnames there are."""
+        return len(self._petnames)
+
+    def petnames(self) -> list[Petname]:
+        """Return the petnames in alphabetical order."""
+        return sorted(self._petnames.values(), key=lambda p: p.name)
+
+    def get(self, name: str) -> Petname | None:
+        """Return the petname with a name, if there is one."""
+        return self._petnames.get(normalize_petname(name))
+
+    def resolve(self, name: str) -> str | None:
+        """Return the destination hash a petname stands for."""
+        petname = self.get(name)
+        return petname.destination_hash if petname is not None else None
+
+    def name_for(self, destination_hash: str) -> str | None:
+        """Return the first petname given to a destination, if any."""
+        destination_hash = destination_hash.lower()
+        return next(
+            (
+                p.name
+                for p in self.petnames()
+                if p.destination_hash == destination_hash
+            ),
+            None,
+        )
+
+    def set(self, name: str, destination_hash: str, note: str = "") -> Petname:
+        """Give a destination a petname, replacing any with the same name.
+
+        Raises:
+            ValueError: If the name or destination hash is not valid.
+
+        """
+        error = petname_error(name)
+        if error is not None:
+            raise ValueError(error)
+        destination_hash = destination_hash.strip().lower()
+        if not is_destination_hash(destination_hash):
+            raise ValueError(tr("Enter a 32 character destination hash"))
+        petname = Petname(normalize_petname(name), destination_hash, note.strip())
+        self._petnames[petname.name] = petname
+        self._save()
+        return petname
+
+    def remove(self, name: str) -> None:
+        """Delete a petname."""
+        if self._petnames.pop(normalize_petname(name), None) is not None:
+            self._save()
+
+    def merge(self, petnames: list[Petname]) -> tuple[int, int]:
+        """Add imported petnames, keeping the local ones on a clash.
+
+        Returns:
+            How many were added, and how many were left out because the
+            name already stands for another destination.
+
+        """
+        added = conflicts = 0
+        for petname in petnames:
+            existing = self._petnames.get(petname.name)
+            if existing is None:
+                self._petnames[petname.name] = petname
+                added += 1
+            elif existing.destination_hash != petname.destination_hash:
+                conflicts += 1
+        if added:
+            self._save()
+        return added, conflicts
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_petnames([asdict(p) for p in self.petnames()])
+        except Exception:  # noqa: BLE001
+            pass
+
+
+def expand_petname(text: str, directory: PetnameDirectory) -> str:
+    """Replace a ``~name`` at the start of an address with its hash.
+
+    Text that does not start with a petname is returned unchanged.
+
+    Raises:
+        AddressError: If the petname is not in the directory.
+
+    """
+    stripped = text.strip()
+    if stripped.lower().startswith(URL_SCHEME):
+        stripped = stripped[len(URL_SCHEME) :]
+    match = _ADDRESS_RE.match(stripped)
+    if match is None:
+        return text
+    name, path = match.groups()
+    destination_hash = directory.resolve(name)
+    if destination_hash is None:
+        raise AddressError(tr("There is no petname ~{name}.", name=name))
+    return f"{destination_hash}:{path or DEFAULT_PAGE_PATH}"
@@ -0,0 +1,218 @@
+"""Plugins for Ren Browser.
+
+Plugins let protocol experiments and page formats live outside the
+browser. A plugin is a Python module with a ``register(plugin)`` function,
+either a ``.py`` file in the plugins folder of the storage directory or a
+package installed with a ``ren_browser.plugins`` entry point. Through the
+object it is given it can add:
+
+- transforms, ``transform(content, page_path) -> str``, run on every page
+  before it is rendered;
+- renderers, ``render(content, page_path, on_link_click) -> ft.Control``,
+  used for pages whose path ends in a given extension;
+- sidebar panels, ``build(tab_manager) -> ft.Control``, shown as extra tabs
+  next to the announce list.
+
+Plugins run with the browser's own rights, so none are loaded unless they
+are turned on under Settings → Plugins. A plugin that fails to load, or
+whose register raises, adds nothing and is listed with its error.
+"""
+
+import importlib.util
+import logging
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from importlib.metadata import entry_points
+from pathlib import Path
+
+import flet as ft
+
+ENTRY_POINT_GROUP = "ren_browser.plugins"
+logger = logging.getLogger(__name__)
+
+Transform = Callable[[str, str], str]
+Renderer = Callable[[str, str, Callable[[str], None]], ft.Control]
+PanelBuilder = Callable[[object], ft.Control]
+
+
+@dataclass
+class PluginInfo:
+    """A plugin that was found and what came of loading it."""
+
+    name: str
+    source: str
+    error: str | None = None
+    # Descriptions of the hooks it added, such as "renderer for .gmi"
+    hooks: list[str] = field(default_factory=list)
+
+
+class Plugin:
+    """What a plugin's register function is given to add its hooks with."""
+
+    def __init__(self, name: str):
+        """Start collecting the hooks of a plugin.
+
+        Args:
+            name: Name the plugin is listed under.
+
+        """
+        self.name = name
+        self.transforms: list[Transform] = []
+        self.renderers: dict[str, Renderer] = {}
+        self.panels: list[tuple[str, PanelBuilder]] = []
+
+    def add_transform(self, transform: Transform) -> None:
+        """Change the source of every page before it is rendered."""
+        self.transforms.append(transform)
+
+    def add_renderer(self, extension: str, render: Renderer) -> None:
+        """Render pages whose path ends in extension, such as ".gmi"."""
+        extension = extension.lower()
+        if not extension.startswith("."):
+            extension = f".{extension}"
+        self.renderers[extension] = render
+
+    def add_panel(self, title: str, build: PanelBuilder) -> None:
+        """Add a tab to the sidebar, built once the browser has started."""
+        self.panels.append((title, build))
+
+
+class PluginRegistry:
+    """The plugins loaded and the hooks they added."""
+
+    def __init__(self):
+        """Start with no plugins."""
+        self.plugins: list[PluginInfo] = []
+        self.transforms: list[tuple[str, Transform]] = []
+        self.renderers: dict[str, tuple[str, Renderer]] = {}
+        self.panels: list[tuple[str, str, PanelBuilder]] = []
+
+    def load(self, name: str, source: str, register: Callable[[], object]):
+        """Load one plugin, recording its error if it fails.
+
+        Args:
+            name: Name the plugin is listed under.
+            source: Where the plugin came from, shown in settings.
+            register: Imports the plugin, returning its module or its
+                register function.
+
+        Returns:
+            PluginInfo: The plugin as listed in settings.
+
+        """
+        info = PluginInfo(name, source)
+        self.plugins.append(info)
+        plugin = Plugin(name)
+        try:
+            loaded = register()
+            getattr(loaded, "register", loaded)(plugin)
+        except Exception as exc:  # noqa: BLE001
+            info.error = f"{type(exc).__name__}: {exc}"
+            logger.warning("Plugin %s failed to load: %s", name, info.error)
+            return info
+        self.transforms.extend((name, transform) for transform in plugin.transforms)
+        for extension, render in plugin.renderers.items():
+            self.renderers[extension] = (name, render)
+        self.panels.extend((name, title, build) for title, build in plugin.panels)
+        info.hooks = [
+            *(["page transform"] * len(plugin.transforms)),
+            *(f"renderer for {extension}" for extension in plugin.renderers),
+            *(f"sidebar panel {title!r}" for title, _ in plugin.panels),
+        ]
+        return info
+
+    def transform(self, content: str, page_path: str) -> str:
+        """Run a page through every transform, skipping ones that fail."""
+        for name, transform in self.transforms:
+            try:
+                content = transform(content, page_path)
+            except Exception:  # noqa: BLE001
+                logger.exception("Plugin %s failed to transform %s", name, page_path)
+        return content
+
+    def renderer_for(self, page_path: str) -> tuple[str, Renderer] | None:
+        """Return the plugin renderer and its plugin's name for a page, if any."""
+        lower = page_path.lower()
+        for extension, found in self.renderers.items():
+            if lower.endswith(extension):
+                return found
+        return None
+
+    def render(
+        self,
+        content: str,
+        page_path: str,
+        on_link_click: Callable[[str], None],
+    ) -> tuple[str, ft.Control] | None:
+        """Render a page with a plugin renderer.
+
+        Args:
+            content: Page source, already transformed.
+            page_path: Path of the page, whose extension picks the renderer.
+            on_link_click: Called with the target of a link that was clicked.
+
+        Returns:
+            tuple[str, ft.Control] | None: The name of the plugin and the
+            page, or None if no plugin renders the page or its renderer failed.
+
+        """
+        found = self.renderer_for(page_path)
+        if found is None:
+            return None
+        name, render = found
+        try:
+            return name, render(content, page_path, on_link_click)
+        except Exception:  # noqa: BLE001
+            logger.exception("Plugin %s failed to render %s", name, page_path)
+            return None
+
+    def build_panels(self, tab_manager) -> list[tuple[str, ft.Control]]:
+        """Build the sidebar panels, leaving out ones that fail to build."""
+        panels = []
+        for name, title, build in self.panels:
+            try:
+                panels.append((title, build(tab_manager)))
+            except Exception:  # noqa: BLE001
+                logger.exception("Plugin %s failed to build panel %s", name, title)
+        return panels
+
+
+def _import_file(path: Path):
+    name = f"ren_browser_plugin_{path.stem}"
+    spec = importlib.util.spec_from_file_location(name, path)
+    if spec is None or spec.loader is None:
+        raise ImportError(f"Cannot import {path}")
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+# Registry of the last load, listed in settings
+_loaded: PluginRegistry | None = None
+
+
+def load_plugins(directory: Path | None) -> PluginRegistry:
+    """Load the installed plugins and those in a folder.
+
+    Args:
+        directory: Folder of ``.py`` plugin files, None to load only
+            installed ones.
+
+    Returns:
+        PluginRegistry: The plugins found and the hooks they added.
+
+    """
+    global _loaded
+    registry = PluginRegistry()
+    _loaded = registry
+    for entry_point in entry_points(group=ENTRY_POINT_GROUP):
+        registry.load(entry_point.name, entry_point.value, entry_point.load)
+    if directory is not None and directory.is_dir():
+        for path in sorted(directory.glob("*.py")):
+            registry.load(path.stem, str(path), lambda path=path: _import_file(path))
+    return registry
+
+
+def loaded_plugins() -> list[PluginInfo]:
+    """Return the plugins found when plugins were last loaded."""
+    return list(_loaded.plugins) if _loaded is not None else []
@@ -0,0 +1,241 @@
+"""Battery awareness for Ren Browser.
+
+Reads whether the device runs on battery and how charged it is, and
+decides from the power settings whether the browser should save power.
+While it does, the node list and the interface status are refreshed less
+often and linked pages are not prefetched. All of it is configured in the
+Power section of the settings.
+"""
+
+import asyncio
+import ctypes
+import re
+import shutil
+import subprocess
+import sys
+from dataclasses import dataclass
+from pathlib import Path
+
+from ren_browser.i18n.i18n import tr
+
+POWER_SUPPLY_DIR = Path("/sys/class/power_supply")
+CHECK_SECONDS = 60
+SAVER_LEVELS = {
+    100: "At any battery level",
+    50: "At 50% battery or less",
+    30: "At 30% battery or less",
+    15: "At 15% battery or less",
+}
+INTERVAL_FACTORS = {
+    2: "Twice as long",
+    3: "Three times as long",
+    5: "Five times as long",
+    10: "Ten times as long",
+}
+DEFAULT_SAVER_LEVEL = 100
+DEFAULT_INTERVAL_FACTOR = 3
+
+_PMSET_PERCENT_RE = re.compile(r"(\d+)%")
+
+
+@dataclass(frozen=True)
+class BatteryStatus:
+    """Whether the device runs on its battery, and how charged it is."""
+
+    on_battery: bool
+    percent: int | None
+
+
+def _read(path: Path) -> str:
+    try:
+        return path.read_text(encoding="ascii").strip()
+    except (OSError, UnicodeDecodeError):
+        return ""
+
+
+def _sysfs_status(root: Path) -> BatteryStatus | None:
+    # Linux and Android list each battery and charger under power_supply
+    try:
+        supplies = sorted(root.iterdir())
+    except OSError:
+        return None
+    batteries = []
+    external = False
+    for supply in supplies:
+        kind = _read(supply / "type")
+        if kind == "Battery":
+            batteries.append(supply)
+        elif kind and _read(supply / "online") == "1":
+            external = True
+    if not batteries:
+        return None
+    discharging = any(_read(b / "status") == "Discharging" for b in batteries)
+    capacity = _read(batteries[0] / "capacity")
+    return BatteryStatus(
+        on_battery=discharging and not external,
+        percent=int(capacity) if capacity.isdigit() else None,
+    )
+
+
+def _pmset_status() -> BatteryStatus | None:
+    command = shutil.which("pmset")
+    if command is None:
+        return None
+    try:
+        output = subprocess.run(
+            [command, "-g", "batt"],
+            capture_output=True,
+            text=True,
+            timeout=5,
+            check=True,
+        ).stdout
+    except (OSError, subprocess.SubprocessError):
+        return None
+    match = _PMSET_PERCENT_RE.search(output)
+    if match is None:
+        return None
+    return BatteryStatus("'Battery Power'" in output, int(match.group(1)))
+
+
+class _SystemPowerStatus(ctypes.Structure):
+    _fields_ = [
+        ("ACLineStatus", ctypes.c_ubyte),
+        ("BatteryFlag", ctypes.c_ubyte),
+        ("BatteryLifePercent", ctypes.c_ubyte),
+        ("SystemStatusFlag", ctypes.c_ubyte),
+        ("BatteryLifeTime", ctypes.c_ulong),
+        ("BatteryFullLifeTime", ctypes.c_ulong),
+    ]
+
+
+def _windows_status() -> BatteryStatus | None:
+    status = _SystemPowerStatus()
+    if not ctypes.windll.kernel32.GetSystemPowerStatus(ctypes.byref(status)):
+        return None
+    # 128 means there is no battery, 255 that the state is unknown
+    if status.BatteryFlag in (128, 255):
+        return None
+    percent = status.BatteryLifePercent
+    return BatteryStatus(status.ACLineStatus == 0, None if percent == 255 else percent)
+
+
+def battery_status() -> BatteryStatus | None:
+    """Return the battery state, None without a battery or when unknown."""
+    try:
+        if sys.platform == "win32":
+            return _windows_status()
+        if sys.platform == "darwin":
+            return _pmset_status()
+        return _sysfs_status(POWER_SUPPLY_DIR)
+    except (OSError, ValueError, AttributeError):
+        return None
+
+
+def saver_level(settings: dict) -> int:
+    """Return the battery percentage at or below which power is saved."""
+    level = settings.get("battery_saver_percent", DEFAULT_SAVER_LEVEL)
+    return level if level in SAVER_LEVELS else DEFAULT_SAVER_LEVEL
+
+
+def interval_factor(settings: dict) -> int:
+    """Return how many times longer refresh intervals are while saving power."""
+    factor = settings.get("battery_interval_factor", DEFAULT_INTERVAL_FACTOR)
+    return factor if factor in INTERVAL_FACTORS else DEFAULT_INTERVAL_FACTOR
+
+
+def saving_power(status: BatteryStatus | None, settings: dict) -> bool:
+    """Return whether to save power for a battery state and the settings."""
+    if status is None or not status.on_battery:
+        return False
+    if not settings.get("battery_saver", True):
+        return False
+    return status.percent is None or status.percent <= saver_level(settings)
+
+
+def describe_status(status: BatteryStatus | None) -> str:
+    """Describe a battery state for the settings."""
+    if status is None:
+        return tr("No battery found")
+    if not status.on_battery:
+        return tr("Plugged in")
+    if status.percent is None:
+        return tr("On battery")
+    return tr("On battery, {percent}%", percent=status.percent)
+
+
+class PowerMonitor:
+    """Checks the battery now and then and saves power while on it."""
+
+    def __init__(self, page, tab_manager, read_status=battery_status):
+        """Prepare the monitor.
+
+        Args:
+            page: Flet page the background check runs on.
+            tab_manager: Tab manager whose refreshing is slowed down.
+            read_status: Returns the current BatteryStatus.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.read_status = read_status
+        self.status: BatteryStatus | None = None
+        self.saving = False
+        self.factor = 1
+        self._loop_id = None
+
+    def start(self) -> None:
+        """Check the battery now and every CHECK_SECONDS."""
+        loop_id = object()
+        self._loop_id = loop_id
+
+        async def check_loop():
+            while self._loop_id is loop_id:
+                if not self.tab_manager.suspended:
+                    status = await asyncio.to_thread(self.read_status)
+                    if self._loop_id is not loop_id:
+                        return
+                    self.apply(status)
+                await asyncio.sleep(CHECK_SECONDS)
+
+        self.page.run_task(check_loop)
+
+    def stop(self) -> None:
+        """Stop checking the battery."""
+        self._loop_id = None
+
+    def check(self) -> None:
+        """Read the battery state and apply it."""
+        self.apply(self.read_status())
+
+    def apply(self, status: BatteryStatus | None) -> None:
+        """Save power or stop saving it for a battery state.
+
+        Called again after the power settings changed, it picks up the new
+        thresholds.
+        """
+        self.status = status
+        settings = self.tab_manager.settings
+        saving = saving_power(status, settings)
+        factor = interval_factor(settings) if saving else 1
+        self.tab_manager.prefetch_paused = saving and settings.get(
+            "battery_pause_prefetch",
+            True,
+        )
+        if self.tab_manager.prefetch_paused:
+            self.tab_manager.prefetcher.cancel()
+        if (saving, factor) == (self.saving, self.factor):
+            return
+        self.saving = saving
+        self.factor = factor
+        # An idle window starts refreshing with the new factor on focus
+        restart = not self.tab_manager.idle
+        announce_list = self.tab_manager.announce_list
+        if announce_list is not None:
+            announce_list.interval_factor = factor
+            if restart and announce_list.refresh_interval is not None:
+                announce_list.start_refresh()
+        status_bar = self.tab_manager.status_bar
+        if status_bar is not None:
+            status_bar.interval_factor = factor
+            if restart and status_bar.poll_interval is not None:
+                status_bar.start()
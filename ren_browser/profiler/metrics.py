@@ -0,0 +1,168 @@
+"""Metrics endpoint for Ren Browser.
+
+Serves counters and gauges about page fetches, the page cache and resource
+use in the Prometheus text format on a local port, so long-running installs
+can be scraped and graphed. The endpoint is off unless a port is given on
+the command line, and only listens on the loopback interface.
+"""
+
+import threading
+from collections.abc import Callable
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+from ren_browser.downloads.downloads import ACTIVE
+from ren_browser.profiler.profiler import memory_usage
+
+CONTENT_TYPE = "text/plain; version=0.0.4; charset=utf-8"
+PREFIX = "ren_browser_"
+
+
+def _metric(name: str, kind: str, help_text: str, value) -> list[str]:
+    return [
+        f"# HELP {PREFIX}{name} {help_text}",
+        f"# TYPE {PREFIX}{name} {kind}",
+        f"{PREFIX}{name} {value}",
+    ]
+
+
+def metrics_text(tab_manager) -> str:
+    """Return the browser's metrics in the Prometheus text format."""
+    monitor = tab_manager.performance
+    cache = tab_manager.page_cache
+    rate, _ = monitor.update_rate()
+    lines = [
+        *_metric(
+            "page_fetches_total",
+            "counter",
+            "Pages fetched from the network.",
+            monitor.fetches,
+        ),
+        *_metric(
+            "page_fetch_failures_total",
+            "counter",
+            "Page fetches that failed.",
+            monitor.fetch_failures,
+        ),
+        f"# HELP {PREFIX}page_fetch_seconds Time taken by page fetches.",
+        f"# TYPE {PREFIX}page_fetch_seconds summary",
+        f"{PREFIX}page_fetch_seconds_sum {monitor.fetch_seconds}",
+        f"{PREFIX}page_fetch_seconds_count {monitor.fetches}",
+        *_metric(
+            "cache_hits_total",
+            "counter",
+            "Page loads served from the cache.",
+            cache.hits,
+        ),
+        *_metric(
+            "cache_misses_total",
+            "counter",
+            "Page loads the cache could not serve.",
+            cache.misses,
+        ),
+        *_metric("cache_pages", "gauge", "Pages cached in memory.", len(cache)),
+        *_metric(
+            "cache_bytes",
+            "gauge",
+            "Bytes of pages cached in memory.",
+            cache.size(),
+        ),
+    ]
+    if cache.disk is not None:
+        lines += _metric(
+            "disk_cache_bytes",
+            "gauge",
+            "Bytes of pages cached on disk.",
+            cache.disk.size(),
+        )
+    lines += [
+        *_metric("tabs", "gauge", "Open tabs.", len(tab_manager.manager.tabs)),
+        *_metric(
+            "downloads_active",
+            "gauge",
+            "Downloads in progress.",
+            sum(
+                1
+                for download in tab_manager.downloads.downloads()
+                if download.status in ACTIVE
+            ),
+        ),
+        *_metric(
+            "prefetch_queued",
+            "gauge",
+            "Linked pages waiting to be prefetched.",
+            tab_manager.prefetcher.pending(),
+        ),
+        *_metric(
+            "page_updates_per_second",
+            "gauge",
+            "Page updates per second, when timed by the performance overlay.",
+            rate,
+        ),
+    ]
+    memory = memory_usage()
+    if memory is not None:
+        lines += _metric("memory_bytes", "gauge", "Memory the browser uses.", memory)
+    return "\n".join(lines) + "\n"
+
+
+class MetricsServer:
+    """Serves metrics over HTTP on a local port."""
+
+    def __init__(self, port: int, render: Callable[[], str]):
+        """Prepare the server.
+
+        Args:
+            port: Port to listen on, 0 for any free one.
+            render: Returns the metrics text, called for each scrape.
+
+        """
+        self.requested_port = port
+        self.render = render
+        self._server: ThreadingHTTPServer | None = None
+
+    @property
+    def port(self) -> int | None:
+        """Return the port being listened on, or None before start."""
+        return self._server.server_address[1] if self._server else None
+
+    def start(self) -> bool:
+        """Start listening on the loopback interface.
+
+        Returns:
+            bool: Whether the server is running.
+
+        """
+        render = self.render
+
+        class Handler(BaseHTTPRequestHandler):
+            def do_GET(self):
+                if self.path.split("?", 1)[0] not in ("/", "/metrics"):
+                    self.send_error(404)
+                    return
+                body = render().encode("utf-8")
+                self.send_response(200)
+                self.send_header("Content-Type", CONTENT_TYPE)
+                self.send_header("Content-Length", str(len(body)))
+                self.end_headers()
+                self.wfile.write(body)
+
+            def log_message(self, format, *args):  # noqa: A002
+                pass
+
+        try:
+            self._server = ThreadingHTTPServer(
+                ("127.0.0.1", self.requested_port),
+                Handler,
+            )
+        except OSError:
+            return False
+        self._server.daemon_threads = True
+        threading.Thread(target=self._server.serve_forever, daemon=True).start()
+        return True
+
+    def stop(self) -> None:
+        """Stop listening."""
+        server, self._server = self._server, None
+        if server is not None:
+            server.shutdown()
+            server.server_close()
@@ -1,6 +1,112 @@
 """Performance profiler for Ren Browser.
 
 Provides profiling capabilities for monitoring browser performance
-and resource usage.
+and resource usage. Times page updates and fetches, and reads the memory
+the browser uses, for the performance overlay and the metrics endpoint.
 """
-# Add a profiler to the browser.
+
+import os
+import sys
+import threading
+import time
+from collections import deque
+from dataclasses import dataclass
+
+# Seconds of page updates the update rate and latency are averaged over.
+UPDATE_WINDOW = 5.0
+
+
+@dataclass
+class FetchTiming:
+    """How long the last page fetch took."""
+
+    address: str
+    seconds: float
+    finished_at: float
+
+
+def memory_usage() -> int | None:
+    """Return the memory the browser process uses in bytes, None if unknown.
+
+    The resident size is read on Linux. Elsewhere the peak resident size is
+    used where the platform reports it.
+    """
+    try:
+        with open("/proc/self/statm", encoding="ascii") as statm:
+            pages = int(statm.read().split()[1])
+        return pages * os.sysconf("SC_PAGE_SIZE")
+    except (OSError, ValueError, IndexError, AttributeError):
+        pass
+    try:
+        import resource
+    except ImportError:
+        return None
+    peak = resource.getrusage(resource.RUSAGE_SELF).ru_maxrss
+    # macOS reports bytes, other systems kilobytes
+    return peak if sys.platform == "darwin" else peak * 1024
+
+
+class PerformanceMonitor:
+    """Collects page update and fetch timings."""
+
+    def __init__(self):
+        """Initialize a monitor with no timings."""
+        self.last_fetch: FetchTiming | None = None
+        self.fetches = 0
+        self.fetch_failures = 0
+        self.fetch_seconds = 0.0
+        self._updates: deque[tuple[float, float]] = deque()
+        self._lock = threading.Lock()
+
+    def record_update(self, seconds: float, now: float | None = None) -> None:
+        """Record how long a page update took."""
+        now = time.monotonic() if now is None else now
+        with self._lock:
+            self._updates.append((now, seconds))
+            self._trim(now)
+
+    def record_fetch(self, address: str, seconds: float) -> None:
+        """Record how long fetching a page took."""
+        self.last_fetch = FetchTiming(address, seconds, time.time())
+        with self._lock:
+            self.fetches += 1
+            self.fetch_seconds += seconds
+
+    def record_fetch_failure(self) -> None:
+        """Record a page fetch that failed."""
+        with self._lock:
+            self.fetch_failures += 1
+
+    def update_rate(self, now: float | None = None) -> tuple[float, float | None]:
+        """Return the page updates per second and their average duration.
+
+        Returns:
+            Updates per second over the last ``UPDATE_WINDOW`` seconds, and
+            their average duration in seconds, None if there were none.
+
+        """
+        now = time.monotonic() if now is None else now
+        with self._lock:
+            self._trim(now)
+            durations = [seconds for _, seconds in self._updates]
+        average = sum(durations) / len(durations) if durations else None
+        return len(durations) / UPDATE_WINDOW, average
+
+    def _trim(self, now: float) -> None:
+        # Called with the lock held
+        while self._updates and self._updates[0][0] < now - UPDATE_WINDOW:
+            self._updates.popleft()
+
+
+def watch_updates(page, monitor: PerformanceMonitor) -> None:
+    """Time every update of a Flet page with a monitor."""
+    update = page.update
+
+    def timed_update(*controls):
+        started = time.perf_counter()
+        try:
+            return update(*controls)
+        finally:
+            monitor.record_update(time.perf_counter() - started)
+
+    page.update = timed_update
@@ -0,0 +1,606 @@
+"""QR codes for Ren Browser.
+
+A small QR code encoder so addresses can be handed to a phone without
+adding an image library. Text is encoded in byte mode at error correction
+level M, in the smallest version from 1 to 10 that fits, which is plenty
+for a ``ren://`` address.
+
+The decoder reads codes of versions 1 to 10 at any error correction level,
+correcting damaged codewords, so codes made by other apps can be scanned.
+"""
+
+import struct
+import zlib
+
+# Error correction codewords per block, then (blocks, data codewords per
+# block) for each group, at level M.
+_BLOCKS = {
+    1: (10, ((1, 16),)),
+    2: (16, ((1, 28),)),
+    3: (26, ((1, 44),)),
+    4: (18, ((2, 32),)),
+    5: (24, ((2, 43),)),
+    6: (16, ((4, 27),)),
+    7: (18, ((4, 31),)),
+    8: (22, ((2, 38), (2, 39))),
+    9: (22, ((3, 36), (2, 37))),
+    10: (26, ((4, 43), (1, 44))),
+}
+_ALIGNMENT = {
+    1: (),
+    2: (6, 18),
+    3: (6, 22),
+    4: (6, 26),
+    5: (6, 30),
+    6: (6, 34),
+    7: (6, 22, 38),
+    8: (6, 24, 42),
+    9: (6, 26, 46),
+    10: (6, 28, 50),
+}
+# The same for the other levels, keyed by their format bits.
+_LEVEL_BLOCKS = {
+    0b01: {
+        1: (7, ((1, 19),)),
+        2: (10, ((1, 34),)),
+        3: (15, ((1, 55),)),
+        4: (20, ((1, 80),)),
+        5: (26, ((1, 108),)),
+        6: (18, ((2, 68),)),
+        7: (20, ((2, 78),)),
+        8: (24, ((2, 97),)),
+        9: (30, ((2, 116),)),
+        10: (18, ((2, 68), (2, 69))),
+    },
+    0b00: _BLOCKS,
+    0b11: {
+        1: (13, ((1, 13),)),
+        2: (22, ((1, 22),)),
+        3: (18, ((2, 17),)),
+        4: (26, ((2, 24),)),
+        5: (18, ((2, 15), (2, 16))),
+        6: (24, ((4, 19),)),
+        7: (18, ((2, 14), (4, 15))),
+        8: (22, ((4, 18), (2, 19))),
+        9: (20, ((4, 16), (4, 17))),
+        10: (24, ((6, 19), (2, 20))),
+    },
+    0b10: {
+        1: (17, ((1, 9),)),
+        2: (28, ((1, 16),)),
+        3: (22, ((2, 13),)),
+        4: (16, ((4, 9),)),
+        5: (22, ((2, 11), (2, 12))),
+        6: (28, ((4, 15),)),
+        7: (26, ((4, 13), (1, 14))),
+        8: (26, ((4, 14), (2, 15))),
+        9: (24, ((4, 12), (4, 13))),
+        10: (28, ((6, 15), (2, 16))),
+    },
+}
+_LEVEL_M = 0b00
+_ALPHANUMERIC = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:"
+_PAD_BYTES = (0xEC, 0x11)
+_MASKS = (
+    lambda x, y: (x + y) % 2 == 0,
+    lambda x, y: y % 2 == 0,
+    lambda x, y: x % 3 == 0,
+    lambda x, y: (x + y) % 3 == 0,
+    lambda x, y: (x // 3 + y // 2) % 2 == 0,
+    lambda x, y: x * y % 2 + x * y % 3 == 0,
+    lambda x, y: (x * y % 2 + x * y % 3) % 2 == 0,
+    lambda x, y: ((x + y) % 2 + x * y % 3) % 2 == 0,
+)
+_FINDER_LIKE = (
+    (True, False, True, True, True, False, True, False, False, False, False),
+    (False, False, False, False, True, False, True, True, True, False, True),
+)
+
+
+def _data_capacity(version: int) -> int:
+    _, groups = _BLOCKS[version]
+    return sum(count * size for count, size in groups)
+
+
+def _gf_multiply(a: int, b: int) -> int:
+    result = 0
+    for i in range(7, -1, -1):
+        result = (result << 1) ^ ((result >> 7) * 0x11D)
+        if (b >> i) & 1:
+            result ^= a
+    return result & 0xFF
+
+
+def _rs_divisor(degree: int) -> list[int]:
+    divisor = [0] * (degree - 1) + [1]
+    root = 1
+    for _ in range(degree):
+        for j in range(degree):
+            divisor[j] = _gf_multiply(divisor[j], root)
+            if j + 1 < degree:
+                divisor[j] ^= divisor[j + 1]
+        root = _gf_multiply(root, 0x02)
+    return divisor
+
+
+def error_correction(data: list[int], degree: int) -> list[int]:
+    """Return the Reed-Solomon error correction codewords for a block."""
+    divisor = _rs_divisor(degree)
+    result = [0] * degree
+    for byte in data:
+        factor = byte ^ result.pop(0)
+        result.append(0)
+        for i, coefficient in enumerate(divisor):
+            result[i] ^= _gf_multiply(coefficient, factor)
+    return result
+
+
+def format_bits(mask: int, level: int = _LEVEL_M) -> int:
+    """Return the 15 format information bits for a mask, at level M by default."""
+    data = level << 3 | mask
+    remainder = data
+    for _ in range(10):
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537)
+    return (data << 10 | remainder) ^ 0x5412
+
+
+def _gf_power(exponent: int) -> int:
+    result = 1
+    for _ in range(exponent % 255):
+        result = _gf_multiply(result, 0x02)
+    return result
+
+
+def _gf_inverse(a: int) -> int:
+    # The multiplicative group has 255 elements, so a to the 254 is 1 / a
+    result = 1
+    for _ in range(254):
+        result = _gf_multiply(result, a)
+    return result
+
+
+def _poly_eval(coefficients: list[int], x: int) -> int:
+    # Coefficients from the lowest power up
+    result = 0
+    for coefficient in reversed(coefficients):
+        result = _gf_multiply(result, x) ^ coefficient
+    return result
+
+
+def correct_errors(block: list[int], degree: int) -> list[int]:
+    """Correct a Reed-Solomon block of data followed by its correction codewords.
+
+    Args:
+        block: Data and error correction codewords as read from a code.
+        degree: Number of error correction codewords at the end.
+
+    Returns:
+        The block with up to degree / 2 wrong codewords corrected.
+
+    Raises:
+        ValueError: If the block has more errors than can be corrected.
+
+    """
+    syndromes = []
+    for j in range(degree):
+        root = _gf_power(j)
+        value = 0
+        for byte in block:
+            value = _gf_multiply(value, root) ^ byte
+        syndromes.append(value)
+    if not any(syndromes):
+        return list(block)
+
+    # Berlekamp-Massey finds the error locator polynomial
+    locator, previous = [1], [1]
+    errors, shift, last = 0, 1, 1
+    for n in range(degree):
+        discrepancy = syndromes[n]
+        for i in range(1, errors + 1):
+            if i < len(locator):
+                discrepancy ^= _gf_multiply(locator[i], syndromes[n - i])
+        if discrepancy == 0:
+            shift += 1
+            continue
+        factor = _gf_multiply(discrepancy, _gf_inverse(last))
+        update = [0] * shift + [_gf_multiply(factor, c) for c in previous]
+        saved = list(locator)
+        locator += [0] * (len(update) - len(locator))
+        for i, coefficient in enumerate(update):
+            locator[i] ^= coefficient
+        if 2 * errors <= n:
+            errors, previous, last, shift = n + 1 - errors, saved, discrepancy, 1
+        else:
+            shift += 1
+    locator = locator[: errors + 1]
+    if errors * 2 > degree:
+        raise ValueError("The QR code is too damaged to read.")
+
+    # Each root of the locator marks a wrong codeword, Forney gives its value
+    evaluator = [0] * degree
+    for i, s in enumerate(syndromes):
+        for j, coefficient in enumerate(locator):
+            if i + j < degree:
+                evaluator[i + j] ^= _gf_multiply(s, coefficient)
+    derivative = [locator[i] if i % 2 == 1 else 0 for i in range(1, len(locator))]
+    corrected = list(block)
+    found = 0
+    for index in range(len(block)):
+        position = len(block) - 1 - index
+        x = _gf_power(position)
+        x_inverse = _gf_power(255 - position)
+        if _poly_eval(locator, x_inverse) != 0:
+            continue
+        denominator = _poly_eval(derivative, x_inverse)
+        if denominator == 0:
+            raise ValueError("The QR code is too damaged to read.")
+        magnitude = _gf_multiply(
+            _gf_multiply(x, _poly_eval(evaluator, x_inverse)),
+            _gf_inverse(denominator),
+        )
+        corrected[index] ^= magnitude
+        found += 1
+    if found != errors:
+        raise ValueError("The QR code is too damaged to read.")
+    return corrected
+
+
+def version_bits(version: int) -> int:
+    """Return the 18 version information bits, used from version 7 up."""
+    remainder = version
+    for _ in range(12):
+        remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25)
+    return version << 12 | remainder
+
+
+def _codewords(data: bytes, version: int) -> list[int]:
+    count_bits = 8 if version < 10 else 16
+    bits = [0, 1, 0, 0]
+    bits += [(len(data) >> i) & 1 for i in range(count_bits - 1, -1, -1)]
+    for byte in data:
+        bits += [(byte >> i) & 1 for i in range(7, -1, -1)]
+    capacity = _data_capacity(version) * 8
+    bits += [0] * min(4, capacity - len(bits))
+    bits += [0] * (-len(bits) % 8)
+    codewords = [
+        int("".join(map(str, bits[i : i + 8])), 2) for i in range(0, len(bits), 8)
+    ]
+    for i in range(_data_capacity(version) - len(codewords)):
+        codewords.append(_PAD_BYTES[i % 2])
+
+    degree, groups = _BLOCKS[version]
+    blocks = []
+    start = 0
+    for count, size in groups:
+        for _ in range(count):
+            blocks.append(codewords[start : start + size])
+            start += size
+    corrections = [error_correction(block, degree) for block in blocks]
+    result = []
+    for i in range(max(len(block) for block in blocks)):
+        result.extend(block[i] for block in blocks if i < len(block))
+    for i in range(degree):
+        result.extend(correction[i] for correction in corrections)
+    return result
+
+
+class _Matrix:
+    def __init__(self, version: int):
+        self.version = version
+        self.size = version * 4 + 17
+        self.modules = [[False] * self.size for _ in range(self.size)]
+        self.reserved = [[False] * self.size for _ in range(self.size)]
+
+    def set_function(self, x: int, y: int, dark: bool) -> None:
+        self.modules[y][x] = dark
+        self.reserved[y][x] = True
+
+    def draw_function_patterns(self) -> None:
+        size = self.size
+        for i in range(size):
+            self.set_function(6, i, i % 2 == 0)
+            self.set_function(i, 6, i % 2 == 0)
+        for cx, cy in ((3, 3), (size - 4, 3), (3, size - 4)):
+            for dy in range(-4, 5):
+                for dx in range(-4, 5):
+                    x, y = cx + dx, cy + dy
+                    if 0 <= x < size and 0 <= y < size:
+                        distance = max(abs(dx), abs(dy))
+                        self.set_function(x, y, distance not in (2, 4))
+        positions = _ALIGNMENT[self.version]
+        last = len(positions) - 1
+        for i, cx in enumerate(positions):
+            for j, cy in enumerate(positions):
+                if (i, j) in ((0, 0), (0, last), (last, 0)):
+                    continue
+                for dy in range(-2, 3):
+                    for dx in range(-2, 3):
+                        dark = max(abs(dx), abs(dy)) != 1
+                        self.set_function(cx + dx, cy + dy, dark)
+        self.draw_format(0)
+        if self.version >= 7:
+            bits = version_bits(self.version)
+            for i in range(18):
+                dark = (bits >> i) & 1 == 1
+                a, b = size - 11 + i % 3, i // 3
+                self.set_function(a, b, dark)
+                self.set_function(b, a, dark)
+
+    def draw_format(self, mask: int) -> None:
+        bits = format_bits(mask)
+        size = self.size
+
+        def bit(i):
+            return (bits >> i) & 1 == 1
+
+        for i in range(6):
+            self.set_function(8, i, bit(i))
+        self.set_function(8, 7, bit(6))
+        self.set_function(8, 8, bit(7))
+        self.set_function(7, 8, bit(8))
+        for i in range(9, 15):
+            self.set_function(14 - i, 8, bit(i))
+        for i in range(8):
+            self.set_function(size - 1 - i, 8, bit(i))
+        for i in range(8, 15):
+            self.set_function(8, size - 15 + i, bit(i))
+        self.set_function(8, size - 8, True)
+
+    def draw_codewords(self, codewords: list[int]) -> None:
+        size = self.size
+        total = len(codewords) * 8
+        i = 0
+        right = size - 1
+        while right >= 1:
+            if right == 6:
+                right = 5
+            upward = (right + 1) & 2 == 0
+            for vertical in range(size):
+                y = size - 1 - vertical if upward else vertical
+                for x in (right, right - 1):
+                    if not self.reserved[y][x] and i < total:
+                        byte = codewords[i >> 3]
+                        self.modules[y][x] = (byte >> (7 - (i & 7))) & 1 == 1
+                        i += 1
+            right -= 2
+
+    def read_codewords(self, count: int) -> list[int]:
+        size = self.size
+        bits = []
+        right = size - 1
+        while right >= 1:
+            if right == 6:
+                right = 5
+            upward = (right + 1) & 2 == 0
+            for vertical in range(size):
+                y = size - 1 - vertical if upward else vertical
+                for x in (right, right - 1):
+                    if not self.reserved[y][x]:
+                        bits.append(self.modules[y][x])
+            right -= 2
+        return [
+            sum(bit << (7 - i) for i, bit in enumerate(bits[start : start + 8]))
+            for start in range(0, count * 8, 8)
+        ]
+
+    def apply_mask(self, mask: int) -> None:
+        condition = _MASKS[mask]
+        for y in range(self.size):
+            for x in range(self.size):
+                if not self.reserved[y][x] and condition(x, y):
+                    self.modules[y][x] = not self.modules[y][x]
+
+    def penalty(self) -> int:
+        size = self.size
+        rows = self.modules
+        columns = [[rows[y][x] for y in range(size)] for x in range(size)]
+        score = 0
+        for line in rows + columns:
+            run = 1
+            for i in range(1, size + 1):
+                if i < size and line[i] == line[i - 1]:
+                    run += 1
+                    continue
+                if run >= 5:
+                    score += run - 2
+                run = 1
+            padded = [False] * 4 + line + [False] * 4
+            for i in range(len(padded) - 10):
+                if tuple(padded[i : i + 11]) in _FINDER_LIKE:
+                    score += 40
+        for y in range(size - 1):
+            for x in range(size - 1):
+                color = rows[y][x]
+                if color == rows[y][x + 1] == rows[y + 1][x] == rows[y + 1][x + 1]:
+                    score += 3
+        dark = sum(sum(row) for row in rows)
+        total = size * size
+        score += abs(dark * 20 - total * 10) // total * 10
+        return score
+
+
+def encode(text: str) -> list[list[bool]]:
+    """Encode text as a QR code.
+
+    Args:
+        text: Text to encode, as UTF-8.
+
+    Returns:
+        The modules row by row, True for dark, without the quiet zone.
+
+    Raises:
+        ValueError: If the text is too long for a version 10 code.
+
+    """
+    data = text.encode("utf-8")
+    for version in _BLOCKS:
+        count_bits = 8 if version < 10 else 16
+        if 4 + count_bits + len(data) * 8 <= _data_capacity(version) * 8:
+            break
+    else:
+        raise ValueError("Text is too long for a QR code.")
+
+    codewords = _codewords(data, version)
+    best = None
+    for mask in range(len(_MASKS)):
+        matrix = _Matrix(version)
+        matrix.draw_function_patterns()
+        matrix.draw_codewords(codewords)
+        matrix.apply_mask(mask)
+        matrix.draw_format(mask)
+        score = matrix.penalty()
+        if best is None or score < best[0]:
+            best = (score, matrix)
+    return best[1].modules
+
+
+def _read_format(modules: list[list[bool]]) -> tuple[int, int]:
+    size = len(modules)
+    first = [modules[i][8] for i in range(6)]
+    first += [modules[7][8], modules[8][8], modules[8][7]]
+    first += [modules[8][14 - i] for i in range(9, 15)]
+    second = [modules[8][size - 1 - i] for i in range(8)]
+    second += [modules[size - 15 + i][8] for i in range(8, 15)]
+    best = None
+    for copy in (first, second):
+        bits = sum(bit << i for i, bit in enumerate(copy))
+        for level in _LEVEL_BLOCKS:
+            for mask in range(len(_MASKS)):
+                distance = bin(bits ^ format_bits(mask, level)).count("1")
+                if best is None or distance < best[0]:
+                    best = (distance, level, mask)
+    # The format bits are BCH coded and survive three flipped modules
+    if best[0] > 3:
+        raise ValueError("The QR code format could not be read.")
+    return best[1], best[2]
+
+
+def _read_segments(data: list[int], version: int) -> str:
+    bits = [(byte >> (7 - i)) & 1 for byte in data for i in range(8)]
+    position = 0
+
+    def take(count: int) -> int:
+        nonlocal position
+        if position + count > len(bits):
+            raise ValueError("The QR code data ends early.")
+        value = 0
+        for bit in bits[position : position + count]:
+            value = value << 1 | bit
+        position += count
+        return value
+
+    small = version < 10
+    text = []
+    while len(bits) - position >= 4:
+        mode = take(4)
+        if mode == 0b0000:
+            break
+        if mode == 0b0100:
+            length = take(8 if small else 16)
+            raw = bytes(take(8) for _ in range(length))
+            try:
+                text.append(raw.decode("utf-8"))
+            except UnicodeDecodeError:
+                text.append(raw.decode("latin-1"))
+        elif mode == 0b0010:
+            length = take(9 if small else 11)
+            for _ in range(length // 2):
+                pair = take(11)
+                text.append(_ALPHANUMERIC[pair // 45] + _ALPHANUMERIC[pair % 45])
+            if length % 2:
+                text.append(_ALPHANUMERIC[take(6)])
+        elif mode == 0b0001:
+            length = take(10 if small else 12)
+            for _ in range(length // 3):
+                text.append(f"{take(10):03d}")
+            if length % 3 == 2:
+                text.append(f"{take(7):02d}")
+            elif length % 3 == 1:
+                text.append(str(take(4)))
+        elif mode == 0b0111:
+            # Only the character set changes, which is taken to be UTF-8
+            take(8)
+        else:
+            raise ValueError("The QR code uses an unsupported mode.")
+    return "".join(text)
+
+
+def decode(modules: list[list[bool]]) -> str:
+    """Read the text in a QR code.
+
+    Args:
+        modules: Modules row by row, True for dark, without the quiet zone.
+
+    Returns:
+        The text in the code.
+
+    Raises:
+        ValueError: If the modules are not a readable version 1 to 10 code.
+
+    """
+    size = len(modules)
+    version = (size - 17) // 4
+    if version not in _BLOCKS or size != version * 4 + 17:
+        raise ValueError("Only QR codes of versions 1 to 10 can be read.")
+    level, mask = _read_format(modules)
+    matrix = _Matrix(version)
+    matrix.draw_function_patterns()
+    matrix.modules = [list(row) for row in modules]
+    matrix.apply_mask(mask)
+
+    degree, groups = _LEVEL_BLOCKS[level][version]
+    sizes = [size for count, size in groups for _ in range(count)]
+    codewords = matrix.read_codewords(sum(sizes) + degree * len(sizes))
+    blocks = [[] for _ in sizes]
+    position = 0
+    for i in range(max(sizes)):
+        for block, block_size in zip(blocks, sizes):
+            if i < block_size:
+                block.append(codewords[position])
+                position += 1
+    for _ in range(degree):
+        for block in blocks:
+            block.append(codewords[position])
+            position += 1
+    data = []
+    for block, block_size in zip(blocks, sizes):
+        data += correct_errors(block, degree)[:block_size]
+    return _read_segments(data, version)
+
+
+def to_png(modules: list[list[bool]], scale: int = 8, border: int = 4) -> bytes:
+    """Render QR code modules as a black-on-white PNG image.
+
+    Args:
+        modules: Modules as returned by encode.
+        scale: Pixels per module.
+        border: Width of the quiet zone around the code, in modules.
+
+    Returns:
+        The PNG file contents.
+
+    """
+    size = len(modules)
+    width = (size + border * 2) * scale
+    quiet = [[False] * size] * border
+    raw = bytearray()
+    for row in quiet + modules + quiet:
+        line = bytearray()
+        for dark in [False] * border + row + [False] * border:
+            line.extend((0 if dark else 255,) * scale)
+        for _ in range(scale):
+            raw.append(0)
+            raw.extend(line)
+
+    def chunk(kind: bytes, data: bytes) -> bytes:
+        body = kind + data
+        crc = struct.pack(">I", zlib.crc32(body))
+        return struct.pack(">I", len(data)) + body + crc
+
+    header = struct.pack(">IIBBBBB", width, width, 8, 0, 0, 0, 0)
+    return (
+        b"\x89PNG\r\n\x1a\n"
+        + chunk(b"IHDR", header)
+        + chunk(b"IDAT", zlib.compress(bytes(raw), 9))
+        + chunk(b"IEND", b"")
+    )
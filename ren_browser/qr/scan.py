@@ -0,0 +1,359 @@
+"""QR code scanning for Ren Browser.
+
+Finds a QR code in a photo or screenshot and reads the text in it. PNG
+images are read without an image library. Photos in other formats, like
+the JPEG files cameras take, are read through Pillow when it is installed.
+
+The code is located by its three finder patterns and sampled along the
+lines between them, which copes with codes that are turned or scaled but
+not with photos taken at a steep angle.
+"""
+
+import io
+import struct
+import zlib
+from collections import Counter
+from dataclasses import dataclass
+from itertools import combinations
+
+from ren_browser.qr.qr import decode
+
+PNG_SIGNATURE = b"\x89PNG\r\n\x1a\n"
+# Larger images are scaled down before looking for a code.
+MAX_SIDE = 1200
+
+_CHANNELS = {0: 1, 2: 3, 3: 1, 4: 2, 6: 4}
+
+
+@dataclass
+class GrayImage:
+    """An image as one brightness byte per pixel, row by row."""
+
+    width: int
+    height: int
+    pixels: bytes
+
+    def row(self, y: int) -> bytes:
+        """Return the pixels of a row."""
+        return self.pixels[y * self.width : (y + 1) * self.width]
+
+
+def _paeth(a: int, b: int, c: int) -> int:
+    p = a + b - c
+    pa, pb, pc = abs(p - a), abs(p - b), abs(p - c)
+    if pa <= pb and pa <= pc:
+        return a
+    return b if pb <= pc else c
+
+
+def _unfilter(raw: bytes, height: int, stride: int, bpp: int) -> list[bytes]:
+    rows = []
+    previous = bytes(stride)
+    position = 0
+    for _ in range(height):
+        kind = raw[position]
+        line = bytearray(raw[position + 1 : position + 1 + stride])
+        position += stride + 1
+        if kind == 1:
+            for i in range(bpp, stride):
+                line[i] = (line[i] + line[i - bpp]) & 0xFF
+        elif kind == 2:
+            line = bytearray((a + b) & 0xFF for a, b in zip(line, previous))
+        elif kind == 3:
+            for i in range(stride):
+                left = line[i - bpp] if i >= bpp else 0
+                line[i] = (line[i] + (left + previous[i]) // 2) & 0xFF
+        elif kind == 4:
+            for i in range(stride):
+                left = line[i - bpp] if i >= bpp else 0
+                corner = previous[i - bpp] if i >= bpp else 0
+                line[i] = (line[i] + _paeth(left, previous[i], corner)) & 0xFF
+        elif kind != 0:
+            raise ValueError("The PNG image is damaged.")
+        rows.append(bytes(line))
+        previous = line
+    return rows
+
+
+def read_png(data: bytes) -> GrayImage:
+    """Read an 8-bit, non-interlaced PNG image as grayscale.
+
+    Raises:
+        ValueError: If the data is not such a PNG image.
+
+    """
+    if not data.startswith(PNG_SIGNATURE):
+        raise ValueError("Not a PNG image.")
+    position = len(PNG_SIGNATURE)
+    header = None
+    palette = b""
+    compressed = bytearray()
+    while position + 8 <= len(data):
+        length, kind = struct.unpack(">I4s", data[position : position + 8])
+        body = data[position + 8 : position + 8 + length]
+        position += length + 12
+        if kind == b"IHDR":
+            header = struct.unpack(">IIBBBBB", body)
+        elif kind == b"PLTE":
+            palette = body
+        elif kind == b"IDAT":
+            compressed += body
+        elif kind == b"IEND":
+            break
+    if header is None:
+        raise ValueError("The PNG image is damaged.")
+    width, height, depth, color, _, _, interlace = header
+    if depth != 8 or interlace or color not in _CHANNELS:
+        raise ValueError("Only 8-bit, non-interlaced PNG images can be scanned.")
+    channels = _CHANNELS[color]
+    try:
+        raw = zlib.decompress(bytes(compressed))
+    except zlib.error as exc:
+        raise ValueError("The PNG image is damaged.") from exc
+    if len(raw) < height * (width * channels + 1):
+        raise ValueError("The PNG image is damaged.")
+    rows = _unfilter(raw, height, width * channels, channels)
+
+    if color == 3:
+        if len(palette) < 3:
+            raise ValueError("The PNG image is damaged.")
+        shades = bytes(
+            (palette[i] + 2 * palette[i + 1] + palette[i + 2]) // 4
+            for i in range(0, len(palette) - 2, 3)
+        ).ljust(256, b"\0")
+        pixels = b"".join(row.translate(shades) for row in rows)
+    elif channels <= 2:
+        pixels = b"".join(row[::channels] for row in rows)
+    else:
+        # Transparency is ignored, codes are drawn opaque
+        pixels = b"".join(
+            bytes(
+                (r + 2 * g + b) >> 2
+                for r, g, b in zip(
+                    row[::channels],
+                    row[1::channels],
+                    row[2::channels],
+                )
+            )
+            for row in rows
+        )
+    return GrayImage(width, height, pixels)
+
+
+def _read_with_pillow(data: bytes) -> GrayImage:
+    try:
+        from PIL import Image
+    except ImportError as exc:
+        raise ValueError("Only PNG images can be scanned on this device.") from exc
+    try:
+        with Image.open(io.BytesIO(data)) as image:
+            image.thumbnail((MAX_SIDE, MAX_SIDE))
+            gray = image.convert("L")
+    except (OSError, ValueError) as exc:
+        raise ValueError("The image could not be read.") from exc
+    return GrayImage(gray.width, gray.height, gray.tobytes())
+
+
+def read_image(data: bytes) -> GrayImage:
+    """Read an image file as grayscale, no larger than MAX_SIDE.
+
+    Raises:
+        ValueError: If the image cannot be read.
+
+    """
+    if data.startswith(PNG_SIGNATURE):
+        image = read_png(data)
+    else:
+        image = _read_with_pillow(data)
+    step = -(-max(image.width, image.height) // MAX_SIDE)
+    if step <= 1:
+        return image
+    rows = [image.row(y)[::step] for y in range(0, image.height, step)]
+    return GrayImage(len(rows[0]), len(rows), b"".join(rows))
+
+
+def threshold(image: GrayImage) -> int:
+    """Return the brightness separating dark from light pixels (Otsu's method)."""
+    histogram = Counter(image.pixels)
+    total = len(image.pixels)
+    weighted = sum(level * count for level, count in histogram.items())
+    best, best_level = -1.0, 128
+    below = below_weighted = 0
+    for level in range(256):
+        below += histogram.get(level, 0)
+        below_weighted += level * histogram.get(level, 0)
+        above = total - below
+        if below == 0 or above == 0:
+            continue
+        mean_below = below_weighted / below
+        mean_above = (weighted - below_weighted) / above
+        spread = below * above * (mean_below - mean_above) ** 2
+        if spread > best:
+            best, best_level = spread, level
+    return best_level + 1
+
+
+def _binarize(image: GrayImage) -> list[list[bool]]:
+    level = threshold(image)
+    return [[pixel < level for pixel in image.row(y)] for y in range(image.height)]
+
+
+def _runs(line: list[bool]) -> list[tuple[bool, int, int]]:
+    runs = []
+    start = 0
+    for i in range(1, len(line) + 1):
+        if i == len(line) or line[i] != line[start]:
+            runs.append((line[start], start, i - start))
+            start = i
+    return runs
+
+
+def _finder_ratio(counts: list[int]) -> bool:
+    # Finder patterns cross as dark, light, dark, light, dark in 1:1:3:1:1
+    module = sum(counts) / 7
+    if module < 1:
+        return False
+    tolerance = module * 0.6
+    return (
+        all(abs(counts[i] - module) < tolerance for i in (0, 1, 3, 4))
+        and abs(counts[2] - 3 * module) < 3 * tolerance
+    )
+
+
+def _line_candidates(line: list[bool]) -> list[tuple[float, float]]:
+    runs = _runs(line)
+    found = []
+    for i in range(len(runs) - 4):
+        if not runs[i][0]:
+            continue
+        counts = [run[2] for run in runs[i : i + 5]]
+        if _finder_ratio(counts):
+            center = runs[i + 2][1] + runs[i + 2][2] / 2
+            found.append((center, sum(counts) / 7))
+    return found
+
+
+def _vertical_center(
+    bits: list[list[bool]],
+    x: int,
+    y: int,
+) -> tuple[float, float] | None:
+    runs = _runs([row[x] for row in bits])
+    index = next(i for i, run in enumerate(runs) if run[1] <= y < run[1] + run[2])
+    if not runs[index][0] or index < 2 or index + 2 >= len(runs):
+        return None
+    counts = [run[2] for run in runs[index - 2 : index + 3]]
+    if not _finder_ratio(counts):
+        return None
+    _, start, length = runs[index]
+    return start + length / 2, sum(counts) / 7
+
+
+def find_finders(bits: list[list[bool]]) -> list[tuple[float, float, float]]:
+    """Return the finder patterns in a binarized image.
+
+    Returns:
+        Each pattern's center x and y and its module size, most often seen
+        first.
+
+    """
+    clusters = []
+    for y, line in enumerate(bits):
+        for x, module in _line_candidates(line):
+            vertical = _vertical_center(bits, int(x), y)
+            if vertical is None:
+                continue
+            cy, vertical_module = vertical
+            module = (module + vertical_module) / 2
+            for cluster in clusters:
+                if (
+                    abs(cluster[0] / cluster[3] - x) <= module * 2
+                    and abs(cluster[1] / cluster[3] - cy) <= module * 2
+                ):
+                    cluster[0] += x
+                    cluster[1] += cy
+                    cluster[2] += module
+                    cluster[3] += 1
+                    break
+            else:
+                clusters.append([x, cy, module, 1])
+    clusters.sort(key=lambda c: -c[3])
+    return [(c[0] / c[3], c[1] / c[3], c[2] / c[3]) for c in clusters]
+
+
+def _distance(a, b) -> float:
+    return ((a[0] - b[0]) ** 2 + (a[1] - b[1]) ** 2) ** 0.5
+
+
+def _order(finders) -> tuple:
+    # The top-left pattern is the one opposite the longest side
+    a, b, c = finders
+    _, top_left, first, second = max(
+        (_distance(b, c), a, b, c),
+        (_distance(a, c), b, a, c),
+        (_distance(a, b), c, a, b),
+        key=lambda side: side[0],
+    )
+    cross = (first[0] - top_left[0]) * (second[1] - top_left[1]) - (
+        first[1] - top_left[1]
+    ) * (second[0] - top_left[0])
+    if cross > 0:
+        return top_left, first, second
+    return top_left, second, first
+
+
+def sample(bits: list[list[bool]], finders) -> list[list[bool]]:
+    """Read the modules of the code whose three finder patterns are given.
+
+    Raises:
+        ValueError: If the patterns cannot belong to one code.
+
+    """
+    top_left, top_right, bottom_left = _order(finders)
+    module = sum(f[2] for f in finders) / 3
+    across = (
+        _distance(top_left, top_right) + _distance(top_left, bottom_left)
+    ) / (2 * module)
+    version = round((across + 7 - 17) / 4)
+    if not 1 <= version <= 10:
+        raise ValueError("No QR code of a supported size was found.")
+    size = version * 4 + 17
+    span = size - 7
+    height, width = len(bits), len(bits[0])
+    modules = []
+    for row in range(size):
+        line = []
+        for col in range(size):
+            u, v = (col - 3) / span, (row - 3) / span
+            x = top_left[0] + u * (top_right[0] - top_left[0])
+            x += v * (bottom_left[0] - top_left[0])
+            y = top_left[1] + u * (top_right[1] - top_left[1])
+            y += v * (bottom_left[1] - top_left[1])
+            px, py = int(x), int(y)
+            line.append(0 <= px < width and 0 <= py < height and bits[py][px])
+        modules.append(line)
+    return modules
+
+
+def scan(data: bytes) -> str | None:
+    """Read the text in the QR code in an image file.
+
+    Args:
+        data: Contents of the image file.
+
+    Returns:
+        The text in the code, or None when no readable code was found.
+
+    Raises:
+        ValueError: If the image itself cannot be read.
+
+    """
+    bits = _binarize(read_image(data))
+    # Parts of the code can look like a finder pattern, so the four
+    # patterns seen most often are tried three at a time
+    for trio in combinations(find_finders(bits)[:4], 3):
+        try:
+            return decode(sample(bits, trio))
+        except ValueError:
+            continue
+    return None
@@ -0,0 +1,147 @@
+"""Reading list for Ren Browser.
+
+Saves the fetched source of pages to disk so they can be read later without
+a connection to the node that served them.
+"""
+
+import hashlib
+import json
+import time
+from dataclasses import asdict, dataclass
+from pathlib import Path
+
+
+@dataclass
+class ReadingItem:
+    """A page saved for later.
+
+    Contains the address, title, save time and the file holding its source.
+    """
+
+    address: str
+    title: str
+    saved_at: float
+    file: str
+
+    @classmethod
+    def from_dict(cls, data) -> "ReadingItem | None":
+        """Build an item from the stored index, or None if it is malformed."""
+        if not isinstance(data, dict) or not data.get("address") or not data.get("file"):
+            return None
+        try:
+            saved_at = float(data.get("saved_at", 0))
+        except (TypeError, ValueError):
+            saved_at = 0.0
+        return cls(
+            address=data["address"],
+            title=data.get("title") or data["address"],
+            saved_at=saved_at,
+            file=Path(data["file"]).name,
+        )
+
+
+class ReadingList:
+    """Offline copies of pages kept in a directory with a JSON index."""
+
+    def __init__(self, directory: Path):
+        """Load the reading list index.
+
+        Args:
+            directory: Directory holding the saved pages and ``index.json``.
+
+        """
+        self.directory = Path(directory)
+        self._items: list[ReadingItem] = []
+        try:
+            stored = json.loads((self.directory / "index.json").read_text("utf-8"))
+        except (OSError, ValueError):
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            item = ReadingItem.from_dict(data)
+            if item is not None and self.get(item.address) is None:
+                self._items.append(item)
+
+    def get(self, address: str) -> ReadingItem | None:
+        """Return the saved item for an address, if there is one."""
+        for item in self._items:
+            if item.address == address:
+                return item
+        return None
+
+    def items(self) -> list[ReadingItem]:
+        """Return all saved pages, most recently saved first."""
+        return sorted(self._items, key=lambda item: item.saved_at, reverse=True)
+
+    def save(self, address: str, title: str, content: str, page_path: str) -> ReadingItem:
+        """Save a page's source, replacing any earlier copy.
+
+        Args:
+            address: Normalized address of the page.
+            title: Title shown in the reading list.
+            content: Fetched page source, written unchanged.
+            page_path: Path of the page on its node, used for the file suffix.
+
+        Returns:
+            ReadingItem: The saved item.
+
+        Raises:
+            OSError: If the page could not be written.
+
+        """
+        suffix = ".mu" if page_path.endswith(".mu") else ".txt"
+        file = hashlib.sha256(address.encode("utf-8")).hexdigest()[:32] + suffix
+        self.directory.mkdir(parents=True, exist_ok=True)
+        (self.directory / file).write_text(content, encoding="utf-8")
+        item = self.get(address)
+        if item is None:
+            item = ReadingItem(address, title or address, time.time(), file)
+            self._items.append(item)
+        else:
+            item.title = title or address
+            item.saved_at = time.time()
+            item.file = file
+        self._save_index()
+        return item
+
+    def load(self, address: str) -> str | None:
+        """Return the saved source of a page, or None if it is not available."""
+        item = self.get(address)
+        if item is None:
+            return None
+        try:
+            return (self.directory / item.file).read_text(encoding="utf-8")
+        except OSError:
+            return None
+
+    def remove(self, address: str) -> None:
+        """Delete the saved copy of a page, if any."""
+        item = self.get(address)
+        if item is None:
+            return
+        self._items.remove(item)
+        try:
+            (self.directory / item.file).unlink()
+        except OSError:
+            pass
+        self._save_index()
+
+    def clear(self, since: float | None = None) -> None:
+        """Delete saved pages.
+
+        Args:
+            since: Only delete pages saved at or after this time.
+
+        """
+        for item in list(self._items):
+            if since is None or item.saved_at >= since:
+                self.remove(item.address)
+
+    def _save_index(self) -> None:
+        try:
+            self.directory.mkdir(parents=True, exist_ok=True)
+            (self.directory / "index.json").write_text(
+                json.dumps([asdict(item) for item in self._items], indent=2),
+                encoding="utf-8",
+            )
+        except OSError:
+            pass
@@ -0,0 +1,290 @@
+"""Remote control of a running Ren Browser.
+
+A running browser answers JSON-RPC 2.0 requests so window managers and
+scripts can drive it: open an address in a new tab, list the tabs, reload
+one and ask for its status. Like deep link handoff it listens on the
+loopback interface instead of a Unix socket or named pipe, so it works the
+same on every platform, and only answers requests carrying the token it
+publishes with its port in the storage directory. Each connection carries
+one newline terminated request and gets one response line back.
+"""
+
+import contextlib
+import inspect
+import json
+import os
+import secrets
+import socket
+import threading
+from collections.abc import Callable
+from pathlib import Path
+
+from ren_browser import rns
+from ren_browser.deeplink.deeplink import read_message
+
+REMOTE_TIMEOUT = 2.0
+
+# JSON-RPC 2.0 error codes
+PARSE_ERROR = -32700
+INVALID_REQUEST = -32600
+METHOD_NOT_FOUND = -32601
+INVALID_PARAMS = -32602
+INTERNAL_ERROR = -32603
+# Not part of JSON-RPC, used by the client when no browser answers
+NOT_RUNNING = -32000
+
+
+class RemoteError(Exception):
+    """A remote control request that failed, with its JSON-RPC error code."""
+
+    def __init__(self, code: int, message: str):
+        """Create the error.
+
+        Args:
+            code: JSON-RPC error code.
+            message: What went wrong.
+
+        """
+        super().__init__(message)
+        self.code = code
+
+
+def _error(request_id, code: int, message: str) -> dict:
+    return {
+        "jsonrpc": "2.0",
+        "id": request_id,
+        "error": {"code": code, "message": message},
+    }
+
+
+class RemoteControlServer:
+    """Answers remote control requests from scripts on this machine."""
+
+    def __init__(self, path: Path, methods: dict[str, Callable]):
+        """Prepare the server.
+
+        Args:
+            path: File the port and token are published in.
+            methods: Functions answering each method by name, called from the
+                server thread with the request's params as arguments.
+
+        """
+        self.path = Path(path)
+        self.methods = methods
+        self.token = secrets.token_hex(16)
+        self._sock: socket.socket | None = None
+        self._thread: threading.Thread | None = None
+
+    @property
+    def port(self) -> int | None:
+        """Return the port being listened on, or None before start."""
+        return self._sock.getsockname()[1] if self._sock else None
+
+    def start(self) -> bool:
+        """Start listening and publish where to reach this instance.
+
+        Returns:
+            bool: Whether the server is running.
+
+        """
+        try:
+            self._sock = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+            self._sock.bind(("127.0.0.1", 0))
+            self._sock.listen()
+            self.path.write_text(
+                json.dumps({"port": self.port, "token": self.token}),
+                encoding="utf-8",
+            )
+            with contextlib.suppress(OSError):
+                os.chmod(self.path, 0o600)
+        except OSError:
+            self.stop()
+            return False
+        self._thread = threading.Thread(target=self._serve, daemon=True)
+        self._thread.start()
+        return True
+
+    def stop(self) -> None:
+        """Stop listening and withdraw the published port."""
+        sock, self._sock = self._sock, None
+        if sock is not None:
+            with contextlib.suppress(OSError):
+                sock.close()
+        with contextlib.suppress(OSError, json.JSONDecodeError):
+            published = json.loads(self.path.read_text(encoding="utf-8"))
+            if published.get("token") == self.token:
+                self.path.unlink()
+
+    def _serve(self) -> None:
+        while self._sock is not None:
+            try:
+                conn, _ = self._sock.accept()
+            except OSError:
+                return
+            with conn:
+                conn.settimeout(REMOTE_TIMEOUT)
+                try:
+                    response = self.handle(read_message(conn))
+                    if response is not None:
+                        conn.sendall(json.dumps(response).encode("utf-8") + b"\n")
+                except OSError:
+                    continue
+
+    def handle(self, message: dict | None) -> dict | None:
+        """Answer one request.
+
+        Args:
+            message: The decoded request, None if it was not a JSON object.
+
+        Returns:
+            dict | None: The response, None for notifications, which are
+            requests without an id.
+
+        """
+        if message is None:
+            return _error(None, PARSE_ERROR, "Request is not a JSON object")
+        request_id = message.get("id")
+        if message.get("token") != self.token:
+            return _error(request_id, INVALID_REQUEST, "Wrong or missing token")
+        method = message.get("method")
+        params = message.get("params", [])
+        if message.get("jsonrpc") != "2.0" or not isinstance(method, str):
+            return _error(request_id, INVALID_REQUEST, "Not a JSON-RPC 2.0 request")
+        if method not in self.methods:
+            return _error(request_id, METHOD_NOT_FOUND, f"Unknown method: {method}")
+        if not isinstance(params, list | dict):
+            return _error(request_id, INVALID_PARAMS, "Params must be a list or object")
+        args, kwargs = (params, {}) if isinstance(params, list) else ([], params)
+        handler = self.methods[method]
+        try:
+            inspect.signature(handler).bind(*args, **kwargs)
+        except TypeError as exc:
+            return _error(request_id, INVALID_PARAMS, str(exc))
+        try:
+            result = handler(*args, **kwargs)
+        except RemoteError as exc:
+            return _error(request_id, exc.code, str(exc))
+        except Exception as exc:  # noqa: BLE001
+            return _error(request_id, INTERNAL_ERROR, str(exc))
+        if "id" not in message:
+            return None
+        return {"jsonrpc": "2.0", "id": request_id, "result": result}
+
+
+def call(
+    path: Path,
+    method: str,
+    params: list | dict | None = None,
+    timeout: float = REMOTE_TIMEOUT,
+):
+    """Call a method of the running browser.
+
+    Args:
+        path: File the running instance published its port and token in.
+        method: Name of the method.
+        params: Arguments of the method, by position or by name.
+        timeout: Seconds to wait for the running instance.
+
+    Returns:
+        The method's result.
+
+    Raises:
+        RemoteError: If no browser is running or the method failed.
+
+    """
+    try:
+        published = json.loads(Path(path).read_text(encoding="utf-8"))
+        port = int(published["port"])
+        token = published["token"]
+    except (OSError, ValueError, KeyError, TypeError) as exc:
+        raise RemoteError(NOT_RUNNING, "Ren Browser is not running") from exc
+    request = {
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params if params is not None else [],
+        "token": token,
+    }
+    try:
+        with socket.create_connection(("127.0.0.1", port), timeout=timeout) as conn:
+            conn.sendall(json.dumps(request).encode("utf-8") + b"\n")
+            response = read_message(conn)
+    except OSError as exc:
+        raise RemoteError(NOT_RUNNING, "Ren Browser is not running") from exc
+    if response is None:
+        raise RemoteError(PARSE_ERROR, "Ren Browser sent an unreadable response")
+    if "error" in response:
+        error = response["error"] or {}
+        raise RemoteError(
+            error.get("code", INTERNAL_ERROR),
+            error.get("message", "Unknown error"),
+        )
+    return response.get("result")
+
+
+def _describe_tab(index: int, tab: dict, active: int) -> dict:
+    private = bool(tab.get("private"))
+    return {
+        "index": index,
+        # Private tabs are listed without what is open in them
+        "title": None if private else tab["title"],
+        "address": None if private else tab.get("address"),
+        "active": index == active,
+        "loading": tab.get("load_progress") is not None,
+        "private": private,
+    }
+
+
+def browser_methods(tab_manager) -> dict[str, Callable]:
+    """Return the remote control methods acting on the browser's tabs.
+
+    ``open(address, background=False)`` opens an address in a new tab and
+    returns its index, ``tabs()`` lists the tabs, ``reload(tab=None,
+    hard=False)`` reloads a tab, the active one by default, and
+    ``status()`` describes the browser and its active tab.
+    """
+    manager = tab_manager.manager
+
+    def tab_index(tab) -> int:
+        if tab is None:
+            return manager.index
+        if isinstance(tab, bool) or not isinstance(tab, int):
+            raise RemoteError(INVALID_PARAMS, "Tab must be an index")
+        if not 0 <= tab < len(manager.tabs):
+            raise RemoteError(INVALID_PARAMS, f"No tab {tab}")
+        return tab
+
+    def open_address(address: str, background: bool = False) -> dict:
+        if not isinstance(address, str) or not address.strip():
+            raise RemoteError(INVALID_PARAMS, "Address must be a string")
+        tab_manager.open_link_in_new_tab(address.strip(), select=not background)
+        return {"tab": len(manager.tabs) - 1}
+
+    def tabs() -> list[dict]:
+        return [
+            _describe_tab(index, tab, manager.index)
+            for index, tab in enumerate(manager.tabs)
+        ]
+
+    def reload(tab: int | None = None, hard: bool = False) -> dict:
+        index = tab_index(tab)
+        tab_manager.reload(index, hard=bool(hard))
+        return {"tab": index}
+
+    def status() -> dict:
+        return {
+            "reticulum": rns.get_reticulum_instance() is not None,
+            "tabs": len(manager.tabs),
+            "active": _describe_tab(
+                manager.index,
+                manager.tabs[manager.index],
+                manager.index,
+            ),
+        }
+
+    return {
+        "open": open_address,
+        "tabs": tabs,
+        "reload": reload,
+        "status": status,
+    }
@@ -0,0 +1,249 @@
+"""Code viewer for Ren Browser.
+
+Shows source and configuration files served by nodes in monospace with
+numbered lines and syntax highlighting. Highlighting is a light pass over
+comments, strings, numbers and keywords, chosen by the file extension;
+files of other kinds are left to the plaintext renderer.
+"""
+
+import re
+from dataclasses import dataclass
+
+import flet as ft
+
+from ren_browser.renderer.plaintext import TEXT_SIZE, scaled_size
+
+
+@dataclass(frozen=True)
+class Language:
+    """How the files of one language are highlighted."""
+
+    name: str
+    keywords: frozenset = frozenset()
+    line_comment: str | None = "#"
+    block_comment: tuple[str, str] | None = None
+    triple_quotes: bool = False
+    char_literals: bool = False
+    sections: bool = False
+
+
+_C_KEYWORDS = (
+    "break case char const continue default do double else enum extern float "
+    "for goto if int long return short signed sizeof static struct switch "
+    "typedef union unsigned void volatile while"
+)
+
+LANGUAGES = {
+    "python": Language(
+        "Python",
+        frozenset(
+            "and as assert async await break class continue def del elif else "
+            "except False finally for from global if import in is lambda None "
+            "nonlocal not or pass raise return True try while with yield".split(),
+        ),
+        triple_quotes=True,
+    ),
+    "rust": Language(
+        "Rust",
+        frozenset(
+            "as async await break const continue crate dyn else enum extern false "
+            "fn for if impl in let loop match mod move mut pub ref return self "
+            "Self static struct super trait true type unsafe use where while".split(),
+        ),
+        line_comment="//",
+        block_comment=("/*", "*/"),
+        char_literals=True,
+    ),
+    "c": Language(
+        "C",
+        frozenset(_C_KEYWORDS.split()),
+        line_comment="//",
+        block_comment=("/*", "*/"),
+        char_literals=True,
+    ),
+    "go": Language(
+        "Go",
+        frozenset(
+            "break case chan const continue default defer else fallthrough for "
+            "func go goto if import interface map nil package range return "
+            "select struct switch true false type var".split(),
+        ),
+        line_comment="//",
+        block_comment=("/*", "*/"),
+        char_literals=True,
+    ),
+    "javascript": Language(
+        "JavaScript",
+        frozenset(
+            "async await break case catch class const continue default delete "
+            "do else export extends false finally for function if import in "
+            "instanceof let new null return super switch this throw true try "
+            "typeof undefined var void while yield".split(),
+        ),
+        line_comment="//",
+        block_comment=("/*", "*/"),
+    ),
+    "shell": Language(
+        "Shell",
+        frozenset(
+            "case do done elif else esac export fi for function if in local "
+            "return then until while".split(),
+        ),
+    ),
+    "config": Language(
+        "Config",
+        frozenset("true false yes no on off".split()),
+        sections=True,
+    ),
+    "json": Language(
+        "JSON",
+        frozenset("true false null".split()),
+        line_comment=None,
+    ),
+}
+
+EXTENSIONS = {
+    ".py": "python",
+    ".rs": "rust",
+    ".c": "c",
+    ".h": "c",
+    ".go": "go",
+    ".js": "javascript",
+    ".ts": "javascript",
+    ".sh": "shell",
+    ".toml": "config",
+    ".conf": "config",
+    ".cfg": "config",
+    ".ini": "config",
+    ".yaml": "config",
+    ".yml": "config",
+    ".json": "json",
+}
+
+TOKEN_COLORS = {
+    "keyword": ft.Colors.PRIMARY,
+    "string": ft.Colors.TERTIARY,
+    "number": ft.Colors.SECONDARY,
+    "comment": ft.Colors.ON_SURFACE_VARIANT,
+    "section": ft.Colors.PRIMARY,
+}
+
+
+def code_language(page_path: str) -> Language | None:
+    """Return the language a file is highlighted as, None for other files."""
+    dot = page_path.rfind(".")
+    if dot == -1 or "/" in page_path[dot:]:
+        return None
+    key = EXTENSIONS.get(page_path[dot:].lower())
+    return LANGUAGES[key] if key else None
+
+
+def _token_pattern(language: Language) -> re.Pattern:
+    patterns = []
+    if language.block_comment:
+        start, end = (re.escape(mark) for mark in language.block_comment)
+        patterns.append(rf"(?P<block>{start}.*?(?:{end}|\Z))")
+    if language.line_comment:
+        patterns.append(rf"(?P<comment>{re.escape(language.line_comment)}[^\n]*)")
+    if language.triple_quotes:
+        patterns.append(
+            r'(?P<triple>(?P<quote>"""|\'\'\')[\s\S]*?(?:(?P=quote)|\Z))',
+        )
+    # Single quotes around one character are a character, not a string, in
+    # languages where a lone quote also marks lifetimes or labels
+    single = r"\\.|[^'\\\n]" if language.char_literals else r"(?:\\.|[^'\\\n])*"
+    patterns.append(rf'(?P<string>"(?:\\.|[^"\\\n])*"|\'(?:{single})\')')
+    if language.sections:
+        patterns.append(r"(?P<section>^[ \t]*\[[^\]\n]*\])")
+    patterns.append(r"(?P<number>\b\d[\d_]*(?:\.\d+)?\b)")
+    patterns.append(r"(?P<word>\b[A-Za-z_][A-Za-z0-9_]*\b)")
+    return re.compile("|".join(patterns), re.MULTILINE | re.DOTALL)
+
+
+def highlight(content: str, language: Language) -> list[tuple[str, str | None]]:
+    """Split source into ``(text, kind)`` tokens for highlighting.
+
+    Args:
+        content: Source of the file.
+        language: Language the source is written in.
+
+    Returns:
+        list: Consecutive pieces of the source, kind being "keyword",
+        "string", "number", "comment", "section" or None for plain text.
+
+    """
+    tokens: list[tuple[str, str | None]] = []
+    position = 0
+
+    def add(text: str, kind: str | None) -> None:
+        if tokens and tokens[-1][1] == kind:
+            tokens[-1] = (tokens[-1][0] + text, kind)
+        else:
+            tokens.append((text, kind))
+
+    for match in _token_pattern(language).finditer(content):
+        if match.start() > position:
+            add(content[position : match.start()], None)
+        position = match.end()
+        group = match.lastgroup
+        text = match.group()
+        if group == "word":
+            add(text, "keyword" if text in language.keywords else None)
+        elif group in ("block", "comment"):
+            add(text, "comment")
+        elif group == "triple":
+            add(text, "string")
+        else:
+            add(text, group)
+    if position < len(content):
+        add(content[position:], None)
+    return tokens
+
+
+def render_code(
+    content: str,
+    language: Language,
+    text_scale: float = 1.0,
+    min_size: float = 0,
+) -> ft.Control:
+    """Show a source file in monospace with numbered and highlighted lines.
+
+    Lines are not wrapped, so each number stays beside its line, and the
+    numbers are left out when the code is selected and copied.
+    """
+    lines = content.split("\n")
+    size = scaled_size(TEXT_SIZE, text_scale, min_size)
+    width = len(str(len(lines)))
+    spans = [
+        ft.TextSpan(
+            text,
+            style=ft.TextStyle(
+                color=TOKEN_COLORS.get(kind),
+                italic=kind == "comment",
+                weight=ft.FontWeight.BOLD if kind == "section" else None,
+            ),
+        )
+        for text, kind in highlight(content, language)
+    ]
+    return ft.Row(
+        controls=[
+            ft.Text(
+                "\n".join(str(n).rjust(width) for n in range(1, len(lines) + 1)),
+                font_family="monospace",
+                size=size,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+                no_wrap=True,
+            ),
+            ft.Text(
+                spans=spans,
+                selectable=True,
+                font_family="monospace",
+                size=size,
+                no_wrap=True,
+            ),
+        ],
+        spacing=16,
+        vertical_alignment=ft.CrossAxisAlignment.START,
+        scroll=ft.ScrollMode.AUTO,
+        expand=True,
+    )
@@ -0,0 +1,171 @@
+"""Gemtext renderer for Ren Browser.
+
+Parses ``text/gemini`` pages into the blocks of the micron renderer, so
+Gemini pages are shown with the same headings, links and text styles as
+pages from nodes. Links are made absolute against the page address,
+quotes are set in italics one level in and preformatted text keeps a
+monospace font.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.gemini.gemini import join_url
+from ren_browser.i18n.i18n import tr
+from ren_browser.renderer.micron import (
+    MicronBlock,
+    blocks_to_text,
+    is_rtl,
+    render_blocks,
+)
+
+_PREFORMATTED = "```"
+
+
+def _span(text: str, italic: bool = False, monospace: bool = False) -> dict:
+    return {
+        "text": text,
+        "bold": False,
+        "italic": italic,
+        "underline": False,
+        "color": None,
+        "bgcolor": None,
+        "monospace": monospace,
+    }
+
+
+def _text(text: str, level: int = 0, **style) -> MicronBlock:
+    return MicronBlock("text", level, parts=(_span(text, **style),), rtl=is_rtl(text))
+
+
+def parse_link(line: str, base_url: str) -> tuple[str, str] | None:
+    """Return the ``(label, target)`` of a ``=>`` line, None if it has no target.
+
+    The target is made absolute against base_url.
+    """
+    parts = line[2:].split(maxsplit=1)
+    if not parts:
+        return None
+    label = parts[1].strip() if len(parts) > 1 else ""
+    return label, join_url(base_url, parts[0])
+
+
+def parse_gemtext(content: str, base_url: str) -> tuple[MicronBlock, ...]:
+    """Parse a gemtext page into blocks of the micron renderer.
+
+    Args:
+        content: Gemtext source.
+        base_url: Address of the page, links are resolved against it.
+
+    Returns:
+        tuple[MicronBlock, ...]: The blocks, one per line.
+
+    """
+    blocks = []
+    preformatted = False
+    for line in content.replace("\r\n", "\n").split("\n"):
+        if line.startswith(_PREFORMATTED):
+            preformatted = not preformatted
+            continue
+        if preformatted:
+            block = _text(line, monospace=True) if line else MicronBlock("blank")
+            blocks.append(block)
+        elif not line.strip():
+            blocks.append(MicronBlock("blank"))
+        elif line.startswith("=>"):
+            link = parse_link(line, base_url)
+            if link is not None:
+                blocks.append(
+                    MicronBlock("links", parts=(link,), rtl=is_rtl(link[0])),
+                )
+        elif line.startswith("#"):
+            level = min(len(line) - len(line.lstrip("#")), 3)
+            heading = line.lstrip("#").strip()
+            if heading:
+                blocks.append(
+                    MicronBlock("heading", level, heading, rtl=is_rtl(heading)),
+                )
+        elif line.startswith("* "):
+            blocks.append(_text(f"• {line[2:].strip()}"))
+        elif line.startswith(">"):
+            blocks.append(_text(line[1:].strip(), level=1, italic=True))
+        else:
+            blocks.append(_text(line))
+    return tuple(blocks)
+
+
+def gemtext_title(content: str) -> str | None:
+    """Return the text of the first heading of a gemtext page, if any."""
+    preformatted = False
+    for line in content.split("\n"):
+        if line.startswith(_PREFORMATTED):
+            preformatted = not preformatted
+        elif not preformatted and line.startswith("#"):
+            heading = line.lstrip("#").strip()
+            if heading:
+                return heading
+    return None
+
+
+def gemtext_to_text(content: str) -> str:
+    """Return the readable text of a gemtext page, links by their label."""
+    return blocks_to_text(parse_gemtext(content, ""))
+
+
+def render_gemtext(content: str, base_url: str, **options) -> ft.Control:
+    """Render a gemtext page.
+
+    Args:
+        content: Gemtext source.
+        base_url: Address of the page, links are resolved against it.
+        **options: Link callbacks and text options of render_blocks.
+
+    Returns:
+        ft.Control: The rendered page.
+
+    """
+    return render_blocks(parse_gemtext(content, base_url), **options)
+
+
+def build_input_page(
+    prompt: str,
+    sensitive: bool,
+    on_submit: Callable[[str], None],
+    spell_check: Callable[[ft.TextField], ft.Control] | None = None,
+) -> ft.Control:
+    """Build the page asking for the input a Gemini page needs.
+
+    Args:
+        prompt: Question sent by the server.
+        sensitive: Hide the answer while it is typed, as for passwords.
+        on_submit: Called with the answer.
+        spell_check: Optional wrapper adding spell checking to the answer
+            field, which sensitive answers are never given.
+
+    Returns:
+        ft.Control: The input page.
+
+    """
+    field = ft.TextField(
+        label=prompt,
+        password=sensitive,
+        can_reveal_password=sensitive,
+        autofocus=True,
+        on_submit=lambda e: on_submit(field.value or ""),
+    )
+    return ft.Container(
+        padding=ft.padding.all(24),
+        content=ft.Column(
+            spacing=12,
+            controls=[
+                ft.Text(prompt, size=16, selectable=True),
+                spell_check(field) if spell_check and not sensitive else field,
+                ft.ElevatedButton(
+                    tr("Send"),
+                    icon=ft.Icons.SEND,
+                    on_click=lambda e: on_submit(field.value or ""),
+                ),
+            ],
+        ),
+    )
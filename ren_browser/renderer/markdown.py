@@ -0,0 +1,223 @@
+"""Markdown renderer for Ren Browser.
+
+Parses Markdown pages served by nodes into the blocks of the micron
+renderer, so ``.md`` files are shown with the same headings, links and
+text styles as micron pages. Headings, emphasis, inline code, lists,
+quotes, rules, fenced code blocks and links are supported. Relative links
+are resolved against the page, so they open the files beside it on the
+same node.
+"""
+
+import posixpath
+import re
+
+import flet as ft
+
+from ren_browser.renderer.micron import (
+    MicronBlock,
+    blocks_to_text,
+    is_rtl,
+    render_blocks,
+)
+
+_FENCE_RE = re.compile(r"^ {0,3}(```|~~~)")
+_HEADING_RE = re.compile(r"^ {0,3}(#{1,6})\s+(.*?)\s*#*\s*$")
+_RULE_RE = re.compile(r"^ {0,3}([-*_])(\s*\1){2,}\s*$")
+_LIST_RE = re.compile(r"^(\s*)([-*+]|\d{1,9}[.)])\s+(.*)$")
+_QUOTE_RE = re.compile(r"^ {0,3}>\s?(.*)$")
+_INLINE_RE = re.compile(
+    r"(?P<code>`+)(?P<code_text>.+?)(?P=code)"
+    r"|!?\[(?P<label>[^\]]*)\]\((?P<url>[^)\s]+)(?:\s+\"[^\"]*\")?\)"
+    r"|<(?P<auto>[a-z][a-z0-9+.-]*:[^>\s]+)>"
+    r"|(?P<strong>\*\*|__)(?P<strong_text>.+?)(?P=strong)"
+    r"|(?P<em>[*_])(?P<em_text>[^*_]+?)(?P=em)"
+    r"|\\(?P<escaped>[\\`*_{}\[\]()#+\-.!>])",
+)
+
+
+def _span(text: str, bold=False, italic=False, monospace=False) -> dict:
+    return {
+        "text": text,
+        "bold": bold,
+        "italic": italic,
+        "underline": False,
+        "color": None,
+        "bgcolor": None,
+        "monospace": monospace,
+    }
+
+
+def resolve_markdown_link(url: str, page_path: str) -> str | None:
+    """Return the address a Markdown link opens, None for links within the page.
+
+    Relative paths are resolved against the directory of page_path and
+    returned as ``:/`` links, which open on the same node.
+    """
+    if url.startswith("#"):
+        return None
+    if ":" in url or not page_path:
+        return url
+    if not url.startswith("/"):
+        url = posixpath.join(posixpath.dirname(page_path), url)
+    return f":{posixpath.normpath(url)}"
+
+
+def parse_inline(
+    text: str,
+    page_path: str = "",
+    bold: bool = False,
+    italic: bool = False,
+) -> list:
+    """Parse the inline markup of a line into spans and ``(label, url)`` links.
+
+    Args:
+        text: Text of a paragraph, heading or list item.
+        page_path: Path of the page, relative links are resolved against it.
+        bold: Whether the text is already bold.
+        italic: Whether the text is already italic.
+
+    Returns:
+        list: Span dicts and link tuples in the order they appear.
+
+    """
+    parts = []
+    position = 0
+    for match in _INLINE_RE.finditer(text):
+        if match.start() > position:
+            parts.append(_span(text[position : match.start()], bold, italic))
+        position = match.end()
+        if match.group("code"):
+            parts.append(_span(match.group("code_text").strip(), monospace=True))
+        elif match.group("url") is not None:
+            label = match.group("label")
+            target = resolve_markdown_link(match.group("url"), page_path)
+            if target is None:
+                parts.extend(parse_inline(label, page_path, bold, italic))
+            else:
+                parts.append((label, target))
+        elif match.group("auto"):
+            parts.append(("", match.group("auto")))
+        elif match.group("strong"):
+            parts.extend(
+                parse_inline(match.group("strong_text"), page_path, True, italic),
+            )
+        elif match.group("em"):
+            parts.extend(parse_inline(match.group("em_text"), page_path, bold, True))
+        else:
+            parts.append(_span(match.group("escaped"), bold, italic))
+    if position < len(text):
+        parts.append(_span(text[position:], bold, italic))
+    return parts
+
+
+def _plain(parts: list) -> str:
+    return "".join(
+        part[0] if isinstance(part, tuple) else part["text"] for part in parts
+    )
+
+
+def _text_block(parts: list, level: int = 0) -> MicronBlock:
+    text = _plain(parts)
+    kind = "links" if any(isinstance(part, tuple) for part in parts) else "text"
+    return MicronBlock(kind, level, parts=tuple(parts), rtl=is_rtl(text))
+
+
+def parse_markdown(content: str, page_path: str = "") -> tuple[MicronBlock, ...]:
+    """Parse a Markdown page into blocks of the micron renderer.
+
+    The lines of a paragraph are joined into one block, as Markdown wraps
+    them into a single paragraph.
+
+    Args:
+        content: Markdown source.
+        page_path: Path of the page on its node, for relative links.
+
+    Returns:
+        tuple[MicronBlock, ...]: The blocks of the page.
+
+    """
+    blocks = []
+    paragraph: list[str] = []
+    fence = None
+
+    def flush():
+        if paragraph:
+            text = " ".join(line.strip() for line in paragraph)
+            blocks.append(_text_block(parse_inline(text, page_path)))
+            paragraph.clear()
+
+    for line in content.replace("\r\n", "\n").split("\n"):
+        fence_match = _FENCE_RE.match(line)
+        if fence is not None:
+            if fence_match and fence_match.group(1) == fence:
+                fence = None
+            elif line.strip():
+                blocks.append(MicronBlock("text", parts=(_span(line, monospace=True),)))
+            else:
+                blocks.append(MicronBlock("blank"))
+            continue
+        if fence_match:
+            flush()
+            fence = fence_match.group(1)
+            continue
+        if not line.strip():
+            flush()
+            blocks.append(MicronBlock("blank"))
+            continue
+        heading = _HEADING_RE.match(line)
+        if heading:
+            flush()
+            level = min(len(heading.group(1)), 3)
+            text = _plain(parse_inline(heading.group(2), page_path))
+            blocks.append(MicronBlock("heading", level, text, rtl=is_rtl(text)))
+            continue
+        if _RULE_RE.match(line):
+            flush()
+            blocks.append(MicronBlock("divider"))
+            continue
+        item = _LIST_RE.match(line)
+        if item:
+            flush()
+            indent, marker, text = item.groups()
+            bullet = marker if marker[0].isdigit() else "•"
+            parts = [_span(f"{bullet} "), *parse_inline(text, page_path)]
+            blocks.append(_text_block(parts, len(indent.expandtabs(4)) // 2))
+            continue
+        quote = _QUOTE_RE.match(line)
+        if quote:
+            flush()
+            blocks.append(
+                _text_block(parse_inline(quote.group(1), page_path, italic=True), 1),
+            )
+            continue
+        paragraph.append(line)
+    flush()
+    return tuple(blocks)
+
+
+def markdown_title(content: str) -> str | None:
+    """Return the text of the first heading of a Markdown page, if any."""
+    for block in parse_markdown(content):
+        if block.kind == "heading" and block.text:
+            return block.text
+    return None
+
+
+def markdown_to_text(content: str) -> str:
+    """Return the readable text of a Markdown page, links by their label."""
+    return blocks_to_text(parse_markdown(content))
+
+
+def render_markdown(content: str, page_path: str = "", **options) -> ft.Control:
+    """Render a Markdown page.
+
+    Args:
+        content: Markdown source.
+        page_path: Path of the page on its node, for relative links.
+        **options: Link callbacks and text options of render_blocks.
+
+    Returns:
+        ft.Control: The rendered page.
+
+    """
+    return render_blocks(parse_markdown(content, page_path), **options)
@@ -1,13 +1,23 @@
 """Micron markup renderer for Ren Browser.
 
-Provides rendering capabilities for micron markup content.
+Provides rendering capabilities for micron markup content. Each paragraph
+takes its direction from its first letter, so Arabic and Hebrew lines are
+laid out right to left, indented from the right and, unless the page
+aligns them explicitly, aligned to the right.
 """
 
+import hashlib
 import re
+import threading
+import unicodedata
+from collections import OrderedDict
+from collections.abc import Callable
+from dataclasses import dataclass, replace
 
 import flet as ft
 
-from ren_browser.renderer.plaintext import render_plaintext
+from ren_browser.accessibility.accessibility import link_label
+from ren_browser.renderer.plaintext import TEXT_SIZE, render_plaintext, scaled_size
 
 
 def hex_to_rgb(hex_color: str) -> str:
@@ -101,7 +111,78 @@ def parse_micron_line(line: str) -> list:
     return spans
 
 
-def render_micron(content: str, on_link_click=None) -> ft.Control:
+_RTL_CLASSES = ("R", "AL")
+
+
+def is_rtl(text: str) -> bool:
+    """Return whether text reads right to left.
+
+    As in the Unicode bidirectional algorithm, the first letter with a
+    strong direction decides; text without one reads left to right.
+    """
+    for char in text:
+        direction = unicodedata.bidirectional(char)
+        if direction in _RTL_CLASSES:
+            return True
+        if direction == "L":
+            return False
+    return False
+
+
+_LINK_RE = re.compile(r"`\[([^`]*)`([^\]]*)\]")
+
+
+def micron_links(content: str) -> list[tuple[str, str]]:
+    """Return the ``(label, target)`` of every link in micron markup."""
+    return [
+        (m.group(1), m.group(2))
+        for line in content.split("\n")
+        if not line.startswith("#")
+        for m in _LINK_RE.finditer(line)
+    ]
+
+
+def micron_to_text(content: str) -> str:
+    """Return the readable text of micron markup without formatting.
+
+    The text is taken from the blocks the page is rendered from, one line
+    each: comments are dropped, headings lose their markers, dividers
+    become empty lines and links are replaced by their label.
+
+    Args:
+        content: Micron markup content.
+
+    Returns:
+        str: Plain text as it reads on the rendered page.
+
+    """
+    return blocks_to_text(parse_micron(content))
+
+
+@dataclass(frozen=True)
+class Mark:
+    """A passage to highlight wherever it appears on a page.
+
+    Contains the text, the ``r,g,b`` colour it is highlighted in and an
+    optional note shown when the pointer rests on it.
+    """
+
+    text: str
+    color: str
+    note: str = ""
+
+
+def render_micron(
+    content: str,
+    on_link_click=None,
+    on_link_hover=None,
+    on_link_new_tab=None,
+    font_family: str | None = None,
+    text_scale: float = 1.0,
+    high_contrast: bool = False,
+    min_size: float = 0,
+    marks: tuple[Mark, ...] = (),
+) -> ft.Control:
     """Render micron markup content to a Flet control.
 
     Falls back to plaintext renderer if parsing fails.
@@ -109,37 +190,179 @@ def render_micron(content: str, on_link_click=None) -> ft.Control:
     Args:
         content: Micron markup content to render.
         on_link_click: Optional callback function(url) called when a link is clicked.
+        on_link_hover: Optional callback function(url | None) called when the
+            pointer enters or leaves a link.
+        on_link_new_tab: Optional callback function(url) called when a link is
+            middle-clicked to open it in a new tab.
+        font_family: Font to render the page in, or None for the theme font.
+        text_scale: Factor the text is zoomed by.
+        high_contrast: Whether to ignore the page's colours, always
+            underline links and outline the focused one thickly.
+        min_size: Smallest text size, whatever the page and zoom ask for.
+        marks: Passages highlighted on the page.
 
     Returns:
         ft.Control: Rendered content as a Flet control.
 
     """
     try:
-        return _render_micron_internal(content, on_link_click)
+        return render_blocks(
+            parse_micron(content),
+            on_link_click,
+            on_link_hover,
+            on_link_new_tab,
+            font_family,
+            text_scale,
+            high_contrast,
+            min_size,
+            marks,
+        )
     except Exception as e:
         print(f"Micron rendering failed: {e}, falling back to plaintext")
-        return render_plaintext(content)
+        return render_plaintext(content, font_family, text_scale, min_size)
 
 
-def _render_micron_internal(content: str, on_link_click=None) -> ft.Control:
-    """Internal micron rendering implementation.
+@dataclass(frozen=True)
+class MicronBlock:
+    """A parsed line of micron markup, ready to be turned into controls.
 
-    Args:
-        content: Micron markup content to render.
-        on_link_click: Optional callback function(url) called when a link is clicked.
+    ``kind`` is ``"blank"``, ``"heading"``, ``"divider"``, ``"links"`` or
+    ``"text"``. ``parts`` holds span dicts, and for rows with links also
+    ``(label, url)`` tuples in the order they appear. ``alignment`` is
+    ``START`` unless the page aligns the line, and ``rtl`` tells whether
+    the line reads right to left.
+    """
+
+    kind: str
+    level: int = 0
+    text: str = ""
+    parts: tuple = ()
+    alignment: str = ft.TextAlign.START
+    rtl: bool = False
+
+
+PARSE_CACHE_SIZE = 64
+RENDER_CHUNK = 300
+LOAD_MORE_PIXELS = 2000
+
+_parsed: OrderedDict[str, tuple[MicronBlock, ...]] = OrderedDict()
+_parsed_lock = threading.Lock()
 
-    Returns:
-        ft.Control: Rendered content as a Flet control.
 
+def parse_micron(content: str) -> tuple[MicronBlock, ...]:
+    """Parse micron markup into blocks, reusing the result for known content.
+
+    Parsed pages are kept by the hash of their content, so showing a page
+    again after a tab switch, a zoom change or from the cache skips parsing.
+    The most recently used pages are kept, up to ``PARSE_CACHE_SIZE``.
     """
-    lines = content.split("\n")
-    controls = []
+    key = hashlib.sha256(content.encode("utf-8")).hexdigest()
+    with _parsed_lock:
+        blocks = _parsed.get(key)
+        if blocks is not None:
+            _parsed.move_to_end(key)
+            return blocks
+    blocks = _parse_blocks(content)
+    with _parsed_lock:
+        _parsed[key] = blocks
+        while len(_parsed) > PARSE_CACHE_SIZE:
+            _parsed.popitem(last=False)
+    return blocks
+
+
+def clear_parse_cache() -> None:
+    """Forget every parsed page."""
+    with _parsed_lock:
+        _parsed.clear()
+
+
+def blocks_to_text(blocks: tuple[MicronBlock, ...]) -> str:
+    """Return the text of parsed blocks, one line each, links by their label."""
+    lines = []
+    for block in blocks:
+        if block.kind == "heading":
+            lines.append(block.text)
+        elif block.kind in ("text", "links"):
+            lines.append(
+                "".join(
+                    part[0] or part[1] if isinstance(part, tuple) else part["text"]
+                    for part in block.parts
+                ),
+            )
+        else:
+            lines.append("")
+    return "\n".join(lines)
+
+
+def mark_blocks(
+    blocks: tuple[MicronBlock, ...],
+    marks: tuple[Mark, ...],
+) -> tuple[MicronBlock, ...]:
+    """Return blocks with the passages of marks highlighted.
+
+    A passage running over several lines is matched line by line. Text is
+    only highlighted outside links, and the blocks given, which may be
+    shared with the parse cache, are left unchanged.
+    """
+    pieces = [
+        (line.strip(), mark)
+        for mark in marks
+        for line in mark.text.splitlines()
+        if line.strip()
+    ]
+    if not pieces:
+        return blocks
+    return tuple(
+        _mark_block(block, pieces) if block.kind in ("text", "links") else block
+        for block in blocks
+    )
+
+
+def _mark_block(block: MicronBlock, pieces) -> MicronBlock:
+    text = "".join(
+        part[0] or part[1] if isinstance(part, tuple) else part["text"]
+        for part in block.parts
+    )
+    # Mark of each character of the block's text, the first one wins
+    marked: list[Mark | None] = [None] * len(text)
+    for piece, mark in pieces:
+        start = text.find(piece)
+        while start >= 0:
+            for index in range(start, start + len(piece)):
+                marked[index] = marked[index] or mark
+            start = text.find(piece, start + len(piece))
+    if not any(marked):
+        return block
+    parts = []
+    position = 0
+    for part in block.parts:
+        if isinstance(part, tuple):
+            parts.append(part)
+            position += len(part[0] or part[1])
+            continue
+        end = position + len(part["text"])
+        run_start = position
+        for index in range(position + 1, end + 1):
+            if index == end or marked[index] is not marked[run_start]:
+                mark = marked[run_start]
+                piece = {**part, "text": text[run_start:index]}
+                if mark is not None:
+                    piece.update(color="0,0,0", bgcolor=mark.color, note=mark.note)
+                    piece["highlight"] = True
+                parts.append(piece)
+                run_start = index
+        position = end
+    return replace(block, parts=tuple(parts))
+
+
+def _parse_blocks(content: str) -> tuple[MicronBlock, ...]:
+    blocks = []
     section_level = 0
-    alignment = ft.TextAlign.LEFT
+    alignment = ft.TextAlign.START
 
-    for line in lines:
+    for line in content.split("\n"):
         if not line:
-            controls.append(ft.Container(height=10))
+            blocks.append(MicronBlock("blank"))
             continue
 
         if line.startswith("#"):
@@ -155,7 +378,7 @@ def _render_micron_internal(content: str, on_link_click=None) -> ft.Control:
             alignment = ft.TextAlign.RIGHT
             line = line[2:]
         elif line.startswith("`a"):
-            alignment = ft.TextAlign.LEFT
+            alignment = ft.TextAlign.START
             line = line[2:]
 
         if line.startswith(">"):
@@ -164,111 +387,290 @@ def _render_micron_internal(content: str, on_link_click=None) -> ft.Control:
                 level += 1
             section_level = level
             heading_text = line[level:].strip()
-
             if heading_text:
-                controls.append(
-                    ft.Container(
-                        content=ft.Text(
-                            heading_text,
-                            size=20 - (level * 2),
-                            weight=ft.FontWeight.BOLD,
-                            color=ft.Colors.BLUE_400,
-                        ),
-                        padding=ft.padding.only(left=level * 20, top=10, bottom=5),
+                blocks.append(
+                    MicronBlock(
+                        "heading",
+                        level,
+                        heading_text,
+                        rtl=is_rtl(heading_text),
                     ),
                 )
             continue
 
         if line.strip() == "-":
-            controls.append(
-                ft.Container(
-                    content=ft.Divider(color=ft.Colors.GREY_700),
-                    padding=ft.padding.only(left=section_level * 20),
-                ),
-            )
+            blocks.append(MicronBlock("divider", section_level))
             continue
 
         if "`[" in line:
-            row_controls = []
+            parts = []
             last_end = 0
-
-            for link_match in re.finditer(r"`\[([^`]*)`([^\]]*)\]", line):
+            for link_match in _LINK_RE.finditer(line):
                 before = line[last_end : link_match.start()]
                 if before:
-                    before_spans = parse_micron_line(before)
-                    row_controls.extend(
-                        create_text_span(span) for span in before_spans
-                    )
-
-                label = link_match.group(1)
-                url = link_match.group(2)
-
-                def make_link_handler(link_url):
-                    def handler(e):
-                        if on_link_click:
-                            on_link_click(link_url)
-
-                    return handler
-
-                row_controls.append(
-                    ft.TextButton(
-                        text=label if label else url,
-                        style=ft.ButtonStyle(
-                            color=ft.Colors.BLUE_400,
-                            overlay_color=ft.Colors.BLUE_900,
-                        ),
-                        on_click=make_link_handler(url),
-                    ),
-                )
-
+                    parts.extend(parse_micron_line(before))
+                parts.append((link_match.group(1), link_match.group(2)))
                 last_end = link_match.end()
-
             after = line[last_end:]
             if after:
-                after_spans = parse_micron_line(after)
-                row_controls.extend(
-                    create_text_span(span) for span in after_spans
+                parts.extend(parse_micron_line(after))
+            if parts:
+                text = "".join(
+                    part[0] or part[1] if isinstance(part, tuple) else part["text"]
+                    for part in parts
                 )
-
-            if row_controls:
-                controls.append(
-                    ft.Container(
-                        content=ft.Row(
-                            controls=row_controls,
-                            spacing=0,
-                            wrap=True,
-                        ),
-                        padding=ft.padding.only(left=section_level * 20),
+                blocks.append(
+                    MicronBlock(
+                        "links",
+                        section_level,
+                        parts=tuple(parts),
+                        rtl=is_rtl(text),
                     ),
                 )
                 continue
 
         spans = parse_micron_line(line)
         if spans:
-            text_controls = [create_text_span(span) for span in spans]
-
-            controls.append(
-                ft.Container(
-                    content=ft.Row(
-                        controls=text_controls,
-                        spacing=0,
-                        wrap=True,
-                        alignment=alignment,
+            blocks.append(
+                MicronBlock(
+                    "text",
+                    section_level,
+                    parts=tuple(spans),
+                    alignment=alignment,
+                    rtl=is_rtl("".join(span["text"] for span in spans)),
+                ),
+            )
+
+    return tuple(blocks)
+
+
+def row_alignment(alignment: str, rtl: bool) -> ft.MainAxisAlignment:
+    """Return the row alignment for a paragraph's alignment and direction.
+
+    Rows of right-to-left paragraphs lay out from the right, so there the
+    start is the right edge and explicit left and right alignments swap.
+    """
+    if alignment == ft.TextAlign.CENTER:
+        return ft.MainAxisAlignment.CENTER
+    if alignment == ft.TextAlign.START:
+        return ft.MainAxisAlignment.START
+    if (alignment == ft.TextAlign.RIGHT) != rtl:
+        return ft.MainAxisAlignment.END
+    return ft.MainAxisAlignment.START
+
+
+def _indent(level: int, rtl: bool, **padding) -> ft.Padding:
+    # Sections are indented from the side the paragraph starts on
+    if rtl:
+        return ft.padding.only(right=level * 20, **padding)
+    return ft.padding.only(left=level * 20, **padding)
+
+
+def render_blocks(
+    blocks: tuple[MicronBlock, ...],
+    on_link_click=None,
+    on_link_hover=None,
+    on_link_new_tab=None,
+    font_family: str | None = None,
+    text_scale: float = 1.0,
+    high_contrast: bool = False,
+    min_size: float = 0,
+    marks: tuple[Mark, ...] = (),
+) -> ft.Control:
+    """Render parsed blocks, of micron or another format mapped onto them.
+
+    Args:
+        blocks: Blocks to render, in order.
+        on_link_click: Optional callback function(url) called when a link is clicked.
+        on_link_hover: Optional callback function(url | None) called on link hover.
+        on_link_new_tab: Optional callback function(url) called on middle-click.
+        font_family: Font to render the page in, or None for the theme font.
+        text_scale: Factor the text is zoomed by.
+        high_contrast: Whether to render for the High Contrast theme.
+        min_size: Smallest text size.
+        marks: Passages highlighted on the page.
+
+    Returns:
+        ft.Control: Rendered content as a Flet control.
+
+    """
+    blocks = mark_blocks(blocks, marks)
+    focus_width = 3 if high_contrast else 2
+
+    def make_link_handler(link_url):
+        def handler(e):
+            if on_link_click:
+                on_link_click(link_url)
+
+        return handler
+
+    def make_hover_handler(link_url):
+        def handler(e):
+            if on_link_hover:
+                on_link_hover(link_url if e.data == "true" else None)
+
+        return handler
+
+    def make_new_tab_handler(link_url):
+        def handler(e):
+            on_link_new_tab(link_url)
+
+        return handler
+
+    def link_control(label: str, url: str) -> ft.Control:
+        text = label if label else url
+        link_button = ft.TextButton(
+            content=ft.Text(text, semantics_label=link_label(label, url)),
+            data=url,
+            style=ft.ButtonStyle(
+                color=ft.Colors.TERTIARY,
+                overlay_color=ft.Colors.PRIMARY_CONTAINER,
+                # A ring around the link that has the keyboard focus
+                side={
+                    ft.ControlState.FOCUSED: ft.BorderSide(
+                        focus_width,
+                        ft.Colors.PRIMARY,
+                    ),
+                    ft.ControlState.DEFAULT: ft.BorderSide(0, ft.Colors.TRANSPARENT),
+                },
+                text_style=ft.TextStyle(
+                    font_family=font_family,
+                    size=scaled_size(TEXT_SIZE, text_scale, min_size),
+                    decoration=(
+                        ft.TextDecoration.UNDERLINE if high_contrast else None
                     ),
-                    padding=ft.padding.only(left=section_level * 20),
                 ),
+            ),
+            on_click=make_link_handler(url),
+            on_hover=make_hover_handler(url),
+        )
+        if on_link_new_tab:
+            # Flet reports no modifier keys with clicks, so a middle
+            # click is what opens a link in a new background tab.
+            link_button = ft.GestureDetector(
+                content=link_button,
+                on_tertiary_tap_down=make_new_tab_handler(url),
+            )
+        return link_button
+
+    def build(block: MicronBlock) -> ft.Control:
+        if block.kind == "blank":
+            return ft.Container(height=10)
+        if block.kind == "heading":
+            return ft.Container(
+                content=ft.Text(
+                    block.text,
+                    size=scaled_size(20 - (block.level * 2), text_scale, min_size),
+                    weight=ft.FontWeight.BOLD,
+                    color=ft.Colors.PRIMARY,
+                    font_family=font_family,
+                ),
+                padding=_indent(block.level, block.rtl, top=10, bottom=5),
+                alignment=ft.alignment.center_right if block.rtl else None,
+                rtl=block.rtl,
+            )
+        if block.kind == "divider":
+            return ft.Container(
+                content=ft.Divider(color=ft.Colors.OUTLINE_VARIANT),
+                padding=ft.padding.only(left=block.level * 20),
+            )
+        row_controls = [
+            link_control(*part)
+            if isinstance(part, tuple)
+            else create_text_span(
+                part,
+                font_family,
+                text_scale,
+                high_contrast,
+                min_size,
             )
+            for part in block.parts
+        ]
+        row = ft.Row(
+            controls=row_controls,
+            spacing=0,
+            wrap=True,
+            alignment=row_alignment(block.alignment, block.rtl),
+        )
+        return ft.Container(
+            content=row,
+            padding=_indent(block.level, block.rtl),
+            rtl=block.rtl,
+        )
 
-    return ft.Column(
-        controls=controls,
-        spacing=5,
-        scroll=ft.ScrollMode.AUTO,
-        expand=True,
-    )
+    return MicronView(blocks, build)
+
+
+def near_end(e) -> bool:
+    """Return whether a scroll event is close to the end of the content."""
+    return e.max_scroll_extent - e.pixels < LOAD_MORE_PIXELS
 
 
-def create_text_span(span: dict) -> ft.Text:
-    """Create a Text control from a span dict."""
+class MicronView(ft.Column):
+    """A rendered micron page that builds its controls as it is scrolled.
+
+    Only the first ``RENDER_CHUNK`` blocks are turned into controls at
+    first, and the next ones are added each time the view is scrolled close
+    to its end, so a page with thousands of lines does not create thousands
+    of controls before anything is shown.
+    """
+
+    def __init__(
+        self,
+        blocks: tuple[MicronBlock, ...],
+        build: Callable[[MicronBlock], ft.Control],
+    ):
+        """Build the controls for the first blocks of a page.
+
+        Args:
+            blocks: Parsed blocks of the page.
+            build: Function turning a block into its control.
+
+        """
+        super().__init__(
+            controls=[],
+            spacing=5,
+            scroll=ft.ScrollMode.AUTO,
+            expand=True,
+            on_scroll_interval=100,
+        )
+        self.blocks = blocks
+        self.build = build
+        self.rendered = 0
+        self.on_scroll = self._on_scroll
+        self.render_more()
+
+    def render_more(self) -> bool:
+        """Add the controls for the next chunk of blocks.
+
+        Returns:
+            Whether any controls were added.
+
+        """
+        chunk = self.blocks[self.rendered : self.rendered + RENDER_CHUNK]
+        self.controls.extend(self.build(block) for block in chunk)
+        self.rendered += len(chunk)
+        return bool(chunk)
+
+    def _on_scroll(self, e) -> None:  # type: ignore
+        if near_end(e) and self.render_more():
+            self.update()
+
+
+def create_text_span(
+    span: dict,
+    font_family: str | None = None,
+    text_scale: float = 1.0,
+    high_contrast: bool = False,
+    min_size: float = 0,
+) -> ft.Text:
+    """Create a Text control from a span dict, in a font and zoom if given.
+
+    In high contrast the span's own colours are dropped for the theme's,
+    except on highlighted passages. Spans marked ``monospace`` keep a
+    monospace font whatever the page font, and the note of a highlighted
+    passage is shown as its tooltip.
+    """
     styles = []
     if span["bold"]:
         styles.append(ft.TextStyle(weight=ft.FontWeight.BOLD))
@@ -276,8 +678,9 @@ def create_text_span(span: dict) -> ft.Text:
         styles.append(ft.TextStyle(italic=True))
 
     text_decoration = ft.TextDecoration.UNDERLINE if span["underline"] else None
-    color = span["color"]
-    bgcolor = span["bgcolor"]
+    own_colors = span.get("highlight") or not high_contrast
+    color = span["color"] if own_colors else None
+    bgcolor = span["bgcolor"] if own_colors else None
 
     text_style = ft.TextStyle(
         weight=ft.FontWeight.BOLD if span["bold"] else None,
@@ -292,4 +695,7 @@ def create_text_span(span: dict) -> ft.Text:
         bgcolor=f"rgb({bgcolor})" if bgcolor else None,
         selectable=True,
         no_wrap=False,
+        font_family="monospace" if span.get("monospace") else font_family,
+        size=scaled_size(TEXT_SIZE, text_scale, min_size),
+        tooltip=span.get("note") or None,
     )
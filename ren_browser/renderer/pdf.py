@@ -0,0 +1,304 @@
+"""PDF export for Ren Browser.
+
+Lays out a page's styled spans onto paginated A4 pages using the standard PDF
+fonts, so node bulletins can be archived without extra dependencies.
+"""
+
+from dataclasses import dataclass, field
+
+from ren_browser.renderer.micron import _LINK_RE, parse_micron_line
+
+PAGE_WIDTH = 595
+PAGE_HEIGHT = 842
+MARGIN = 50
+BODY_SIZE = 11
+MONO_SIZE = 9.5
+INDENT = 18
+
+# Standard Type 1 fonts every PDF reader provides.
+FONTS = {
+    "F1": "Helvetica",
+    "F2": "Helvetica-Bold",
+    "F3": "Helvetica-Oblique",
+    "F4": "Helvetica-BoldOblique",
+    "F5": "Courier",
+}
+# Average glyph widths as a share of the font size, used for line wrapping.
+CHAR_WIDTH = {"F1": 0.52, "F2": 0.56, "F3": 0.52, "F4": 0.56, "F5": 0.6}
+HEADING_COLOR = (0.1, 0.3, 0.6)
+
+
+@dataclass
+class Run:
+    """A piece of text drawn in a single style."""
+
+    text: str
+    font: str = "F1"
+    color: tuple[float, float, float] | None = None
+
+
+@dataclass
+class Block:
+    """A laid out line of the page before wrapping.
+
+    Kind is "text", "heading", "divider" or "blank".
+    """
+
+    kind: str
+    runs: list[Run] = field(default_factory=list)
+    indent: int = 0
+    size: float = BODY_SIZE
+
+
+def _font(bold: bool, italic: bool) -> str:
+    return {(False, False): "F1", (True, False): "F2", (False, True): "F3"}.get(
+        (bold, italic),
+        "F4",
+    )
+
+
+def _color(rgb: str | None) -> tuple[float, float, float] | None:
+    """Convert a renderer color to PDF values, dropping colors too pale for paper."""
+    if not rgb:
+        return None
+    r, g, b = (int(part) / 255 for part in rgb.split(","))
+    if 0.299 * r + 0.587 * g + 0.114 * b > 0.8:
+        return None
+    return (r, g, b)
+
+
+def layout_micron(content: str) -> list[Block]:
+    """Turn micron markup into blocks, following the on-screen renderer.
+
+    Text between two ``\\`=`` lines is kept as a monospace block.
+    """
+    blocks: list[Block] = []
+    level = 0
+    literal = False
+    for line in content.split("\n"):
+        if line.strip() == "`=":
+            literal = not literal
+            continue
+        if literal:
+            blocks.append(Block("text", [Run(line, "F5")], level, MONO_SIZE))
+            continue
+        if not line:
+            blocks.append(Block("blank"))
+            continue
+        if line.startswith("#"):
+            continue
+        if line.startswith(("`c", "`l", "`r", "`a")):
+            line = line[2:]
+        if line.startswith(">"):
+            level = len(line) - len(line.lstrip(">"))
+            heading = line[level:].strip()
+            if heading:
+                text = "".join(span["text"] for span in parse_micron_line(heading))
+                blocks.append(
+                    Block(
+                        "heading",
+                        [Run(text, "F2", HEADING_COLOR)],
+                        level - 1 if level else 0,
+                        20 - level * 2,
+                    ),
+                )
+            continue
+        if line.strip() == "-":
+            blocks.append(Block("divider", indent=level))
+            continue
+        line = _LINK_RE.sub(lambda m: m.group(1) or m.group(2), line)
+        runs = [
+            Run(
+                span["text"],
+                _font(span["bold"], span["italic"]),
+                _color(span["color"]),
+            )
+            for span in parse_micron_line(line)
+        ]
+        if runs:
+            blocks.append(Block("text", runs, level))
+    return blocks
+
+
+def layout_plaintext(content: str) -> list[Block]:
+    """Turn plain text into monospace blocks."""
+    return [
+        Block("text", [Run(line, "F5")], size=MONO_SIZE) if line else Block("blank")
+        for line in content.split("\n")
+    ]
+
+
+def _text_width(text: str, font: str, size: float) -> float:
+    return len(text) * CHAR_WIDTH[font] * size
+
+
+def _wrap(block: Block, width: float) -> list[list[Run]]:
+    """Split a block's runs into lines that fit the given width."""
+    lines: list[list[Run]] = [[]]
+    used = 0.0
+    for run in block.runs:
+        words = run.text.split(" ")
+        for n, word in enumerate(words):
+            piece = word if n == 0 else f" {word}"
+            piece_width = _text_width(piece, run.font, block.size)
+            if used + piece_width > width and used > 0:
+                lines.append([])
+                used = 0.0
+                piece = word
+                piece_width = _text_width(piece, run.font, block.size)
+            while piece_width > width and len(piece) > 1:
+                fit = max(int(width / (CHAR_WIDTH[run.font] * block.size)), 1)
+                lines[-1].append(Run(piece[:fit], run.font, run.color))
+                lines.append([])
+                piece = piece[fit:]
+                piece_width = _text_width(piece, run.font, block.size)
+            if piece:
+                lines[-1].append(Run(piece, run.font, run.color))
+                used += piece_width
+    return lines
+
+
+def _escape(text: str) -> str:
+    text = text.encode("cp1252", errors="replace").decode("cp1252")
+    return text.replace("\\", "\\\\").replace("(", "\\(").replace(")", "\\)")
+
+
+def paginate(
+    blocks: list[Block],
+    title: str = "",
+    monochrome: bool = False,
+) -> list[str]:
+    """Lay blocks out onto pages.
+
+    Args:
+        blocks: Blocks of the page, in order.
+        title: Heading printed at the top of the first page.
+        monochrome: Print all text in black, dropping the page's colors.
+
+    Returns:
+        The content stream of each page.
+
+    """
+    pages: list[list[str]] = [[]]
+    y = PAGE_HEIGHT - MARGIN
+    body_width = PAGE_WIDTH - 2 * MARGIN
+
+    def new_page():
+        nonlocal y
+        pages.append([])
+        y = PAGE_HEIGHT - MARGIN
+
+    def need(height):
+        if y - height < MARGIN:
+            new_page()
+
+    if title:
+        heading = Block("heading", [Run(title, "F2")], size=16)
+        blocks = [heading, Block("blank"), *blocks]
+
+    for block in blocks:
+        x = MARGIN + block.indent * INDENT
+        if block.kind == "blank":
+            y -= BODY_SIZE * 0.8
+            continue
+        if block.kind == "divider":
+            need(10)
+            y -= 5
+            pages[-1].append(
+                f"0.6 0.6 0.6 RG 0.5 w {x} {y:.2f} m "
+                f"{PAGE_WIDTH - MARGIN} {y:.2f} l S",
+            )
+            y -= 5
+            continue
+        leading = block.size * (1.5 if block.kind == "heading" else 1.3)
+        for runs in _wrap(block, body_width - block.indent * INDENT):
+            need(leading)
+            y -= leading
+            parts = [f"BT {x} {y:.2f} Td"]
+            for run in runs:
+                r, g, b = (None if monochrome else run.color) or (0, 0, 0)
+                parts.append(
+                    f"/{run.font} {block.size} Tf {r:.3f} {g:.3f} {b:.3f} rg "
+                    f"({_escape(run.text)}) Tj",
+                )
+            parts.append("ET")
+            pages[-1].append(" ".join(parts))
+    return ["\n".join(ops) for ops in pages]
+
+
+def build_pdf(streams: list[str]) -> bytes:
+    """Assemble page content streams into a PDF file."""
+    font_ids = {name: 3 + n for n, name in enumerate(FONTS)}
+    first_page = 3 + len(FONTS)
+    objects: list[bytes] = [
+        b"<< /Type /Catalog /Pages 2 0 R >>",
+        b"",
+    ]
+    for base in FONTS.values():
+        objects.append(
+            f"<< /Type /Font /Subtype /Type1 /BaseFont /{base} "
+            f"/Encoding /WinAnsiEncoding >>".encode(),
+        )
+    fonts = " ".join(f"/{name} {obj} 0 R" for name, obj in font_ids.items())
+    kids = []
+    for n, stream in enumerate(streams):
+        page_id = first_page + n * 2
+        kids.append(f"{page_id} 0 R")
+        objects.append(
+            f"<< /Type /Page /Parent 2 0 R "
+            f"/MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] "
+            f"/Resources << /Font << {fonts} >> >> "
+            f"/Contents {page_id + 1} 0 R >>".encode(),
+        )
+        data = stream.encode("cp1252", errors="replace")
+        objects.append(
+            f"<< /Length {len(data)} >>\nstream\n".encode() + data + b"\nendstream",
+        )
+    objects[1] = (
+        f"<< /Type /Pages /Kids [{' '.join(kids)}] /Count {len(kids)} >>".encode()
+    )
+
+    out = bytearray(b"%PDF-1.4\n")
+    offsets = []
+    for n, obj in enumerate(objects, start=1):
+        offsets.append(len(out))
+        out += f"{n} 0 obj\n".encode() + obj + b"\nendobj\n"
+    xref = len(out)
+    out += f"xref\n0 {len(objects) + 1}\n0000000000 65535 f \n".encode()
+    for offset in offsets:
+        out += f"{offset:010d} 00000 n \n".encode()
+    out += (
+        f"trailer\n<< /Size {len(objects) + 1} /Root 1 0 R >>\n"
+        f"startxref\n{xref}\n%%EOF\n"
+    ).encode()
+    return bytes(out)
+
+
+def page_to_pdf(
+    content: str,
+    page_path: str,
+    title: str = "",
+    monochrome: bool = False,
+) -> bytes:
+    """Export a fetched page as a PDF document.
+
+    Args:
+        content: Page source.
+        page_path: Path of the page on its node, deciding how it is laid out.
+        title: Heading printed at the top of the first page.
+        monochrome: Print all text in black, as for printing.
+
+    Returns:
+        bytes: The PDF file.
+
+    """
+    blocks = None
+    if page_path.endswith(".mu"):
+        try:
+            blocks = layout_micron(content)
+        except ValueError:
+            # Markup the parser rejects is printed as source, as on screen
+            blocks = None
+    if blocks is None:
+        blocks = layout_plaintext(content)
+    return build_pdf(paginate(blocks, title, monochrome))
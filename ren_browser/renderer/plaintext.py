@@ -5,12 +5,72 @@ Provides fallback rendering for plaintext content and source viewing.
 
 import flet as ft
 
+TEXT_SIZE = 14
 
-def render_plaintext(content: str) -> ft.Control:
-    """Fallback plaintext renderer: displays raw text safely in a monospace, selectable control."""
+
+def scaled_size(
+    size: float,
+    text_scale: float,
+    min_size: float = 0,
+) -> float | None:
+    """Return a text size zoomed by a scale, or None for Flet's default size.
+
+    Sizes that would come out below min_size are raised to it.
+    """
+    if size * text_scale < min_size:
+        return min_size
+    if text_scale == 1:
+        return None if size == TEXT_SIZE else size
+    return size * text_scale
+
+
+def render_plaintext(
+    content: str,
+    font_family: str | None = None,
+    text_scale: float = 1.0,
+    min_size: float = 0,
+) -> ft.Control:
+    """Fallback plaintext renderer: displays raw text safely in a monospace, selectable control.
+
+    A font family replaces the monospace font if given, text_scale zooms
+    the text and min_size keeps it from getting smaller than that.
+    """
     return ft.Text(
         content,
         selectable=True,
-        font_family="monospace",
+        font_family=font_family or "monospace",
+        size=scaled_size(TEXT_SIZE, text_scale, min_size),
         expand=True,
     )
+
+
+def render_source(content: str, min_size: float = 0) -> ft.Control:
+    """Show the source of a page in monospace with its lines numbered.
+
+    Lines are not wrapped, so each number stays beside its line, and the
+    numbers are left out when the source is selected and copied.
+    """
+    lines = content.split("\n")
+    size = scaled_size(TEXT_SIZE, 1.0, min_size)
+    width = len(str(len(lines)))
+    return ft.Row(
+        controls=[
+            ft.Text(
+                "\n".join(str(n).rjust(width) for n in range(1, len(lines) + 1)),
+                font_family="monospace",
+                size=size,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+                no_wrap=True,
+            ),
+            ft.Text(
+                content,
+                selectable=True,
+                font_family="monospace",
+                size=size,
+                no_wrap=True,
+            ),
+        ],
+        spacing=16,
+        vertical_alignment=ft.CrossAxisAlignment.START,
+        scroll=ft.ScrollMode.AUTO,
+    )
@@ -0,0 +1,72 @@
+"""Page title extraction for Ren Browser.
+
+Derives a human readable title from fetched page content so tabs and the
+window can show something better than the raw address.
+"""
+
+import html
+import re
+
+from ren_browser.renderer.markdown import markdown_title
+from ren_browser.renderer.micron import parse_micron_line
+
+_HTML_TITLE_RE = re.compile(r"<title[^>]*>(.*?)</title>", re.IGNORECASE | re.DOTALL)
+_ALIGNMENT_TAGS = ("`c", "`l", "`r", "`a")
+
+
+def extract_micron_title(content: str) -> str | None:
+    """Return the text of the first micron heading, if any.
+
+    Args:
+        content: Micron markup content.
+
+    Returns:
+        The heading text with formatting tags stripped, or None.
+
+    """
+    for line in content.split("\n"):
+        if line.startswith(_ALIGNMENT_TAGS):
+            line = line[2:]
+        if not line.startswith(">"):
+            continue
+        heading = line.lstrip(">").strip()
+        if not heading:
+            continue
+        text = "".join(span["text"] for span in parse_micron_line(heading)).strip()
+        if text:
+            return text
+    return None
+
+
+def extract_html_title(content: str) -> str | None:
+    """Return the contents of the HTML ``<title>`` element, if any."""
+    match = _HTML_TITLE_RE.search(content)
+    if not match:
+        return None
+    text = " ".join(html.unescape(match.group(1)).split())
+    return text or None
+
+
+def extract_title(content: str, page_path: str = "") -> str | None:
+    """Extract a title from page content.
+
+    HTML pages use their ``<title>`` element and Markdown pages their first
+    heading, everything else is treated as micron and uses the first heading.
+
+    Args:
+        content: Fetched page content.
+        page_path: Path the content was fetched from, used to pick the format.
+
+    Returns:
+        The extracted title, or None when the page does not provide one.
+
+    """
+    if not content:
+        return None
+    if page_path.endswith((".html", ".htm")) or _HTML_TITLE_RE.search(content):
+        title = extract_html_title(content)
+        if title:
+            return title
+    if page_path.endswith(".md"):
+        return markdown_title(content)
+    return extract_micron_title(content)
@@ -287,3 +287,36 @@ def write_config_file(content: str) -> bool:
 def get_last_error() -> str | None:
     """Return the last recorded Reticulum error."""
     return rns_manager.get_last_error()
+
+
+def get_interface_statuses() -> list[dict]:
+    """Return the state and traffic of each transport interface.
+
+    Each status holds the interface's name, type, online state, bitrate
+    and bytes received and sent, and for radios the signal strength and
+    signal to noise ratio of the last packet when the radio reports them.
+    Local client interfaces connected to a shared instance are skipped.
+    """
+    statuses = []
+    interfaces = getattr(RNS.Transport, "interfaces", []) or []
+    for interface in interfaces:
+        if interface is None:
+            continue
+        if interface.__class__.__name__ == "LocalClientInterface" and getattr(
+            interface, "is_connected_to_shared_instance", False,
+        ):
+            continue
+        statuses.append(
+            {
+                "name": getattr(interface, "name", None)
+                or interface.__class__.__name__,
+                "online": bool(getattr(interface, "online", False)),
+                "type": interface.__class__.__name__,
+                "bitrate": getattr(interface, "bitrate", None),
+                "rxb": getattr(interface, "rxb", 0) or 0,
+                "txb": getattr(interface, "txb", 0) or 0,
+                "rssi": getattr(interface, "r_stat_rssi", None),
+                "snr": getattr(interface, "r_stat_snr", None),
+            },
+        )
+    return statuses
@@ -0,0 +1,220 @@
+"""Full-text search of the pages kept on this device for Ren Browser.
+
+There is no search engine on the mesh, so the closest substitute is
+searching what has already been fetched: the text of cached pages and
+reading list copies, and the titles and addresses of visited pages.
+Results are ranked by where and how often the words of the query occur
+and come with a snippet of the text around the first match.
+"""
+
+import re
+from dataclasses import dataclass, field
+
+from ren_browser.renderer.markdown import markdown_to_text
+from ren_browser.renderer.micron import micron_to_text
+from ren_browser.renderer.title import extract_title
+
+SNIPPET_LENGTH = 160
+MAX_RESULTS = 50
+# Weight of one occurrence of a word in each part of a page, and the most
+# occurrences in the text that count so long pages do not always win
+TITLE_WEIGHT = 8
+ADDRESS_WEIGHT = 3
+TEXT_WEIGHT = 1
+MAX_TEXT_HITS = 10
+
+
+@dataclass
+class SearchDocument:
+    """A page that can be searched.
+
+    Contains the address, title and readable text of the page and where it
+    was found, such as ``cache``, ``reading_list`` or ``history``.
+    """
+
+    address: str
+    title: str
+    text: str = ""
+    sources: list[str] = field(default_factory=list)
+
+
+@dataclass
+class SearchResult:
+    """A page matching a query.
+
+    Contains the page, its score and the snippet of its text shown below
+    the title, split into parts that are highlighted or not.
+    """
+
+    document: SearchDocument
+    score: int
+    snippet: list[tuple[str, bool]]
+
+
+def query_terms(query: str) -> list[str]:
+    """Return the distinct lowercased words of a query, in order."""
+    terms: list[str] = []
+    for word in query.lower().split():
+        if word not in terms:
+            terms.append(word)
+    return terms
+
+
+def readable_text(content: str, address: str) -> str:
+    """Return the text of a page as it reads, without markup."""
+    page_path = address.split(":", 1)[1] if ":" in address else ""
+    if page_path.endswith(".md"):
+        return markdown_to_text(content)
+    if page_path.endswith(".mu") or not page_path:
+        return micron_to_text(content)
+    return content
+
+
+def build_documents(page_cache, reading_list, history) -> list[SearchDocument]:
+    """Collect the searchable pages, one document per address.
+
+    Args:
+        page_cache: PageCache whose pages in memory and on disk are read.
+        reading_list: ReadingList whose offline copies are read.
+        history: HistoryManager whose visits give titles and addresses.
+
+    Returns:
+        The documents, pages with text before those only visited.
+
+    """
+    documents: dict[str, SearchDocument] = {}
+
+    def add(address, source, content=None, title=""):
+        document = documents.get(address)
+        if document is None:
+            document = documents[address] = SearchDocument(address, title)
+        if source not in document.sources:
+            document.sources.append(source)
+        if content and not document.text:
+            document.text = readable_text(content, address)
+            if not document.title:
+                page_path = address.split(":", 1)[-1]
+                document.title = extract_title(content, page_path) or ""
+        if title and not document.title:
+            document.title = title
+
+    for address, entry in page_cache.entries():
+        add(address, "cache", entry.content)
+    if page_cache.disk is not None:
+        for address in page_cache.disk.addresses():
+            if address in documents:
+                continue
+            stored = page_cache.disk.get(address, use=False)
+            if stored is not None:
+                add(address, "cache", stored[0])
+    for item in reading_list.items():
+        add(item.address, "reading_list", reading_list.load(item.address), item.title)
+    for entry in history.entries():
+        if entry.title != entry.address:
+            add(entry.address, "history", title=entry.title)
+        else:
+            add(entry.address, "history")
+    for document in documents.values():
+        document.title = document.title or document.address
+    return list(documents.values())
+
+
+def score(document: SearchDocument, terms: list[str]) -> int:
+    """Return how well a page matches the words of a query.
+
+    Every word has to occur in the title, address or text of the page,
+    otherwise the page does not match and scores 0.
+    """
+    title = document.title.lower()
+    address = document.address.lower()
+    text = document.text.lower()
+    total = 0
+    for term in terms:
+        hits = (
+            title.count(term) * TITLE_WEIGHT
+            + address.count(term) * ADDRESS_WEIGHT
+            + min(text.count(term), MAX_TEXT_HITS) * TEXT_WEIGHT
+        )
+        if not hits:
+            return 0
+        total += hits
+    return total
+
+
+def highlight(text: str, terms: list[str]) -> list[tuple[str, bool]]:
+    """Split text into parts, marking those that are words of the query."""
+    if not terms:
+        return [(text, False)] if text else []
+    pattern = re.compile(
+        "|".join(re.escape(term) for term in sorted(terms, key=len, reverse=True)),
+        re.IGNORECASE,
+    )
+    parts: list[tuple[str, bool]] = []
+    position = 0
+    for match in pattern.finditer(text):
+        if match.start() > position:
+            parts.append((text[position : match.start()], False))
+        parts.append((match.group(), True))
+        position = match.end()
+    if position < len(text):
+        parts.append((text[position:], False))
+    return parts
+
+
+def snippet(text: str, terms: list[str], length: int = SNIPPET_LENGTH) -> str:
+    """Return the part of a text around the first word of the query in it.
+
+    The snippet is cut at spaces where it can be and marked with an
+    ellipsis where text was left out. Without a match the text starts it.
+    """
+    text = " ".join(text.split())
+    lowered = text.lower()
+    found = [index for index in map(lowered.find, terms) if index >= 0]
+    first = min(found) if found else 0
+    start = max(first - length // 3, 0)
+    if start:
+        space = text.find(" ", start, first)
+        start = space + 1 if space >= 0 else start
+    end = min(start + length, len(text))
+    if end < len(text):
+        space = text.rfind(" ", max(first, start), end)
+        end = space if space > first else end
+    part = text[start:end].strip()
+    if start:
+        part = "…" + part
+    if end < len(text):
+        part += "…"
+    return part
+
+
+def search(
+    documents: list[SearchDocument],
+    query: str,
+    limit: int = MAX_RESULTS,
+) -> list[SearchResult]:
+    """Return the pages matching a query, best first.
+
+    Args:
+        documents: Pages to search, as built by build_documents.
+        query: Words that all have to occur in a page, in any case.
+        limit: Most results returned.
+
+    Returns:
+        The matching pages with their highlighted snippets.
+
+    """
+    terms = query_terms(query)
+    if not terms:
+        return []
+    scored = [
+        (score(document, terms), index, document)
+        for index, document in enumerate(documents)
+    ]
+    ranked = sorted(
+        ((points, index, document) for points, index, document in scored if points),
+        key=lambda item: (-item[0], item[1]),
+    )
+    return [
+        SearchResult(document, points, highlight(snippet(document.text, terms), terms))
+        for points, _, document in ranked[:limit]
+    ]
@@ -0,0 +1,157 @@
+"""Speed dial for Ren Browser.
+
+Keeps the addresses pinned to the new tab page, in the order the user
+arranged them, and fills the remaining tiles with the most visited pages.
+"""
+
+from collections import Counter
+from dataclasses import dataclass
+
+from ren_browser.pages.address import INTERNAL_SCHEME
+
+MAX_TILES = 8
+
+
+@dataclass
+class Tile:
+    """A speed dial tile.
+
+    Contains the address, the label shown on the tile and whether the user
+    pinned it or it was picked from history.
+    """
+
+    address: str
+    label: str
+    pinned: bool = True
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "Tile | None":
+        """Build a pinned tile from stored data, or None if it is malformed."""
+        if not isinstance(data, dict) or not data.get("address"):
+            return None
+        return cls(address=data["address"], label=data.get("label") or data["address"])
+
+
+def top_sites(entries, limit: int, exclude: set[str] | None = None) -> list[Tile]:
+    """Pick the most visited pages from history.
+
+    Args:
+        entries: History entries, most recent first.
+        limit: Number of tiles to return.
+        exclude: Addresses that already have a tile.
+
+    Returns:
+        Unpinned tiles, most visited first with ties going to the most recent.
+
+    """
+    exclude = exclude or set()
+    counts = Counter(entry.address for entry in entries)
+    titles: dict[str, str] = {}
+    order: list[str] = []
+    for entry in entries:
+        if entry.address not in titles:
+            titles[entry.address] = entry.title
+            order.append(entry.address)
+    candidates = [
+        address
+        for address in order
+        if address not in exclude and not address.startswith(INTERNAL_SCHEME)
+    ]
+    candidates.sort(key=lambda address: -counts[address])
+    return [
+        Tile(address, titles[address], pinned=False)
+        for address in candidates[:limit]
+    ]
+
+
+class SpeedDial:
+    """Keeps the pinned speed dial tiles and persists them through storage."""
+
+    def __init__(self, storage, max_tiles: int = MAX_TILES):
+        """Load the pinned tiles.
+
+        Args:
+            storage: StorageManager used to load and save the tiles.
+            max_tiles: Number of tiles shown on the new tab page.
+
+        """
+        self.storage = storage
+        self.max_tiles = max_tiles
+        self._pinned: list[Tile] = []
+        try:
+            stored = storage.load_speed_dial()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            tile = Tile.from_dict(data)
+            if tile is not None and self.get(tile.address) is None:
+                self._pinned.append(tile)
+
+    def get(self, address: str) -> Tile | None:
+        """Return the pinned tile for an address, if any."""
+        return next((tile for tile in self._pinned if tile.address == address), None)
+
+    def pinned(self) -> list[Tile]:
+        """Return the pinned tiles in display order."""
+        return list(self._pinned)
+
+    def tiles(self, history_entries=()) -> list[Tile]:
+        """Return the tiles to show: pinned ones first, then top sites."""
+        pinned = self._pinned[: self.max_tiles]
+        free = self.max_tiles - len(pinned)
+        exclude = {tile.address for tile in pinned}
+        return [*pinned, *top_sites(history_entries, free, exclude)]
+
+    def pin(self, address: str, label: str, position: int | None = None) -> Tile:
+        """Pin an address, or move it if it is already pinned.
+
+        Args:
+            address: Normalized page address.
+            label: Label shown on the tile.
+            position: Index to place the tile at, defaulting to the end.
+
+        Returns:
+            Tile: The pinned tile.
+
+        """
+        tile = self.get(address)
+        if tile is None:
+            tile = Tile(address, label or address)
+        else:
+            self._pinned.remove(tile)
+        if position is None:
+            position = len(self._pinned)
+        self._pinned.insert(max(0, min(position, len(self._pinned))), tile)
+        self._save()
+        return tile
+
+    def unpin(self, address: str) -> None:
+        """Remove an address from the speed dial."""
+        tile = self.get(address)
+        if tile is not None:
+            self._pinned.remove(tile)
+            self._save()
+
+    def rename(self, address: str, label: str) -> None:
+        """Change the label of a pinned tile."""
+        tile = self.get(address)
+        if tile is not None:
+            tile.label = label.strip() or address
+            self._save()
+
+    def move(self, address: str, position: int) -> None:
+        """Move a pinned tile to a new position."""
+        tile = self.get(address)
+        if tile is not None:
+            self.pin(address, tile.label, position)
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_speed_dial(
+                [
+                    {"address": tile.address, "label": tile.label}
+                    for tile in self._pinned
+                ],
+            )
+        except Exception:  # noqa: BLE001
+            pass
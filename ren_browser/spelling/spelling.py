@@ -0,0 +1,268 @@
+"""Spell checking for Ren Browser.
+
+Text typed into input fields is checked against a dictionary of the chosen
+language. Dictionaries are Hunspell ``.dic`` files, as installed with most
+Linux systems and offered for download for nearly every language, or plain
+word lists with one word a line. They are found in the dictionaries
+folder of the storage directory, the one packaged with the browser and the
+system's Hunspell folders, and named by their file name, such as
+``en_US``. Only the word list of a Hunspell dictionary is read, so words
+its affix rules would derive are not known.
+"""
+
+import re
+import shutil
+from dataclasses import dataclass
+from pathlib import Path
+
+DICTIONARY_EXTENSIONS = (".dic", ".txt")
+# Dictionaries packaged with the browser, such as en_US.dic
+BUNDLED_DICTIONARIES_DIR = Path(__file__).resolve().parent.parent / "dictionaries"
+SYSTEM_DICTIONARY_DIRS = (
+    Path("/usr/share/hunspell"),
+    Path("/usr/share/myspell"),
+    Path("/usr/share/myspell/dicts"),
+)
+MAX_SUGGESTIONS = 5
+# Longest word that is looked for two edits away, as that takes a while
+MAX_DEEP_SUGGESTION_LENGTH = 8
+
+_WORD_RE = re.compile(r"[^\W\d_]+(?:['’][^\W\d_]+)*")
+
+
+@dataclass(frozen=True)
+class Misspelling:
+    """A word not in the dictionary, with where it is in the text."""
+
+    word: str
+    start: int
+    end: int
+
+
+def find_dictionaries(*directories: Path) -> dict[str, Path]:
+    """Return the dictionary files in directories, keyed by name.
+
+    A name found in an earlier directory hides the same name in later ones.
+    """
+    found: dict[str, Path] = {}
+    for directory in directories:
+        try:
+            files = sorted(Path(directory).iterdir())
+        except OSError:
+            continue
+        for file in files:
+            if file.suffix.lower() in DICTIONARY_EXTENSIONS and file.is_file():
+                found.setdefault(file.stem, file)
+    return found
+
+
+def pick_dictionary(dictionaries: dict[str, Path], language: str) -> Path | None:
+    """Return the dictionary of a language, such as ``de`` or ``en_GB``.
+
+    A dictionary named after the language is used first, then the first
+    one of a region speaking it, such as ``de_DE`` for ``de``.
+    """
+    if not language:
+        return None
+    if language in dictionaries:
+        return dictionaries[language]
+    code = re.split(r"[_-]", language)[0].lower()
+    for name in sorted(dictionaries):
+        if re.split(r"[_-]", name)[0].lower() == code:
+            return dictionaries[name]
+    return None
+
+
+def read_dictionary(path: Path) -> set[str]:
+    """Read the words of a dictionary file, lowercased.
+
+    Hunspell files start with the number of words and follow each word
+    with its affix flags after a slash, which are both left out.
+
+    Raises:
+        OSError: If the file could not be read.
+
+    """
+    words = set()
+    text = Path(path).read_text(encoding="utf-8", errors="replace")
+    for number, line in enumerate(text.splitlines()):
+        word = line.split("/", 1)[0].strip()
+        if not word or word.startswith("#") or (number == 0 and word.isdigit()):
+            continue
+        words.add(word.lower())
+    return words
+
+
+class SpellChecker:
+    """Checks words against the words of a dictionary."""
+
+    def __init__(self, words: set[str]):
+        """Initialize the checker.
+
+        Args:
+            words: Known words, lowercased.
+
+        """
+        self.words = words
+        self.ignored: set[str] = set()
+        self.alphabet = "".join(sorted({letter for word in words for letter in word}))
+        self._suggestions: dict[str, list[str]] = {}
+
+    def known(self, word: str) -> bool:
+        """Return whether a word is spelled correctly, in any case.
+
+        Single letters and words in capitals only, like acronyms, always
+        are.
+        """
+        lowered = word.lower()
+        if len(word) < 2 or (word.isupper() and len(word) <= 5):
+            return True
+        if lowered in self.words or lowered in self.ignored:
+            return True
+        plain = lowered.replace("’", "'")
+        return plain in self.words or plain.split("'", 1)[0] in self.words
+
+    def ignore(self, word: str) -> None:
+        """Treat a word as spelled correctly until the browser is closed."""
+        self.ignored.add(word.lower())
+
+    def misspellings(self, text: str) -> list[Misspelling]:
+        """Return the words of a text that are not in the dictionary."""
+        return [
+            Misspelling(match.group(), match.start(), match.end())
+            for match in _WORD_RE.finditer(text)
+            if not self.known(match.group())
+        ]
+
+    def suggestions(self, word: str, limit: int = MAX_SUGGESTIONS) -> list[str]:
+        """Return known words close to a misspelled one, closest first.
+
+        Words two typing mistakes away are only looked for when none is one
+        away. Suggestions are capitalized like the misspelled word.
+        """
+        lowered = word.lower()
+        if lowered not in self._suggestions:
+            self._suggestions[lowered] = self._closest(lowered)
+        found = self._suggestions[lowered]
+        if word[:1].isupper():
+            found = [
+                candidate.upper() if word.isupper() else candidate.capitalize()
+                for candidate in found
+            ]
+        return found[:limit]
+
+    def _closest(self, word: str) -> list[str]:
+        edits = self._edits(word)
+        found = sorted(edits & self.words)
+        if not found and len(word) <= MAX_DEEP_SUGGESTION_LENGTH:
+            found = sorted(
+                {second for first in edits for second in self._edits(first)}
+                & self.words,
+            )
+        return found
+
+    def _edits(self, word: str) -> set[str]:
+        splits = [(word[:i], word[i:]) for i in range(len(word) + 1)]
+        edits = {left + right[1:] for left, right in splits if right}
+        edits |= {
+            left + right[1] + right[0] + right[2:]
+            for left, right in splits
+            if len(right) > 1
+        }
+        for letter in self.alphabet:
+            edits |= {left + letter + right[1:] for left, right in splits if right}
+            edits |= {left + letter + right for left, right in splits}
+        edits.discard(word)
+        return edits
+
+
+class SpellCheck:
+    """Spell checking of input fields, following the spelling settings.
+
+    The dictionary is read the first time a field is checked, and again
+    when the settings pick another one.
+    """
+
+    def __init__(self, directory: Path):
+        """Initialize spell checking.
+
+        Args:
+            directory: Dictionaries folder of the storage directory, whose
+                dictionaries hide packaged and system ones of the same name.
+
+        """
+        self.directory = Path(directory)
+        self.enabled = True
+        self.path: Path | None = None
+        self._checker: SpellChecker | None = None
+
+    def dictionaries(self) -> dict[str, Path]:
+        """Return the dictionaries that can be chosen, keyed by name."""
+        return find_dictionaries(
+            self.directory,
+            BUNDLED_DICTIONARIES_DIR,
+            *SYSTEM_DICTIONARY_DIRS,
+        )
+
+    def add_dictionary(self, source: Path) -> str:
+        """Copy a dictionary file, such as a downloaded one, into the folder.
+
+        Returns:
+            The name the dictionary can be chosen by.
+
+        Raises:
+            ValueError: If the file is not a dictionary.
+            OSError: If the file could not be copied.
+
+        """
+        source = Path(source)
+        if source.suffix.lower() not in DICTIONARY_EXTENSIONS:
+            raise ValueError(f"{source.name} is not a .dic or .txt file")
+        self.directory.mkdir(parents=True, exist_ok=True)
+        target = self.directory / source.name
+        shutil.copyfile(source, target)
+        if target == self.path:
+            self._checker = None
+        return source.stem
+
+    def configure(self, settings: dict, language: str) -> None:
+        """Follow the spelling settings.
+
+        Args:
+            settings: App settings holding ``spell_check`` and
+                ``spell_check_language``.
+            language: Interface language, checked in when the settings do
+                not name a dictionary.
+
+        """
+        self.enabled = settings.get("spell_check", True)
+        path = pick_dictionary(
+            self.dictionaries(),
+            settings.get("spell_check_language") or language,
+        )
+        if path != self.path:
+            self.path = path
+            self._checker = None
+
+    def checker(self) -> SpellChecker | None:
+        """Return the checker of the chosen dictionary.
+
+        Returns:
+            The checker, or None if spell checking is off or there is no
+            readable dictionary for the language.
+
+        """
+        if not self.enabled or self.path is None:
+            return None
+        if self._checker is None:
+            try:
+                self._checker = SpellChecker(read_dictionary(self.path))
+            except OSError:
+                self.path = None
+                return None
+        return self._checker
+
+
+def replace_word(text: str, misspelling: Misspelling, replacement: str) -> str:
+    """Return a text with a misspelled word replaced."""
+    return text[: misspelling.start] + replacement + text[misspelling.end :]
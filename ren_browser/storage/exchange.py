@@ -0,0 +1,228 @@
+"""Settings import and export for Ren Browser.
+
+Writes the app settings, keyboard shortcuts and custom themes to a single
+JSON file, and reads such a file back with every value checked, so a
+configuration can be moved to another machine.
+"""
+
+import json
+from dataclasses import dataclass, field
+
+from ren_browser.controls.shortcuts import ACTIONS, keybindings, parse_chord
+from ren_browser.storage.schema import (
+    DEFAULT_APP_SETTINGS,
+    SETTING_LABELS,
+    SETTINGS_VERSION,
+    VERSION_KEY,
+    migrate_settings,
+)
+from ren_browser.themes.themes import COLOR_FIELDS, CustomTheme, is_color
+
+JSON_FORMAT = "ren-browser-settings"
+JSON_VERSION = 1
+
+
+class SettingsFormatError(ValueError):
+    """Raised when a settings file cannot be read."""
+
+
+@dataclass
+class SettingsBundle:
+    """Settings and custom themes read from a settings file."""
+
+    settings: dict
+    themes: list[CustomTheme] = field(default_factory=list)
+    ignored: list[str] = field(default_factory=list)
+
+
+def to_json(settings: dict, themes: list[CustomTheme]) -> str:
+    """Serialize the settings and custom themes to the export format."""
+    return json.dumps(
+        {
+            "format": JSON_FORMAT,
+            "version": JSON_VERSION,
+            "settings": {VERSION_KEY: SETTINGS_VERSION, **settings},
+            "themes": [
+                {
+                    "name": theme.name,
+                    **{key: getattr(theme, key) for key in COLOR_FIELDS},
+                }
+                for theme in themes
+            ],
+        },
+        indent=2,
+    )
+
+
+def from_json(text: str) -> SettingsBundle:
+    """Read and check a settings file.
+
+    Settings this version does not know, such as ones written by a newer
+    release, are skipped and listed in ``ignored``.
+
+    Raises:
+        SettingsFormatError: If the file is not a settings export or holds a
+            value of the wrong type.
+
+    """
+    try:
+        data = json.loads(text)
+    except json.JSONDecodeError as exc:
+        raise SettingsFormatError(f"Not a valid JSON file: {exc}") from exc
+    if not isinstance(data, dict) or data.get("format") != JSON_FORMAT:
+        raise SettingsFormatError("The file is not a Ren Browser settings export.")
+    version = data.get("version")
+    if not isinstance(version, int) or version > JSON_VERSION:
+        raise SettingsFormatError(
+            "The file was exported by a newer version of Ren Browser.",
+        )
+    settings, ignored = check_settings(migrate_settings(data.get("settings", {})))
+    bundle = SettingsBundle(settings=settings, ignored=ignored)
+    themes = data.get("themes", [])
+    if not isinstance(themes, list):
+        raise SettingsFormatError("The themes in the file are not a list.")
+    bundle.themes = [_check_theme(entry) for entry in themes]
+    return bundle
+
+
+def check_settings(stored) -> tuple[dict, list[str]]:
+    """Check settings read from a file against the known settings.
+
+    Returns:
+        The settings, and the names of the ones this version does not know,
+        which are left out.
+
+    Raises:
+        SettingsFormatError: If the settings are not a table or one holds a
+            value of the wrong type.
+
+    """
+    if not isinstance(stored, dict):
+        raise SettingsFormatError("The settings in the file are not a table.")
+    settings, ignored = {}, []
+    for key, value in stored.items():
+        if key not in DEFAULT_APP_SETTINGS:
+            ignored.append(key)
+        elif key == "keybindings":
+            settings[key] = _check_keybindings(value)
+        elif key == "address_aliases":
+            settings[key] = _check_aliases(value)
+        else:
+            settings[key] = _check_value(key, value)
+    return settings, ignored
+
+
+def _check_value(key: str, value):
+    default = DEFAULT_APP_SETTINGS[key]
+    label = SETTING_LABELS.get(key, key)
+    if isinstance(default, bool):
+        valid = isinstance(value, bool)
+    elif isinstance(default, int | float):
+        valid = isinstance(value, int | float) and not isinstance(value, bool)
+    else:
+        valid = isinstance(value, type(default))
+    if not valid:
+        raise SettingsFormatError(f"{label} has an invalid value: {value!r}")
+    return value
+
+
+def _check_keybindings(value) -> dict[str, list[str]]:
+    if not isinstance(value, dict):
+        raise SettingsFormatError("The keyboard shortcuts are not a table.")
+    checked = {}
+    for action, chords in value.items():
+        if action not in ACTIONS:
+            continue
+        if not isinstance(chords, list):
+            raise SettingsFormatError(f"The shortcuts for {action} are not a list.")
+        try:
+            checked[action] = [parse_chord(chord) for chord in chords]
+        except (AttributeError, ValueError) as exc:
+            raise SettingsFormatError(f"Invalid shortcut for {action}: {exc}") from exc
+    return checked
+
+
+def _check_aliases(value) -> dict[str, str]:
+    if not isinstance(value, dict) or not all(
+        isinstance(keyword, str) and isinstance(address, str)
+        for keyword, address in value.items()
+    ):
+        raise SettingsFormatError("The address aliases are not a table of text.")
+    return value
+
+
+def _check_theme(entry) -> CustomTheme:
+    if not isinstance(entry, dict):
+        raise SettingsFormatError("A theme in the file is not a table.")
+    name = entry.get("name")
+    if not isinstance(name, str) or not name.strip():
+        raise SettingsFormatError("A theme in the file has no name.")
+    colors = {}
+    for key, label in COLOR_FIELDS.items():
+        if key not in entry:
+            continue
+        if not is_color(entry[key]):
+            raise SettingsFormatError(f"{label} of theme {name} is not a colour.")
+        colors[key] = entry[key].lower()
+    return CustomTheme(name.strip(), **colors)
+
+
+def _format(value) -> str:
+    if isinstance(value, bool):
+        return "on" if value else "off"
+    if value == "":
+        return "(default)"
+    return str(value)
+
+
+def describe_changes(current: dict, bundle: SettingsBundle, library) -> list[str]:
+    """Describe how importing a bundle would change the browser.
+
+    Args:
+        current: Settings in use now.
+        bundle: Settings and themes read from a file.
+        library: ThemeLibrary the themes would be saved to.
+
+    Returns:
+        One line per changed setting, shortcut or theme, empty if the file
+        matches the current configuration.
+
+    """
+    changes = []
+    for key, value in bundle.settings.items():
+        if key == "keybindings":
+            continue
+        old = current.get(key, DEFAULT_APP_SETTINGS[key])
+        if old != value:
+            label = SETTING_LABELS.get(key, key)
+            changes.append(f"{label}: {_format(old)} → {_format(value)}")
+    if "keybindings" in bundle.settings:
+        old_bindings = keybindings(current.get("keybindings"))
+        new_bindings = keybindings(bundle.settings["keybindings"])
+        for action, (label, _) in ACTIONS.items():
+            old, new = old_bindings[action], new_bindings[action]
+            if old != new:
+                changes.append(
+                    f"Shortcut for {label}: "
+                    f"{', '.join(old) or '(none)'} → {', '.join(new) or '(none)'}",
+                )
+    for theme in bundle.themes:
+        saved = library.get(theme.name)
+        if saved is None:
+            changes.append(f"New theme: {theme.name}")
+        elif saved != theme:
+            changes.append(f"Updated theme: {theme.name}")
+    return changes
+
+
+def import_bundle(bundle: SettingsBundle, current: dict, library) -> dict:
+    """Save a bundle's themes and return the settings with its values applied.
+
+    Raises:
+        OSError: If a theme file could not be written.
+
+    """
+    for theme in bundle.themes:
+        previous = theme.name if library.get(theme.name) else None
+        library.save(theme, previous)
+    return {**current, **bundle.settings}
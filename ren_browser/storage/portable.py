@@ -0,0 +1,37 @@
+"""Portable mode for Ren Browser.
+
+In portable mode all state, from settings and history to the Reticulum
+identity, is kept in a folder beside the executable instead of the user's
+config directory, so the browser can run off a USB stick. It is turned on
+with ``--portable`` or by placing a file named ``portable`` next to the
+executable.
+"""
+
+import sys
+from pathlib import Path
+
+MARKER_FILE = "portable"
+DATA_DIR = "ren_browser_data"
+
+_enabled = False
+
+
+def app_directory() -> Path:
+    """Return the folder holding the executable the browser was started from."""
+    if getattr(sys, "frozen", False):
+        return Path(sys.executable).resolve().parent
+    return Path(sys.argv[0]).resolve().parent
+
+
+def set_portable(enabled: bool) -> None:
+    """Turn portable mode on or off regardless of the marker file."""
+    global _enabled
+    _enabled = enabled
+
+
+def portable_directory() -> Path | None:
+    """Return the folder state is kept in while portable, None otherwise."""
+    directory = app_directory()
+    if _enabled or (directory / MARKER_FILE).is_file():
+        return directory / DATA_DIR
+    return None
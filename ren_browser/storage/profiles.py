@@ -0,0 +1,95 @@
+"""User profiles for Ren Browser.
+
+Each profile keeps its own settings, cache, history, bookmarks and
+Reticulum identity in a folder of its own. The default profile uses the
+storage folder itself, so data from before profiles existed stays where
+it is, and other profiles live in its ``profiles`` folder.
+"""
+
+import re
+from pathlib import Path
+
+DEFAULT_PROFILE = "default"
+PROFILES_DIR = "profiles"
+STARTUP_FILE = "startup_profile"
+
+_NAME = re.compile(r"[A-Za-z0-9][A-Za-z0-9_-]{0,31}")
+
+
+class ProfileError(ValueError):
+    """Raised when a profile name cannot be used."""
+
+
+def check_profile_name(name: str) -> str:
+    """Return a profile name without surrounding spaces.
+
+    Raises:
+        ProfileError: If the name has characters other than letters, digits,
+            dashes and underscores, or is longer than 32 characters.
+
+    """
+    name = name.strip()
+    if not _NAME.fullmatch(name):
+        raise ProfileError(
+            "Use up to 32 letters, digits, dashes and underscores, "
+            "starting with a letter or digit.",
+        )
+    return name
+
+
+def profile_directory(base: Path, name: str) -> Path:
+    """Return the folder holding a profile's data."""
+    if name == DEFAULT_PROFILE:
+        return base
+    return base / PROFILES_DIR / name
+
+
+def list_profiles(base: Path) -> list[str]:
+    """Return the default profile followed by the others by name."""
+    try:
+        folders = [path for path in (base / PROFILES_DIR).iterdir() if path.is_dir()]
+    except OSError:
+        folders = []
+    names = sorted(path.name for path in folders if _NAME.fullmatch(path.name))
+    return [DEFAULT_PROFILE, *(name for name in names if name != DEFAULT_PROFILE)]
+
+
+def create_profile(base: Path, name: str) -> str:
+    """Create an empty profile.
+
+    Returns:
+        The profile's name.
+
+    Raises:
+        ProfileError: If the name cannot be used or the profile exists.
+        OSError: If the profile's folder could not be created.
+
+    """
+    name = check_profile_name(name)
+    if name in list_profiles(base):
+        raise ProfileError(f"A profile named {name} already exists.")
+    profile_directory(base, name).mkdir(parents=True)
+    return name
+
+
+def startup_profile(base: Path) -> str:
+    """Return the profile to use when none is given on the command line.
+
+    A stored profile that is invalid or has been removed means the default.
+    """
+    try:
+        name = (base / STARTUP_FILE).read_text(encoding="utf-8").strip()
+    except (OSError, UnicodeDecodeError):
+        return DEFAULT_PROFILE
+    return name if name in list_profiles(base) else DEFAULT_PROFILE
+
+
+def set_startup_profile(base: Path, name: str) -> None:
+    """Use a profile from the next launch on.
+
+    Raises:
+        OSError: If the choice could not be stored.
+
+    """
+    base.mkdir(parents=True, exist_ok=True)
+    (base / STARTUP_FILE).write_text(name, encoding="utf-8")
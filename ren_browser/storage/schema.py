@@ -0,0 +1,155 @@
+"""Settings schema for Ren Browser.
+
+Lists every app setting with its default value and the label it is shown
+with, and upgrades settings files written by older releases. Files carry
+the version of the schema they were written with, and each migration
+takes settings one version further. Settings this release does not know,
+such as ones written by a newer release, are kept as they are.
+"""
+
+from collections.abc import Callable
+from dataclasses import dataclass
+
+from ren_browser.themes.themes import is_color, is_dark_color
+
+SETTINGS_VERSION = 2
+VERSION_KEY = "settings_version"
+
+
+@dataclass(frozen=True)
+class Setting:
+    """An app setting's default value and label."""
+
+    default: object
+    label: str
+
+
+SETTINGS = {
+    "horizontal_scroll": Setting(False, "Horizontal scroll"),
+    "page_colors": Setting("theme", "Page colours"),
+    "page_bgcolor": Setting("#000000", "Page background colour"),
+    "page_fgcolor": Setting("#ffffff", "Page text colour"),
+    "theme": Setting("dark", "Theme"),
+    "language": Setting("", "Language"),
+    "ui_font": Setting("", "Interface font"),
+    "content_font": Setting("", "Page font"),
+    "readable_font": Setting(False, "Dyslexia-friendly page font"),
+    "min_font_size": Setting(0, "Minimum page text size"),
+    "keybindings": Setting({}, "Keyboard shortcuts"),
+    "address_aliases": Setting({}, "Address aliases"),
+    "spell_check": Setting(True, "Check spelling"),
+    "spell_check_language": Setting("", "Spelling language"),
+    "cache_max_age": Setting(300, "Cached pages served for (seconds)"),
+    "cache_max_pages": Setting(500, "Most pages cached"),
+    "cache_max_mb": Setting(20, "Most megabytes cached"),
+    "cache_disk_mb": Setting(100, "Most megabytes cached on disk"),
+    "record_history": Setting(True, "Record history"),
+    "cache_pages": Setting(True, "Cache pages"),
+    "prefetch_links": Setting(False, "Prefetch linked pages"),
+    "prefetch_kb": Setting(500, "Most kilobytes prefetched per page"),
+    "private_tabs": Setting(False, "Open new tabs as private"),
+    "download_dir": Setting("", "Download folder"),
+    "ask_download_location": Setting(False, "Always ask where to save downloads"),
+    "always_show_address_bar": Setting(True, "Always show address bar"),
+    "show_status_bar": Setting(True, "Show status bar"),
+    "sidebar_collapsed": Setting(False, "Sidebar collapsed"),
+    "sidebar_width": Setting(300, "Sidebar width"),
+    "ui_scale": Setting(1.0, "Interface zoom"),
+    "window": Setting({}, "Window size and position"),
+    "home_page": Setting("", "Home page"),
+    "startup": Setting("new_tab", "On startup"),
+    "confirm_close": Setting(True, "Ask before closing"),
+    "sidebar_sort": Setting("recent", "Sidebar sort order"),
+    "hide_anonymous": Setting(False, "Hide anonymous nodes"),
+    "sidebar_aspect": Setting("nomadnetwork.node", "Sidebar aspect"),
+    "stale_after_hours": Setting(6, "Nodes are stale after (hours)"),
+    "stale_nodes": Setting("section", "Stale nodes in the sidebar"),
+    "status_poll_seconds": Setting(5, "Interface status checked every (seconds)"),
+    "node_refresh_seconds": Setting(30, "Node list refreshed every (seconds)"),
+    "node_refresh_sidebar_only": Setting(True, "Refresh node list only while shown"),
+    "link_preview": Setting(True, "Preview links on hover"),
+    "favorite_notifications": Setting(True, "Favorite node notifications"),
+    "system_notifications": Setting(False, "Desktop notifications"),
+    "notify_favorites": Setting(True, "Desktop notifications for favorite nodes"),
+    "notify_watched": Setting(True, "Desktop notifications for watched pages"),
+    "notify_downloads": Setting(True, "Desktop notifications for downloads"),
+    "tray_icon": Setting(False, "Tray icon"),
+    "close_to_tray": Setting(False, "Close to tray"),
+    "battery_saver": Setting(True, "Save power on battery"),
+    "battery_saver_percent": Setting(100, "Save power from battery level"),
+    "battery_interval_factor": Setting(3, "Refresh intervals on battery"),
+    "battery_pause_prefetch": Setting(True, "Pause prefetching on battery"),
+    "pause_unfocused": Setting(True, "Pause background work when unfocused"),
+    "reduced_motion": Setting(False, "Reduce motion and redraws"),
+    "log_file_level": Setting("warning", "Log file level"),
+    "log_file_kb": Setting(1024, "Log file size (kilobytes)"),
+    "log_file_count": Setting(3, "Old log files kept"),
+    "plugins_enabled": Setting(False, "Load plugins"),
+    "crash_report_tabs": Setting(False, "Include open tabs in crash reports"),
+    "check_updates": Setting(False, "Check for updates"),
+    "clipboard_hints": Setting(False, "Offer to open copied addresses"),
+}
+
+DEFAULT_APP_SETTINGS = {key: setting.default for key, setting in SETTINGS.items()}
+SETTING_LABELS = {key: setting.label for key, setting in SETTINGS.items()}
+
+
+def _from_unversioned(settings: dict) -> dict:
+    # Files from before versioning already use the current names and values
+    return settings
+
+
+def _page_colors_from_bgcolor(settings: dict) -> dict:
+    # A page background other than the old black default used to override
+    # the theme on its own, so it becomes custom page colours with readable
+    # text
+    bgcolor = settings.get("page_bgcolor")
+    if "page_colors" in settings or not is_color(bgcolor):
+        return settings
+    if bgcolor.lower() == "#000000":
+        return settings
+    return {
+        **settings,
+        "page_colors": "custom",
+        "page_fgcolor": "#ffffff" if is_dark_color(bgcolor) else "#000000",
+    }
+
+
+# Migration upgrading settings written with each version to the next one
+MIGRATIONS: dict[int, Callable[[dict], dict]] = {
+    0: _from_unversioned,
+    1: _page_colors_from_bgcolor,
+}
+
+
+def settings_version(stored) -> int:
+    """Return the schema version settings were written with, 0 if unversioned."""
+    version = stored.get(VERSION_KEY, 0) if isinstance(stored, dict) else 0
+    if isinstance(version, bool) or not isinstance(version, int):
+        return 0
+    return version
+
+
+def migrate_settings(stored):
+    """Upgrade settings read from a file to the current schema.
+
+    Settings written by a newer release are left as they are, and values
+    that are not tables are returned unchanged for the caller to reject.
+
+    Returns:
+        The settings without the version key.
+
+    """
+    if not isinstance(stored, dict):
+        return stored
+    version = settings_version(stored)
+    settings = {key: value for key, value in stored.items() if key != VERSION_KEY}
+    while version < SETTINGS_VERSION:
+        settings = MIGRATIONS[version](settings)
+        version += 1
+    return settings
+
+
+def unknown_settings(settings: dict) -> dict:
+    """Return the settings this release does not know, to be kept on save."""
+    return {key: value for key, value in settings.items() if key not in SETTINGS}
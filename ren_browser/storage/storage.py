@@ -11,6 +11,15 @@ from typing import Any
 
 import flet as ft
 
+from ren_browser.storage import portable, profiles
+from ren_browser.storage.schema import (
+    DEFAULT_APP_SETTINGS,
+    SETTINGS_VERSION,
+    VERSION_KEY,
+    migrate_settings,
+    settings_version,
+)
+
 
 class StorageManager:
     """Cross-platform storage manager for Ren Browser.
@@ -19,16 +28,25 @@ class StorageManager:
     with platform-specific storage locations.
     """
 
-    def __init__(self, page: ft.Page | None = None):
+    def __init__(self, page: ft.Page | None = None, profile: str | None = None):
         """Initialize storage manager.
 
         Args:
             page: Optional Flet page instance for client storage access.
+            profile: Profile whose data is used, by default the one chosen
+                to start with.
 
         """
         self.page = page
-        self._storage_dir = self._get_storage_directory()
+        self.portable = portable.portable_directory() is not None
+        self.base_dir = self._get_storage_directory()
+        self.profile = profile or profiles.startup_profile(self.base_dir)
+        self._storage_dir = profiles.profile_directory(self.base_dir, self.profile)
         self._ensure_storage_directory()
+        # Settings this process wrote last, told apart from edits by hand
+        self.last_saved_settings: dict | None = None
+        # Schema version of the settings file, kept if a newer release wrote it
+        self.settings_version = SETTINGS_VERSION
 
     def _get_storage_directory(self) -> pathlib.Path:
         """Get the appropriate storage directory for the current platform."""
@@ -36,6 +54,10 @@ class StorageManager:
         if self.page and hasattr(self.page, "client_storage"):
             pass
 
+        portable_dir = portable.portable_directory()
+        if portable_dir is not None:
+            return portable_dir
+
         if os.name == "posix" and "ANDROID_ROOT" in os.environ:
             if "ANDROID_DATA" in os.environ:
                 storage_dir = pathlib.Path(os.environ["ANDROID_DATA"]) / "ren_browser"
@@ -64,13 +86,95 @@ class StorageManager:
         except (OSError, PermissionError):
             import tempfile
 
-            self._storage_dir = pathlib.Path(tempfile.gettempdir()) / "ren_browser"
+            self._storage_dir = profiles.profile_directory(
+                pathlib.Path(tempfile.gettempdir()) / "ren_browser",
+                self.profile,
+            )
             self._storage_dir.mkdir(parents=True, exist_ok=True)
 
+    def _client_key(self, key: str) -> str:
+        if self.profile == profiles.DEFAULT_PROFILE:
+            return key
+        return f"{key}:{self.profile}"
+
+    def list_profiles(self) -> list[str]:
+        """Return the names of all profiles, the default one first."""
+        return profiles.list_profiles(self.base_dir)
+
+    def create_profile(self, name: str) -> str:
+        """Create an empty profile, see profiles.create_profile."""
+        return profiles.create_profile(self.base_dir, name)
+
+    def startup_profile(self) -> str:
+        """Return the profile used when none is given on the command line."""
+        return profiles.startup_profile(self.base_dir)
+
+    def set_startup_profile(self, name: str) -> None:
+        """Use a profile from the next launch on, see profiles.set_startup_profile."""
+        profiles.set_startup_profile(self.base_dir, name)
+
     def get_config_path(self) -> pathlib.Path:
         """Get the path to the main configuration file."""
         return self._storage_dir / "config"
 
+    def get_reading_list_path(self) -> pathlib.Path:
+        """Get the directory holding pages saved for offline reading."""
+        return self._storage_dir / "reading_list"
+
+    def get_cache_path(self) -> pathlib.Path:
+        """Get the directory holding cached pages."""
+        return self._storage_dir / "cache"
+
+    def get_revisions_path(self) -> pathlib.Path:
+        """Get the directory holding earlier versions of fetched pages."""
+        return self._storage_dir / "revisions"
+
+    def get_themes_path(self) -> pathlib.Path:
+        """Get the directory holding custom theme files."""
+        return self._storage_dir / "themes"
+
+    def get_fonts_path(self) -> pathlib.Path:
+        """Get the directory holding font files offered in the font settings."""
+        return self._storage_dir / "fonts"
+
+    def get_downloads_path(self) -> pathlib.Path:
+        """Get the directory downloaded files are saved to.
+
+        A portable browser keeps them beside its other data.
+        """
+        downloads = pathlib.Path.home() / "Downloads"
+        if not self.portable and downloads.is_dir():
+            return downloads
+        return self._storage_dir / "downloads"
+
+    def get_app_settings_path(self) -> pathlib.Path:
+        """Get the path of the file holding the app settings."""
+        return self._storage_dir / "settings.json"
+
+    def get_logs_path(self) -> pathlib.Path:
+        """Get the directory the log file is written to."""
+        return self._storage_dir / "logs"
+
+    def get_crashes_path(self) -> pathlib.Path:
+        """Get the directory crash reports are written to."""
+        return self._storage_dir / "crashes"
+
+    def get_dictionaries_path(self) -> pathlib.Path:
+        """Get the directory holding spell checking dictionaries."""
+        return self._storage_dir / "dictionaries"
+
+    def get_plugins_path(self) -> pathlib.Path:
+        """Get the directory plugin files are loaded from."""
+        return self._storage_dir / "plugins"
+
+    def get_instance_path(self) -> pathlib.Path:
+        """Get the file a running browser publishes its deep link port in."""
+        return self._storage_dir / "instance.json"
+
+    def get_remote_path(self) -> pathlib.Path:
+        """Get the file a running browser publishes its remote control port in."""
+        return self._storage_dir / "remote.json"
+
     def get_reticulum_config_path(self) -> pathlib.Path:
         """Get the path to the Reticulum configuration directory."""
         # Check for global override from app
@@ -82,8 +186,14 @@ class StorageManager:
         except ImportError:
             pass
 
-        # On Android, use app storage directory instead of ~/.reticulum
-        if os.name == "posix" and "ANDROID_ROOT" in os.environ:
+        # Portable browsers and profiles other than the default have an
+        # identity of their own, and Android uses the app storage directory
+        # instead of ~/.reticulum
+        if (
+            self.portable
+            or self.profile != profiles.DEFAULT_PROFILE
+            or (os.name == "posix" and "ANDROID_ROOT" in os.environ)
+        ):
             return self._storage_dir / "reticulum"
 
         # Default to standard RNS config directory
@@ -102,7 +212,10 @@ class StorageManager:
         try:
             # Always save to client storage first (most reliable on mobile)
             if self.page and hasattr(self.page, "client_storage"):
-                self.page.client_storage.set("ren_browser_config", config_content)
+                self.page.client_storage.set(
+                    self._client_key("ren_browser_config"),
+                    config_content,
+                )
 
             # Save to reticulum config directory for RNS to use
             reticulum_config_path = self.get_reticulum_config_path() / "config"
@@ -122,9 +235,12 @@ class StorageManager:
         """Fallback config saving for when primary method fails."""
         try:
             if self.page and hasattr(self.page, "client_storage"):
-                self.page.client_storage.set("ren_browser_config", config_content)
                 self.page.client_storage.set(
-                    "ren_browser_config_error",
+                    self._client_key("ren_browser_config"),
+                    config_content,
+                )
+                self.page.client_storage.set(
+                    self._client_key("ren_browser_config_error"),
                     f"File save failed: {error}",
                 )
                 return True
@@ -155,7 +271,9 @@ class StorageManager:
         # On Android, prioritize client storage first as it's more reliable
         if os.name == "posix" and "ANDROID_ROOT" in os.environ:
             if self.page and hasattr(self.page, "client_storage"):
-                stored_config = self.page.client_storage.get("ren_browser_config")
+                stored_config = self.page.client_storage.get(
+                    self._client_key("ren_browser_config"),
+                )
                 if stored_config:
                     return stored_config
 
@@ -170,14 +288,18 @@ class StorageManager:
 
             # Fallback to client storage for non-Android or if files don't exist
             if self.page and hasattr(self.page, "client_storage"):
-                stored_config = self.page.client_storage.get("ren_browser_config")
+                stored_config = self.page.client_storage.get(
+                    self._client_key("ren_browser_config"),
+                )
                 if stored_config:
                     return stored_config
 
         except (OSError, PermissionError, UnicodeDecodeError):
             # If file access fails, try client storage as fallback
             if self.page and hasattr(self.page, "client_storage"):
-                stored_config = self.page.client_storage.get("ren_browser_config")
+                stored_config = self.page.client_storage.get(
+                    self._client_key("ren_browser_config"),
+                )
                 if stored_config:
                     return stored_config
 
@@ -192,7 +314,7 @@ class StorageManager:
 
             if self.page and hasattr(self.page, "client_storage"):
                 self.page.client_storage.set(
-                    "ren_browser_bookmarks",
+                    self._client_key("ren_browser_bookmarks"),
                     json.dumps(bookmarks),
                 )
 
@@ -209,7 +331,9 @@ class StorageManager:
                     return json.load(f)
 
             if self.page and hasattr(self.page, "client_storage"):
-                stored_bookmarks = self.page.client_storage.get("ren_browser_bookmarks")
+                stored_bookmarks = self.page.client_storage.get(
+                    self._client_key("ren_browser_bookmarks"),
+                )
                 if stored_bookmarks:
                     return json.loads(stored_bookmarks)
 
@@ -226,7 +350,10 @@ class StorageManager:
                 json.dump(history, f, indent=2)
 
             if self.page and hasattr(self.page, "client_storage"):
-                self.page.client_storage.set("ren_browser_history", json.dumps(history))
+                self.page.client_storage.set(
+                    self._client_key("ren_browser_history"),
+                    json.dumps(history),
+                )
 
             return True
         except Exception:
@@ -241,7 +368,9 @@ class StorageManager:
                     return json.load(f)
 
             if self.page and hasattr(self.page, "client_storage"):
-                stored_history = self.page.client_storage.get("ren_browser_history")
+                stored_history = self.page.client_storage.get(
+                    self._client_key("ren_browser_history"),
+                )
                 if stored_history:
                     return json.loads(stored_history)
 
@@ -250,17 +379,266 @@ class StorageManager:
 
         return []
 
+    def save_downloads(self, downloads: list) -> bool:
+        """Save the download history to storage."""
+        try:
+            downloads_path = self._storage_dir / "downloads.json"
+            with open(downloads_path, "w", encoding="utf-8") as f:
+                json.dump(downloads, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_downloads(self) -> list:
+        """Load the download history from storage."""
+        try:
+            downloads_path = self._storage_dir / "downloads.json"
+            if downloads_path.exists():
+                with open(downloads_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return []
+
+    def save_gemini_hosts(self, hosts: dict) -> bool:
+        """Save the certificate fingerprints of known Gemini servers."""
+        try:
+            hosts_path = self._storage_dir / "gemini_hosts.json"
+            with open(hosts_path, "w", encoding="utf-8") as f:
+                json.dump(hosts, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_gemini_hosts(self) -> dict:
+        """Load the certificate fingerprints of known Gemini servers."""
+        try:
+            hosts_path = self._storage_dir / "gemini_hosts.json"
+            if hosts_path.exists():
+                with open(hosts_path, encoding="utf-8") as f:
+                    hosts = json.load(f)
+                    return hosts if isinstance(hosts, dict) else {}
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return {}
+
+    def save_update_state(self, state: dict) -> bool:
+        """Save when updates were last checked for and what was found."""
+        try:
+            state_path = self._storage_dir / "update_check.json"
+            with open(state_path, "w", encoding="utf-8") as f:
+                json.dump(state, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_update_state(self) -> dict:
+        """Load when updates were last checked for and what was found."""
+        try:
+            state_path = self._storage_dir / "update_check.json"
+            if state_path.exists():
+                with open(state_path, encoding="utf-8") as f:
+                    state = json.load(f)
+                    return state if isinstance(state, dict) else {}
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return {}
+
+    def save_speed_dial(self, tiles: list) -> bool:
+        """Save the pinned speed dial tiles to storage."""
+        try:
+            speed_dial_path = self._storage_dir / "speed_dial.json"
+            with open(speed_dial_path, "w", encoding="utf-8") as f:
+                json.dump(tiles, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_speed_dial(self) -> list:
+        """Load the pinned speed dial tiles from storage."""
+        try:
+            speed_dial_path = self._storage_dir / "speed_dial.json"
+            if speed_dial_path.exists():
+                with open(speed_dial_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return []
+
+    def save_watched_pages(self, pages: list) -> bool:
+        """Save the pages watched for changes to storage."""
+        try:
+            watched_path = self._storage_dir / "watched.json"
+            with open(watched_path, "w", encoding="utf-8") as f:
+                json.dump(pages, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_watched_pages(self) -> list:
+        """Load the pages watched for changes from storage."""
+        try:
+            watched_path = self._storage_dir / "watched.json"
+            if watched_path.exists():
+                with open(watched_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return []
+
+    def save_annotations(self, annotations: list) -> bool:
+        """Save the highlights and notes made on pages to storage."""
+        try:
+            annotations_path = self._storage_dir / "annotations.json"
+            with open(annotations_path, "w", encoding="utf-8") as f:
+                json.dump(annotations, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_annotations(self) -> list:
+        """Load the highlights and notes made on pages from storage."""
+        try:
+            annotations_path = self._storage_dir / "annotations.json"
+            if annotations_path.exists():
+                with open(annotations_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return []
+
+    def save_feed(self, feed: dict) -> bool:
+        """Save the followed nodes and their feed to storage."""
+        try:
+            feed_path = self._storage_dir / "feed.json"
+            with open(feed_path, "w", encoding="utf-8") as f:
+                json.dump(feed, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_feed(self) -> dict:
+        """Load the followed nodes and their feed from storage."""
+        try:
+            feed_path = self._storage_dir / "feed.json"
+            if feed_path.exists():
+                with open(feed_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return {}
+
+    def save_announce_activity(self, activity: dict) -> bool:
+        """Save the announces heard from each node, by hour, to storage."""
+        try:
+            activity_path = self._storage_dir / "announce_activity.json"
+            with open(activity_path, "w", encoding="utf-8") as f:
+                json.dump(activity, f)
+            return True
+        except Exception:
+            return False
+
+    def load_announce_activity(self) -> dict:
+        """Load the announces heard from each node, by hour, from storage."""
+        try:
+            activity_path = self._storage_dir / "announce_activity.json"
+            if activity_path.exists():
+                with open(activity_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return {}
+
+    def save_node_metadata(self, metadata: dict) -> bool:
+        """Save what the user recorded about nodes to storage."""
+        try:
+            nodes_path = self._storage_dir / "nodes.json"
+            with open(nodes_path, "w", encoding="utf-8") as f:
+                json.dump(metadata, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_node_metadata(self) -> dict:
+        """Load what the user recorded about nodes from storage."""
+        try:
+            nodes_path = self._storage_dir / "nodes.json"
+            if nodes_path.exists():
+                with open(nodes_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return {}
+
+    def save_petnames(self, petnames: list) -> bool:
+        """Save the petnames given to destinations to storage."""
+        try:
+            petnames_path = self._storage_dir / "petnames.json"
+            with open(petnames_path, "w", encoding="utf-8") as f:
+                json.dump(petnames, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_petnames(self) -> list:
+        """Load the petnames given to destinations from storage."""
+        try:
+            petnames_path = self._storage_dir / "petnames.json"
+            if petnames_path.exists():
+                with open(petnames_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return []
+
+    def save_session(self, session: dict) -> bool:
+        """Save the open tabs to storage."""
+        try:
+            session_path = self._storage_dir / "session.json"
+            with open(session_path, "w", encoding="utf-8") as f:
+                json.dump(session, f, indent=2)
+            return True
+        except Exception:
+            return False
+
+    def load_session(self) -> dict:
+        """Load the tabs saved by the previous run."""
+        try:
+            session_path = self._storage_dir / "session.json"
+            if session_path.exists():
+                with open(session_path, encoding="utf-8") as f:
+                    return json.load(f)
+        except (OSError, json.JSONDecodeError):
+            pass
+
+        return {}
+
     def save_app_settings(self, settings: dict) -> bool:
-        """Save application settings to storage."""
+        """Save application settings to storage.
+
+        The settings are written with the version of the settings schema.
+        """
+        stored = {VERSION_KEY: self.settings_version, **settings}
         try:
-            settings_path = self._storage_dir / "settings.json"
+            settings_path = self.get_app_settings_path()
             with open(settings_path, "w", encoding="utf-8") as f:
-                json.dump(settings, f, indent=2)
+                json.dump(stored, f, indent=2)
+            self.last_saved_settings = settings
 
             if self.page and hasattr(self.page, "client_storage"):
                 self.page.client_storage.set(
-                    "ren_browser_settings",
-                    json.dumps(settings),
+                    self._client_key("ren_browser_settings"),
+                    json.dumps(stored),
                 )
 
             return True
@@ -268,34 +646,43 @@ class StorageManager:
             return False
 
     def load_app_settings(self) -> dict:
-        """Load application settings from storage."""
-        default_settings = {
-            "horizontal_scroll": False,
-            "page_bgcolor": "#000000",
-        }
+        """Load application settings from storage.
+
+        Settings written by an older release are migrated to the current
+        schema, and settings this release does not know are kept.
+        """
+        default_settings = {**DEFAULT_APP_SETTINGS}
 
         try:
-            settings_path = self._storage_dir / "settings.json"
+            settings_path = self.get_app_settings_path()
             if settings_path.exists():
                 with open(settings_path, encoding="utf-8") as f:
                     loaded = json.load(f)
-                    return {**default_settings, **loaded}
+                    return {**default_settings, **self._migrate(loaded)}
 
             if self.page and hasattr(self.page, "client_storage"):
-                stored_settings = self.page.client_storage.get("ren_browser_settings")
+                stored_settings = self.page.client_storage.get(
+                    self._client_key("ren_browser_settings"),
+                )
                 if stored_settings and isinstance(stored_settings, str):
                     loaded = json.loads(stored_settings)
-                    return {**default_settings, **loaded}
+                    return {**default_settings, **self._migrate(loaded)}
 
         except (OSError, json.JSONDecodeError, TypeError):
             pass
 
         return default_settings
 
+    def _migrate(self, loaded: dict) -> dict:
+        self.settings_version = max(SETTINGS_VERSION, settings_version(loaded))
+        return migrate_settings(loaded)
+
     def get_storage_info(self) -> dict[str, Any]:
         """Get information about the storage system."""
         return {
             "storage_dir": str(self._storage_dir),
+            "profile": self.profile,
+            "portable": self.portable,
             "config_path": str(self.get_config_path()),
             "reticulum_config_path": str(self.get_reticulum_config_path()),
             "storage_dir_exists": self._storage_dir.exists(),
@@ -317,13 +704,21 @@ class StorageManager:
 
 # Global storage instance
 _storage_manager: StorageManager | None = None
+_profile: str | None = None
+
+
+def set_profile(name: str | None) -> None:
+    """Use a profile's data from now on, or the startup profile if None."""
+    global _profile, _storage_manager
+    _profile = name
+    _storage_manager = None
 
 
 def get_storage_manager(page: ft.Page | None = None) -> StorageManager:
     """Get the global storage manager instance."""
     global _storage_manager
     if _storage_manager is None:
-        _storage_manager = StorageManager(page)
+        _storage_manager = StorageManager(page, _profile)
     elif page and _storage_manager.page is None:
         _storage_manager.page = page
     return _storage_manager
@@ -332,7 +727,7 @@ def get_storage_manager(page: ft.Page | None = None) -> StorageManager:
 def initialize_storage(page: ft.Page) -> StorageManager:
     """Initialize the storage system with a Flet page."""
     global _storage_manager
-    _storage_manager = StorageManager(page)
+    _storage_manager = StorageManager(page, _profile)
     return _storage_manager
 
 
@@ -0,0 +1,52 @@
+"""Auto-refresh intervals for Ren Browser tabs.
+
+Some node pages are dashboards that change on their own; a tab can be set
+to re-fetch its page every so often without showing the loading view.
+"""
+
+import re
+
+MIN_INTERVAL = 5
+
+REFRESH_INTERVALS = {
+    0: "Off",
+    30: "Every 30 seconds",
+    60: "Every minute",
+    300: "Every 5 minutes",
+}
+
+_UNITS = {"": 1, "s": 1, "m": 60, "h": 3600}
+_INTERVAL_PATTERN = re.compile(r"^(\d+)\s*([smh]?)$")
+
+
+def parse_interval(text: str) -> int:
+    """Parse a custom refresh interval such as ``90``, ``45s`` or ``2m``.
+
+    Args:
+        text: Interval typed by the user, in seconds unless a unit is given.
+
+    Returns:
+        int: The interval in seconds.
+
+    Raises:
+        ValueError: If the text is not an interval of at least MIN_INTERVAL.
+
+    """
+    match = _INTERVAL_PATTERN.match(text.strip().lower())
+    if match is None:
+        raise ValueError("Use a number of seconds, or add m or h.")
+    seconds = int(match.group(1)) * _UNITS[match.group(2)]
+    if seconds < MIN_INTERVAL:
+        raise ValueError(f"Refresh at most every {MIN_INTERVAL} seconds.")
+    return seconds
+
+
+def interval_label(seconds: int) -> str:
+    """Describe a refresh interval."""
+    if seconds in REFRESH_INTERVALS:
+        return REFRESH_INTERVALS[seconds]
+    if seconds % 3600 == 0:
+        return f"Every {seconds // 3600} h"
+    if seconds % 60 == 0:
+        return f"Every {seconds // 60} min"
+    return f"Every {seconds} s"
@@ -0,0 +1,50 @@
+"""Tab groups for Ren Browser.
+
+Tabs can be put in named, coloured groups that sit together in the tab
+bar, such as the tabs opened while reading up on one node. A group can be
+collapsed to just its name in the tab bar and closed as a whole.
+"""
+
+from dataclasses import dataclass
+
+# Colours a group can have, each with the label it is offered under
+GROUP_COLORS = {
+    "blue": "Blue",
+    "green": "Green",
+    "yellow": "Yellow",
+    "red": "Red",
+    "purple": "Purple",
+    "cyan": "Cyan",
+    "orange": "Orange",
+    "pink": "Pink",
+}
+
+
+@dataclass(eq=False)
+class TabGroup:
+    """A named group of tabs.
+
+    Groups compare by identity, so two groups may share a name.
+    """
+
+    name: str
+    color: str = "blue"
+    collapsed: bool = False
+
+
+def next_color(groups: list[TabGroup]) -> str:
+    """Return the first colour of GROUP_COLORS no group has yet.
+
+    Once every colour is taken they are handed out again in order.
+    """
+    used = [group.color for group in groups]
+    for color in GROUP_COLORS:
+        if color not in used:
+            return color
+    return list(GROUP_COLORS)[len(groups) % len(GROUP_COLORS)]
+
+
+def group_end(tabs: list[dict], group: TabGroup) -> int | None:
+    """Return the index of the last tab in a group, or None if it has none."""
+    indexes = [i for i, tab in enumerate(tabs) if tab.get("group") is group]
+    return indexes[-1] if indexes else None
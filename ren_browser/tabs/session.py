@@ -0,0 +1,76 @@
+"""Tab session persistence for Ren Browser.
+
+Remembers which addresses are open in which tabs so the browser can pick up
+where it left off on the next launch, and which tabs were recently closed.
+"""
+
+import time
+from dataclasses import dataclass, field
+
+MAX_CLOSED_TABS = 10
+
+STARTUP_NEW_TAB = "new_tab"
+STARTUP_HOME = "home"
+STARTUP_RESTORE = "restore"
+
+STARTUP_OPTIONS = {
+    STARTUP_NEW_TAB: "Open a new tab",
+    STARTUP_HOME: "Open the home page",
+    STARTUP_RESTORE: "Restore previous session",
+}
+
+
+@dataclass
+class ClosedTab:
+    """A tab the user closed, kept so it can be reopened."""
+
+    address: str
+    title: str
+    closed_at: float = field(default_factory=time.time)
+
+
+class SessionManager:
+    """Saves the open tabs and keeps the session found at startup."""
+
+    def __init__(self, storage):
+        """Load the session saved by the previous run.
+
+        Args:
+            storage: StorageManager used to load and save the session.
+
+        """
+        self.storage = storage
+        try:
+            stored = storage.load_session()
+        except Exception:  # noqa: BLE001
+            stored = {}
+        self.tabs, self.index = self._parse(stored)
+
+    @staticmethod
+    def _parse(stored) -> tuple[list[str | None], int]:
+        if not isinstance(stored, dict) or not isinstance(stored.get("tabs"), list):
+            return [], 0
+        tabs = [
+            address if isinstance(address, str) and address else None
+            for address in stored["tabs"]
+        ]
+        index = stored.get("index", 0)
+        if not isinstance(index, int) or not 0 <= index < len(tabs):
+            index = 0
+        return tabs, index
+
+    def save(self, tabs: list[str | None], index: int) -> None:
+        """Save the open tabs.
+
+        The session loaded at startup is kept, so saving while tabs are still
+        being created does not lose it before it is restored.
+
+        Args:
+            tabs: Address shown in each tab, or None for a new tab page.
+            index: Index of the active tab.
+
+        """
+        try:
+            self.storage.save_session({"tabs": tabs, "index": index})
+        except Exception:  # noqa: BLE001
+            pass
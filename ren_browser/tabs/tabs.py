@@ -4,14 +4,160 @@ Provides tab creation, switching, and content management functionality
 for the browser interface.
 """
 
+import asyncio
+import dataclasses
+import time
+import urllib.parse
+from pathlib import Path
 from types import SimpleNamespace
 
 import flet as ft
 
-from ren_browser.pages.page_request import PageFetcher, PageRequest
-from ren_browser.renderer.micron import render_micron
-from ren_browser.renderer.plaintext import render_plaintext
+from ren_browser.accessibility.accessibility import tab_label
+from ren_browser.annotations.annotations import AnnotationStore, highlight_rgb
+from ren_browser.announces.activity import AnnounceActivity
+from ren_browser.bookmarks.bookmarks import BookmarkManager
+from ren_browser.controls.autocomplete import (
+    AddressAutocomplete,
+    bookmark_suggestions,
+    history_suggestions,
+    petname_suggestions,
+)
+from ren_browser.controls.bookmark_editor import BookmarkEditor
+from ren_browser.controls.chrome_navigation import ChromeNavigator
+from ren_browser.controls.gestures import TouchGestures
+from ren_browser.controls.link_navigation import LinkNavigator
+from ren_browser.crash.crash import browser_version
+from ren_browser.downloads.downloads import DownloadManager, is_file_address
+from ren_browser.feed.feed import NodeFeed
+from ren_browser.gemini.gemini import (
+    GeminiClient,
+    GeminiError,
+    GeminiInput,
+    GeminiResponse,
+    host_key,
+    is_gemini_address,
+)
+from ren_browser.history.history import HistoryManager
+from ren_browser.i18n.i18n import current_language, tr
+from ren_browser.logs import configure_log_file, log_error, log_file_options
+from ren_browser.images.images import is_image_name
+from ren_browser.nodes.nodes import NodeMetadata
+from ren_browser.notifications.notifications import NotificationCenter
+from ren_browser.pages.address import (
+    INTERNAL_SCHEME,
+    PAGES_PREFIX,
+    AddressError,
+    extract_address,
+    file_address,
+    internal_page_name,
+    local_file_path,
+    local_link,
+    lxmf_address,
+    parse_address,
+    resolve_link,
+)
+from ren_browser.pages.aliases import expand_alias
+from ren_browser.pages.cache import PageCache, cache_limits
+from ren_browser.pages.disk_cache import DiskCache, disk_cache_limit
+from ren_browser.pages.prefetch import Prefetcher, prefetch_budget, prefetch_targets
+from ren_browser.pages.page_request import (
+    PageFetcher,
+    PageFetchError,
+    PageRequest,
+    phase_label,
+    phase_progress,
+)
+from ren_browser.pages.revisions import PageRevisions, Revision, diff_lines
+from ren_browser.pages.trace import PageTrace
+from ren_browser.petnames.petnames import PetnameDirectory, expand_petname
+from ren_browser.plugins.plugins import PluginRegistry, load_plugins
+from ren_browser.profiler.profiler import PerformanceMonitor
+from ren_browser.reading_list.reading_list import ReadingList
+from ren_browser.renderer.code import code_language, render_code
+from ren_browser.renderer.gemtext import (
+    build_input_page,
+    gemtext_title,
+    gemtext_to_text,
+    render_gemtext,
+)
+from ren_browser.renderer.markdown import markdown_to_text, render_markdown
+from ren_browser.renderer.micron import (
+    Mark,
+    MicronView,
+    micron_to_text,
+    near_end,
+    render_micron,
+)
+from ren_browser.renderer.plaintext import render_plaintext, render_source
+from ren_browser.renderer.title import extract_title
+from ren_browser.search.search import build_documents
+from ren_browser.speed_dial.speed_dial import SpeedDial
+from ren_browser.spelling.spelling import SpellCheck
 from ren_browser.storage.storage import get_storage_manager
+from ren_browser.tabs.groups import TabGroup, group_end
+from ren_browser.tabs.session import (
+    MAX_CLOSED_TABS,
+    STARTUP_HOME,
+    STARTUP_RESTORE,
+    ClosedTab,
+    SessionManager,
+)
+from ren_browser.tabs.workspace import Workspace, WorkspaceTab
+from ren_browser.themes.themes import ThemeLibrary
+from ren_browser.updates.updates import POLL_INTERVAL, UpdateChecker
+from ren_browser.ui.activity import build_activity_page
+from ren_browser.ui.annotations import AnnotationDialog, build_annotations_page
+from ren_browser.ui.announce_ticker import AnnounceTicker
+from ren_browser.ui.auto_refresh import AutoRefreshMenu
+from ren_browser.ui.avatars import build_node_avatar, update_node_avatar
+from ren_browser.ui.bookmarks import BookmarkFiles, build_bookmarks_page
+from ren_browser.ui.cache import build_cache_page
+from ren_browser.ui.changes import build_changes_banner, build_diff_view
+from ren_browser.ui.clipboard_hint import ClipboardHint
+from ren_browser.ui.devtools import DevToolsPane
+from ren_browser.ui.downloads import DownloadsPanel, SaveLocationPicker
+from ren_browser.ui.encoding import EncodingDialog
+from ren_browser.ui.error_page import build_error_page, build_stale_banner
+from ren_browser.ui.feed import build_feed_page
+from ren_browser.ui.fonts import (
+    BUNDLED_FONTS_DIR,
+    min_font_size,
+    page_font,
+    register_fonts,
+)
+from ren_browser.ui.history import build_history_page
+from ren_browser.ui.image_viewer import ImageViewer
+from ren_browser.ui.log_viewer import build_logs_page
+from ren_browser.ui.lxmf_card import LxmfAddressCard
+from ren_browser.ui.notify import count_badge, desktop_notify, show_snack
+from ren_browser.ui.open_file import FileOpener
+from ren_browser.ui.petnames import PetnameFiles, build_petnames_page
+from ren_browser.ui.playground import build_playground_page
+from ren_browser.ui.print_page import PagePrinter
+from ren_browser.ui.qr_code import QrCodeDialog
+from ren_browser.ui.recently_closed import build_recently_closed
+from ren_browser.ui.reading_list import build_reading_list_page
+from ren_browser.ui.save_page import PageSaver
+from ren_browser.ui.search import build_search_page
+from ren_browser.ui.speed_dial import build_speed_dial
+from ren_browser.ui.spelling import spell_checked
+from ren_browser.ui.tab_groups import TabGroupDialog, build_group_chip, group_swatch
+from ren_browser.ui.tab_switcher import TabSwitcher
+from ren_browser.ui.theme import (
+    apply_content_colors,
+    apply_theme,
+    is_high_contrast,
+)
+from ren_browser.ui.update_page import build_update_page
+from ren_browser.ui.watched import build_watched_page
+from ren_browser.ui.workspaces import WorkspaceFiles
+from ren_browser.watch.watch import PageWatcher
+
+
+def blocked_error(destination_hash: str) -> PageFetchError:
+    """Return the error shown instead of loading a blocked node's page."""
+    return PageFetchError("blocked", f"{destination_hash} is on your blocklist")
 
 
 class TabsManager:
@@ -20,21 +166,249 @@ class TabsManager:
     Handles tab creation, switching, closing, and content rendering.
     """
 
-    def __init__(self, page: ft.Page) -> None:
+    def __init__(self, page: ft.Page, settings: dict | None = None) -> None:
         """Initialize the tab manager.
 
         Args:
             page: Flet page instance for UI updates.
+            settings: App settings already loaded, or None to load them.
 
         """
-        import ren_browser.app as app_module
-
         self.page = page
         self.page.on_resize = self._on_resize
         self.manager = SimpleNamespace(tabs=[], index=0)
 
         storage = get_storage_manager(page)
-        self.settings = storage.load_app_settings()
+        self.settings = settings if settings is not None else (
+            storage.load_app_settings()
+        )
+        self.page_cache = PageCache(
+            *cache_limits(self.settings),
+            disk=DiskCache(storage.get_cache_path(), disk_cache_limit(self.settings)),
+        )
+        self.page_cache.set_enabled(self.settings.get("cache_pages", True))
+        self.performance = PerformanceMonitor()
+        self.history = HistoryManager(storage)
+        self.session = SessionManager(storage)
+        self.closed_tabs: list[ClosedTab] = []
+        self.speed_dial = SpeedDial(storage)
+        self.bookmarks = BookmarkManager(storage)
+        self.reading_list = ReadingList(storage.get_reading_list_path())
+        self.annotations = AnnotationStore(storage)
+        self.petnames = PetnameDirectory(storage)
+        self.revisions = PageRevisions(storage.get_revisions_path())
+        self.activity = AnnounceActivity(storage)
+        self.spelling = SpellCheck(storage.get_dictionaries_path())
+        self.spelling.configure(self.settings, current_language())
+        self.logs_path = storage.get_logs_path()
+        self.plugins_path = storage.get_plugins_path()
+        # Plugins are loaded once, turning them on or off needs a restart
+        self.plugins = (
+            load_plugins(self.plugins_path)
+            if self.settings.get("plugins_enabled", False)
+            else PluginRegistry()
+        )
+        # Markup in the micron playground, kept while the browser runs
+        self.playground_source = ""
+        self.themes = ThemeLibrary(storage.get_themes_path())
+        register_fonts(page, BUNDLED_FONTS_DIR)
+        self.fonts = register_fonts(page, storage.get_fonts_path())
+        self.watcher = PageWatcher(storage, self.fetch_source)
+        self.feed = NodeFeed(storage, self.fetch_source)
+        self.nodes = NodeMetadata(storage)
+        self.notifications = NotificationCenter()
+        self.downloads = DownloadManager(
+            storage,
+            storage.get_downloads_path(),
+            run_thread=page.run_thread,
+        )
+        self.downloads.configure(self.settings.get("download_dir", ""))
+        self.downloads.add_completion_listener(self._on_download_complete)
+        self.downloads.add_failure_listener(self._on_download_failed)
+        self.prefetcher = Prefetcher(
+            self.fetch_source,
+            self.page_cache,
+            run_thread=page.run_thread,
+            budget=prefetch_budget(self.settings),
+        )
+        self.downloads_panel = DownloadsPanel(
+            page,
+            self.downloads,
+            on_view=self.view_image,
+        )
+        self.devtools = DevToolsPane(page, self)
+        self.save_location = SaveLocationPicker(
+            page,
+            self.downloads,
+            on_start=lambda: self.downloads_panel.set_visible(True),
+        )
+        self.page_saver = PageSaver(page, self.downloads.directory)
+        self.printer = PagePrinter(page)
+        self.updates = UpdateChecker(storage, browser_version())
+        self.gemini = GeminiClient(
+            storage.load_gemini_hosts(),
+            on_trust=storage.save_gemini_hosts,
+        )
+        self.file_opener = FileOpener(page, self.open_link_in_new_tab)
+        self.workspaces = WorkspaceFiles(page, self)
+        self.annotation_dialog = AnnotationDialog(page, self)
+        self.encoding_dialog = EncodingDialog(page, self)
+        self.clipboard_hint = ClipboardHint(page, self)
+        self.qr_code = QrCodeDialog(page)
+        self.lxmf_card = LxmfAddressCard(page, self)
+        self.bookmark_files = BookmarkFiles(
+            page,
+            self.bookmarks,
+            on_change=self._on_bookmarks_imported,
+        )
+        self.petname_files = PetnameFiles(
+            page,
+            self.petnames,
+            on_change=self._on_petnames_imported,
+        )
+        self.internal_pages = {
+            "bookmarks": (
+                "Bookmarks",
+                lambda: build_bookmarks_page(
+                    self.bookmarks,
+                    on_open=self._open_address,
+                    on_change=self._on_bookmarks_changed,
+                    files=self.bookmark_files,
+                ),
+            ),
+            "history": (
+                "History",
+                lambda: build_history_page(
+                    self.history,
+                    on_open=self._open_address,
+                    on_change=self.page.update,
+                    node_name=self.node_name,
+                ),
+            ),
+            "reading-list": (
+                "Reading List",
+                lambda: build_reading_list_page(
+                    self.reading_list,
+                    on_open=self.open_offline_copy,
+                    on_change=self.page.update,
+                ),
+            ),
+            "search": (
+                "Search",
+                lambda: build_search_page(
+                    lambda: build_documents(
+                        self.page_cache,
+                        self.reading_list,
+                        self.history,
+                    ),
+                    on_open=self._open_address,
+                    on_change=self.page.update,
+                ),
+            ),
+            "annotations": (
+                "Annotations",
+                lambda: build_annotations_page(
+                    self.annotations,
+                    on_open=self._open_address,
+                    on_edit=self.annotation_dialog.edit,
+                    on_change=self.page.update,
+                ),
+            ),
+            "petnames": (
+                "Petnames",
+                lambda: build_petnames_page(
+                    self.petnames,
+                    on_open=self._open_address,
+                    on_change=self.page.update,
+                    files=self.petname_files,
+                ),
+            ),
+            "announces": ("Announces", self._build_announce_ticker),
+            "activity": (
+                "Announce Activity",
+                lambda: build_activity_page(
+                    self.activity,
+                    on_open=self._open_address,
+                    on_change=self.page.update,
+                    node_name=self.node_name,
+                ),
+            ),
+            "feed": ("Feed", self._build_feed_page),
+            "cache": (
+                "Page Cache",
+                lambda: build_cache_page(
+                    self.page_cache,
+                    on_open=self._open_address,
+                    on_change=self.page.update,
+                ),
+            ),
+            "watched": (
+                "Watched Pages",
+                lambda: build_watched_page(
+                    self.watcher,
+                    on_open=self._open_address,
+                    on_check=lambda done: self.page.run_thread(
+                        lambda: self.check_watched_pages(done),
+                    ),
+                    on_change=self.page.update,
+                ),
+            ),
+            "playground": (
+                "Micron Playground",
+                lambda: build_playground_page(
+                    self.playground_source,
+                    render=self._render_playground,
+                    on_edit=self._keep_playground_source,
+                    on_export=lambda text: self.page_saver.choose(
+                        text,
+                        "/page/index.mu",
+                    ),
+                    on_change=self.page.update,
+                ),
+            ),
+            "update": (
+                "Updates",
+                lambda: build_update_page(
+                    self.updates,
+                    on_check=lambda done: self.page.run_thread(
+                        lambda: self.check_for_updates(done, manual=True),
+                    ),
+                    on_open_release=self.page.launch_url,
+                    on_change=self.page.update,
+                ),
+            ),
+            "logs": (
+                "Logs",
+                lambda: build_logs_page(
+                    on_copy=self._copy_logs,
+                    on_change=self.page.update,
+                ),
+            ),
+        }
+        self.autocomplete = AddressAutocomplete(page, on_select=self._on_suggestion)
+        self.gestures = TouchGestures(self)
+        self.link_navigator = LinkNavigator(self)
+        self.chrome_navigator = ChromeNavigator(self)
+        self.autocomplete.add_source(
+            lambda: bookmark_suggestions(self.bookmarks.as_dicts()),
+        )
+        self.autocomplete.add_source(
+            lambda: petname_suggestions(self.petnames.petnames()),
+        )
+        self.autocomplete.add_source(
+            lambda: history_suggestions(self.history.as_dicts(), self.node_name),
+        )
+        self.bookmark_editor = BookmarkEditor(
+            page,
+            self.bookmarks,
+            on_change=self._update_bookmark_button,
+        )
+        self.bookmark_btn = ft.IconButton(
+            ft.Icons.STAR_BORDER,
+            tooltip="Bookmark this page (Ctrl+D)",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: self.bookmark_current_page(),
+        )
 
         self.tab_bar = ft.Container(
             content=ft.Row(
@@ -44,47 +418,254 @@ class TabsManager:
             padding=ft.padding.symmetric(horizontal=8, vertical=8),
         )
         self.overflow_menu = None
+        self.compact_tabs = False
+        self.tab_groups: list[TabGroup] = []
+        self.group_dialog = TabGroupDialog(page, self)
+        self.tab_switcher = TabSwitcher(page, self)
+        self.progress_bar = ft.ProgressBar(
+            value=0,
+            height=3,
+            color=ft.Colors.PRIMARY,
+            bgcolor=ft.Colors.TRANSPARENT,
+            visible=False,
+        )
+        self.address_bar: ft.Control | None = None
+        self.status_bar = None
+        self.sidebar = None
+        self.zoom = None
+        self.command_palette = None
+        self.shortcut_sheet = None
+        self.keyboard = None
+        self.power_monitor = None
+        self.tray = None
+        self.prefetch_paused = False
+        self.fullscreen = False
+        self.suspended = False
+        self.idle = False
+        self.hovered_link: str | None = None
+        self.link_preview = None
+        self.announce_list = None
+        self.announce_service = None
+        self.announce_ticker = None
+        self.resolve_node_name = None
+        self.node_name = None
         self.content_container = ft.Container(
             expand=True,
-            bgcolor=self.settings.get("page_bgcolor", ft.Colors.BLACK),
             padding=ft.padding.all(16),
         )
+        apply_content_colors(self.content_container, self.settings)
 
-        def handle_link_click_home(link_url):
-            if len(self.manager.tabs) > 0:
-                tab = self.manager.tabs[0]
-                full_url = link_url
-                if ":" not in link_url:
-                    full_url = f"{link_url}:/page/index.mu"
-                tab["url_field"].value = full_url
-                self._on_tab_go(None, 0)
-
-        default_content = (
-            render_micron(
-                "Welcome to Ren Browser",
-                on_link_click=handle_link_click_home,
-            )
-            if app_module.RENDERER == "micron"
-            else render_plaintext("Welcome to Ren Browser")
+        self._add_tab_internal("Home", self._build_new_tab_page(0))
+        self.home_btn = ft.IconButton(
+            ft.Icons.HOME,
+            tooltip="Home (Alt+Home)",
+            on_click=lambda e: self.go_home(),
+            icon_color=ft.Colors.ON_SURFACE,
         )
-        self._add_tab_internal("Home", default_content)
+        self.auto_refresh = AutoRefreshMenu(self.page, self)
+        self.watch_btn = ft.IconButton(
+            ft.Icons.VISIBILITY,
+            tooltip="Watched pages",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: self.open_link_in_new_tab(f"{INTERNAL_SCHEME}watched"),
+        )
+        self._update_watch_badge()
+        self.feed_btn = ft.IconButton(
+            ft.Icons.RSS_FEED,
+            tooltip="Feed",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: self.open_link_in_new_tab(f"{INTERNAL_SCHEME}feed"),
+        )
+        self._update_feed_badge()
         self.add_btn = ft.IconButton(
             ft.Icons.ADD,
             tooltip="New Tab",
             on_click=self._on_add_click,
-            icon_color=ft.Colors.WHITE,
+            icon_color=ft.Colors.ON_SURFACE,
         )
         self.close_btn = ft.IconButton(
             ft.Icons.CLOSE,
             tooltip="Close Tab",
             on_click=self._on_close_click,
-            icon_color=ft.Colors.WHITE,
+            icon_color=ft.Colors.ON_SURFACE,
         )
+        self.tab_bar.content.controls.insert(0, self.tab_switcher.button)
         self.tab_bar.content.controls.append(self.add_btn)
         self.tab_bar.content.controls.append(self.close_btn)
         self.select_tab(0)
         self._update_tab_visibility()
 
+    def _build_new_tab_page(
+        self,
+        idx: int,
+        text: str = "Welcome to Ren Browser",
+    ) -> ft.Control:
+        """Build the new tab page.
+
+        The speed dial and recently closed tabs are listed below the text.
+        """
+        import ren_browser.app as app_module
+
+        def handle_link_click(link_url):
+            if len(self.manager.tabs) > idx:
+                self._follow_link(idx, link_url)
+
+        content = (
+            render_micron(
+                text,
+                on_link_click=handle_link_click,
+                on_link_hover=self._on_link_hover,
+                on_link_new_tab=lambda link_url: self.open_link_in_new_tab(
+                    link_url,
+                    select=False,
+                ),
+                font_family=self.content_font(),
+                high_contrast=is_high_contrast(self.settings),
+                min_size=min_font_size(self.settings),
+            )
+            if app_module.RENDERER == "micron"
+            else render_plaintext(
+                text,
+                self.content_font(),
+                min_size=min_font_size(self.settings),
+            )
+        )
+        controls = [
+            content,
+            build_speed_dial(
+                self.speed_dial,
+                self.history,
+                on_open=self._open_address,
+                on_change=self.page.update,
+            ),
+        ]
+        closed = build_recently_closed(self.closed_tabs, self.reopen_closed_tab)
+        if closed is not None:
+            controls.append(closed)
+        return ft.Column(controls=controls, spacing=0)
+
+    def go_home(self) -> None:
+        """Navigate the active tab to the configured home page.
+
+        An empty ``home_page`` setting shows the new tab page instead.
+        """
+        idx = self.manager.index
+        tab = self.manager.tabs[idx]
+        home = (self.settings.get("home_page") or "").strip()
+        if home:
+            tab["url_field"].value = home
+            self._on_tab_go(None, idx)
+            return
+        tab["url_field"].value = ""
+        tab["url_field"].error_text = None
+        self._record_navigation(tab, "", False)
+        tab["address"] = None
+        tab["load_id"] = object()
+        tab["load_failed"] = False
+        tab["source"] = None
+        update_node_avatar(tab["avatar"], None)
+        self.set_tab_title(idx, "Home")
+        self._set_tab_content(tab, self._build_new_tab_page(idx))
+        self._update_bookmark_button()
+        self._set_load_phase(tab, None)
+        self._save_session()
+        self.page.update()
+
+    def apply_startup(self) -> None:
+        """Open the tabs chosen by the ``startup`` setting.
+
+        Called once the window is built. "restore" reopens the tabs saved by
+        the previous run, "home" opens the home page and anything else keeps
+        the new tab page. Restored tabs only load their page once selected,
+        so starting up fetches just the page of the active tab.
+        """
+        startup = self.settings.get("startup")
+        if startup == STARTUP_HOME:
+            self.go_home()
+            return
+        if startup == STARTUP_RESTORE:
+            self.restore_session()
+
+    def restore_session(self) -> None:
+        """Reopen the tabs saved by the previous run."""
+        self._open_tabs(list(self.session.tabs), self.session.index)
+
+    def _open_tabs(
+        self,
+        addresses: list[str | None],
+        index: int,
+        titles: list[str] | None = None,
+    ) -> None:
+        """Open tabs that load their page once selected, and select one.
+
+        The first one takes the place of the new tab page when nothing else
+        is open, the others are added after the open tabs.
+
+        Args:
+            addresses: Address of each tab, or None for a new tab page.
+            index: Which of the new tabs to select.
+            titles: Title shown for each tab until its page is loaded.
+
+        """
+        if not addresses:
+            return
+        offset = len(self.manager.tabs)
+        reuse = offset == 1 and not self.manager.tabs[0].get("address")
+        if reuse:
+            offset = 0
+        for position, address in enumerate(addresses):
+            if reuse and position == 0:
+                if address:
+                    self._defer_load(self.manager.tabs[0], address)
+            elif address:
+                self.open_link_in_new_tab(address, select=False, defer=True)
+            else:
+                self._on_add_click(None)
+            if address and titles and titles[position]:
+                self.set_tab_title(offset + position, titles[position])
+        self.select_tab(offset + index)
+
+    def current_workspace(self, name: str) -> Workspace:
+        """Return the open tabs as a workspace, leaving out private tabs."""
+        active = self.manager.tabs[self.manager.index] if self.manager.tabs else None
+        kept = [
+            tab
+            for tab in self.manager.tabs
+            if tab.get("address") and not tab.get("private")
+        ]
+        index = next((i for i, tab in enumerate(kept) if tab is active), 0)
+        tabs = [WorkspaceTab(tab["address"], tab["title"]) for tab in kept]
+        return Workspace(name, tabs, index)
+
+    def open_workspace(self, workspace: Workspace) -> None:
+        """Open the tabs of a workspace after the open ones."""
+        self._open_tabs(
+            [tab.address for tab in workspace.tabs],
+            workspace.index,
+            [tab.title for tab in workspace.tabs],
+        )
+        self.page.update()
+
+    def _defer_load(self, tab: dict, address: str) -> None:
+        """Set a tab's address, loading it only once the tab is selected."""
+        tab["url_field"].value = address
+        tab["address"] = address
+        tab["deferred"] = True
+
+    def _save_session(self) -> None:
+        """Save the open tabs so they can be restored on the next launch.
+
+        Private tabs are left out.
+        """
+        active = self.manager.tabs[self.manager.index] if self.manager.tabs else None
+        kept = [tab for tab in self.manager.tabs if not tab.get("private")]
+        index = next((i for i, tab in enumerate(kept) if tab is active), 0)
+        self.session.save([tab.get("address") for tab in kept], index)
+
+    def records_history(self, tab: dict) -> bool:
+        """Return whether pages visited in a tab are added to the history."""
+        return self.settings.get("record_history", True) and not tab.get("private")
+
     def _on_resize(self, e) -> None:  # type: ignore
         """Handle page resize event and update tab visibility."""
         self._update_tab_visibility()
@@ -96,9 +677,31 @@ class TabsManager:
             settings: Dictionary containing appearance settings.
 
         """
+        content_style = self.content_style()
         self.settings = settings
-        bgcolor = settings.get("page_bgcolor", "#000000")
-        self.content_container.bgcolor = bgcolor
+        apply_theme(
+            self.page,
+            settings.get("theme", "dark"),
+            self.themes,
+            settings.get("ui_font"),
+        )
+        apply_content_colors(self.content_container, settings)
+        self.page_cache.configure(*cache_limits(settings))
+        self.page_cache.disk.configure(disk_cache_limit(settings))
+        self.page_cache.set_enabled(settings.get("cache_pages", True))
+        self.spelling.configure(settings, current_language())
+        if not settings.get("clipboard_hints", False):
+            self.clipboard_hint.hide()
+        for tab in self.manager.tabs:
+            if "cache" in tab:
+                tab["cache"].configure(*cache_limits(settings))
+                tab["cache"].set_enabled(settings.get("cache_pages", True))
+        self.prefetcher.configure(prefetch_budget(settings))
+        self.downloads.configure(settings.get("download_dir", ""))
+        self.page_saver.directory = self.downloads.directory
+        configure_log_file(self.logs_path, *log_file_options(settings))
+        if self.content_style() != content_style:
+            self._rerender_pages()
 
         horizontal_scroll = settings.get("horizontal_scroll", False)
         scroll_mode = ft.ScrollMode.ALWAYS if horizontal_scroll else ft.ScrollMode.AUTO
@@ -109,15 +712,154 @@ class TabsManager:
             if "content_control" in tab and hasattr(tab["content_control"], "scroll"):
                 tab["content_control"].scroll = scroll_mode
 
+        if self.sidebar:
+            self.sidebar.resize(settings.get("sidebar_width", self.sidebar.width))
+        if self.address_bar and settings.get("always_show_address_bar", True):
+            self.address_bar.visible = True
+        if self.status_bar:
+            self.status_bar.apply_settings(settings)
+        if self.announce_list:
+            self.announce_list.render()
+            if self.announce_list.refresh_interval is not None:
+                self.announce_list.start_refresh()
+        if self.power_monitor:
+            self.power_monitor.apply(self.power_monitor.status)
+        if self.tray:
+            self.tray.apply_settings(settings)
+        reduced = self.reduced_motion()
+        if self.sidebar:
+            self.sidebar.set_animated(not reduced)
+        if self.announce_ticker:
+            self.announce_ticker.set_live(not reduced)
+        if reduced:
+            for tab in self.manager.tabs:
+                if "spinner" in tab:
+                    tab["spinner"].visible = False
+
         if self.content_container.content:
             self.content_container.content.update()
         self.page.update()
 
+    def reduced_motion(self) -> bool:
+        """Return whether animations and non-essential redraws are turned off."""
+        return self.settings.get("reduced_motion", False)
+
+    def content_font(self) -> str | None:
+        """Return the font rendered pages are shown in, None for the default."""
+        return page_font(self.settings)
+
+    def content_style(self) -> tuple:
+        """Return what rendered pages look like, which they are redone for."""
+        return (
+            self.content_font(),
+            min_font_size(self.settings),
+            is_high_contrast(self.settings),
+        )
+
+    def _rerender_pages(self, destination_hash: str | None = None) -> None:
+        """Render the open pages again from their source, keeping the scroll.
+
+        Pages in tabs that are not shown are dropped instead, to be rebuilt
+        when their tab is selected.
+
+        Args:
+            destination_hash: Only render the pages of this node again.
+
+        """
+        active = self.manager.tabs[self.manager.index]
+        for tab in self.manager.tabs:
+            if tab.get("source") is None or tab.get("load_progress") is not None:
+                continue
+            try:
+                address = parse_address(tab["address"])
+            except (AddressError, KeyError, TypeError):
+                continue
+            if destination_hash not in (None, address.destination_hash):
+                continue
+            if tab is not active:
+                self._release_view(tab)
+                continue
+            self._show_page(
+                tab,
+                address,
+                tab["source"],
+                tab.get("origin") or "cache",
+                silent=True,
+            )
+
+    def reveal_address_bar(self) -> None:
+        """Show the address bar pre-filled with the active tab's address."""
+        tab = self.manager.tabs[self.manager.index]
+        if tab.get("address"):
+            tab["url_field"].value = tab["address"]
+        if self.address_bar:
+            self.address_bar.visible = True
+
+    def set_fullscreen(self, enabled: bool) -> None:
+        """Enter or leave distraction-free fullscreen mode.
+
+        Fullscreen hides everything but the rendered page content.
+
+        Args:
+            enabled: Whether fullscreen mode should be active.
+
+        """
+        self.fullscreen = enabled
+        chrome_visible = not enabled
+        if self.page.appbar:
+            self.page.appbar.visible = chrome_visible
+        self.tab_bar.visible = chrome_visible
+        if self.sidebar:
+            docked = not self.sidebar.drawer_mode
+            self.sidebar.control.visible = chrome_visible and docked
+            self.sidebar.divider.visible = (
+                chrome_visible and docked and not self.sidebar.collapsed
+            )
+        if self.status_bar:
+            self.status_bar.control.visible = chrome_visible and self.settings.get(
+                "show_status_bar",
+                True,
+            )
+        if enabled:
+            self.autocomplete.dismiss()
+        self.page.window.full_screen = enabled
+        if self.zoom:
+            self.zoom.apply()
+        self.page.update()
+
+    def toggle_fullscreen(self) -> None:
+        """Switch distraction-free fullscreen mode on or off."""
+        self.set_fullscreen(not self.fullscreen)
+
+    def _auto_hide_address_bar(self) -> None:
+        """Hide the address bar after a load unless it is pinned visible."""
+        if self.address_bar and not self.settings.get("always_show_address_bar", True):
+            self.address_bar.visible = False
+
+    def _update_tab_labels(self) -> None:
+        """Describe every tab in the strip for screen readers."""
+        tabs = self.manager.tabs
+        for i, tab in enumerate(tabs):
+            if "label" in tab:
+                tab["label"].semantics_label = tab_label(
+                    tab["title"],
+                    i,
+                    len(tabs),
+                    active=i == self.manager.index,
+                    private=tab.get("private", False),
+                    loading=tab.get("load_progress") is not None,
+                )
+
     def _update_tab_visibility(self) -> None:
         """Dynamically adjust tab visibility based on page width.
 
-        Hides tabs that do not fit and moves them to an overflow menu.
+        Hides tabs that do not fit and moves them to an overflow menu. In
+        compact mode every tab is hidden and the tab switcher lists them.
+        Tabs of a collapsed group are hidden behind the group's chip, except
+        the active one.
         """
+        self.tab_switcher.update_button(self.compact_tabs)
+        self._update_tab_labels()
         if not self.page.width or self.page.width == 0:
             return
 
@@ -125,29 +867,42 @@ class TabsManager:
             self.tab_bar.content.controls.remove(self.overflow_menu)
             self.overflow_menu = None
 
+        if self.compact_tabs:
+            for control in self.tab_bar.content.controls:
+                if isinstance(control, ft.Container):
+                    control.visible = False
+            return
+
         available_width = self.page.width - 100
 
         cumulative_width = 0
-        visible_tabs_count = 0
+        overflowing = []
+        last_visible = None
 
-        tab_containers = [
-            c for c in self.tab_bar.content.controls if isinstance(c, ft.Container)
-        ]
+        tab_containers = self._tab_containers()
 
         for i, tab in enumerate(self.manager.tabs):
+            container = tab_containers[i] if i < len(tab_containers) else None
+            group = tab.get("group")
+            if group is not None and group.collapsed and i != self.manager.index:
+                if container is not None:
+                    container.visible = False
+                continue
             estimated_width = len(tab["title"]) * 10 + 32 + self.tab_bar.content.spacing
 
             if cumulative_width + estimated_width <= available_width or i == 0:
                 cumulative_width += estimated_width
-                if i < len(tab_containers):
-                    tab_containers[i].visible = True
-                visible_tabs_count += 1
-            elif i < len(tab_containers):
-                tab_containers[i].visible = False
+                if container is not None:
+                    container.visible = True
+                    last_visible = container
+            else:
+                if container is not None:
+                    container.visible = False
+                overflowing.append(i)
 
-        if len(self.manager.tabs) > visible_tabs_count:
+        if overflowing:
             overflow_items = []
-            for i in range(visible_tabs_count, len(self.manager.tabs)):
+            for i in overflowing:
                 tab_data = self.manager.tabs[i]
                 overflow_items.append(
                     ft.PopupMenuItem(
@@ -158,32 +913,62 @@ class TabsManager:
 
             self.overflow_menu = ft.PopupMenuButton(
                 icon=ft.Icons.MORE_HORIZ,
-                tooltip=f"{len(self.manager.tabs) - visible_tabs_count} more tabs",
+                tooltip=f"{len(overflowing)} more tabs",
                 items=overflow_items,
             )
 
-            self.tab_bar.content.controls.insert(visible_tabs_count, self.overflow_menu)
+            controls = self.tab_bar.content.controls
+            position = controls.index(last_visible) + 1 if last_visible else 1
+            controls.insert(position, self.overflow_menu)
+
+    def set_compact_tabs(self, compact: bool) -> None:
+        """Swap the tab strip for the tab switcher, or back.
+
+        Args:
+            compact: Whether the window is narrow enough for the switcher.
+
+        """
+        if compact == self.compact_tabs:
+            return
+        self.compact_tabs = compact
+        if not compact:
+            self.tab_switcher.panel.visible = False
+        self._update_tab_visibility()
 
-    def _add_tab_internal(self, title: str, content: ft.Control) -> None:
+    def _add_tab_internal(
+        self,
+        title: str,
+        content: ft.Control,
+        private: bool | None = None,
+    ) -> None:
         """Add a new tab to the manager with the given title and content."""
         idx = len(self.manager.tabs)
         url_field = ft.TextField(
             value=title,
+            hint_text="Address or node name",
             expand=True,
             text_style=ft.TextStyle(size=14),
             content_padding=ft.padding.symmetric(horizontal=16, vertical=12),
             border_radius=24,
-            border_color=ft.Colors.GREY_700,
-            focused_border_color=ft.Colors.BLUE_400,
-            bgcolor=ft.Colors.GREY_800,
+            border_color=ft.Colors.OUTLINE_VARIANT,
+            focused_border_color=ft.Colors.PRIMARY,
+            bgcolor=ft.Colors.SURFACE_CONTAINER_HIGHEST,
             prefix_icon=ft.Icons.SEARCH,
+            suffix=ft.IconButton(
+                ft.Icons.CONTENT_PASTE_GO,
+                tooltip="Paste and go (Ctrl+Shift+V)",
+                icon_size=18,
+                on_click=lambda e: self.paste_and_go(),
+            ),
+            on_change=self._on_url_change,
+            on_submit=self._on_url_submit,
         )
         go_btn = ft.IconButton(
             ft.Icons.ARROW_FORWARD,
             tooltip="Go",
             on_click=lambda e, i=idx: self._on_tab_go(e, i),
-            icon_color=ft.Colors.BLUE_400,
-            bgcolor=ft.Colors.BLUE_900,
+            icon_color=ft.Colors.PRIMARY,
+            bgcolor=ft.Colors.PRIMARY_CONTAINER,
         )
         content_control = content
         horizontal_scroll = self.settings.get("horizontal_scroll", False)
@@ -192,35 +977,72 @@ class TabsManager:
         tab_content = ft.Column(
             expand=True,
             scroll=scroll_mode,
+            on_scroll_interval=250,
             controls=[
                 content_control,
             ],
         )
-        self.manager.tabs.append(
-            {
-                "title": title,
-                "url_field": url_field,
-                "go_btn": go_btn,
-                "content_control": content_control,
-                "content": tab_content,
-            },
+        title_label = ft.Text(
+            title,
+            size=13,
+            weight=ft.FontWeight.W_500,
+            overflow=ft.TextOverflow.ELLIPSIS,
         )
+        avatar = build_node_avatar(None, size=18)
+        spinner = ft.ProgressRing(
+            width=14,
+            height=14,
+            stroke_width=2,
+            color=ft.Colors.PRIMARY,
+            visible=False,
+        )
+        if private is None:
+            private = self.settings.get("private_tabs", False)
+        tab = {
+            "title": title,
+            "default_title": title,
+            "private": private,
+            "label": title_label,
+            "avatar": avatar,
+            "spinner": spinner,
+            "url_field": url_field,
+            "go_btn": go_btn,
+            "content_control": content_control,
+            "content": tab_content,
+        }
+        if private:
+            # Pages of a private tab are cached only for that tab, in memory
+            tab["cache"] = PageCache(*cache_limits(self.settings))
+            tab["cache"].set_enabled(self.settings.get("cache_pages", True))
+        tab_content.on_scroll = lambda e: self._on_content_scroll(tab, e)
+        if self.keyboard is not None:
+            self.keyboard.follow_fields(content_control, tab_content)
+        self.manager.tabs.append(tab)
         tab_container = ft.Container(
             content=ft.Row(
                 controls=[
-                    ft.Text(
-                        title,
-                        size=13,
-                        weight=ft.FontWeight.W_500,
-                        overflow=ft.TextOverflow.ELLIPSIS,
+                    spinner,
+                    avatar,
+                    ft.Icon(
+                        ft.Icons.VISIBILITY_OFF,
+                        size=14,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                        tooltip="Private tab",
+                        visible=private,
                     ),
+                    title_label,
                 ],
                 spacing=8,
             ),
+            tooltip=title,
             on_click=lambda e, i=idx: self.select_tab(i),  # type: ignore
             padding=ft.padding.symmetric(horizontal=16, vertical=10),
             border_radius=8,
-            bgcolor=ft.Colors.GREY_800,
+            bgcolor=(
+                ft.Colors.TERTIARY_CONTAINER
+                if private
+                else ft.Colors.SURFACE_CONTAINER_HIGHEST
+            ),
             ink=True,
             width=150,
         )
@@ -228,52 +1050,143 @@ class TabsManager:
         self.tab_bar.content.controls.insert(insert_pos, tab_container)
         self._update_tab_visibility()
 
-    def _on_add_click(self, e) -> None:  # type: ignore
-        """Handle the add tab button click event."""
-        title = f"Tab {len(self.manager.tabs) + 1}"
-        content_text = f"Content for {title}"
-        import ren_browser.app as app_module
+    def _on_content_scroll(self, tab: dict, e) -> None:  # type: ignore
+        """Remember a tab's scroll position and grow long pages near the end."""
+        tab["scroll_offset"] = e.pixels
+        self.gestures.on_scroll(e)
+        control = tab.get("content_control")
+        if isinstance(control, MicronView) and near_end(e) and control.render_more():
+            self.page.update()
 
-        new_idx = len(self.manager.tabs)
+    def _on_url_change(self, e) -> None:  # type: ignore
+        """Refresh address suggestions as the user types."""
+        self.autocomplete.update_query(e.control.value or "")
+        self.page.update()
 
-        def handle_link_click_new(link_url):
-            tab = self.manager.tabs[new_idx]
-            full_url = link_url
-            if ":" not in link_url:
-                full_url = f"{link_url}:/page/index.mu"
-            tab["url_field"].value = full_url
-            self._on_tab_go(None, new_idx)
+    def _on_url_submit(self, e) -> None:  # type: ignore
+        """Navigate the active tab, preferring a highlighted suggestion."""
+        address = self.autocomplete.accept()
+        if address:
+            self.manager.tabs[self.manager.index]["url_field"].value = address
+        self._on_tab_go(e, self.manager.index)
 
-        content = (
-            render_micron(content_text, on_link_click=handle_link_click_new)
-            if app_module.RENDERER == "micron"
-            else render_plaintext(content_text)
+    def paste_and_go(self) -> None:
+        """Navigate the active tab to the address on the clipboard."""
+        text = self.page.get_clipboard()
+        if not text or not text.strip():
+            return
+        idx = self.manager.index
+        self.manager.tabs[idx]["url_field"].value = extract_address(text)
+        self._on_tab_go(None, idx)
+
+    def _on_suggestion(self, address: str) -> None:
+        """Navigate the active tab to a suggestion picked from the dropdown."""
+        self._open_address(address)
+
+    def _open_address(self, address: str) -> None:
+        """Navigate the active tab to an address."""
+        self.manager.tabs[self.manager.index]["url_field"].value = address
+        self._on_tab_go(None, self.manager.index)
+
+    def bookmark_current_page(self) -> None:
+        """Bookmark the active tab's page and open the bookmark editor."""
+        tab = self.manager.tabs[self.manager.index]
+        address = tab.get("address")
+        if not address:
+            return
+        self.bookmark_editor.open(address, tab["title"])
+
+    def _update_bookmark_button(self) -> None:
+        """Fill the star button when the active tab's page is bookmarked."""
+        address = self.manager.tabs[self.manager.index].get("address")
+        bookmarked = bool(address) and self.bookmarks.get(address) is not None
+        self.bookmark_btn.icon = ft.Icons.STAR if bookmarked else ft.Icons.STAR_BORDER
+        self.bookmark_btn.icon_color = (
+            ft.Colors.AMBER_400 if bookmarked else ft.Colors.ON_SURFACE
+        )
+
+    def _on_bookmarks_changed(self) -> None:
+        """Refresh the star button after bookmarks were edited elsewhere."""
+        self._update_bookmark_button()
+        self.page.update()
+
+    def _on_bookmarks_imported(self) -> None:
+        """Redraw any open bookmarks page after an import."""
+        for tab in self.manager.tabs:
+            if tab.get("address") == f"{INTERNAL_SCHEME}bookmarks":
+                self._show_internal_page(tab, "bookmarks")
+        self._on_bookmarks_changed()
+
+    def _on_petnames_imported(self) -> None:
+        """Redraw any open petnames page after an import."""
+        for tab in self.manager.tabs:
+            if tab.get("address") == f"{INTERNAL_SCHEME}petnames":
+                self._show_internal_page(tab, "petnames")
+        self.page.update()
+
+    def _on_add_click(self, e) -> None:  # type: ignore
+        """Handle the add tab button click event."""
+        title = f"Tab {len(self.manager.tabs) + 1}"
+        content = self._build_new_tab_page(
+            len(self.manager.tabs),
+            f"Content for {title}",
         )
         self._add_tab_internal(title, content)
         self.select_tab(len(self.manager.tabs) - 1)
         self.page.update()
 
+    def open_private_tab(self) -> None:
+        """Open a new private tab, whatever new tabs are set to be."""
+        title = f"Tab {len(self.manager.tabs) + 1}"
+        content = self._build_new_tab_page(len(self.manager.tabs))
+        self._add_tab_internal(title, content, private=True)
+        self.select_tab(len(self.manager.tabs) - 1)
+        self.page.update()
+
+    def reopen_closed_tab(self, entry: ClosedTab | None = None) -> None:
+        """Reopen a recently closed tab.
+
+        Args:
+            entry: Closed tab to restore, defaulting to the most recent one.
+
+        """
+        if entry is None:
+            entry = self.closed_tabs[0] if self.closed_tabs else None
+        if entry is None or entry not in self.closed_tabs:
+            return
+        self.closed_tabs.remove(entry)
+        self.open_link_in_new_tab(entry.address)
+
     def _on_close_click(self, e) -> None:  # type: ignore
         """Handle the close tab button click event."""
+        self.close_tab(self.manager.index)
+
+    def close_tab(self, idx: int) -> None:
+        """Close the tab at idx, keeping at least one tab open."""
         if len(self.manager.tabs) <= 1:
             return
-        idx = self.manager.index
+        active = self.manager.index
 
-        tab_containers = [
-            c for c in self.tab_bar.content.controls if isinstance(c, ft.Container)
-        ]
+        tab_containers = self._tab_containers()
         control_to_remove = tab_containers[idx]
 
-        self.manager.tabs.pop(idx)
+        closed = self.manager.tabs.pop(idx)
         self.tab_bar.content.controls.remove(control_to_remove)
+        if closed.get("address") and not closed.get("private"):
+            self.closed_tabs.insert(0, ClosedTab(closed["address"], closed["title"]))
+            del self.closed_tabs[MAX_CLOSED_TABS:]
+        if "cache" in closed:
+            closed.pop("cache").clear()
+        if closed.get("group") is not None:
+            self._drop_empty_groups()
+        self._layout_tab_bar()
 
-        updated_tab_containers = [
-            c for c in self.tab_bar.content.controls if isinstance(c, ft.Container)
-        ]
-        for i, control in enumerate(updated_tab_containers):
-            control.on_click = lambda e, i=i: self.select_tab(i)  # type: ignore
-
-        new_idx = min(idx, len(self.manager.tabs) - 1)
+        if idx == active:
+            new_idx = min(idx, len(self.manager.tabs) - 1)
+        else:
+            # Another tab stays active, which moves up if it came after idx
+            new_idx = active - 1 if idx < active else active
+            self.manager.index = new_idx
         self.select_tab(new_idx)
         self._update_tab_visibility()
         self.page.update()
@@ -285,90 +1198,1678 @@ class TabsManager:
             idx: Index of the tab to select.
 
         """
+        tabs = self.manager.tabs
+        previous = tabs[self.manager.index] if self.manager.index < len(tabs) else None
+        if previous is not None and previous is not tabs[idx]:
+            self._release_view(previous)
         self.manager.index = idx
+        self._restore_view(tabs[idx])
+        if tabs[idx].pop("deferred", False):
+            self._on_tab_go(None, idx)
 
-        tab_containers = [
-            c for c in self.tab_bar.content.controls if isinstance(c, ft.Container)
-        ]
-        for i, control in enumerate(tab_containers):
-            if i == idx:
-                control.bgcolor = ft.Colors.BLUE_900
-                control.border = ft.border.all(2, ft.Colors.BLUE_400)
+        tab_containers = self._tab_containers()
+        for i, (control, tab) in enumerate(zip(tab_containers, tabs, strict=False)):
+            if tab.get("private"):
+                # Private tabs keep their own colour so they stand out
+                control.bgcolor = ft.Colors.TERTIARY_CONTAINER
+                accent = ft.Colors.TERTIARY
             else:
-                control.bgcolor = ft.Colors.GREY_800
-                control.border = None
+                control.bgcolor = (
+                    ft.Colors.PRIMARY_CONTAINER
+                    if i == idx
+                    else ft.Colors.SURFACE_CONTAINER_HIGHEST
+                )
+                accent = ft.Colors.PRIMARY
+            control.border = (
+                ft.border.all(2, accent) if i == idx else self._group_border(tab)
+            )
+        self._update_tab_labels()
 
         self.content_container.content = self.manager.tabs[idx]["content"]
+        self.hovered_link = None
+        if self.link_preview:
+            self.link_preview.hide()
+        if self.bookmark_editor.is_open:
+            self.bookmark_editor.close()
+        self._update_window_title()
+        self._update_progress_bar()
+        self._update_bookmark_button()
+        self.auto_refresh.refresh()
+        self._refresh_status()
+        self.devtools.refresh()
+        self._save_session()
+        self.page.update()
+
+    def _tab_containers(self) -> list[ft.Container]:
+        """Return the tab bar's container of each tab, in tab order."""
+        return [
+            c for c in self.tab_bar.content.controls if isinstance(c, ft.Container)
+        ]
+
+    def _layout_tab_bar(self, containers: list[ft.Container] | None = None) -> None:
+        """Put the tabs in order in the tab bar, each group behind its chip.
+
+        Args:
+            containers: Container of each tab in tab order, defaulting to
+                the order they are in now.
+
+        """
+        if containers is None:
+            containers = self._tab_containers()
+        controls = [self.tab_switcher.button]
+        previous_group = None
+        for i, (tab, container) in enumerate(
+            zip(self.manager.tabs, containers, strict=False),
+        ):
+            group = tab.get("group")
+            if group is not None and group is not previous_group:
+                controls.append(build_group_chip(group, self))
+            previous_group = group
+            container.on_click = lambda e, i=i: self.select_tab(i)  # type: ignore
+            tab["go_btn"].on_click = lambda e, i=i: self._on_tab_go(e, i)
+            controls.append(container)
+        self.tab_bar.content.controls[:] = [*controls, self.add_btn, self.close_btn]
+        self.overflow_menu = None
+
+    def _group_border(self, tab: dict) -> ft.Border | None:
+        """Return the coloured underline of a grouped tab, None otherwise."""
+        group = tab.get("group")
+        if group is None:
+            return None
+        return ft.border.only(bottom=ft.BorderSide(3, group_swatch(group)))
+
+    def _move_tab(self, old: int, new: int) -> None:
+        """Move the tab at old to new, keeping the same tab active."""
+        tabs = self.manager.tabs
+        active = tabs[self.manager.index]
+        containers = self._tab_containers()
+        tabs.insert(new, tabs.pop(old))
+        containers.insert(new, containers.pop(old))
+        self.manager.index = next(i for i, tab in enumerate(tabs) if tab is active)
+        self._layout_tab_bar(containers)
+
+    def _drop_empty_groups(self) -> None:
+        """Forget the groups no tab is in any more."""
+        self.tab_groups = [
+            group
+            for group in self.tab_groups
+            if group_end(self.manager.tabs, group) is not None
+        ]
+
+    def _redraw_groups(self) -> None:
+        """Lay out the tab bar again after its groups changed."""
+        self._drop_empty_groups()
+        self._layout_tab_bar()
+        self.select_tab(self.manager.index)
+        self._update_tab_visibility()
         self.page.update()
 
-    def _on_tab_go(self, e, idx: int) -> None:  # type: ignore
-        """Handle the go button click event for a tab, loading new content."""
+    def add_to_group(self, idx: int, group: TabGroup) -> None:
+        """Put the tab at idx in a group, next to the group's other tabs."""
         tab = self.manager.tabs[idx]
-        url = tab["url_field"].value.strip()
-        if not url:
+        if group not in self.tab_groups:
+            self.tab_groups.append(group)
+        tab.pop("group", None)
+        end = group_end(self.manager.tabs, group)
+        tab["group"] = group
+        if end is not None:
+            self._move_tab(idx, end + 1 if end < idx else end)
+        self._redraw_groups()
+
+    def remove_from_group(self, idx: int) -> None:
+        """Take the tab at idx out of its group, moving it after the group."""
+        group = self.manager.tabs[idx].pop("group", None)
+        if group is None:
             return
+        end = group_end(self.manager.tabs, group)
+        if end is not None and end > idx:
+            self._move_tab(idx, end)
+        self._redraw_groups()
 
-        placeholder_text = f"Loading content for {url}..."
-        import ren_browser.app as app_module
+    def toggle_group(self, group: TabGroup) -> None:
+        """Collapse a group to its chip in the tab bar, or expand it again."""
+        group.collapsed = not group.collapsed
+        self._redraw_groups()
 
-        current_node_hash = None
-        if ":" in url:
-            current_node_hash = url.split(":")[0]
+    def edit_group(self, group: TabGroup, name: str, color: str) -> None:
+        """Rename and recolour a group."""
+        group.name = name
+        group.color = color
+        self._redraw_groups()
 
-        def handle_link_click(link_url):
-            full_url = link_url
-            if ":" not in link_url:
-                full_url = f"{link_url}:/page/index.mu"
-            elif link_url.startswith(":/"):
-                if current_node_hash:
-                    full_url = f"{current_node_hash}{link_url}"
-                else:
-                    full_url = link_url
-            tab["url_field"].value = full_url
-            self._on_tab_go(None, idx)
+    def ungroup(self, group: TabGroup) -> None:
+        """Take every tab out of a group, keeping the tabs open."""
+        for tab in self.manager.tabs:
+            if tab.get("group") is group:
+                tab.pop("group")
+        self._redraw_groups()
 
-        placeholder_control = (
-            render_micron(placeholder_text, on_link_click=handle_link_click)
-            if app_module.RENDERER == "micron"
-            else render_plaintext(placeholder_text)
-        )
-        tab["content_control"] = placeholder_control
-        tab["content"].controls[0] = placeholder_control
-        if self.manager.index == idx:
-            self.content_container.content = tab["content"]
-        self.page.update()
+    def close_group(self, group: TabGroup) -> None:
+        """Close every tab of a group.
 
-        def fetch_and_update():
-            parts = url.split(":", 1)
-            if len(parts) != 2:
-                result = "Error: Invalid URL format. Expected format: hash:/page/path"
-                page_path = ""
-            else:
-                dest_hash = parts[0]
-                page_path = parts[1] if parts[1].startswith("/") else f"/{parts[1]}"
+        A new tab is opened first if the group holds every open tab.
+        """
+        indexes = [
+            i for i, tab in enumerate(self.manager.tabs) if tab.get("group") is group
+        ]
+        if len(indexes) == len(self.manager.tabs):
+            self._on_add_click(None)
+        for idx in reversed(indexes):
+            self.close_tab(idx)
+        self._redraw_groups()
+
+    def _release_view(self, tab: dict) -> None:
+        """Drop the controls of a rendered page that is no longer shown.
+
+        The page is rebuilt from its source when the tab is selected again,
+        so many open tabs do not each keep a full set of controls.
+        """
+        if (
+            tab.get("source") is None
+            or tab.get("load_progress") is not None
+            or tab.get("load_failed")
+            or tab.get("view_released")
+        ):
+            return
+        tab["view_released"] = True
+        self._set_tab_content(tab, ft.Container())
+
+    def suspend(self) -> None:
+        """Stop background work while the app is in the background.
 
-                req = PageRequest(destination_hash=dest_hash, page_path=page_path)
-                page_fetcher = PageFetcher()
-                try:
-                    result = page_fetcher.fetch_page(req)
-                except Exception as ex:
-                    app_module.log_error(str(ex))
-                    result = f"Error: {ex}"
+        Auto-refresh, watched pages and the node feed skip their checks,
+        queued prefetches are dropped, the session is saved in case the app
+        is not resumed, and rendered pages are released so the window is
+        built afresh on resume.
+        """
+        self.suspended = True
+        self.prefetcher.cancel()
+        self._save_session()
+        for tab in self.manager.tabs:
+            self._release_view(tab)
 
+    def resume(self) -> None:
+        """Restart background work and rebuild the page of the active tab."""
+        self.suspended = False
+        if self.manager.tabs:
+            self._restore_view(self.manager.tabs[self.manager.index])
+
+    def _restore_view(self, tab: dict) -> None:
+        """Rebuild the page of a tab whose controls were dropped."""
+        if not tab.get("view_released"):
+            return
+        path = local_file_path(tab.get("address") or "")
+        if tab.get("origin") == "gemini":
+            response = GeminiResponse(tab["address"], tab["mime"], tab["source"])
+            self._show_gemini_page(tab, response)
+        elif path is not None:
+            self._show_local_file(tab, path, tab["address"], traverse=True)
+        else:
             try:
-                tab = self.manager.tabs[idx]
-            except IndexError:
+                address = parse_address(tab["address"])
+            except (AddressError, KeyError, TypeError):
                 return
+            self._show_page(
+                tab,
+                address,
+                tab["source"],
+                tab.get("origin") or "cache",
+                silent=True,
+                stale_since=tab.get("stale_since"),
+            )
+        if tab.get("scroll_offset"):
+            tab["content"].scroll_to(offset=tab["scroll_offset"], duration=0)
 
-            if page_path and page_path.endswith(".mu"):
-                new_control = render_micron(result, on_link_click=handle_link_click)
-            else:
-                new_control = render_plaintext(result)
+    def set_tab_title(self, idx: int, title: str | None) -> None:
+        """Update the label of the tab at the given index.
 
-            tab["content_control"] = new_control
-            tab["content"].controls[0] = new_control
-            if self.manager.index == idx:
-                self.content_container.content = tab["content"]
-            self.page.update()
+        Args:
+            idx: Index of the tab to rename.
+            title: New title, or None to restore the tab's default title.
 
-        self.page.run_thread(fetch_and_update)
+        """
+        tab = self.manager.tabs[idx]
+        title = title or tab.get("default_title", tab["title"])
+        tab["title"] = title
+        if "label" in tab:
+            tab["label"].value = title
+        tab_containers = [
+            c for c in self.tab_bar.content.controls if isinstance(c, ft.Container)
+        ]
+        if idx < len(tab_containers):
+            tab_containers[idx].tooltip = title
+        if self.manager.index == idx:
+            self._update_window_title()
+        self._update_tab_visibility()
+
+    def _update_progress_bar(self) -> None:
+        """Show the active tab's load progress in the progress bar."""
+        progress = self.manager.tabs[self.manager.index].get("load_progress")
+        self.progress_bar.visible = progress is not None
+        self.progress_bar.value = progress or 0
+
+    def _refresh_status(self) -> None:
+        """Refresh the status bar, if one is attached."""
+        if self.status_bar:
+            self.status_bar.refresh()
+
+    def _on_link_hover(self, link_url: str | None) -> None:
+        """Show the link under the pointer in the status bar and preview it."""
+        self.hovered_link = link_url
+        if self.reduced_motion():
+            # Hovering only redraws the status bar and the preview
+            return
+        if self.link_preview and self.settings.get("link_preview", True):
+            tab = self.manager.tabs[self.manager.index]
+            self.link_preview.hover(link_url, self.current_hash(tab))
+        self._refresh_status()
+        self.page.update()
+
+    def _set_load_phase(self, tab: dict, phase: str | None, fraction=None) -> None:
+        """Record a tab's load phase and refresh the progress display.
+
+        Args:
+            tab: Tab whose load progressed.
+            phase: Current LOAD_PHASES phase, or None once loading finished.
+            fraction: Progress within the phase, if known.
+
+        """
+        if "spinner" in tab:
+            tab["spinner"].visible = phase is not None and not self.reduced_motion()
+        trace = tab.get("trace")
+        if trace is not None and trace.finished is None:
+            trace.enter(phase)
+        if phase is None:
+            tab["load_progress"] = None
+            tab["load_label"] = None
+        else:
+            tab["load_progress"] = phase_progress(phase, fraction)
+            tab["load_label"] = phase_label(phase, fraction)
+            if tab.get("load_status") is not None:
+                tab["load_status"].value = tab["load_label"]
+        self._update_tab_labels()
+        if self.manager.tabs[self.manager.index] is tab:
+            self._update_progress_bar()
+            self._refresh_status()
+            self.devtools.refresh()
+        self.page.update()
+
+    def _build_loading_view(self, tab: dict, url: str) -> ft.Control:
+        """Build the placeholder shown while a page is loading."""
+        status = ft.Text(
+            phase_label("link"),
+            size=13,
+            color=ft.Colors.ON_SURFACE_VARIANT,
+        )
+        tab["load_status"] = status
+        indicator = (
+            ft.Icon(ft.Icons.HOURGLASS_EMPTY, size=24)
+            if self.reduced_motion()
+            else ft.ProgressRing(width=24, height=24, stroke_width=3)
+        )
+        return ft.Row(
+            controls=[
+                indicator,
+                ft.Column(
+                    controls=[
+                        ft.Text(
+                            tr("Loading {url}", url=url),
+                            size=14,
+                            weight=ft.FontWeight.W_500,
+                            selectable=True,
+                        ),
+                        status,
+                    ],
+                    spacing=6,
+                ),
+            ],
+            spacing=16,
+        )
+
+    def _update_window_title(self) -> None:
+        """Mirror the active tab's title in the window title."""
+        title = self.manager.tabs[self.manager.index]["title"]
+        self.page.title = f"{title} - Ren Browser"
+
+    def _cache_for(self, tab: dict) -> PageCache:
+        """Return the page cache of a tab, its own one for private tabs."""
+        return tab.get("cache", self.page_cache)
+
+    def _tab_index(self, tab: dict) -> int | None:
+        """Return the current index of a tab, or None if it was closed."""
+        for i, candidate in enumerate(self.manager.tabs):
+            if candidate is tab:
+                return i
+        return None
+
+    def current_hash(self, tab: dict) -> str | None:
+        """Return the destination hash of the page shown in a tab."""
+        address = tab.get("address")
+        if not address or address.startswith(INTERNAL_SCHEME):
+            return None
+        return address.split(":", 1)[0]
+
+    def page_text(self, idx: int) -> str:
+        """Return the readable text of the page shown in the tab at idx."""
+        tab = self.manager.tabs[idx]
+        source = tab.get("source")
+        if source is None:
+            return ""
+        return self._readable_text(tab, source)
+
+    def _readable_text(self, tab: dict, source: str) -> str:
+        """Return the readable text of a page source shown in a tab."""
+        if tab.get("page_path", "").endswith(".mu"):
+            return micron_to_text(source)
+        if tab.get("page_path", "").endswith(".md"):
+            return markdown_to_text(source)
+        if tab.get("origin") == "gemini" and tab.get("mime", "").startswith(
+            "text/gemini",
+        ):
+            return gemtext_to_text(source)
+        return source
+
+    def copy_page_text(self, idx: int) -> None:
+        """Put the readable text of the page in the tab at idx on the clipboard."""
+        text = self.page_text(idx)
+        if not text:
+            return
+        self.page.set_clipboard(text)
+        show_snack(self.page, tr("Page text copied"))
+
+    def open_link_in_new_tab(
+        self,
+        link_url: str,
+        current_hash: str | None = None,
+        select: bool = True,
+        defer: bool = False,
+    ) -> None:
+        """Load a link in a new tab.
+
+        Args:
+            link_url: Link target, possibly relative to current_hash.
+            current_hash: Destination hash of the page containing the link.
+            select: Whether to switch to the new tab or leave it in the background.
+            defer: Load the page only once the tab is first selected.
+
+        """
+        if self._open_lxmf(link_url):
+            return
+        url = resolve_link(link_url, current_hash)
+        self._add_tab_internal(url, render_plaintext(tr("Loading {url}", url=url)))
+        idx = len(self.manager.tabs) - 1
+        tab = self.manager.tabs[idx]
+        if defer:
+            self._defer_load(tab, url)
+        else:
+            tab["url_field"].value = url
+        if select:
+            self.select_tab(idx)
+        if not defer:
+            self._on_tab_go(None, idx)
+        self.page.update()
+
+    def pin_to_speed_dial(self, idx: int) -> None:
+        """Pin the page shown in the tab at idx to the speed dial."""
+        tab = self.manager.tabs[idx]
+        address = tab.get("address")
+        if not address or internal_page_name(address) is not None:
+            return
+        self.speed_dial.pin(address, tab["title"])
+        show_snack(self.page, f"Pinned {tab['title']} to the speed dial")
+
+    def read_later(self, idx: int) -> None:
+        """Save the page shown in the tab at idx to the reading list."""
+        tab = self.manager.tabs[idx]
+        source = tab.get("source")
+        address = tab.get("address")
+        if source is None or not address:
+            return
+        try:
+            self.reading_list.save(address, tab["title"], source, tab["page_path"])
+        except OSError as exc:
+            show_snack(self.page, f"Could not save page: {exc}", success=False)
+            return
+        show_snack(self.page, f"Saved {tab['title']} to the reading list")
+
+    def page_marks(self, address: str) -> tuple[Mark, ...]:
+        """Return the passages highlighted on a page."""
+        return tuple(
+            Mark(annotation.quote, highlight_rgb(annotation), annotation.note)
+            for annotation in self.annotations.for_page(address)
+        )
+
+    def annotate_page(self, idx: int) -> None:
+        """Ask for a passage of the page in the tab at idx to highlight."""
+        if self.manager.tabs[idx].get("source") is None:
+            return
+        self.annotation_dialog.annotate(idx)
+
+    def annotations_changed(self, address: str) -> None:
+        """Show changed highlights on the open pages and the annotations page."""
+        tab = self.manager.tabs[self.manager.index]
+        if tab.get("address") == f"{INTERNAL_SCHEME}annotations":
+            self._show_internal_page(tab, "annotations", traverse=True)
+        try:
+            destination_hash = parse_address(address).destination_hash
+        except AddressError:
+            destination_hash = None
+        self._rerender_pages(destination_hash)
+        self.page.update()
+
+    def open_offline_copy(self, address: str) -> None:
+        """Show the reading list copy of a page in the active tab."""
+        content = self.reading_list.load(address)
+        if content is None:
+            return
+        try:
+            parsed = parse_address(address)
+        except AddressError:
+            return
+        tab = self.manager.tabs[self.manager.index]
+        tab["url_field"].value = address
+        tab["url_field"].error_text = None
+        tab["address"] = address
+        tab["load_id"] = object()
+        update_node_avatar(tab["avatar"], parsed.destination_hash)
+        self._save_session()
+        self._show_page(tab, parsed, content, "offline")
+
+    def save_page_as(self, idx: int) -> None:
+        """Save the source of the page shown in the tab at idx to a file."""
+        tab = self.manager.tabs[idx]
+        source = tab.get("source")
+        if source is None:
+            return
+        self.page_saver.choose(source, tab.get("page_path", ""))
+
+    def show_qr_code(self, idx: int) -> None:
+        """Show the address of the page in the tab at idx as a QR code."""
+        tab = self.manager.tabs[idx]
+        try:
+            address = parse_address(tab.get("address") or "")
+        except AddressError:
+            return
+        self.qr_code.show(address.url(), tab.get("title"))
+
+    def export_pdf(self, idx: int) -> None:
+        """Export the page shown in the tab at idx as a PDF document."""
+        tab = self.manager.tabs[idx]
+        source = tab.get("source")
+        if source is None:
+            return
+        self.page_saver.choose_pdf(source, tab.get("page_path", ""), tab["title"])
+
+    def print_page(self, idx: int) -> None:
+        """Print the page shown in the tab at idx."""
+        tab = self.manager.tabs[idx]
+        source = tab.get("source")
+        if source is None:
+            return
+        self.printer.print_page(source, tab.get("page_path", ""), tab["title"])
+
+    def download_file(self, address: str) -> None:
+        """Download the file at an address and show the downloads panel.
+
+        With the ``ask_download_location`` setting on, a save dialog asks
+        where to put the file first.
+        """
+        if self.settings.get("ask_download_location", False):
+            self.save_location.choose(address)
+            return
+        self.downloads.start(address)
+        self.downloads_panel.set_visible(True)
+
+    def view_source(self, idx: int) -> None:
+        """Open the source of the page shown in the tab at idx in a new tab.
+
+        The source is shown as it was fetched, with its lines numbered.
+        """
+        tab = self.manager.tabs[idx]
+        source = tab.get("source")
+        if source is None:
+            return
+        self._add_tab_internal(
+            f"Source: {tab['title']}",
+            render_source(source, min_size=min_font_size(self.settings)),
+        )
+        self.select_tab(len(self.manager.tabs) - 1)
+        self.page.update()
+
+    def _follow_link(self, idx: int, link_url: str, current_hash=None) -> None:
+        """Load a link clicked on the page shown in the tab at idx."""
+        if self._open_lxmf(link_url):
+            return
+        tab = self.manager.tabs[idx]
+        tab["url_field"].value = resolve_link(link_url, current_hash)
+        self._on_tab_go(None, idx)
+
+    def _open_lxmf(self, link_url: str) -> bool:
+        """Show the address card for an LXMF link and return whether it was one."""
+        destination_hash = lxmf_address(link_url)
+        if destination_hash is None:
+            return False
+        self.lxmf_card.show(destination_hash)
+        return True
+
+    def _on_tab_go(  # type: ignore
+        self,
+        e,
+        idx: int,
+        use_cache: bool = True,
+        refresh_path: bool = False,
+        traverse: bool = False,
+    ) -> None:
+        """Handle the go button click event for a tab, loading new content.
+
+        Args:
+            e: Triggering event, if any.
+            idx: Index of the tab to load.
+            use_cache: Serve a fresh cached copy instead of fetching.
+            refresh_path: Request a new path to the node before fetching.
+            traverse: The load goes back or forward in the tab's history,
+                which go_back and go_forward already updated.
+
+        """
+        tab = self.manager.tabs[idx]
+        url = tab["url_field"].value.strip()
+        if not url:
+            return
+        self.autocomplete.dismiss()
+        alias = expand_alias(url, self.settings)
+        if alias is not None:
+            url = alias
+            tab["url_field"].value = url
+        if self._open_lxmf(url):
+            return
+
+        name = internal_page_name(url)
+        if name is not None:
+            self._show_internal_page(tab, name, traverse)
+            return
+        path = local_file_path(url)
+        if path is not None:
+            self._show_local_file(tab, path, url, traverse)
+            return
+        if is_gemini_address(url):
+            self._load_gemini(tab, url, traverse)
+            return
+
+        try:
+            address = parse_address(
+                expand_petname(url, self.petnames),
+                self.resolve_node_name,
+            )
+        except AddressError as exc:
+            tab["url_field"].error_text = str(exc)
+            self.page.update()
+            return
+        tab["url_field"].error_text = None
+        url = str(address)
+        if self.nodes.is_blocked(address.destination_hash):
+            tab["url_field"].value = url
+            self._record_navigation(tab, url, traverse)
+            tab["address"] = url
+            update_node_avatar(tab["avatar"], address.destination_hash)
+            tab["load_id"] = object()
+            tab["trace"] = None
+            self._show_error(tab, address, blocked_error(address.destination_hash))
+            return
+        if is_file_address(address.page_path):
+            self.download_file(url)
+            tab["url_field"].value = tab.get("address") or ""
+            self.page.update()
+            return
+        tab["url_field"].value = url
+        self._record_navigation(tab, url, traverse)
+        tab["address"] = url
+        update_node_avatar(tab["avatar"], address.destination_hash)
+        self._save_session()
+
+        load_id = object()
+        tab["load_id"] = load_id
+
+        bypass_cache = self.nodes.overrides(address.destination_hash).bypass_cache
+        # Pages decoded in another encoding are kept out of the shared cache
+        encoding = tab.get("encoding")
+        bypass_cache = bypass_cache or encoding is not None
+        trace = PageTrace(
+            url,
+            {
+                "destination_hash": address.destination_hash,
+                "page_path": address.page_path,
+                "field_data": None,
+                "refresh_path": refresh_path,
+                "use_cache": use_cache and not bypass_cache,
+            },
+        )
+        tab["trace"] = trace
+        page_cache = self._cache_for(tab)
+        cached = page_cache.get(url) if use_cache and not bypass_cache else None
+        if cached is not None:
+            self._show_page(tab, address, cached.content, "cache")
+            return
+        stale = (
+            page_cache.get(url, allow_stale=True)
+            if use_cache and not bypass_cache and page_cache.max_age
+            else None
+        )
+        if stale is not None:
+            # Show the expired copy at once and swap in the page if it changed
+            self._show_page(tab, address, stale.content, "cache")
+            self.refresh_silently(tab)
+            return
+
+        placeholder_control = self._build_loading_view(tab, url)
+        self._set_tab_content(tab, placeholder_control)
+        self._set_load_phase(tab, "link")
+
+        def fetch_and_update():
+            req = PageRequest(
+                destination_hash=address.destination_hash,
+                page_path=address.page_path,
+            )
+
+            def on_progress(phase, fraction):
+                if tab.get("load_id") is load_id:
+                    self._set_load_phase(tab, phase, fraction)
+
+            page_fetcher = PageFetcher()
+            error = None
+            started = time.monotonic()
+            try:
+                result = page_fetcher.fetch_page(
+                    req,
+                    on_progress=on_progress,
+                    refresh_path=refresh_path,
+                    encoding=encoding,
+                )
+            except Exception as ex:
+                log_error(str(ex))
+                error = ex
+                trace.fail(ex)
+                self.performance.record_fetch_failure()
+            else:
+                self.performance.record_fetch(url, time.monotonic() - started)
+
+            if self._tab_index(tab) is None or tab.get("load_id") is not load_id:
+                return
+            if error is not None:
+                self._show_error(tab, address, error)
+                return
+            if encoding is None:
+                page_cache.put(url, result)
+            self._show_page(tab, address, result, "network")
+
+        self.page.run_thread(fetch_and_update)
+
+    def _record_navigation(self, tab: dict, url: str, traverse: bool) -> None:
+        """Put the page a tab leaves for url on its back list."""
+        previous = tab.get("address")
+        if traverse or not previous or previous == url:
+            return
+        tab.setdefault("back", []).append(previous)
+        tab["forward"] = []
+
+    def go_back(self, idx: int) -> None:
+        """Load the page the tab at idx showed before the current one."""
+        self._traverse(idx, "back", "forward")
+
+    def go_forward(self, idx: int) -> None:
+        """Load the page the tab at idx went back from."""
+        self._traverse(idx, "forward", "back")
+
+    def _traverse(self, idx: int, source: str, target: str) -> None:
+        tab = self.manager.tabs[idx]
+        if not tab.get(source):
+            return
+        address = tab[source].pop()
+        if tab.get("address"):
+            tab.setdefault(target, []).append(tab["address"])
+        tab["url_field"].value = address
+        self._on_tab_go(None, idx, traverse=True)
+
+    def reload(self, idx: int, hard: bool = False) -> None:
+        """Fetch the page in the tab at idx again, skipping the cache.
+
+        Args:
+            idx: Index of the tab to reload.
+            hard: Also request a new path to the node before fetching,
+                instead of relying on the one Reticulum knows.
+
+        """
+        tab = self.manager.tabs[idx]
+        url = tab.get("address")
+        if not url:
+            return
+        tab["url_field"].value = url
+        if hard and not url.startswith(INTERNAL_SCHEME):
+            show_snack(self.page, "Hard refresh: requesting a new path to the node")
+        self._on_tab_go(None, idx, use_cache=False, refresh_path=hard)
+
+    def set_auto_refresh(self, idx: int, seconds: int) -> None:
+        """Re-fetch the page in the tab at idx every so many seconds.
+
+        Args:
+            idx: Index of the tab to refresh.
+            seconds: Interval between refreshes, or 0 to stop refreshing.
+
+        """
+        tab = self.manager.tabs[idx]
+        refresh_id = object()
+        tab["refresh_interval"] = seconds
+        tab["refresh_id"] = refresh_id
+        if seconds:
+
+            async def refresh_loop():
+                while True:
+                    await asyncio.sleep(seconds)
+                    if (
+                        self._tab_index(tab) is None
+                        or tab.get("refresh_id") is not refresh_id
+                    ):
+                        return
+                    if not self.suspended:
+                        self.refresh_silently(tab)
+
+            self.page.run_task(refresh_loop)
+        if self.manager.tabs[self.manager.index] is tab:
+            self.auto_refresh.refresh()
+        self.page.update()
+
+    def _previous_version(
+        self,
+        tab: dict,
+        url: str,
+        content: str,
+        origin: str,
+    ) -> Revision | None:
+        """Record a page fetched into a tab and return its earlier version.
+
+        Versions are kept like cached pages, so not for private tabs, with
+        the page cache turned off or for pages decoded in another encoding.
+        """
+        if (
+            tab.get("private")
+            or tab.get("encoding") is not None
+            or not self.settings.get("cache_pages", True)
+            or self.nodes.overrides(url.split(":", 1)[0]).bypass_cache
+        ):
+            return None
+        if origin == "network":
+            self.revisions.record(url, content)
+        return self.revisions.previous(url, content)
+
+    def toggle_changes(self, idx: int | None) -> None:
+        """Switch the tab at idx between its page and what changed on it."""
+        if idx is None:
+            return
+        tab = self.manager.tabs[idx]
+        url = tab.get("address")
+        if tab.get("source") is None or not url:
+            return
+        try:
+            address = parse_address(url)
+        except AddressError:
+            return
+        tab["changes_shown"] = None if tab.get("changes_shown") == url else url
+        self._show_page(
+            tab,
+            address,
+            tab["source"],
+            tab.get("origin") or "cache",
+            silent=True,
+        )
+        self.page.update()
+
+    def choose_encoding(self, idx: int) -> None:
+        """Ask which encoding the tab at idx decodes its pages in."""
+        if self.manager.tabs[idx].get("source") is None:
+            return
+        self.encoding_dialog.open(idx)
+
+    def set_encoding(self, idx: int, encoding: str | None) -> None:
+        """Decode the pages of the tab at idx in an encoding, and reload.
+
+        Args:
+            idx: Index of the tab.
+            encoding: Python codec name, or None to detect the encoding.
+
+        """
+        tab = self.manager.tabs[idx]
+        if tab.get("encoding") == encoding:
+            return
+        tab["encoding"] = encoding
+        if tab.get("address") and not tab["address"].startswith(INTERNAL_SCHEME):
+            self.reload(idx)
+
+    def refresh_silently(self, tab: dict) -> None:
+        """Re-fetch a tab's page from the network without the loading view.
+
+        The page is only redrawn if its content changed, and the scroll
+        position is kept. An unchanged page keeps its cached copy, which
+        counts as fresh again.
+        """
+        url = tab.get("address")
+        if (
+            not url
+            or url.startswith(INTERNAL_SCHEME)
+            or tab.get("load_progress") is not None
+        ):
+            return
+        try:
+            address = parse_address(url)
+        except AddressError:
+            return
+        load_id = tab.get("load_id")
+
+        def fetch_and_update():
+            try:
+                result = self.fetch_source(url, tab.get("encoding"))
+            except Exception as ex:  # noqa: BLE001
+                log_error(str(ex))
+                return
+            if self._tab_index(tab) is None or tab.get("load_id") is not load_id:
+                return
+            if tab.get("encoding") is None:
+                self._cache_for(tab).revalidate(url, result)
+            if result == tab.get("source"):
+                return
+            self._show_page(tab, address, result, "network", silent=True)
+            if tab.get("scroll_offset"):
+                tab["content"].scroll_to(offset=tab["scroll_offset"], duration=0)
+            self.page.update()
+
+        self.page.run_thread(fetch_and_update)
+
+    def fetch_source(self, url: str, encoding: str | None = None) -> str:
+        """Fetch the content at an address from the network.
+
+        Args:
+            url: Address of the page.
+            encoding: Encoding the page is decoded in, or None to detect it.
+
+        Raises:
+            AddressError: If the address is not valid.
+            PageFetchError: If the page could not be fetched.
+
+        """
+        address = parse_address(url)
+        if self.nodes.is_blocked(address.destination_hash):
+            raise blocked_error(address.destination_hash)
+        req = PageRequest(
+            destination_hash=address.destination_hash,
+            page_path=address.page_path,
+        )
+        return PageFetcher().fetch_page(req, encoding=encoding)
+
+    def set_node_blocked(
+        self,
+        destination_hash: str,
+        blocked: bool,
+        name: str | None = None,
+    ) -> None:
+        """Block or unblock a node and refresh the sidebar."""
+        self.nodes.set_blocked(destination_hash, blocked, name)
+        if self.announce_list is not None:
+            self.announce_list.refresh()
+
+    def set_node_overrides(
+        self,
+        destination_hash: str,
+        overrides,
+        name: str | None = None,
+    ) -> None:
+        """Change how a node's pages are shown and redraw its open pages."""
+        self.nodes.set_overrides(destination_hash, overrides, name)
+        self._rerender_pages(destination_hash)
+        self.page.update()
+
+    def toggle_plain_text(self, idx: int) -> None:
+        """Switch the node of the page in the tab at idx between renderers.
+
+        Its pages are shown as plain text, or with the renderer for their
+        format again, through the node's overrides.
+        """
+        try:
+            address = parse_address(self.manager.tabs[idx].get("address") or "")
+        except AddressError:
+            return
+        overrides = self.nodes.overrides(address.destination_hash)
+        self.set_node_overrides(
+            address.destination_hash,
+            dataclasses.replace(overrides, plain_text=not overrides.plain_text),
+        )
+
+    def clear_page_cache(self) -> None:
+        """Forget every cached page."""
+        self.page_cache.clear()
+        for tab in self.manager.tabs:
+            if "cache" in tab:
+                tab["cache"].clear()
+        show_snack(self.page, tr("Page cache cleared"))
+
+    def toggle_watch(self, idx: int) -> None:
+        """Start or stop watching the page shown in the tab at idx."""
+        tab = self.manager.tabs[idx]
+        address = tab.get("address")
+        source = tab.get("source")
+        if source is None or not address:
+            return
+        if self.watcher.get(address) is not None:
+            self.watcher.unwatch(address)
+            show_snack(self.page, f"Stopped watching {tab['title']}")
+        else:
+            self.watcher.watch(address, tab["title"], source)
+            show_snack(self.page, f"Watching {tab['title']} for changes")
+        self._update_watch_badge()
+
+    def _on_download_complete(self, download) -> None:
+        message = f"{download.name} downloaded"
+        image = bool(download.path) and is_image_name(download.path)
+        self.notifications.add(
+            "downloads",
+            message,
+            file_address(Path(download.path)) if image else None,
+        )
+        desktop_notify(self.settings, "downloads", message)
+        if image:
+            self.view_image(Path(download.path), select=False)
+
+    def _on_download_failed(self, download) -> None:
+        self.notifications.add(
+            "error",
+            f"{download.name} could not be downloaded: {download.error}",
+            download.address,
+        )
+
+    def view_image(self, path: Path, select: bool = True) -> None:
+        """Open an image file in the image viewer in a new tab."""
+        self.open_link_in_new_tab(file_address(path), select=select)
+
+    def start_page_watcher(self) -> None:
+        """Check the watched pages for changes in the background."""
+
+        async def watch_loop():
+            while True:
+                await asyncio.sleep(self.watcher.interval)
+                if not self.suspended:
+                    self.page.run_thread(self.check_watched_pages)
+
+        self.page.run_task(watch_loop)
+
+    def check_watched_pages(self, on_done=None) -> None:
+        """Check every watched page now and announce the ones that changed.
+
+        Args:
+            on_done: Called once the checks are done.
+
+        """
+        changed = self.watcher.check_all()
+        message = None
+        if len(changed) == 1:
+            message = f"{changed[0].title} has changed"
+        elif changed:
+            message = f"{len(changed)} watched pages have changed"
+        if message:
+            show_snack(self.page, message)
+            address = (
+                changed[0].address
+                if len(changed) == 1
+                else f"{INTERNAL_SCHEME}watched"
+            )
+            self.notifications.add("watched", message, address)
+            desktop_notify(self.settings, "watched", message)
+        self._update_watch_badge()
+        if on_done:
+            on_done()
+        self.page.update()
+
+    def toggle_follow(self, idx: int) -> None:
+        """Follow or unfollow the node serving the page in the tab at idx."""
+        tab = self.manager.tabs[idx]
+        destination_hash = self.current_hash(tab)
+        if not destination_hash:
+            return
+        if self.feed.get(destination_hash) is not None:
+            self.feed.unfollow(destination_hash)
+            show_snack(self.page, "Unfollowed node")
+        else:
+            name = self.node_name(destination_hash) if self.node_name else None
+            node = self.feed.follow(destination_hash, name or tab["title"])
+            show_snack(self.page, f"Following {node.name}")
+            self.page.run_thread(lambda: self.feed.check(destination_hash))
+        self._update_feed_badge()
+
+    def start_node_feed(self) -> None:
+        """Check the followed nodes for new pages in the background."""
+
+        async def feed_loop():
+            while True:
+                await asyncio.sleep(self.feed.interval)
+                if not self.suspended:
+                    self.page.run_thread(self.check_feed)
+
+        self.page.run_task(feed_loop)
+
+    def check_feed(self, on_done=None) -> None:
+        """Check every followed node now and announce new pages.
+
+        Args:
+            on_done: Called once the checks are done.
+
+        """
+        found = self.feed.check_all()
+        message = None
+        if len(found) == 1:
+            message = f"New in your feed: {found[0].title}"
+        elif found:
+            message = f"{len(found)} new pages in your feed"
+        if message:
+            show_snack(self.page, message)
+            self.notifications.add(
+                "feed",
+                message,
+                found[0].address if len(found) == 1 else f"{INTERNAL_SCHEME}feed",
+            )
+        self._update_feed_badge()
+        if on_done:
+            on_done()
+        self.page.update()
+
+    def start_update_checks(self) -> None:
+        """Check for a new release once a day while update checks are on."""
+
+        async def update_loop():
+            while True:
+                if (
+                    self.settings.get("check_updates", False)
+                    and not self.suspended
+                    and self.updates.due()
+                ):
+                    self.page.run_thread(self.check_for_updates)
+                await asyncio.sleep(POLL_INTERVAL)
+
+        self.page.run_task(update_loop)
+
+    def check_for_updates(self, on_done=None, manual: bool = False) -> None:
+        """Check for a new release now and announce it if there is one.
+
+        Args:
+            on_done: Called once the check is done.
+            manual: The check was asked for, so failures are reported too.
+
+        """
+        try:
+            release = self.updates.check()
+        except (OSError, ValueError) as exc:
+            log_error(f"Update check failed: {exc}")
+            if manual:
+                show_snack(self.page, tr("Update check failed"), success=False)
+            else:
+                self.notifications.add("error", tr("Update check failed"))
+            release = None
+        if release is not None:
+            show_snack(
+                self.page,
+                tr("Ren Browser {version} is available", version=release.version),
+                action=tr("Release Notes"),
+                on_action=lambda e: self.open_link_in_new_tab(
+                    f"{INTERNAL_SCHEME}update",
+                ),
+            )
+        if on_done:
+            on_done()
+        self.page.update()
+
+    def _build_announce_ticker(self) -> ft.Control:
+        """Build the live announce ticker, replacing any earlier one."""
+        if self.announce_ticker is not None:
+            self.announce_ticker.detach()
+        if self.announce_service is None:
+            return render_plaintext("Announces are not being received.")
+        self.announce_ticker = AnnounceTicker(
+            self.announce_service,
+            on_open=self._open_address,
+            on_change=self.page.update,
+        )
+        self.announce_ticker.set_live(not self.reduced_motion())
+        self.announce_ticker.attach()
+        return self.announce_ticker.control
+
+    def _render_playground(self, text: str) -> ft.Control:
+        # Links in the playground lead nowhere, they have no node to point to
+        return render_micron(
+            text,
+            font_family=self.content_font(),
+            high_contrast=is_high_contrast(self.settings),
+            min_size=min_font_size(self.settings),
+        )
+
+    def _keep_playground_source(self, text: str) -> None:
+        self.playground_source = text
+
+    def _copy_logs(self, text: str) -> None:
+        self.page.set_clipboard(text)
+        show_snack(self.page, "Log messages copied")
+
+    def _build_feed_page(self) -> ft.Control:
+        """Build the feed page and mark its items as read."""
+        self.feed.mark_read()
+        self._update_feed_badge()
+        return build_feed_page(
+            self.feed,
+            on_open=self._open_address,
+            on_check=lambda done: self.page.run_thread(
+                lambda: self.check_feed(done),
+            ),
+            on_change=self.page.update,
+        )
+
+    def _update_feed_badge(self) -> None:
+        """Show how many feed items are unread on the feed button."""
+        count = self.feed.unread_count()
+        self.feed_btn.badge = count_badge(count)
+        self.feed_btn.icon_color = ft.Colors.PRIMARY if count else ft.Colors.ON_SURFACE
+
+    def _update_watch_badge(self) -> None:
+        """Show how many watched pages changed on the watched pages button."""
+        count = self.watcher.unseen_count()
+        self.watch_btn.badge = count_badge(count)
+        self.watch_btn.icon_color = (
+            ft.Colors.PRIMARY if count else ft.Colors.ON_SURFACE
+        )
+
+    def _show_internal_page(self, tab: dict, name: str, traverse=False) -> None:
+        """Show a built-in ``about:`` page in a tab."""
+        page_entry = self.internal_pages.get(name)
+        if page_entry is None:
+            tab["url_field"].error_text = (
+                f"There is no internal page called {INTERNAL_SCHEME}{name}."
+            )
+            self.page.update()
+            return
+        title, build = page_entry
+        address = f"{INTERNAL_SCHEME}{name}"
+        tab["url_field"].error_text = None
+        tab["url_field"].value = address
+        tab["trace"] = None
+        self._record_navigation(tab, address, traverse)
+        tab["address"] = address
+        tab["load_id"] = object()
+        tab["load_failed"] = False
+        tab["source"] = None
+        tab["origin"] = "local"
+        update_node_avatar(tab["avatar"], None)
+        self.set_tab_title(self._tab_index(tab), title)
+        self._set_tab_content(tab, build())
+        if self.keyboard is not None:
+            self.keyboard.follow_fields(tab["content_control"], tab["content"])
+        self._update_bookmark_button()
+        self._set_load_phase(tab, None)
+        self._save_session()
+
+    def _show_local_file(self, tab: dict, path, url: str, traverse=False) -> None:
+        """Show a page opened from disk in a tab.
+
+        Micron and Markdown files are rendered like pages from a node, and
+        their ``/page/`` links open the files beside them. The file is read again
+        on every load, so reloading shows the latest edit.
+        """
+        try:
+            if is_image_name(path.name):
+                data, content = path.read_bytes(), None
+            else:
+                content = path.read_text(encoding="utf-8")
+        except (OSError, UnicodeDecodeError) as exc:
+            tab["url_field"].error_text = tr(
+                "Cannot open {path}: {error}",
+                path=path,
+                error=exc,
+            )
+            self.page.update()
+            return
+
+        def handle_link_click(link_url):
+            idx = self._tab_index(tab)
+            if idx is not None:
+                self._follow_link(idx, local_link(link_url, path.parent) or link_url)
+
+        def handle_link_new_tab(link_url):
+            target = local_link(link_url, path.parent) or link_url
+            self.open_link_in_new_tab(target, select=False)
+
+        tab["url_field"].error_text = None
+        tab["url_field"].value = url
+        tab["trace"] = None
+        self._record_navigation(tab, url, traverse)
+        tab["address"] = url
+        tab["load_id"] = object()
+        tab["load_failed"] = False
+        tab["view_released"] = False
+        tab["source"] = content
+        tab["page_path"] = path.name
+        tab["origin"] = "local"
+        update_node_avatar(tab["avatar"], None)
+        if content is None:
+            control = ImageViewer(data, path.name, on_change=self.page.update).control
+        elif path.suffix == ".mu":
+            control = render_micron(
+                content,
+                on_link_click=handle_link_click,
+                on_link_hover=self._on_link_hover,
+                on_link_new_tab=handle_link_new_tab,
+                font_family=self.content_font(),
+                high_contrast=is_high_contrast(self.settings),
+                min_size=min_font_size(self.settings),
+            )
+        elif path.suffix == ".md":
+            control = render_markdown(
+                content,
+                f"{PAGES_PREFIX}{path.name}",
+                on_link_click=handle_link_click,
+                on_link_hover=self._on_link_hover,
+                on_link_new_tab=handle_link_new_tab,
+                font_family=self.content_font(),
+                high_contrast=is_high_contrast(self.settings),
+                min_size=min_font_size(self.settings),
+            )
+        elif code_language(path.name) is not None:
+            control = render_code(
+                content,
+                code_language(path.name),
+                min_size=min_font_size(self.settings),
+            )
+        else:
+            control = render_plaintext(
+                content,
+                self.content_font(),
+                min_size=min_font_size(self.settings),
+            )
+        title = (content and extract_title(content, path.name)) or path.name
+        self.set_tab_title(self._tab_index(tab), title)
+        self._set_tab_content(tab, control)
+        if self.keyboard is not None:
+            self.keyboard.follow_fields(tab["content_control"], tab["content"])
+        self._update_bookmark_button()
+        self._set_load_phase(tab, None)
+        self._save_session()
+
+    def _load_gemini(self, tab: dict, url: str, traverse: bool = False) -> None:
+        """Fetch a ``gemini://`` page into a tab in the background."""
+        tab["url_field"].error_text = None
+        tab["url_field"].value = url
+        tab["trace"] = None
+        self._record_navigation(tab, url, traverse)
+        tab["address"] = url
+        update_node_avatar(tab["avatar"], None)
+        self._save_session()
+        load_id = object()
+        tab["load_id"] = load_id
+        self._set_tab_content(tab, self._build_loading_view(tab, url))
+        self._set_load_phase(tab, "link")
+
+        def fetch():
+            try:
+                result = self.gemini.fetch(url, tab.get("encoding"))
+            except (GeminiError, GeminiInput) as exc:
+                result = exc
+            if self._tab_index(tab) is None or tab.get("load_id") is not load_id:
+                return
+            if isinstance(result, GeminiInput):
+                self._show_gemini_input(tab, result)
+            elif isinstance(result, GeminiError):
+                log_error(str(result))
+                self._show_gemini_error(tab, url, result)
+            else:
+                self._show_gemini_page(tab, result)
+
+        self.page.run_thread(fetch)
+
+    def _show_gemini_page(self, tab: dict, response: GeminiResponse) -> None:
+        """Show a fetched Gemini page, gemtext rendered and other text as is."""
+
+        def handle_link_click(link_url):
+            idx = self._tab_index(tab)
+            if idx is not None:
+                self._follow_link(idx, link_url)
+
+        tab["url_field"].value = response.url
+        tab["address"] = response.url
+        tab["load_failed"] = False
+        tab["view_released"] = False
+        tab["source"] = response.body
+        tab["mime"] = response.mime
+        tab["page_path"] = urllib.parse.urlsplit(response.url).path
+        tab["origin"] = "gemini"
+        title = None
+        if response.mime.startswith("text/gemini"):
+            control = render_gemtext(
+                response.body,
+                response.url,
+                on_link_click=handle_link_click,
+                on_link_hover=self._on_link_hover,
+                on_link_new_tab=lambda link: self.open_link_in_new_tab(
+                    link,
+                    select=False,
+                ),
+                font_family=self.content_font(),
+                high_contrast=is_high_contrast(self.settings),
+                min_size=min_font_size(self.settings),
+                marks=self.page_marks(response.url),
+            )
+            title = gemtext_title(response.body)
+        elif response.mime.startswith("text/"):
+            control = render_plaintext(
+                response.body,
+                self.content_font(),
+                min_size=min_font_size(self.settings),
+            )
+        else:
+            control = render_plaintext(
+                tr("{mime} pages cannot be shown.", mime=response.mime.split(";")[0]),
+            )
+        self.set_tab_title(self._tab_index(tab), title or response.url)
+        self._set_tab_content(tab, control)
+        self._update_bookmark_button()
+        self._set_load_phase(tab, None)
+        self._save_session()
+
+    def _show_gemini_input(self, tab: dict, request: GeminiInput) -> None:
+        """Ask for the input a Gemini page needs, then load it with the answer."""
+
+        def submit(value):
+            idx = self._tab_index(tab)
+            if idx is not None:
+                query = urllib.parse.quote(value)
+                tab["url_field"].value = f"{request.url.split('?')[0]}?{query}"
+                self._on_tab_go(None, idx)
+
+        tab["load_failed"] = False
+        tab["source"] = None
+        self.set_tab_title(self._tab_index(tab), request.url)
+        self._set_tab_content(
+            tab,
+            build_input_page(
+                request.prompt,
+                request.sensitive,
+                submit,
+                spell_check=lambda field: spell_checked(
+                    self.page,
+                    field,
+                    self.spelling,
+                ),
+            ),
+        )
+        self._set_load_phase(tab, None)
+
+    def _show_gemini_error(self, tab: dict, url: str, error: GeminiError) -> None:
+        """Show the error page for a Gemini page that failed to load."""
+
+        def retry():
+            idx = self._tab_index(tab)
+            if idx is not None:
+                tab["url_field"].value = url
+                self._on_tab_go(None, idx)
+
+        def trust():
+            self.gemini.forget(host_key(url))
+            retry()
+
+        tab["load_failed"] = True
+        tab["source"] = None
+        tab["origin"] = None
+        self.set_tab_title(self._tab_index(tab), None)
+        self._set_tab_content(
+            tab,
+            build_error_page(
+                error.kind,
+                url,
+                str(error),
+                on_retry=retry,
+                on_trust=trust if error.kind == "certificate" else None,
+            ),
+        )
+        tab["load_status"] = None
+        self._set_load_phase(tab, None)
+
+    def _set_tab_content(
+        self,
+        tab: dict,
+        control: ft.Control,
+        banner: ft.Control | None = None,
+    ) -> None:
+        """Replace the content shown in a tab, with a banner above it if given."""
+        tab["content_control"] = control
+        tab["content"].controls[:] = [control] if banner is None else [banner, control]
+        if self.manager.tabs[self.manager.index] is tab:
+            self.content_container.content = tab["content"]
+
+    def _show_page(
+        self,
+        tab: dict,
+        address,
+        content: str,
+        origin: str,
+        silent: bool = False,
+        stale_since: float | None = None,
+    ) -> None:
+        """Render fetched or cached content into a tab.
+
+        Args:
+            tab: Tab to show the page in.
+            address: Parsed address the content belongs to.
+            content: Page source.
+            origin: Where the content came from, "network" or "cache".
+            silent: Whether this is an auto-refresh, which is neither recorded
+                in history nor shown as a load in progress.
+            stale_since: When a cached copy shown because the page failed to
+                load was fetched, to say so in a banner above it.
+
+        """
+        current_node_hash = address.destination_hash
+        page_path = address.page_path
+
+        def handle_link_click(link_url):
+            current_idx = self._tab_index(tab)
+            if current_idx is not None:
+                self._follow_link(current_idx, link_url, current_node_hash)
+
+        def handle_link_new_tab(link_url):
+            self.open_link_in_new_tab(link_url, current_node_hash, select=False)
+
+        if not silent:
+            self._set_load_phase(tab, "parse")
+        tab["load_failed"] = False
+        tab["view_released"] = False
+        tab["source"] = content
+        tab["page_path"] = page_path
+        tab["origin"] = origin
+        tab["stale_since"] = stale_since
+        overrides = self.nodes.overrides(current_node_hash)
+        text_scale = overrides.zoom or 1.0
+        shown = self.plugins.transform(content, page_path)
+        plugin_page = (
+            None
+            if overrides.plain_text
+            else self.plugins.render(shown, page_path, handle_link_click)
+        )
+        if plugin_page is not None:
+            renderer = f"plugin:{plugin_page[0]}"
+        elif page_path.endswith(".mu") and not overrides.plain_text:
+            renderer = "micron"
+        elif page_path.endswith(".md") and not overrides.plain_text:
+            renderer = "markdown"
+        elif code_language(page_path) is not None and not overrides.plain_text:
+            renderer = "code"
+        else:
+            renderer = "plaintext"
+        trace = tab.get("trace")
+        if trace is not None and not silent:
+            trace.respond(origin, content)
+            trace.renderer = renderer
+        if plugin_page is not None:
+            new_control = plugin_page[1]
+        elif renderer == "micron":
+            new_control = render_micron(
+                shown,
+                on_link_click=handle_link_click,
+                on_link_hover=self._on_link_hover,
+                on_link_new_tab=handle_link_new_tab,
+                font_family=self.content_font(),
+                text_scale=text_scale,
+                high_contrast=is_high_contrast(self.settings),
+                min_size=min_font_size(self.settings),
+                marks=self.page_marks(str(address)),
+            )
+        elif renderer == "markdown":
+            new_control = render_markdown(
+                shown,
+                page_path,
+                on_link_click=handle_link_click,
+                on_link_hover=self._on_link_hover,
+                on_link_new_tab=handle_link_new_tab,
+                font_family=self.content_font(),
+                text_scale=text_scale,
+                high_contrast=is_high_contrast(self.settings),
+                min_size=min_font_size(self.settings),
+                marks=self.page_marks(str(address)),
+            )
+        elif renderer == "code":
+            new_control = render_code(
+                shown,
+                code_language(page_path),
+                text_scale,
+                min_size=min_font_size(self.settings),
+            )
+        else:
+            new_control = render_plaintext(
+                shown,
+                self.content_font(),
+                text_scale,
+                min_size=min_font_size(self.settings),
+            )
+        if not silent:
+            self._set_load_phase(tab, "render")
+
+        idx = self._tab_index(tab)
+        self.set_tab_title(idx, extract_title(content, page_path))
+        if not silent and self.records_history(tab):
+            self.history.record(str(address), tab["title"], current_node_hash)
+        if self.watcher.get(str(address)) is not None:
+            self.watcher.seen(str(address), content)
+            self._update_watch_badge()
+        banner = None
+        if stale_since is not None:
+
+            def retry():
+                current_idx = self._tab_index(tab)
+                if current_idx is not None:
+                    self.reload(current_idx)
+
+            banner = build_stale_banner(time.time() - stale_since, on_retry=retry)
+        previous = self._previous_version(tab, str(address), content, origin)
+        if previous is not None:
+            showing = tab.get("changes_shown") == str(address)
+            if showing:
+                new_control = build_diff_view(
+                    diff_lines(
+                        self._readable_text(tab, previous.content),
+                        self._readable_text(tab, content),
+                    ),
+                    self.content_font(),
+                    min_font_size(self.settings),
+                )
+            changes = build_changes_banner(
+                time.time() - previous.fetched_at,
+                showing,
+                on_toggle=lambda: self.toggle_changes(self._tab_index(tab)),
+            )
+            banner = (
+                changes
+                if banner is None
+                else ft.Column(spacing=0, controls=[banner, changes])
+            )
+        self._set_tab_content(tab, new_control, banner)
+        self._update_bookmark_button()
+        tab["load_status"] = None
+        if self.manager.index == idx and not silent:
+            self.hovered_link = None
+            self._auto_hide_address_bar()
+        self._set_load_phase(tab, None)
+        if (
+            not silent
+            and page_path.endswith(".mu")
+            and not tab.get("private")
+            and not overrides.bypass_cache
+            and not self.prefetch_paused
+            and not self.idle
+        ):
+            self.prefetcher.prefetch(prefetch_targets(address, content))
+
+    def _show_error(self, tab: dict, address, error: Exception) -> None:
+        """Show the error page for a failed load in a tab."""
+        url = str(address)
+        kind = getattr(error, "kind", "unknown")
+
+        def reload(**kwargs):
+            idx = self._tab_index(tab)
+            if idx is not None:
+                tab["url_field"].value = url
+                self._on_tab_go(None, idx, **kwargs)
+
+        open_cached = None
+        cached_age = None
+        page_cache = self._cache_for(tab)
+        entry = page_cache.get(url, allow_stale=True)
+        if entry is not None:
+            cached_age = entry.age()
+
+            def open_cached():
+                cached = page_cache.get(url, allow_stale=True)
+                if cached is not None and self._tab_index(tab) is not None:
+                    tab["load_id"] = object()
+                    self._show_page(
+                        tab,
+                        address,
+                        cached.content,
+                        "cache",
+                        stale_since=cached.fetched_at,
+                    )
+
+        elif self.reading_list.get(url) is not None:
+
+            def open_cached():
+                content = self.reading_list.load(url)
+                if content is not None and self._tab_index(tab) is not None:
+                    tab["load_id"] = object()
+                    self._show_page(tab, address, content, "offline")
+
+        on_unblock = None
+        if kind == "blocked":
+            open_cached = None
+
+            def on_unblock():
+                self.set_node_blocked(address.destination_hash, False)
+                reload(use_cache=False)
+
+        tab["load_failed"] = True
+        tab["source"] = None
+        tab["origin"] = None
+        idx = self._tab_index(tab)
+        self.set_tab_title(idx, None)
+        self._set_tab_content(
+            tab,
+            build_error_page(
+                kind,
+                url,
+                str(error),
+                on_retry=lambda: reload(use_cache=False),
+                on_request_path=lambda: reload(use_cache=False, refresh_path=True),
+                on_open_cached=open_cached,
+                on_unblock=on_unblock,
+                cached_age=cached_age,
+            ),
+        )
+        tab["load_status"] = None
+        self._set_load_phase(tab, None)
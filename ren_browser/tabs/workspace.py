@@ -0,0 +1,105 @@
+"""Workspace files for Ren Browser.
+
+A workspace is a named set of tabs saved to a JSON file, such as the BBS
+nodes checked every week, so the same tabs can be opened again later or
+on another machine.
+"""
+
+import json
+import re
+from dataclasses import dataclass, field
+
+JSON_FORMAT = "ren-browser-workspace"
+JSON_VERSION = 1
+FILE_EXTENSION = "json"
+
+_FILE_NAME_RE = re.compile(r"[^A-Za-z0-9]+")
+
+
+class WorkspaceFormatError(ValueError):
+    """Raised when a workspace file cannot be read."""
+
+
+@dataclass(frozen=True)
+class WorkspaceTab:
+    """A tab of a workspace."""
+
+    address: str
+    title: str = ""
+
+
+@dataclass
+class Workspace:
+    """Named set of tabs, in tab bar order, and the one that was active."""
+
+    name: str
+    tabs: list[WorkspaceTab] = field(default_factory=list)
+    index: int = 0
+
+
+def file_name(name: str) -> str:
+    """Return the file name a workspace is saved under by default."""
+    stem = _FILE_NAME_RE.sub("-", name).strip("-").lower() or "workspace"
+    return f"{stem}.{FILE_EXTENSION}"
+
+
+def to_json(workspace: Workspace) -> str:
+    """Serialize a workspace to the workspace file format."""
+    return json.dumps(
+        {
+            "format": JSON_FORMAT,
+            "version": JSON_VERSION,
+            "name": workspace.name,
+            "tabs": [
+                {"address": tab.address, "title": tab.title} for tab in workspace.tabs
+            ],
+            "index": workspace.index,
+        },
+        indent=2,
+    )
+
+
+def from_json(text: str) -> Workspace:
+    """Read and check a workspace file.
+
+    Tabs without an address are skipped, and an index pointing past the
+    tabs falls back to the first one.
+
+    Raises:
+        WorkspaceFormatError: If the file is not a workspace or holds no tabs.
+
+    """
+    try:
+        data = json.loads(text)
+    except json.JSONDecodeError as exc:
+        raise WorkspaceFormatError(f"Not a valid JSON file: {exc}") from exc
+    if not isinstance(data, dict) or data.get("format") != JSON_FORMAT:
+        raise WorkspaceFormatError("The file is not a Ren Browser workspace.")
+    version = data.get("version")
+    if not isinstance(version, int) or version > JSON_VERSION:
+        raise WorkspaceFormatError(
+            "The file was saved by a newer version of Ren Browser.",
+        )
+    entries = data.get("tabs")
+    tabs = []
+    for entry in entries if isinstance(entries, list) else []:
+        if not isinstance(entry, dict):
+            continue
+        address = entry.get("address")
+        if not isinstance(address, str) or not address.strip():
+            continue
+        title = entry.get("title")
+        tabs.append(
+            WorkspaceTab(address.strip(), title if isinstance(title, str) else ""),
+        )
+    if not tabs:
+        raise WorkspaceFormatError("The workspace has no tabs.")
+    name = data.get("name")
+    index = data.get("index")
+    if isinstance(index, bool) or not isinstance(index, int):
+        index = 0
+    return Workspace(
+        name=name.strip() if isinstance(name, str) and name.strip() else "Workspace",
+        tabs=tabs,
+        index=index if 0 <= index < len(tabs) else 0,
+    )
@@ -0,0 +1,188 @@
+"""Custom themes for Ren Browser.
+
+A custom theme sets the accent, background, text, link and active tab
+colours of the browser. Each one is kept as a small TOML file in the themes
+directory, so themes can be edited by hand and shared with other users.
+"""
+
+import json
+import re
+import tomllib
+from dataclasses import dataclass
+from pathlib import Path
+
+COLOR_FIELDS = {
+    "accent": "Accent",
+    "background": "Background",
+    "text": "Text",
+    "link": "Links",
+    "tab": "Active tab",
+}
+
+_COLOR_RE = re.compile(r"#(?:[0-9a-fA-F]{6}|[0-9a-fA-F]{8})")
+
+
+def is_color(value) -> bool:
+    """Return whether a value is a ``#rrggbb`` or ``#aarrggbb`` colour."""
+    return isinstance(value, str) and _COLOR_RE.fullmatch(value) is not None
+
+
+def mix(color: str, other: str, amount: float) -> str:
+    """Blend two colours, ignoring any alpha.
+
+    Args:
+        color: Colour to start from.
+        other: Colour to blend towards.
+        amount: How far to blend, from 0 for ``color`` to 1 for ``other``.
+
+    Returns:
+        The blended colour as ``#rrggbb``.
+
+    """
+    start = [int(color[-6:][i : i + 2], 16) for i in (0, 2, 4)]
+    end = [int(other[-6:][i : i + 2], 16) for i in (0, 2, 4)]
+    channels = (round(a + (b - a) * amount) for a, b in zip(start, end, strict=True))
+    return "#" + "".join(f"{channel:02x}" for channel in channels)
+
+
+def is_dark_color(color: str) -> bool:
+    """Return whether a colour is dark, so text on it needs to be light."""
+    red, green, blue = (int(color[-6:][i : i + 2], 16) for i in (0, 2, 4))
+    return red * 0.299 + green * 0.587 + blue * 0.114 < 128
+
+
+@dataclass
+class CustomTheme:
+    """A user-defined set of browser colours."""
+
+    name: str
+    accent: str = "#42a5f5"
+    background: str = "#000000"
+    text: str = "#ffffff"
+    link: str = "#42a5f5"
+    tab: str = "#0d47a1"
+
+    @classmethod
+    def from_toml(cls, text: str) -> "CustomTheme | None":
+        """Read a theme file, or return None if it is malformed.
+
+        Colours that are missing or invalid keep their defaults.
+        """
+        try:
+            data = tomllib.loads(text)
+        except tomllib.TOMLDecodeError:
+            return None
+        name = data.get("name")
+        if not isinstance(name, str) or not name.strip():
+            return None
+        colors = data.get("colors")
+        if not isinstance(colors, dict):
+            colors = {}
+        return cls(
+            name=name.strip(),
+            **{
+                key: colors[key].lower()
+                for key in COLOR_FIELDS
+                if is_color(colors.get(key))
+            },
+        )
+
+    def to_toml(self) -> str:
+        """Return the theme as the contents of a theme file."""
+        lines = [f"name = {json.dumps(self.name)}", "", "[colors]"]
+        lines.extend(f'{key} = "{getattr(self, key)}"' for key in COLOR_FIELDS)
+        return "\n".join(lines) + "\n"
+
+    def is_dark(self) -> bool:
+        """Return whether the background is dark, so text needs to be light."""
+        return is_dark_color(self.background)
+
+
+class ThemeLibrary:
+    """Custom themes kept as TOML files in a directory."""
+
+    def __init__(self, directory: Path):
+        """Load the themes in a directory.
+
+        Args:
+            directory: Directory holding one ``.toml`` file per theme.
+
+        """
+        self.directory = Path(directory)
+        self._themes: dict[str, tuple[CustomTheme, str]] = {}
+        try:
+            files = sorted(self.directory.glob("*.toml"))
+        except OSError:
+            files = []
+        for file in files:
+            try:
+                theme = CustomTheme.from_toml(file.read_text(encoding="utf-8"))
+            except (OSError, UnicodeDecodeError):
+                continue
+            if theme is not None and theme.name not in self._themes:
+                self._themes[theme.name] = (theme, file.name)
+
+    def themes(self) -> list[CustomTheme]:
+        """Return the saved themes by name."""
+        return sorted(
+            (theme for theme, _ in self._themes.values()),
+            key=lambda theme: theme.name.lower(),
+        )
+
+    def get(self, name: str) -> CustomTheme | None:
+        """Return the theme with a name, if there is one."""
+        entry = self._themes.get(name)
+        return entry[0] if entry else None
+
+    def save(self, theme: CustomTheme, previous: str | None = None) -> CustomTheme:
+        """Write a theme to its file, replacing the theme it was edited from.
+
+        Args:
+            theme: Theme to save.
+            previous: Name of the theme being edited, or None for a new one.
+
+        Returns:
+            CustomTheme: The saved theme.
+
+        Raises:
+            ValueError: If the name is empty or used by another theme, or a
+                colour is invalid.
+            OSError: If the file could not be written.
+
+        """
+        theme.name = theme.name.strip()
+        if not theme.name:
+            raise ValueError("Enter a name for the theme.")
+        if theme.name != previous and theme.name in self._themes:
+            raise ValueError(f"There is already a theme called {theme.name}.")
+        for key, label in COLOR_FIELDS.items():
+            if not is_color(getattr(theme, key)):
+                raise ValueError(f"{label} must be a colour like #1e88e5.")
+        entry = self._themes.get(previous) if previous else None
+        file = entry[1] if entry else self._free_file(theme.name)
+        self.directory.mkdir(parents=True, exist_ok=True)
+        (self.directory / file).write_text(theme.to_toml(), encoding="utf-8")
+        if previous is not None:
+            self._themes.pop(previous, None)
+        self._themes[theme.name] = (theme, file)
+        return theme
+
+    def remove(self, name: str) -> None:
+        """Delete a theme and its file."""
+        entry = self._themes.pop(name, None)
+        if entry is None:
+            return
+        try:
+            (self.directory / entry[1]).unlink()
+        except OSError:
+            pass
+
+    def _free_file(self, name: str) -> str:
+        stem = re.sub(r"[^a-z0-9]+", "-", name.lower()).strip("-") or "theme"
+        used = {file for _, file in self._themes.values()}
+        file = f"{stem}.toml"
+        number = 2
+        while file in used or (self.directory / file).exists():
+            file = f"{stem}-{number}.toml"
+            number += 1
+        return file
@@ -0,0 +1,309 @@
+"""Announce activity page for Ren Browser.
+
+The ``about:activity`` page charts the announces heard over the last day,
+week or month, for every node together or for one node, over time and by
+hour of the day. It shows when intermittent nodes, such as solar powered
+ones, are usually on the air, so pages can be fetched from them then.
+"""
+
+import time
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.announces.activity import HOUR
+from ren_browser.i18n.i18n import ntr, tr
+from ren_browser.ui.avatars import build_node_avatar
+
+# Spans the page can chart, with their label, length and the seconds each
+# bar of the timeline covers
+RANGES = {
+    "day": ("Last 24 hours", 24 * HOUR, HOUR),
+    "week": ("Last 7 days", 7 * 24 * HOUR, 6 * HOUR),
+    "month": ("Last 30 days", 30 * 24 * HOUR, 24 * HOUR),
+}
+ALL_NODES = "all"
+MAX_NODES = 50
+CHART_HEIGHT = 120
+
+
+def bucket_label(start: float, bucket: int) -> str:
+    """Return the label of the timeline bar starting at a time."""
+    started = datetime.fromtimestamp(start)
+    if bucket >= 24 * HOUR:
+        return started.strftime("%a %d %b")
+    return started.strftime("%a %H:00")
+
+
+def peak_hours(counts: list[int]) -> list[int]:
+    """Return the hours of the day with the most announces, in order."""
+    peak = max(counts, default=0)
+    if not peak:
+        return []
+    return [hour for hour, count in enumerate(counts) if count == peak]
+
+
+def build_activity_page(
+    activity,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+    node_name: Callable[[str], str | None] | None = None,
+    now: Callable[[], float] = time.time,
+) -> ft.Control:
+    """Build the ``about:activity`` page.
+
+    Args:
+        activity: AnnounceActivity whose counts are charted.
+        on_open: Called with a node's index address to open it.
+        on_change: Called after the page has been redrawn so it can be updated.
+        node_name: Optional lookup from destination hash to node name.
+        now: Returns the current time, the end of the charted span.
+
+    Returns:
+        ft.Control: The activity page.
+
+    """
+    range_dropdown = ft.Dropdown(
+        label=tr("Period"),
+        value="week",
+        width=200,
+        options=[
+            ft.dropdown.Option(key, tr(label))
+            for key, (label, _, _) in RANGES.items()
+        ],
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    node_dropdown = ft.Dropdown(
+        label=tr("Node"),
+        value=ALL_NODES,
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    body = ft.Column(spacing=8)
+
+    def name_of(destination_hash):
+        name = node_name(destination_hash) if node_name else None
+        return name or activity.name(destination_hash) or destination_hash[:16]
+
+    def select(destination_hash):
+        node_dropdown.value = destination_hash
+        render()
+        on_change()
+
+    def render():
+        _, span, bucket = RANGES.get(range_dropdown.value, RANGES["week"])
+        end = now()
+        start = end - span
+        nodes = activity.nodes(start)
+        node_dropdown.options = [
+            ft.dropdown.Option(ALL_NODES, tr("All nodes")),
+            *(
+                ft.dropdown.Option(n.destination_hash, name_of(n.destination_hash))
+                for n in nodes[:MAX_NODES]
+            ),
+        ]
+        if node_dropdown.value not in {o.key for o in node_dropdown.options}:
+            node_dropdown.value = ALL_NODES
+        selected = None if node_dropdown.value == ALL_NODES else node_dropdown.value
+        if not nodes:
+            body.controls = [
+                ft.Text(
+                    tr(
+                        "No announces have been heard in this period. Announces "
+                        "are counted while the browser runs.",
+                    ),
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ]
+            return
+        timeline = activity.timeline(start, end, bucket, selected)
+        first = int(start) // HOUR * HOUR
+        by_hour = activity.by_hour_of_day(start, selected)
+        heard = sum(timeline)
+        summary = (
+            ntr(
+                "1 announce from {name}",
+                "{count} announces from {name}",
+                heard,
+                name=ntr("1 node", "{count} nodes", len(nodes)),
+            )
+            if selected is None
+            else ntr(
+                "1 announce from {name}",
+                "{count} announces from {name}",
+                heard,
+                name=name_of(selected),
+            )
+        )
+        peaks = peak_hours(by_hour)
+        controls: list[ft.Control] = [
+            ft.Text(summary, size=14),
+            _section(tr("Over time")),
+            _bar_chart(
+                timeline,
+                [
+                    bucket_label(first + i * bucket, bucket)
+                    for i in range(len(timeline))
+                ],
+            ),
+            ft.Row(
+                alignment=ft.MainAxisAlignment.SPACE_BETWEEN,
+                controls=[
+                    _axis_label(bucket_label(first, bucket)),
+                    _axis_label(bucket_label(end, bucket)),
+                ],
+            ),
+            _section(tr("By hour of day")),
+            _bar_chart(by_hour, [f"{hour:02d}:00" for hour in range(24)]),
+            ft.Row(
+                alignment=ft.MainAxisAlignment.SPACE_BETWEEN,
+                controls=[_axis_label(f"{hour:02d}:00") for hour in (0, 12, 23)],
+            ),
+        ]
+        if peaks:
+            controls.append(
+                ft.Text(
+                    tr(
+                        "Most often heard at {hours}",
+                        hours=", ".join(f"{hour:02d}:00" for hour in peaks),
+                    ),
+                    size=13,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.append(_section(tr("Nodes")))
+        controls.extend(
+            _build_node(
+                node,
+                name_of(node.destination_hash),
+                activity.by_hour_of_day(start, node.destination_hash),
+                selected == node.destination_hash,
+                select,
+                on_open,
+            )
+            for node in nodes[:MAX_NODES]
+        )
+        body.controls = controls
+
+    def on_filter(_):
+        render()
+        on_change()
+
+    range_dropdown.on_change = on_filter
+    node_dropdown.on_change = on_filter
+    render()
+    return ft.Column(
+        spacing=8,
+        controls=[
+            ft.Text(
+                tr("Announce Activity"),
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+            ft.Row(controls=[range_dropdown, node_dropdown], spacing=8),
+            body,
+        ],
+    )
+
+
+def _section(title: str) -> ft.Control:
+    return ft.Container(
+        content=ft.Text(title, size=16, weight=ft.FontWeight.BOLD),
+        padding=ft.padding.only(top=12),
+    )
+
+
+def _axis_label(text: str) -> ft.Control:
+    return ft.Text(text, size=11, color=ft.Colors.ON_SURFACE_VARIANT)
+
+
+def _bar_chart(
+    counts: list[int],
+    labels: list[str],
+    height: int = CHART_HEIGHT,
+) -> ft.Control:
+    peak = max(counts, default=0) or 1
+    return ft.Container(
+        height=height,
+        content=ft.Row(
+            spacing=2,
+            vertical_alignment=ft.CrossAxisAlignment.END,
+            controls=[
+                ft.Container(
+                    expand=True,
+                    height=max(round(height * count / peak), 2),
+                    bgcolor=ft.Colors.PRIMARY if count else ft.Colors.OUTLINE_VARIANT,
+                    border_radius=ft.border_radius.only(top_left=2, top_right=2),
+                    tooltip=f"{label}: {count}",
+                )
+                for count, label in zip(counts, labels, strict=True)
+            ],
+        ),
+    )
+
+
+def _build_node(
+    node,
+    name: str,
+    by_hour: list[int],
+    selected: bool,
+    on_select: Callable[[str], None],
+    on_open: Callable[[str], None],
+) -> ft.Control:
+    heard = datetime.fromtimestamp(node.last_heard).strftime("%Y-%m-%d %H:00")
+    return ft.Container(
+        content=ft.Row(
+            spacing=12,
+            controls=[
+                build_node_avatar(node.destination_hash, size=20),
+                ft.Column(
+                    spacing=2,
+                    width=220,
+                    controls=[
+                        ft.Text(
+                            name,
+                            size=14,
+                            weight=ft.FontWeight.W_500,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                        ft.Text(
+                            ntr(
+                                "1 announce, last at {time}",
+                                "{count} announces, last at {time}",
+                                node.count,
+                                time=heard,
+                            ),
+                            size=12,
+                            color=ft.Colors.ON_SURFACE_VARIANT,
+                        ),
+                    ],
+                ),
+                ft.Container(
+                    expand=True,
+                    content=_bar_chart(
+                        by_hour,
+                        [f"{hour:02d}:00" for hour in range(24)],
+                        height=24,
+                    ),
+                ),
+                ft.IconButton(
+                    ft.Icons.OPEN_IN_NEW,
+                    tooltip=tr("Open node"),
+                    icon_size=18,
+                    on_click=lambda e: on_open(
+                        f"{node.destination_hash}:/page/index.mu",
+                    ),
+                ),
+            ],
+        ),
+        ink=True,
+        on_click=lambda e: on_select(node.destination_hash),
+        bgcolor=ft.Colors.SECONDARY_CONTAINER if selected else None,
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
@@ -0,0 +1,290 @@
+"""Annotation controls for Ren Browser.
+
+The dialog that highlights a passage of the page shown, with a note, or
+changes or deletes a highlight, and the ``about:annotations`` page listing
+the highlights and notes of every page. Flet does not expose the text
+selected on a page, so the passage is pasted into the dialog, which fills
+it in from the clipboard when what was copied is on the page.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.annotations.annotations import (
+    HIGHLIGHT_COLORS,
+    Annotation,
+    highlight_rgb,
+    quote_error,
+)
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.ui.avatars import build_node_avatar
+
+
+class AnnotationDialog:
+    """Dialog highlighting a passage of the active page, or editing a highlight."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            tab_manager: Tab manager holding the annotations and the pages.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.address: str | None = None
+        self.page_title = ""
+        self.page_text = ""
+        self.editing: Annotation | None = None
+        self.quote_field = ft.TextField(
+            label=tr("Passage"),
+            hint_text=tr("Copy text from the page and paste it here"),
+            multiline=True,
+            min_lines=2,
+            max_lines=6,
+            width=420,
+        )
+        self.note_field = ft.TextField(
+            label=tr("Note"),
+            multiline=True,
+            min_lines=2,
+            max_lines=6,
+            width=420,
+        )
+        self.color_dropdown = ft.Dropdown(
+            label=tr("Colour"),
+            width=420,
+            options=[
+                ft.dropdown.Option(color, tr(label))
+                for color, (_, label) in HIGHLIGHT_COLORS.items()
+            ],
+        )
+        self.title = ft.Text()
+        self.delete_btn = ft.TextButton(
+            tr("Delete"),
+            icon=ft.Icons.DELETE_OUTLINE,
+            on_click=lambda e: self.delete(),
+        )
+        self.dialog = ft.AlertDialog(
+            title=self.title,
+            content=ft.Column(
+                tight=True,
+                spacing=12,
+                controls=[self.quote_field, self.note_field, self.color_dropdown],
+            ),
+            actions=[
+                self.delete_btn,
+                ft.TextButton(tr("Cancel"), on_click=lambda e: self.close()),
+                ft.FilledButton(tr("Save"), on_click=lambda e: self.apply()),
+            ],
+        )
+
+    def annotate(self, idx: int) -> None:
+        """Ask for a passage of the page in the tab at idx to highlight."""
+        tab = self.tab_manager.manager.tabs[idx]
+        self.editing = None
+        self.address = tab.get("address")
+        self.page_title = tab.get("title") or ""
+        self.page_text = self.tab_manager.page_text(idx)
+        copied = (self.page.get_clipboard() or "").strip()
+        self.title.value = tr("Highlight Passage")
+        self.quote_field.value = (
+            copied if copied and quote_error(copied, self.page_text) is None else ""
+        )
+        self.quote_field.error_text = None
+        self.quote_field.read_only = False
+        self.note_field.value = ""
+        self.color_dropdown.value = "yellow"
+        self.delete_btn.visible = False
+        open_dialog(self.page, self.dialog)
+
+    def edit(self, annotation: Annotation) -> None:
+        """Ask for a new note and colour of a highlight, or delete it."""
+        self.editing = annotation
+        self.address = annotation.address
+        self.title.value = tr("Edit Highlight")
+        self.quote_field.value = annotation.quote
+        self.quote_field.error_text = None
+        self.quote_field.read_only = True
+        self.note_field.value = annotation.note
+        self.color_dropdown.value = annotation.color
+        self.delete_btn.visible = True
+        open_dialog(self.page, self.dialog)
+
+    def apply(self) -> None:
+        """Save the highlight, checking a new passage is on the page."""
+        note = self.note_field.value or ""
+        color = self.color_dropdown.value or "yellow"
+        if self.editing is not None:
+            self.close()
+            self.tab_manager.annotations.update(self.editing, note, color)
+            self.tab_manager.annotations_changed(self.editing.address)
+            return
+        quote = self.quote_field.value or ""
+        self.quote_field.error_text = quote_error(quote, self.page_text)
+        if self.quote_field.error_text or not self.address:
+            self.page.update()
+            return
+        self.close()
+        self.tab_manager.annotations.add(
+            self.address,
+            self.page_title,
+            quote,
+            note,
+            color,
+        )
+        self.tab_manager.annotations_changed(self.address)
+
+    def delete(self) -> None:
+        """Delete the highlight being edited."""
+        annotation = self.editing
+        self.close()
+        if annotation is not None:
+            self.tab_manager.annotations.remove(annotation)
+            self.tab_manager.annotations_changed(annotation.address)
+
+    def close(self) -> None:
+        """Close the dialog."""
+        self.page.close(self.dialog)
+
+
+def build_annotations_page(
+    annotations,
+    on_open: Callable[[str], None],
+    on_edit: Callable[[Annotation], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:annotations`` page.
+
+    Args:
+        annotations: AnnotationStore whose highlights are listed.
+        on_open: Called with an address when a page is clicked.
+        on_edit: Called with a highlight to change its note or colour.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The annotations page.
+
+    """
+    page = ft.Column(spacing=4)
+
+    def remove(annotation):
+        annotations.remove(annotation)
+        render()
+        on_change()
+
+    def render():
+        controls: list[ft.Control] = [
+            ft.Text(
+                tr("Annotations"),
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+        ]
+        pages = annotations.pages()
+        if not pages:
+            controls.append(
+                ft.Text(
+                    tr(
+                        "Right-click a page and choose Highlight Passage to mark "
+                        "it up. Highlights show again every time the page is "
+                        "opened.",
+                    ),
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        for address, page_annotations in pages:
+            controls.append(_build_page_header(address, page_annotations, on_open))
+            controls.extend(
+                _build_annotation(annotation, on_edit, remove)
+                for annotation in page_annotations
+            )
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _build_page_header(address, page_annotations, on_open) -> ft.Control:
+    return ft.Container(
+        content=ft.Row(
+            spacing=12,
+            controls=[
+                build_node_avatar(address.split(":", 1)[0], size=20),
+                ft.Column(
+                    spacing=2,
+                    expand=True,
+                    controls=[
+                        ft.Text(
+                            page_annotations[-1].title,
+                            size=16,
+                            weight=ft.FontWeight.BOLD,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                        ft.Text(
+                            address,
+                            size=12,
+                            color=ft.Colors.ON_SURFACE_VARIANT,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        ink=True,
+        on_click=lambda e: on_open(address),
+        padding=ft.padding.only(left=12, right=12, top=12, bottom=4),
+        border_radius=8,
+    )
+
+
+def _build_annotation(annotation, on_edit, on_remove) -> ft.Control:
+    made = datetime.fromtimestamp(annotation.created_at).strftime("%Y-%m-%d %H:%M")
+    lines: list[ft.Control] = [
+        ft.Text(
+            annotation.quote,
+            size=14,
+            italic=True,
+            max_lines=4,
+            overflow=ft.TextOverflow.ELLIPSIS,
+            selectable=True,
+        ),
+    ]
+    if annotation.note:
+        lines.append(ft.Text(annotation.note, size=13, selectable=True))
+    lines.append(ft.Text(made, size=12, color=ft.Colors.ON_SURFACE_VARIANT))
+    return ft.Container(
+        content=ft.Row(
+            vertical_alignment=ft.CrossAxisAlignment.START,
+            controls=[
+                ft.Container(
+                    width=4,
+                    height=40,
+                    bgcolor=f"rgb({highlight_rgb(annotation)})",
+                    border_radius=2,
+                ),
+                ft.Column(spacing=2, expand=True, controls=lines),
+                ft.IconButton(
+                    ft.Icons.EDIT_OUTLINED,
+                    tooltip=tr("Edit Highlight"),
+                    icon_size=18,
+                    on_click=lambda e: on_edit(annotation),
+                ),
+                ft.IconButton(
+                    ft.Icons.DELETE_OUTLINE,
+                    tooltip=tr("Delete"),
+                    icon_size=18,
+                    on_click=lambda e: on_remove(annotation),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.only(left=44, right=12, top=4, bottom=4),
+        border_radius=8,
+    )
@@ -0,0 +1,1103 @@
+"""Announce list for Ren Browser's sidebar.
+
+Shows the nodes heard on the network as cards, with how often their pages
+were visited, and opens a node's index page when its card is clicked. A
+search box narrows the list by name, alias, hash or the user's notes,
+ranking the closest matches first, and chips narrow it to one aspect, page
+serving nodes by default.
+Favorite nodes are pinned to a section at the top, even before they have
+announced this session, followed by collapsible user-defined groups that
+nodes are dragged onto to file them. Nodes that have not announced can be
+added by hash. Cards are edged by how recently the
+node announced, and ungrouped nodes that have gone quiet for longer than
+the stale threshold are moved to a collapsed section or hidden. Cards are
+built a batch at a time as the list is scrolled, so hundreds of nodes do
+not have to be built on every refresh, and a card is only built again when
+what it shows has changed, so a refresh sends just the changed cards. The
+list is rebuilt every so often, optionally only while the sidebar is shown,
+so the edges keep up with time. From the keyboard, the arrow keys move an
+outline through the cards and headers and Enter opens or toggles the one
+outlined.
+"""
+
+import asyncio
+import time
+from collections.abc import Callable
+from functools import partial
+
+import flet as ft
+
+from ren_browser.accessibility.accessibility import node_label
+from ren_browser.announces.announces import (
+    ANNOUNCE_ASPECTS,
+    NODE_ASPECT,
+    Announce,
+    request_path,
+)
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.history.history import visit_summary
+from ren_browser.renderer.plaintext import render_plaintext
+from ren_browser.storage.storage import get_storage_manager
+from ren_browser.ui.avatars import build_node_avatar
+from ren_browser.ui.node_details import NodeDetails
+from ren_browser.ui.node_export import NodeExport
+from ren_browser.ui.notify import show_snack
+
+DRAG_GROUP = "sidebar-nodes"
+SEARCH_DELAY = 0.25
+PAGE_SIZE = 60
+SCROLL_AHEAD = 400
+SORT_ORDERS = {
+    "recent": "Last announce",
+    "name": "Name A–Z",
+    "first_seen": "First seen",
+    "most_visited": "Most visited",
+}
+FRESH_SECONDS = 30 * 60
+STALE_AFTER_OPTIONS = {
+    1: "1 hour",
+    3: "3 hours",
+    6: "6 hours",
+    12: "12 hours",
+    24: "1 day",
+}
+NODE_REFRESH_INTERVAL = 30
+NODE_REFRESH_OPTIONS = {
+    0: "Never",
+    10: "10 seconds",
+    30: "30 seconds",
+    60: "1 minute",
+    300: "5 minutes",
+}
+STALE_NODE_MODES = {
+    "section": "Move to a Stale section",
+    "hide": "Hide",
+    "show": "Leave in place",
+}
+FRESHNESS_COLORS = {
+    "fresh": ft.Colors.GREEN_400,
+    "aging": ft.Colors.AMBER_400,
+    "stale": ft.Colors.GREY_600,
+}
+
+
+def freshness(ann, now: float, stale_after: float) -> str | None:
+    """Return how fresh a node's last announce is.
+
+    Args:
+        ann: Announce of the node.
+        now: Current time, in seconds since the epoch.
+        stale_after: Age in seconds after which a node is stale.
+
+    Returns:
+        ``"fresh"`` within FRESH_SECONDS, ``"stale"`` after stale_after,
+        ``"aging"`` in between, or None if it has not announced this session.
+
+    """
+    if not ann.timestamp:
+        return None
+    age = now - ann.timestamp
+    if age >= stale_after:
+        return "stale"
+    if age < FRESH_SECONDS:
+        return "fresh"
+    return "aging"
+
+
+def fuzzy_score(query: str, text: str | None) -> int | None:
+    """Score how well text matches a query typed into the sidebar search.
+
+    A query found whole scores highest, more so at the start of the text or
+    of a word. Otherwise the query's characters must appear in order, and
+    the score rewards runs of adjacent characters and characters that start
+    words, so ``hlt`` finds ``Hilltop``.
+
+    Args:
+        query: Casefolded query, without surrounding spaces.
+        text: Text to search.
+
+    Returns:
+        The score, or None if text does not match.
+
+    """
+    if not text:
+        return None
+    text = text.casefold()
+    position = text.find(query)
+    if position >= 0:
+        starts_word = position == 0 or not text[position - 1].isalnum()
+        return 1000 + (200 if starts_word else 0) - position
+    bonus = [
+        9 if i == 0 or not text[i - 1].isalnum() else 1 for i in range(len(text))
+    ]
+    # best[j] is the best score with the latest query character at text[j].
+    best: list[int | None] = [
+        bonus[j] if char == query[0] else None for j, char in enumerate(text)
+    ]
+    for wanted in query[1:]:
+        scores: list[int | None] = [None] * len(text)
+        earlier = None
+        for j, char in enumerate(text):
+            if j and best[j - 1] is not None:
+                previous = best[j - 1]
+                if char == wanted:
+                    scores[j] = max(previous + 10, earlier or 0) + bonus[j]
+                earlier = previous if earlier is None else max(earlier, previous)
+            elif char == wanted and earlier is not None:
+                scores[j] = earlier + bonus[j]
+        best = scores
+    found = [score for score in best if score is not None]
+    return max(found) if found else None
+
+
+def node_score(
+    ann,
+    note: str | None,
+    query: str,
+    alias: str | None = None,
+) -> int | None:
+    """Score a node against a sidebar search.
+
+    Names and aliases match fuzzily, while the hash and notes must contain
+    the query, since nearly any query is a subsequence of a long hash.
+
+    Args:
+        ann: Announce of the node.
+        note: The user's note about the node, if any.
+        query: Text typed into the search box.
+        alias: The user's alias for the node, if any.
+
+    Returns:
+        0 for a blank query, the best score across the fields, or None if
+        the node does not match.
+
+    """
+    query = query.strip().casefold()
+    if not query:
+        return 0
+    scores = [fuzzy_score(query, ann.display_name), fuzzy_score(query, alias)]
+    for field in (ann.destination_hash, note):
+        if field and query in field.casefold():
+            scores.append(500)
+    scores = [score for score in scores if score is not None]
+    return max(scores) if scores else None
+
+
+def node_matches(
+    ann,
+    note: str | None,
+    query: str,
+    alias: str | None = None,
+) -> bool:
+    """Return whether a node matches a sidebar search.
+
+    Args:
+        ann: Announce of the node.
+        note: The user's note about the node, if any.
+        query: Text typed into the search box.
+        alias: The user's alias for the node, if any.
+
+    Returns:
+        True if the query is blank or matches the name, alias, hash or note.
+
+    """
+    return node_score(ann, note, query, alias) is not None
+
+
+def sort_announces(
+    announces,
+    order: str,
+    stats: dict,
+    aliases: dict | None = None,
+) -> list:
+    """Return announces in the chosen sidebar order.
+
+    By name, nodes without one come last. By first seen, the nodes heard
+    earliest this session come first.
+
+    Args:
+        announces: Announces, most recent first.
+        order: Key of SORT_ORDERS.
+        stats: NodeStats keyed by destination hash.
+        aliases: Local aliases keyed by destination hash, sorted by in place
+            of the announced names.
+
+    Returns:
+        The announces, sorted.
+
+    """
+    announces = list(announces)
+    aliases = aliases or {}
+    if order == "name":
+
+        def name_key(ann):
+            name = aliases.get(ann.destination_hash) or ann.display_name
+            return (name is None, (name or ann.destination_hash).casefold())
+
+        announces.sort(key=name_key)
+    elif order == "first_seen":
+        announces.sort(key=lambda ann: ann.first_seen or ann.timestamp)
+    elif order == "most_visited":
+
+        def key(ann):
+            node = stats.get(ann.destination_hash)
+            return (-node.visits, -node.last_visit) if node else (0, 0)
+
+        announces.sort(key=key)
+    return announces
+
+
+def node_refresh_interval(settings: dict) -> int:
+    """Return the seconds between rebuilds of the node list, 0 for never."""
+    seconds = settings.get("node_refresh_seconds", NODE_REFRESH_INTERVAL)
+    return seconds if seconds in NODE_REFRESH_OPTIONS else NODE_REFRESH_INTERVAL
+
+
+class AnnounceList:
+    """List of announced nodes shown in the sidebar."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the list.
+
+        Args:
+            page: Flet page instance for UI updates.
+            tab_manager: Tab manager that opens nodes and holds the settings.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.announces: list = []
+        order = tab_manager.settings.get("sidebar_sort", "recent")
+        self.sort_order = order if order in SORT_ORDERS else "recent"
+        self.sort_dropdown = ft.Dropdown(
+            value=self.sort_order,
+            dense=True,
+            label="Sort by",
+            options=[
+                ft.dropdown.Option(key, label) for key, label in SORT_ORDERS.items()
+            ],
+            on_change=lambda e: self.set_sort(e.control.value),
+        )
+        self.limit = PAGE_SIZE
+        self._rows: list[Callable[[], ft.Control]] = []
+        self.more_button = ft.TextButton(on_click=lambda e: self.show_more())
+        # Row outlined for the keyboard, and the border it had before
+        self.highlighted: ft.Container | None = None
+        self._highlight_border = None
+        self.list_view = ft.ListView(
+            expand=True,
+            spacing=8,
+            padding=ft.padding.all(8),
+            on_scroll=self._on_scroll,
+        )
+        self.hide_anonymous = bool(tab_manager.settings.get("hide_anonymous", False))
+        self.anonymous_button = ft.IconButton(
+            on_click=lambda e: self.set_hide_anonymous(not self.hide_anonymous),
+        )
+        self._update_anonymous_button()
+        aspect = tab_manager.settings.get("sidebar_aspect", NODE_ASPECT)
+        self.aspect = aspect if aspect in ANNOUNCE_ASPECTS else None
+        self.aspect_chips = ft.Row(spacing=4, wrap=True)
+        self._update_aspect_chips()
+        self.query = ""
+        self._query_version = 0
+        self.refresh_interval: int | None = None
+        # Stretches the refresh interval while saving power on battery
+        self.interval_factor = 1
+        self._refresh_id = None
+        self._scores: dict[str, int] = {}
+        self.stale_collapsed = True
+        self._freshness: dict[str, str | None] = {}
+        self._cards: dict[tuple[str, bool], tuple[tuple, ft.Control]] = {}
+        self.paused = False
+        self._changed_while_paused = False
+        self.search_field = ft.TextField(
+            hint_text="Search nodes",
+            prefix_icon=ft.Icons.SEARCH,
+            dense=True,
+            on_change=lambda e: self.schedule_query(e.control.value),
+            on_submit=lambda e: self.set_query(e.control.value),
+        )
+        self.details = NodeDetails(
+            page,
+            tab_manager,
+            self.open_node,
+            on_change=self.refresh,
+        )
+        self.export = NodeExport(page, lambda: self.announces, tab_manager.nodes)
+        self.renaming: str | None = None
+        self.group_field = ft.TextField(
+            label="Group name",
+            autofocus=True,
+            on_submit=lambda e: self.apply_group_dialog(),
+        )
+        self.group_dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text("New Group"),
+            content=self.group_field,
+            actions=[
+                ft.TextButton(
+                    "Cancel",
+                    on_click=lambda e: self.page.close(self.group_dialog),
+                ),
+                ft.FilledButton("Save", on_click=lambda e: self.apply_group_dialog()),
+            ],
+        )
+        self.node_hash_field = ft.TextField(
+            label="Destination hash",
+            autofocus=True,
+            text_style=ft.TextStyle(font_family="monospace"),
+            on_submit=lambda e: self.apply_add_node_dialog(),
+        )
+        self.node_name_field = ft.TextField(
+            label="Name (optional)",
+            on_submit=lambda e: self.apply_add_node_dialog(),
+        )
+        self.request_path_checkbox = ft.Checkbox(
+            label="Request a path now",
+            value=True,
+        )
+        self.add_node_dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text("Add Node"),
+            content=ft.Column(
+                tight=True,
+                width=380,
+                controls=[
+                    self.node_hash_field,
+                    self.node_name_field,
+                    self.request_path_checkbox,
+                ],
+            ),
+            actions=[
+                ft.TextButton(
+                    "Cancel",
+                    on_click=lambda e: self.page.close(self.add_node_dialog),
+                ),
+                ft.FilledButton(
+                    "Add",
+                    on_click=lambda e: self.apply_add_node_dialog(),
+                ),
+            ],
+        )
+        self.control = ft.Column(
+            expand=True,
+            spacing=0,
+            controls=[
+                ft.Container(
+                    content=self.search_field,
+                    padding=ft.padding.only(left=8, right=8, bottom=8),
+                ),
+                ft.Container(
+                    content=ft.Row(
+                        controls=[
+                            ft.Container(content=self.sort_dropdown, expand=True),
+                            self.anonymous_button,
+                            ft.IconButton(
+                                ft.Icons.SENSORS,
+                                tooltip="Live announces",
+                                on_click=lambda e: tab_manager.open_link_in_new_tab(
+                                    "about:announces",
+                                ),
+                            ),
+                            ft.IconButton(
+                                ft.Icons.ADD_LOCATION_ALT,
+                                tooltip="Add node by hash",
+                                on_click=lambda e: self.open_add_node_dialog(),
+                            ),
+                            ft.IconButton(
+                                ft.Icons.CREATE_NEW_FOLDER,
+                                tooltip="New group",
+                                on_click=lambda e: self.open_group_dialog(),
+                            ),
+                            ft.PopupMenuButton(
+                                icon=ft.Icons.MORE_VERT,
+                                tooltip="More",
+                                items=[
+                                    ft.PopupMenuItem(
+                                        text="Export as JSON…",
+                                        on_click=lambda e: self.export.choose_export(
+                                            "json",
+                                        ),
+                                    ),
+                                    ft.PopupMenuItem(
+                                        text="Export as CSV…",
+                                        on_click=lambda e: self.export.choose_export(
+                                            "csv",
+                                        ),
+                                    ),
+                                ],
+                            ),
+                        ],
+                        spacing=4,
+                    ),
+                    padding=ft.padding.symmetric(horizontal=8),
+                ),
+                ft.Container(
+                    content=self.aspect_chips,
+                    padding=ft.padding.only(left=8, right=8, top=4),
+                ),
+                self.list_view,
+            ],
+        )
+
+    def update(self, announces) -> None:
+        """Show a new list of announces, if it differs from the one shown."""
+        announces = list(announces)
+        if announces == self.announces:
+            return
+        self.announces = announces
+        if self.paused:
+            self._changed_while_paused = True
+            return
+        self.render()
+        self.page.update()
+
+    def set_sort(self, order: str) -> None:
+        """Change and remember the sidebar sort order."""
+        if order not in SORT_ORDERS:
+            return
+        self.sort_order = order
+        self.sort_dropdown.value = order
+        self.limit = PAGE_SIZE
+        settings = {**self.tab_manager.settings, "sidebar_sort": order}
+        self.tab_manager.settings = settings
+        get_storage_manager(self.page).save_app_settings(settings)
+        self.render()
+        self.page.update()
+
+    def schedule_query(self, query: str) -> None:
+        """Search once typing has paused, instead of on every keystroke."""
+        self._query_version += 1
+        version = self._query_version
+
+        async def apply():
+            await asyncio.sleep(SEARCH_DELAY)
+            if version == self._query_version:
+                self.set_query(query)
+
+        self.page.run_task(apply)
+
+    def start_refresh(self) -> None:
+        """Rebuild the list every so often, as set in the settings.
+
+        Called again after the settings changed, it replaces the running
+        loop if the interval is different.
+        """
+        interval = node_refresh_interval(self.tab_manager.settings)
+        interval *= self.interval_factor
+        if self._refresh_id is not None and interval == self.refresh_interval:
+            return
+        refresh_id = object()
+        self._refresh_id = refresh_id
+        self.refresh_interval = interval
+        if not interval:
+            return
+
+        async def refresh_loop():
+            while True:
+                await asyncio.sleep(interval)
+                if self._refresh_id is not refresh_id:
+                    return
+                settings = self.tab_manager.settings
+                if settings.get("node_refresh_sidebar_only", True) and (
+                    self.sidebar_hidden()
+                ):
+                    continue
+                self.refresh()
+
+        self.page.run_task(refresh_loop)
+
+    def pause(self) -> None:
+        """Stop rebuilding the list, keeping announces that arrive meanwhile."""
+        self.paused = True
+        self._refresh_id = None
+
+    def resume(self) -> None:
+        """Rebuild the list again, showing announces heard while paused."""
+        self.paused = False
+        self.start_refresh()
+        if self._changed_while_paused:
+            self._changed_while_paused = False
+            self.render()
+
+    def sidebar_hidden(self) -> bool:
+        """Return whether the list is out of sight, collapsed or in fullscreen."""
+        sidebar = getattr(self.tab_manager, "sidebar", None)
+        return bool(
+            self.tab_manager.fullscreen
+            or (sidebar is not None and sidebar.hidden),
+        )
+
+    def set_query(self, query: str) -> None:
+        """Show only the nodes matching a search, best matches first."""
+        self._query_version += 1
+        self.query = query or ""
+        self.limit = PAGE_SIZE
+        self.refresh()
+
+    def refresh(self) -> None:
+        """Rebuild the list, for changes made outside the sidebar."""
+        self.render()
+        self.page.update()
+
+    def _shown(self, ann, favorite: bool = False) -> bool:
+        if self.tab_manager.nodes.is_blocked(ann.destination_hash):
+            return False
+        if self.aspect and ann.aspect != self.aspect:
+            return False
+        info = self.tab_manager.nodes.get(ann.destination_hash)
+        named = ann.display_name or info.alias
+        if self.hide_anonymous and not favorite and not named:
+            return False
+        score = node_score(ann, info.note, self.query, info.alias)
+        if score is None:
+            return False
+        self._scores[ann.destination_hash] = score
+        return True
+
+    def set_hide_anonymous(self, hide: bool) -> None:
+        """Show or hide nodes that announce without a name, and remember it."""
+        self.hide_anonymous = hide
+        self._update_anonymous_button()
+        settings = {**self.tab_manager.settings, "hide_anonymous": hide}
+        self.tab_manager.settings = settings
+        get_storage_manager(self.page).save_app_settings(settings)
+        self.render()
+        self.page.update()
+
+    def set_aspect(self, aspect: str | None) -> None:
+        """Show only nodes announcing an aspect, or every node for None."""
+        self.aspect = aspect if aspect in ANNOUNCE_ASPECTS else None
+        self.limit = PAGE_SIZE
+        self._update_aspect_chips()
+        settings = {**self.tab_manager.settings, "sidebar_aspect": self.aspect or ""}
+        self.tab_manager.settings = settings
+        get_storage_manager(self.page).save_app_settings(settings)
+        self.render()
+        self.page.update()
+
+    def _update_aspect_chips(self) -> None:
+        choices = [(None, "All"), *ANNOUNCE_ASPECTS.items()]
+        self.aspect_chips.controls = [
+            ft.Chip(
+                label=ft.Text(label, size=12),
+                tooltip=aspect or "Every aspect",
+                selected=self.aspect == aspect,
+                show_checkmark=False,
+                on_select=lambda e, aspect=aspect: self.set_aspect(aspect),
+            )
+            for aspect, label in choices
+        ]
+
+    def _update_anonymous_button(self) -> None:
+        hide = self.hide_anonymous
+        self.anonymous_button.icon = (
+            ft.Icons.PERSON_OFF if hide else ft.Icons.PERSON_OUTLINE
+        )
+        self.anonymous_button.icon_color = ft.Colors.PRIMARY if hide else None
+        self.anonymous_button.tooltip = (
+            "Show anonymous nodes" if hide else "Hide anonymous nodes"
+        )
+
+    def toggle_favorite(self, ann) -> None:
+        """Pin a node to the favorites section, or unpin it."""
+        nodes = self.tab_manager.nodes
+        favorite = not nodes.is_favorite(ann.destination_hash)
+        nodes.set_favorite(ann.destination_hash, favorite, ann.display_name)
+        self.render()
+        self.page.update()
+
+    def copy_hash(self, ann) -> None:
+        """Put a node's full destination hash on the clipboard."""
+        self.page.set_clipboard(ann.destination_hash)
+        show_snack(self.page, "Node hash copied")
+
+    def favorite_announces(self) -> list:
+        """Return announces for the favorite nodes, heard this session or not."""
+        heard = {ann.destination_hash: ann for ann in self.announces}
+        return [
+            heard.get(destination_hash)
+            or Announce(destination_hash, info.name, 0)
+            for destination_hash, info in self.tab_manager.nodes.favorites()
+        ]
+
+    def group_announces(self, group: str) -> list:
+        """Return announces for a group's nodes that are not favorites."""
+        heard = {ann.destination_hash: ann for ann in self.announces}
+        return [
+            heard.get(destination_hash)
+            or Announce(destination_hash, info.name, 0)
+            for destination_hash, info in self.tab_manager.nodes.members(group)
+            if not info.favorite
+        ]
+
+    def added_announces(self) -> list:
+        """Return announces for ungrouped nodes added by hash and not yet heard."""
+        heard = {ann.destination_hash for ann in self.announces}
+        return [
+            Announce(destination_hash, info.name, 0)
+            for destination_hash, info in self.tab_manager.nodes.added()
+            if destination_hash not in heard
+            and not info.favorite
+            and info.group is None
+        ]
+
+    def open_add_node_dialog(self) -> None:
+        """Ask for the hash of a node to add to the sidebar."""
+        self.node_hash_field.value = ""
+        self.node_hash_field.error_text = None
+        self.node_name_field.value = ""
+        open_dialog(self.page, self.add_node_dialog)
+
+    def apply_add_node_dialog(self) -> None:
+        """Add the node entered in the dialog, if its hash is valid."""
+        try:
+            destination_hash = self.tab_manager.nodes.add_node(
+                self.node_hash_field.value or "",
+                self.node_name_field.value,
+            )
+        except ValueError as exc:
+            self.node_hash_field.error_text = str(exc)
+            self.page.update()
+            return
+        self.page.close(self.add_node_dialog)
+        if self.request_path_checkbox.value:
+            request_path(destination_hash)
+        self.render()
+        self.page.update()
+
+    def move_to_group(self, destination_hash: str, group: str | None) -> None:
+        """File a node under a group, or take it out of its group for None."""
+        heard = {ann.destination_hash: ann for ann in self.announces}
+        ann = heard.get(destination_hash)
+        self.tab_manager.nodes.set_group(
+            destination_hash,
+            group,
+            ann.display_name if ann else None,
+        )
+        self.render()
+        self.page.update()
+
+    def toggle_stale(self) -> None:
+        """Collapse or expand the section of stale nodes."""
+        self.stale_collapsed = not self.stale_collapsed
+        self.render()
+        self.page.update()
+
+    def toggle_group(self, group: str) -> None:
+        """Collapse or expand a group's section."""
+        nodes = self.tab_manager.nodes
+        info = nodes.group(group)
+        if info is not None:
+            nodes.set_collapsed(group, not info.collapsed)
+            self.render()
+            self.page.update()
+
+    def delete_group(self, group: str) -> None:
+        """Delete a group, moving its nodes back to the ungrouped list."""
+        self.tab_manager.nodes.remove_group(group)
+        self.render()
+        self.page.update()
+
+    def open_group_dialog(self, group: str | None = None) -> None:
+        """Ask for the name of a new group, or a new name for an existing one."""
+        self.renaming = group
+        self.group_dialog.title = ft.Text("Rename Group" if group else "New Group")
+        self.group_field.value = group or ""
+        self.group_field.error_text = None
+        open_dialog(self.page, self.group_dialog)
+
+    def apply_group_dialog(self) -> None:
+        """Create or rename the group named in the dialog, if the name is valid."""
+        nodes = self.tab_manager.nodes
+        name = self.group_field.value or ""
+        try:
+            if self.renaming:
+                nodes.rename_group(self.renaming, name)
+            else:
+                nodes.add_group(name)
+        except ValueError as exc:
+            self.group_field.error_text = str(exc)
+            self.page.update()
+            return
+        self.page.close(self.group_dialog)
+        self.render()
+        self.page.update()
+
+    def render(self) -> None:
+        """Rebuild the cards from the current announces."""
+        stats = self.tab_manager.history.node_stats()
+        nodes = self.tab_manager.nodes
+        settings = self.tab_manager.settings
+        hours = settings.get("stale_after_hours", 6)
+        stale_after = (hours if hours in STALE_AFTER_OPTIONS else 6) * 3600
+        mode = settings.get("stale_nodes", "section")
+        now = time.time()
+        aliases = nodes.aliases()
+        self._freshness = {
+            ann.destination_hash: freshness(ann, now, stale_after)
+            for ann in self.announces
+        }
+        self._scores = {}
+        favorites = [ann for ann in self.favorite_announces() if self._shown(ann, True)]
+        groups = nodes.groups()
+        rows: list[Callable[[], ft.Control]] = []
+        listed: set[tuple[str, bool]] = set()
+
+        def add_cards(announces, favorite=False):
+            announces = sort_announces(announces, self.sort_order, stats, aliases)
+            if self.query.strip():
+                announces.sort(key=lambda ann: -self._scores[ann.destination_hash])
+            listed.update((ann.destination_hash, favorite) for ann in announces)
+            rows.extend(
+                partial(
+                    self._card,
+                    ann,
+                    stats.get(ann.destination_hash),
+                    favorite,
+                )
+                for ann in announces
+            )
+
+        if favorites:
+            rows.append(partial(_section_header, "Favorites"))
+            add_cards(favorites, favorite=True)
+        for group in groups:
+            members = [
+                ann
+                for ann in self.group_announces(group.name)
+                if self._shown(ann)
+            ]
+            rows.append(partial(self._build_group_header, group, len(members)))
+            if not group.collapsed:
+                add_cards(members)
+        others = [
+            ann
+            for ann in self.announces
+            if not nodes.is_favorite(ann.destination_hash)
+            and nodes.get(ann.destination_hash).group is None
+            and self._shown(ann)
+        ]
+        others += [ann for ann in self.added_announces() if self._shown(ann)]
+        stale = []
+        if mode in ("section", "hide"):
+            stale = [
+                ann
+                for ann in others
+                if self._freshness.get(ann.destination_hash) == "stale"
+            ]
+            stale_hashes = {ann.destination_hash for ann in stale}
+            others = [
+                ann for ann in others if ann.destination_hash not in stale_hashes
+            ]
+        if groups or (favorites and others):
+            rows.append(
+                lambda: self._drop_target(_section_header("All nodes"), None),
+            )
+        add_cards(others)
+        if stale and mode == "section":
+            rows.append(partial(self._build_stale_header, len(stale)))
+            if not self.stale_collapsed:
+                add_cards(stale)
+        self._rows = rows
+        self._cards = {key: card for key, card in self._cards.items() if key in listed}
+        self.list_view.controls = [row() for row in rows[: self.limit]]
+        self._add_more_button()
+
+    def show_more(self) -> None:
+        """Build the next batch of entries below the ones already shown."""
+        controls = [c for c in self.list_view.controls if c is not self.more_button]
+        shown = len(controls)
+        if shown >= len(self._rows):
+            return
+        self.limit = max(self.limit, shown) + PAGE_SIZE
+        controls.extend(row() for row in self._rows[shown : self.limit])
+        self.list_view.controls = controls
+        self._add_more_button()
+        self.page.update()
+
+    def _add_more_button(self) -> None:
+        remaining = len(self._rows) - len(self.list_view.controls)
+        if remaining > 0:
+            self.more_button.text = f"Show {min(remaining, PAGE_SIZE)} more"
+            self.list_view.controls.append(self.more_button)
+
+    def _on_scroll(self, e) -> None:
+        if e.max_scroll_extent is None or e.pixels is None:
+            return
+        if e.max_scroll_extent - e.pixels < SCROLL_AHEAD:
+            self.show_more()
+
+    def navigable_rows(self) -> list[ft.Container]:
+        """Return the shown cards and headers that open or toggle something."""
+        rows = []
+        for control in self.list_view.controls:
+            if isinstance(control, (ft.Draggable, ft.DragTarget)):
+                control = control.content
+            if isinstance(control, ft.Container) and control.on_click is not None:
+                rows.append(control)
+        return rows
+
+    def move_highlight(self, step: int) -> bool:
+        """Outline the row step rows below the outlined one, or above it.
+
+        Without an outlined row, the first row is outlined, or the last one
+        for a negative step. Passing the last row shows the next batch.
+
+        Returns:
+            bool: False when the list has no rows.
+
+        """
+        rows = self.navigable_rows()
+        index = next(
+            (i for i, row in enumerate(rows) if row is self.highlighted),
+            None,
+        )
+        if index is None:
+            target = 0 if step >= 0 else len(rows) - 1
+        else:
+            target = index + step
+        if target >= len(rows) and len(self._rows) > len(rows):
+            self.show_more()
+            rows = self.navigable_rows()
+        if not rows:
+            return False
+        self._set_highlight(rows[max(0, min(target, len(rows) - 1))])
+        return True
+
+    def activate_highlight(self) -> bool:
+        """Open the outlined card or toggle the outlined header.
+
+        Returns:
+            bool: False when no row is outlined.
+
+        """
+        row = self.highlighted
+        if row is None or row not in self.navigable_rows():
+            return False
+        row.on_click(None)
+        return True
+
+    def clear_highlight(self) -> None:
+        """Remove the keyboard outline."""
+        self._set_highlight(None)
+
+    def _set_highlight(self, row: ft.Container | None) -> None:
+        if self.highlighted is not None:
+            self.highlighted.border = self._highlight_border
+        self.highlighted = row
+        if row is None:
+            return
+        self._highlight_border = row.border
+        row.border = ft.border.all(2, ft.Colors.PRIMARY)
+        if row.key is None:
+            row.key = f"row-{id(row)}"
+        self.list_view.scroll_to(key=row.key, duration=150)
+
+    def open_node(self, ann) -> None:
+        """Open a node's index page in a new tab, closing the sidebar drawer."""
+        sidebar = getattr(self.tab_manager, "sidebar", None)
+        if sidebar is not None:
+            sidebar.set_drawer_open(False)
+        name = self.tab_manager.nodes.display_name(
+            ann.destination_hash,
+            ann.display_name,
+        )
+        title = name or "Anonymous"
+        full_url = f"{ann.destination_hash}:/page/index.mu"
+        placeholder = render_plaintext(f"Fetching content for {full_url}")
+        self.tab_manager._add_tab_internal(title, placeholder)
+        idx = len(self.tab_manager.manager.tabs) - 1
+        tab = self.tab_manager.manager.tabs[idx]
+        tab["url_field"].value = full_url
+        self.tab_manager.select_tab(idx)
+        self.tab_manager._on_tab_go(None, idx)
+
+    def _card(self, ann, stats, favorite: bool) -> ft.Control:
+        # Reuses the card built last time when nothing it shows has changed,
+        # so Flet only sends the cards that did
+        key = (ann.destination_hash, favorite)
+        signature = (
+            ann,
+            self.tab_manager.nodes.display_name(
+                ann.destination_hash,
+                ann.display_name,
+            ),
+            visit_summary(stats),
+            self._freshness.get(ann.destination_hash),
+        )
+        cached = self._cards.get(key)
+        if cached is not None and cached[0] == signature:
+            return cached[1]
+        card = self._build_card(ann, stats, favorite)
+        self._cards[key] = (signature, card)
+        return card
+
+    def _build_card(self, ann, stats, favorite: bool) -> ft.Control:
+        name = self.tab_manager.nodes.display_name(
+            ann.destination_hash,
+            ann.display_name,
+        )
+        label = ft.Text(
+            name or ann.destination_hash,
+            size=14,
+            weight=ft.FontWeight.W_500,
+            overflow=ft.TextOverflow.ELLIPSIS,
+            semantics_label=node_label(name, ann.destination_hash),
+        )
+        summary = visit_summary(stats)
+        fresh = self._freshness.get(ann.destination_hash)
+        lines: list[ft.Control] = [label]
+        if summary:
+            lines.append(
+                ft.Text(summary, size=11, color=ft.Colors.ON_SURFACE_VARIANT),
+            )
+        card = ft.Container(
+            content=ft.Row(
+                controls=[
+                    build_node_avatar(ann.destination_hash, size=24),
+                    ft.Column(spacing=2, expand=True, controls=lines),
+                    ft.IconButton(
+                        ft.Icons.INFO_OUTLINE,
+                        icon_size=18,
+                        tooltip="Node details",
+                        on_click=lambda e: self.details.show(ann),
+                    ),
+                    ft.IconButton(
+                        ft.Icons.CONTENT_COPY,
+                        icon_size=16,
+                        tooltip="Copy node hash",
+                        on_click=lambda e: self.copy_hash(ann),
+                    ),
+                    ft.IconButton(
+                        ft.Icons.STAR if favorite else ft.Icons.STAR_BORDER,
+                        icon_size=18,
+                        icon_color=ft.Colors.AMBER_400 if favorite else None,
+                        tooltip=(
+                            "Remove from favorites" if favorite else "Add to favorites"
+                        ),
+                        on_click=lambda e: self.toggle_favorite(ann),
+                    ),
+                ],
+                spacing=12,
+            ),
+            padding=ft.padding.all(12),
+            border_radius=8,
+            border=ft.border.only(
+                left=ft.BorderSide(
+                    3,
+                    FRESHNESS_COLORS.get(fresh, ft.Colors.SURFACE_CONTAINER_HIGHEST),
+                ),
+            ),
+            tooltip={
+                "fresh": "Announced recently",
+                "aging": "Not announced for a while",
+                "stale": "Stale, not announced for a long time",
+            }.get(fresh),
+            bgcolor=ft.Colors.SURFACE_CONTAINER_HIGHEST,
+            ink=True,
+            on_click=lambda e: self.open_node(ann),
+        )
+        return ft.Draggable(
+            group=DRAG_GROUP,
+            data=ann.destination_hash,
+            content=card,
+            content_feedback=ft.Container(
+                content=ft.Text(name or ann.destination_hash, size=14),
+                padding=ft.padding.all(12),
+                border_radius=8,
+                opacity=0.8,
+                bgcolor=ft.Colors.PRIMARY_CONTAINER,
+            ),
+        )
+
+    def _build_group_header(self, group, count: int) -> ft.Control:
+        name = group.name
+        header = ft.Container(
+            content=ft.Row(
+                controls=[
+                    ft.Icon(
+                        ft.Icons.CHEVRON_RIGHT
+                        if group.collapsed
+                        else ft.Icons.EXPAND_MORE,
+                        size=16,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                    ),
+                    ft.Text(
+                        f"{name} ({count})",
+                        size=12,
+                        weight=ft.FontWeight.BOLD,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                        expand=True,
+                        overflow=ft.TextOverflow.ELLIPSIS,
+                    ),
+                    ft.PopupMenuButton(
+                        icon=ft.Icons.MORE_HORIZ,
+                        icon_size=16,
+                        tooltip="Group options",
+                        items=[
+                            ft.PopupMenuItem(
+                                text="Rename…",
+                                on_click=lambda e: self.open_group_dialog(name),
+                            ),
+                            ft.PopupMenuItem(
+                                text="Delete group",
+                                on_click=lambda e: self.delete_group(name),
+                            ),
+                        ],
+                    ),
+                ],
+                spacing=4,
+            ),
+            padding=ft.padding.only(left=4, top=8),
+            ink=True,
+            on_click=lambda e: self.toggle_group(name),
+        )
+        return self._drop_target(header, name)
+
+    def _build_stale_header(self, count: int) -> ft.Control:
+        return ft.Container(
+            content=ft.Row(
+                controls=[
+                    ft.Icon(
+                        ft.Icons.CHEVRON_RIGHT
+                        if self.stale_collapsed
+                        else ft.Icons.EXPAND_MORE,
+                        size=16,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                    ),
+                    ft.Text(
+                        f"Stale ({count})",
+                        size=12,
+                        weight=ft.FontWeight.BOLD,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                        expand=True,
+                    ),
+                ],
+                spacing=4,
+            ),
+            padding=ft.padding.only(left=4, top=8),
+            ink=True,
+            on_click=lambda e: self.toggle_stale(),
+        )
+
+    def _drop_target(self, content: ft.Control, group: str | None) -> ft.Control:
+        def accept(e):
+            source = e.page.get_control(e.src_id)
+            if source is not None and source.data:
+                self.move_to_group(source.data, group)
+
+        return ft.DragTarget(group=DRAG_GROUP, on_accept=accept, content=content)
+
+
+def _section_header(text: str) -> ft.Control:
+    return ft.Container(
+        content=ft.Text(
+            text,
+            size=12,
+            weight=ft.FontWeight.BOLD,
+            color=ft.Colors.ON_SURFACE_VARIANT,
+        ),
+        padding=ft.padding.only(left=4, top=8),
+    )
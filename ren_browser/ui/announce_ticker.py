@@ -0,0 +1,205 @@
+"""Live announce ticker for Ren Browser.
+
+Lists announces as they arrive, newest first, with their time and aspect.
+The list can be paused while reading and filtered by name, hash or aspect.
+With reduced motion it is not redrawn for every announce, only when asked.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+
+MAX_ROWS = 200
+
+
+def announce_matches(ann, query: str) -> bool:
+    """Return whether an announce matches the ticker filter."""
+    query = query.strip().casefold()
+    if not query:
+        return True
+    fields = (ann.display_name, ann.destination_hash, ann.aspect)
+    return any(query in field.casefold() for field in fields if field)
+
+
+class AnnounceTicker:
+    """The ``about:announces`` page."""
+
+    def __init__(
+        self,
+        service,
+        on_open: Callable[[str], None],
+        on_change: Callable[[], None],
+    ):
+        """Initialize the ticker.
+
+        Args:
+            service: AnnounceService whose announces are shown.
+            on_open: Called with a node's index address when a row is clicked.
+            on_change: Called after the page has been redrawn so it can be updated.
+
+        """
+        self.service = service
+        self.on_open = on_open
+        self.on_change = on_change
+        self.paused = False
+        self.live = True
+        self.query = ""
+        self.shown = 0
+        self.cleared = 0
+        self.pause_button = ft.OutlinedButton(on_click=lambda e: self.toggle_pause())
+        self.filter_field = ft.TextField(
+            hint_text="Filter by name, hash or aspect",
+            prefix_icon=ft.Icons.FILTER_LIST,
+            dense=True,
+            expand=True,
+            on_change=lambda e: self.set_query(e.control.value),
+        )
+        self.status = ft.Text(size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+        self.rows = ft.Column(spacing=2)
+        self.control = ft.Column(
+            spacing=8,
+            controls=[
+                ft.Row(
+                    controls=[
+                        ft.Text(
+                            "Announces",
+                            size=24,
+                            weight=ft.FontWeight.BOLD,
+                            color=ft.Colors.PRIMARY,
+                            expand=True,
+                        ),
+                        self.pause_button,
+                        ft.OutlinedButton(
+                            "Clear",
+                            icon=ft.Icons.CLEAR_ALL,
+                            on_click=lambda e: self.clear(),
+                        ),
+                    ],
+                ),
+                ft.Row(controls=[self.filter_field, self.status]),
+                self.rows,
+            ],
+        )
+        self.render()
+
+    def attach(self) -> None:
+        """Start receiving announces."""
+        self.service.add_listener(self.on_announce)
+
+    def detach(self) -> None:
+        """Stop receiving announces."""
+        self.service.remove_listener(self.on_announce)
+
+    def on_announce(self, _ann) -> None:
+        """Show a newly received announce, unless paused."""
+        if not self.live:
+            return
+        if self.paused:
+            self._update_status()
+        else:
+            self.render()
+        self.on_change()
+
+    def set_live(self, live: bool) -> None:
+        """Redraw for every announce, or only when the button is clicked."""
+        self.live = live
+        self.render()
+
+    def toggle_pause(self) -> None:
+        """Pause or resume the ticker, or show new announces when not live."""
+        if self.live:
+            self.paused = not self.paused
+        self.render()
+        self.on_change()
+
+    def set_query(self, query: str) -> None:
+        """Show only announces matching a filter."""
+        self.query = query or ""
+        self.render()
+        self.on_change()
+
+    def clear(self) -> None:
+        """Hide the announces received so far."""
+        self.cleared = len(self.service.log)
+        self.render()
+        self.on_change()
+
+    def _received(self) -> list:
+        log = list(self.service.log)
+        return log[min(self.cleared, len(log)) :]
+
+    def _update_status(self) -> None:
+        waiting = len(self._received()) - self.shown
+        if not self.live:
+            self.status.value = "Not updated live"
+        elif self.paused and waiting > 0:
+            self.status.value = f"Paused, {waiting} new"
+        elif self.paused:
+            self.status.value = "Paused"
+        else:
+            self.status.value = "Live"
+
+    def render(self) -> None:
+        """Rebuild the rows from the announces received so far."""
+        received = self._received()
+        if not self.paused:
+            self.shown = len(received)
+        visible = [
+            ann
+            for ann in reversed(received[: self.shown])
+            if announce_matches(ann, self.query)
+        ][:MAX_ROWS]
+        if not self.live:
+            self.pause_button.text = "Show new"
+            self.pause_button.icon = ft.Icons.REFRESH
+        else:
+            self.pause_button.text = "Resume" if self.paused else "Pause"
+            self.pause_button.icon = (
+                ft.Icons.PLAY_ARROW if self.paused else ft.Icons.PAUSE
+            )
+        self._update_status()
+        if not visible:
+            self.rows.controls = [
+                ft.Text(
+                    "Waiting for announces…"
+                    if not received
+                    else "No announces match the filter.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ]
+            return
+        self.rows.controls = [self._build_row(ann) for ann in visible]
+
+    def _build_row(self, ann) -> ft.Control:
+        heard = datetime.fromtimestamp(ann.timestamp).strftime("%H:%M:%S")
+        address = f"{ann.destination_hash}:/page/index.mu"
+        return ft.Container(
+            content=ft.Row(
+                controls=[
+                    ft.Text(heard, size=12, font_family="monospace", width=64),
+                    build_node_avatar(ann.destination_hash, size=18),
+                    ft.Text(
+                        ann.display_name or "Anonymous",
+                        size=13,
+                        expand=True,
+                        overflow=ft.TextOverflow.ELLIPSIS,
+                    ),
+                    ft.Text(
+                        ann.destination_hash[:8],
+                        size=12,
+                        font_family="monospace",
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                    ),
+                    ft.Text(ann.aspect, size=12, color=ft.Colors.BLUE_200),
+                ],
+                spacing=12,
+            ),
+            padding=ft.padding.symmetric(horizontal=12, vertical=4),
+            border_radius=6,
+            ink=True,
+            on_click=lambda e: self.on_open(address),
+        )
@@ -0,0 +1,98 @@
+"""Auto-refresh menu for Ren Browser.
+
+Address bar button choosing how often the active tab re-fetches its page,
+with a dialog for intervals other than the presets.
+"""
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.tabs.auto_refresh import (
+    REFRESH_INTERVALS,
+    interval_label,
+    parse_interval,
+)
+
+
+class AutoRefreshMenu:
+    """Menu setting the active tab's auto-refresh interval."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the menu.
+
+        Args:
+            page: Flet page the custom interval dialog is shown on.
+            tab_manager: Tab manager whose active tab is refreshed.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.button = ft.PopupMenuButton(
+            icon=ft.Icons.AUTORENEW,
+            tooltip="Auto refresh: Off",
+            icon_color=ft.Colors.ON_SURFACE,
+        )
+        self.interval_field = ft.TextField(
+            label="Refresh every",
+            hint_text="e.g. 90, 45s or 10m",
+            autofocus=True,
+            on_submit=lambda e: self.apply_custom(),
+        )
+        self.dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text("Custom Refresh Interval"),
+            content=self.interval_field,
+            actions=[
+                ft.TextButton("Cancel", on_click=lambda e: self.page.close(self.dialog)),
+                ft.FilledButton("Set", on_click=lambda e: self.apply_custom()),
+            ],
+        )
+        self.refresh()
+
+    def _active_interval(self) -> int:
+        manager = self.tab_manager.manager
+        return manager.tabs[manager.index].get("refresh_interval", 0)
+
+    def refresh(self) -> None:
+        """Show the active tab's interval on the button and in the menu."""
+        current = self._active_interval()
+        items = [
+            ft.PopupMenuItem(
+                text=label,
+                checked=seconds == current,
+                on_click=lambda e, seconds=seconds: self.choose(seconds),
+            )
+            for seconds, label in REFRESH_INTERVALS.items()
+        ]
+        items.append(
+            ft.PopupMenuItem(
+                text="Custom…",
+                checked=current not in REFRESH_INTERVALS,
+                on_click=lambda e: self.open_custom(),
+            ),
+        )
+        self.button.items = items
+        self.button.icon_color = ft.Colors.PRIMARY if current else ft.Colors.ON_SURFACE
+        self.button.tooltip = f"Auto refresh: {interval_label(current)}"
+
+    def choose(self, seconds: int) -> None:
+        """Set the active tab's interval."""
+        self.tab_manager.set_auto_refresh(self.tab_manager.manager.index, seconds)
+
+    def open_custom(self) -> None:
+        """Ask for an interval that is not one of the presets."""
+        current = self._active_interval()
+        self.interval_field.value = str(current) if current else ""
+        self.interval_field.error_text = None
+        open_dialog(self.page, self.dialog)
+
+    def apply_custom(self) -> None:
+        """Set the interval typed into the dialog, if it is valid."""
+        try:
+            seconds = parse_interval(self.interval_field.value or "")
+        except ValueError as exc:
+            self.interval_field.error_text = str(exc)
+            self.page.update()
+            return
+        self.page.close(self.dialog)
+        self.choose(seconds)
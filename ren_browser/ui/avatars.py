@@ -0,0 +1,72 @@
+"""Node avatars for Ren Browser.
+
+Generates small deterministic colored badges from destination hashes so
+nodes can be told apart at a glance in tabs and the announce list.
+"""
+
+import colorsys
+import hashlib
+
+import flet as ft
+
+
+def node_color(destination_hash: str) -> str:
+    """Return a deterministic hex color for a destination hash.
+
+    Args:
+        destination_hash: Hex destination hash of the node.
+
+    Returns:
+        Color string in ``#rrggbb`` form.
+
+    """
+    digest = hashlib.sha256(destination_hash.lower().encode("utf-8")).digest()
+    hue = int.from_bytes(digest[:2], "big") / 0xFFFF
+    saturation = 0.45 + (digest[2] / 255) * 0.3
+    red, green, blue = colorsys.hls_to_rgb(hue, 0.5, saturation)
+    return f"#{int(red * 255):02x}{int(green * 255):02x}{int(blue * 255):02x}"
+
+
+def build_node_avatar(destination_hash: str | None, size: int = 20) -> ft.Container:
+    """Build a circular avatar for a node.
+
+    Args:
+        destination_hash: Hex destination hash, or None for a hidden avatar.
+        size: Diameter of the avatar in pixels.
+
+    Returns:
+        ft.Container: The avatar control.
+
+    """
+    avatar = ft.Container(
+        width=size,
+        height=size,
+        border_radius=size / 2,
+        alignment=ft.alignment.center,
+        content=ft.Text(
+            "",
+            size=size * 0.45,
+            weight=ft.FontWeight.BOLD,
+            color=ft.Colors.WHITE,
+        ),
+    )
+    update_node_avatar(avatar, destination_hash)
+    return avatar
+
+
+def update_node_avatar(avatar: ft.Container, destination_hash: str | None) -> None:
+    """Point an existing avatar at a different node.
+
+    Args:
+        avatar: Avatar previously created with build_node_avatar.
+        destination_hash: Hex destination hash, or None to hide the avatar.
+
+    """
+    if not destination_hash:
+        avatar.visible = False
+        avatar.tooltip = None
+        return
+    avatar.visible = True
+    avatar.bgcolor = node_color(destination_hash)
+    avatar.tooltip = destination_hash
+    avatar.content.value = destination_hash[:2].upper()
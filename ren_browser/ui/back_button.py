@@ -0,0 +1,61 @@
+"""Back button handling for Ren Browser.
+
+On Android the system back button or gesture pops the app's only view,
+which closed the browser at once. The view now refuses the pop and the
+back action steps through the browser instead: it closes the tab switcher,
+the sidebar drawer or fullscreen, then goes back in the tab's history,
+then closes the tab. Only with a single tab and nowhere to go back to is
+the view popped and the app left.
+"""
+
+import flet as ft
+
+
+class BackNavigation:
+    """Maps the system back action to browser navigation."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Intercept back on the page's root view.
+
+        Args:
+            page: Flet page whose root view is guarded.
+            tab_manager: Tab manager that is navigated.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.view = page.views[0]
+        self.view.can_pop = False
+        self.view.on_confirm_pop = self.on_confirm_pop
+
+    def on_confirm_pop(self, e) -> None:  # type: ignore
+        """Handle a back action, letting the view pop if nothing else did."""
+        handled = self.back()
+        self.view.confirm_pop(not handled)
+        if handled:
+            self.page.update()
+
+    def back(self) -> bool:
+        """Take one step back in the browser.
+
+        Returns:
+            bool: False when there is nothing left to go back from.
+
+        """
+        tab_manager = self.tab_manager
+        sidebar = tab_manager.sidebar
+        if tab_manager.tab_switcher.is_open:
+            tab_manager.tab_switcher.panel.visible = False
+        elif sidebar is not None and sidebar.drawer_open:
+            sidebar.set_drawer_open(False)
+        elif tab_manager.fullscreen:
+            tab_manager.set_fullscreen(False)
+        else:
+            idx = tab_manager.manager.index
+            if tab_manager.manager.tabs[idx].get("back"):
+                tab_manager.go_back(idx)
+            elif len(tab_manager.manager.tabs) > 1:
+                tab_manager.close_tab(idx)
+            else:
+                return False
+        return True
@@ -0,0 +1,296 @@
+"""Bookmarks page for Ren Browser.
+
+Lists bookmarks grouped by folder, filters them by tag and lets them be
+moved between folders, deleted, opened, imported or exported.
+"""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.bookmarks.bookmarks import UNSORTED, folder_label
+from ren_browser.bookmarks.exchange import (
+    BookmarkFormatError,
+    parse_bookmarks,
+    to_json,
+    to_netscape_html,
+)
+from ren_browser.ui.avatars import build_node_avatar
+from ren_browser.ui.notify import show_snack
+
+EXPORT_FORMATS = {
+    "json": ("bookmarks.json", to_json),
+    "html": ("bookmarks.html", to_netscape_html),
+}
+
+
+class BookmarkFiles:
+    """Native file dialogs for importing and exporting bookmarks."""
+
+    def __init__(self, page: ft.Page, bookmarks, on_change: Callable[[], None]):
+        """Initialize the file dialogs.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            bookmarks: BookmarkManager that is imported into or exported.
+            on_change: Called after bookmarks were imported.
+
+        """
+        self.page = page
+        self.bookmarks = bookmarks
+        self.on_change = on_change
+        self.export_format: str | None = None
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+
+    def choose_export(self, fmt: str) -> None:
+        """Ask where to export the bookmarks in a format ("json" or "html")."""
+        file_name, _ = EXPORT_FORMATS[fmt]
+        self.export_format = fmt
+        self.picker.save_file(
+            dialog_title="Export Bookmarks",
+            file_name=file_name,
+            allowed_extensions=[fmt],
+        )
+
+    def choose_import(self) -> None:
+        """Ask for a JSON or Netscape HTML bookmarks file to import."""
+        self.export_format = None
+        self.picker.pick_files(
+            dialog_title="Import Bookmarks",
+            allowed_extensions=["json", "html", "htm"],
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        fmt, self.export_format = self.export_format, None
+        if fmt is not None and e.path:
+            self.export_to(Path(e.path), fmt)
+        elif fmt is None and e.files:
+            self.import_from(Path(e.files[0].path))
+
+    def export_to(self, path: Path, fmt: str) -> bool:
+        """Write all bookmarks to a file and report the outcome."""
+        _, serialize = EXPORT_FORMATS[fmt]
+        try:
+            path.write_text(serialize(self.bookmarks.bookmarks()), encoding="utf-8")
+        except OSError as exc:
+            self._notify(f"Failed to export bookmarks: {exc}", success=False)
+            return False
+        self._notify(f"Exported {len(self.bookmarks.bookmarks())} bookmarks to {path}")
+        return True
+
+    def import_from(self, path: Path) -> int:
+        """Add the bookmarks from a file and report how many were new.
+
+        Returns:
+            int: Number of bookmarks added.
+
+        """
+        try:
+            imported = parse_bookmarks(path.read_text(encoding="utf-8"))
+        except (OSError, UnicodeDecodeError, BookmarkFormatError) as exc:
+            self._notify(f"Failed to import bookmarks: {exc}", success=False)
+            return 0
+        added = self.bookmarks.merge(imported)
+        skipped = len(imported) - added
+        message = f"Imported {added} bookmarks"
+        if skipped:
+            message += f" ({skipped} already saved)"
+        self._notify(message)
+        self.on_change()
+        return added
+
+    def _notify(self, message: str, success: bool = True) -> None:
+        show_snack(self.page, message, success)
+
+
+def build_bookmarks_page(
+    bookmarks,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+    files: BookmarkFiles | None = None,
+) -> ft.Control:
+    """Build the ``about:bookmarks`` page.
+
+    Args:
+        bookmarks: BookmarkManager whose bookmarks are listed.
+        on_open: Called with an address when a bookmark is clicked.
+        on_change: Called after the page has been redrawn so it can be updated.
+        files: File dialogs for the import and export buttons, if available.
+
+    Returns:
+        ft.Control: The bookmarks page.
+
+    """
+    page = ft.Column(spacing=4)
+    state = {"tag": None}
+
+    def set_tag(tag):
+        state["tag"] = tag
+        render()
+        on_change()
+
+    def move(address, folder):
+        bookmarks.move(address, folder)
+        render()
+        on_change()
+
+    def remove(address):
+        bookmarks.remove(address)
+        render()
+        on_change()
+
+    def render():
+        tag = state["tag"]
+        if tag is not None and tag not in bookmarks.tags():
+            tag = state["tag"] = None
+        header: list[ft.Control] = [
+            ft.Text(
+                "Bookmarks",
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+                expand=True,
+            ),
+        ]
+        if files is not None:
+            header.extend(
+                [
+                    ft.TextButton(
+                        "Import",
+                        icon=ft.Icons.FILE_UPLOAD,
+                        on_click=lambda e: files.choose_import(),
+                    ),
+                    ft.TextButton(
+                        "Export JSON",
+                        icon=ft.Icons.FILE_DOWNLOAD,
+                        on_click=lambda e: files.choose_export("json"),
+                    ),
+                    ft.TextButton(
+                        "Export HTML",
+                        icon=ft.Icons.FILE_DOWNLOAD,
+                        on_click=lambda e: files.choose_export("html"),
+                    ),
+                ],
+            )
+        controls: list[ft.Control] = [ft.Row(controls=header)]
+        if bookmarks.tags():
+            controls.append(
+                ft.Row(
+                    wrap=True,
+                    spacing=6,
+                    controls=[
+                        _build_tag_chip("All", tag is None, lambda e: set_tag(None)),
+                        *(
+                            _build_tag_chip(
+                                name,
+                                name == tag,
+                                lambda e, name=name: set_tag(name),
+                            )
+                            for name in bookmarks.tags()
+                        ),
+                    ],
+                ),
+            )
+        groups = bookmarks.by_folder(tag)
+        if not groups:
+            controls.append(
+                ft.Text(
+                    "Press Ctrl+D on a page to bookmark it.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        folders = [UNSORTED, *bookmarks.folders()]
+        for folder, folder_bookmarks in groups:
+            controls.append(
+                ft.Container(
+                    content=ft.Row(
+                        controls=[
+                            ft.Icon(ft.Icons.FOLDER, size=18),
+                            ft.Text(
+                                folder_label(folder),
+                                size=16,
+                                weight=ft.FontWeight.BOLD,
+                            ),
+                        ],
+                        spacing=8,
+                    ),
+                    padding=ft.padding.only(top=12),
+                ),
+            )
+            controls.extend(
+                _build_entry(bookmark, folders, on_open, move, remove)
+                for bookmark in folder_bookmarks
+            )
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _build_tag_chip(label: str, selected: bool, on_click) -> ft.Control:
+    return ft.Container(
+        content=ft.Text(label, size=12),
+        padding=ft.padding.symmetric(horizontal=10, vertical=4),
+        border_radius=12,
+        bgcolor=ft.Colors.BLUE_700 if selected else ft.Colors.SURFACE_CONTAINER_HIGHEST,
+        ink=True,
+        on_click=on_click,
+    )
+
+
+def _build_entry(bookmark, folders, on_open, on_move, on_remove) -> ft.Control:
+    address = bookmark.address
+    details = address
+    if bookmark.tags:
+        details = f"{address}  ·  {', '.join(bookmark.tags)}"
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                build_node_avatar(address.split(":", 1)[0], size=20),
+                ft.Container(
+                    expand=True,
+                    ink=True,
+                    on_click=lambda e: on_open(address),
+                    content=ft.Column(
+                        spacing=2,
+                        controls=[
+                            ft.Text(
+                                bookmark.title,
+                                size=14,
+                                weight=ft.FontWeight.W_500,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                            ft.Text(
+                                details,
+                                size=12,
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                        ],
+                    ),
+                ),
+                ft.Dropdown(
+                    value=bookmark.folder,
+                    width=160,
+                    dense=True,
+                    options=[
+                        ft.dropdown.Option(folder, folder_label(folder))
+                        for folder in folders
+                    ],
+                    on_change=lambda e: on_move(address, e.control.value or UNSORTED),
+                ),
+                ft.IconButton(
+                    ft.Icons.DELETE_OUTLINE,
+                    tooltip="Delete bookmark",
+                    icon_size=18,
+                    on_click=lambda e: on_remove(address),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
@@ -0,0 +1,134 @@
+"""Cache inspector for Ren Browser.
+
+Lists the pages held in the page cache with their size, age and how often
+they were served from it, along with the share of page loads the cache
+answered, and lets single pages be forgotten.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+from ren_browser.ui.downloads import format_size
+
+
+def format_age(seconds: float) -> str:
+    """Describe an age in seconds in the largest whole unit."""
+    seconds = max(int(seconds), 0)
+    for unit, length in (("d", 86400), ("h", 3600), ("min", 60)):
+        if seconds >= length:
+            return f"{seconds // length} {unit}"
+    return f"{seconds} s"
+
+
+def _summary(cache) -> str:
+    pages = len(cache)
+    summary = f"{pages} page{'s' if pages != 1 else ''}, {format_size(cache.size())}"
+    ratio = cache.hit_ratio()
+    if ratio is not None:
+        summary += (
+            f"  ·  {ratio:.0%} served from the cache "
+            f"({cache.hits} hits, {cache.misses} misses)"
+        )
+    if cache.disk is not None:
+        summary += f"  ·  {len(cache.disk)} on disk, {format_size(cache.disk.size())}"
+    return summary
+
+
+def build_cache_page(
+    cache,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:cache`` page.
+
+    Args:
+        cache: PageCache whose pages are listed.
+        on_open: Called with an address when a cached page is clicked.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The cache inspector page.
+
+    """
+    page = ft.Column(spacing=4)
+
+    def remove(address):
+        cache.remove(address)
+        render()
+        on_change()
+
+    def render():
+        controls: list[ft.Control] = [
+            ft.Text(
+                "Page Cache",
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+            ft.Text(_summary(cache), size=14, color=ft.Colors.ON_SURFACE_VARIANT),
+        ]
+        entries = cache.entries()
+        if not entries:
+            controls.append(
+                ft.Text(
+                    "No pages are cached in memory.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.extend(
+            _build_item(address, entry, cache.max_age, on_open, remove)
+            for address, entry in entries
+        )
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _build_item(address, entry, max_age, on_open, on_remove) -> ft.Control:
+    age = entry.age()
+    details = (
+        f"{format_size(entry.size)}  ·  {format_age(age)} old"
+        f"{' (expired)' if age > max_age else ''}"
+        f"  ·  {entry.hits} hit{'s' if entry.hits != 1 else ''}"
+    )
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                build_node_avatar(address.split(":", 1)[0], size=20),
+                ft.Container(
+                    expand=True,
+                    ink=True,
+                    on_click=lambda e: on_open(address),
+                    content=ft.Column(
+                        spacing=2,
+                        controls=[
+                            ft.Text(
+                                address,
+                                size=14,
+                                weight=ft.FontWeight.W_500,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                            ft.Text(
+                                details,
+                                size=12,
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                            ),
+                        ],
+                    ),
+                ),
+                ft.IconButton(
+                    ft.Icons.DELETE_OUTLINE,
+                    tooltip="Remove from cache",
+                    icon_size=18,
+                    on_click=lambda e: on_remove(address),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
@@ -0,0 +1,163 @@
+"""What changed view for Ren Browser.
+
+A banner above a page that changed since it was last fetched offers to
+show what changed, and the view it switches to lists the readable text
+of the page with the added lines highlighted and the removed ones struck
+through. Long runs of unchanged lines are folded so the changes stand out.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.i18n.i18n import ntr, tr
+from ren_browser.pages.revisions import ADDED, REMOVED, SAME
+from ren_browser.renderer.plaintext import TEXT_SIZE, scaled_size
+from ren_browser.ui.cache import format_age
+
+# Unchanged lines kept around each change, the rest are folded
+CONTEXT_LINES = 3
+
+
+def build_changes_banner(
+    age: float,
+    showing: bool,
+    on_toggle: Callable[[], None],
+) -> ft.Control:
+    """Build the banner above a page that changed since it was last fetched.
+
+    Args:
+        age: Seconds since the earlier version was fetched.
+        showing: Whether the changes are shown instead of the page.
+        on_toggle: Called to switch between the page and its changes.
+
+    Returns:
+        ft.Control: The banner.
+
+    """
+    return ft.Container(
+        padding=ft.padding.symmetric(horizontal=16, vertical=4),
+        bgcolor=ft.Colors.SECONDARY_CONTAINER,
+        content=ft.Row(
+            spacing=8,
+            controls=[
+                ft.Icon(ft.Icons.DIFFERENCE_OUTLINED, size=18),
+                ft.Text(
+                    tr(
+                        "This page changed since it was fetched {age} ago.",
+                        age=format_age(age),
+                    ),
+                    size=13,
+                    expand=True,
+                ),
+                ft.Switch(
+                    label=tr("What changed"),
+                    value=showing,
+                    on_change=lambda e: on_toggle(),
+                ),
+            ],
+        ),
+    )
+
+
+def build_diff_view(
+    lines: list[tuple[str, str]],
+    font_family: str | None = None,
+    min_size: float = 0,
+) -> ft.Control:
+    """Build the view of what changed on a page.
+
+    Args:
+        lines: ``(kind, line)`` pairs as returned by diff_lines.
+        font_family: Font the page text is shown in, monospace if None.
+        min_size: Smallest text size.
+
+    Returns:
+        ft.Control: The changes, line by line.
+
+    """
+    size = scaled_size(TEXT_SIZE, 1.0, min_size)
+    near = _near_changes(lines)
+    controls: list[ft.Control] = []
+    folded = 0
+    for index, (kind, line) in enumerate(lines):
+        if index not in near:
+            folded += 1
+            continue
+        if folded:
+            controls.append(_folded(folded))
+            folded = 0
+        controls.append(_diff_line(kind, line, font_family, size))
+    if folded:
+        controls.append(_folded(folded))
+    if not any(kind != SAME for kind, _ in lines):
+        controls.insert(
+            0,
+            ft.Text(
+                tr("The readable text of the page did not change."),
+                size=13,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+        )
+    return ft.Column(spacing=0, controls=controls)
+
+
+def _near_changes(lines: list[tuple[str, str]]) -> set[int]:
+    near: set[int] = set()
+    for index, (kind, _) in enumerate(lines):
+        if kind != SAME:
+            near.update(range(index - CONTEXT_LINES, index + CONTEXT_LINES + 1))
+    return near
+
+
+def _folded(count: int) -> ft.Control:
+    return ft.Container(
+        padding=ft.padding.symmetric(vertical=4),
+        content=ft.Text(
+            ntr("1 unchanged line", "{count} unchanged lines", count),
+            size=12,
+            italic=True,
+            color=ft.Colors.ON_SURFACE_VARIANT,
+        ),
+    )
+
+
+def _diff_line(
+    kind: str,
+    line: str,
+    font_family: str | None,
+    size: float | None,
+) -> ft.Control:
+    marker, bgcolor, style = {
+        ADDED: ("+", ft.Colors.with_opacity(0.25, ft.Colors.GREEN), None),
+        REMOVED: (
+            "-",
+            ft.Colors.with_opacity(0.25, ft.Colors.RED),
+            ft.TextStyle(decoration=ft.TextDecoration.LINE_THROUGH),
+        ),
+    }.get(kind, (" ", None, None))
+    return ft.Container(
+        bgcolor=bgcolor,
+        padding=ft.padding.symmetric(horizontal=4),
+        content=ft.Row(
+            spacing=8,
+            vertical_alignment=ft.CrossAxisAlignment.START,
+            controls=[
+                ft.Text(
+                    marker,
+                    font_family="monospace",
+                    size=size,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+                ft.Text(
+                    line,
+                    font_family=font_family or "monospace",
+                    size=size,
+                    style=style,
+                    selectable=True,
+                    expand=True,
+                    color=None if kind != SAME else ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ],
+        ),
+    )
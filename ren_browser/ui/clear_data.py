@@ -0,0 +1,130 @@
+"""Clear browsing data dialog for Ren Browser.
+
+Lets the user pick which kinds of stored data to delete and how far back to
+go, instead of wiping everything at once.
+"""
+
+import time
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.ui.notify import show_snack
+
+CLEAR_RANGES = {
+    "hour": ("Last hour", 3600),
+    "day": ("Last 24 hours", 86400),
+    "all": ("Everything", None),
+}
+DATA_KINDS = {
+    "cache": "Cached pages",
+    "history": "Browsing history",
+    "downloads": "Download history (files are kept)",
+    "reading_list": "Reading list copies",
+    "bookmarks": "Bookmarks",
+}
+DEFAULT_KINDS = ("cache", "history")
+
+
+def clear_browsing_data(
+    tab_manager,
+    kinds,
+    range_key: str,
+    now: float | None = None,
+) -> None:
+    """Delete the chosen kinds of data from the chosen time range.
+
+    Args:
+        tab_manager: Tab manager owning the stores to clear.
+        kinds: Keys of DATA_KINDS to clear.
+        range_key: Key of CLEAR_RANGES saying how far back to go.
+        now: Current time, for tests.
+
+    """
+    seconds = CLEAR_RANGES[range_key][1]
+    since = None if seconds is None else (now or time.time()) - seconds
+    if "cache" in kinds:
+        tab_manager.page_cache.clear(since)
+        tab_manager.revisions.clear(since)
+    if "history" in kinds:
+        tab_manager.history.clear(since)
+    if "downloads" in kinds:
+        tab_manager.downloads.clear_finished(since)
+    if "reading_list" in kinds:
+        tab_manager.reading_list.clear(since)
+    if "bookmarks" in kinds:
+        tab_manager.bookmarks.clear(since)
+        tab_manager._on_bookmarks_imported()
+
+
+class ClearDataDialog:
+    """Dialog choosing what browsing data to clear."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            tab_manager: Tab manager owning the stores to clear.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.checkboxes = {
+            key: ft.Checkbox(label=label, value=key in DEFAULT_KINDS)
+            for key, label in DATA_KINDS.items()
+        }
+        self.range_group = ft.RadioGroup(
+            value="all",
+            content=ft.Column(
+                spacing=0,
+                controls=[
+                    ft.Radio(value=key, label=label)
+                    for key, (label, _) in CLEAR_RANGES.items()
+                ],
+            ),
+        )
+        self.dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text("Clear Browsing Data"),
+            content=ft.Column(
+                tight=True,
+                spacing=8,
+                controls=[
+                    ft.Text("Time range", weight=ft.FontWeight.BOLD),
+                    self.range_group,
+                    ft.Text("Data", weight=ft.FontWeight.BOLD),
+                    *self.checkboxes.values(),
+                ],
+            ),
+            actions=[
+                ft.TextButton("Cancel", on_click=lambda e: self.close()),
+                ft.FilledButton(
+                    "Clear Data",
+                    icon=ft.Icons.DELETE_SWEEP,
+                    on_click=lambda e: self.clear(),
+                ),
+            ],
+        )
+
+    def selected_kinds(self) -> list[str]:
+        """Return the kinds of data that are ticked."""
+        return [key for key, box in self.checkboxes.items() if box.value]
+
+    def open(self) -> None:
+        """Show the dialog."""
+        open_dialog(self.page, self.dialog)
+
+    def close(self) -> None:
+        """Hide the dialog without clearing anything."""
+        self.page.close(self.dialog)
+
+    def clear(self) -> None:
+        """Clear the ticked data and close the dialog."""
+        kinds = self.selected_kinds()
+        self.page.close(self.dialog)
+        if not kinds:
+            return
+        clear_browsing_data(self.tab_manager, kinds, self.range_group.value or "all")
+        labels = ", ".join(DATA_KINDS[key].split(" (")[0].lower() for key in kinds)
+        show_snack(self.page, f"Cleared {labels}")
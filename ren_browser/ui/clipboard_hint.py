@@ -0,0 +1,88 @@
+"""Clipboard address hint for Ren Browser.
+
+When the window gains focus with a destination hash or ``ren://`` link on
+the clipboard, as after copying one from a chat, a small chip beside the
+address bar offers to open it. The clipboard is only read when this is
+turned on in the privacy settings, and each copied text is offered once.
+"""
+
+import flet as ft
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.pages.address import clipboard_address
+
+
+class ClipboardHint:
+    """Chip offering to open the address on the clipboard."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the chip, hidden until an address is copied.
+
+        Args:
+            page: Flet page whose clipboard is read.
+            tab_manager: Tab manager the address is opened in.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.address: str | None = None
+        # Clipboard text last looked at, so it is not offered again
+        self._seen: str | None = None
+        self.label = ft.Text(size=12)
+        self.chip = ft.Chip(
+            label=self.label,
+            leading=ft.Icon(ft.Icons.CONTENT_PASTE_GO, size=16),
+            on_click=lambda e: self.open(),
+            on_delete=lambda e: self.dismiss(),
+            delete_icon_tooltip=tr("Dismiss"),
+            visible=False,
+        )
+
+    def check(self) -> None:
+        """Offer the address on the clipboard, if there is a new one."""
+        if not self.tab_manager.settings.get("clipboard_hints", False):
+            self.hide()
+            return
+        try:
+            text = self.page.get_clipboard() or ""
+        except Exception:  # noqa: BLE001
+            return
+        if text == self._seen:
+            return
+        self._seen = text
+        address = clipboard_address(text)
+        manager = self.tab_manager.manager
+        if address is None or manager.tabs[manager.index].get("address") == address:
+            self.hide()
+        else:
+            self.show(address)
+        self.page.update()
+
+    def show(self, address: str) -> None:
+        """Offer to open an address."""
+        destination_hash = address.split(":", 1)[0]
+        node_name = self.tab_manager.node_name
+        name = (node_name(destination_hash) if node_name else None) or (
+            f"{destination_hash[:8]}…"
+        )
+        self.address = address
+        self.label.value = tr("Open {name}?", name=name)
+        self.chip.tooltip = address
+        self.chip.visible = True
+
+    def hide(self) -> None:
+        """Stop offering the address."""
+        self.address = None
+        self.chip.visible = False
+
+    def open(self) -> None:
+        """Open the offered address in the active tab."""
+        address = self.address
+        self.hide()
+        if address is not None:
+            self.tab_manager._open_address(address)
+
+    def dismiss(self) -> None:
+        """Hide the chip without opening the address."""
+        self.hide()
+        self.page.update()
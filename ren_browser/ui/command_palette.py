@@ -0,0 +1,119 @@
+"""Command palette for Ren Browser.
+
+Lists every action of the keyboard shortcuts with the chords bound to it,
+narrowed by fuzzy search as a command is typed. Enter runs the best match.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.controls.shortcuts import ACTIONS, keybindings
+from ren_browser.i18n.i18n import tr
+from ren_browser.ui.announce_list import fuzzy_score
+
+PALETTE_ACTION = "command_palette"
+
+
+def palette_matches(query: str) -> list[str]:
+    """Return the actions matching a query, best first.
+
+    Actions are matched on their translated label. Without a query every
+    action is listed in the order of ACTIONS.
+
+    Args:
+        query: Text typed into the palette.
+
+    Returns:
+        list[str]: Keys of ACTIONS, leaving out the palette itself.
+
+    """
+    actions = [action for action in ACTIONS if action != PALETTE_ACTION]
+    query = query.strip().casefold()
+    if not query:
+        return actions
+    scored = []
+    for position, action in enumerate(actions):
+        score = fuzzy_score(query, tr(ACTIONS[action][0]))
+        if score is not None:
+            scored.append((-score, position, action))
+    return [action for _, _, action in sorted(scored)]
+
+
+class CommandPalette:
+    """Dialog for finding and running any action by name."""
+
+    def __init__(self, page: ft.Page, tab_manager, run: Callable[[str], None]):
+        """Build the palette.
+
+        Args:
+            page: Flet page the palette is shown on.
+            tab_manager: Tab manager whose settings hold the keybindings.
+            run: Runs an action of ACTIONS, as a shortcut would.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.run = run
+        self.matches: list[str] = []
+        self.query = ft.TextField(
+            hint_text=tr("Type a command"),
+            prefix_icon=ft.Icons.SEARCH,
+            autofocus=True,
+            dense=True,
+            on_change=lambda e: self.filter(e.control.value),
+            on_submit=lambda e: self.run_first(),
+        )
+        self.results = ft.ListView(height=360, spacing=0)
+        self.dialog = ft.AlertDialog(
+            content=ft.Container(
+                width=480,
+                content=ft.Column(
+                    tight=True,
+                    controls=[self.query, self.results],
+                ),
+            ),
+        )
+
+    def open(self) -> None:
+        """Show the palette with every action listed."""
+        self.query.value = ""
+        self.filter("")
+        open_dialog(self.page, self.dialog)
+
+    def filter(self, query: str) -> None:
+        """List the actions matching query with their shortcuts."""
+        bindings = keybindings(self.tab_manager.settings.get("keybindings"))
+        self.matches = palette_matches(query)
+        self.results.controls = [
+            ft.ListTile(
+                title=ft.Text(tr(ACTIONS[action][0])),
+                trailing=ft.Text(
+                    ", ".join(bindings[action]),
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+                dense=True,
+                selected=index == 0,
+                on_click=lambda e, action=action: self.run_action(action),
+            )
+            for index, action in enumerate(self.matches)
+        ]
+        if not self.matches:
+            self.results.controls = [
+                ft.Text(
+                    tr("No matching commands"),
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ]
+        self.page.update()
+
+    def run_first(self) -> None:
+        """Run the best match for the query, if there is one."""
+        if self.matches:
+            self.run_action(self.matches[0])
+
+    def run_action(self, action: str) -> None:
+        """Close the palette and run an action."""
+        self.page.close(self.dialog)
+        self.run(action)
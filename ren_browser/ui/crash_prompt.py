@@ -0,0 +1,75 @@
+"""Crash recovery prompt for Ren Browser.
+
+Shown on the launch after a crash. It says where the crash report was
+saved and offers to reopen the tabs that were open, unless the startup
+setting has restored them already.
+"""
+
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.tabs.session import STARTUP_RESTORE
+from ren_browser.ui.notify import show_snack
+
+
+class CrashPrompt:
+    """Asks whether to restore the session after a crash."""
+
+    def __init__(self, page: ft.Page, tab_manager, report: Path):
+        """Build the prompt.
+
+        Args:
+            page: Flet page the prompt is shown on.
+            tab_manager: Tab manager holding the session of the previous run.
+            report: Crash report the previous run wrote.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.report = report
+        self.dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text(tr("Ren Browser closed unexpectedly")),
+            content=ft.Column(
+                tight=True,
+                controls=[
+                    ft.Text(
+                        tr("A crash report was saved to {path}.", path=report),
+                        selectable=True,
+                    ),
+                    ft.Text(tr("Restore the tabs from the previous session?")),
+                ],
+            ),
+            actions=[
+                ft.TextButton(tr("Not Now"), on_click=lambda e: self.dismiss()),
+                ft.FilledButton(tr("Restore Tabs"), on_click=lambda e: self.restore()),
+            ],
+        )
+
+    def show(self) -> None:
+        """Offer to restore the session, or only say where the report is."""
+        restored = self.tab_manager.settings.get("startup") == STARTUP_RESTORE
+        if restored or not any(self.tab_manager.session.tabs):
+            show_snack(
+                self.page,
+                tr(
+                    "Ren Browser closed unexpectedly. A crash report was saved "
+                    "to {path}.",
+                    path=self.report,
+                ),
+            )
+            return
+        open_dialog(self.page, self.dialog)
+
+    def restore(self) -> None:
+        """Reopen the tabs of the previous session."""
+        self.page.close(self.dialog)
+        self.tab_manager.restore_session()
+        self.page.update()
+
+    def dismiss(self) -> None:
+        """Close the prompt and keep the tabs as they are."""
+        self.page.close(self.dialog)
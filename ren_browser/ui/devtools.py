@@ -0,0 +1,207 @@
+"""Developer tools for Ren Browser.
+
+A pane under the page, toggled with F12, showing how the page in the
+active tab was loaded: the request sent to the node and what came back as
+JSON, how long each load phase took, the renderer that showed it and, for
+micron pages, the tree of blocks and spans the page was parsed into. It is
+meant for page authors and for finding out why a page looks the way it
+does.
+"""
+
+import flet as ft
+
+from ren_browser.pages.page_request import LOAD_PHASES
+from ren_browser.pages.trace import to_json
+from ren_browser.renderer.markdown import parse_markdown
+from ren_browser.renderer.micron import MicronBlock, parse_micron
+
+# Lines of the span tree shown, long pages are cut off after this
+MAX_TREE_LINES = 2000
+_SPAN_STYLES = ("bold", "italic", "underline")
+
+
+def _describe_span(span: dict) -> str:
+    styles = [style for style in _SPAN_STYLES if span.get(style)]
+    for key in ("color", "bgcolor"):
+        if span.get(key):
+            styles.append(f"{key}={span[key]}")
+    return " ".join([f"span {span['text']!r}", *styles])
+
+
+def block_tree(blocks: tuple[MicronBlock, ...]) -> list[str]:
+    """Describe parsed micron blocks and their spans, one per line.
+
+    Blocks are listed with their kind, section level and alignment, and
+    the spans and links of each block follow it, indented.
+    """
+    lines = []
+    for block in blocks:
+        if block.kind == "blank":
+            lines.append("blank")
+            continue
+        head = f"{block.kind} level {block.level}"
+        if block.kind == "heading":
+            head += f" {block.text!r}"
+        if block.alignment != ft.TextAlign.START:
+            head += f" align={block.alignment}"
+        if block.rtl:
+            head += " rtl"
+        lines.append(head)
+        for part in block.parts:
+            if isinstance(part, tuple):
+                label, url = part
+                lines.append(f"  link {label!r} -> {url}")
+            else:
+                lines.append(f"  {_describe_span(part)}")
+    return lines
+
+
+def timing_lines(trace) -> list[str]:
+    """Describe how long each phase of a page load took."""
+    lines = [
+        f"{LOAD_PHASES[phase][2]}: {seconds * 1000:.0f} ms"
+        for phase, seconds in trace.timings()
+    ]
+    state = "" if trace.finished is not None else " so far"
+    lines.append(f"Total: {trace.total() * 1000:.0f} ms{state}")
+    return lines
+
+
+class DevToolsPane:
+    """Pane under the page describing how the active tab's page loaded."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the hidden pane.
+
+        Args:
+            page: Flet page instance for UI updates.
+            tab_manager: Tab manager whose active tab is described.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.summary = ft.Text("", size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+        self.request_text = self._code_text()
+        self.response_text = self._code_text()
+        self.timing_text = self._code_text()
+        self.tree_text = self._code_text()
+        self.tabs = ft.Tabs(
+            selected_index=0,
+            expand=True,
+            tabs=[
+                self._tab("Request", self.request_text),
+                self._tab("Response", self.response_text),
+                self._tab("Timing", self.timing_text),
+                self._tab("Spans", self.tree_text),
+            ],
+        )
+        self.control = ft.Container(
+            visible=False,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+            border_radius=8,
+            padding=ft.padding.all(8),
+            margin=ft.margin.symmetric(horizontal=8),
+            height=260,
+            content=ft.Column(
+                spacing=6,
+                controls=[
+                    ft.Row(
+                        controls=[
+                            ft.Text(
+                                "Developer Tools",
+                                size=14,
+                                weight=ft.FontWeight.BOLD,
+                            ),
+                            ft.Container(content=self.summary, expand=True),
+                            ft.IconButton(
+                                ft.Icons.REFRESH,
+                                tooltip="Refresh",
+                                icon_size=16,
+                                on_click=lambda e: self.set_visible(True),
+                            ),
+                            ft.IconButton(
+                                ft.Icons.CLOSE,
+                                tooltip="Hide developer tools",
+                                icon_size=16,
+                                on_click=lambda e: self.set_visible(False),
+                            ),
+                        ],
+                    ),
+                    self.tabs,
+                ],
+            ),
+        )
+
+    @staticmethod
+    def _code_text() -> ft.Text:
+        return ft.Text("", size=12, font_family="monospace", selectable=True)
+
+    @staticmethod
+    def _tab(label: str, text: ft.Text) -> ft.Tab:
+        return ft.Tab(
+            text=label,
+            content=ft.Column(controls=[text], scroll=ft.ScrollMode.AUTO),
+        )
+
+    @property
+    def visible(self) -> bool:
+        """Whether the pane is shown."""
+        return bool(self.control.visible)
+
+    def set_visible(self, visible: bool) -> None:
+        """Show or hide the pane."""
+        self.control.visible = visible
+        if visible:
+            self.render()
+        self.page.update()
+
+    def toggle(self) -> None:
+        """Show the pane if hidden, otherwise hide it."""
+        self.set_visible(not self.visible)
+
+    def refresh(self) -> None:
+        """Describe the active tab again if the pane is shown."""
+        if self.visible:
+            self.render()
+
+    def render(self) -> None:
+        """Fill the pane in from the trace of the active tab's last load."""
+        manager = self.tab_manager.manager
+        tab = manager.tabs[manager.index]
+        trace = tab.get("trace")
+        if trace is None:
+            self.summary.value = "No page was loaded from a node in this tab."
+            for text in (
+                self.request_text,
+                self.response_text,
+                self.timing_text,
+                self.tree_text,
+            ):
+                text.value = ""
+            return
+        renderer = trace.renderer or "none yet"
+        self.summary.value = f"{trace.address}  ·  renderer: {renderer}"
+        self.request_text.value = to_json(trace.request)
+        self.response_text.value = (
+            to_json(trace.response) if trace.response is not None else "Waiting..."
+        )
+        self.timing_text.value = "\n".join(timing_lines(trace))
+        self.tree_text.value = self._tree(tab, trace)
+
+    @staticmethod
+    def _tree(tab: dict, trace) -> str:
+        if trace.renderer not in ("micron", "markdown"):
+            return "Only micron and Markdown pages are parsed into spans."
+        source = tab.get("source")
+        if source is None:
+            return ""
+        if trace.renderer == "markdown":
+            blocks = parse_markdown(source, tab.get("page_path", ""))
+        else:
+            blocks = parse_micron(source)
+        lines = block_tree(blocks)
+        if len(lines) > MAX_TREE_LINES:
+            cut = len(lines) - MAX_TREE_LINES
+            lines = [*lines[:MAX_TREE_LINES], f"... {cut} more lines"]
+        return "\n".join(lines)
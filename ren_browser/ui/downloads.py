@@ -0,0 +1,274 @@
+"""Downloads panel for Ren Browser.
+
+Shows active and finished file transfers under the tab bar with their
+progress and controls to pause, resume, cancel or find them on disk, and
+asks where to save a file when the browser is set to.
+"""
+
+from collections.abc import Callable
+from pathlib import Path, PurePosixPath
+
+import flet as ft
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.images.images import is_image_name
+from ren_browser.pages.address import parse_address
+
+STATUS_LABELS = {
+    "downloading": "Downloading",
+    "paused": "Paused",
+    "completed": "Completed",
+    "failed": "Failed",
+    "cancelled": "Cancelled",
+}
+
+
+def format_size(size: int | None) -> str:
+    """Return a human readable file size."""
+    if size is None:
+        return ""
+    if size >= 1_000_000:
+        return f"{size / 1_000_000:.1f} MB"
+    if size >= 1_000:
+        return f"{size / 1_000:.1f} KB"
+    return f"{size} B"
+
+
+def download_summary(download) -> str:
+    """Return the status line shown under a download's name."""
+    label = STATUS_LABELS.get(download.status, download.status)
+    if download.status == "downloading":
+        return f"{label}... {int(download.progress * 100)}%"
+    if download.status == "completed":
+        return f"{label} · {format_size(download.size)}"
+    if download.status == "failed" and download.error:
+        return f"{label}: {download.error}"
+    return label
+
+
+class DownloadsPanel:
+    """Collapsible list of downloads."""
+
+    def __init__(
+        self,
+        page: ft.Page,
+        downloads,
+        on_view: Callable[[Path], None] | None = None,
+    ):
+        """Initialize the downloads panel.
+
+        Args:
+            page: Flet page instance for UI updates.
+            downloads: DownloadManager whose downloads are shown.
+            on_view: Called with the file of a downloaded image to view it.
+
+        """
+        self.page = page
+        self.downloads = downloads
+        self.on_view = on_view
+        self.list_column = ft.Column(spacing=4, scroll=ft.ScrollMode.AUTO)
+        self.control = ft.Container(
+            visible=False,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+            border_radius=8,
+            padding=ft.padding.all(8),
+            margin=ft.margin.symmetric(horizontal=8),
+            height=220,
+            content=ft.Column(
+                spacing=6,
+                controls=[
+                    ft.Row(
+                        controls=[
+                            ft.Text(
+                                "Downloads",
+                                size=14,
+                                weight=ft.FontWeight.BOLD,
+                                expand=True,
+                            ),
+                            ft.TextButton(
+                                "Clear finished",
+                                on_click=lambda e: downloads.clear_finished(),
+                            ),
+                            ft.IconButton(
+                                ft.Icons.CLOSE,
+                                tooltip="Hide downloads",
+                                icon_size=16,
+                                on_click=lambda e: self.set_visible(False),
+                            ),
+                        ],
+                    ),
+                    ft.Container(content=self.list_column, expand=True),
+                ],
+            ),
+        )
+        downloads.add_listener(self.refresh)
+        self.render()
+
+    def set_visible(self, visible: bool) -> None:
+        """Show or hide the panel."""
+        self.control.visible = visible
+        self.page.update()
+
+    def toggle(self) -> None:
+        """Show the panel if hidden, otherwise hide it."""
+        self.set_visible(not self.control.visible)
+
+    def refresh(self) -> None:
+        """Redraw the list after a download changed."""
+        self.render()
+        self.page.update()
+
+    def render(self) -> None:
+        """Rebuild the rows for every download."""
+        downloads = self.downloads.downloads()
+        if not downloads:
+            self.list_column.controls = [
+                ft.Text(
+                    "Files you download from nodes will be listed here.",
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ]
+            return
+        self.list_column.controls = [self._build_row(d) for d in downloads]
+
+    def open_folder(self, download) -> None:
+        """Open the folder containing a finished download."""
+        if download.path:
+            self.page.launch_url(Path(download.path).parent.as_uri())
+
+    def _build_row(self, download) -> ft.Control:
+        manager = self.downloads
+        actions: list[ft.Control] = []
+        if download.status == "downloading":
+            actions.append(
+                ft.IconButton(
+                    ft.Icons.PAUSE,
+                    tooltip="Pause",
+                    icon_size=16,
+                    on_click=lambda e: manager.pause(download.id),
+                ),
+            )
+        if download.status in ("paused", "failed", "cancelled"):
+            paused = download.status == "paused"
+            actions.append(
+                ft.IconButton(
+                    ft.Icons.PLAY_ARROW if paused else ft.Icons.REFRESH,
+                    tooltip="Resume" if paused else "Retry",
+                    icon_size=16,
+                    on_click=lambda e: manager.resume(download.id),
+                ),
+            )
+        if download.status in ("downloading", "paused"):
+            actions.append(
+                ft.IconButton(
+                    ft.Icons.CLOSE,
+                    tooltip="Cancel",
+                    icon_size=16,
+                    on_click=lambda e: manager.cancel(download.id),
+                ),
+            )
+        if (
+            download.status == "completed"
+            and download.path
+            and self.on_view is not None
+            and is_image_name(download.path)
+        ):
+            actions.append(
+                ft.IconButton(
+                    ft.Icons.IMAGE_OUTLINED,
+                    tooltip="View image",
+                    icon_size=16,
+                    on_click=lambda e: self.on_view(Path(download.path)),
+                ),
+            )
+        if download.status == "completed":
+            actions.append(
+                ft.IconButton(
+                    ft.Icons.FOLDER_OPEN,
+                    tooltip="Open containing folder",
+                    icon_size=16,
+                    on_click=lambda e: self.open_folder(download),
+                ),
+            )
+        if download.status in ("completed", "failed", "cancelled"):
+            actions.append(
+                ft.IconButton(
+                    ft.Icons.DELETE_OUTLINE,
+                    tooltip="Remove from list",
+                    icon_size=16,
+                    on_click=lambda e: manager.remove(download.id),
+                ),
+            )
+        return ft.Container(
+            padding=ft.padding.symmetric(horizontal=8, vertical=4),
+            border_radius=8,
+            bgcolor=ft.Colors.SURFACE_CONTAINER_HIGHEST,
+            content=ft.Row(
+                spacing=8,
+                controls=[
+                    ft.Icon(ft.Icons.INSERT_DRIVE_FILE, size=18),
+                    ft.Column(
+                        spacing=2,
+                        expand=True,
+                        controls=[
+                            ft.Text(
+                                download.name,
+                                size=13,
+                                weight=ft.FontWeight.W_500,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                            ft.ProgressBar(
+                                value=download.progress,
+                                height=3,
+                                color=ft.Colors.PRIMARY,
+                                visible=download.status in ("downloading", "paused"),
+                            ),
+                            ft.Text(
+                                download_summary(download),
+                                size=11,
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                            ),
+                        ],
+                    ),
+                    *actions,
+                ],
+            ),
+        )
+
+
+class SaveLocationPicker:
+    """Native save dialog asking where to write a download."""
+
+    def __init__(self, page: ft.Page, downloads, on_start: Callable[[], None]):
+        """Initialize the save dialog.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            downloads: DownloadManager the chosen downloads are started with.
+            on_start: Called after a download was started.
+
+        """
+        self.page = page
+        self.downloads = downloads
+        self.on_start = on_start
+        self.pending: str | None = None
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+
+    def choose(self, address: str) -> None:
+        """Ask where to save the file at an address, then download it."""
+        self.pending = address
+        name = PurePosixPath(parse_address(address).page_path).name or "download"
+        self.picker.save_file(
+            dialog_title=tr("Save File As"),
+            file_name=name,
+            initial_directory=str(self.downloads.directory),
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        address, self.pending = self.pending, None
+        if address is not None and e.path:
+            self.downloads.start(address, Path(e.path))
+            self.on_start()
@@ -0,0 +1,76 @@
+"""Text encoding menu for Ren Browser.
+
+Dialog choosing the encoding the active tab decodes its pages in, for
+pages of older nodes that are not UTF-8 and look garbled as detected.
+"""
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.pages.charset import ENCODINGS
+
+AUTOMATIC = "auto"
+
+
+class EncodingDialog:
+    """Dialog setting the encoding of a tab's pages."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            tab_manager: Tab manager whose tab is decoded in the encoding.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.idx = 0
+        self.choices = ft.RadioGroup(
+            content=ft.Column(
+                tight=True,
+                spacing=0,
+                controls=[
+                    ft.Radio(value=AUTOMATIC, label=tr("Automatic")),
+                    *(
+                        ft.Radio(value=encoding, label=tr(label))
+                        for encoding, label in ENCODINGS.items()
+                    ),
+                ],
+            ),
+            on_change=lambda e: self.apply(),
+        )
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("Text Encoding")),
+            content=ft.Column(
+                tight=True,
+                scroll=ft.ScrollMode.AUTO,
+                height=360,
+                controls=[self.choices],
+            ),
+            actions=[
+                ft.TextButton(tr("Close"), on_click=lambda e: self.close()),
+            ],
+        )
+
+    def open(self, idx: int) -> None:
+        """Ask for the encoding of the tab at idx, showing the one it uses."""
+        self.idx = idx
+        self.choices.value = (
+            self.tab_manager.manager.tabs[idx].get("encoding") or AUTOMATIC
+        )
+        open_dialog(self.page, self.dialog)
+
+    def apply(self) -> None:
+        """Decode the tab's pages in the chosen encoding."""
+        choice = self.choices.value
+        self.close()
+        self.tab_manager.set_encoding(
+            self.idx,
+            None if choice in (None, AUTOMATIC) else choice,
+        )
+
+    def close(self) -> None:
+        """Close the dialog."""
+        self.page.close(self.dialog)
@@ -0,0 +1,196 @@
+"""Error pages for Ren Browser.
+
+Explains why a page failed to load and offers ways to recover, instead of
+showing the raw exception text in the tab.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.ui.cache import format_age
+
+ERROR_DETAILS = {
+    "no_path": (
+        ft.Icons.ROUTE,
+        "No path to node",
+        "Reticulum could not find a route to this node. It may be offline or "
+        "out of reach of your interfaces.",
+    ),
+    "unknown_identity": (
+        ft.Icons.PERSON_OFF,
+        "Node identity unknown",
+        "A path is known but the node has not announced its identity yet, so "
+        "the request cannot be encrypted. Requesting the path again may help.",
+    ),
+    "link_failed": (
+        ft.Icons.LINK_OFF,
+        "Request failed",
+        "The node was reached but the page request failed. The page may not "
+        "exist or the node refused the request.",
+    ),
+    "timeout": (
+        ft.Icons.TIMER_OFF,
+        "Request timed out",
+        "The node did not answer in time. Slow or busy links can take a while, "
+        "so trying again often works.",
+    ),
+    "blocked": (
+        ft.Icons.BLOCK,
+        "Node blocked",
+        "You blocked this node, so its pages are not loaded. Unblock it here "
+        "or under Settings, Blocked Nodes to visit it again.",
+    ),
+    "gemini": (
+        ft.Icons.PUBLIC_OFF,
+        "Gemini page failed to load",
+        "The Gemini server could not be reached or refused the request. "
+        "The address may be wrong or the server may be down.",
+    ),
+    "certificate": (
+        ft.Icons.GPP_BAD,
+        "Certificate changed",
+        "The server shows a different certificate than on your first visit. "
+        "Servers renew their certificates, but a change can also mean someone "
+        "is intercepting the connection. Only trust it if you expected this.",
+    ),
+    "unknown": (
+        ft.Icons.ERROR_OUTLINE,
+        "Page failed to load",
+        "Something unexpected went wrong while loading the page.",
+    ),
+}
+
+
+def build_error_page(
+    kind: str,
+    address: str,
+    detail: str,
+    on_retry: Callable[[], None],
+    on_request_path: Callable[[], None] | None = None,
+    on_open_cached: Callable[[], None] | None = None,
+    on_unblock: Callable[[], None] | None = None,
+    on_trust: Callable[[], None] | None = None,
+    cached_age: float | None = None,
+) -> ft.Control:
+    """Build the page shown in a tab whose load failed.
+
+    Args:
+        kind: Failure kind from PageFetchError, or "unknown".
+        address: Address that failed to load.
+        detail: Technical error message.
+        on_retry: Called to load the address again.
+        on_request_path: Called to load the address with a fresh path request,
+            or None for addresses that are not on a node.
+        on_open_cached: Called to show the cached copy, or None if there is none.
+        on_unblock: Called to unblock the node, for pages of blocked nodes.
+            Replaces the other actions, which cannot help.
+        on_trust: Called to trust a changed certificate and load the page again.
+        cached_age: Seconds since the cached copy was fetched, or None if the
+            copy is not from the page cache.
+
+    Returns:
+        ft.Control: The error page.
+
+    """
+    icon, title, explanation = ERROR_DETAILS.get(kind, ERROR_DETAILS["unknown"])
+    if on_unblock is not None:
+        actions: list[ft.Control] = [
+            ft.ElevatedButton(
+                "Unblock Node",
+                icon=ft.Icons.LOCK_OPEN,
+                on_click=lambda e: on_unblock(),
+            ),
+        ]
+    elif on_request_path is None:
+        actions = [
+            ft.ElevatedButton(
+                "Retry",
+                icon=ft.Icons.REFRESH,
+                on_click=lambda e: on_retry(),
+            ),
+        ]
+        if on_trust is not None:
+            actions.append(
+                ft.OutlinedButton(
+                    "Trust New Certificate",
+                    icon=ft.Icons.VERIFIED_USER,
+                    on_click=lambda e: on_trust(),
+                ),
+            )
+    else:
+        actions = [
+            ft.ElevatedButton(
+                "Retry",
+                icon=ft.Icons.REFRESH,
+                on_click=lambda e: on_retry(),
+            ),
+            ft.OutlinedButton(
+                "Request Path Again",
+                icon=ft.Icons.ROUTE,
+                on_click=lambda e: on_request_path(),
+            ),
+            ft.OutlinedButton(
+                "Open Cached Copy"
+                if cached_age is None
+                else f"Show Cached Copy from {format_age(cached_age)} ago",
+                icon=ft.Icons.HISTORY,
+                disabled=on_open_cached is None,
+                on_click=lambda e: on_open_cached and on_open_cached(),
+            ),
+        ]
+    return ft.Container(
+        padding=ft.padding.all(24),
+        content=ft.Column(
+            spacing=12,
+            controls=[
+                ft.Icon(icon, size=48, color=ft.Colors.ERROR),
+                ft.Text(title, size=20, weight=ft.FontWeight.BOLD),
+                ft.Text(explanation, size=14),
+                ft.Text(
+                    address,
+                    size=13,
+                    font_family="monospace",
+                    color=ft.Colors.BLUE_200,
+                    selectable=True,
+                ),
+                ft.Text(
+                    detail,
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                    selectable=True,
+                ),
+                ft.Row(spacing=8, wrap=True, controls=actions),
+            ],
+        ),
+    )
+
+
+def build_stale_banner(age: float, on_retry: Callable[[], None]) -> ft.Control:
+    """Build the banner shown above a cached copy opened from an error page.
+
+    Args:
+        age: Seconds since the copy was fetched.
+        on_retry: Called to try loading the page again.
+
+    Returns:
+        ft.Control: The banner.
+
+    """
+    return ft.Container(
+        padding=ft.padding.symmetric(horizontal=16, vertical=8),
+        bgcolor=ft.Colors.TERTIARY_CONTAINER,
+        content=ft.Row(
+            spacing=8,
+            controls=[
+                ft.Icon(ft.Icons.HISTORY, size=18, color=ft.Colors.TERTIARY),
+                ft.Text(
+                    f"The page could not be loaded. This copy was cached "
+                    f"{format_age(age)} ago and may be out of date.",
+                    size=13,
+                    expand=True,
+                ),
+                ft.TextButton("Retry", on_click=lambda e: on_retry()),
+            ],
+        ),
+    )
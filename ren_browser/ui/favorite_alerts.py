@@ -0,0 +1,72 @@
+"""Favorite node announce notifications for Ren Browser.
+
+Tells the user when a favorite node announces again after being quiet for
+a while, with a button to open its index page.
+"""
+
+import time
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.ui.notify import desktop_notify, show_snack
+
+OFFLINE_SECONDS = 60 * 60
+STARTUP_GRACE = 2 * 60
+
+
+class FavoriteAlerts:
+    """Announce listener that notifies about favorite nodes coming back."""
+
+    def __init__(self, page: ft.Page, tab_manager, on_open: Callable[[str], None]):
+        """Initialize the alerts.
+
+        Args:
+            page: Flet page the notifications are shown on.
+            tab_manager: Tab manager holding the settings and node metadata.
+            on_open: Called with a node's index address when Open is clicked.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.on_open = on_open
+        self.started = time.time()
+        self.last_heard: dict[str, float] = {}
+
+    def is_return(self, ann) -> bool:
+        """Return whether a favorite's announce follows a quiet spell.
+
+        A favorite heard earlier this session is back once it was quiet for
+        OFFLINE_SECONDS. One not heard yet only counts after STARTUP_GRACE,
+        so the announces arriving as Reticulum starts up stay quiet.
+        """
+        last = self.last_heard.get(ann.destination_hash)
+        self.last_heard[ann.destination_hash] = ann.timestamp
+        if last is None:
+            return ann.timestamp - self.started >= STARTUP_GRACE
+        return ann.timestamp - last >= OFFLINE_SECONDS
+
+    def on_announce(self, ann) -> None:
+        """Notify about a favorite node announcing after a quiet spell."""
+        nodes = self.tab_manager.nodes
+        if not nodes.is_favorite(ann.destination_hash):
+            return
+        if not self.is_return(ann):
+            return
+        settings = self.tab_manager.settings
+        if not settings.get("favorite_notifications", True):
+            return
+        name = nodes.display_name(
+            ann.destination_hash,
+            ann.display_name or nodes.get(ann.destination_hash).name,
+        )
+        message = f"{name or ann.destination_hash[:8]} is back online"
+        address = f"{ann.destination_hash}:/page/index.mu"
+        show_snack(
+            self.page,
+            message,
+            action="Open",
+            on_action=lambda e: self.on_open(address),
+        )
+        self.tab_manager.notifications.add("favorites", message, address)
+        desktop_notify(settings, "favorites", message)
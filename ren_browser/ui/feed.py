@@ -0,0 +1,195 @@
+"""Followed-nodes feed page for Ren Browser.
+
+Lists new and changed pages found on followed nodes, newest first, and the
+nodes being followed.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+
+
+def build_feed_page(
+    feed,
+    on_open: Callable[[str], None],
+    on_check: Callable[[Callable[[], None]], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:feed`` page.
+
+    Args:
+        feed: NodeFeed whose items and followed nodes are listed.
+        on_open: Called with an address when an item is clicked.
+        on_check: Called to check every followed node now, with a callback
+            to run once the checks are done.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The feed page.
+
+    """
+    page = ft.Column(spacing=4)
+    checking = [False]
+
+    def changed():
+        render()
+        on_change()
+
+    def unfollow(destination_hash):
+        feed.unfollow(destination_hash)
+        changed()
+
+    def done():
+        checking[0] = False
+        changed()
+
+    def check_now(_):
+        checking[0] = True
+        changed()
+        on_check(done)
+
+    def render():
+        nodes = feed.nodes()
+        names = {node.destination_hash: node.name for node in nodes}
+        controls: list[ft.Control] = [
+            ft.Row(
+                controls=[
+                    ft.Text(
+                        "Feed",
+                        size=24,
+                        weight=ft.FontWeight.BOLD,
+                        color=ft.Colors.PRIMARY,
+                        expand=True,
+                    ),
+                    ft.OutlinedButton(
+                        "Checking…" if checking[0] else "Check Now",
+                        icon=ft.Icons.REFRESH,
+                        disabled=checking[0] or not nodes,
+                        on_click=check_now,
+                    ),
+                ],
+            ),
+        ]
+        items = feed.items()
+        if not nodes:
+            controls.append(
+                ft.Text(
+                    "Right-click a page and choose Follow Node to see its new "
+                    "and changed pages here.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        elif not items:
+            controls.append(
+                ft.Text(
+                    "Nothing new yet. Followed nodes are checked every so often.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.extend(
+            _build_item(item, names.get(item.node_hash, item.node_hash), on_open)
+            for item in items
+        )
+        if nodes:
+            controls.append(
+                ft.Container(
+                    content=ft.Text("Following", size=16, weight=ft.FontWeight.BOLD),
+                    padding=ft.padding.only(top=16),
+                ),
+            )
+            controls.extend(_build_node(node, unfollow) for node in nodes)
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _build_item(item, node_name: str, on_open) -> ft.Control:
+    found = datetime.fromtimestamp(item.found_at).strftime("%Y-%m-%d %H:%M")
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                build_node_avatar(item.node_hash, size=20),
+                ft.Column(
+                    spacing=2,
+                    expand=True,
+                    controls=[
+                        ft.Text(
+                            item.title,
+                            size=14,
+                            weight=ft.FontWeight.W_500,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                        ft.Text(
+                            f"{node_name}  ·  {found}",
+                            size=12,
+                            color=ft.Colors.ON_SURFACE_VARIANT,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                    ],
+                ),
+                ft.Container(
+                    content=ft.Text(
+                        "New" if item.kind == "new" else "Updated",
+                        size=11,
+                        color=ft.Colors.WHITE,
+                    ),
+                    bgcolor=(
+                        ft.Colors.GREEN_700
+                        if item.kind == "new"
+                        else ft.Colors.BLUE_700
+                    ),
+                    border_radius=8,
+                    padding=ft.padding.symmetric(horizontal=8, vertical=2),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=8),
+        border_radius=8,
+        ink=True,
+        on_click=lambda e: on_open(item.address),
+    )
+
+
+def _build_node(node, on_unfollow) -> ft.Control:
+    if node.error:
+        status = f"Last check failed: {node.error}"
+    elif node.checked_at:
+        checked = datetime.fromtimestamp(node.checked_at).strftime("%Y-%m-%d %H:%M")
+        status = f"Checked {checked}"
+    else:
+        status = "Not checked yet"
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                build_node_avatar(node.destination_hash, size=20),
+                ft.Column(
+                    spacing=2,
+                    expand=True,
+                    controls=[
+                        ft.Text(node.name, size=14, overflow=ft.TextOverflow.ELLIPSIS),
+                        ft.Text(
+                            status,
+                            size=12,
+                            color=ft.Colors.ON_SURFACE_VARIANT,
+                        ),
+                    ],
+                ),
+                ft.IconButton(
+                    ft.Icons.PERSON_REMOVE,
+                    tooltip="Unfollow",
+                    icon_size=18,
+                    on_click=lambda e: on_unfollow(node.destination_hash),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
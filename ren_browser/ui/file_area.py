@@ -0,0 +1,155 @@
+"""File area browser for Ren Browser.
+
+Lists the files a node shares, as linked from its fetched pages, with
+their sizes where known, and downloads them through the download manager.
+The node's index page is fetched when the browser has none of its pages
+yet, so the list is not empty on a first visit.
+"""
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.file_area.file_area import NodeFile, node_files
+from ren_browser.i18n.i18n import ntr, tr
+from ren_browser.pages.address import DEFAULT_PAGE_PATH, Address
+from ren_browser.ui.downloads import format_size
+
+
+def fetched_pages(tab_manager) -> list[tuple[str, str]]:
+    """Return the ``(address, content)`` of the cached and open pages."""
+    pages = [(url, entry.content) for url, entry in tab_manager.page_cache.entries()]
+    pages.extend(
+        (tab["address"], tab["source"])
+        for tab in tab_manager.manager.tabs
+        if tab.get("source") is not None and not tab.get("private")
+    )
+    return pages
+
+
+class FileArea:
+    """Dialog listing the files shared by a node."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the file area dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            tab_manager: Tab manager holding the fetched pages and downloads.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.destination_hash: str | None = None
+        self._scan_id: object | None = None
+        self.status = ft.Text("", size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+        self.files = ft.Column(spacing=0, tight=True, scroll=ft.ScrollMode.AUTO)
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("Files")),
+            content=ft.Container(
+                width=480,
+                height=400,
+                content=ft.Column(controls=[self.status, self.files], spacing=8),
+            ),
+            actions=[
+                ft.TextButton(tr("Scan Index Page"), on_click=lambda e: self.scan()),
+                ft.TextButton(tr("Close"), on_click=lambda e: self.close()),
+            ],
+        )
+
+    def show(self, destination_hash: str, name: str) -> None:
+        """Open the file area of a node.
+
+        Args:
+            destination_hash: Node whose files are listed.
+            name: Name of the node, shown in the title.
+
+        """
+        self.destination_hash = destination_hash
+        self.dialog.title = ft.Text(tr("Files on {name}", name=name))
+        files = self.render()
+        open_dialog(self.page, self.dialog)
+        if not files and not self._has_index():
+            self.scan()
+
+    def render(self, extra_pages=()) -> list[NodeFile]:
+        """List the files known for the node and return them.
+
+        Args:
+            extra_pages: ``(address, content)`` of pages fetched for the list
+                that may not be cached.
+
+        """
+        files = node_files(
+            self.destination_hash,
+            [*fetched_pages(self.tab_manager), *extra_pages],
+            self.tab_manager.downloads.downloads(),
+        )
+        self.status.value = (
+            ntr(
+                "1 file linked from its pages",
+                "{count} files linked from its pages",
+                len(files),
+            )
+            if files
+            else tr("No files are linked from the pages fetched from this node.")
+        )
+        self.files.controls = [self._file_row(file) for file in files]
+        return files
+
+    def scan(self) -> None:
+        """Fetch the node's index page in the background and list its files."""
+        destination_hash = self.destination_hash
+        url = str(Address(destination_hash, DEFAULT_PAGE_PATH))
+        scan_id = object()
+        self._scan_id = scan_id
+        self.status.value = tr("Fetching the index page…")
+        self.page.update()
+
+        def fetch():
+            try:
+                content = self.tab_manager.fetch_source(url)
+            except Exception as exc:  # noqa: BLE001
+                if self._scan_id is scan_id:
+                    self.status.value = tr(
+                        "Cannot fetch the index page: {error}",
+                        error=exc,
+                    )
+                    self.page.update()
+                return
+            self.tab_manager.page_cache.put(url, content)
+            if self._scan_id is scan_id and self.destination_hash == destination_hash:
+                self.render([(url, content)])
+                self.page.update()
+
+        self.page.run_thread(fetch)
+
+    def download(self, file: NodeFile) -> None:
+        """Download a file through the download manager."""
+        self.close()
+        self.tab_manager.download_file(file.address)
+
+    def close(self) -> None:
+        """Close the dialog, ignoring a scan still running."""
+        self._scan_id = None
+        self.page.close(self.dialog)
+
+    def _has_index(self) -> bool:
+        url = str(Address(self.destination_hash, DEFAULT_PAGE_PATH))
+        return self.tab_manager.page_cache.get(url, allow_stale=True) is not None
+
+    def _file_row(self, file: NodeFile) -> ft.Control:
+        details = [file.label] if file.label and file.label != file.name else []
+        details.append(
+            format_size(file.size) if file.size is not None else tr("Size unknown"),
+        )
+        return ft.ListTile(
+            leading=ft.Icon(ft.Icons.INSERT_DRIVE_FILE_OUTLINED),
+            title=ft.Text(file.name, overflow=ft.TextOverflow.ELLIPSIS),
+            subtitle=ft.Text(" · ".join(details), size=12),
+            trailing=ft.IconButton(
+                ft.Icons.DOWNLOAD,
+                tooltip=tr("Download"),
+                on_click=lambda e: self.download(file),
+            ),
+            on_click=lambda e: self.download(file),
+        )
@@ -0,0 +1,161 @@
+"""Font settings for Ren Browser.
+
+The interface font and the font rendered pages are shown in are chosen by
+family name. The choices are a few generic families, the font files found
+in the fonts folder of the storage directory, and any font installed on the
+system, typed in by name.
+
+For readers who need it, pages can be shown in OpenDyslexic instead, from
+the fonts folder shipped with the browser or else as installed on the
+system, and their text kept from getting smaller than a minimum size.
+"""
+
+from pathlib import Path
+
+import flet as ft
+
+FONT_EXTENSIONS = (".ttf", ".otf")
+UI_FONT_PRESETS = {
+    "": "Default",
+    "sans-serif": "Sans serif",
+    "serif": "Serif",
+    "monospace": "Monospace",
+}
+CONTENT_FONT_PRESETS = {
+    "": "Default (monospace for plain text)",
+    "monospace": "Monospace",
+    "sans-serif": "Sans serif",
+    "serif": "Serif",
+}
+OTHER_FONT = "other:"
+READABLE_FONT = "OpenDyslexic"
+# Font files packaged with the browser, such as OpenDyslexic-Regular.otf
+BUNDLED_FONTS_DIR = Path(__file__).resolve().parent.parent / "fonts"
+MIN_FONT_SIZES = {
+    0: "No minimum",
+    12: "12 points",
+    14: "14 points",
+    16: "16 points",
+    18: "18 points",
+    20: "20 points",
+}
+
+
+def local_fonts(directory: Path) -> dict[str, str]:
+    """Return the font files in a directory, keyed by family name.
+
+    The family name is the file name without its extension.
+    """
+    try:
+        files = sorted(Path(directory).iterdir())
+    except OSError:
+        return {}
+    return {
+        file.stem: str(file)
+        for file in files
+        if file.suffix.lower() in FONT_EXTENSIONS and file.is_file()
+    }
+
+
+def register_fonts(page: ft.Page, directory: Path) -> list[str]:
+    """Make the font files in a directory available to the page.
+
+    Returns:
+        The family names of the registered fonts.
+
+    """
+    fonts = local_fonts(directory)
+    if fonts:
+        page.fonts = {**(page.fonts or {}), **fonts}
+    return list(fonts)
+
+
+def min_font_size(settings: dict) -> int:
+    """Return the smallest text size pages are shown in, 0 for no minimum."""
+    size = settings.get("min_font_size", 0)
+    return size if size in MIN_FONT_SIZES else 0
+
+
+def page_font(settings: dict) -> str | None:
+    """Return the font rendered pages are shown in, None for the default."""
+    if settings.get("readable_font", False):
+        return READABLE_FONT
+    return settings.get("content_font") or None
+
+
+class FontPicker:
+    """Dropdown choosing a font, with a name field for system fonts."""
+
+    def __init__(
+        self,
+        page: ft.Page,
+        label: str,
+        value: str,
+        presets: dict[str, str],
+    ):
+        """Build the picker.
+
+        Args:
+            page: Flet page the picker is shown on.
+            label: Label of the dropdown.
+            value: Font family chosen so far, "" for the default.
+            presets: Families always offered, keyed by family name.
+
+        """
+        self.page = page
+        self.presets = presets
+        self.dropdown = ft.Dropdown(
+            label=label,
+            width=300,
+            border_color=ft.Colors.OUTLINE_VARIANT,
+            focused_border_color=ft.Colors.PRIMARY,
+            on_change=lambda e: self._on_pick(),
+        )
+        self.name_field = ft.TextField(
+            label="Font name",
+            hint_text="As installed on this system",
+            width=240,
+            border_color=ft.Colors.OUTLINE_VARIANT,
+            focused_border_color=ft.Colors.PRIMARY,
+        )
+        self.control = ft.Row(
+            controls=[self.dropdown, self.name_field],
+            spacing=16,
+        )
+        self.families: list[str] = []
+        self.set_value(value)
+
+    @property
+    def value(self) -> str:
+        """Return the chosen family name, "" for the default."""
+        if self.dropdown.value == OTHER_FONT:
+            return (self.name_field.value or "").strip()
+        return self.dropdown.value or ""
+
+    def set_value(self, value: str) -> None:
+        """Show a family name, as a listed choice if it is one."""
+        self.dropdown.options = [
+            *(ft.dropdown.Option(key, label) for key, label in self.presets.items()),
+            *(ft.dropdown.Option(family, family) for family in self.families),
+            ft.dropdown.Option(OTHER_FONT, "System font…"),
+        ]
+        if value in self.presets or value in self.families:
+            self.dropdown.value = value
+            self.name_field.value = ""
+        else:
+            self.dropdown.value = OTHER_FONT
+            self.name_field.value = value
+        self._show_name_field()
+
+    def set_families(self, families: list[str]) -> None:
+        """Offer the font files found in the fonts folder too."""
+        value = self.value
+        self.families = [family for family in families if family not in self.presets]
+        self.set_value(value)
+
+    def _show_name_field(self) -> None:
+        self.name_field.visible = self.dropdown.value == OTHER_FONT
+
+    def _on_pick(self) -> None:
+        self._show_name_field()
+        self.page.update()
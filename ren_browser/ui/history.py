@@ -0,0 +1,164 @@
+"""History page for Ren Browser.
+
+Lists visited pages grouped by day, filters them by text and date range,
+and reopens them when clicked.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.history.history import day_label, group_by_day, parse_day
+
+
+def build_history_page(
+    history,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None] | None = None,
+    node_name: Callable[[str], str | None] | None = None,
+) -> ft.Control:
+    """Build the ``about:history`` page.
+
+    Args:
+        history: HistoryManager whose entries are listed.
+        on_open: Called with an address when an entry is clicked.
+        on_change: Called after the list has been filtered so it can be updated.
+        node_name: Optional lookup from destination hash to node name, used when
+            searching.
+
+    Returns:
+        ft.Control: The history page.
+
+    """
+    results = ft.Column(spacing=4)
+    search_field = ft.TextField(
+        hint_text="Search by title, address or node name",
+        prefix_icon=ft.Icons.SEARCH,
+        dense=True,
+        expand=True,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    start_field = ft.TextField(
+        label="From",
+        hint_text="YYYY-MM-DD",
+        dense=True,
+        width=140,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    end_field = ft.TextField(
+        label="To",
+        hint_text="YYYY-MM-DD",
+        dense=True,
+        width=140,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    def read_day(field):
+        try:
+            day = parse_day(field.value or "")
+        except ValueError:
+            field.error_text = "Use YYYY-MM-DD"
+            return None, False
+        field.error_text = None
+        return day, True
+
+    def render():
+        start, start_ok = read_day(start_field)
+        end, end_ok = read_day(end_field)
+        if not (start_ok and end_ok):
+            results.controls = []
+            return
+        query = search_field.value or ""
+        filtering = bool(query.strip() or start or end)
+        if filtering:
+            entries = history.search(query, start, end, node_name)
+        else:
+            entries = history.entries()
+        controls: list[ft.Control] = []
+        if not entries:
+            controls.append(
+                ft.Text(
+                    "No visits match your search."
+                    if filtering
+                    else "Pages you visit will be listed here.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        for day, day_entries in group_by_day(entries):
+            controls.append(
+                ft.Container(
+                    content=ft.Text(
+                        day_label(day),
+                        size=16,
+                        weight=ft.FontWeight.BOLD,
+                    ),
+                    padding=ft.padding.only(top=12),
+                ),
+            )
+            controls.extend(_build_entry(entry, on_open) for entry in day_entries)
+        results.controls = controls
+
+    def on_filter(_):
+        render()
+        if on_change:
+            on_change()
+
+    search_field.on_change = on_filter
+    start_field.on_submit = on_filter
+    start_field.on_blur = on_filter
+    end_field.on_submit = on_filter
+    end_field.on_blur = on_filter
+
+    render()
+    return ft.Column(
+        spacing=4,
+        controls=[
+            ft.Text(
+                "History",
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+            ft.Row(controls=[search_field, start_field, end_field], spacing=8),
+            results,
+        ],
+    )
+
+
+def _build_entry(entry, on_open: Callable[[str], None]) -> ft.Control:
+    visited = datetime.fromtimestamp(entry.timestamp)
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                ft.Text(
+                    visited.strftime("%H:%M"),
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                    width=48,
+                ),
+                ft.Text(
+                    entry.title,
+                    size=14,
+                    weight=ft.FontWeight.W_500,
+                    overflow=ft.TextOverflow.ELLIPSIS,
+                ),
+                ft.Text(
+                    entry.address,
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                    overflow=ft.TextOverflow.ELLIPSIS,
+                    expand=True,
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=8),
+        border_radius=8,
+        ink=True,
+        on_click=lambda e, address=entry.address: on_open(address),
+    )
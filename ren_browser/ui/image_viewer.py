@@ -0,0 +1,121 @@
+"""Image viewer for Ren Browser.
+
+Shows a downloaded or local image in a tab, fitted to the window or at a
+chosen zoom, so it can be looked at without opening it from disk.
+"""
+
+import base64
+
+import flet as ft
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.images.images import image_size
+
+IMAGE_ZOOM_LEVELS = (0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 8.0)
+
+
+class ImageViewer:
+    """Tab content showing one image with zoom and fit controls."""
+
+    def __init__(self, data: bytes, name: str, on_change=None):
+        """Build the viewer.
+
+        Args:
+            data: Image file content.
+            name: File name shown above the image.
+            on_change: Called after the zoom changed so the page can update.
+
+        """
+        self.size = image_size(data)
+        self.on_change = on_change
+        # None fits the image to the width of the window
+        self.zoom: float | None = None
+        self.image = ft.Image(
+            src_base64=base64.b64encode(data).decode("ascii"),
+            fit=ft.ImageFit.CONTAIN,
+            gapless_playback=True,
+        )
+        self.zoom_text = ft.Text("", size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+        self.zoom_out_button = ft.IconButton(
+            ft.Icons.ZOOM_OUT,
+            tooltip=tr("Zoom Out"),
+            on_click=lambda e: self.step(-1),
+        )
+        self.zoom_in_button = ft.IconButton(
+            ft.Icons.ZOOM_IN,
+            tooltip=tr("Zoom In"),
+            on_click=lambda e: self.step(1),
+        )
+        details = name
+        if self.size is not None:
+            details = tr(
+                "{name}, {width} × {height} pixels",
+                name=name,
+                width=self.size[0],
+                height=self.size[1],
+            )
+        self.control = ft.Column(
+            spacing=8,
+            controls=[
+                ft.Row(
+                    spacing=4,
+                    controls=[
+                        ft.Text(
+                            details,
+                            size=13,
+                            expand=True,
+                            overflow=ft.TextOverflow.ELLIPSIS,
+                        ),
+                        self.zoom_text,
+                        self.zoom_out_button,
+                        self.zoom_in_button,
+                        ft.TextButton(
+                            tr("Fit"),
+                            icon=ft.Icons.FIT_SCREEN,
+                            on_click=lambda e: self.set_zoom(None),
+                        ),
+                        ft.TextButton(
+                            "100%",
+                            tooltip=tr("Actual Size"),
+                            disabled=self.size is None,
+                            on_click=lambda e: self.set_zoom(1.0),
+                        ),
+                    ],
+                ),
+                ft.Row(controls=[self.image], scroll=ft.ScrollMode.AUTO),
+            ],
+        )
+        self._apply()
+
+    def set_zoom(self, zoom: float | None) -> None:
+        """Show the image at a zoom factor, or fitted to the window for None."""
+        self.zoom = zoom
+        self._apply()
+        if self.on_change:
+            self.on_change()
+
+    def step(self, direction: int) -> None:
+        """Zoom to the next level in or, for a negative direction, out."""
+        current = 1.0 if self.zoom is None else self.zoom
+        if direction > 0:
+            levels = [level for level in IMAGE_ZOOM_LEVELS if level > current + 1e-6]
+            zoom = levels[0] if levels else IMAGE_ZOOM_LEVELS[-1]
+        else:
+            levels = [level for level in IMAGE_ZOOM_LEVELS if level < current - 1e-6]
+            zoom = levels[-1] if levels else IMAGE_ZOOM_LEVELS[0]
+        self.set_zoom(zoom)
+
+    def _apply(self) -> None:
+        unsized = self.size is None
+        if self.zoom is None or unsized:
+            self.image.width = None
+            self.image.height = None
+            self.image.expand = True
+            self.zoom_text.value = tr("Fitted")
+        else:
+            self.image.width = self.size[0] * self.zoom
+            self.image.height = self.size[1] * self.zoom
+            self.image.expand = False
+            self.zoom_text.value = f"{self.zoom:.0%}"
+        self.zoom_out_button.disabled = unsized or self.zoom == IMAGE_ZOOM_LEVELS[0]
+        self.zoom_in_button.disabled = unsized or self.zoom == IMAGE_ZOOM_LEVELS[-1]
@@ -0,0 +1,177 @@
+"""Network interface panel for Ren Browser.
+
+Lists the Reticulum interfaces of the backend with their type, state,
+bitrate and traffic, opened from the connection indicator, so whether a
+LoRa radio or TCP link is up can be told without leaving the browser.
+The traffic rates are worked out from the byte counts of one status check
+to the next while the panel is open.
+"""
+
+import time
+
+import flet as ft
+
+from ren_browser import rns
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.ui.downloads import format_size
+
+
+def format_bitrate(bitrate: int | None) -> str | None:
+    """Return a human readable bitrate, or None if it is not known."""
+    if not bitrate:
+        return None
+    if bitrate >= 1_000_000:
+        return f"{bitrate / 1_000_000:.1f} Mbps"
+    if bitrate >= 1_000:
+        return f"{bitrate / 1_000:.0f} kbps"
+    return f"{bitrate} bps"
+
+
+def traffic_label(total: int | None, rate: float | None = None) -> str:
+    """Return the bytes moved over an interface, with the rate if known."""
+    label = format_size(total or 0)
+    if rate is not None:
+        label += f" ({format_size(round(rate))}/s)"
+    return label
+
+
+def interface_details(status: dict) -> list[str]:
+    """Return the details shown under an interface's name."""
+    details = [status["type"], tr("Online") if status["online"] else tr("Offline")]
+    bitrate = format_bitrate(status.get("bitrate"))
+    if bitrate:
+        details.append(bitrate)
+    if status.get("rssi") is not None:
+        details.append(f"RSSI {status['rssi']} dBm")
+    if status.get("snr") is not None:
+        details.append(f"SNR {status['snr']} dB")
+    return details
+
+
+class InterfacePanel:
+    """Dialog listing the transport interfaces and their traffic."""
+
+    def __init__(self, page: ft.Page):
+        """Build the panel.
+
+        Args:
+            page: Flet page the panel is shown on.
+
+        """
+        self.page = page
+        self.is_open = False
+        self.statuses: list[dict] = []
+        # Time and byte counts of each interface at the previous status check
+        self._previous: dict[str, tuple[float, int, int]] = {}
+        self._rates: dict[str, tuple[float, float]] = {}
+        self.rows = ft.Column(tight=True, spacing=4, scroll=ft.ScrollMode.AUTO)
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("Network Interfaces")),
+            content=ft.Container(content=self.rows, width=460),
+            actions=[
+                ft.TextButton(
+                    tr("Refresh"),
+                    icon=ft.Icons.REFRESH,
+                    on_click=lambda e: self.refresh(),
+                ),
+                ft.TextButton(tr("Close"), on_click=lambda e: self.close()),
+            ],
+            on_dismiss=lambda e: self._dismissed(),
+        )
+
+    def open(self) -> None:
+        """Show the panel with the interfaces as they are now."""
+        self.is_open = True
+        self._previous = {}
+        self._rates = {}
+        self.refresh()
+        open_dialog(self.page, self.dialog)
+
+    def close(self) -> None:
+        """Close the panel."""
+        self._dismissed()
+        self.page.close(self.dialog)
+
+    def refresh(self) -> None:
+        """Check the interfaces again."""
+        try:
+            statuses = rns.get_interface_statuses()
+        except Exception:  # noqa: BLE001
+            statuses = []
+        self.set_statuses(statuses)
+        self.page.update()
+
+    def set_statuses(self, statuses: list[dict]) -> None:
+        """Show interface statuses, as the status bar checks them."""
+        self.statuses = statuses
+        if not self.is_open:
+            return
+        now = time.monotonic()
+        for status in statuses:
+            name = status["name"]
+            rxb, txb = status.get("rxb") or 0, status.get("txb") or 0
+            if name in self._previous:
+                then, old_rxb, old_txb = self._previous[name]
+                if now > then:
+                    self._rates[name] = (
+                        max(rxb - old_rxb, 0) / (now - then),
+                        max(txb - old_txb, 0) / (now - then),
+                    )
+            self._previous[name] = (now, rxb, txb)
+        self.rows.controls = [self._build_row(status) for status in statuses] or [
+            ft.Text(
+                tr("No interfaces detected"),
+                size=13,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+        ]
+
+    def _dismissed(self) -> None:
+        self.is_open = False
+
+    def _build_row(self, status: dict) -> ft.Control:
+        rx_rate, tx_rate = self._rates.get(status["name"], (None, None))
+        traffic = tr(
+            "Received {received}, sent {sent}",
+            received=traffic_label(status.get("rxb"), rx_rate),
+            sent=traffic_label(status.get("txb"), tx_rate),
+        )
+        return ft.Container(
+            content=ft.Row(
+                vertical_alignment=ft.CrossAxisAlignment.START,
+                spacing=12,
+                controls=[
+                    ft.Icon(
+                        ft.Icons.CIRCLE,
+                        size=12,
+                        color=(
+                            ft.Colors.GREEN if status["online"] else ft.Colors.ERROR
+                        ),
+                    ),
+                    ft.Column(
+                        spacing=2,
+                        expand=True,
+                        controls=[
+                            ft.Text(
+                                status["name"],
+                                size=14,
+                                weight=ft.FontWeight.W_500,
+                                selectable=True,
+                            ),
+                            ft.Text(
+                                "  ·  ".join(interface_details(status)),
+                                size=12,
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                            ),
+                            ft.Text(
+                                traffic,
+                                size=12,
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                            ),
+                        ],
+                    ),
+                ],
+            ),
+            padding=ft.padding.symmetric(vertical=6),
+        )
@@ -0,0 +1,107 @@
+"""On-screen keyboard handling for Ren Browser.
+
+Android reports the on-screen keyboard as an inset at the bottom of the
+view, and draws it over whatever is there. While it is open the window's
+content is padded by its height so the layout shrinks above it, the panels
+drawn over the page are lifted with it, and a focused field inside a
+scrolling page is scrolled back into view.
+"""
+
+import weakref
+
+import flet as ft
+
+
+class KeyboardInsets:
+    """Keeps focused inputs clear of the on-screen keyboard."""
+
+    def __init__(self, page: ft.Page, zoom):
+        """Follow the keyboard inset reported for the page.
+
+        Args:
+            page: Flet page whose media changes are followed.
+            zoom: UiZoom scaling the root control that is padded.
+
+        """
+        self.page = page
+        self.zoom = zoom
+        self.height = 0.0
+        self.focused: tuple[ft.Control, ft.Control | None] | None = None
+        self._anchored: list[tuple[ft.Control, float]] = []
+        self._followed = weakref.WeakSet()
+        page.on_media_change = self.on_media_change
+
+    def anchor(self, control: ft.Control) -> None:
+        """Lift an overlay positioned from the bottom along with the keyboard."""
+        self._anchored.append((control, control.bottom or 0))
+
+    def follow(self, field: ft.Control, scrollable: ft.Control | None = None) -> None:
+        """Remember when a field has focus, to scroll it into view.
+
+        Args:
+            field: Text field to follow.
+            scrollable: Scrolling control the field is in, if any.
+
+        """
+        if field in self._followed:
+            return
+        self._followed.add(field)
+        if field.key is None:
+            field.key = f"keyboard-{id(field)}"
+        on_focus, on_blur = field.on_focus, field.on_blur
+
+        def focus(e):
+            self.focused = (field, scrollable)
+            self.reveal()
+            if on_focus:
+                on_focus(e)
+
+        def blur(e):
+            if self.focused is not None and self.focused[0] is field:
+                self.focused = None
+            if on_blur:
+                on_blur(e)
+
+        field.on_focus = focus
+        field.on_blur = blur
+
+    def follow_fields(self, control: ft.Control, scrollable: ft.Control) -> None:
+        """Follow every text field in a control tree inside a scrolling control."""
+        if isinstance(control, ft.TextField):
+            self.follow(control, scrollable)
+            return
+        children = list(getattr(control, "controls", None) or [])
+        content = getattr(control, "content", None)
+        if isinstance(content, ft.Control):
+            children.append(content)
+        for child in children:
+            self.follow_fields(child, scrollable)
+
+    def on_media_change(self, e) -> None:  # type: ignore
+        """Take the keyboard height from the view's bottom inset."""
+        insets = e.view_insets
+        self.set_height(insets.bottom if insets is not None else 0)
+
+    def set_height(self, height: float | None) -> None:
+        """Make room for a keyboard of the given height, 0 when it is closed."""
+        height = max(height or 0, 0)
+        if height == self.height:
+            return
+        self.height = height
+        if self.zoom.root is not None:
+            # The root is scaled by the zoom, the overlays are not
+            self.zoom.root.padding = (
+                ft.padding.only(bottom=height / self.zoom.factor) if height else None
+            )
+        for control, bottom in self._anchored:
+            control.bottom = bottom + height
+        self.reveal()
+        self.page.update()
+
+    def reveal(self) -> None:
+        """Scroll the focused field into view while the keyboard is open."""
+        if not self.height or self.focused is None:
+            return
+        field, scrollable = self.focused
+        if scrollable is not None:
+            scrollable.scroll_to(key=field.key, duration=200)
@@ -0,0 +1,117 @@
+"""App lifecycle handling for Ren Browser.
+
+When the app goes to the background, as on Android when another app is
+opened, the background loops stop, the session is saved and the rendered
+pages are released. When the app comes back the loops restart and the page
+of the active tab is built again, so the window is redrawn from scratch
+instead of being left black.
+
+On the desktop a window that loses focus or is minimized only idles: the
+node list, the interface status and the performance overlay stop
+refreshing and nothing is prefetched until the window has focus again.
+Watched pages and the node feed are still checked, so their notifications
+arrive while the browser is in the background.
+"""
+
+import flet as ft
+
+# Inactive is left out, as it is also reported for the notification shade
+# or a system dialog over the app.
+PAUSE_STATES = (ft.AppLifecycleState.HIDE, ft.AppLifecycleState.PAUSE)
+RESUME_STATES = (ft.AppLifecycleState.SHOW, ft.AppLifecycleState.RESUME)
+IDLE_EVENTS = (ft.WindowEventType.BLUR, ft.WindowEventType.MINIMIZE)
+WAKE_EVENTS = (ft.WindowEventType.FOCUS, ft.WindowEventType.RESTORE)
+
+
+class AppLifecycle:
+    """Suspends the browser in the background and resumes it on return."""
+
+    def __init__(self, page: ft.Page, tab_manager, settings_watcher=None):
+        """Listen for lifecycle changes of the page.
+
+        Args:
+            page: Flet page whose lifecycle is followed.
+            tab_manager: Tab manager with the components to suspend.
+            settings_watcher: SettingsWatcher to stop in the background.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.settings_watcher = settings_watcher
+        self.suspended = False
+        self.idle = False
+        page.on_app_lifecycle_state_change = self.on_change
+
+    def on_change(self, e) -> None:
+        """Suspend or resume for a lifecycle state change."""
+        if e.state in PAUSE_STATES:
+            self.suspend()
+        elif e.state in RESUME_STATES:
+            self.resume()
+
+    def on_window_event(self, e) -> None:
+        """Idle or wake up for a focus change of the desktop window."""
+        if e.type in IDLE_EVENTS:
+            self.go_idle()
+        elif e.type in WAKE_EVENTS:
+            self.wake()
+
+    def go_idle(self) -> None:
+        """Stop refreshing while the window is not used, if the settings allow."""
+        if self.idle or self.suspended:
+            return
+        if not self.tab_manager.settings.get("pause_unfocused", True):
+            return
+        self.idle = True
+        tab_manager = self.tab_manager
+        tab_manager.idle = True
+        tab_manager.prefetcher.cancel()
+        tab_manager.announce_list.pause()
+        tab_manager.status_bar.stop()
+        tab_manager.performance_overlay.pause()
+
+    def wake(self) -> None:
+        """Refresh again once the window is used."""
+        if not self.idle:
+            return
+        self.idle = False
+        tab_manager = self.tab_manager
+        tab_manager.idle = False
+        if self.suspended:
+            return
+        tab_manager.announce_list.resume()
+        tab_manager.status_bar.refresh_connection()
+        tab_manager.status_bar.start()
+        tab_manager.performance_overlay.resume()
+        self.page.update()
+
+    def suspend(self) -> None:
+        """Stop background work and drop the rendered pages."""
+        if self.suspended:
+            return
+        self.suspended = True
+        tab_manager = self.tab_manager
+        tab_manager.suspend()
+        tab_manager.announce_list.pause()
+        tab_manager.status_bar.stop()
+        tab_manager.performance_overlay.pause()
+        if self.settings_watcher is not None:
+            self.settings_watcher.stop()
+
+    def resume(self) -> None:
+        """Restart background work and redraw the window."""
+        if not self.suspended:
+            return
+        self.suspended = False
+        self.idle = False
+        tab_manager = self.tab_manager
+        tab_manager.idle = False
+        tab_manager.resume()
+        tab_manager.announce_list.resume()
+        tab_manager.status_bar.refresh_connection()
+        tab_manager.status_bar.start()
+        tab_manager.performance_overlay.resume()
+        if self.settings_watcher is not None:
+            self.settings_watcher.check()
+            self.settings_watcher.start()
+        self.page.update()
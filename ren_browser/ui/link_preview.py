@@ -0,0 +1,172 @@
+"""Link previews for Ren Browser.
+
+When the pointer rests on a link, shows the first few lines of the target
+page in a small popover, read from the page cache when possible so the
+user can judge whether a slow fetch over the mesh is worth it.
+"""
+
+import time
+
+import flet as ft
+
+from ren_browser.downloads.downloads import is_file_address
+from ren_browser.pages.address import (
+    AddressError,
+    internal_page_name,
+    parse_address,
+    resolve_link,
+)
+from ren_browser.pages.page_request import PageFetcher, PageRequest
+from ren_browser.renderer.markdown import markdown_to_text
+from ren_browser.renderer.micron import micron_to_text
+
+PREVIEW_DELAY = 0.8
+PREVIEW_LINES = 6
+PREVIEW_WIDTH = 360
+
+
+def preview_lines(
+    content: str,
+    page_path: str,
+    max_lines: int = PREVIEW_LINES,
+) -> list[str]:
+    """Return the first non-blank lines of a page's readable text."""
+    if page_path.endswith(".mu"):
+        text = micron_to_text(content)
+    elif page_path.endswith(".md"):
+        text = markdown_to_text(content)
+    else:
+        text = content
+    lines = [line.strip() for line in text.splitlines() if line.strip()]
+    return lines[:max_lines]
+
+
+class LinkPreview:
+    """Popover previewing the page behind the hovered link."""
+
+    def __init__(self, page: ft.Page, tab_manager, delay: float = PREVIEW_DELAY):
+        """Initialize the popover.
+
+        Args:
+            page: Flet page instance the popover is overlaid on.
+            tab_manager: Tab manager whose cache is read and filled.
+            delay: Seconds the pointer must rest on a link before previewing.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.delay = delay
+        self.address: str | None = None
+        self._hover_id: object | None = None
+        self.title = ft.Text(
+            "",
+            size=12,
+            color=ft.Colors.ON_SURFACE_VARIANT,
+            overflow=ft.TextOverflow.ELLIPSIS,
+        )
+        self.body = ft.Column(spacing=2)
+        self.panel = ft.Container(
+            visible=False,
+            width=PREVIEW_WIDTH,
+            left=16,
+            bottom=40,
+            padding=ft.padding.all(12),
+            border_radius=8,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+            content=ft.Column(spacing=6, controls=[self.title, self.body]),
+        )
+        page.overlay.append(self.panel)
+
+    @property
+    def is_open(self) -> bool:
+        """Return whether the popover is showing."""
+        return bool(self.panel.visible)
+
+    def hover(self, link: str | None, current_hash: str | None = None) -> None:
+        """Preview a link after the delay, or hide the popover if link is None.
+
+        Args:
+            link: Hovered link target, possibly relative to current_hash.
+            current_hash: Destination hash of the page containing the link.
+
+        """
+        self._hover_id = None
+        if not link:
+            self.hide()
+            return
+        url = resolve_link(link, current_hash)
+        if internal_page_name(url) is not None:
+            return
+        try:
+            address = parse_address(url, self.tab_manager.resolve_node_name)
+        except AddressError:
+            return
+        if is_file_address(address.page_path):
+            return
+        hover_id = object()
+        self._hover_id = hover_id
+        self.page.run_thread(lambda: self._load(hover_id, address))
+
+    def hide(self) -> None:
+        """Hide the popover."""
+        self._hover_id = None
+        self.address = None
+        if self.panel.visible:
+            self.panel.visible = False
+            self.page.update()
+
+    def _load(self, hover_id: object, address) -> None:
+        time.sleep(self.delay)
+        if self._hover_id is not hover_id:
+            return
+        url = str(address)
+        if self.tab_manager.nodes.is_blocked(address.destination_hash):
+            self._show_message(hover_id, url, "This node is blocked.")
+            return
+        cached = self.tab_manager.page_cache.get(url, allow_stale=True)
+        if cached is not None:
+            self._show(hover_id, url, address.page_path, cached.content)
+            return
+        self._show_message(hover_id, url, "Fetching preview…")
+        req = PageRequest(
+            destination_hash=address.destination_hash,
+            page_path=address.page_path,
+        )
+        try:
+            content = PageFetcher().fetch_page(req)
+        except Exception as exc:  # noqa: BLE001
+            self._show_message(hover_id, url, f"No preview: {exc}")
+            return
+        self.tab_manager.page_cache.put(url, content)
+        self._show(hover_id, url, address.page_path, content)
+
+    def _show(self, hover_id, url: str, page_path: str, content: str) -> None:
+        lines = preview_lines(content, page_path)
+        if not lines:
+            self._show_message(hover_id, url, "This page is empty.")
+            return
+        self._open(
+            hover_id,
+            url,
+            [
+                ft.Text(line, size=13, max_lines=1, overflow=ft.TextOverflow.ELLIPSIS)
+                for line in lines
+            ],
+        )
+
+    def _show_message(self, hover_id, url: str, message: str) -> None:
+        self._open(
+            hover_id,
+            url,
+            [ft.Text(message, size=13, italic=True, color=ft.Colors.GREY_500)],
+        )
+
+    def _open(self, hover_id, url: str, controls: list[ft.Control]) -> None:
+        if self._hover_id is not hover_id:
+            return
+        self.address = url
+        self.title.value = url
+        self.body.controls = controls
+        self.panel.visible = True
+        self.page.update()
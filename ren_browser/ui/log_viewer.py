@@ -0,0 +1,128 @@
+"""Log viewer for Ren Browser.
+
+The ``about:logs`` page lists the recent messages of the browser and of
+Reticulum, newest first, so what went wrong can be looked into on phones
+and without a terminal. Chips narrow the list to some levels, a search box
+to messages containing some text, and the messages shown can be copied.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.logs import LEVELS, recent_logs
+
+# Entries beyond this are left out of the page, newest kept
+MAX_SHOWN = 500
+LEVEL_COLORS = {
+    "error": ft.Colors.ERROR,
+    "warning": ft.Colors.AMBER_400,
+    "info": ft.Colors.ON_SURFACE,
+    "debug": ft.Colors.ON_SURFACE_VARIANT,
+}
+
+
+def build_logs_page(
+    on_copy: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:logs`` page.
+
+    Args:
+        on_copy: Called with the text of the messages shown to copy it.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The log viewer page.
+
+    """
+    levels = set(LEVELS)
+    page = ft.Column(spacing=8)
+    chips = ft.Row(spacing=4, wrap=True)
+    entries_column = ft.Column(spacing=2)
+    summary = ft.Text("", size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+    search_field = ft.TextField(
+        hint_text="Search logs",
+        prefix_icon=ft.Icons.SEARCH,
+        dense=True,
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+        on_change=lambda e: redraw(),
+    )
+
+    def shown():
+        return recent_logs(levels, search_field.value or "")[-MAX_SHOWN:]
+
+    def toggle_level(level):
+        levels.symmetric_difference_update({level})
+        redraw()
+
+    def copy():
+        on_copy("\n".join(entry.format() for entry in shown()))
+
+    def redraw():
+        render()
+        on_change()
+
+    def render():
+        chips.controls = [
+            ft.Chip(
+                label=ft.Text(label, size=12),
+                selected=level in levels,
+                on_select=lambda e, level=level: toggle_level(level),
+            )
+            for level, label in LEVELS.items()
+        ]
+        entries = shown()
+        summary.value = f"{len(entries)} message{'s' if len(entries) != 1 else ''}"
+        entries_column.controls = [
+            ft.Text(
+                entry.format(),
+                size=12,
+                font_family="monospace",
+                color=LEVEL_COLORS.get(entry.level),
+                selectable=True,
+            )
+            for entry in reversed(entries)
+        ] or [
+            ft.Text(
+                "No messages match.",
+                size=14,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+        ]
+
+    page.controls = [
+        ft.Row(
+            controls=[
+                ft.Text(
+                    "Logs",
+                    size=24,
+                    weight=ft.FontWeight.BOLD,
+                    color=ft.Colors.PRIMARY,
+                    expand=True,
+                ),
+                ft.IconButton(
+                    ft.Icons.REFRESH,
+                    tooltip="Show new messages",
+                    on_click=lambda e: redraw(),
+                ),
+                ft.IconButton(
+                    ft.Icons.COPY_ALL,
+                    tooltip="Copy the messages shown",
+                    on_click=lambda e: copy(),
+                ),
+            ],
+        ),
+        ft.Row(
+            controls=[search_field, chips],
+            spacing=12,
+            wrap=True,
+            vertical_alignment=ft.CrossAxisAlignment.CENTER,
+        ),
+        summary,
+        entries_column,
+    ]
+    render()
+    return page
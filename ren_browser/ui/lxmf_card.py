@@ -0,0 +1,88 @@
+"""LXMF address card for Ren Browser.
+
+Pages link to ``lxmf@<hash>`` addresses so visitors can message the node's
+operator. The browser cannot send LXMF messages, so such a link opens a
+card with the address to copy into a messaging app instead of a page load.
+"""
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.ui.notify import show_snack
+
+MESSAGING_ASPECT = "lxmf.delivery"
+
+
+class LxmfAddressCard:
+    """Dialog showing an LXMF address to copy."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the card.
+
+        Args:
+            page: Flet page the card is shown on.
+            tab_manager: Tab manager whose announces name the address.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.destination_hash: str | None = None
+        self.hash_text = ft.Text(size=13, font_family="monospace", selectable=True)
+        self.name_text = ft.Text(size=14, weight=ft.FontWeight.W_500)
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("LXMF Address")),
+            content=ft.Column(
+                tight=True,
+                spacing=12,
+                width=380,
+                controls=[
+                    self.name_text,
+                    self.hash_text,
+                    ft.Text(
+                        tr(
+                            "Ren Browser cannot send messages. Copy the address "
+                            "into Sideband, MeshChat or NomadNet to write to it.",
+                        ),
+                        size=12,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                    ),
+                ],
+            ),
+            actions=[
+                ft.TextButton(tr("Copy Address"), on_click=lambda e: self.copy()),
+                ft.TextButton(
+                    tr("Close"),
+                    on_click=lambda e: self.page.close(self.dialog),
+                ),
+            ],
+        )
+
+    def show(self, destination_hash: str) -> None:
+        """Open the card for an LXMF destination hash."""
+        self.destination_hash = destination_hash
+        name = self.announced_name(destination_hash)
+        self.name_text.value = name or ""
+        self.name_text.visible = bool(name)
+        self.hash_text.value = f"<{destination_hash}>"
+        open_dialog(self.page, self.dialog)
+
+    def announced_name(self, destination_hash: str) -> str | None:
+        """Return the name the address announced for messaging, if heard."""
+        service = self.tab_manager.announce_service
+        if service is None:
+            return None
+        for ann in service.get_announces():
+            if (
+                ann.destination_hash == destination_hash
+                and ann.aspect == MESSAGING_ASPECT
+                and ann.display_name
+            ):
+                return ann.display_name
+        return None
+
+    def copy(self) -> None:
+        """Put the address on the clipboard."""
+        if self.destination_hash:
+            self.page.set_clipboard(self.destination_hash)
+            show_snack(self.page, tr("LXMF address copied"))
@@ -0,0 +1,308 @@
+"""Node detail panel for Ren Browser.
+
+Shows everything known about a node from its announces, the path to it and
+the user's own visits, alias and notes, with quick actions to open it, browse
+its files, copy its hash, show it as a QR code, block it or remove it if it
+was added by hash.
+How the node's pages are shown can be changed here too.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.announces.announces import path_info
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.history.history import visit_summary
+from ren_browser.nodes.nodes import NodeOverrides
+from ren_browser.pages.address import DEFAULT_PAGE_PATH, Address
+from ren_browser.ui.avatars import build_node_avatar
+from ren_browser.ui.file_area import FileArea
+from ren_browser.ui.notify import show_snack
+from ren_browser.ui.qr_code import QrCodeDialog
+from ren_browser.ui.zoom import ZOOM_LEVELS
+
+DEFAULT_PAGE_ZOOM = ""
+
+
+def _format_time(timestamp: float) -> str:
+    return datetime.fromtimestamp(timestamp).strftime("%Y-%m-%d %H:%M")
+
+
+def detail_rows(ann, stats, path) -> list[tuple[str, str]]:
+    """Return the labelled facts shown for a node.
+
+    Args:
+        ann: Announce of the node. A zero timestamp means it has not been
+            heard this session.
+        stats: NodeStats of the node, or None if it was never visited.
+        path: PathInfo for the node.
+
+    Returns:
+        ``(label, value)`` pairs in display order.
+
+    """
+    heard = bool(ann.timestamp)
+    if path.hops is None:
+        route = "No known path"
+    else:
+        hops = "1 hop" if path.hops == 1 else f"{path.hops} hops"
+        route = f"{hops} via {path.interface}" if path.interface else hops
+    return [
+        ("Destination", ann.destination_hash),
+        ("Identity", ann.identity_hash or "Unknown"),
+        ("Aspect", ann.aspect),
+        (
+            "First seen",
+            _format_time(ann.first_seen or ann.timestamp)
+            if heard
+            else "Not heard this session",
+        ),
+        (
+            "Last announce",
+            _format_time(ann.timestamp) if heard else "Not heard this session",
+        ),
+        ("Announces", str(ann.announce_count) if heard else "0"),
+        ("Path", route),
+        ("Visits", visit_summary(stats) or "Never visited"),
+    ]
+
+
+class NodeDetails:
+    """Dialog showing the details of a node."""
+
+    def __init__(
+        self,
+        page: ft.Page,
+        tab_manager,
+        on_open: Callable,
+        on_change: Callable[[], None] | None = None,
+    ):
+        """Initialize the panel.
+
+        Args:
+            page: Flet page the panel is shown on.
+            tab_manager: Tab manager holding the visit history and node metadata.
+            on_open: Called with the announce when Open is clicked.
+            on_change: Called after the node's alias or note was changed or
+                it was blocked or removed.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.on_open = on_open
+        self.on_change = on_change
+        self.announce = None
+        self.qr_code = QrCodeDialog(page)
+        self.file_area = FileArea(page, tab_manager)
+        self.remove_button = ft.TextButton(
+            "Remove",
+            visible=False,
+            on_click=lambda e: self.remove(),
+        )
+        self.rows = ft.Column(tight=True, spacing=8)
+        self.alias_field = ft.TextField(
+            label="Alias",
+            hint_text="Shown instead of the announced name",
+            on_blur=lambda e: self.save_alias(),
+        )
+        self.note_field = ft.TextField(
+            label="Notes",
+            multiline=True,
+            min_lines=2,
+            max_lines=6,
+            on_blur=lambda e: self.save_note(),
+        )
+        self.plain_text_switch = ft.Switch(
+            label="Show pages as plain text",
+            on_change=lambda e: self.save_overrides(),
+        )
+        self.bypass_cache_switch = ft.Switch(
+            label="Always fetch pages, never from the cache",
+            on_change=lambda e: self.save_overrides(),
+        )
+        self.zoom_dropdown = ft.Dropdown(
+            label="Page zoom",
+            options=[
+                ft.dropdown.Option(DEFAULT_PAGE_ZOOM, "Default"),
+                *(
+                    ft.dropdown.Option(str(level), f"{round(level * 100)}%")
+                    for level in ZOOM_LEVELS
+                ),
+            ],
+            width=200,
+            on_change=lambda e: self.save_overrides(),
+        )
+        self.body = ft.Column(
+            tight=True,
+            spacing=16,
+            width=420,
+            scroll=ft.ScrollMode.AUTO,
+            controls=[
+                self.rows,
+                self.alias_field,
+                self.note_field,
+                ft.Text("Pages from this node", size=14, weight=ft.FontWeight.BOLD),
+                self.plain_text_switch,
+                self.bypass_cache_switch,
+                self.zoom_dropdown,
+            ],
+        )
+        self.dialog = ft.AlertDialog(
+            title=ft.Text("Node"),
+            content=self.body,
+            actions=[
+                self.remove_button,
+                ft.TextButton("Block", on_click=lambda e: self.block()),
+                ft.TextButton("Files", on_click=lambda e: self.show_files()),
+                ft.TextButton("Copy Hash", on_click=lambda e: self.copy_hash()),
+                ft.TextButton("Show QR", on_click=lambda e: self.show_qr()),
+                ft.TextButton("Close", on_click=lambda e: self.close()),
+                ft.FilledButton("Open", on_click=lambda e: self.open()),
+            ],
+        )
+
+    def show(self, ann) -> None:
+        """Open the panel for a node."""
+        self.announce = ann
+        self.render()
+        open_dialog(self.page, self.dialog)
+
+    def render(self) -> None:
+        """Fill the panel with the current node's details."""
+        ann = self.announce
+        stats = self.tab_manager.history.node_stats().get(ann.destination_hash)
+        rows = detail_rows(ann, stats, path_info(ann.destination_hash))
+        info = self.tab_manager.nodes.get(ann.destination_hash)
+        self.dialog.title = ft.Row(
+            controls=[
+                build_node_avatar(ann.destination_hash, size=28),
+                ft.Text(
+                    info.alias or ann.display_name or "Anonymous",
+                    expand=True,
+                    overflow=ft.TextOverflow.ELLIPSIS,
+                ),
+            ],
+            spacing=12,
+        )
+        self.alias_field.value = info.alias or ""
+        self.note_field.value = info.note or ""
+        self.remove_button.visible = info.added
+        self.plain_text_switch.value = info.overrides.plain_text
+        self.bypass_cache_switch.value = info.overrides.bypass_cache
+        self.zoom_dropdown.value = (
+            str(info.overrides.zoom) if info.overrides.zoom else DEFAULT_PAGE_ZOOM
+        )
+        self.rows.controls = [
+            ft.Row(
+                controls=[
+                    ft.Text(
+                        label,
+                        size=12,
+                        width=110,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                    ),
+                    ft.Text(value, size=13, selectable=True, expand=True),
+                ],
+                vertical_alignment=ft.CrossAxisAlignment.START,
+            )
+            for label, value in rows
+        ]
+
+    def save_note(self) -> None:
+        """Store what was typed into the notes field."""
+        if self.announce is None:
+            return
+        nodes = self.tab_manager.nodes
+        destination_hash = self.announce.destination_hash
+        before = nodes.get(destination_hash).note
+        nodes.set_note(
+            destination_hash,
+            self.note_field.value,
+            self.announce.display_name,
+        )
+        if nodes.get(destination_hash).note != before and self.on_change:
+            self.on_change()
+
+    def save_alias(self) -> None:
+        """Store what was typed into the alias field."""
+        if self.announce is None:
+            return
+        nodes = self.tab_manager.nodes
+        destination_hash = self.announce.destination_hash
+        before = nodes.get(destination_hash).alias
+        nodes.set_alias(
+            destination_hash,
+            self.alias_field.value,
+            self.announce.display_name,
+        )
+        if nodes.get(destination_hash).alias != before and self.on_change:
+            self.on_change()
+
+    def save_overrides(self) -> None:
+        """Store how the node's pages are shown and redraw its open pages."""
+        if self.announce is None:
+            return
+        zoom = self.zoom_dropdown.value
+        self.tab_manager.set_node_overrides(
+            self.announce.destination_hash,
+            NodeOverrides(
+                plain_text=bool(self.plain_text_switch.value),
+                zoom=float(zoom) if zoom else None,
+                bypass_cache=bool(self.bypass_cache_switch.value),
+            ),
+            self.announce.display_name,
+        )
+
+    def copy_hash(self) -> None:
+        """Put the node's full destination hash on the clipboard."""
+        self.page.set_clipboard(self.announce.destination_hash)
+        show_snack(self.page, "Node hash copied")
+
+    def show_qr(self) -> None:
+        """Show the link to the node's index page as a QR code."""
+        ann = self.announce
+        self.close()
+        address = Address(ann.destination_hash, DEFAULT_PAGE_PATH)
+        self.qr_code.show(address.url(), ann.display_name or "Anonymous")
+
+    def show_files(self) -> None:
+        """Close the panel and list the files the node shares."""
+        ann = self.announce
+        self.close()
+        alias = self.tab_manager.nodes.get(ann.destination_hash).alias
+        name = alias or ann.display_name or "Anonymous"
+        self.file_area.show(ann.destination_hash, name)
+
+    def block(self) -> None:
+        """Block the node, hiding it from the sidebar, and close the panel."""
+        ann = self.announce
+        self.close()
+        self.tab_manager.nodes.set_blocked(
+            ann.destination_hash,
+            True,
+            ann.display_name,
+        )
+        if self.on_change:
+            self.on_change()
+        show_snack(self.page, "Node blocked. Unblock it in Settings.")
+
+    def remove(self) -> None:
+        """Take a node added by hash back out of the sidebar."""
+        ann = self.announce
+        self.close()
+        self.tab_manager.nodes.remove_node(ann.destination_hash)
+        if self.on_change:
+            self.on_change()
+
+    def open(self) -> None:
+        """Close the panel and open the node's index page."""
+        self.close()
+        self.on_open(self.announce)
+
+    def close(self) -> None:
+        """Save the alias and note and close the panel."""
+        self.save_alias()
+        self.save_note()
+        self.page.close(self.dialog)
@@ -0,0 +1,64 @@
+"""Node list export dialog for Ren Browser.
+
+Asks where to save the known nodes and writes them as JSON or CSV.
+"""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.nodes.export import node_records, to_csv, to_json
+from ren_browser.ui.notify import show_snack
+
+EXPORT_FORMATS = {
+    "json": ("nodes.json", to_json),
+    "csv": ("nodes.csv", to_csv),
+}
+
+
+class NodeExport:
+    """Native file dialog for exporting the node list."""
+
+    def __init__(self, page: ft.Page, announces: Callable[[], list], nodes):
+        """Initialize the file dialog.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            announces: Returns the announces heard this session.
+            nodes: NodeMetadata holding the user's aliases, notes and groups.
+
+        """
+        self.page = page
+        self.announces = announces
+        self.nodes = nodes
+        self.export_format: str | None = None
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+
+    def choose_export(self, fmt: str) -> None:
+        """Ask where to export the nodes in a format ("json" or "csv")."""
+        file_name, _ = EXPORT_FORMATS[fmt]
+        self.export_format = fmt
+        self.picker.save_file(
+            dialog_title="Export Nodes",
+            file_name=file_name,
+            allowed_extensions=[fmt],
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        fmt, self.export_format = self.export_format, None
+        if fmt is not None and e.path:
+            self.export_to(Path(e.path), fmt)
+
+    def export_to(self, path: Path, fmt: str) -> bool:
+        """Write the known nodes to a file and report the outcome."""
+        _, serialize = EXPORT_FORMATS[fmt]
+        records = node_records(self.announces(), self.nodes)
+        try:
+            path.write_text(serialize(records), encoding="utf-8")
+        except OSError as exc:
+            show_snack(self.page, f"Failed to export nodes: {exc}", False)
+            return False
+        show_snack(self.page, f"Exported {len(records)} nodes to {path}")
+        return True
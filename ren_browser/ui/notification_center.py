@@ -0,0 +1,129 @@
+"""Notification center panel for Ren Browser.
+
+A bell in the app bar, badged with the events not seen yet, opens a list
+of the recent events so the ones shown only briefly can be read again.
+"""
+
+import time
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.notifications.notifications import (
+    KINDS,
+    Notification,
+    NotificationCenter,
+)
+from ren_browser.ui.cache import format_age
+from ren_browser.ui.notify import count_badge
+
+KIND_ICONS = {
+    "downloads": ft.Icons.DOWNLOAD_DONE,
+    "watched": ft.Icons.VISIBILITY,
+    "feed": ft.Icons.RSS_FEED,
+    "favorites": ft.Icons.STAR,
+    "error": ft.Icons.ERROR_OUTLINE,
+}
+
+
+class NotificationPanel:
+    """Bell button and dialog listing the events of the notification center."""
+
+    def __init__(
+        self,
+        page: ft.Page,
+        center: NotificationCenter,
+        on_open: Callable[[str], None],
+    ):
+        """Build the bell button and the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            center: Notification center whose events are listed.
+            on_open: Called with the address of an event that is clicked.
+
+        """
+        self.page = page
+        self.center = center
+        self.on_open = on_open
+        self.button = ft.IconButton(
+            ft.Icons.NOTIFICATIONS_OUTLINED,
+            tooltip=tr("Notifications"),
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: self.show(),
+        )
+        self.list_column = ft.Column(spacing=0, tight=True, scroll=ft.ScrollMode.AUTO)
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("Notifications")),
+            content=ft.Container(width=440, height=400, content=self.list_column),
+            actions=[
+                ft.TextButton(tr("Clear All"), on_click=lambda e: self.clear()),
+                ft.TextButton(tr("Close"), on_click=lambda e: self.close()),
+            ],
+        )
+        center.add_listener(self._on_change)
+        self.update_badge()
+
+    def update_badge(self) -> None:
+        """Show how many events were not seen yet on the bell."""
+        unread = self.center.unread
+        self.button.badge = count_badge(unread)
+        self.button.icon = (
+            ft.Icons.NOTIFICATIONS_ACTIVE if unread else ft.Icons.NOTIFICATIONS_OUTLINED
+        )
+
+    def show(self) -> None:
+        """Open the list and count its events as seen."""
+        self.render()
+        self.center.mark_read()
+        open_dialog(self.page, self.dialog)
+
+    def render(self, now: float | None = None) -> None:
+        """List the events, newest first."""
+        entries = self.center.entries()
+        if not entries:
+            self.list_column.controls = [
+                ft.Text(
+                    tr("Downloads, changed pages and other events will show up here."),
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ]
+            return
+        now = time.time() if now is None else now
+        self.list_column.controls = [self._entry_row(entry, now) for entry in entries]
+
+    def clear(self) -> None:
+        """Forget every event."""
+        self.center.clear()
+        self.render()
+        self.page.update()
+
+    def close(self) -> None:
+        """Close the list."""
+        self.page.close(self.dialog)
+
+    def open_entry(self, entry: Notification) -> None:
+        """Close the list and open the address of an event."""
+        self.close()
+        if entry.address:
+            self.on_open(entry.address)
+
+    def _on_change(self) -> None:
+        self.update_badge()
+        self.page.update()
+
+    def _entry_row(self, entry: Notification, now: float) -> ft.Control:
+        kind = tr(KINDS.get(entry.kind, entry.kind))
+        when = tr("{age} ago", age=format_age(now - entry.timestamp))
+        return ft.ListTile(
+            leading=ft.Icon(
+                KIND_ICONS.get(entry.kind, ft.Icons.NOTIFICATIONS),
+                color=ft.Colors.ERROR if entry.kind == "error" else None,
+            ),
+            title=ft.Text(entry.message, size=13),
+            subtitle=ft.Text(f"{kind} · {when}", size=11),
+            on_click=(lambda e: self.open_entry(entry)) if entry.address else None,
+        )
@@ -0,0 +1,129 @@
+"""Transient notifications and count badges for Ren Browser."""
+
+import os
+import shutil
+import subprocess
+import sys
+from collections.abc import Callable
+
+import flet as ft
+
+
+def show_snack(
+    page: ft.Page,
+    message: str,
+    success: bool = True,
+    action: str | None = None,
+    on_action: Callable | None = None,
+) -> ft.SnackBar:
+    """Show a short message at the bottom of the window.
+
+    Args:
+        page: Flet page instance to show the message on.
+        message: Text to show.
+        success: Whether the message reports a success or a failure.
+        action: Label of a button shown beside the message, if any.
+        on_action: Called when the button is clicked.
+
+    Returns:
+        ft.SnackBar: The snack bar that was opened.
+
+    """
+    snack = ft.SnackBar(
+        content=ft.Text(message, color=ft.Colors.WHITE),
+        bgcolor=ft.Colors.GREEN_900 if success else ft.Colors.RED_900,
+        duration=(3000 if success else 4000) + (3000 if action else 0),
+        action=action,
+        on_action=on_action,
+    )
+    page.overlay.append(snack)
+    snack.open = True
+    page.update()
+    return snack
+
+
+def count_badge(count: int) -> ft.Badge | None:
+    """Return a badge showing a count of new things, or None if there are none."""
+    return ft.Badge(text=str(count)) if count else None
+
+
+# Kinds of events shown as desktop notifications, by the setting that
+# switches each on once desktop notifications are enabled.
+NOTIFY_CATEGORIES = {
+    "favorites": "notify_favorites",
+    "watched": "notify_watched",
+    "downloads": "notify_downloads",
+}
+
+# Windows has no notification command, so a toast is raised through the
+# WinRT API from PowerShell. Title and message arrive in the environment.
+_WINDOWS_TOAST = (
+    "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications,"
+    " ContentType=WindowsRuntime] | Out-Null;"
+    "$template = [Windows.UI.Notifications.ToastNotificationManager]::"
+    "GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02);"
+    "$text = $template.GetElementsByTagName('text');"
+    "$text.Item(0).AppendChild($template.CreateTextNode($env:REN_TITLE)) | Out-Null;"
+    "$text.Item(1).AppendChild($template.CreateTextNode($env:REN_MESSAGE)) | Out-Null;"
+    "$toast = [Windows.UI.Notifications.ToastNotification]::new($template);"
+    "[Windows.UI.Notifications.ToastNotificationManager]::"
+    "CreateToastNotifier('Ren Browser').Show($toast)"
+)
+
+
+def system_notify(title: str, message: str) -> bool:
+    """Show a desktop notification outside the window, where supported.
+
+    Uses ``notify-send`` on Linux, ``osascript`` on macOS and a PowerShell
+    toast on Windows.
+
+    Returns:
+        True if a notification was sent.
+
+    """
+    env = None
+    if sys.platform == "win32":
+        command = shutil.which("powershell")
+        args = [command, "-NoProfile", "-NonInteractive", "-Command", _WINDOWS_TOAST]
+        env = {**os.environ, "REN_TITLE": title, "REN_MESSAGE": message}
+    elif sys.platform == "darwin":
+        command = shutil.which("osascript")
+        text = message.replace('"', "'")
+        heading = title.replace('"', "'")
+        script = f'display notification "{text}" with title "{heading}"'
+        args = [command, "-e", script]
+    else:
+        command = shutil.which("notify-send")
+        args = [command, title, message]
+    if not command:
+        return False
+    try:
+        subprocess.Popen(args, env=env)
+    except OSError:
+        return False
+    return True
+
+
+def desktop_notify(
+    settings: dict,
+    category: str,
+    message: str,
+    title: str = "Ren Browser",
+) -> bool:
+    """Show a desktop notification if the settings allow it.
+
+    Args:
+        settings: App settings holding the desktop notification switches.
+        category: Key of NOTIFY_CATEGORIES the event belongs to.
+        message: Text of the notification.
+        title: Heading of the notification.
+
+    Returns:
+        True if a notification was sent.
+
+    """
+    if not settings.get("system_notifications", False):
+        return False
+    if not settings.get(NOTIFY_CATEGORIES[category], True):
+        return False
+    return system_notify(title, message)
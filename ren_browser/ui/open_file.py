@@ -0,0 +1,43 @@
+"""Open File for Ren Browser.
+
+Opens a micron or text page from disk in a new tab, for previewing pages
+before they are uploaded to a node.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.pages.address import file_address
+
+PAGE_EXTENSIONS = ["mu", "txt"]
+
+
+class FileOpener:
+    """Native open dialog for pages on disk."""
+
+    def __init__(self, page: ft.Page, on_open: Callable[[str], None]):
+        """Initialize the open dialog.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            on_open: Called with the ``file://`` address of the chosen page.
+
+        """
+        self.page = page
+        self.on_open = on_open
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+
+    def choose(self) -> None:
+        """Ask for a page to open."""
+        self.picker.pick_files(
+            dialog_title="Open File",
+            allowed_extensions=PAGE_EXTENSIONS,
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        # The web build is given file contents without a path, which a
+        # file:// address cannot point to.
+        if e.files and e.files[0].path:
+            self.on_open(file_address(e.files[0].path))
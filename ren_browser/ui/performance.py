@@ -0,0 +1,124 @@
+"""Performance overlay for Ren Browser.
+
+A small panel in the corner of the window showing how often and how fast
+the page updates, the memory the browser uses, the size of the page cache,
+the requests in flight and how long the last page fetch took. It is
+toggled with a keyboard shortcut and refreshes every second while shown.
+Page updates are only timed once the overlay has been opened.
+"""
+
+import asyncio
+
+import flet as ft
+
+from ren_browser.downloads.downloads import ACTIVE
+from ren_browser.profiler.profiler import memory_usage, watch_updates
+from ren_browser.ui.downloads import format_size
+
+REFRESH_SECONDS = 1
+
+
+def performance_lines(tab_manager) -> list[str]:
+    """Describe the browser's current performance, one measurement a line."""
+    monitor = tab_manager.performance
+    rate, latency = monitor.update_rate()
+    lines = [
+        f"Updates: {rate:.1f}/s"
+        + (f", {latency * 1000:.1f} ms each" if latency is not None else ""),
+    ]
+    memory = memory_usage()
+    lines.append(f"Memory: {format_size(memory) if memory is not None else 'unknown'}")
+    cache = tab_manager.page_cache
+    cached = f"Cache: {len(cache)} pages, {format_size(cache.size())}"
+    if cache.disk is not None:
+        cached += f" (disk {format_size(cache.disk.size())})"
+    lines.append(cached)
+    loading = sum(
+        1 for tab in tab_manager.manager.tabs if tab.get("load_progress") is not None
+    )
+    downloads = sum(
+        1
+        for download in tab_manager.downloads.downloads()
+        if download.status in ACTIVE
+    )
+    lines.append(
+        f"In flight: {loading} pages, {downloads} downloads, "
+        f"{tab_manager.prefetcher.pending()} prefetches queued",
+    )
+    fetch = monitor.last_fetch
+    lines.append(
+        f"Last fetch: {fetch.seconds:.2f} s, {fetch.address}"
+        if fetch is not None
+        else "Last fetch: none yet",
+    )
+    return lines
+
+
+class PerformanceOverlay:
+    """Panel over the page with live performance measurements."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the hidden overlay and add it to the page.
+
+        Args:
+            page: Flet page the overlay is drawn over.
+            tab_manager: Tab manager whose monitor and caches are shown.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.text = ft.Text("", size=11, font_family="monospace", selectable=True)
+        self.control = ft.Container(
+            content=self.text,
+            right=12,
+            bottom=36,
+            padding=8,
+            border_radius=6,
+            bgcolor=ft.Colors.SURFACE_CONTAINER_HIGHEST,
+            opacity=0.9,
+            visible=False,
+        )
+        self._loop_id = None
+        self._watching = False
+        page.overlay.append(self.control)
+
+    def refresh(self) -> None:
+        """Show the current measurements."""
+        self.text.value = "\n".join(performance_lines(self.tab_manager))
+
+    def toggle(self) -> None:
+        """Show the overlay if hidden, hide it otherwise."""
+        self.control.visible = not self.control.visible
+        if not self.control.visible:
+            self._loop_id = None
+            return
+        if not self._watching:
+            watch_updates(self.page, self.tab_manager.performance)
+            self._watching = True
+        self.refresh()
+        self._start_loop()
+
+    def pause(self) -> None:
+        """Stop refreshing, keeping the overlay shown."""
+        self._loop_id = None
+
+    def resume(self) -> None:
+        """Refresh again if the overlay is shown."""
+        if self.control.visible:
+            self.refresh()
+            self._start_loop()
+
+    def _start_loop(self) -> None:
+        # A new loop replaces the running one, which stops at its next wake-up
+        loop_id = object()
+        self._loop_id = loop_id
+
+        async def refresh_loop():
+            while True:
+                await asyncio.sleep(REFRESH_SECONDS)
+                if self._loop_id is not loop_id:
+                    return
+                self.refresh()
+                self.page.update()
+
+        self.page.run_task(refresh_loop)
@@ -0,0 +1,290 @@
+"""Petname directory for Ren Browser.
+
+The ``about:petnames`` page lists the names given to destinations and has
+a form to add or change one, and the file dialogs that export the petnames
+to a naming file or import the petnames of one shared by someone else.
+"""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.i18n.i18n import ntr, tr
+from ren_browser.petnames.petnames import (
+    Petname,
+    PetnameFormatError,
+    from_text,
+    petname_error,
+    to_text,
+)
+from ren_browser.ui.avatars import build_node_avatar
+from ren_browser.ui.notify import show_snack
+
+FILE_NAME = "petnames.txt"
+
+
+class PetnameFiles:
+    """Native file dialogs for importing and exporting petnames."""
+
+    def __init__(self, page: ft.Page, directory, on_change: Callable[[], None]):
+        """Initialize the file dialogs.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            directory: PetnameDirectory that is imported into or exported.
+            on_change: Called after petnames were imported.
+
+        """
+        self.page = page
+        self.directory = directory
+        self.on_change = on_change
+        self.exporting = False
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+
+    def choose_export(self) -> None:
+        """Ask where to export the petnames."""
+        self.exporting = True
+        self.picker.save_file(
+            dialog_title=tr("Export Petnames"),
+            file_name=FILE_NAME,
+            allowed_extensions=["txt"],
+        )
+
+    def choose_import(self) -> None:
+        """Ask for a naming file to import."""
+        self.exporting = False
+        self.picker.pick_files(
+            dialog_title=tr("Import Petnames"),
+            allowed_extensions=["txt"],
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        exporting, self.exporting = self.exporting, False
+        if exporting and e.path:
+            self.export_to(Path(e.path))
+        elif not exporting and e.files and e.files[0].path:
+            self.import_from(Path(e.files[0].path))
+
+    def export_to(self, path: Path) -> bool:
+        """Write all petnames to a naming file and report the outcome."""
+        petnames = self.directory.petnames()
+        try:
+            path.write_text(to_text(petnames), encoding="utf-8")
+        except OSError as exc:
+            show_snack(
+                self.page,
+                tr("Failed to export petnames: {error}", error=exc),
+                False,
+            )
+            return False
+        show_snack(
+            self.page,
+            ntr(
+                "Exported 1 petname to {path}",
+                "Exported {count} petnames to {path}",
+                len(petnames),
+                path=path,
+            ),
+        )
+        return True
+
+    def import_from(self, path: Path) -> int:
+        """Add the petnames of a naming file and report how many were new.
+
+        Returns:
+            int: Number of petnames added.
+
+        """
+        try:
+            imported = from_text(path.read_text(encoding="utf-8"))
+        except (OSError, UnicodeDecodeError, PetnameFormatError) as exc:
+            show_snack(
+                self.page,
+                tr("Failed to import petnames: {error}", error=exc),
+                False,
+            )
+            return 0
+        added, conflicts = self.directory.merge(imported)
+        message = ntr("Imported 1 petname", "Imported {count} petnames", added)
+        if conflicts:
+            message += " " + ntr(
+                "(1 name already used for another destination was kept)",
+                "({count} names already used for other destinations were kept)",
+                conflicts,
+            )
+        show_snack(self.page, message)
+        self.on_change()
+        return added
+
+
+def build_petnames_page(
+    directory,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+    files: PetnameFiles | None = None,
+) -> ft.Control:
+    """Build the ``about:petnames`` page.
+
+    Args:
+        directory: PetnameDirectory whose petnames are listed.
+        on_open: Called with a ``~name`` address when a petname is clicked.
+        on_change: Called after the page has been redrawn so it can be updated.
+        files: File dialogs for the import and export buttons, if available.
+
+    Returns:
+        ft.Control: The petnames page.
+
+    """
+    page = ft.Column(spacing=4)
+    state: dict[str, str | None] = {"editing": None}
+    name_field = ft.TextField(label=tr("Petname"), prefix_text="~", width=180)
+    hash_field = ft.TextField(label=tr("Destination hash"), expand=True)
+    note_field = ft.TextField(label=tr("Note"), expand=True)
+    save_btn = ft.FilledButton(tr("Add"), on_click=lambda e: save())
+    cancel_btn = ft.TextButton(tr("Cancel"), on_click=lambda e: reset())
+
+    def reset():
+        state["editing"] = None
+        for field in (name_field, hash_field, note_field):
+            field.value = ""
+            field.error_text = None
+        render()
+        on_change()
+
+    def save():
+        name_field.error_text = petname_error(name_field.value or "")
+        hash_field.error_text = None
+        if name_field.error_text is not None:
+            on_change()
+            return
+        try:
+            petname = directory.set(
+                name_field.value or "",
+                hash_field.value or "",
+                note_field.value or "",
+            )
+        except ValueError as exc:
+            hash_field.error_text = str(exc)
+            on_change()
+            return
+        old = state["editing"]
+        if old is not None and old != petname.name:
+            directory.remove(old)
+        reset()
+
+    def edit(petname: Petname):
+        state["editing"] = petname.name
+        name_field.value = petname.name
+        hash_field.value = petname.destination_hash
+        note_field.value = petname.note
+        name_field.error_text = hash_field.error_text = None
+        render()
+        on_change()
+
+    def remove(petname: Petname):
+        directory.remove(petname.name)
+        if state["editing"] == petname.name:
+            state["editing"] = None
+        render()
+        on_change()
+
+    def render():
+        editing = state["editing"] is not None
+        save_btn.text = tr("Save") if editing else tr("Add")
+        cancel_btn.visible = editing
+        header: list[ft.Control] = [
+            ft.Text(
+                tr("Petnames"),
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+                expand=True,
+            ),
+        ]
+        if files is not None:
+            header.extend(
+                [
+                    ft.TextButton(
+                        tr("Import"),
+                        icon=ft.Icons.FILE_UPLOAD,
+                        on_click=lambda e: files.choose_import(),
+                    ),
+                    ft.TextButton(
+                        tr("Export"),
+                        icon=ft.Icons.FILE_DOWNLOAD,
+                        on_click=lambda e: files.choose_export(),
+                    ),
+                ],
+            )
+        controls: list[ft.Control] = [
+            ft.Row(controls=header),
+            ft.Text(
+                tr(
+                    "Give destinations names of your own and open them as "
+                    "~name or ~name/page/index.mu in the address bar.",
+                ),
+                size=14,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            ft.Row(
+                vertical_alignment=ft.CrossAxisAlignment.START,
+                controls=[name_field, hash_field, note_field, save_btn, cancel_btn],
+            ),
+        ]
+        petnames = directory.petnames()
+        if not petnames:
+            controls.append(
+                ft.Text(
+                    tr("No petnames yet."),
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.extend(
+            _build_petname(petname, on_open, edit, remove) for petname in petnames
+        )
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _build_petname(petname: Petname, on_open, on_edit, on_remove) -> ft.Control:
+    lines: list[ft.Control] = [
+        ft.Text(f"~{petname.name}", size=16, weight=ft.FontWeight.BOLD),
+        ft.Text(
+            petname.destination_hash,
+            size=12,
+            color=ft.Colors.ON_SURFACE_VARIANT,
+            selectable=True,
+        ),
+    ]
+    if petname.note:
+        lines.append(ft.Text(petname.note, size=13))
+    return ft.Container(
+        content=ft.Row(
+            spacing=12,
+            controls=[
+                build_node_avatar(petname.destination_hash, size=20),
+                ft.Column(spacing=2, expand=True, controls=lines),
+                ft.IconButton(
+                    ft.Icons.EDIT_OUTLINED,
+                    tooltip=tr("Edit"),
+                    icon_size=18,
+                    on_click=lambda e: on_edit(petname),
+                ),
+                ft.IconButton(
+                    ft.Icons.DELETE_OUTLINE,
+                    tooltip=tr("Delete"),
+                    icon_size=18,
+                    on_click=lambda e: on_remove(petname),
+                ),
+            ],
+        ),
+        ink=True,
+        on_click=lambda e: on_open(f"~{petname.name}"),
+        padding=ft.padding.symmetric(horizontal=12, vertical=8),
+        border_radius=8,
+    )
@@ -0,0 +1,120 @@
+"""Micron playground for Ren Browser.
+
+The ``about:playground`` page: a micron editor beside the page it renders
+to, redrawn as it is typed, so node operators can write pages in the
+browser before publishing them. Example snippets show the markup for
+headings, styles, colours, links and layout, and the page can be exported
+as a ``.mu`` file.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+# Markup inserted by each example button, in the order they are listed
+SNIPPETS = {
+    "Headings": ">Heading\nText under the heading.\n>>Subheading\nIndented text.\n",
+    "Styles": "`!Bold`!, `*italic`* and `_underlined`_ text, `!`*combined`* `` too.\n",
+    "Colours": "`Ff00Red`f text and `B00fa blue background`b.\n",
+    "Links": "`[Home`:/page/index.mu] and `[another node`0123abcd:/page/index.mu]\n",
+    "Layout": "`cCentered text\n`rRight aligned\n`aBack to the start\n-\n",
+}
+STARTER = ">Welcome\nEdit the markup on the left to see the page on the right.\n"
+
+
+def build_playground_page(
+    source: str,
+    render: Callable[[str], ft.Control],
+    on_edit: Callable[[str], None],
+    on_export: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:playground`` page.
+
+    Args:
+        source: Markup the editor starts with, STARTER if empty.
+        render: Turns markup into the control shown beside the editor.
+        on_edit: Called with the markup after every edit, to keep it.
+        on_export: Called with the markup to save it to a file.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The playground page.
+
+    """
+    preview = ft.Container(
+        expand=True,
+        padding=ft.padding.all(12),
+        border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+        border_radius=8,
+    )
+    editor = ft.TextField(
+        value=source or STARTER,
+        multiline=True,
+        min_lines=24,
+        text_style=ft.TextStyle(font_family="monospace", size=13),
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+        on_change=lambda e: edited(),
+    )
+
+    def show():
+        preview.content = render(editor.value or "")
+
+    def edited():
+        on_edit(editor.value or "")
+        show()
+        on_change()
+
+    def insert(snippet):
+        text = editor.value or ""
+        if text and not text.endswith("\n"):
+            text += "\n"
+        editor.value = text + snippet
+        edited()
+
+    show()
+    return ft.Column(
+        spacing=8,
+        controls=[
+            ft.Row(
+                controls=[
+                    ft.Text(
+                        "Micron Playground",
+                        size=24,
+                        weight=ft.FontWeight.BOLD,
+                        color=ft.Colors.PRIMARY,
+                        expand=True,
+                    ),
+                    ft.OutlinedButton(
+                        "Export",
+                        icon=ft.Icons.SAVE_ALT,
+                        tooltip="Save the markup as a .mu file",
+                        on_click=lambda e: on_export(editor.value or ""),
+                    ),
+                ],
+            ),
+            ft.Row(
+                spacing=6,
+                wrap=True,
+                controls=[
+                    ft.Text("Insert:", size=13, color=ft.Colors.ON_SURFACE_VARIANT),
+                    *(
+                        ft.TextButton(
+                            name,
+                            on_click=lambda e, snippet=snippet: insert(snippet),
+                        )
+                        for name, snippet in SNIPPETS.items()
+                    ),
+                ],
+            ),
+            ft.Row(
+                spacing=12,
+                vertical_alignment=ft.CrossAxisAlignment.START,
+                controls=[
+                    ft.Container(content=editor, expand=True),
+                    preview,
+                ],
+            ),
+        ],
+    )
@@ -0,0 +1,102 @@
+"""Printing for Ren Browser.
+
+Lays the page out for paper with the PDF export, black text on white A4
+pages, and hands the document to the system to print. Windows prints it
+through the default PDF application and macOS opens the print dialog of
+Preview. Other desktops have no common print dialog, so the document is
+opened in the default PDF viewer to print from there.
+"""
+
+import os
+import shutil
+import subprocess
+import sys
+import tempfile
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.renderer.pdf import page_to_pdf
+from ren_browser.ui.notify import show_snack
+
+
+def print_command(path: Path) -> list[str] | None:
+    """Return the command printing a PDF file, or None if there is none.
+
+    Windows is left out, as it prints through ``os.startfile`` instead.
+    """
+    if sys.platform == "darwin":
+        command = shutil.which("osascript")
+        script = (
+            f'tell application "Preview" to print POSIX file "{path}" '
+            "with print dialog"
+        )
+        args = [command, "-e", script]
+    else:
+        command = shutil.which("xdg-open")
+        args = [command, str(path)]
+    return args if command else None
+
+
+def print_file(path: Path) -> bool:
+    """Send a PDF file to the system for printing.
+
+    Returns:
+        True if the file was handed over.
+
+    """
+    try:
+        if sys.platform == "win32":
+            os.startfile(str(path), "print")  # type: ignore[attr-defined]
+            return True
+        args = print_command(path)
+        if args is None:
+            return False
+        subprocess.Popen(args)
+    except OSError:
+        return False
+    return True
+
+
+class PagePrinter:
+    """Prints fetched pages."""
+
+    def __init__(self, page: ft.Page):
+        """Initialize the printer.
+
+        Args:
+            page: Flet page instance outcomes are reported on.
+
+        """
+        self.page = page
+
+    def print_page(self, source: str, page_path: str, title: str) -> bool:
+        """Lay a page out for paper and send it to be printed.
+
+        Args:
+            source: Page source as fetched from the node.
+            page_path: Path of the page on its node, deciding how it is laid out.
+            title: Heading printed at the top of the first page.
+
+        Returns:
+            True if the page was sent to be printed.
+
+        """
+        if self.page.web:
+            show_snack(self.page, "Printing is not available here", success=False)
+            return False
+        data = page_to_pdf(source, page_path, title, monochrome=True)
+        try:
+            # The file is left in place, as the print job or viewer reads it
+            # after this returns.
+            handle, name = tempfile.mkstemp(prefix="ren-print-", suffix=".pdf")
+            with os.fdopen(handle, "wb") as file:
+                file.write(data)
+        except OSError as exc:
+            show_snack(self.page, f"Failed to print page: {exc}", success=False)
+            return False
+        if not print_file(Path(name)):
+            show_snack(self.page, "No way to print was found", success=False)
+            return False
+        show_snack(self.page, f"Sent {title} to print")
+        return True
@@ -0,0 +1,71 @@
+"""QR code overlay for Ren Browser.
+
+Shows the ``ren://`` link of a node or page as a QR code so it can be
+scanned by a phone.
+"""
+
+import base64
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.qr.qr import encode, to_png
+
+QR_SIZE = 280
+
+
+class QrCodeDialog:
+    """Dialog showing an address as a QR code."""
+
+    def __init__(self, page: ft.Page):
+        """Initialize the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+
+        """
+        self.page = page
+        self.url: str | None = None
+        self.image = ft.Image(width=QR_SIZE, height=QR_SIZE)
+        self.url_text = ft.Text(
+            size=12,
+            font_family="monospace",
+            selectable=True,
+            text_align=ft.TextAlign.CENTER,
+        )
+        self.dialog = ft.AlertDialog(
+            title=ft.Text("QR Code"),
+            content=ft.Column(
+                tight=True,
+                spacing=12,
+                width=QR_SIZE,
+                horizontal_alignment=ft.CrossAxisAlignment.CENTER,
+                controls=[self.image, self.url_text],
+            ),
+            actions=[
+                ft.TextButton("Copy Link", on_click=lambda e: self.copy()),
+                ft.TextButton("Close", on_click=lambda e: self.page.close(self.dialog)),
+            ],
+        )
+
+    def show(self, url: str, title: str | None = None) -> None:
+        """Open the dialog for a link.
+
+        Args:
+            url: ``ren://`` link to encode.
+            title: Name of the node or page, shown as the dialog title.
+
+        """
+        self.url = url
+        self.dialog.title = ft.Text(
+            title or "QR Code",
+            overflow=ft.TextOverflow.ELLIPSIS,
+        )
+        self.image.src_base64 = base64.b64encode(to_png(encode(url))).decode("ascii")
+        self.url_text.value = url
+        open_dialog(self.page, self.dialog)
+
+    def copy(self) -> None:
+        """Put the link on the clipboard."""
+        if self.url:
+            self.page.set_clipboard(self.url)
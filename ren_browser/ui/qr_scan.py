@@ -0,0 +1,81 @@
+"""QR code scanning for Ren Browser.
+
+The counterpart to the QR code overlay: a Scan button in the address bar
+on phones and tablets. It asks for a picture, which on those devices can
+be taken with the camera, then opens the address in the QR code in it.
+"""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.pages.address import find_address
+from ren_browser.qr.scan import scan
+from ren_browser.ui.notify import show_snack
+
+CAMERA_PLATFORMS = (ft.PagePlatform.ANDROID, ft.PagePlatform.IOS)
+
+
+def has_camera(page: ft.Page) -> bool:
+    """Return whether the app runs natively on a device with a camera."""
+    return not page.web and page.platform in CAMERA_PLATFORMS
+
+
+class QrScanner:
+    """Scan button opening the address in a photographed QR code."""
+
+    def __init__(self, page: ft.Page, on_open: Callable[[str], None]):
+        """Build the button and attach the image picker to the page.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            on_open: Called with the address found in a scanned code.
+
+        """
+        self.page = page
+        self.on_open = on_open
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+        self.button = ft.IconButton(
+            ft.Icons.QR_CODE_SCANNER,
+            tooltip="Scan QR code",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: self.choose(),
+            visible=has_camera(page),
+        )
+
+    def choose(self) -> None:
+        """Ask for a photo or screenshot of a QR code."""
+        self.picker.pick_files(
+            dialog_title="Scan QR Code",
+            file_type=ft.FilePickerFileType.IMAGE,
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        if e.files and e.files[0].path:
+            path = Path(e.files[0].path)
+            # Looking for the code takes a moment on large photos
+            self.page.run_thread(self.scan_file, path)
+
+    def scan_file(self, path: Path) -> str | None:
+        """Open the address in the QR code in an image and report failures.
+
+        Returns:
+            The address opened, or None if there was none.
+
+        """
+        try:
+            text = scan(path.read_bytes())
+        except (OSError, ValueError) as exc:
+            show_snack(self.page, f"Failed to scan the image: {exc}", False)
+            return None
+        if text is None:
+            show_snack(self.page, "No QR code was found in the image", False)
+            return None
+        address = find_address(text)
+        if address is None:
+            show_snack(self.page, f"The QR code holds no address: {text}", False)
+            return None
+        self.on_open(address)
+        return address
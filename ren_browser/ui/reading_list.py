@@ -0,0 +1,103 @@
+"""Reading list page for Ren Browser.
+
+Lists pages saved for offline reading and opens or deletes them.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+
+
+def build_reading_list_page(
+    reading_list,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:reading-list`` page.
+
+    Args:
+        reading_list: ReadingList whose saved pages are listed.
+        on_open: Called with an address when a saved page is clicked.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The reading list page.
+
+    """
+    page = ft.Column(spacing=4)
+
+    def remove(address):
+        reading_list.remove(address)
+        render()
+        on_change()
+
+    def render():
+        controls: list[ft.Control] = [
+            ft.Text(
+                "Reading List",
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+        ]
+        items = reading_list.items()
+        if not items:
+            controls.append(
+                ft.Text(
+                    "Right-click a page and choose Read Later to keep a copy "
+                    "you can open without a connection.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.extend(_build_item(item, on_open, remove) for item in items)
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _build_item(item, on_open, on_remove) -> ft.Control:
+    address = item.address
+    saved = datetime.fromtimestamp(item.saved_at).strftime("%Y-%m-%d %H:%M")
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                build_node_avatar(address.split(":", 1)[0], size=20),
+                ft.Container(
+                    expand=True,
+                    ink=True,
+                    on_click=lambda e: on_open(address),
+                    content=ft.Column(
+                        spacing=2,
+                        controls=[
+                            ft.Text(
+                                item.title,
+                                size=14,
+                                weight=ft.FontWeight.W_500,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                            ft.Text(
+                                f"{address}  ·  saved {saved}",
+                                size=12,
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                        ],
+                    ),
+                ),
+                ft.IconButton(
+                    ft.Icons.DELETE_OUTLINE,
+                    tooltip="Delete saved copy",
+                    icon_size=18,
+                    on_click=lambda e: on_remove(address),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
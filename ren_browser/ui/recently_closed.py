@@ -0,0 +1,66 @@
+"""Recently closed tabs section for Ren Browser.
+
+Shown on the new tab page so closed tabs can be brought back with one click.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+
+def build_recently_closed(entries, on_restore: Callable) -> ft.Control | None:
+    """Build the "Recently closed" list for the new tab page.
+
+    Args:
+        entries: ClosedTab entries, most recently closed first.
+        on_restore: Called with an entry when it is clicked.
+
+    Returns:
+        The section, or None if no tabs have been closed.
+
+    """
+    if not entries:
+        return None
+    controls: list[ft.Control] = [
+        ft.Text("Recently closed", size=16, weight=ft.FontWeight.BOLD),
+    ]
+    controls.extend(_build_entry(entry, on_restore) for entry in entries)
+    return ft.Container(
+        content=ft.Column(controls=controls, spacing=4),
+        padding=ft.padding.only(top=24),
+    )
+
+
+def _build_entry(entry, on_restore: Callable) -> ft.Control:
+    closed = datetime.fromtimestamp(entry.closed_at)
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                ft.Icon(ft.Icons.TAB, size=16, color=ft.Colors.ON_SURFACE_VARIANT),
+                ft.Text(
+                    entry.title,
+                    size=14,
+                    weight=ft.FontWeight.W_500,
+                    overflow=ft.TextOverflow.ELLIPSIS,
+                ),
+                ft.Text(
+                    entry.address,
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                    overflow=ft.TextOverflow.ELLIPSIS,
+                    expand=True,
+                ),
+                ft.Text(
+                    closed.strftime("%H:%M"),
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=8),
+        border_radius=8,
+        ink=True,
+        on_click=lambda e: on_restore(entry),
+    )
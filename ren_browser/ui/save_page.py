@@ -0,0 +1,93 @@
+"""Save Page As for Ren Browser.
+
+Writes the fetched source of the active page, or a PDF export of it, to a
+file chosen in a native save dialog.
+"""
+
+from pathlib import Path, PurePosixPath
+
+import flet as ft
+
+from ren_browser.renderer.pdf import page_to_pdf
+from ren_browser.ui.notify import show_snack
+
+
+def suggested_file_name(page_path: str) -> str:
+    """Return the default file name for saving a page.
+
+    Micron pages keep their ``.mu`` name and anything else is saved as text.
+    """
+    name = PurePosixPath(page_path).name or "page"
+    if name.endswith((".mu", ".txt")):
+        return name
+    return f"{name}.txt"
+
+
+class PageSaver:
+    """Native save dialog for the source of fetched pages."""
+
+    def __init__(self, page: ft.Page, directory: Path | None = None):
+        """Initialize the save dialog.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            directory: Folder the dialog opens in.
+
+        """
+        self.page = page
+        self.directory = directory
+        self.pending: bytes | None = None
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+
+    def choose(self, source: str, page_path: str) -> None:
+        """Ask where to save a page's source.
+
+        Args:
+            source: Page source as fetched from the node.
+            page_path: Path of the page on its node, used for the file name.
+
+        """
+        # Pages are decoded as strict UTF-8 when fetched, so encoding the
+        # source again gives back the bytes the node sent.
+        self._choose("Save Page As", suggested_file_name(page_path), source.encode())
+
+    def choose_pdf(self, source: str, page_path: str, title: str) -> None:
+        """Ask where to save a PDF export of a page.
+
+        Args:
+            source: Page source as fetched from the node.
+            page_path: Path of the page on its node, deciding how it is laid out.
+            title: Heading printed at the top of the document.
+
+        """
+        stem = suggested_file_name(page_path).rsplit(".", 1)[0]
+        self._choose(
+            "Export as PDF",
+            f"{stem}.pdf",
+            page_to_pdf(source, page_path, title),
+        )
+
+    def _choose(self, dialog_title: str, file_name: str, data: bytes) -> None:
+        self.pending = data
+        self.picker.save_file(
+            dialog_title=dialog_title,
+            file_name=file_name,
+            allowed_extensions=[file_name.rsplit(".", 1)[-1]],
+            initial_directory=str(self.directory) if self.directory else None,
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        data, self.pending = self.pending, None
+        if data is not None and e.path:
+            self.save_to(Path(e.path), data)
+
+    def save_to(self, path: Path, data: bytes) -> bool:
+        """Write a saved page to a file and report the outcome."""
+        try:
+            path.write_bytes(data)
+        except OSError as exc:
+            show_snack(self.page, f"Failed to save page: {exc}", success=False)
+            return False
+        show_snack(self.page, f"Saved page to {path}")
+        return True
@@ -0,0 +1,170 @@
+"""Search page for Ren Browser.
+
+Searches the full text of the cached and saved pages and the titles of
+visited ones, listing the best matches with the matching words
+highlighted in their title and a snippet of their text.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.i18n.i18n import ntr, tr
+from ren_browser.search.search import (
+    SearchDocument,
+    SearchResult,
+    highlight,
+    query_terms,
+    search,
+)
+from ren_browser.ui.avatars import build_node_avatar
+
+SOURCE_LABELS = {
+    "cache": "Cache",
+    "reading_list": "Reading List",
+    "history": "History",
+}
+
+
+def highlighted_spans(parts: list[tuple[str, bool]]) -> list[ft.TextSpan]:
+    """Build text spans of highlighted parts, marking the matched words."""
+    return [
+        ft.TextSpan(
+            text,
+            ft.TextStyle(
+                weight=ft.FontWeight.BOLD,
+                bgcolor=ft.Colors.with_opacity(0.3, ft.Colors.PRIMARY),
+            )
+            if matched
+            else None,
+        )
+        for text, matched in parts
+    ]
+
+
+def build_search_page(
+    documents: Callable[[], list[SearchDocument]],
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:search`` page.
+
+    Args:
+        documents: Returns the pages to search. Called once, on the first
+            search, as reading every cached page takes a while.
+        on_open: Called with an address when a result is clicked.
+        on_change: Called after the results have been redrawn so the page
+            can be updated.
+
+    Returns:
+        ft.Control: The search page.
+
+    """
+    loaded: list[list[SearchDocument]] = []
+    results = ft.Column(spacing=4)
+    search_field = ft.TextField(
+        hint_text=tr("Search cached and saved pages and history"),
+        prefix_icon=ft.Icons.SEARCH,
+        autofocus=True,
+        dense=True,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    def render():
+        query = search_field.value or ""
+        if not query.strip():
+            results.controls = [
+                ft.Text(
+                    tr(
+                        "There is no search engine on the mesh. This searches the "
+                        "pages kept on this device instead.",
+                    ),
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            ]
+            return
+        if not loaded:
+            loaded.append(documents())
+        found = search(loaded[0], query)
+        controls: list[ft.Control] = [
+            ft.Text(
+                ntr("1 page found", "{count} pages found", len(found))
+                if found
+                else tr("No pages match your search."),
+                size=14,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+        ]
+        terms = query_terms(query)
+        controls.extend(_build_result(result, terms, on_open) for result in found)
+        results.controls = controls
+
+    def on_search(_):
+        render()
+        on_change()
+
+    search_field.on_change = on_search
+    search_field.on_submit = on_search
+
+    render()
+    return ft.Column(
+        spacing=4,
+        controls=[
+            ft.Text(
+                tr("Search"),
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+            search_field,
+            results,
+        ],
+    )
+
+
+def _build_result(
+    result: SearchResult,
+    terms: list[str],
+    on_open: Callable[[str], None],
+) -> ft.Control:
+    document = result.document
+    details = "  ·  ".join(
+        [document.address]
+        + [tr(SOURCE_LABELS.get(source, source)) for source in document.sources],
+    )
+    lines: list[ft.Control] = [
+        ft.Text(
+            spans=highlighted_spans(highlight(document.title, terms)),
+            size=15,
+            weight=ft.FontWeight.W_500,
+            max_lines=1,
+            overflow=ft.TextOverflow.ELLIPSIS,
+        ),
+        ft.Text(
+            details,
+            size=12,
+            color=ft.Colors.ON_SURFACE_VARIANT,
+            max_lines=1,
+            overflow=ft.TextOverflow.ELLIPSIS,
+        ),
+    ]
+    if result.snippet:
+        lines.append(
+            ft.Text(spans=highlighted_spans(result.snippet), size=13, max_lines=3),
+        )
+    return ft.Container(
+        content=ft.Row(
+            vertical_alignment=ft.CrossAxisAlignment.START,
+            spacing=12,
+            controls=[
+                build_node_avatar(document.address.split(":", 1)[0], size=20),
+                ft.Column(spacing=2, expand=True, controls=lines),
+            ],
+        ),
+        ink=True,
+        on_click=lambda e: on_open(document.address),
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
@@ -5,16 +5,92 @@ from __future__ import annotations
 from datetime import datetime
 from pathlib import Path
 import logging
+import re
 
 import flet as ft
-import RNS
 
 from ren_browser import rns
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.controls.shortcuts import (
+    ACTION_CATEGORIES,
+    ACTIONS,
+    find_conflicts,
+    keybindings,
+    parse_chord,
+)
+from ren_browser.i18n.i18n import SYSTEM_LANGUAGE, available_languages, ntr, tr
+from ren_browser.logs import (
+    LOG_FILE_COUNTS,
+    LOG_FILE_LEVELS,
+    LOG_FILE_SIZES,
+    log_file_options,
+    log_file_path,
+)
+from ren_browser.pages.address import AddressError, internal_page_name, parse_address
+from ren_browser.pages.aliases import aliases, keyword_error, normalize_keyword
+from ren_browser.pages.cache import DEFAULT_MAX_AGE, cache_limits
+from ren_browser.pages.disk_cache import disk_cache_limit
+from ren_browser.pages.prefetch import prefetch_budget
+from ren_browser.plugins.plugins import loaded_plugins
+from ren_browser.power.power import (
+    INTERVAL_FACTORS,
+    SAVER_LEVELS,
+    describe_status,
+    interval_factor,
+    saver_level,
+)
+from ren_browser.storage.profiles import DEFAULT_PROFILE, ProfileError
+from ren_browser.storage.schema import DEFAULT_APP_SETTINGS, unknown_settings
 from ren_browser.storage.storage import get_storage_manager
+from ren_browser.tabs.session import STARTUP_NEW_TAB, STARTUP_OPTIONS
+from ren_browser.themes.themes import is_color
+from ren_browser.ui import notify
+from ren_browser.ui.announce_list import (
+    NODE_REFRESH_INTERVAL,
+    NODE_REFRESH_OPTIONS,
+    STALE_AFTER_OPTIONS,
+    STALE_NODE_MODES,
+    node_refresh_interval,
+)
+from ren_browser.ui.clear_data import ClearDataDialog
+from ren_browser.ui.downloads import format_size
+from ren_browser.ui.fonts import (
+    CONTENT_FONT_PRESETS,
+    MIN_FONT_SIZES,
+    READABLE_FONT,
+    UI_FONT_PRESETS,
+    FontPicker,
+    min_font_size,
+)
+from ren_browser.ui.interfaces import format_bitrate
+from ren_browser.ui.settings_transfer import SettingsFiles
+from ren_browser.ui.sidebar import MAX_SIDEBAR_WIDTH, MIN_SIDEBAR_WIDTH, SIDEBAR_WIDTH
+from ren_browser.ui.status_bar import POLL_INTERVAL, POLL_OPTIONS, poll_interval
+from ren_browser.ui.theme import (
+    CUSTOM_PREFIX,
+    PAGE_COLOR_OPTIONS,
+    THEME_OPTIONS,
+    theme_options,
+)
+from ren_browser.ui.theme_editor import ThemeEditor
 
 BUTTON_BG = "#0B3D91"
 BUTTON_BG_HOVER = "#082C6C"
 logger = logging.getLogger(__name__)
+CACHE_AGE_OPTIONS = {
+    0: "Never, always refetch",
+    60: "1 minute",
+    300: "5 minutes",
+    900: "15 minutes",
+    3600: "1 hour",
+    86400: "1 day",
+}
+MAX_CACHE_PAGES = 100_000
+MAX_CACHE_MB = 10_000
+MAX_PREFETCH_KB = 100_000
+# A chord is its modifiers, each ending in "+", then the key, which may be
+# "," or "+" itself, up to the comma separating it from the next chord.
+_CHORD_LIST_RE = re.compile(r"\s*((?:[^,+]+\+)*(?:,|\+|[^,+][^,]*))\s*(?:,|$)")
 
 
 def _blue_button_style() -> ft.ButtonStyle:
@@ -25,6 +101,129 @@ def _blue_button_style() -> ft.ButtonStyle:
     )
 
 
+_ACTION_BUTTONS = (ft.ElevatedButton, ft.FilledButton, ft.OutlinedButton, ft.TextButton)
+
+
+def setting_labels(control) -> list[str]:
+    """Return the labels, captions and option names shown by a settings row."""
+    if isinstance(control, ft.Text):
+        labels = [control.value]
+    else:
+        labels = [getattr(control, "label", None), getattr(control, "text", None)]
+        labels += [option.text for option in getattr(control, "options", None) or []]
+    for child in [
+        *(getattr(control, "controls", None) or []),
+        getattr(control, "content", None),
+    ]:
+        if child is not None:
+            labels += setting_labels(child)
+    return [label for label in labels if isinstance(label, str) and label]
+
+
+def _is_action(control) -> bool:
+    if isinstance(control, ft.Row):
+        return bool(control.controls) and all(map(_is_action, control.controls))
+    return isinstance(control, _ACTION_BUTTONS)
+
+
+def filter_settings(section, query: str) -> bool:
+    """Show only the rows of a settings section that match a search.
+
+    A row matches when one of its labels contains the query. Buttons are
+    shown while anything else in their section matches, and a matching
+    heading shows everything under it. An empty query shows every row.
+
+    Returns:
+        bool: Whether anything in the section matches.
+
+    """
+    query = query.strip().lower()
+    if not isinstance(section, ft.Column):
+        section.visible = True
+        labels = setting_labels(section)
+        return not query or any(query in label.lower() for label in labels)
+    children = section.controls
+    if children and isinstance(children[0], ft.Text) and filter_settings(
+        children[0],
+        query,
+    ):
+        query = ""
+    matched = False
+    for child in children:
+        if not _is_action(child):
+            child.visible = filter_settings(child, query)
+            matched = matched or child.visible
+    for child in children:
+        if _is_action(child):
+            child.visible = matched or not query
+    return matched or not query
+
+
+def check_number(field: ft.TextField, low: int, high: int) -> int | None:
+    """Read a whole number from a field, showing an error if it is out of range.
+
+    Returns:
+        The number, or None if the field holds something else.
+
+    """
+    field.error_text = None
+    text = (field.value or "").strip()
+    if not text.isdigit():
+        field.error_text = tr("Enter a whole number")
+        return None
+    value = int(text)
+    if not low <= value <= high:
+        field.error_text = tr(
+            "Enter a number from {low} to {high}",
+            low=low,
+            high=high,
+        )
+        return None
+    return value
+
+
+def check_color(field: ft.TextField) -> str | None:
+    """Read a colour from a field, showing an error if it is not one."""
+    value = (field.value or "").strip()
+    field.error_text = None if is_color(value) else tr("Enter a colour like #1a2b3c")
+    return value if field.error_text is None else None
+
+
+def check_home_page(field: ft.TextField, resolve_name=None) -> str | None:
+    """Read a home page address from a field, showing an error if it is invalid.
+
+    An empty field is valid and means the new tab page.
+    """
+    value = (field.value or "").strip()
+    field.error_text = None
+    if not value or internal_page_name(value) is not None:
+        return value
+    try:
+        parse_address(value, resolve_name)
+    except AddressError as exc:
+        field.error_text = str(exc)
+        return None
+    return value
+
+
+def check_directory(field: ft.TextField) -> str | None:
+    """Read a folder from a field, showing an error if it cannot be used.
+
+    An empty field is valid and means the default downloads folder. A folder
+    that does not exist yet is created by the first download.
+    """
+    value = (field.value or "").strip()
+    field.error_text = None
+    if not value:
+        return value
+    path = Path(value).expanduser()
+    if not path.is_absolute():
+        field.error_text = tr("Enter the full path of a folder")
+    elif path.exists() and not path.is_dir():
+        field.error_text = tr("This is a file, not a folder")
+    return value if field.error_text is None else None
+
+
 def _get_config_file_path() -> Path:
     config_dir = rns.get_config_path()
     if config_dir:
@@ -48,43 +247,11 @@ def _write_config_text(config_path: Path, content: str) -> None:
     config_path.write_text(content, encoding="utf-8")
 
 
-def _get_interface_statuses():
-    statuses = []
-    interfaces = getattr(RNS.Transport, "interfaces", []) or []
-    for interface in interfaces:
-        if interface is None:
-            continue
-        if interface.__class__.__name__ == "LocalClientInterface" and getattr(
-            interface, "is_connected_to_shared_instance", False,
-        ):
-            continue
-        statuses.append(
-            {
-                "name": getattr(interface, "name", None)
-                or interface.__class__.__name__,
-                "online": bool(getattr(interface, "online", False)),
-                "type": interface.__class__.__name__,
-                "bitrate": getattr(interface, "bitrate", None),
-            },
-        )
-    return statuses
-
-
-def _format_bitrate(bitrate: int | None) -> str | None:
-    if not bitrate:
-        return None
-    if bitrate >= 1_000_000:
-        return f"{bitrate / 1_000_000:.1f} Mbps"
-    if bitrate >= 1_000:
-        return f"{bitrate / 1_000:.0f} kbps"
-    return f"{bitrate} bps"
-
-
 def _build_interface_chip_controls(statuses):
     if not statuses:
         return [
             ft.Text(
-                "No interfaces detected",
+                tr("No interfaces detected"),
                 size=11,
                 color=ft.Colors.ON_SURFACE_VARIANT,
             ),
@@ -94,7 +261,7 @@ def _build_interface_chip_controls(statuses):
     for status in statuses:
         indicator_color = ft.Colors.GREEN if status["online"] else ft.Colors.ERROR
         tooltip = status["type"]
-        bitrate_label = _format_bitrate(status.get("bitrate"))
+        bitrate_label = format_bitrate(status.get("bitrate"))
         if bitrate_label:
             tooltip = f"{tooltip} • {bitrate_label}"
 
@@ -117,93 +284,1332 @@ def _build_interface_chip_controls(statuses):
     return chips
 
 
-def _refresh_interface_status(summary_text, chip_wrap, updated_text):
-    statuses = _get_interface_statuses()
-    total = len(statuses)
-    online = sum(1 for entry in statuses if entry["online"])
+def _refresh_interface_status(summary_text, chip_wrap, updated_text):
+    statuses = rns.get_interface_statuses()
+    total = len(statuses)
+    online = sum(1 for entry in statuses if entry["online"])
+
+    if total == 0:
+        summary_text.value = tr("No active interfaces")
+        summary_text.color = ft.Colors.ERROR
+    else:
+        summary_text.value = tr(
+            "{online}/{total} interfaces online",
+            online=online,
+            total=total,
+        )
+        summary_text.color = ft.Colors.GREEN if online else ft.Colors.ERROR
+
+    chip_wrap.controls = _build_interface_chip_controls(statuses)
+    updated_text.value = tr(
+        "Updated {time}",
+        time=datetime.now().strftime("%H:%M:%S"),
+    )
+
+
+def _build_status_section(page: ft.Page, tab_manager):
+    summary_text = ft.Text("", size=16, weight=ft.FontWeight.BOLD)
+    updated_text = ft.Text("", size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+    chip_wrap = ft.Row(
+        spacing=6,
+        run_spacing=6,
+        vertical_alignment=ft.CrossAxisAlignment.CENTER,
+    )
+
+    def refresh(_=None):
+        _refresh_interface_status(summary_text, chip_wrap, updated_text)
+        page.update()
+
+    refresh()
+
+    refresh_button = ft.IconButton(
+        icon=ft.Icons.REFRESH,
+        tooltip=tr("Refresh status"),
+        on_click=refresh,
+        icon_color=ft.Colors.BLUE_200,
+    )
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Row(
+                controls=[
+                    ft.Row(
+                        controls=[
+                            ft.Icon(ft.Icons.LAN, size=18, color=ft.Colors.BLUE_200),
+                            summary_text,
+                        ],
+                        spacing=6,
+                        vertical_alignment=ft.CrossAxisAlignment.CENTER,
+                    ),
+                    refresh_button,
+                ],
+                alignment=ft.MainAxisAlignment.SPACE_BETWEEN,
+                vertical_alignment=ft.CrossAxisAlignment.CENTER,
+            ),
+            chip_wrap,
+            updated_text,
+            ft.TextButton(
+                tr("View Logs"),
+                icon=ft.Icons.RECEIPT_LONG,
+                on_click=lambda _: tab_manager.open_link_in_new_tab("about:logs"),
+            ),
+        ],
+    )
+
+    return section, refresh
+
+
+def _build_storage_field(storage):
+    storage_field = ft.TextField(
+        label=tr("Storage Information"),
+        value="",
+        expand=True,
+        multiline=True,
+        read_only=True,
+        min_lines=10,
+        max_lines=15,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        text_style=ft.TextStyle(font_family="monospace", size=12),
+    )
+
+    def refresh():
+        info = storage.get_storage_info()
+        storage_field.value = "\n".join(
+            f"{key}: {value}" for key, value in info.items()
+        )
+
+    refresh()
+    return storage_field, refresh
+
+
+def _build_cache_section(page: ft.Page, tab_manager, storage):
+    max_age_dropdown = ft.Dropdown(
+        label=tr("Serve cached pages for"),
+        options=[
+            ft.dropdown.Option(str(seconds), tr(label))
+            for seconds, label in CACHE_AGE_OPTIONS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    max_pages_field = ft.TextField(
+        label=tr("Most pages kept"),
+        hint_text=tr("0 for no limit"),
+        width=200,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    max_mb_field = ft.TextField(
+        label=tr("Most megabytes kept"),
+        hint_text=tr("0 for no limit"),
+        width=200,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    disk_mb_field = ft.TextField(
+        label=tr("Most megabytes on disk"),
+        hint_text=tr("0 to cache in memory only"),
+        width=200,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    prefetch_switch = ft.Switch(label=tr("Prefetch linked pages on the same node"))
+    prefetch_kb_field = ft.TextField(
+        label=tr("Most kilobytes prefetched per page"),
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    usage = ft.Text(size=12, color=ft.Colors.ON_SURFACE_VARIANT)
+
+    def validate():
+        max_pages = check_number(max_pages_field, 0, MAX_CACHE_PAGES)
+        max_mb = check_number(max_mb_field, 0, MAX_CACHE_MB)
+        disk_mb = check_number(disk_mb_field, 0, MAX_CACHE_MB)
+        prefetch_kb = check_number(prefetch_kb_field, 1, MAX_PREFETCH_KB)
+        return max_pages, max_mb, disk_mb, prefetch_kb
+
+    def on_field_change(_):
+        validate()
+        page.update()
+
+    for field in (max_pages_field, max_mb_field, disk_mb_field, prefetch_kb_field):
+        field.on_change = on_field_change
+
+    def save(_):
+        limits = validate()
+        if None in limits:
+            page.update()
+            return
+        max_pages, max_mb, disk_mb, prefetch_kb = limits
+        settings = {
+            **tab_manager.settings,
+            "cache_max_age": int(max_age_dropdown.value or DEFAULT_MAX_AGE),
+            "cache_max_pages": max_pages,
+            "cache_max_mb": max_mb,
+            "cache_disk_mb": disk_mb,
+            "prefetch_links": bool(prefetch_switch.value),
+            "prefetch_kb": prefetch_kb,
+        }
+        tab_manager.settings = settings
+        tab_manager.page_cache.configure(*cache_limits(settings))
+        if tab_manager.page_cache.disk is not None:
+            tab_manager.page_cache.disk.configure(disk_cache_limit(settings))
+        tab_manager.prefetcher.configure(prefetch_budget(settings))
+        storage.save_app_settings(settings)
+        refresh()
+        notify.show_snack(page, tr("Cache settings saved"))
+
+    def restore_defaults(_):
+        max_age_dropdown.value = str(DEFAULT_APP_SETTINGS["cache_max_age"])
+        max_pages_field.value = str(DEFAULT_APP_SETTINGS["cache_max_pages"])
+        max_mb_field.value = str(DEFAULT_APP_SETTINGS["cache_max_mb"])
+        disk_mb_field.value = str(DEFAULT_APP_SETTINGS["cache_disk_mb"])
+        prefetch_switch.value = DEFAULT_APP_SETTINGS["prefetch_links"]
+        prefetch_kb_field.value = str(DEFAULT_APP_SETTINGS["prefetch_kb"])
+        validate()
+        page.update()
+
+    def refresh():
+        max_age, max_pages, max_bytes = cache_limits(tab_manager.settings)
+        if max_age not in CACHE_AGE_OPTIONS:
+            max_age = DEFAULT_MAX_AGE
+        max_age_dropdown.value = str(max_age)
+        max_pages_field.value = str(max_pages)
+        max_mb_field.value = str(max_bytes // 1_000_000)
+        disk_mb_field.value = str(disk_cache_limit(tab_manager.settings) // 1_000_000)
+        prefetch_switch.value = bool(tab_manager.settings.get("prefetch_links", False))
+        budget = prefetch_budget({**tab_manager.settings, "prefetch_links": True})
+        prefetch_kb_field.value = str(budget // 1000)
+        for field in (max_pages_field, max_mb_field, disk_mb_field, prefetch_kb_field):
+            field.error_text = None
+        cache = tab_manager.page_cache
+        pages = ntr("1 page", "{count} pages", len(cache))
+        usage.value = tr(
+            "{pages} cached, {size}",
+            pages=pages,
+            size=format_size(cache.size()),
+        )
+        if cache.disk is not None:
+            stored = ntr("1 page", "{count} pages", len(cache.disk))
+            usage.value += " · " + tr(
+                "{pages} on disk, {size}",
+                pages=stored,
+                size=format_size(cache.disk.size()),
+            )
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Page Cache"), size=16, weight=ft.FontWeight.BOLD),
+            max_age_dropdown,
+            ft.Row(
+                controls=[max_pages_field, max_mb_field, disk_mb_field],
+                spacing=16,
+                wrap=True,
+            ),
+            prefetch_switch,
+            prefetch_kb_field,
+            usage,
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Cache Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                    ft.TextButton(
+                        tr("View Cached Pages"),
+                        icon=ft.Icons.MANAGE_SEARCH,
+                        on_click=lambda _: tab_manager.open_link_in_new_tab(
+                            "about:cache",
+                        ),
+                    ),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+PRIVACY_SWITCHES = {
+    "record_history": "Record visited pages in the history",
+    "cache_pages": "Keep fetched pages in the page cache",
+    "private_tabs": "Open new tabs as private tabs",
+    "crash_report_tabs": "Include the addresses of open tabs in crash reports",
+    "check_updates": "Check GitHub for new releases once a day",
+    "clipboard_hints": "Offer to open node addresses found on the clipboard",
+}
+
+
+def _build_privacy_section(page: ft.Page, tab_manager, storage):
+    switches = {
+        key: ft.Switch(label=tr(label)) for key, label in PRIVACY_SWITCHES.items()
+    }
+
+    def save(_):
+        settings = {
+            **tab_manager.settings,
+            **{key: switch.value for key, switch in switches.items()},
+        }
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save privacy settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        notify.show_snack(page, tr("Privacy settings saved"))
+
+    def restore_defaults(_):
+        for key, switch in switches.items():
+            switch.value = DEFAULT_APP_SETTINGS[key]
+        page.update()
+
+    def refresh():
+        for key, switch in switches.items():
+            switch.value = tab_manager.settings.get(key, DEFAULT_APP_SETTINGS[key])
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Privacy"), size=18, weight=ft.FontWeight.BOLD),
+            *switches.values(),
+            ft.Text(
+                tr(
+                    "Private tabs add nothing to the history, the shared page "
+                    "cache, the saved session or the recently closed tabs. "
+                    "Their pages are cached in memory for the tab alone and "
+                    "forgotten when it closes. Ctrl+Shift+N opens one.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Privacy Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_downloads_section(page: ft.Page, tab_manager, storage):
+    directory_field = ft.TextField(
+        label=tr("Download folder"),
+        hint_text=str(tab_manager.downloads.default_directory),
+        expand=True,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+        on_change=lambda e: (check_directory(directory_field), page.update()),
+    )
+    ask_switch = ft.Switch(label=tr("Always ask where to save downloads"))
+
+    def on_folder_picked(e):  # type: ignore
+        if e.path:
+            directory_field.value = e.path
+            check_directory(directory_field)
+            page.update()
+
+    folder_picker = ft.FilePicker(on_result=on_folder_picked)
+    page.overlay.append(folder_picker)
+
+    def choose_folder(_):
+        folder_picker.get_directory_path(
+            dialog_title=tr("Choose Download Folder"),
+            initial_directory=str(tab_manager.downloads.directory),
+        )
+
+    def save(_):
+        directory = check_directory(directory_field)
+        if directory is None:
+            page.update()
+            notify.show_snack(page, tr("Fix the highlighted settings first."), False)
+            return
+        settings = {
+            **tab_manager.settings,
+            "download_dir": directory,
+            "ask_download_location": ask_switch.value,
+        }
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save download settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        notify.show_snack(page, tr("Download settings saved"))
+
+    def restore_defaults(_):
+        directory_field.value = DEFAULT_APP_SETTINGS["download_dir"]
+        directory_field.error_text = None
+        ask_switch.value = DEFAULT_APP_SETTINGS["ask_download_location"]
+        page.update()
+
+    def refresh():
+        settings = tab_manager.settings
+        directory_field.value = settings.get("download_dir", "")
+        directory_field.error_text = None
+        ask_switch.value = settings.get("ask_download_location", False)
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Downloads"), size=18, weight=ft.FontWeight.BOLD),
+            ft.Row(
+                controls=[
+                    directory_field,
+                    ft.OutlinedButton(
+                        tr("Choose Folder…"),
+                        icon=ft.Icons.FOLDER_OPEN,
+                        on_click=choose_folder,
+                    ),
+                ],
+                spacing=8,
+            ),
+            ask_switch,
+            ft.Text(
+                tr(
+                    "Leave the folder empty to use the default. Save Page As "
+                    "opens in this folder too.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Download Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_power_section(page: ft.Page, tab_manager, storage):
+    status_text = ft.Text("", size=13)
+    saver_switch = ft.Switch(label=tr("Save power on battery"))
+    level_dropdown = ft.Dropdown(
+        label=tr("Save power"),
+        options=[
+            ft.dropdown.Option(str(level), tr(label))
+            for level, label in SAVER_LEVELS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    factor_dropdown = ft.Dropdown(
+        label=tr("Wait between refreshes"),
+        options=[
+            ft.dropdown.Option(str(factor), tr(label))
+            for factor, label in INTERVAL_FACTORS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    prefetch_switch = ft.Switch(label=tr("Pause prefetching while saving power"))
+    unfocused_switch = ft.Switch(label=tr("Pause background work when unfocused"))
+    motion_switch = ft.Switch(label=tr("Reduce motion and redraws"))
+
+    def save(_):
+        settings = {
+            **tab_manager.settings,
+            "battery_saver": saver_switch.value,
+            "battery_saver_percent": int(level_dropdown.value),
+            "battery_interval_factor": int(factor_dropdown.value),
+            "battery_pause_prefetch": prefetch_switch.value,
+            "pause_unfocused": unfocused_switch.value,
+            "reduced_motion": motion_switch.value,
+        }
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save power settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        notify.show_snack(page, tr("Power settings saved"))
+
+    def restore_defaults(_):
+        saver_switch.value = DEFAULT_APP_SETTINGS["battery_saver"]
+        level_dropdown.value = str(DEFAULT_APP_SETTINGS["battery_saver_percent"])
+        factor_dropdown.value = str(DEFAULT_APP_SETTINGS["battery_interval_factor"])
+        prefetch_switch.value = DEFAULT_APP_SETTINGS["battery_pause_prefetch"]
+        unfocused_switch.value = DEFAULT_APP_SETTINGS["pause_unfocused"]
+        motion_switch.value = DEFAULT_APP_SETTINGS["reduced_motion"]
+        page.update()
+
+    def refresh():
+        settings = tab_manager.settings
+        monitor = tab_manager.power_monitor
+        status = describe_status(monitor.status if monitor else None)
+        status_text.value = tr("Power: {status}", status=status)
+        saver_switch.value = settings.get("battery_saver", True)
+        level_dropdown.value = str(saver_level(settings))
+        factor_dropdown.value = str(interval_factor(settings))
+        prefetch_switch.value = settings.get("battery_pause_prefetch", True)
+        unfocused_switch.value = settings.get("pause_unfocused", True)
+        motion_switch.value = tab_manager.reduced_motion()
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Power"), size=18, weight=ft.FontWeight.BOLD),
+            status_text,
+            saver_switch,
+            level_dropdown,
+            factor_dropdown,
+            prefetch_switch,
+            ft.Text(
+                tr(
+                    "While saving power the node list and the interface status "
+                    "are refreshed less often. The battery is checked every "
+                    "minute.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            unfocused_switch,
+            ft.Text(
+                tr(
+                    "While the window is unfocused or minimized the node list, "
+                    "the interface status and the performance overlay are not "
+                    "refreshed and nothing is prefetched. Watched pages and the "
+                    "feed are still checked.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            motion_switch,
+            ft.Text(
+                tr(
+                    "Reduced motion stops the loading spinners and the sidebar "
+                    "animation, and does not redraw for hovered links or every "
+                    "new announce. Useful on e-ink screens and solar power.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Power Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_accessibility_section(page: ft.Page, tab_manager, storage):
+    size_dropdown = ft.Dropdown(
+        label=tr("Minimum page text size"),
+        options=[
+            ft.dropdown.Option(str(size), tr(label))
+            for size, label in MIN_FONT_SIZES.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    readable_switch = ft.Switch(label=tr("Dyslexia-friendly page font"))
+
+    def save(_):
+        settings = {
+            **tab_manager.settings,
+            "min_font_size": int(size_dropdown.value),
+            "readable_font": readable_switch.value,
+        }
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save accessibility settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        notify.show_snack(page, tr("Accessibility settings saved"))
+
+    def restore_defaults(_):
+        size_dropdown.value = str(DEFAULT_APP_SETTINGS["min_font_size"])
+        readable_switch.value = DEFAULT_APP_SETTINGS["readable_font"]
+        page.update()
+
+    def refresh():
+        settings = tab_manager.settings
+        size_dropdown.value = str(min_font_size(settings))
+        readable_switch.value = settings.get("readable_font", False)
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Accessibility"), size=18, weight=ft.FontWeight.BOLD),
+            size_dropdown,
+            ft.Text(
+                tr(
+                    "Text on pages is never shown smaller than this, whatever "
+                    "size the page or its zoom asks for.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            readable_switch,
+            ft.Text(
+                tr(
+                    "Shows pages in {font} instead of the page font.",
+                    font=READABLE_FONT,
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Accessibility Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_logging_section(page: ft.Page, tab_manager, storage):
+    def dropdown(label, choices):
+        return ft.Dropdown(
+            label=tr(label),
+            options=[
+                ft.dropdown.Option(str(value), tr(text))
+                for value, text in choices.items()
+            ],
+            width=300,
+            border_color=ft.Colors.OUTLINE_VARIANT,
+            focused_border_color=ft.Colors.PRIMARY,
+        )
+
+    level_dropdown = dropdown("Write to the log file", LOG_FILE_LEVELS)
+    size_dropdown = dropdown("Start a new log file at", LOG_FILE_SIZES)
+    count_dropdown = dropdown("Keep", LOG_FILE_COUNTS)
+    path_text = ft.Text(
+        "",
+        size=12,
+        color=ft.Colors.ON_SURFACE_VARIANT,
+        selectable=True,
+    )
+
+    def save(_):
+        settings = {
+            **tab_manager.settings,
+            "log_file_level": level_dropdown.value,
+            "log_file_kb": int(size_dropdown.value),
+            "log_file_count": int(count_dropdown.value),
+        }
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save logging settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        refresh()
+        notify.show_snack(page, tr("Logging settings saved"))
+
+    def restore_defaults(_):
+        level_dropdown.value = DEFAULT_APP_SETTINGS["log_file_level"]
+        size_dropdown.value = str(DEFAULT_APP_SETTINGS["log_file_kb"])
+        count_dropdown.value = str(DEFAULT_APP_SETTINGS["log_file_count"])
+        page.update()
+
+    def refresh():
+        level, max_kb, count = log_file_options(tab_manager.settings)
+        level_dropdown.value = level
+        size_dropdown.value = str(max_kb)
+        count_dropdown.value = str(count)
+        path = log_file_path()
+        path_text.value = (
+            tr("Log file: {path}", path=path)
+            if path is not None
+            else tr("No log file is written.")
+        )
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Logging"), size=18, weight=ft.FontWeight.BOLD),
+            level_dropdown,
+            ft.Text(
+                tr(
+                    "Messages this important or more are written to a file, "
+                    "so there is something to look into after a crash.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            size_dropdown,
+            count_dropdown,
+            path_text,
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Logging Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                    ft.TextButton(
+                        tr("View Logs"),
+                        icon=ft.Icons.RECEIPT_LONG,
+                        on_click=lambda _: tab_manager.open_link_in_new_tab(
+                            "about:logs",
+                        ),
+                    ),
+                ],
+                spacing=8,
+                wrap=True,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_plugins_section(page: ft.Page, tab_manager, storage):
+    enabled_switch = ft.Switch(label=tr("Load plugins"))
+    folder_text = ft.Text(
+        "",
+        size=12,
+        color=ft.Colors.ON_SURFACE_VARIANT,
+        selectable=True,
+    )
+    plugins_column = ft.Column(spacing=8)
+
+    def save(_):
+        settings = {**tab_manager.settings, "plugins_enabled": enabled_switch.value}
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save plugin settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        notify.show_snack(
+            page,
+            tr("Plugin settings saved. Restart Ren Browser to apply them."),
+        )
+
+    def restore_defaults(_):
+        enabled_switch.value = DEFAULT_APP_SETTINGS["plugins_enabled"]
+        page.update()
+
+    def describe(plugin):
+        if plugin.error:
+            detail = tr("Failed to load: {error}", error=plugin.error)
+        elif plugin.hooks:
+            detail = ", ".join(plugin.hooks)
+        else:
+            detail = tr("Adds nothing")
+        return ft.Column(
+            spacing=2,
+            controls=[
+                ft.Text(plugin.name, size=14, weight=ft.FontWeight.BOLD),
+                ft.Text(
+                    plugin.source,
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                    selectable=True,
+                ),
+                ft.Text(
+                    detail,
+                    size=12,
+                    color=ft.Colors.ERROR if plugin.error else None,
+                ),
+            ],
+        )
+
+    def refresh():
+        enabled_switch.value = tab_manager.settings.get("plugins_enabled", False)
+        folder_text.value = tr(
+            "Plugin folder: {path}",
+            path=storage.get_plugins_path(),
+        )
+        plugins_column.controls = [
+            describe(plugin) for plugin in loaded_plugins()
+        ] or [
+            ft.Text(
+                tr("No plugins are loaded."),
+                size=13,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+        ]
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Plugins"), size=18, weight=ft.FontWeight.BOLD),
+            enabled_switch,
+            ft.Text(
+                tr(
+                    "Plugins are Python files in the plugin folder or installed "
+                    "packages. They can change pages, render new page formats and "
+                    "add sidebar panels. They run with the browser's own rights, "
+                    "so only load plugins you trust. Changes apply after a "
+                    "restart.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            folder_text,
+            plugins_column,
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Plugin Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_profiles_section(page: ft.Page, storage):
+    startup_dropdown = ft.Dropdown(
+        label=tr("Profile on startup"),
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    name_field = ft.TextField(
+        label=tr("New profile"),
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    def set_options():
+        startup_dropdown.options = [
+            ft.dropdown.Option(name) for name in storage.list_profiles()
+        ]
+
+    def create(_):
+        try:
+            name = storage.create_profile(name_field.value or "")
+        except (ProfileError, OSError) as exc:
+            name_field.error_text = str(exc)
+            page.update()
+            return
+        name_field.value = ""
+        name_field.error_text = None
+        set_options()
+        startup_dropdown.value = name
+        notify.show_snack(
+            page,
+            tr("Created the {name} profile. Save to start with it.", name=name),
+        )
+
+    def save(_):
+        name = startup_dropdown.value or DEFAULT_PROFILE
+        try:
+            storage.set_startup_profile(name)
+        except OSError as exc:
+            notify.show_snack(
+                page,
+                tr("Failed to save the startup profile: {error}", error=exc),
+                False,
+            )
+            return
+        notify.show_snack(
+            page,
+            tr("Ren Browser starts with the {name} profile from now on", name=name),
+        )
+
+    def restore_defaults(_):
+        startup_dropdown.value = DEFAULT_PROFILE
+        page.update()
+
+    def refresh():
+        set_options()
+        startup_dropdown.value = storage.startup_profile()
+        name_field.error_text = None
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Profiles"), size=18, weight=ft.FontWeight.BOLD),
+            ft.Text(tr("This window uses the {name} profile.", name=storage.profile)),
+            startup_dropdown,
+            ft.Row(
+                controls=[
+                    name_field,
+                    ft.OutlinedButton(
+                        tr("Create Profile"),
+                        icon=ft.Icons.PERSON_ADD,
+                        on_click=create,
+                    ),
+                ],
+                spacing=8,
+            ),
+            ft.Text(
+                tr(
+                    "Each profile has its own settings, history, bookmarks, cache "
+                    "and Reticulum identity. A profile can also be chosen when "
+                    "starting with --profile NAME.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Startup Profile"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_blocked_section(page: ft.Page, tab_manager):
+    section = ft.Column(spacing=8, scroll=ft.ScrollMode.AUTO, expand=True)
+
+    def unblock(destination_hash):
+        tab_manager.set_node_blocked(destination_hash, False)
+        refresh()
+        page.update()
+
+    def refresh():
+        blocked = tab_manager.nodes.blocked()
+        controls: list[ft.Control] = [
+            ft.Text(tr("Blocked Nodes"), size=18, weight=ft.FontWeight.BOLD),
+        ]
+        if not blocked:
+            controls.append(
+                ft.Text(
+                    tr(
+                        "No nodes are blocked. Block a node from its details in "
+                        "the sidebar.",
+                    ),
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.extend(
+            ft.Row(
+                controls=[
+                    ft.Column(
+                        spacing=2,
+                        expand=True,
+                        controls=[
+                            ft.Text(info.name or "Anonymous", size=14),
+                            ft.Text(
+                                destination_hash,
+                                size=12,
+                                font_family="monospace",
+                                color=ft.Colors.ON_SURFACE_VARIANT,
+                                selectable=True,
+                            ),
+                        ],
+                    ),
+                    ft.OutlinedButton(
+                        tr("Unblock"),
+                        icon=ft.Icons.LOCK_OPEN,
+                        on_click=lambda e, h=destination_hash: unblock(h),
+                    ),
+                ],
+            )
+            for destination_hash, info in blocked
+        )
+        section.controls = controls
+
+    return section, refresh
+
+
+def _build_aliases_section(page: ft.Page, tab_manager, storage):
+    keyword_field = ft.TextField(
+        label=tr("Keyword"),
+        width=180,
+        dense=True,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    address_field = ft.TextField(
+        label=tr("Address"),
+        hint_text="abc123…:/page/index.mu",
+        expand=True,
+        dense=True,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    rows = ft.Column(spacing=4)
+
+    def save_aliases(updated: dict[str, str]) -> bool:
+        settings = {**tab_manager.settings, "address_aliases": updated}
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save aliases"), False)
+            return False
+        tab_manager.settings = settings
+        return True
+
+    def add(_):
+        keyword = normalize_keyword(keyword_field.value or "")
+        keyword_field.error_text = keyword_error(keyword)
+        address = check_home_page(
+            address_field,
+            getattr(tab_manager, "resolve_node_name", None),
+        )
+        if address == "":
+            address_field.error_text = tr("Enter an address")
+        if keyword_field.error_text or address_field.error_text:
+            page.update()
+            return
+        if save_aliases({**aliases(tab_manager.settings), keyword: address}):
+            keyword_field.value = ""
+            address_field.value = ""
+            notify.show_snack(page, tr("Alias {keyword} saved", keyword=keyword))
+        refresh()
+        page.update()
+
+    def remove(keyword):
+        updated = aliases(tab_manager.settings)
+        updated.pop(keyword, None)
+        save_aliases(updated)
+        refresh()
+        page.update()
+
+    def refresh():
+        current = aliases(tab_manager.settings)
+        if not current:
+            rows.controls = [
+                ft.Text(tr("No aliases yet."), color=ft.Colors.ON_SURFACE_VARIANT),
+            ]
+            return
+        rows.controls = [
+            ft.Row(
+                controls=[
+                    ft.Text(keyword, size=14, width=180, weight=ft.FontWeight.W_500),
+                    ft.Text(
+                        address,
+                        size=12,
+                        expand=True,
+                        font_family="monospace",
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                        selectable=True,
+                    ),
+                    ft.IconButton(
+                        ft.Icons.DELETE_OUTLINE,
+                        tooltip=tr("Remove"),
+                        on_click=lambda e, k=keyword: remove(k),
+                    ),
+                ],
+            )
+            for keyword, address in sorted(current.items())
+        ]
+
+    section = ft.Column(
+        spacing=12,
+        scroll=ft.ScrollMode.AUTO,
+        expand=True,
+        controls=[
+            ft.Text(tr("Address Aliases"), size=18, weight=ft.FontWeight.BOLD),
+            ft.Text(
+                tr(
+                    "Type a keyword in the address bar to open the address it "
+                    "stands for, such as weather for a node's forecast page.",
+                ),
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            rows,
+            ft.Row(
+                controls=[
+                    keyword_field,
+                    address_field,
+                    ft.ElevatedButton(
+                        tr("Add Alias"),
+                        icon=ft.Icons.ADD,
+                        on_click=add,
+                        style=_blue_button_style(),
+                    ),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def _build_spelling_section(page: ft.Page, tab_manager, storage):
+    spelling = tab_manager.spelling
+    enabled_switch = ft.Switch(label=tr("Check spelling in input fields"))
+    language_dropdown = ft.Dropdown(
+        label=tr("Spelling language"),
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+    folder_text = ft.Text(
+        "",
+        size=12,
+        color=ft.Colors.ON_SURFACE_VARIANT,
+        selectable=True,
+    )
+
+    def on_dictionary_picked(e):  # type: ignore
+        if not e.files or not e.files[0].path:
+            return
+        try:
+            name = spelling.add_dictionary(Path(e.files[0].path))
+        except (OSError, ValueError) as exc:
+            notify.show_snack(
+                page,
+                tr("Failed to add dictionary: {error}", error=exc),
+                False,
+            )
+            return
+        refresh()
+        language_dropdown.value = name
+        notify.show_snack(page, tr("Dictionary {name} added", name=name))
+        page.update()
+
+    dictionary_picker = ft.FilePicker(on_result=on_dictionary_picked)
+    page.overlay.append(dictionary_picker)
+
+    def choose_dictionary(_):
+        dictionary_picker.pick_files(
+            dialog_title=tr("Add Dictionary"),
+            allowed_extensions=["dic", "txt"],
+        )
+
+    def save(_):
+        settings = {
+            **tab_manager.settings,
+            "spell_check": enabled_switch.value,
+            "spell_check_language": language_dropdown.value or "",
+        }
+        if not storage.save_app_settings(settings):
+            notify.show_snack(page, tr("Failed to save spelling settings"), False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        refresh()
+        notify.show_snack(page, tr("Spelling settings saved"))
+
+    def restore_defaults(_):
+        enabled_switch.value = DEFAULT_APP_SETTINGS["spell_check"]
+        language_dropdown.value = DEFAULT_APP_SETTINGS["spell_check_language"]
+        page.update()
+
+    def refresh():
+        settings = tab_manager.settings
+        names = sorted(spelling.dictionaries())
+        enabled_switch.value = settings.get("spell_check", True)
+        language_dropdown.options = [
+            ft.dropdown.Option("", tr("Same as the interface")),
+            *(ft.dropdown.Option(name) for name in names),
+        ]
+        language = settings.get("spell_check_language", "")
+        language_dropdown.value = language if language in names else ""
+        folder_text.value = tr("Dictionary folder: {path}", path=spelling.directory)
+        language_dropdown.error_text = (
+            None
+            if spelling.path is not None or not enabled_switch.value
+            else tr("No dictionary found for this language")
+        )
+
+    section = ft.Column(
+        spacing=12,
+        controls=[
+            ft.Text(tr("Spelling"), size=18, weight=ft.FontWeight.BOLD),
+            enabled_switch,
+            ft.Text(
+                tr(
+                    "Misspelled words are listed below the field they are typed "
+                    "in, with suggestions when clicked. Password fields are "
+                    "never checked.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            language_dropdown,
+            ft.Text(
+                tr(
+                    "Dictionaries are Hunspell .dic files or word lists with one "
+                    "word a line. The system's Hunspell dictionaries are found "
+                    "by themselves, and downloaded ones can be added.",
+                ),
+                size=12,
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            folder_text,
+            ft.OutlinedButton(
+                tr("Add Dictionary…"),
+                icon=ft.Icons.LIBRARY_ADD,
+                on_click=choose_dictionary,
+            ),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Save Spelling Settings"),
+                        icon=ft.Icons.SAVE,
+                        on_click=save,
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
+    return section, refresh
+
+
+def parse_chord_list(text: str) -> list[str]:
+    """Parse comma-separated chords, where ``Ctrl+,`` binds the comma key.
 
-    if total == 0:
-        summary_text.value = "No active interfaces"
-        summary_text.color = ft.Colors.ERROR
-    else:
-        summary_text.value = f"{online}/{total} interfaces online"
-        summary_text.color = ft.Colors.GREEN if online else ft.Colors.ERROR
+    Raises:
+        ValueError: If a chord is invalid.
 
-    chip_wrap.controls = _build_interface_chip_controls(statuses)
-    updated_text.value = f"Updated {datetime.now().strftime('%H:%M:%S')}"
+    """
+    parts = _CHORD_LIST_RE.findall(text or "")
+    return list(dict.fromkeys(parse_chord(part) for part in parts if part.strip()))
 
 
-def _build_status_section(page: ft.Page):
-    summary_text = ft.Text("", size=16, weight=ft.FontWeight.BOLD)
-    updated_text = ft.Text("", size=12, color=ft.Colors.ON_SURFACE_VARIANT)
-    chip_wrap = ft.Row(
-        spacing=6,
-        run_spacing=6,
-        vertical_alignment=ft.CrossAxisAlignment.CENTER,
-    )
+def _build_shortcuts_section(page: ft.Page, tab_manager, storage):
+    fields = {
+        action: ft.TextField(
+            label=tr(label),
+            hint_text=tr("None"),
+            dense=True,
+            border_color=ft.Colors.OUTLINE_VARIANT,
+            focused_border_color=ft.Colors.PRIMARY,
+        )
+        for action, (label, _) in ACTIONS.items()
+    }
 
-    def refresh(_=None):
-        _refresh_interface_status(summary_text, chip_wrap, updated_text)
+    def validate() -> dict[str, list[str]] | None:
+        bindings = {}
+        for action, field in fields.items():
+            field.error_text = None
+            try:
+                bindings[action] = parse_chord_list(field.value)
+            except ValueError as exc:
+                field.error_text = str(exc)
+        for chord, actions in find_conflicts(bindings).items():
+            for action in actions:
+                others = ", ".join(tr(ACTIONS[a][0]) for a in actions if a != action)
+                fields[action].error_text = tr(
+                    "{chord} is also used by {others}.",
+                    chord=chord,
+                    others=others,
+                )
+        if any(field.error_text for field in fields.values()):
+            return None
+        return bindings
+
+    def on_field_change(_):
+        validate()
         page.update()
 
-    refresh()
+    for field in fields.values():
+        field.on_change = on_field_change
 
-    refresh_button = ft.IconButton(
-        icon=ft.Icons.REFRESH,
-        tooltip="Refresh status",
-        on_click=refresh,
-        icon_color=ft.Colors.BLUE_200,
-    )
+    grouped_fields = []
+    for category, actions in ACTION_CATEGORIES.items():
+        grouped_fields.append(ft.Text(tr(category), weight=ft.FontWeight.BOLD))
+        grouped_fields.extend(fields[action] for action in actions)
+
+    def save(_):
+        bindings = validate()
+        if bindings is None:
+            page.update()
+            notify.show_snack(
+                page,
+                tr("Fix the highlighted shortcuts first."),
+                success=False,
+            )
+            return
+        overrides = {
+            action: chords
+            for action, chords in bindings.items()
+            if chords != list(ACTIONS[action][1])
+        }
+        settings = {**tab_manager.settings, "keybindings": overrides}
+        tab_manager.settings = settings
+        storage.save_app_settings(settings)
+        notify.show_snack(page, tr("Shortcuts saved"))
+
+    def restore_defaults(_):
+        for action, field in fields.items():
+            field.value = ", ".join(ACTIONS[action][1])
+        validate()
+        page.update()
+
+    def refresh():
+        bindings = keybindings(tab_manager.settings.get("keybindings"))
+        for action, field in fields.items():
+            field.value = ", ".join(bindings[action])
+        validate()
 
     section = ft.Column(
         spacing=12,
+        scroll=ft.ScrollMode.AUTO,
+        expand=True,
         controls=[
+            ft.Text(tr("Keyboard Shortcuts"), size=18, weight=ft.FontWeight.BOLD),
+            ft.Text(
+                tr(
+                    "Separate several shortcuts for one action with commas. "
+                    "Leave a field empty to turn its shortcut off.",
+                ),
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+            *grouped_fields,
             ft.Row(
                 controls=[
-                    ft.Row(
-                        controls=[
-                            ft.Icon(ft.Icons.LAN, size=18, color=ft.Colors.BLUE_200),
-                            summary_text,
-                        ],
-                        spacing=6,
-                        vertical_alignment=ft.CrossAxisAlignment.CENTER,
+                    ft.ElevatedButton(
+                        tr("Save Shortcuts"),
+                        icon=ft.Icons.KEYBOARD,
+                        on_click=save,
+                        style=_blue_button_style(),
                     ),
-                    refresh_button,
+                    ft.TextButton(tr("Restore Defaults"), on_click=restore_defaults),
                 ],
-                alignment=ft.MainAxisAlignment.SPACE_BETWEEN,
-                vertical_alignment=ft.CrossAxisAlignment.CENTER,
+                spacing=8,
             ),
-            chip_wrap,
-            updated_text,
         ],
     )
-
     return section, refresh
 
 
-def _build_storage_field(storage):
-    storage_field = ft.TextField(
-        label="Storage Information",
-        value="",
-        expand=True,
-        multiline=True,
-        read_only=True,
-        min_lines=10,
-        max_lines=15,
-        border_color=ft.Colors.GREY_700,
-        text_style=ft.TextStyle(font_family="monospace", size=12),
-    )
-
-    def refresh():
-        info = storage.get_storage_info()
-        storage_field.value = "\n".join(
-            f"{key}: {value}" for key, value in info.items()
-        )
-
-    refresh()
-    return storage_field, refresh
-
-
 def open_settings_tab(page: ft.Page, tab_manager):
     """Open a settings tab with configuration, status, and storage info."""
     storage = get_storage_manager(page)
@@ -212,29 +1618,263 @@ def open_settings_tab(page: ft.Page, tab_manager):
     app_settings = storage.load_app_settings()
 
     config_field = ft.TextField(
-        label="Reticulum Configuration",
+        label=tr("Reticulum Configuration"),
         value=config_text,
         expand=True,
         multiline=True,
         min_lines=15,
         max_lines=20,
-        border_color=ft.Colors.GREY_700,
-        focused_border_color=ft.Colors.BLUE_400,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
         text_style=ft.TextStyle(font_family="monospace", size=12),
     )
 
     horizontal_scroll_switch = ft.Switch(
-        label="Enable Horizontal Scroll (preserve ASCII art)",
+        label=tr("Enable Horizontal Scroll (preserve ASCII art)"),
         value=app_settings.get("horizontal_scroll", False),
     )
 
+    always_show_address_bar_switch = ft.Switch(
+        label=tr("Always show address bar"),
+        value=app_settings.get("always_show_address_bar", True),
+    )
+
+    show_status_bar_switch = ft.Switch(
+        label=tr("Show status bar"),
+        value=app_settings.get("show_status_bar", True),
+    )
+
+    link_preview_switch = ft.Switch(
+        label=tr("Preview links on hover"),
+        value=app_settings.get("link_preview", True),
+    )
+
+    favorite_notifications_switch = ft.Switch(
+        label=tr("Notify when favorite nodes come back online"),
+        value=app_settings.get("favorite_notifications", True),
+    )
+
+    system_notifications_switch = ft.Switch(
+        label=tr("Also show desktop notifications"),
+        value=app_settings.get("system_notifications", False),
+    )
+
+    # Desktop notifications by category, see NOTIFY_CATEGORIES
+    notify_switches = {
+        key: ft.Switch(label=tr(label), value=app_settings.get(key, True))
+        for key, label in (
+            ("notify_favorites", "Desktop notifications for favorite nodes"),
+            ("notify_watched", "Desktop notifications for changed watched pages"),
+            ("notify_downloads", "Desktop notifications for finished downloads"),
+        )
+    }
+
+    home_page_field = ft.TextField(
+        label=tr("Home page"),
+        value=app_settings.get("home_page", ""),
+        hint_text=tr("hash:/page/index.mu (empty for the new tab page)"),
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    startup_dropdown = ft.Dropdown(
+        label=tr("On startup"),
+        value=app_settings.get("startup", STARTUP_NEW_TAB),
+        options=[
+            ft.dropdown.Option(key, tr(label))
+            for key, label in STARTUP_OPTIONS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    confirm_close_switch = ft.Switch(
+        label=tr("Ask before closing with several tabs or running downloads"),
+        value=app_settings.get("confirm_close", True),
+    )
+
+    tray_icon_switch = ft.Switch(
+        label=tr("Show an icon in the system tray"),
+        value=app_settings.get("tray_icon", False),
+        visible=not page.web,
+    )
+
+    close_to_tray_switch = ft.Switch(
+        label=tr("Close to the tray icon instead of quitting"),
+        value=app_settings.get("close_to_tray", False),
+        visible=not page.web,
+    )
+
+    theme_dropdown = ft.Dropdown(
+        label=tr("Theme"),
+        value=app_settings.get("theme", "dark"),
+        options=[
+            ft.dropdown.Option(key, tr(label)) for key, label in THEME_OPTIONS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    language_dropdown = ft.Dropdown(
+        label=tr("Language"),
+        value=app_settings.get("language", SYSTEM_LANGUAGE),
+        options=[
+            ft.dropdown.Option(SYSTEM_LANGUAGE, tr("System default")),
+            *(
+                ft.dropdown.Option(code, name)
+                for code, name in available_languages().items()
+            ),
+        ],
+        helper_text=tr("Takes effect after a restart"),
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    def refresh_theme_options():
+        options = theme_options(tab_manager.themes)
+        theme_dropdown.options = [
+            ft.dropdown.Option(key, tr(label)) for key, label in options.items()
+        ]
+        if theme_dropdown.value not in options:
+            theme_dropdown.value = "dark"
+
+    def on_themes_changed(previous, name):
+        active = tab_manager.settings.get("theme")
+        if previous is not None and active == CUSTOM_PREFIX + previous:
+            theme = CUSTOM_PREFIX + name if name else "dark"
+            settings = {**tab_manager.settings, "theme": theme}
+            storage.save_app_settings(settings)
+            tab_manager.apply_settings(settings)
+            theme_dropdown.value = theme
+        refresh_theme_options()
+        page.update()
+
+    theme_editor = ThemeEditor(page, tab_manager.themes, on_themes_changed)
+    def on_edit_themes(_):
+        value = theme_dropdown.value or ""
+        theme_editor.open(
+            value.removeprefix(CUSTOM_PREFIX)
+            if value.startswith(CUSTOM_PREFIX)
+            else None,
+        )
+
+    edit_themes_btn = ft.OutlinedButton(
+        tr("Edit Themes…"),
+        icon=ft.Icons.COLOR_LENS,
+        on_click=on_edit_themes,
+    )
+
+    ui_font_picker = FontPicker(
+        page,
+        tr("Interface font"),
+        app_settings.get("ui_font", ""),
+        UI_FONT_PRESETS,
+    )
+    content_font_picker = FontPicker(
+        page,
+        tr("Page font"),
+        app_settings.get("content_font", ""),
+        CONTENT_FONT_PRESETS,
+    )
+
+    stale_after_dropdown = ft.Dropdown(
+        label=tr("Nodes are stale after"),
+        value=str(app_settings.get("stale_after_hours", 6)),
+        options=[
+            ft.dropdown.Option(str(hours), tr(label))
+            for hours, label in STALE_AFTER_OPTIONS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    stale_nodes_dropdown = ft.Dropdown(
+        label=tr("Stale nodes in the sidebar"),
+        value=app_settings.get("stale_nodes", "section"),
+        options=[
+            ft.dropdown.Option(key, tr(label))
+            for key, label in STALE_NODE_MODES.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    status_poll_dropdown = ft.Dropdown(
+        label=tr("Check interface status every"),
+        value=str(poll_interval(app_settings)),
+        options=[
+            ft.dropdown.Option(str(seconds), tr(label))
+            for seconds, label in POLL_OPTIONS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    node_refresh_dropdown = ft.Dropdown(
+        label=tr("Refresh the node list every"),
+        value=str(node_refresh_interval(app_settings)),
+        options=[
+            ft.dropdown.Option(str(seconds), tr(label))
+            for seconds, label in NODE_REFRESH_OPTIONS.items()
+        ],
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    node_refresh_sidebar_only_switch = ft.Switch(
+        label=tr("Only refresh the node list while the sidebar is shown"),
+        value=app_settings.get("node_refresh_sidebar_only", True),
+    )
+
+    sidebar_width_field = ft.TextField(
+        label=tr("Sidebar width"),
+        value=str(app_settings.get("sidebar_width", SIDEBAR_WIDTH)),
+        hint_text=tr(
+            "{low} to {high} pixels",
+            low=MIN_SIDEBAR_WIDTH,
+            high=MAX_SIDEBAR_WIDTH,
+        ),
+        width=200,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    page_colors_dropdown = ft.Dropdown(
+        label=tr("Page colours"),
+        value=app_settings.get("page_colors", "theme"),
+        options=[
+            ft.dropdown.Option(key, tr(label))
+            for key, label in PAGE_COLOR_OPTIONS.items()
+        ],
+        helper_text=tr("Pages can be read in other colours than the theme's"),
+        width=300,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
     page_bgcolor_field = ft.TextField(
-        label="Page Background Color (hex)",
+        label=tr("Page Background Color (hex)"),
         value=app_settings.get("page_bgcolor", "#000000"),
         hint_text="#000000",
         width=200,
-        border_color=ft.Colors.GREY_700,
-        focused_border_color=ft.Colors.BLUE_400,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
+
+    page_fgcolor_field = ft.TextField(
+        label=tr("Page Text Color (hex)"),
+        value=app_settings.get("page_fgcolor", "#ffffff"),
+        hint_text="#ffffff",
+        width=200,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
     )
 
     color_preview = ft.Container(
@@ -242,21 +1882,63 @@ def open_settings_tab(page: ft.Page, tab_manager):
         height=40,
         bgcolor=app_settings.get("page_bgcolor", "#000000"),
         border_radius=8,
-        border=ft.border.all(1, ft.Colors.GREY_700),
+        border=ft.border.all(1, ft.Colors.OUTLINE_VARIANT),
+        alignment=ft.alignment.center,
+        content=ft.Text(
+            "Aa",
+            color=app_settings.get("page_fgcolor", "#ffffff"),
+            weight=ft.FontWeight.BOLD,
+        ),
     )
 
-    def on_bgcolor_change(_):
-        try:
+    custom_page_colors = ft.Row(
+        controls=[page_bgcolor_field, page_fgcolor_field, color_preview],
+        alignment=ft.MainAxisAlignment.START,
+        spacing=16,
+        visible=page_colors_dropdown.value == "custom",
+    )
+
+    def validate_appearance():
+        """Check the typed settings, showing an error under invalid ones.
+
+        Returns:
+            The checked values by setting, or None if any is invalid.
+
+        """
+        values = {
+            "home_page": check_home_page(
+                home_page_field,
+                getattr(tab_manager, "resolve_node_name", None),
+            ),
+            "sidebar_width": check_number(
+                sidebar_width_field,
+                MIN_SIDEBAR_WIDTH,
+                MAX_SIDEBAR_WIDTH,
+            ),
+            "page_bgcolor": check_color(page_bgcolor_field),
+            "page_fgcolor": check_color(page_fgcolor_field),
+        }
+        if any(value is None for value in values.values()):
+            return None
+        return values
+
+    def on_appearance_field_change(_):
+        validate_appearance()
+        if page_bgcolor_field.error_text is None:
             color_preview.bgcolor = page_bgcolor_field.value
-            page.update()
-        except Exception as exc:
-            logger.warning(
-                "Ignoring invalid background color '%s': %s",
-                page_bgcolor_field.value,
-                exc,
-            )
+        if page_fgcolor_field.error_text is None:
+            color_preview.content.color = page_fgcolor_field.value
+        custom_page_colors.visible = page_colors_dropdown.value == "custom"
+        page.update()
 
-    page_bgcolor_field.on_change = on_bgcolor_change
+    for field in (
+        home_page_field,
+        sidebar_width_field,
+        page_bgcolor_field,
+        page_fgcolor_field,
+        page_colors_dropdown,
+    ):
+        field.on_change = on_appearance_field_change
 
     def show_snack(message, *, success=True):
         snack = ft.SnackBar(
@@ -281,15 +1963,21 @@ def open_settings_tab(page: ft.Page, tab_manager):
     def on_save_config(_):
         try:
             _write_config_text(config_path, config_field.value)
-            show_snack(f"Configuration saved to {config_path}")
+            show_snack(tr("Configuration saved to {path}", path=config_path))
         except Exception as exc:  # noqa: BLE001
-            show_snack(f"Failed to save configuration: {exc}", success=False)
+            show_snack(
+                tr("Failed to save configuration: {error}", error=exc),
+                success=False,
+            )
 
     def on_save_and_reload_config(_):
         try:
             _write_config_text(config_path, config_field.value)
         except Exception as exc:  # noqa: BLE001
-            show_snack(f"Failed to save configuration: {exc}", success=False)
+            show_snack(
+                tr("Failed to save configuration: {error}", error=exc),
+                success=False,
+            )
             return
 
         loading_snack = ft.SnackBar(
@@ -299,9 +1987,9 @@ def open_settings_tab(page: ft.Page, tab_manager):
                         width=16,
                         height=16,
                         stroke_width=2,
-                        color=ft.Colors.BLUE_400,
+                        color=ft.Colors.PRIMARY,
                     ),
-                    ft.Text("Reloading Reticulum...", color=ft.Colors.WHITE),
+                    ft.Text(tr("Reloading Reticulum..."), color=ft.Colors.WHITE),
                 ],
                 tight=True,
             ),
@@ -320,136 +2008,633 @@ def open_settings_tab(page: ft.Page, tab_manager):
             except Exception as exc:  # noqa: BLE001
                 loading_snack.open = False
                 page.update()
-                show_snack(f"Reload failed: {exc}", success=False)
+                show_snack(tr("Reload failed: {error}", error=exc), success=False)
 
         def on_reload_complete(success, error):
             loading_snack.open = False
             page.update()
             if success:
-                show_snack("Reticulum reloaded successfully!")
+                show_snack(tr("Reticulum reloaded successfully!"))
             else:
-                show_snack(f"Reload failed: {error}", success=False)
+                show_snack(tr("Reload failed: {error}", error=error), success=False)
 
         page.run_task(do_reload)
 
+    def refresh_appearance(settings):
+        theme_dropdown.value = settings.get("theme", "dark")
+        refresh_theme_options()
+        ui_font_picker.set_value(settings.get("ui_font", ""))
+        content_font_picker.set_value(settings.get("content_font", ""))
+        horizontal_scroll_switch.value = settings.get("horizontal_scroll", False)
+        always_show_address_bar_switch.value = settings.get(
+            "always_show_address_bar",
+            True,
+        )
+        show_status_bar_switch.value = settings.get("show_status_bar", True)
+        link_preview_switch.value = settings.get("link_preview", True)
+        favorite_notifications_switch.value = settings.get(
+            "favorite_notifications",
+            True,
+        )
+        system_notifications_switch.value = settings.get("system_notifications", False)
+        for key, switch in notify_switches.items():
+            switch.value = settings.get(key, True)
+        home_page_field.value = settings.get("home_page", "")
+        startup_dropdown.value = settings.get("startup", STARTUP_NEW_TAB)
+        confirm_close_switch.value = settings.get("confirm_close", True)
+        tray_icon_switch.value = settings.get("tray_icon", False)
+        close_to_tray_switch.value = settings.get("close_to_tray", False)
+        stale_after_dropdown.value = str(settings.get("stale_after_hours", 6))
+        stale_nodes_dropdown.value = settings.get("stale_nodes", "section")
+        status_poll_dropdown.value = str(poll_interval(settings))
+        node_refresh_dropdown.value = str(node_refresh_interval(settings))
+        node_refresh_sidebar_only_switch.value = settings.get(
+            "node_refresh_sidebar_only",
+            True,
+        )
+        language_dropdown.value = settings.get("language", SYSTEM_LANGUAGE)
+        sidebar_width_field.value = str(settings.get("sidebar_width", SIDEBAR_WIDTH))
+        page_colors_dropdown.value = settings.get("page_colors", "theme")
+        page_bgcolor_field.value = settings.get("page_bgcolor", "#000000")
+        page_fgcolor_field.value = settings.get("page_fgcolor", "#ffffff")
+        color_preview.bgcolor = page_bgcolor_field.value
+        color_preview.content.color = page_fgcolor_field.value
+        custom_page_colors.visible = page_colors_dropdown.value == "custom"
+        validate_appearance()
+
     def on_save_app_settings(_):
+        values = validate_appearance()
+        if values is None:
+            page.update()
+            show_snack(tr("Fix the highlighted settings first."), success=False)
+            return
         try:
             new_settings = {
+                # Reloaded so settings saved elsewhere since the tab opened,
+                # such as shortcuts or an import, are kept.
+                **storage.load_app_settings(),
+                "theme": theme_dropdown.value or "dark",
+                "ui_font": ui_font_picker.value,
+                "content_font": content_font_picker.value,
                 "horizontal_scroll": horizontal_scroll_switch.value,
-                "page_bgcolor": page_bgcolor_field.value,
+                "page_colors": page_colors_dropdown.value or "theme",
+                "page_bgcolor": values["page_bgcolor"],
+                "page_fgcolor": values["page_fgcolor"],
+                "always_show_address_bar": always_show_address_bar_switch.value,
+                "show_status_bar": show_status_bar_switch.value,
+                "link_preview": link_preview_switch.value,
+                "favorite_notifications": favorite_notifications_switch.value,
+                "system_notifications": system_notifications_switch.value,
+                **{key: switch.value for key, switch in notify_switches.items()},
+                "home_page": values["home_page"],
+                "sidebar_width": values["sidebar_width"],
+                "startup": startup_dropdown.value or STARTUP_NEW_TAB,
+                "confirm_close": confirm_close_switch.value,
+                "tray_icon": tray_icon_switch.value,
+                "close_to_tray": close_to_tray_switch.value,
+                "stale_after_hours": int(stale_after_dropdown.value or 6),
+                "stale_nodes": stale_nodes_dropdown.value or "section",
+                "status_poll_seconds": int(
+                    status_poll_dropdown.value or POLL_INTERVAL,
+                ),
+                "node_refresh_seconds": int(
+                    node_refresh_dropdown.value or NODE_REFRESH_INTERVAL,
+                ),
+                "node_refresh_sidebar_only": node_refresh_sidebar_only_switch.value,
+                "language": language_dropdown.value or SYSTEM_LANGUAGE,
             }
             success = storage.save_app_settings(new_settings)
             if success:
                 if hasattr(tab_manager, "apply_settings"):
                     tab_manager.apply_settings(new_settings)
-                show_snack("Appearance settings saved and applied!")
+                show_snack(tr("Appearance settings saved and applied!"))
             else:
-                show_snack("Failed to save appearance settings", success=False)
+                show_snack(tr("Failed to save appearance settings"), success=False)
         except Exception as exc:  # noqa: BLE001
-            show_snack(f"Error saving appearance: {exc}", success=False)
+            show_snack(
+                tr("Error saving appearance: {error}", error=exc),
+                success=False,
+            )
 
     save_btn = ft.ElevatedButton(
-        "Save Configuration",
+        tr("Save Configuration"),
         icon=ft.Icons.SAVE,
         on_click=on_save_config,
         style=_blue_button_style(),
     )
     save_reload_btn = ft.ElevatedButton(
-        "Save & Hot Reload",
+        tr("Save & Hot Reload"),
         icon=ft.Icons.REFRESH,
         on_click=on_save_and_reload_config,
         style=_blue_button_style(),
     )
     save_appearance_btn = ft.ElevatedButton(
-        "Save Appearance",
+        tr("Save Appearance"),
         icon=ft.Icons.PALETTE,
         on_click=on_save_app_settings,
         style=_blue_button_style(),
     )
 
-    status_content, refresh_status_section = _build_status_section(page)
+    status_content, refresh_status_section = _build_status_section(page, tab_manager)
     storage_field, refresh_storage_info = _build_storage_field(storage)
+    clear_data_dialog = ClearDataDialog(page, tab_manager)
+
+    def on_settings_imported():
+        refresh_appearance(tab_manager.settings)
+        refresh_cache()
+        refresh_privacy()
+        refresh_downloads()
+        refresh_shortcuts()
+        page.update()
+
+    def restore_appearance_defaults():
+        refresh_appearance(DEFAULT_APP_SETTINGS)
+        page.update()
+
+    def reset_all_settings():
+        page.close(reset_dialog)
+        # Settings of newer releases are theirs to reset
+        settings = {**unknown_settings(tab_manager.settings), **DEFAULT_APP_SETTINGS}
+        if not storage.save_app_settings(settings):
+            show_snack(tr("Failed to reset the settings"), success=False)
+            return
+        tab_manager.settings = settings
+        tab_manager.apply_settings(settings)
+        on_settings_imported()
+        show_snack(tr("All settings were reset to their defaults"))
+
+    reset_dialog = ft.AlertDialog(
+        modal=True,
+        title=ft.Text(tr("Reset All Settings")),
+        content=ft.Text(
+            tr(
+                "Every setting and keyboard shortcut goes back to its default. "
+                "Bookmarks, history and custom themes are kept.",
+            ),
+        ),
+        actions=[
+            ft.TextButton(tr("Cancel"), on_click=lambda e: page.close(reset_dialog)),
+            ft.FilledButton(tr("Reset"), on_click=lambda e: reset_all_settings()),
+        ],
+    )
+
+    settings_files = SettingsFiles(page, tab_manager, storage, on_settings_imported)
+    cache_content, refresh_cache = _build_cache_section(page, tab_manager, storage)
+    blocked_content, refresh_blocked = _build_blocked_section(page, tab_manager)
+    aliases_content, refresh_aliases = _build_aliases_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_aliases()
+    spelling_content, refresh_spelling = _build_spelling_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_spelling()
+    privacy_content, refresh_privacy = _build_privacy_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_privacy()
+    downloads_content, refresh_downloads = _build_downloads_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_downloads()
+    power_content, refresh_power = _build_power_section(page, tab_manager, storage)
+    refresh_power()
+    accessibility_content, refresh_accessibility = _build_accessibility_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_accessibility()
+    logging_content, refresh_logging = _build_logging_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_logging()
+    plugins_content, refresh_plugins = _build_plugins_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    refresh_plugins()
+    profiles_content, refresh_profiles = _build_profiles_section(page, storage)
+    refresh_profiles()
+    shortcuts_content, refresh_shortcuts = _build_shortcuts_section(
+        page,
+        tab_manager,
+        storage,
+    )
+    storage_content = ft.Column(
+        expand=True,
+        spacing=16,
+        controls=[
+            storage_field,
+            ft.ElevatedButton(
+                tr("Clear Browsing Data…"),
+                icon=ft.Icons.DELETE_SWEEP,
+                on_click=lambda e: clear_data_dialog.open(),
+                style=_blue_button_style(),
+            ),
+            cache_content,
+            ft.Text(tr("Backup"), size=16, weight=ft.FontWeight.BOLD),
+            ft.Row(
+                controls=[
+                    ft.ElevatedButton(
+                        tr("Export Settings…"),
+                        icon=ft.Icons.UPLOAD_FILE,
+                        on_click=lambda e: settings_files.choose_export(),
+                        style=_blue_button_style(),
+                    ),
+                    ft.ElevatedButton(
+                        tr("Import Settings…"),
+                        icon=ft.Icons.FILE_OPEN,
+                        on_click=lambda e: settings_files.choose_import(),
+                        style=_blue_button_style(),
+                    ),
+                    ft.TextButton(
+                        tr("Reset All Settings…"),
+                        icon=ft.Icons.RESTART_ALT,
+                        on_click=lambda e: open_dialog(page, reset_dialog),
+                    ),
+                ],
+                spacing=8,
+            ),
+        ],
+    )
 
     appearance_content = ft.Column(
         spacing=16,
         controls=[
-            ft.Text("Appearance Settings", size=18, weight=ft.FontWeight.BOLD),
-            horizontal_scroll_switch,
+            ft.Text(tr("Appearance Settings"), size=18, weight=ft.FontWeight.BOLD),
             ft.Row(
-                controls=[page_bgcolor_field, color_preview],
-                alignment=ft.MainAxisAlignment.START,
+                controls=[theme_dropdown, edit_themes_btn],
                 spacing=16,
             ),
-            save_appearance_btn,
+            language_dropdown,
+            ui_font_picker.control,
+            content_font_picker.control,
+            horizontal_scroll_switch,
+            always_show_address_bar_switch,
+            show_status_bar_switch,
+            link_preview_switch,
+            favorite_notifications_switch,
+            system_notifications_switch,
+            *notify_switches.values(),
+            startup_dropdown,
+            confirm_close_switch,
+            tray_icon_switch,
+            close_to_tray_switch,
+            home_page_field,
+            stale_after_dropdown,
+            stale_nodes_dropdown,
+            status_poll_dropdown,
+            node_refresh_dropdown,
+            node_refresh_sidebar_only_switch,
+            sidebar_width_field,
+            page_colors_dropdown,
+            custom_page_colors,
+            ft.Row(
+                controls=[
+                    save_appearance_btn,
+                    ft.TextButton(
+                        tr("Restore Defaults"),
+                        on_click=lambda e: restore_appearance_defaults(),
+                    ),
+                ],
+                spacing=8,
+            ),
         ],
     )
 
     content_placeholder = ft.Container(expand=True, content=config_field)
+    sections = {
+        "Configuration": config_field,
+        "Appearance": appearance_content,
+        "Status": status_content,
+        "Storage": storage_content,
+        "Privacy": privacy_content,
+        "Downloads": downloads_content,
+        "Power": power_content,
+        "Accessibility": accessibility_content,
+        "Logging": logging_content,
+        "Plugins": plugins_content,
+        "Profiles": profiles_content,
+        "Blocked Nodes": blocked_content,
+        "Aliases": aliases_content,
+        "Spelling": spelling_content,
+        "Shortcuts": shortcuts_content,
+    }
+    expands = {name: section.expand for name, section in sections.items()}
+    search_results = ft.Column(spacing=24, scroll=ft.ScrollMode.AUTO, expand=True)
+    # View to go back to once the search is cleared
+    shown_view = [config_field]
+
+    def on_search(_):
+        query = (search_field.value or "").strip()
+        if not query:
+            end_search()
+            page.update()
+            return
+        if content_placeholder.content is not search_results:
+            shown_view[0] = content_placeholder.content
+            refresh_theme_options()
+            ui_font_picker.set_families(tab_manager.fonts)
+            content_font_picker.set_families(tab_manager.fonts)
+            refresh_privacy()
+            refresh_downloads()
+            refresh_power()
+            refresh_accessibility()
+            refresh_logging()
+            refresh_plugins()
+            refresh_profiles()
+            refresh_blocked()
+            refresh_aliases()
+            refresh_spelling()
+        results = []
+        for name, section in sections.items():
+            in_name = query.lower() in name.lower()
+            if filter_settings(section, "" if in_name else query):
+                # Sections fill the view when shown alone, but are stacked
+                # in a scrolling list here.
+                section.expand = False
+                results.append(
+                    ft.Column(
+                        spacing=8,
+                        controls=[
+                            ft.Text(
+                                name,
+                                size=18,
+                                weight=ft.FontWeight.BOLD,
+                                color=ft.Colors.PRIMARY,
+                            ),
+                            section,
+                        ],
+                    ),
+                )
+        search_results.controls = results or [
+            ft.Text(
+                tr("No settings match “{query}”", query=query),
+                color=ft.Colors.ON_SURFACE_VARIANT,
+            ),
+        ]
+        content_placeholder.content = search_results
+        page.update()
+
+    def end_search():
+        if content_placeholder.content is search_results:
+            content_placeholder.content = shown_view[0]
+        search_field.value = ""
+        search_results.controls = []
+        for name, section in sections.items():
+            filter_settings(section, "")
+            section.expand = expands[name]
+
+    search_field = ft.TextField(
+        hint_text=tr("Search settings"),
+        prefix_icon=ft.Icons.SEARCH,
+        width=300,
+        dense=True,
+        on_change=on_search,
+        border_color=ft.Colors.OUTLINE_VARIANT,
+        focused_border_color=ft.Colors.PRIMARY,
+    )
 
     def show_config(_):
+        end_search()
         content_placeholder.content = config_field
         page.update()
 
     def show_appearance(_):
+        end_search()
+        refresh_theme_options()
+        ui_font_picker.set_families(tab_manager.fonts)
+        content_font_picker.set_families(tab_manager.fonts)
         content_placeholder.content = appearance_content
         page.update()
 
     def show_status(_):
+        end_search()
         content_placeholder.content = status_content
         refresh_status_section()
 
     def show_storage_info(_):
+        end_search()
         refresh_storage_info()
-        content_placeholder.content = storage_field
+        refresh_cache()
+        content_placeholder.content = storage_content
+        page.update()
+
+    def show_privacy(_):
+        end_search()
+        refresh_privacy()
+        content_placeholder.content = privacy_content
+        page.update()
+
+    def show_downloads(_):
+        end_search()
+        refresh_downloads()
+        content_placeholder.content = downloads_content
+        page.update()
+
+    def show_power(_):
+        end_search()
+        refresh_power()
+        content_placeholder.content = power_content
+        page.update()
+
+    def show_accessibility(_):
+        end_search()
+        refresh_accessibility()
+        content_placeholder.content = accessibility_content
+        page.update()
+
+    def show_logging(_):
+        end_search()
+        refresh_logging()
+        content_placeholder.content = logging_content
+        page.update()
+
+    def show_plugins(_):
+        end_search()
+        refresh_plugins()
+        content_placeholder.content = plugins_content
+        page.update()
+
+    def show_profiles(_):
+        end_search()
+        refresh_profiles()
+        content_placeholder.content = profiles_content
+        page.update()
+
+    def show_blocked(_):
+        end_search()
+        refresh_blocked()
+        content_placeholder.content = blocked_content
+        page.update()
+
+    def show_aliases(_):
+        end_search()
+        refresh_aliases()
+        content_placeholder.content = aliases_content
+        page.update()
+
+    def show_spelling(_):
+        end_search()
+        refresh_spelling()
+        content_placeholder.content = spelling_content
+        page.update()
+
+    def show_shortcuts(_):
+        end_search()
+        refresh_shortcuts()
+        content_placeholder.content = shortcuts_content
         page.update()
 
     def refresh_current_view(_):
         if content_placeholder.content == status_content:
             refresh_status_section()
-        elif content_placeholder.content == storage_field:
+        elif content_placeholder.content == storage_content:
             refresh_storage_info()
+            refresh_cache()
+            page.update()
+        elif content_placeholder.content == blocked_content:
+            refresh_blocked()
+            page.update()
+        elif content_placeholder.content == power_content:
+            refresh_power()
             page.update()
 
     btn_config = ft.FilledButton(
-        "Configuration",
+        tr("Configuration"),
         icon=ft.Icons.SETTINGS,
         on_click=show_config,
         style=_blue_button_style(),
     )
     btn_appearance = ft.FilledButton(
-        "Appearance",
+        tr("Appearance"),
         icon=ft.Icons.PALETTE,
         on_click=show_appearance,
         style=_blue_button_style(),
     )
     btn_status = ft.FilledButton(
-        "Status",
+        tr("Status"),
         icon=ft.Icons.LAN,
         on_click=show_status,
         style=_blue_button_style(),
     )
     btn_storage = ft.FilledButton(
-        "Storage",
+        tr("Storage"),
         icon=ft.Icons.STORAGE,
         on_click=show_storage_info,
         style=_blue_button_style(),
     )
+    btn_privacy = ft.FilledButton(
+        tr("Privacy"),
+        icon=ft.Icons.PRIVACY_TIP,
+        on_click=show_privacy,
+        style=_blue_button_style(),
+    )
+    btn_downloads = ft.FilledButton(
+        tr("Downloads"),
+        icon=ft.Icons.DOWNLOAD,
+        on_click=show_downloads,
+        style=_blue_button_style(),
+    )
+    btn_power = ft.FilledButton(
+        tr("Power"),
+        icon=ft.Icons.BATTERY_SAVER,
+        on_click=show_power,
+        style=_blue_button_style(),
+    )
+    btn_accessibility = ft.FilledButton(
+        tr("Accessibility"),
+        icon=ft.Icons.ACCESSIBILITY_NEW,
+        on_click=show_accessibility,
+        style=_blue_button_style(),
+    )
+    btn_logging = ft.FilledButton(
+        tr("Logging"),
+        icon=ft.Icons.RECEIPT_LONG,
+        on_click=show_logging,
+        style=_blue_button_style(),
+    )
+    btn_plugins = ft.FilledButton(
+        tr("Plugins"),
+        icon=ft.Icons.EXTENSION,
+        on_click=show_plugins,
+        style=_blue_button_style(),
+    )
+    btn_profiles = ft.FilledButton(
+        tr("Profiles"),
+        icon=ft.Icons.PEOPLE,
+        on_click=show_profiles,
+        style=_blue_button_style(),
+    )
+    btn_blocked = ft.FilledButton(
+        tr("Blocked Nodes"),
+        icon=ft.Icons.BLOCK,
+        on_click=show_blocked,
+        style=_blue_button_style(),
+    )
+    btn_aliases = ft.FilledButton(
+        tr("Aliases"),
+        icon=ft.Icons.SHORT_TEXT,
+        on_click=show_aliases,
+        style=_blue_button_style(),
+    )
+    btn_spelling = ft.FilledButton(
+        tr("Spelling"),
+        icon=ft.Icons.SPELLCHECK,
+        on_click=show_spelling,
+        style=_blue_button_style(),
+    )
+    btn_shortcuts = ft.FilledButton(
+        tr("Shortcuts"),
+        icon=ft.Icons.KEYBOARD,
+        on_click=show_shortcuts,
+        style=_blue_button_style(),
+    )
     btn_refresh = ft.IconButton(
         icon=ft.Icons.REFRESH,
-        tooltip="Refresh",
+        tooltip=tr("Refresh"),
         on_click=refresh_current_view,
-        icon_color=ft.Colors.BLUE_400,
+        icon_color=ft.Colors.PRIMARY,
     )
 
     nav_card = ft.Container(
         content=ft.Row(
-            controls=[btn_config, btn_appearance, btn_status, btn_storage, btn_refresh],
+            controls=[
+                btn_config,
+                btn_appearance,
+                btn_status,
+                btn_storage,
+                btn_privacy,
+                btn_downloads,
+                btn_power,
+                btn_accessibility,
+                btn_logging,
+                btn_plugins,
+                btn_profiles,
+                btn_blocked,
+                btn_aliases,
+                btn_spelling,
+                btn_shortcuts,
+                btn_refresh,
+            ],
             spacing=8,
             wrap=True,
         ),
         padding=ft.padding.all(16),
         border_radius=12,
-        bgcolor=ft.Colors.GREY_900,
+        bgcolor=ft.Colors.SURFACE_CONTAINER,
     )
 
     content_card = ft.Container(
@@ -457,7 +2642,7 @@ def open_settings_tab(page: ft.Page, tab_manager):
         expand=True,
         padding=ft.padding.all(16),
         border_radius=12,
-        bgcolor=ft.Colors.GREY_900,
+        bgcolor=ft.Colors.SURFACE_CONTAINER,
     )
 
     action_row = ft.Container(
@@ -474,13 +2659,19 @@ def open_settings_tab(page: ft.Page, tab_manager):
         spacing=16,
         controls=[
             ft.Container(
-                content=ft.Text(
-                    "Settings",
-                    size=24,
-                    weight=ft.FontWeight.BOLD,
-                    color=ft.Colors.BLUE_400,
+                content=ft.Row(
+                    controls=[
+                        ft.Text(
+                            tr("Settings"),
+                            size=24,
+                            weight=ft.FontWeight.BOLD,
+                            color=ft.Colors.PRIMARY,
+                            expand=True,
+                        ),
+                        search_field,
+                    ],
                 ),
-                padding=ft.padding.only(left=16, top=16),
+                padding=ft.padding.only(left=16, top=16, right=16),
             ),
             nav_card,
             content_card,
@@ -0,0 +1,157 @@
+"""Settings import and export dialogs for Ren Browser.
+
+Exports the configuration to a file chosen in a native save dialog, and
+shows what an imported file would change before applying it.
+"""
+
+from collections.abc import Callable
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.storage.exchange import (
+    SettingsBundle,
+    SettingsFormatError,
+    describe_changes,
+    from_json,
+    import_bundle,
+    to_json,
+)
+from ren_browser.ui.notify import show_snack
+
+EXPORT_FILE_NAME = "ren-browser-settings.json"
+
+
+class SettingsFiles:
+    """Native file dialogs for importing and exporting the settings."""
+
+    def __init__(
+        self,
+        page: ft.Page,
+        tab_manager,
+        storage,
+        on_import: Callable[[], None] | None = None,
+    ):
+        """Initialize the file dialogs.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            tab_manager: Tab manager whose settings and themes are exported.
+            storage: Storage manager the imported settings are saved with.
+            on_import: Called after settings were imported.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.storage = storage
+        self.on_import = on_import
+        self.exporting = False
+        self.pending: SettingsBundle | None = None
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+        self.changes = ft.Column(spacing=4, tight=True, scroll=ft.ScrollMode.AUTO)
+        self.dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text("Import Settings"),
+            content=ft.Container(content=self.changes, width=460, height=320),
+            actions=[
+                ft.TextButton("Cancel", on_click=lambda e: self.cancel()),
+                ft.FilledButton("Import", on_click=lambda e: self.apply()),
+            ],
+        )
+
+    def choose_export(self) -> None:
+        """Ask where to export the settings."""
+        self.exporting = True
+        self.picker.save_file(
+            dialog_title="Export Settings",
+            file_name=EXPORT_FILE_NAME,
+            allowed_extensions=["json"],
+        )
+
+    def choose_import(self) -> None:
+        """Ask for a settings file to import."""
+        self.exporting = False
+        self.picker.pick_files(
+            dialog_title="Import Settings",
+            allowed_extensions=["json"],
+        )
+
+    def _on_result(self, e) -> None:  # type: ignore
+        exporting, self.exporting = self.exporting, False
+        if exporting and e.path:
+            self.export_to(Path(e.path))
+        elif not exporting and e.files:
+            self.preview(Path(e.files[0].path))
+
+    def export_to(self, path: Path) -> bool:
+        """Write the settings and custom themes to a file."""
+        text = to_json(self.tab_manager.settings, self.tab_manager.themes.themes())
+        try:
+            path.write_text(text, encoding="utf-8")
+        except OSError as exc:
+            show_snack(self.page, f"Failed to export settings: {exc}", False)
+            return False
+        show_snack(self.page, f"Exported settings to {path}")
+        return True
+
+    def preview(self, path: Path) -> bool:
+        """Read a settings file and list what importing it would change.
+
+        Returns:
+            bool: Whether the file was valid and the preview is shown.
+
+        """
+        try:
+            bundle = from_json(path.read_text(encoding="utf-8"))
+        except (OSError, UnicodeDecodeError, SettingsFormatError) as exc:
+            show_snack(self.page, f"Failed to import settings: {exc}", False)
+            return False
+        changes = describe_changes(
+            self.tab_manager.settings,
+            bundle,
+            self.tab_manager.themes,
+        )
+        if not changes:
+            show_snack(self.page, "The file matches the current settings")
+            return False
+        self.pending = bundle
+        lines = [ft.Text(change, size=13, selectable=True) for change in changes]
+        if bundle.ignored:
+            lines.append(
+                ft.Text(
+                    f"Not known to this version, skipped: {', '.join(bundle.ignored)}",
+                    size=12,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        self.changes.controls = lines
+        open_dialog(self.page, self.dialog)
+        return True
+
+    def apply(self) -> None:
+        """Import the previewed file and apply its settings."""
+        bundle, self.pending = self.pending, None
+        self.page.close(self.dialog)
+        if bundle is None:
+            return
+        try:
+            settings = import_bundle(
+                bundle,
+                self.tab_manager.settings,
+                self.tab_manager.themes,
+            )
+        except (OSError, ValueError) as exc:
+            show_snack(self.page, f"Failed to import settings: {exc}", False)
+            return
+        self.storage.save_app_settings(settings)
+        self.tab_manager.apply_settings(settings)
+        show_snack(self.page, "Settings imported")
+        if self.on_import:
+            self.on_import()
+
+    def cancel(self) -> None:
+        """Close the preview without importing."""
+        self.pending = None
+        self.page.close(self.dialog)
@@ -0,0 +1,101 @@
+"""Live reloading of the settings file for Ren Browser.
+
+Checks the settings file for changes made outside the browser, such as by
+hand in an editor, and applies them without a restart.
+"""
+
+import asyncio
+import json
+
+import flet as ft
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.storage.exchange import check_settings
+from ren_browser.storage.schema import (
+    DEFAULT_APP_SETTINGS,
+    migrate_settings,
+    unknown_settings,
+)
+from ren_browser.ui.notify import show_snack
+
+CHECK_INTERVAL = 2
+
+
+class SettingsWatcher:
+    """Applies changes made to the settings file while the browser runs."""
+
+    def __init__(self, page: ft.Page, tab_manager, storage):
+        """Initialize the watcher.
+
+        Args:
+            page: Flet page instance notifications are shown on.
+            tab_manager: Tab manager the reloaded settings are applied to.
+            storage: Storage manager holding the settings file.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.storage = storage
+        self.path = storage.get_app_settings_path()
+        self._mtime = self._modified()
+        self._watch_id = None
+
+    def _modified(self) -> float | None:
+        try:
+            return self.path.stat().st_mtime
+        except OSError:
+            return None
+
+    def check(self) -> bool:
+        """Apply the settings file if it changed since the last check.
+
+        A file that cannot be read or holds invalid values is reported once
+        and the settings in use are kept, so a half-finished edit does not
+        reset anything.
+
+        Returns:
+            bool: Whether changed settings were applied.
+
+        """
+        mtime = self._modified()
+        if mtime is None or mtime == self._mtime:
+            return False
+        self._mtime = mtime
+        try:
+            stored = migrate_settings(
+                json.loads(self.path.read_text(encoding="utf-8")),
+            )
+            settings, _ = check_settings(stored)
+        except (OSError, ValueError) as exc:
+            show_snack(
+                self.page,
+                tr(
+                    "Could not reload {name}: {error}",
+                    name=self.path.name,
+                    error=exc,
+                ),
+                False,
+            )
+            return False
+        settings = {**DEFAULT_APP_SETTINGS, **unknown_settings(stored), **settings}
+        if settings in (self.tab_manager.settings, self.storage.last_saved_settings):
+            return False
+        self.tab_manager.apply_settings(settings)
+        show_snack(self.page, tr("Settings reloaded from {name}", name=self.path.name))
+        return True
+
+    def start(self) -> None:
+        """Begin checking the settings file in the background."""
+        watch_id = object()
+        self._watch_id = watch_id
+
+        async def watch():
+            while self._watch_id is watch_id:
+                await asyncio.sleep(CHECK_INTERVAL)
+                self.check()
+
+        self.page.run_task(watch)
+
+    def stop(self) -> None:
+        """Stop checking the settings file."""
+        self._watch_id = None
@@ -0,0 +1,86 @@
+"""Keyboard shortcut cheat sheet for Ren Browser.
+
+Lists the chords currently bound to each action, grouped by category.
+The list is built from the shortcut registry each time it is opened, so
+shortcuts rebound in the settings show up as they are.
+"""
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.controls.shortcuts import ACTION_CATEGORIES, ACTIONS, keybindings
+from ren_browser.i18n.i18n import tr
+
+
+def sheet_groups(overrides) -> list[tuple[str, list[tuple[str, str]]]]:
+    """Return the bound shortcuts by category, leaving out unbound actions.
+
+    Args:
+        overrides: Stored ``keybindings`` setting.
+
+    Returns:
+        list: Category labels with the action labels and chords in it.
+
+    """
+    bindings = keybindings(overrides)
+    groups = []
+    for category, actions in ACTION_CATEGORIES.items():
+        rows = [
+            (ACTIONS[action][0], ", ".join(bindings[action]))
+            for action in actions
+            if bindings[action]
+        ]
+        if rows:
+            groups.append((category, rows))
+    return groups
+
+
+class ShortcutSheet:
+    """Dialog listing every keyboard shortcut."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the cheat sheet.
+
+        Args:
+            page: Flet page the sheet is shown on.
+            tab_manager: Tab manager whose settings hold the keybindings.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.groups = ft.Column(spacing=4, tight=True, scroll=ft.ScrollMode.AUTO)
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("Keyboard Shortcuts")),
+            content=ft.Container(width=520, height=440, content=self.groups),
+            actions=[
+                ft.TextButton(
+                    tr("Close"),
+                    on_click=lambda e: self.page.close(self.dialog),
+                ),
+            ],
+        )
+
+    def open(self) -> None:
+        """Show the shortcuts as they are currently bound."""
+        controls = []
+        for category, rows in sheet_groups(
+            self.tab_manager.settings.get("keybindings"),
+        ):
+            controls.append(
+                ft.Text(
+                    tr(category),
+                    weight=ft.FontWeight.BOLD,
+                    color=ft.Colors.PRIMARY,
+                ),
+            )
+            controls.extend(
+                ft.Row(
+                    controls=[
+                        ft.Text(tr(label), expand=True),
+                        ft.Text(chords, color=ft.Colors.ON_SURFACE_VARIANT),
+                    ],
+                )
+                for label, chords in rows
+            )
+        self.groups.controls = controls
+        open_dialog(self.page, self.dialog)
@@ -0,0 +1,286 @@
+"""Docked sidebar for Ren Browser.
+
+Hosts the announce list next to the page content and can be collapsed to a
+narrow strip that only shows connection status. On narrow windows, such as
+phones in portrait, it leaves the layout and slides in over the page as a
+drawer instead.
+"""
+
+import flet as ft
+
+from ren_browser.storage.storage import get_storage_manager
+from ren_browser.ui.status_bar import connection_summary
+
+SIDEBAR_WIDTH = 300
+MIN_SIDEBAR_WIDTH = 200
+MAX_SIDEBAR_WIDTH = 600
+COLLAPSED_WIDTH = 48
+DIVIDER_WIDTH = 4
+# Windows narrower than this show the sidebar as a drawer.
+DRAWER_BREAKPOINT = 720
+DRAWER_MAX_SHARE = 0.85
+DRAWER_ANIMATION = ft.Animation(200, ft.AnimationCurve.EASE_OUT)
+
+
+def clamp_sidebar_width(width) -> int:
+    """Return width limited to the supported sidebar range."""
+    try:
+        width = int(width)
+    except (TypeError, ValueError):
+        return SIDEBAR_WIDTH
+    return max(MIN_SIDEBAR_WIDTH, min(MAX_SIDEBAR_WIDTH, width))
+
+
+class Sidebar:
+    """Collapsible, resizable sidebar docked to the left of the page content."""
+
+    def __init__(self, page: ft.Page, tab_manager, content: ft.Control):
+        """Initialize the sidebar.
+
+        Args:
+            page: Flet page instance for UI updates.
+            tab_manager: Tab manager holding the current app settings.
+            content: Control shown below the sidebar header when expanded.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.collapsed = bool(tab_manager.settings.get("sidebar_collapsed", False))
+        self.width = clamp_sidebar_width(
+            tab_manager.settings.get("sidebar_width", SIDEBAR_WIDTH),
+        )
+        self.content = content
+        self.connection_icon = ft.Icon(ft.Icons.LAN, size=20)
+        # Called when the connection indicator is clicked
+        self.on_connection_click = None
+        self.expanded_view = ft.Column(
+            expand=True,
+            spacing=0,
+            controls=[
+                ft.Container(
+                    content=ft.Row(
+                        controls=[
+                            ft.Text(
+                                "Announcements",
+                                size=20,
+                                weight=ft.FontWeight.BOLD,
+                                color=ft.Colors.PRIMARY,
+                                expand=True,
+                            ),
+                            ft.IconButton(
+                                ft.Icons.CHEVRON_LEFT,
+                                tooltip="Collapse sidebar (Ctrl+B)",
+                                icon_color=ft.Colors.ON_SURFACE,
+                                on_click=lambda e: self.toggle(),
+                            ),
+                        ],
+                    ),
+                    padding=ft.padding.only(left=16, right=4, top=12, bottom=12),
+                ),
+                ft.Divider(height=1, color=ft.Colors.OUTLINE_VARIANT),
+                content,
+            ],
+        )
+        self.collapsed_view = ft.Column(
+            spacing=12,
+            horizontal_alignment=ft.CrossAxisAlignment.CENTER,
+            controls=[
+                ft.IconButton(
+                    ft.Icons.CHEVRON_RIGHT,
+                    tooltip="Expand sidebar (Ctrl+B)",
+                    icon_color=ft.Colors.ON_SURFACE,
+                    on_click=lambda e: self.toggle(),
+                ),
+                ft.Container(
+                    content=self.connection_icon,
+                    on_click=lambda e: self._connection_clicked(),
+                ),
+            ],
+        )
+        self.on_resize = None
+        self.control = ft.Container(bgcolor=ft.Colors.SURFACE_CONTAINER)
+        self.divider = ft.GestureDetector(
+            content=ft.Container(
+                width=DIVIDER_WIDTH,
+                bgcolor=ft.Colors.SURFACE_CONTAINER_HIGHEST,
+            ),
+            mouse_cursor=ft.MouseCursor.RESIZE_LEFT_RIGHT,
+            drag_interval=16,
+            on_horizontal_drag_update=self._on_drag_update,
+            on_horizontal_drag_end=self._on_drag_end,
+        )
+        self.drawer_mode = False
+        self.drawer_open = False
+        self.scrim = ft.Container(
+            left=0,
+            top=0,
+            right=0,
+            bottom=0,
+            bgcolor=ft.Colors.with_opacity(0.5, ft.Colors.BLACK),
+            visible=False,
+            on_click=lambda e: self.set_drawer_open(False),
+        )
+        self.drawer = ft.Container(
+            left=0,
+            top=0,
+            bottom=0,
+            width=self.width,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            offset=ft.Offset(-1, 0),
+            animate_offset=DRAWER_ANIMATION,
+        )
+        page.overlay.extend([self.scrim, self.drawer])
+        self._render()
+
+    def _render(self) -> None:
+        if self.drawer_mode:
+            self.control.content = None
+            self.drawer.content = self.expanded_view
+            self.drawer.offset = ft.Offset(0 if self.drawer_open else -1, 0)
+            self.scrim.visible = self.drawer_open
+            if self.on_resize:
+                self.on_resize()
+            return
+        self.drawer.content = None
+        self.drawer.offset = ft.Offset(-1, 0)
+        self.scrim.visible = False
+        if self.collapsed:
+            self.control.content = self.collapsed_view
+            self.control.width = COLLAPSED_WIDTH
+            self.control.padding = ft.padding.symmetric(vertical=8)
+        else:
+            self.control.content = self.expanded_view
+            self.control.width = self.width
+            self.control.padding = None
+        self.divider.visible = not self.collapsed
+        if self.on_resize:
+            self.on_resize()
+
+    @property
+    def total_width(self) -> int:
+        """Return the horizontal space taken by the sidebar and its divider."""
+        if self.drawer_mode:
+            return 0
+        if self.collapsed:
+            return COLLAPSED_WIDTH
+        return self.width + DIVIDER_WIDTH
+
+    @property
+    def hidden(self) -> bool:
+        """Return whether the announce list is out of sight."""
+        if self.drawer_mode:
+            return not self.drawer_open
+        return self.collapsed
+
+    def fit(self, viewport_width) -> None:
+        """Dock the sidebar on wide windows and make it a drawer on narrow ones.
+
+        Args:
+            viewport_width: Window width in layout pixels, None if unknown.
+
+        """
+        drawer = bool(viewport_width) and viewport_width < DRAWER_BREAKPOINT
+        if drawer:
+            self.drawer.width = min(self.width, int(viewport_width * DRAWER_MAX_SHARE))
+        if drawer == self.drawer_mode:
+            return
+        self.drawer_mode = drawer
+        self.drawer_open = False
+        # The docked sidebar is hidden while it is a drawer
+        self.control.visible = not drawer
+        on_resize, self.on_resize = self.on_resize, None
+        self._render()
+        self.on_resize = on_resize
+
+    def set_animated(self, animated: bool) -> None:
+        """Slide the drawer in and out, or show and hide it at once."""
+        self.drawer.animate_offset = DRAWER_ANIMATION if animated else None
+
+    def set_drawer_open(self, drawer_open: bool) -> None:
+        """Slide the drawer in or out, when the sidebar is a drawer."""
+        if not self.drawer_mode or drawer_open == self.drawer_open:
+            return
+        self.drawer_open = drawer_open
+        self._render()
+        self.page.update()
+
+    def _save_settings(self, **changes) -> None:
+        settings = {**self.tab_manager.settings, **changes}
+        self.tab_manager.settings = settings
+        get_storage_manager(self.page).save_app_settings(settings)
+
+    def resize(self, width) -> None:
+        """Set the expanded sidebar width without persisting it.
+
+        Args:
+            width: Requested width in pixels, clamped to the supported range.
+
+        """
+        self.width = clamp_sidebar_width(width)
+        if not self.collapsed:
+            self.control.width = self.width
+            if self.on_resize:
+                self.on_resize()
+
+    def _on_drag_update(self, e) -> None:  # type: ignore
+        self.resize(self.width + e.delta_x)
+        self.page.update()
+
+    def _on_drag_end(self, e) -> None:  # type: ignore
+        self._save_settings(sidebar_width=self.width)
+
+    def set_collapsed(self, collapsed: bool) -> None:
+        """Collapse or expand the sidebar and remember the choice.
+
+        As a drawer, the drawer is opened or closed instead and nothing is
+        remembered.
+
+        Args:
+            collapsed: Whether the sidebar should show only the compact strip.
+
+        """
+        if self.drawer_mode:
+            self.set_drawer_open(not collapsed)
+            return
+        if collapsed == self.collapsed:
+            return
+        self.collapsed = collapsed
+        self._render()
+        self._save_settings(sidebar_collapsed=collapsed)
+        self.page.update()
+
+    def toggle(self) -> None:
+        """Switch between the expanded and collapsed sidebar, or open the drawer."""
+        if self.drawer_mode:
+            self.set_drawer_open(not self.drawer_open)
+        else:
+            self.set_collapsed(not self.collapsed)
+
+    def set_panels(self, panels: list[tuple[str, ft.Control]]) -> None:
+        """Show panels added by plugins as tabs next to the announce list.
+
+        Args:
+            panels: Title and control of each panel, empty for none.
+
+        """
+        body = self.content
+        if panels:
+            body = ft.Tabs(
+                selected_index=0,
+                expand=True,
+                tabs=[
+                    ft.Tab(text="Nodes", content=self.content),
+                    *(ft.Tab(text=title, content=control) for title, control in panels),
+                ],
+            )
+        self.expanded_view.controls[-1] = body
+
+    def _connection_clicked(self) -> None:
+        if self.on_connection_click:
+            self.on_connection_click()
+
+    def set_connection(self, statuses: list[dict]) -> None:
+        """Show interface statuses on the collapsed strip."""
+        text, online = connection_summary(statuses)
+        self.connection_icon.color = ft.Colors.GREEN if online else ft.Colors.ERROR
+        self.connection_icon.tooltip = text
@@ -0,0 +1,205 @@
+"""Speed dial grid for Ren Browser.
+
+Shows pinned and most visited pages as large tiles on the new tab page.
+Tiles can be dragged onto each other to reorder them and renamed in place.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+
+TILE_WIDTH = 150
+TILE_HEIGHT = 110
+DRAG_GROUP = "speed-dial"
+
+
+def build_speed_dial(
+    speed_dial,
+    history,
+    on_open: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the speed dial grid.
+
+    Dropping a tile on another moves it to that position, pinning it first
+    if it came from history.
+
+    Args:
+        speed_dial: SpeedDial holding the pinned tiles.
+        history: HistoryManager the top sites are picked from.
+        on_open: Called with an address when a tile is clicked.
+        on_change: Called after the grid has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The speed dial section.
+
+    """
+    section = ft.Column(spacing=8)
+    renaming: set[str] = set()
+
+    def changed():
+        render()
+        on_change()
+
+    def drop(address, position):
+        tiles = {tile.address: tile for tile in speed_dial.tiles(history.entries())}
+        if address not in tiles:
+            return
+        position = min(position, len(speed_dial.pinned()))
+        speed_dial.pin(address, tiles[address].label, position)
+        changed()
+
+    def pin(tile):
+        speed_dial.pin(tile.address, tile.label)
+        changed()
+
+    def unpin(tile):
+        speed_dial.unpin(tile.address)
+        changed()
+
+    def start_rename(tile):
+        renaming.add(tile.address)
+        changed()
+
+    def rename(tile, label):
+        renaming.discard(tile.address)
+        speed_dial.rename(tile.address, label)
+        changed()
+
+    actions = {"pin": pin, "unpin": unpin, "rename": start_rename, "save": rename}
+
+    def render():
+        tiles = speed_dial.tiles(history.entries())
+        controls: list[ft.Control] = [
+            ft.Text("Speed dial", size=16, weight=ft.FontWeight.BOLD),
+        ]
+        if not tiles:
+            controls.append(
+                ft.Text(
+                    "Pages you visit often and pages you pin will appear here.",
+                    size=13,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.append(
+            ft.Row(
+                wrap=True,
+                spacing=12,
+                run_spacing=12,
+                controls=[
+                    _build_tile(
+                        tile,
+                        position,
+                        tile.address in renaming,
+                        on_open,
+                        drop,
+                        actions,
+                    )
+                    for position, tile in enumerate(tiles)
+                ],
+            ),
+        )
+        section.controls = controls
+
+    render()
+    return ft.Container(content=section, padding=ft.padding.only(top=24))
+
+
+def _build_tile(tile, position, renaming, on_open, on_drop, actions) -> ft.Control:
+    address = tile.address
+    if renaming:
+        label: ft.Control = ft.TextField(
+            value=tile.label,
+            dense=True,
+            autofocus=True,
+            text_size=13,
+            on_submit=lambda e: actions["save"](tile, e.control.value or ""),
+        )
+    else:
+        label = ft.Text(
+            tile.label,
+            size=13,
+            weight=ft.FontWeight.W_500,
+            max_lines=2,
+            overflow=ft.TextOverflow.ELLIPSIS,
+            text_align=ft.TextAlign.CENTER,
+        )
+    if tile.pinned:
+        menu_items = [
+            ft.PopupMenuItem(
+                text="Rename",
+                icon=ft.Icons.EDIT,
+                on_click=lambda e: actions["rename"](tile),
+            ),
+            ft.PopupMenuItem(
+                text="Unpin",
+                icon=ft.Icons.PUSH_PIN_OUTLINED,
+                on_click=lambda e: actions["unpin"](tile),
+            ),
+        ]
+    else:
+        menu_items = [
+            ft.PopupMenuItem(
+                text="Pin",
+                icon=ft.Icons.PUSH_PIN,
+                on_click=lambda e: actions["pin"](tile),
+            ),
+        ]
+    card = ft.Container(
+        width=TILE_WIDTH,
+        height=TILE_HEIGHT,
+        padding=ft.padding.all(8),
+        border_radius=12,
+        bgcolor=ft.Colors.SURFACE_CONTAINER,
+        border=ft.border.all(
+            1,
+            ft.Colors.PRIMARY if tile.pinned else ft.Colors.SURFACE_CONTAINER_HIGHEST,
+        ),
+        ink=True,
+        tooltip=address,
+        on_click=lambda e: on_open(address),
+        content=ft.Stack(
+            controls=[
+                ft.Column(
+                    horizontal_alignment=ft.CrossAxisAlignment.CENTER,
+                    alignment=ft.MainAxisAlignment.CENTER,
+                    spacing=8,
+                    controls=[
+                        build_node_avatar(address.split(":", 1)[0], size=32),
+                        label,
+                    ],
+                ),
+                ft.PopupMenuButton(
+                    icon=ft.Icons.MORE_VERT,
+                    icon_size=16,
+                    right=0,
+                    top=0,
+                    items=menu_items,
+                ),
+            ],
+        ),
+    )
+
+    def accept(e):
+        source = e.page.get_control(e.src_id)
+        if source is not None and source.data != address:
+            on_drop(source.data, position)
+
+    return ft.DragTarget(
+        group=DRAG_GROUP,
+        on_accept=accept,
+        content=ft.Draggable(
+            group=DRAG_GROUP,
+            data=address,
+            content=card,
+            content_feedback=ft.Container(
+                width=TILE_WIDTH,
+                height=TILE_HEIGHT,
+                border_radius=12,
+                opacity=0.6,
+                bgcolor=ft.Colors.PRIMARY_CONTAINER,
+            ),
+        ),
+    )
@@ -0,0 +1,125 @@
+"""Spell checked input fields for Ren Browser.
+
+Flet text fields cannot mark parts of their text, so the misspelled words
+of a field are listed below it, underlined with a wavy line. Clicking one
+opens a menu of suggestions replacing it in the field, or ignores it. The
+word still being typed at the end of the text is left alone until it is
+finished.
+"""
+
+import flet as ft
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.spelling.spelling import (
+    Misspelling,
+    SpellCheck,
+    SpellChecker,
+    replace_word,
+)
+
+MAX_MARKED = 8
+
+
+def finished_misspellings(checker: SpellChecker, text: str) -> list[Misspelling]:
+    """Return the misspelled words of a text, leaving out one being typed."""
+    found = checker.misspellings(text)
+    if found and found[-1].end == len(text):
+        found.pop()
+    return found
+
+
+class SpellCheckedField:
+    """A text field with its misspelled words listed below it."""
+
+    def __init__(self, page: ft.Page, field: ft.TextField, spelling: SpellCheck):
+        """Wrap a text field, keeping its own change handler.
+
+        Args:
+            page: Flet page the field is shown on.
+            field: Field whose text is checked.
+            spelling: Spell checking following the settings.
+
+        """
+        self.page = page
+        self.field = field
+        self.spelling = spelling
+        self.on_change = field.on_change
+        self.marks = ft.Row(wrap=True, spacing=4, run_spacing=0, visible=False)
+        self.control = ft.Column(spacing=4, tight=True, controls=[field, self.marks])
+        field.on_change = self._on_change
+
+    def check(self) -> None:
+        """List the misspelled words of the field below it."""
+        checker = self.spelling.checker()
+        found = (
+            finished_misspellings(checker, self.field.value or "")
+            if checker is not None
+            else []
+        )
+        self.marks.controls = [
+            self._build_mark(checker, misspelling)
+            for misspelling in found[:MAX_MARKED]
+        ]
+        self.marks.visible = bool(found)
+
+    def replace(self, misspelling: Misspelling, replacement: str) -> None:
+        """Replace a misspelled word in the field."""
+        self.field.value = replace_word(
+            self.field.value or "",
+            misspelling,
+            replacement,
+        )
+        self.check()
+        self.page.update()
+
+    def ignore(self, word: str) -> None:
+        """Stop marking a word anywhere until the browser is closed."""
+        checker = self.spelling.checker()
+        if checker is not None:
+            checker.ignore(word)
+        self.check()
+        self.page.update()
+
+    def _on_change(self, e) -> None:  # type: ignore
+        self.check()
+        if self.on_change:
+            self.on_change(e)
+        self.page.update()
+
+    def _build_mark(self, checker, misspelling) -> ft.PopupMenuButton:
+        suggestions = checker.suggestions(misspelling.word)
+        items = [
+            ft.PopupMenuItem(
+                text=suggestion,
+                on_click=lambda e, s=suggestion: self.replace(misspelling, s),
+            )
+            for suggestion in suggestions
+        ] or [ft.PopupMenuItem(text=tr("No suggestions"), disabled=True)]
+        items += [
+            ft.PopupMenuItem(),
+            ft.PopupMenuItem(
+                text=tr("Ignore"),
+                icon=ft.Icons.SPELLCHECK,
+                on_click=lambda e: self.ignore(misspelling.word),
+            ),
+        ]
+        return ft.PopupMenuButton(
+            tooltip=tr("Spelling suggestions"),
+            content=ft.Text(
+                misspelling.word,
+                size=13,
+                style=ft.TextStyle(
+                    decoration=ft.TextDecoration.UNDERLINE,
+                    decoration_style=ft.TextDecorationStyle.WAVY,
+                    decoration_color=ft.Colors.ERROR,
+                ),
+            ),
+            items=items,
+        )
+
+
+def spell_checked(page: ft.Page, field: ft.TextField, spelling: SpellCheck):
+    """Return a field with spell checking, or the field itself when it is off."""
+    if spelling.checker() is None:
+        return field
+    return SpellCheckedField(page, field, spelling).control
@@ -0,0 +1,181 @@
+"""Status bar for Ren Browser.
+
+Shows connection state, the active tab's request status, the hovered link
+target and where the current page came from along the bottom of the window.
+Clicking the connection state lists the network interfaces.
+"""
+
+import asyncio
+
+import flet as ft
+
+from ren_browser import rns
+from ren_browser.i18n.i18n import tr
+from ren_browser.ui.interfaces import InterfacePanel
+
+POLL_INTERVAL = 5
+POLL_OPTIONS = {
+    2: "2 seconds",
+    5: "5 seconds",
+    10: "10 seconds",
+    30: "30 seconds",
+    60: "1 minute",
+}
+
+
+def poll_interval(settings: dict) -> int:
+    """Return the seconds between interface status checks from settings."""
+    seconds = settings.get("status_poll_seconds", POLL_INTERVAL)
+    return seconds if seconds in POLL_OPTIONS else POLL_INTERVAL
+
+
+def connection_summary(statuses: list[dict]) -> tuple[str, bool]:
+    """Summarize interface statuses for display.
+
+    Args:
+        statuses: Interface statuses as returned by rns.get_interface_statuses.
+
+    Returns:
+        Tuple of summary text and whether any interface is online.
+
+    """
+    if not statuses:
+        return tr("No active interfaces"), False
+    online = sum(1 for entry in statuses if entry["online"])
+    text = tr("{online}/{total} interfaces online", online=online, total=len(statuses))
+    return text, online > 0
+
+
+def request_summary(tab: dict) -> str:
+    """Describe the request state of a tab."""
+    if tab.get("load_progress") is not None:
+        return tab.get("load_label") or tr("Loading")
+    if tab.get("load_failed"):
+        return tr("Failed")
+    if tab.get("address"):
+        return tr("Loaded")
+    return tr("Ready")
+
+
+class StatusBar:
+    """Optional bar along the bottom of the window with browser status."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the status bar.
+
+        Args:
+            page: Flet page instance for UI updates.
+            tab_manager: Tab manager whose active tab is described.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.statuses: list[dict] = []
+        self.interface_panel = InterfacePanel(page)
+        self.connection_listeners = [self.interface_panel.set_statuses]
+        self.poll_interval: int | None = None
+        # Stretches the poll interval while saving power on battery
+        self.interval_factor = 1
+        self._poll_id = None
+        self.connection_icon = ft.Icon(ft.Icons.CIRCLE, size=10)
+        self.connection_text = ft.Text("", size=11)
+        self.request_text = ft.Text("", size=11)
+        self.link_text = ft.Text(
+            "",
+            size=11,
+            color=ft.Colors.BLUE_200,
+            overflow=ft.TextOverflow.ELLIPSIS,
+            expand=True,
+        )
+        self.origin_text = ft.Text("", size=11, color=ft.Colors.ON_SURFACE_VARIANT)
+        self.control = ft.Container(
+            content=ft.Row(
+                controls=[
+                    ft.Container(
+                        content=ft.Row(
+                            controls=[self.connection_icon, self.connection_text],
+                            spacing=6,
+                            tight=True,
+                        ),
+                        tooltip=tr("Show network interfaces"),
+                        on_click=lambda e: self.show_interfaces(),
+                    ),
+                    ft.VerticalDivider(width=16, color=ft.Colors.OUTLINE_VARIANT),
+                    self.request_text,
+                    ft.VerticalDivider(width=16, color=ft.Colors.OUTLINE_VARIANT),
+                    self.link_text,
+                    self.origin_text,
+                ],
+                spacing=6,
+                vertical_alignment=ft.CrossAxisAlignment.CENTER,
+            ),
+            height=24,
+            bgcolor=ft.Colors.SURFACE_CONTAINER,
+            padding=ft.padding.symmetric(horizontal=12),
+            visible=tab_manager.settings.get("show_status_bar", True),
+        )
+        self.refresh_connection()
+        self.refresh()
+
+    def apply_settings(self, settings: dict) -> None:
+        """Show or hide the status bar and change how often it polls."""
+        self.control.visible = settings.get("show_status_bar", True)
+        interval = poll_interval(settings) * self.interval_factor
+        if self._poll_id is not None and interval != self.poll_interval:
+            self._start_polling(interval)
+
+    def add_connection_listener(self, listener) -> None:
+        """Register a callable notified with interface statuses on every refresh."""
+        self.connection_listeners.append(listener)
+        listener(self.statuses)
+
+    def refresh_connection(self) -> None:
+        """Update the connection indicator from the transport interfaces."""
+        try:
+            self.statuses = rns.get_interface_statuses()
+        except Exception:  # noqa: BLE001
+            self.statuses = []
+        text, online = connection_summary(self.statuses)
+        self.connection_text.value = text
+        self.connection_icon.color = ft.Colors.GREEN if online else ft.Colors.ERROR
+        for listener in self.connection_listeners:
+            listener(self.statuses)
+
+    def show_interfaces(self) -> None:
+        """Open the panel listing the network interfaces."""
+        self.interface_panel.open()
+
+    def refresh(self) -> None:
+        """Update the request, hovered link and origin for the active tab."""
+        manager = self.tab_manager.manager
+        tab = manager.tabs[manager.index]
+        self.request_text.value = request_summary(tab)
+        self.link_text.value = self.tab_manager.hovered_link or ""
+        origin = tab.get("origin") or "local"
+        self.origin_text.value = tr("Origin: {origin}", origin=tr(origin))
+
+    def start(self) -> None:
+        """Begin refreshing the connection state in the background."""
+        self._start_polling(
+            poll_interval(self.tab_manager.settings) * self.interval_factor,
+        )
+
+    def stop(self) -> None:
+        """Stop refreshing the connection state."""
+        self._poll_id = None
+
+    def _start_polling(self, interval: int) -> None:
+        # A new loop replaces the running one, which stops at its next wake-up.
+        poll_id = object()
+        self._poll_id = poll_id
+        self.poll_interval = interval
+
+        async def poll():
+            while True:
+                await asyncio.sleep(interval)
+                if self._poll_id is not poll_id:
+                    return
+                self.refresh_connection()
+                self.page.update()
+
+        self.page.run_task(poll)
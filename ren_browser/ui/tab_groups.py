@@ -0,0 +1,189 @@
+"""Tab group controls for Ren Browser.
+
+The chip a group shows in the tab bar, with a menu to collapse, rename,
+ungroup or close it, and the dialog that puts a tab in a group or edits
+one.
+"""
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.tabs.groups import GROUP_COLORS, TabGroup, next_color
+
+GROUP_SWATCHES = {
+    "blue": ft.Colors.BLUE_400,
+    "green": ft.Colors.GREEN_400,
+    "yellow": ft.Colors.AMBER_400,
+    "red": ft.Colors.RED_400,
+    "purple": ft.Colors.PURPLE_300,
+    "cyan": ft.Colors.CYAN_400,
+    "orange": ft.Colors.ORANGE_400,
+    "pink": ft.Colors.PINK_300,
+}
+NEW_GROUP = "new"
+
+
+def group_swatch(group: TabGroup) -> str:
+    """Return the colour a group is drawn in."""
+    return GROUP_SWATCHES.get(group.color, GROUP_SWATCHES["blue"])
+
+
+def build_group_chip(group: TabGroup, tab_manager) -> ft.PopupMenuButton:
+    """Build the tab bar chip of a group, opening its menu when clicked."""
+    swatch = group_swatch(group)
+    return ft.PopupMenuButton(
+        tooltip=tr("Tab group {name}", name=group.name),
+        content=ft.Row(
+            tight=True,
+            spacing=4,
+            controls=[
+                ft.Icon(
+                    ft.Icons.CHEVRON_RIGHT if group.collapsed else ft.Icons.EXPAND_MORE,
+                    size=16,
+                    color=swatch,
+                ),
+                ft.Text(
+                    group.name,
+                    size=13,
+                    weight=ft.FontWeight.BOLD,
+                    color=swatch,
+                    max_lines=1,
+                    overflow=ft.TextOverflow.ELLIPSIS,
+                ),
+            ],
+        ),
+        items=[
+            ft.PopupMenuItem(
+                text=tr("Expand Group") if group.collapsed else tr("Collapse Group"),
+                icon=ft.Icons.UNFOLD_MORE if group.collapsed else ft.Icons.UNFOLD_LESS,
+                on_click=lambda e: tab_manager.toggle_group(group),
+            ),
+            ft.PopupMenuItem(
+                text=tr("Edit Group…"),
+                icon=ft.Icons.EDIT,
+                on_click=lambda e: tab_manager.group_dialog.edit(group),
+            ),
+            ft.PopupMenuItem(
+                text=tr("Ungroup"),
+                icon=ft.Icons.LAYERS_CLEAR,
+                on_click=lambda e: tab_manager.ungroup(group),
+            ),
+            ft.PopupMenuItem(
+                text=tr("Close Group"),
+                icon=ft.Icons.CLOSE,
+                on_click=lambda e: tab_manager.close_group(group),
+            ),
+        ],
+    )
+
+
+class TabGroupDialog:
+    """Dialog putting the active tab in a group, or editing a group."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            tab_manager: Tab manager holding the tabs and their groups.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.editing: TabGroup | None = None
+        self.group_dropdown = ft.Dropdown(
+            label=tr("Group"),
+            width=320,
+            on_change=lambda e: self._on_group_picked(),
+        )
+        self.name_field = ft.TextField(
+            label=tr("Group name"),
+            width=320,
+            on_submit=lambda e: self.apply(),
+        )
+        self.color_dropdown = ft.Dropdown(
+            label=tr("Colour"),
+            width=320,
+            options=[
+                ft.dropdown.Option(color, tr(label))
+                for color, label in GROUP_COLORS.items()
+            ],
+        )
+        self.title = ft.Text()
+        self.dialog = ft.AlertDialog(
+            title=self.title,
+            content=ft.Column(
+                tight=True,
+                spacing=12,
+                controls=[self.group_dropdown, self.name_field, self.color_dropdown],
+            ),
+            actions=[
+                ft.TextButton(tr("Cancel"), on_click=lambda e: self.close()),
+                ft.FilledButton(tr("Save"), on_click=lambda e: self.apply()),
+            ],
+        )
+
+    def add_tab(self) -> None:
+        """Ask which group the active tab goes in."""
+        groups = self.tab_manager.tab_groups
+        self.editing = None
+        self.title.value = tr("Add Tab to Group")
+        self.group_dropdown.visible = bool(groups)
+        self.group_dropdown.options = [
+            ft.dropdown.Option(str(i), group.name) for i, group in enumerate(groups)
+        ] + [ft.dropdown.Option(NEW_GROUP, tr("New group"))]
+        self.group_dropdown.value = NEW_GROUP
+        self.name_field.value = ""
+        self.name_field.error_text = None
+        self.color_dropdown.value = next_color(groups)
+        self._on_group_change()
+        open_dialog(self.page, self.dialog)
+
+    def edit(self, group: TabGroup) -> None:
+        """Ask for a new name and colour of a group."""
+        self.editing = group
+        self.title.value = tr("Edit Group")
+        self.group_dropdown.visible = False
+        self.name_field.value = group.name
+        self.name_field.error_text = None
+        self.name_field.visible = True
+        self.color_dropdown.value = group.color
+        self.color_dropdown.visible = True
+        open_dialog(self.page, self.dialog)
+
+    def apply(self) -> None:
+        """Put the active tab in the chosen group, or save the edited one."""
+        choice = self.group_dropdown.value if self.editing is None else NEW_GROUP
+        if choice != NEW_GROUP:
+            group = self.tab_manager.tab_groups[int(choice)]
+            self.close()
+            self.tab_manager.add_to_group(self.tab_manager.manager.index, group)
+            return
+        name = (self.name_field.value or "").strip()
+        if not name:
+            self.name_field.error_text = tr("Enter a name")
+            self.page.update()
+            return
+        color = self.color_dropdown.value or "blue"
+        self.close()
+        if self.editing is not None:
+            self.tab_manager.edit_group(self.editing, name, color)
+        else:
+            self.tab_manager.add_to_group(
+                self.tab_manager.manager.index,
+                TabGroup(name, color),
+            )
+
+    def close(self) -> None:
+        """Close the dialog."""
+        self.page.close(self.dialog)
+
+    def _on_group_picked(self) -> None:
+        self._on_group_change()
+        self.page.update()
+
+    def _on_group_change(self) -> None:
+        new = self.group_dropdown.value == NEW_GROUP
+        self.name_field.visible = new
+        self.color_dropdown.visible = new
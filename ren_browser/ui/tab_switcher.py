@@ -0,0 +1,177 @@
+"""Tab switcher for Ren Browser on phones.
+
+On narrow windows the tab strip only has room for a tab or two, and tabs
+beyond it were out of reach in portrait. There the strip is replaced with
+a button showing the number of open tabs, which opens a grid of every tab
+over the page. Tapping a tile switches to its tab and swiping a tile away
+closes it.
+"""
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+from ren_browser.ui.notify import count_badge
+
+# Windows narrower than this, in pixels, use the switcher.
+COMPACT_WIDTH = 600
+
+
+class TabSwitcher:
+    """Grid of open tabs shown in place of the tab strip."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Build the hidden switcher and add it to the page.
+
+        Args:
+            page: Flet page the switcher is drawn over.
+            tab_manager: Tab manager whose tabs are listed.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.title = ft.Text("", size=16, weight=ft.FontWeight.BOLD, expand=True)
+        self.grid = ft.GridView(
+            expand=True,
+            max_extent=220,
+            child_aspect_ratio=1.2,
+            spacing=10,
+            run_spacing=10,
+        )
+        self.panel = ft.Container(
+            left=0,
+            top=0,
+            right=0,
+            bottom=0,
+            padding=12,
+            bgcolor=ft.Colors.SURFACE,
+            visible=False,
+            content=ft.Column(
+                spacing=12,
+                controls=[
+                    ft.Row(
+                        controls=[
+                            self.title,
+                            ft.IconButton(
+                                ft.Icons.ADD,
+                                tooltip="New Tab",
+                                on_click=lambda e: self.new_tab(),
+                            ),
+                            ft.IconButton(
+                                ft.Icons.CLOSE,
+                                tooltip="Back to the page",
+                                on_click=lambda e: self.dismiss(),
+                            ),
+                        ],
+                    ),
+                    self.grid,
+                ],
+            ),
+        )
+        self.button = ft.IconButton(
+            ft.Icons.FILTER_NONE,
+            tooltip="Show all tabs",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: self.open(),
+            visible=False,
+        )
+        page.overlay.append(self.panel)
+
+    @property
+    def is_open(self) -> bool:
+        """Return whether the switcher is showing."""
+        return bool(self.panel.visible)
+
+    def update_button(self, visible: bool) -> None:
+        """Show or hide the switcher button with the number of tabs on it."""
+        self.button.visible = visible
+        self.button.badge = count_badge(len(self.tab_manager.manager.tabs))
+
+    def open(self) -> None:
+        """Show the grid of tabs."""
+        self.render()
+        self.panel.visible = True
+        self.page.update()
+
+    def dismiss(self) -> None:
+        """Hide the grid and go back to the page."""
+        self.panel.visible = False
+        self.page.update()
+
+    def render(self) -> None:
+        """Build a tile for every open tab."""
+        tabs = self.tab_manager.manager.tabs
+        self.title.value = f"{len(tabs)} tabs" if len(tabs) != 1 else "1 tab"
+        self.grid.controls = [self._build_tile(i, tab) for i, tab in enumerate(tabs)]
+
+    def activate(self, idx: int) -> None:
+        """Switch to the tab at idx and hide the grid."""
+        self.panel.visible = False
+        self.tab_manager.select_tab(idx)
+        self.page.update()
+
+    def close(self, idx: int) -> None:
+        """Close the tab at idx and list the remaining ones."""
+        self.tab_manager.close_tab(idx)
+        self.render()
+        self.page.update()
+
+    def new_tab(self) -> None:
+        """Open a new tab and hide the grid."""
+        self.panel.visible = False
+        self.tab_manager._on_add_click(None)
+
+    def _build_tile(self, idx: int, tab: dict) -> ft.Control:
+        active = idx == self.tab_manager.manager.index
+        can_close = len(self.tab_manager.manager.tabs) > 1
+        tile = ft.Container(
+            padding=10,
+            border_radius=10,
+            bgcolor=(
+                ft.Colors.PRIMARY_CONTAINER
+                if active
+                else ft.Colors.SURFACE_CONTAINER_HIGHEST
+            ),
+            border=ft.border.all(2, ft.Colors.PRIMARY) if active else None,
+            ink=True,
+            on_click=lambda e, i=idx: self.activate(i),
+            content=ft.Column(
+                spacing=6,
+                controls=[
+                    ft.Row(
+                        spacing=8,
+                        controls=[
+                            build_node_avatar(self.tab_manager.current_hash(tab)),
+                            ft.Text(
+                                tab["title"],
+                                size=13,
+                                weight=ft.FontWeight.W_500,
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                                expand=True,
+                            ),
+                            ft.IconButton(
+                                ft.Icons.CLOSE,
+                                tooltip="Close Tab",
+                                icon_size=16,
+                                visible=can_close,
+                                on_click=lambda e, i=idx: self.close(i),
+                            ),
+                        ],
+                    ),
+                    ft.Text(
+                        tab.get("address") or "New tab page",
+                        size=11,
+                        color=ft.Colors.ON_SURFACE_VARIANT,
+                        max_lines=3,
+                        overflow=ft.TextOverflow.ELLIPSIS,
+                    ),
+                ],
+            ),
+        )
+        if not can_close:
+            # The last tab cannot be closed, so it cannot be swiped away either
+            return tile
+        return ft.Dismissible(
+            content=tile,
+            dismiss_direction=ft.DismissDirection.HORIZONTAL,
+            on_dismiss=lambda e, i=idx: self.close(i),
+        )
@@ -0,0 +1,300 @@
+"""Light, dark and custom themes for Ren Browser.
+
+The browser's own controls use the theme's colour roles rather than fixed
+colours, so one palette per brightness is enough to restyle everything,
+micron headings and links included. The System theme follows the
+platform's light or dark preference, and custom themes from the theme
+library supply their own palette. Every theme outlines the button that
+has the keyboard focus. High Contrast is pure white on black with thicker
+outlines, and rendered pages drop their own colours and always underline
+links under it.
+
+Rendered pages can also be shown in colours of their own whatever the
+theme, such as sepia for reading, through a nested theme on the page area
+so the renderers' default text, heading and link colours follow them.
+"""
+
+import dataclasses
+
+import flet as ft
+
+from ren_browser.themes.themes import CustomTheme, is_color, is_dark_color, mix
+
+THEME_OPTIONS = {
+    "dark": "Dark",
+    "light": "Light",
+    "system": "System",
+    "high_contrast": "High Contrast",
+}
+THEME_MODES = {
+    "dark": ft.ThemeMode.DARK,
+    "light": ft.ThemeMode.LIGHT,
+    "system": ft.ThemeMode.SYSTEM,
+    "high_contrast": ft.ThemeMode.DARK,
+}
+CUSTOM_PREFIX = "custom:"
+HIGH_CONTRAST = "high_contrast"
+FOCUS_OUTLINE_WIDTH = 2
+HIGH_CONTRAST_OUTLINE_WIDTH = 3
+
+PAGE_COLOR_OPTIONS = {
+    "theme": "Same as theme",
+    "dark": "Dark",
+    "light": "Light",
+    "sepia": "Sepia",
+    "custom": "Custom",
+}
+PAGE_COLOR_PRESETS = {
+    "dark": CustomTheme(
+        "Dark",
+        accent="#64b5f6",
+        background="#121212",
+        text="#e0e0e0",
+        link="#64b5f6",
+        tab="#0d47a1",
+    ),
+    "light": CustomTheme(
+        "Light",
+        accent="#1565c0",
+        background="#ffffff",
+        text="#212121",
+        link="#1565c0",
+        tab="#bbdefb",
+    ),
+    "sepia": CustomTheme(
+        "Sepia",
+        accent="#8b4513",
+        background="#f4ecd8",
+        text="#5b4636",
+        link="#1a5fb4",
+        tab="#e4d5b7",
+    ),
+}
+
+DARK_SCHEME = ft.ColorScheme(
+    primary=ft.Colors.BLUE_400,
+    on_primary=ft.Colors.WHITE,
+    primary_container=ft.Colors.BLUE_900,
+    on_primary_container=ft.Colors.WHITE,
+    tertiary=ft.Colors.BLUE_400,
+    surface=ft.Colors.BLACK,
+    on_surface=ft.Colors.WHITE,
+    surface_container=ft.Colors.GREY_900,
+    surface_container_highest=ft.Colors.GREY_800,
+    outline_variant=ft.Colors.GREY_700,
+    background=ft.Colors.BLACK,
+    on_background=ft.Colors.WHITE,
+)
+LIGHT_SCHEME = ft.ColorScheme(
+    primary=ft.Colors.BLUE_800,
+    on_primary=ft.Colors.WHITE,
+    primary_container=ft.Colors.BLUE_100,
+    on_primary_container=ft.Colors.BLUE_900,
+    tertiary=ft.Colors.BLUE_800,
+    surface=ft.Colors.WHITE,
+    on_surface=ft.Colors.BLACK87,
+    on_surface_variant=ft.Colors.GREY_700,
+    surface_container=ft.Colors.GREY_100,
+    surface_container_highest=ft.Colors.GREY_300,
+    outline_variant=ft.Colors.GREY_400,
+    background=ft.Colors.WHITE,
+    on_background=ft.Colors.BLACK87,
+)
+HIGH_CONTRAST_SCHEME = ft.ColorScheme(
+    primary=ft.Colors.WHITE,
+    on_primary=ft.Colors.BLACK,
+    primary_container=ft.Colors.WHITE,
+    on_primary_container=ft.Colors.BLACK,
+    tertiary=ft.Colors.WHITE,
+    surface=ft.Colors.BLACK,
+    on_surface=ft.Colors.WHITE,
+    on_surface_variant=ft.Colors.WHITE,
+    surface_container=ft.Colors.BLACK,
+    surface_container_highest=ft.Colors.BLACK,
+    outline=ft.Colors.WHITE,
+    outline_variant=ft.Colors.WHITE,
+    background=ft.Colors.BLACK,
+    on_background=ft.Colors.WHITE,
+)
+
+
+def theme_options(library) -> dict[str, str]:
+    """Return the theme choices, the built-in ones followed by custom themes.
+
+    Args:
+        library: ThemeLibrary with the custom themes, or None.
+
+    """
+    options = dict(THEME_OPTIONS)
+    if library is not None:
+        for theme in library.themes():
+            options[CUSTOM_PREFIX + theme.name] = theme.name
+    return options
+
+
+def is_high_contrast(settings: dict) -> bool:
+    """Return whether the High Contrast theme is chosen in the settings."""
+    return settings.get("theme") == HIGH_CONTRAST
+
+
+def build_theme(
+    scheme: ft.ColorScheme,
+    font_family: str | None = None,
+    outline_width: int = FOCUS_OUTLINE_WIDTH,
+    **extra,
+) -> ft.Theme:
+    """Return a theme whose buttons are outlined while they have the focus.
+
+    Args:
+        scheme: Colour scheme of the theme.
+        font_family: Font of the interface, or None for Flet's default.
+        outline_width: Width of the focus outline.
+        **extra: Further ft.Theme arguments.
+
+    """
+    outline = ft.ButtonStyle(
+        side={
+            ft.ControlState.FOCUSED: ft.BorderSide(outline_width, ft.Colors.PRIMARY),
+        },
+    )
+    return ft.Theme(
+        color_scheme=scheme,
+        font_family=font_family,
+        text_button_theme=ft.TextButtonTheme(style=outline),
+        elevated_button_theme=ft.ElevatedButtonTheme(style=outline),
+        outlined_button_theme=ft.OutlinedButtonTheme(style=outline),
+        icon_button_theme=ft.IconButtonTheme(style=outline),
+        **extra,
+    )
+
+
+def high_contrast_theme(font_family: str | None = None) -> ft.Theme:
+    """Return the High Contrast theme, outlining focused controls thickly."""
+    return build_theme(
+        HIGH_CONTRAST_SCHEME,
+        font_family,
+        HIGH_CONTRAST_OUTLINE_WIDTH,
+        focus_color=ft.Colors.with_opacity(0.3, ft.Colors.WHITE),
+    )
+
+
+def custom_scheme(theme: CustomTheme) -> ft.ColorScheme:
+    """Return the colour scheme for a custom theme.
+
+    The shades of the bars and dividers are blended from the background
+    and text colours. Links use the tertiary role so they can differ from
+    the accent used for headings and buttons.
+    """
+    return ft.ColorScheme(
+        primary=theme.accent,
+        on_primary=theme.background,
+        primary_container=theme.tab,
+        on_primary_container=theme.text,
+        tertiary=theme.link,
+        surface=theme.background,
+        on_surface=theme.text,
+        on_surface_variant=mix(theme.text, theme.background, 0.3),
+        surface_container=mix(theme.background, theme.text, 0.08),
+        surface_container_highest=mix(theme.background, theme.text, 0.16),
+        outline_variant=mix(theme.background, theme.text, 0.3),
+        background=theme.background,
+        on_background=theme.text,
+    )
+
+
+def apply_theme(
+    page: ft.Page,
+    theme: str,
+    library=None,
+    font_family: str | None = None,
+) -> None:
+    """Switch the page to a theme.
+
+    Args:
+        page: Flet page to restyle.
+        theme: A key of theme_options. Unknown themes fall back to Dark.
+        library: ThemeLibrary holding the custom themes, or None.
+        font_family: Font of the interface, or None for Flet's default.
+
+    """
+    custom = None
+    if library is not None and theme.startswith(CUSTOM_PREFIX):
+        custom = library.get(theme.removeprefix(CUSTOM_PREFIX))
+    font_family = font_family or None
+    if custom is not None:
+        page.theme = build_theme(custom_scheme(custom), font_family)
+        page.dark_theme = page.theme
+        page.theme_mode = (
+            ft.ThemeMode.DARK if custom.is_dark() else ft.ThemeMode.LIGHT
+        )
+    elif theme == HIGH_CONTRAST:
+        page.theme = high_contrast_theme(font_family)
+        page.dark_theme = page.theme
+        page.theme_mode = ft.ThemeMode.DARK
+    else:
+        page.theme = build_theme(LIGHT_SCHEME, font_family)
+        page.dark_theme = build_theme(DARK_SCHEME, font_family)
+        page.theme_mode = THEME_MODES.get(theme, ft.ThemeMode.DARK)
+    page.bgcolor = ft.Colors.SURFACE
+    if page.appbar is not None:
+        page.appbar.bgcolor = ft.Colors.SURFACE_CONTAINER
+
+
+def page_colors(settings: dict) -> CustomTheme | None:
+    """Return the colours rendered pages are shown in.
+
+    Custom colours take their accent and links from the dark or light
+    preset, whichever suits the background.
+
+    Returns:
+        The colours, or None if pages follow the theme, as they always do
+        under High Contrast or when a custom colour is invalid.
+
+    """
+    if is_high_contrast(settings):
+        return None
+    choice = settings.get("page_colors", "theme")
+    if choice != "custom":
+        return PAGE_COLOR_PRESETS.get(choice)
+    background = settings.get("page_bgcolor")
+    text = settings.get("page_fgcolor")
+    if not is_color(background) or not is_color(text):
+        return None
+    preset = PAGE_COLOR_PRESETS["dark" if is_dark_color(background) else "light"]
+    return dataclasses.replace(
+        preset,
+        name="Custom",
+        background=background,
+        text=text,
+    )
+
+
+def content_bgcolor(settings: dict) -> str:
+    """Return the background of rendered pages, the theme's unless set."""
+    colors = page_colors(settings)
+    return ft.Colors.SURFACE if colors is None else colors.background
+
+
+def apply_content_colors(container: ft.Container, settings: dict) -> None:
+    """Show the page area in the page colours of the settings.
+
+    Args:
+        container: Container the rendered pages are shown in.
+        settings: App settings holding the page colours and interface font.
+
+    """
+    colors = page_colors(settings)
+    container.bgcolor = content_bgcolor(settings)
+    if colors is None:
+        container.theme = None
+        container.dark_theme = None
+        container.theme_mode = None
+        return
+    container.theme = build_theme(
+        custom_scheme(colors),
+        settings.get("ui_font") or None,
+    )
+    container.dark_theme = container.theme
+    container.theme_mode = (
+        ft.ThemeMode.DARK if colors.is_dark() else ft.ThemeMode.LIGHT
+    )
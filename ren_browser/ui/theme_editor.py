@@ -0,0 +1,205 @@
+"""Custom theme editor for Ren Browser.
+
+Creates, edits and deletes the themes in the theme library, with a small
+mock-up of the browser that previews the colours while they are typed.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.themes.themes import COLOR_FIELDS, CustomTheme, is_color, mix
+
+NEW_THEME = ""
+
+
+class ThemeEditor:
+    """Dialog editing the custom themes."""
+
+    def __init__(
+        self,
+        page: ft.Page,
+        library,
+        on_change: Callable[[str | None, str | None], None] | None = None,
+    ):
+        """Build the dialog.
+
+        Args:
+            page: Flet page the dialog is shown on.
+            library: ThemeLibrary holding the themes.
+            on_change: Called after a theme was saved or deleted, with the
+                name it had before, None for a new theme, and the name it
+                has now, None once deleted.
+
+        """
+        self.page = page
+        self.library = library
+        self.on_change = on_change
+        self.editing: str | None = None
+        self.theme_dropdown = ft.Dropdown(
+            label="Theme",
+            on_change=lambda e: self.select(e.control.value or NEW_THEME),
+        )
+        self.name_field = ft.TextField(label="Name")
+        self.color_fields = {
+            key: ft.TextField(
+                label=label,
+                hint_text="#rrggbb",
+                width=160,
+                on_change=lambda e: self.update_preview(),
+            )
+            for key, label in COLOR_FIELDS.items()
+        }
+        self.swatches = {
+            key: ft.Container(width=32, height=32, border_radius=6)
+            for key in COLOR_FIELDS
+        }
+        self.error = ft.Text("", color=ft.Colors.ERROR, size=12, visible=False)
+        self.preview_tab = ft.Container(
+            padding=ft.padding.symmetric(horizontal=12, vertical=6),
+            border_radius=6,
+        )
+        self.preview_heading = ft.Text("Heading", size=16, weight=ft.FontWeight.BOLD)
+        self.preview_text = ft.Text("Page text with a ", size=13)
+        self.preview_link = ft.Text("link", size=13)
+        self.preview_bar = ft.Container(
+            padding=8,
+            content=ft.Row(controls=[self.preview_tab], spacing=6),
+        )
+        self.preview = ft.Container(
+            border_radius=8,
+            content=ft.Column(
+                spacing=0,
+                controls=[
+                    self.preview_bar,
+                    ft.Container(
+                        padding=12,
+                        content=ft.Column(
+                            spacing=6,
+                            controls=[
+                                self.preview_heading,
+                                ft.Row(
+                                    spacing=0,
+                                    controls=[self.preview_text, self.preview_link],
+                                ),
+                            ],
+                        ),
+                    ),
+                ],
+            ),
+        )
+        self.delete_button = ft.TextButton(
+            "Delete",
+            on_click=lambda e: self.delete(),
+        )
+        self.dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text("Themes"),
+            content=ft.Column(
+                tight=True,
+                spacing=12,
+                width=420,
+                scroll=ft.ScrollMode.AUTO,
+                controls=[
+                    self.theme_dropdown,
+                    self.name_field,
+                    *(
+                        ft.Row(controls=[self.swatches[key], field], spacing=12)
+                        for key, field in self.color_fields.items()
+                    ),
+                    self.preview,
+                    self.error,
+                ],
+            ),
+            actions=[
+                self.delete_button,
+                ft.TextButton("Close", on_click=lambda e: self.close()),
+                ft.FilledButton("Save", on_click=lambda e: self.save()),
+            ],
+        )
+
+    def open(self, name: str | None = None) -> None:
+        """Show the dialog on a saved theme, or on a new one."""
+        self.select(name or NEW_THEME)
+        open_dialog(self.page, self.dialog)
+
+    def select(self, name: str) -> None:
+        """Load a saved theme into the fields, or defaults for NEW_THEME."""
+        theme = self.library.get(name) if name else None
+        self.editing = theme.name if theme else None
+        shown = theme or CustomTheme("")
+        self.theme_dropdown.options = [
+            ft.dropdown.Option(NEW_THEME, "New theme"),
+            *(ft.dropdown.Option(t.name, t.name) for t in self.library.themes()),
+        ]
+        self.theme_dropdown.value = self.editing or NEW_THEME
+        self.name_field.value = shown.name
+        for key, field in self.color_fields.items():
+            field.value = getattr(shown, key)
+        self.delete_button.visible = theme is not None
+        self.error.visible = False
+        self.update_preview()
+
+    def edited_theme(self) -> CustomTheme:
+        """Return the theme as currently typed into the fields."""
+        return CustomTheme(
+            self.name_field.value or "",
+            **{
+                key: (field.value or "").strip().lower()
+                for key, field in self.color_fields.items()
+            },
+        )
+
+    def update_preview(self) -> None:
+        """Recolour the swatches and preview, skipping colours not typed in full."""
+        default = CustomTheme("")
+        edited = self.edited_theme()
+        colors = {
+            key: getattr(edited if is_color(getattr(edited, key)) else default, key)
+            for key in COLOR_FIELDS
+        }
+        for key, swatch in self.swatches.items():
+            swatch.bgcolor = colors[key]
+        self.preview.bgcolor = colors["background"]
+        background, text = colors["background"], colors["text"]
+        self.preview.border = ft.border.all(1, mix(background, text, 0.3))
+        self.preview_bar.bgcolor = mix(background, text, 0.08)
+        self.preview_tab.bgcolor = colors["tab"]
+        self.preview_tab.content = ft.Text(
+            edited.name.strip() or "Tab",
+            size=12,
+            color=colors["text"],
+        )
+        self.preview_heading.color = colors["accent"]
+        self.preview_text.color = colors["text"]
+        self.preview_link.color = colors["link"]
+        self.page.update()
+
+    def save(self) -> None:
+        """Save the edited theme, keeping the dialog open on it."""
+        previous = self.editing
+        try:
+            theme = self.library.save(self.edited_theme(), previous)
+        except (ValueError, OSError) as exc:
+            self.error.value = str(exc)
+            self.error.visible = True
+            self.page.update()
+            return
+        self.select(theme.name)
+        if self.on_change:
+            self.on_change(previous, theme.name)
+
+    def delete(self) -> None:
+        """Delete the theme being edited and start a new one."""
+        name = self.editing
+        if name is None:
+            return
+        self.library.remove(name)
+        self.select(NEW_THEME)
+        if self.on_change:
+            self.on_change(name, None)
+
+    def close(self) -> None:
+        """Hide the dialog."""
+        self.page.close(self.dialog)
@@ -0,0 +1,137 @@
+"""Tray icon for Ren Browser.
+
+Shows an icon in the system tray while the desktop app runs, with the
+number of announced nodes, the pending notifications and quick actions.
+With the ``close_to_tray`` setting, closing the window hides it to the
+tray so announces keep being monitored. The icon needs the optional
+``pystray`` and Pillow packages and is left out without them.
+"""
+
+from collections.abc import Callable
+
+import flet as ft
+
+from ren_browser.i18n.i18n import ntr, tr
+
+ICON_SIZE = 64
+
+
+def pending_count(tab_manager) -> int:
+    """Return how many changed watched pages and feed items are unseen."""
+    return tab_manager.watcher.unseen_count() + tab_manager.feed.unread_count()
+
+
+def tray_lines(tab_manager) -> list[str]:
+    """Return the status lines shown in the tray menu."""
+    service = tab_manager.announce_service
+    nodes = len(service.get_announces()) if service else 0
+    pending = pending_count(tab_manager)
+    return [
+        ntr("1 node announced", "{count} nodes announced", nodes),
+        ntr("1 pending notification", "{count} pending notifications", pending),
+    ]
+
+
+def _icon_image():
+    from PIL import Image, ImageDraw
+
+    image = Image.new("RGBA", (ICON_SIZE, ICON_SIZE), (0, 0, 0, 0))
+    draw = ImageDraw.Draw(image)
+    draw.ellipse((4, 4, ICON_SIZE - 4, ICON_SIZE - 4), fill=(33, 150, 243, 255))
+    draw.ellipse((22, 22, ICON_SIZE - 22, ICON_SIZE - 22), fill=(255, 255, 255, 255))
+    return image
+
+
+class TrayIcon:
+    """Icon in the system tray with the browser's status and quick actions."""
+
+    def __init__(self, page: ft.Page, tab_manager, on_quit: Callable[[], None]):
+        """Prepare the icon without showing it.
+
+        Args:
+            page: Flet page whose window the icon shows and hides.
+            tab_manager: Tab manager holding the settings and the status.
+            on_quit: Closes the browser.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.on_quit = on_quit
+        self.icon = None
+
+    @property
+    def running(self) -> bool:
+        """Whether the icon is shown in the tray."""
+        return self.icon is not None
+
+    def apply_settings(self, settings: dict) -> None:
+        """Show or remove the icon as the ``tray_icon`` setting asks."""
+        if self.page.web or not settings.get("tray_icon", False):
+            self.stop()
+        elif not self.running:
+            self.start()
+
+    def start(self) -> bool:
+        """Show the icon in the tray.
+
+        Returns:
+            False if the tray is not available on this system.
+
+        """
+        try:
+            import pystray
+
+            image = _icon_image()
+        except ImportError:
+            return False
+
+        def status(index):
+            return lambda item: tray_lines(self.tab_manager)[index]
+
+        menu = pystray.Menu(
+            pystray.MenuItem(
+                tr("Open Ren Browser"),
+                lambda icon, item: self.show_window(),
+                default=True,
+            ),
+            pystray.MenuItem(status(0), None, enabled=False),
+            pystray.MenuItem(status(1), None, enabled=False),
+            pystray.Menu.SEPARATOR,
+            pystray.MenuItem(tr("Quit"), lambda icon, item: self.quit()),
+        )
+        try:
+            self.icon = pystray.Icon("ren-browser", image, "Ren Browser", menu)
+            self.icon.run_detached()
+        except Exception:  # noqa: BLE001
+            self.icon = None
+            return False
+        return True
+
+    def refresh(self) -> None:
+        """Bring the status lines of the menu up to date."""
+        if self.icon is not None:
+            self.icon.update_menu()
+
+    def hide_window(self) -> None:
+        """Hide the window to the tray."""
+        self.page.window.visible = False
+        self.page.window.skip_task_bar = True
+        self.page.update()
+
+    def show_window(self) -> None:
+        """Bring the hidden window back."""
+        self.page.window.skip_task_bar = False
+        self.page.window.visible = True
+        self.page.update()
+        self.page.window.to_front()
+
+    def quit(self) -> None:
+        """Remove the icon and close the browser."""
+        self.stop()
+        self.on_quit()
+
+    def stop(self) -> None:
+        """Remove the icon from the tray."""
+        if self.icon is not None:
+            self.icon.stop()
+            self.icon = None
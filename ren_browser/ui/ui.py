@@ -8,11 +8,32 @@ import flet as ft
 from flet import Page
 
 from ren_browser.announces.announces import AnnounceService
+from ren_browser.controls.autocomplete import node_suggestions
+from ren_browser.controls.context_menu import ContextMenu
 from ren_browser.controls.shortcuts import Shortcuts
-from ren_browser.pages.page_request import PageFetcher, PageRequest
-from ren_browser.renderer.micron import render_micron
-from ren_browser.renderer.plaintext import render_plaintext
+from ren_browser.i18n.i18n import SYSTEM_LANGUAGE, set_language
+from ren_browser.power.power import PowerMonitor
+from ren_browser.storage.storage import get_storage_manager
 from ren_browser.tabs.tabs import TabsManager
+from ren_browser.ui.announce_list import AnnounceList
+from ren_browser.ui.back_button import BackNavigation
+from ren_browser.ui.command_palette import CommandPalette
+from ren_browser.ui.favorite_alerts import FavoriteAlerts
+from ren_browser.ui.keyboard import KeyboardInsets
+from ren_browser.ui.lifecycle import AppLifecycle
+from ren_browser.ui.link_preview import LinkPreview
+from ren_browser.ui.notification_center import NotificationPanel
+from ren_browser.ui.performance import PerformanceOverlay
+from ren_browser.ui.qr_scan import QrScanner
+from ren_browser.ui.settings_watcher import SettingsWatcher
+from ren_browser.ui.shortcut_sheet import ShortcutSheet
+from ren_browser.ui.sidebar import Sidebar
+from ren_browser.ui.status_bar import StatusBar
+from ren_browser.ui.tab_switcher import COMPACT_WIDTH
+from ren_browser.ui.theme import apply_theme
+from ren_browser.ui.tray import TrayIcon
+from ren_browser.ui.window import WindowTracker
+from ren_browser.ui.zoom import UiZoom
 
 
 def build_ui(page: Page):
@@ -21,150 +42,131 @@ def build_ui(page: Page):
     Args:
         page: Flet page instance to build UI on.
 
+    Returns:
+        TabsManager: The tab manager of the built UI.
+
     """
-    page.theme_mode = ft.ThemeMode.DARK
-    page.theme = ft.Theme(
-        color_scheme=ft.ColorScheme(
-            primary=ft.Colors.BLUE_400,
-            on_primary=ft.Colors.WHITE,
-            surface=ft.Colors.BLACK,
-            on_surface=ft.Colors.WHITE,
-            background=ft.Colors.BLACK,
-            on_background=ft.Colors.WHITE,
-        ),
-    )
-    page.bgcolor = ft.Colors.BLACK
     page.appbar = ft.AppBar(
-        bgcolor=ft.Colors.GREY_900,
+        bgcolor=ft.Colors.SURFACE_CONTAINER,
         elevation=2,
     )
-    page.window.maximized = True
     page.padding = 0
 
-    page_fetcher = PageFetcher()
-    announce_list = ft.ListView(expand=True, spacing=8, padding=ft.padding.all(8))
-
-    def update_announces(ann_list):
-        announce_list.controls.clear()
-        for ann in ann_list:
-            label = ann.display_name or ann.destination_hash
-
-            def on_click_ann(e, dest=ann.destination_hash, disp=ann.display_name):
-                title = disp or "Anonymous"
-                full_url = f"{dest}:/page/index.mu"
-                placeholder = render_plaintext(f"Fetching content for {full_url}")
-                tab_manager._add_tab_internal(title, placeholder)
-                idx = len(tab_manager.manager.tabs) - 1
-                tab = tab_manager.manager.tabs[idx]
-                tab["url_field"].value = full_url
-                tab_manager.select_tab(idx)
-                page.update()
-
-                def fetch_and_update():
-                    req = PageRequest(destination_hash=dest, page_path="/page/index.mu")
-                    try:
-                        result = page_fetcher.fetch_page(req)
-                    except Exception as ex:
-                        import ren_browser.app as app_module
-
-                        app_module.log_error(str(ex))
-                        result = f"Error: {ex}"
-                    try:
-                        tab = tab_manager.manager.tabs[idx]
-                    except IndexError:
-                        return
-
-                    def handle_link_click(url):
-                        full_url = url
-                        if ":" not in url:
-                            full_url = f"{url}:/page/index.mu"
-                        elif url.startswith(":/"):
-                            full_url = f"{dest}{url}"
-                        tab["url_field"].value = full_url
-                        tab_manager._on_tab_go(None, idx)
-
-                    if req.page_path.endswith(".mu"):
-                        new_control = render_micron(
-                            result,
-                            on_link_click=handle_link_click,
-                        )
-                    else:
-                        new_control = render_plaintext(result)
-                    tab["content_control"] = new_control
-                    tab["content"].controls[0] = new_control
-                    if tab_manager.manager.index == idx:
-                        tab_manager.content_container.content = tab["content"]
-                    page.update()
-
-                page.run_thread(fetch_and_update)
-
-            announce_card = ft.Container(
-                content=ft.Row(
-                    controls=[
-                        ft.Icon(ft.Icons.LANGUAGE, size=20, color=ft.Colors.BLUE_400),
-                        ft.Text(
-                            label,
-                            size=14,
-                            weight=ft.FontWeight.W_500,
-                            overflow=ft.TextOverflow.ELLIPSIS,
-                        ),
-                    ],
-                    spacing=12,
-                ),
-                padding=ft.padding.all(12),
-                border_radius=8,
-                bgcolor=ft.Colors.GREY_800,
-                ink=True,
-                on_click=on_click_ann,
-            )
-            announce_list.controls.append(announce_card)
-        page.update()
-
-    AnnounceService(update_callback=update_announces)
-    page.drawer = ft.NavigationDrawer(
-        bgcolor=ft.Colors.GREY_900,
-        elevation=8,
-        controls=[
-            ft.Container(
-                content=ft.Text(
-                    "Announcements",
-                    size=20,
-                    weight=ft.FontWeight.BOLD,
-                    color=ft.Colors.BLUE_400,
-                ),
-                padding=ft.padding.symmetric(horizontal=16, vertical=20),
-            ),
-            ft.Divider(height=1, color=ft.Colors.GREY_700),
-            announce_list,
-        ],
+    # The language is chosen before any control is built, as their text is
+    # translated when they are created.
+    storage = get_storage_manager(page)
+    settings = storage.load_app_settings()
+    set_language(settings.get("language", SYSTEM_LANGUAGE))
+    tab_manager = TabsManager(page, settings)
+    window_tracker = WindowTracker(page, tab_manager, storage)
+    tray = TrayIcon(page, tab_manager, on_quit=window_tracker.quit)
+    window_tracker.tray = tray
+    tab_manager.tray = tray
+    apply_theme(
+        page,
+        tab_manager.settings.get("theme", "dark"),
+        tab_manager.themes,
+        tab_manager.settings.get("ui_font"),
     )
+    announce_list = AnnounceList(page, tab_manager)
+    tab_manager.announce_list = announce_list
+    announce_service = AnnounceService(update_callback=announce_list.update)
+    tab_manager.announce_service = announce_service
+    favorite_alerts = FavoriteAlerts(
+        page,
+        tab_manager,
+        on_open=tab_manager.open_link_in_new_tab,
+    )
+    announce_service.add_listener(favorite_alerts.on_announce)
+    announce_service.add_listener(tab_manager.activity.record)
+    announce_service.add_listener(lambda ann: tray.refresh())
+    tray.apply_settings(tab_manager.settings)
+    sidebar = Sidebar(page, tab_manager, announce_list.control)
+    tab_manager.sidebar = sidebar
+    sidebar.set_animated(not tab_manager.reduced_motion())
+    sidebar.set_panels(tab_manager.plugins.build_panels(tab_manager))
     page.appbar.leading = ft.IconButton(
         ft.Icons.MENU,
-        tooltip="Announcements",
-        icon_color=ft.Colors.WHITE,
-        on_click=lambda e: (
-            setattr(page.drawer, "open", not page.drawer.open),
-            page.update(),
-        ),
+        tooltip="Toggle sidebar (Ctrl+B)",
+        icon_color=ft.Colors.ON_SURFACE,
+        on_click=lambda e: sidebar.toggle(),
     )
+    tab_manager.autocomplete.add_source(
+        lambda: node_suggestions(announce_service.get_announces()),
+    )
+
+    def resolve_node_name(name):
+        wanted = name.strip().lower()
+        for destination_hash, alias in tab_manager.nodes.aliases().items():
+            if alias.strip().lower() == wanted:
+                return destination_hash
+        for ann in announce_service.get_announces():
+            if ann.display_name and ann.display_name.strip().lower() == wanted:
+                return ann.destination_hash
+        return None
 
-    tab_manager = TabsManager(page)
+    def node_name(destination_hash):
+        for ann in announce_service.get_announces():
+            if ann.destination_hash == destination_hash:
+                return tab_manager.nodes.display_name(
+                    destination_hash,
+                    ann.display_name,
+                )
+        return tab_manager.nodes.get(destination_hash).alias
+
+    tab_manager.resolve_node_name = resolve_node_name
+    tab_manager.node_name = node_name
     from ren_browser.ui.settings import open_settings_tab
 
+    notification_panel = NotificationPanel(
+        page,
+        tab_manager.notifications,
+        on_open=tab_manager.open_link_in_new_tab,
+    )
     page.appbar.actions = [
+        ft.IconButton(
+            ft.Icons.FOLDER_OPEN,
+            tooltip="Open file (Ctrl+O)",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: tab_manager.file_opener.choose(),
+            visible=not page.web,
+        ),
+        ft.IconButton(
+            ft.Icons.DOWNLOAD,
+            tooltip="Downloads (Ctrl+J)",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: tab_manager.downloads_panel.toggle(),
+        ),
+        ft.IconButton(
+            ft.Icons.BOOKMARKS,
+            tooltip="Bookmarks (Ctrl+Shift+O)",
+            icon_color=ft.Colors.ON_SURFACE,
+            on_click=lambda e: tab_manager.open_link_in_new_tab("about:bookmarks"),
+        ),
+        tab_manager.feed_btn,
+        tab_manager.watch_btn,
+        notification_panel.button,
         ft.IconButton(
             ft.Icons.SETTINGS,
             tooltip="Settings",
-            icon_color=ft.Colors.WHITE,
+            icon_color=ft.Colors.ON_SURFACE,
             on_click=lambda e: open_settings_tab(page, tab_manager),
         ),
     ]
-    Shortcuts(page, tab_manager)
+    shortcuts = Shortcuts(page, tab_manager)
+    tab_manager.command_palette = CommandPalette(page, tab_manager, shortcuts.dispatch)
+    tab_manager.shortcut_sheet = ShortcutSheet(page, tab_manager)
+    scanner = QrScanner(page, tab_manager._open_address)
     url_bar = ft.Container(
         content=ft.Row(
             controls=[
+                tab_manager.home_btn,
                 tab_manager.manager.tabs[tab_manager.manager.index]["url_field"],
                 tab_manager.manager.tabs[tab_manager.manager.index]["go_btn"],
+                tab_manager.clipboard_hint.chip,
+                scanner.button,
+                tab_manager.auto_refresh.button,
+                tab_manager.bookmark_btn,
             ],
             spacing=8,
         ),
@@ -172,37 +174,109 @@ def build_ui(page: Page):
         padding=ft.padding.symmetric(horizontal=8),
     )
     page.appbar.title = url_bar
+    tab_manager.address_bar = url_bar
     orig_select_tab = tab_manager.select_tab
 
     def _select_tab_and_update_url(i):
         orig_select_tab(i)
         tab = tab_manager.manager.tabs[i]
         url_bar.content.controls.clear()
-        url_bar.content.controls.extend([tab["url_field"], tab["go_btn"]])
+        url_bar.content.controls.extend(
+            [
+                tab_manager.home_btn,
+                tab["url_field"],
+                tab["go_btn"],
+                tab_manager.clipboard_hint.chip,
+                scanner.button,
+                tab_manager.auto_refresh.button,
+                tab_manager.bookmark_btn,
+            ],
+        )
         page.update()
 
     tab_manager.select_tab = _select_tab_and_update_url
 
+    zoom = UiZoom(page, tab_manager)
+    tab_manager.zoom = zoom
+
     def _update_content_width(e=None):
-        tab_manager.content_container.width = page.width
+        viewport_width = zoom.viewport_width()
+        sidebar.fit(viewport_width)
+        compact = bool(viewport_width) and viewport_width < COMPACT_WIDTH
+        tab_manager.set_compact_tabs(compact)
+        if viewport_width:
+            tab_manager.content_container.width = viewport_width - sidebar.total_width
+        else:
+            tab_manager.content_container.width = page.width
 
     _update_content_width()
-    page.on_resized = lambda e: (_update_content_width(), page.update())
+    sidebar.on_resize = _update_content_width
+    zoom.on_change = _update_content_width
+    page.on_resized = lambda e: (_update_content_width(), zoom.apply(), page.update())
+    context_menu = ContextMenu(page, tab_manager)
+    tab_manager.link_preview = LinkPreview(page, tab_manager)
     main_area = ft.Column(
         expand=True,
-        controls=[tab_manager.tab_bar, tab_manager.content_container],
+        controls=[
+            tab_manager.autocomplete.panel,
+            tab_manager.bookmark_editor.panel,
+            tab_manager.downloads_panel.control,
+            tab_manager.gestures.wrap_tab_bar(tab_manager.tab_bar),
+            tab_manager.progress_bar,
+            context_menu.wrap(
+                tab_manager.gestures.wrap_content(tab_manager.content_container),
+            ),
+            tab_manager.devtools.control,
+        ],
     )
 
-    layout = ft.Row(expand=True, controls=[main_area])
+    layout = ft.Row(
+        expand=True,
+        spacing=0,
+        controls=[
+            sidebar.control,
+            sidebar.divider,
+            main_area,
+        ],
+    )
+    status_bar = StatusBar(page, tab_manager)
+    tab_manager.status_bar = status_bar
+    status_bar.add_connection_listener(sidebar.set_connection)
+    sidebar.on_connection_click = status_bar.show_interfaces
 
-    page.add(
-        ft.Column(
+    zoom.root = ft.Container(
+        expand=True,
+        content=ft.Column(
             expand=True,
             controls=[
                 layout,
-                ft.Row(
-                    alignment=ft.MainAxisAlignment.END,
-                ),
+                status_bar.control,
             ],
+            spacing=0,
         ),
     )
+    zoom.apply()
+    page.add(zoom.root)
+    tab_manager.performance_overlay = PerformanceOverlay(page, tab_manager)
+    keyboard = KeyboardInsets(page, zoom)
+    for overlay in (
+        sidebar.drawer,
+        sidebar.scrim,
+        tab_manager.tab_switcher.panel,
+        tab_manager.performance_overlay.control,
+    ):
+        keyboard.anchor(overlay)
+    tab_manager.keyboard = keyboard
+    status_bar.start()
+    tab_manager.power_monitor = PowerMonitor(page, tab_manager)
+    tab_manager.power_monitor.start()
+    settings_watcher = SettingsWatcher(page, tab_manager, storage)
+    settings_watcher.start()
+    announce_list.start_refresh()
+    tab_manager.start_page_watcher()
+    tab_manager.start_node_feed()
+    tab_manager.start_update_checks()
+    window_tracker.lifecycle = AppLifecycle(page, tab_manager, settings_watcher)
+    BackNavigation(page, tab_manager)
+    tab_manager.apply_startup()
+    return tab_manager
@@ -0,0 +1,96 @@
+"""Update page for Ren Browser.
+
+The ``about:update`` page tells whether a newer release of Ren Browser is
+out and shows its release notes, which GitHub keeps as Markdown.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.i18n.i18n import tr
+from ren_browser.renderer.markdown import render_markdown
+
+
+def build_update_page(
+    updates,
+    on_check: Callable[[Callable[[], None]], None],
+    on_open_release: Callable[[str], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:update`` page.
+
+    Args:
+        updates: UpdateChecker with the last release found.
+        on_check: Called to check now, with a callback to run once done.
+        on_open_release: Called with the address of a release on GitHub.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The update page.
+
+    """
+    # The release notes scroll on their own below the heading
+    page = ft.Column(spacing=12, expand=True)
+    checking = [False]
+
+    def done():
+        checking[0] = False
+        render()
+        on_change()
+
+    def check_now(_):
+        checking[0] = True
+        render()
+        on_change()
+        on_check(done)
+
+    def render():
+        release = updates.available
+        if release is not None:
+            heading = tr(
+                "Ren Browser {version} is available",
+                version=release.version,
+            )
+        else:
+            heading = tr("Ren Browser is up to date")
+        checked_at = updates.state.get("checked_at")
+        status = tr("You are running {version}.", version=updates.current_version)
+        if checked_at:
+            checked = datetime.fromtimestamp(checked_at).strftime("%Y-%m-%d %H:%M")
+            status = f"{status} {tr('Last checked {time}.', time=checked)}"
+        actions: list[ft.Control] = [
+            ft.OutlinedButton(
+                tr("Checking…") if checking[0] else tr("Check Now"),
+                icon=ft.Icons.REFRESH,
+                disabled=checking[0],
+                on_click=check_now,
+            ),
+        ]
+        if release is not None and release.url:
+            actions.insert(
+                0,
+                ft.ElevatedButton(
+                    tr("Open Release Page"),
+                    icon=ft.Icons.OPEN_IN_NEW,
+                    on_click=lambda e: on_open_release(release.url),
+                ),
+            )
+        controls: list[ft.Control] = [
+            ft.Text(
+                heading,
+                size=24,
+                weight=ft.FontWeight.BOLD,
+                color=ft.Colors.PRIMARY,
+            ),
+            ft.Text(status, size=14, color=ft.Colors.ON_SURFACE_VARIANT),
+            ft.Row(controls=actions, spacing=8, wrap=True),
+        ]
+        if release is not None and release.notes:
+            controls.append(ft.Divider())
+            controls.append(render_markdown(release.notes))
+        page.controls = controls
+
+    render()
+    return page
@@ -0,0 +1,150 @@
+"""Watched pages for Ren Browser.
+
+Lists the pages being watched for changes with the ones that changed since
+they were last opened first, and lets them be checked straight away.
+"""
+
+from collections.abc import Callable
+from datetime import datetime
+
+import flet as ft
+
+from ren_browser.ui.avatars import build_node_avatar
+
+
+def build_watched_page(
+    watcher,
+    on_open: Callable[[str], None],
+    on_check: Callable[[Callable[[], None]], None],
+    on_change: Callable[[], None],
+) -> ft.Control:
+    """Build the ``about:watched`` page.
+
+    Args:
+        watcher: PageWatcher whose pages are listed.
+        on_open: Called with an address when a watched page is clicked.
+        on_check: Called to check every page now, with a callback to run once
+            the checks are done.
+        on_change: Called after the page has been redrawn so it can be updated.
+
+    Returns:
+        ft.Control: The watched pages page.
+
+    """
+    page = ft.Column(spacing=4)
+    checking = [False]
+
+    def changed():
+        render()
+        on_change()
+
+    def remove(address):
+        watcher.unwatch(address)
+        changed()
+
+    def done():
+        checking[0] = False
+        changed()
+
+    def check_now(_):
+        checking[0] = True
+        changed()
+        on_check(done)
+
+    def render():
+        controls: list[ft.Control] = [
+            ft.Row(
+                controls=[
+                    ft.Text(
+                        "Watched Pages",
+                        size=24,
+                        weight=ft.FontWeight.BOLD,
+                        color=ft.Colors.PRIMARY,
+                        expand=True,
+                    ),
+                    ft.OutlinedButton(
+                        "Checking…" if checking[0] else "Check Now",
+                        icon=ft.Icons.REFRESH,
+                        disabled=checking[0] or not watcher.pages(),
+                        on_click=check_now,
+                    ),
+                ],
+            ),
+        ]
+        pages = watcher.pages()
+        if not pages:
+            controls.append(
+                ft.Text(
+                    "Right-click a page and choose Watch for Changes to be told "
+                    "when it is updated.",
+                    size=14,
+                    color=ft.Colors.ON_SURFACE_VARIANT,
+                ),
+            )
+        controls.extend(_build_item(watched, on_open, remove) for watched in pages)
+        page.controls = controls
+
+    render()
+    return page
+
+
+def _describe(watched) -> str:
+    if watched.error:
+        return f"Last check failed: {watched.error}"
+    if watched.unseen and watched.changed_at:
+        changed = datetime.fromtimestamp(watched.changed_at).strftime("%Y-%m-%d %H:%M")
+        return f"Changed {changed}"
+    if watched.checked_at:
+        checked = datetime.fromtimestamp(watched.checked_at).strftime("%Y-%m-%d %H:%M")
+        return f"Unchanged, checked {checked}"
+    return "Not checked yet"
+
+
+def _build_item(watched, on_open, on_remove) -> ft.Control:
+    address = watched.address
+    return ft.Container(
+        content=ft.Row(
+            controls=[
+                build_node_avatar(address.split(":", 1)[0], size=20),
+                ft.Container(
+                    expand=True,
+                    ink=True,
+                    on_click=lambda e: on_open(address),
+                    content=ft.Column(
+                        spacing=2,
+                        controls=[
+                            ft.Text(
+                                watched.title,
+                                size=14,
+                                weight=(
+                                    ft.FontWeight.BOLD
+                                    if watched.unseen
+                                    else ft.FontWeight.W_500
+                                ),
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                            ft.Text(
+                                f"{address}  ·  {_describe(watched)}",
+                                size=12,
+                                color=(
+                                    ft.Colors.BLUE_200
+                                    if watched.unseen
+                                    else ft.Colors.ON_SURFACE_VARIANT
+                                ),
+                                overflow=ft.TextOverflow.ELLIPSIS,
+                            ),
+                        ],
+                    ),
+                ),
+                ft.IconButton(
+                    ft.Icons.VISIBILITY_OFF,
+                    tooltip="Stop watching",
+                    icon_size=18,
+                    on_click=lambda e: on_remove(address),
+                ),
+            ],
+            spacing=12,
+        ),
+        padding=ft.padding.symmetric(horizontal=12, vertical=6),
+        border_radius=8,
+    )
@@ -0,0 +1,202 @@
+"""Window size, position and closing for Ren Browser.
+
+Remembers the size, position and maximized state of the window when the
+browser closes and restores them on the next launch. Closing asks first
+while several tabs are open or downloads are running, or hides the window
+to the tray icon when that is turned on.
+"""
+
+from dataclasses import asdict, dataclass
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.downloads.downloads import ACTIVE
+from ren_browser.i18n.i18n import ntr, tr
+
+MIN_WINDOW_WIDTH = 400
+MIN_WINDOW_HEIGHT = 300
+
+
+def _number(value) -> float | None:
+    if isinstance(value, bool) or not isinstance(value, int | float):
+        return None
+    return float(value)
+
+
+@dataclass
+class WindowState:
+    """Size and position of the window when it is not maximized."""
+
+    width: float | None = None
+    height: float | None = None
+    left: float | None = None
+    top: float | None = None
+    maximized: bool = True
+
+    @classmethod
+    def from_dict(cls, data) -> "WindowState":
+        """Build a state from the stored ``window`` setting.
+
+        Missing or invalid values are left unset, and sizes smaller than a
+        usable window are dropped.
+        """
+        if not isinstance(data, dict):
+            return cls()
+        width, height = _number(data.get("width")), _number(data.get("height"))
+        if width is not None and width < MIN_WINDOW_WIDTH:
+            width = None
+        if height is not None and height < MIN_WINDOW_HEIGHT:
+            height = None
+        maximized = data.get("maximized", True)
+        return cls(
+            width=width,
+            height=height,
+            left=_number(data.get("left")),
+            top=_number(data.get("top")),
+            maximized=maximized if isinstance(maximized, bool) else True,
+        )
+
+    def to_dict(self) -> dict:
+        """Return the state as stored in the ``window`` setting."""
+        return {key: value for key, value in asdict(self).items() if value is not None}
+
+
+def close_warning(tab_manager) -> str | None:
+    """Describe what closing the browser would lose, or None if nothing."""
+    tabs = len(tab_manager.manager.tabs)
+    downloads = tab_manager.downloads.downloads()
+    running = sum(1 for download in downloads if download.status in ACTIVE)
+    reasons = []
+    if tabs > 1:
+        reasons.append(ntr("1 tab is open.", "{count} tabs are open.", tabs))
+    if running:
+        reasons.append(
+            ntr(
+                "1 download is still running.",
+                "{count} downloads are still running.",
+                running,
+            ),
+        )
+    return " ".join(reasons) or None
+
+
+class WindowTracker:
+    """Follows the window's size and position and saves them on close."""
+
+    def __init__(self, page: ft.Page, tab_manager, storage):
+        """Restore the stored window state and start following changes.
+
+        Args:
+            page: Flet page whose window is tracked.
+            tab_manager: Tab manager holding the current app settings.
+            storage: Storage manager the window state is saved with.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.storage = storage
+        self.state = WindowState.from_dict(tab_manager.settings.get("window"))
+        self.tray = None
+        self.lifecycle = None
+        self.restore()
+        page.window.prevent_close = True
+        page.window.on_event = self._on_event
+        self.ask_again = ft.Checkbox(label=tr("Don't ask again"), value=False)
+        self.warning = ft.Text()
+        self.dialog = ft.AlertDialog(
+            modal=True,
+            title=ft.Text(tr("Close Ren Browser?")),
+            content=ft.Column(tight=True, controls=[self.warning, self.ask_again]),
+            actions=[
+                ft.TextButton(tr("Cancel"), on_click=lambda e: self.cancel()),
+                ft.FilledButton(tr("Close"), on_click=lambda e: self.quit()),
+            ],
+        )
+
+    def restore(self) -> None:
+        """Apply the stored state to the window."""
+        window = self.page.window
+        if self.state.width is not None and self.state.height is not None:
+            window.width = self.state.width
+            window.height = self.state.height
+        if self.state.left is not None and self.state.top is not None:
+            window.left = self.state.left
+            window.top = self.state.top
+        window.maximized = self.state.maximized
+
+    def _on_event(self, e) -> None:  # type: ignore
+        if e.type == ft.WindowEventType.CLOSE:
+            self.close()
+        elif e.type == ft.WindowEventType.MAXIMIZE:
+            self.state.maximized = True
+        elif e.type == ft.WindowEventType.UNMAXIMIZE:
+            self.state.maximized = False
+        elif e.type in (ft.WindowEventType.RESIZED, ft.WindowEventType.MOVED):
+            self.record()
+        elif e.type == ft.WindowEventType.FOCUS:
+            self.tab_manager.clipboard_hint.check()
+        if self.lifecycle is not None:
+            self.lifecycle.on_window_event(e)
+
+    def record(self) -> None:
+        """Remember the window's current size and position.
+
+        A maximized or fullscreen window keeps the size it returns to.
+        """
+        window = self.page.window
+        if window.maximized or window.full_screen:
+            return
+        self.state = WindowState.from_dict(
+            {
+                "width": window.width,
+                "height": window.height,
+                "left": window.left,
+                "top": window.top,
+                "maximized": False,
+            },
+        )
+
+    def save(self) -> None:
+        """Store the window state in the app settings."""
+        settings = {**self.tab_manager.settings, "window": self.state.to_dict()}
+        self.tab_manager.settings = settings
+        self.storage.save_app_settings(settings)
+
+    def close(self) -> None:
+        """Close the window, first asking if tabs or downloads would be lost.
+
+        Asking can be turned off with the ``confirm_close`` setting. With
+        ``close_to_tray`` and a tray icon shown, the window is hidden instead.
+        """
+        settings = self.tab_manager.settings
+        if self.tray is not None and self.tray.running:
+            if settings.get("close_to_tray", False):
+                self.tray.hide_window()
+                return
+        warning = None
+        if self.tab_manager.settings.get("confirm_close", True):
+            warning = close_warning(self.tab_manager)
+        if warning is None:
+            self.quit()
+            return
+        self.warning.value = warning
+        self.ask_again.value = False
+        open_dialog(self.page, self.dialog)
+
+    def cancel(self) -> None:
+        """Keep the browser open."""
+        self.page.close(self.dialog)
+
+    def quit(self) -> None:
+        """Save the window state, then close the window."""
+        if self.ask_again.value:
+            self.tab_manager.settings = {
+                **self.tab_manager.settings,
+                "confirm_close": False,
+            }
+        self.save()
+        self.tab_manager.activity.save()
+        if self.tray is not None:
+            self.tray.stop()
+        self.page.window.destroy()
@@ -0,0 +1,133 @@
+"""Workspace dialogs for Ren Browser.
+
+Saves the open tabs under a name to a file chosen in a native save dialog,
+and opens the tabs of such a file again.
+"""
+
+from pathlib import Path
+
+import flet as ft
+
+from ren_browser.controls.dialogs import open_dialog
+from ren_browser.i18n.i18n import tr
+from ren_browser.tabs.workspace import (
+    FILE_EXTENSION,
+    Workspace,
+    WorkspaceFormatError,
+    file_name,
+    from_json,
+    to_json,
+)
+from ren_browser.ui.notify import show_snack
+
+
+class WorkspaceFiles:
+    """Name dialog and native file dialogs for saving and opening workspaces."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the dialogs.
+
+        Args:
+            page: Flet page instance the file picker is attached to.
+            tab_manager: Tab manager whose tabs are saved and opened.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        self.pending: Workspace | None = None
+        self.picker = ft.FilePicker(on_result=self._on_result)
+        page.overlay.append(self.picker)
+        self.name_field = ft.TextField(
+            label=tr("Workspace name"),
+            autofocus=True,
+            on_submit=lambda e: self.choose_file(),
+        )
+        self.dialog = ft.AlertDialog(
+            title=ft.Text(tr("Save Tabs as Workspace")),
+            content=ft.Container(content=self.name_field, width=360),
+            actions=[
+                ft.TextButton(tr("Cancel"), on_click=lambda e: self.cancel()),
+                ft.FilledButton(tr("Save…"), on_click=lambda e: self.choose_file()),
+            ],
+        )
+
+    def choose_save(self) -> None:
+        """Ask for the name to save the open tabs under."""
+        self.name_field.value = ""
+        self.name_field.error_text = None
+        open_dialog(self.page, self.dialog)
+
+    def choose_file(self) -> None:
+        """Ask where to save the workspace named in the dialog."""
+        name = (self.name_field.value or "").strip()
+        if not name:
+            self.name_field.error_text = tr("Enter a name")
+            self.page.update()
+            return
+        workspace = self.tab_manager.current_workspace(name)
+        if not workspace.tabs:
+            self.cancel()
+            show_snack(self.page, tr("No open tabs to save"), False)
+            return
+        self.pending = workspace
+        self.page.close(self.dialog)
+        self.picker.save_file(
+            dialog_title=tr("Save Tabs as Workspace"),
+            file_name=file_name(name),
+            allowed_extensions=[FILE_EXTENSION],
+        )
+
+    def choose_open(self) -> None:
+        """Ask for a workspace file to open."""
+        self.pending = None
+        self.picker.pick_files(
+            dialog_title=tr("Open Workspace"),
+            allowed_extensions=[FILE_EXTENSION],
+        )
+
+    def cancel(self) -> None:
+        """Close the name dialog without saving."""
+        self.pending = None
+        self.page.close(self.dialog)
+
+    def _on_result(self, e) -> None:  # type: ignore
+        workspace, self.pending = self.pending, None
+        if workspace is not None and e.path:
+            self.save_to(workspace, Path(e.path))
+        elif workspace is None and e.files and e.files[0].path:
+            self.open_from(Path(e.files[0].path))
+
+    def save_to(self, workspace: Workspace, path: Path) -> bool:
+        """Write a workspace to a file."""
+        try:
+            path.write_text(to_json(workspace), encoding="utf-8")
+        except OSError as exc:
+            show_snack(
+                self.page,
+                tr("Failed to save workspace: {error}", error=exc),
+                False,
+            )
+            return False
+        show_snack(
+            self.page,
+            tr("Saved workspace {name} to {path}", name=workspace.name, path=path),
+        )
+        return True
+
+    def open_from(self, path: Path) -> bool:
+        """Open the tabs of a workspace file after the open ones."""
+        try:
+            workspace = from_json(path.read_text(encoding="utf-8"))
+        except (OSError, UnicodeDecodeError, WorkspaceFormatError) as exc:
+            show_snack(
+                self.page,
+                tr("Failed to open workspace: {error}", error=exc),
+                False,
+            )
+            return False
+        self.tab_manager.open_workspace(workspace)
+        show_snack(
+            self.page,
+            tr("Opened workspace {name}", name=workspace.name),
+        )
+        return True
@@ -0,0 +1,103 @@
+"""Interface zoom for Ren Browser.
+
+Scales the whole browser body, including the tab bar, sidebar and page
+content, by a single factor controlled with Ctrl+Plus, Ctrl+Minus and Ctrl+0.
+"""
+
+import flet as ft
+
+from ren_browser.storage.storage import get_storage_manager
+
+ZOOM_LEVELS = (0.5, 0.67, 0.75, 0.8, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0)
+DEFAULT_ZOOM = 1.0
+APPBAR_HEIGHT = 56
+
+
+def step_zoom(factor: float, direction: int) -> float:
+    """Return the next zoom level from factor in the given direction.
+
+    Args:
+        factor: Current zoom factor.
+        direction: 1 to zoom in, -1 to zoom out.
+
+    Returns:
+        The neighbouring zoom level, clamped to the smallest and largest levels.
+
+    """
+    if direction > 0:
+        larger = [level for level in ZOOM_LEVELS if level > factor + 1e-6]
+        return larger[0] if larger else ZOOM_LEVELS[-1]
+    smaller = [level for level in ZOOM_LEVELS if level < factor - 1e-6]
+    return smaller[-1] if smaller else ZOOM_LEVELS[0]
+
+
+class UiZoom:
+    """Applies a scale factor to the browser body and remembers it."""
+
+    def __init__(self, page: ft.Page, tab_manager):
+        """Initialize the zoom controller.
+
+        Args:
+            page: Flet page instance for UI updates.
+            tab_manager: Tab manager holding the current app settings.
+
+        """
+        self.page = page
+        self.tab_manager = tab_manager
+        try:
+            factor = float(tab_manager.settings.get("ui_scale", DEFAULT_ZOOM))
+        except (TypeError, ValueError):
+            factor = DEFAULT_ZOOM
+        self.factor = min(max(factor, ZOOM_LEVELS[0]), ZOOM_LEVELS[-1])
+        self.root: ft.Container | None = None
+        self.on_change = None
+
+    def viewport_width(self) -> float | None:
+        """Return the page width in unscaled layout pixels."""
+        if not self.page.width:
+            return None
+        return self.page.width / self.factor
+
+    def apply(self) -> None:
+        """Scale the root control to the current factor.
+
+        The root is given the viewport size divided by the factor so the
+        layout reflows to fill the window once scaled.
+        """
+        if self.root is None:
+            return
+        if self.factor == DEFAULT_ZOOM or not (self.page.width and self.page.height):
+            self.root.scale = None
+            self.root.width = None
+            self.root.height = None
+            self.root.expand = True
+            return
+        height = self.page.height
+        if self.page.appbar and self.page.appbar.visible is not False:
+            height -= APPBAR_HEIGHT
+        self.root.scale = ft.Scale(scale=self.factor, alignment=ft.alignment.top_left)
+        self.root.width = self.page.width / self.factor
+        self.root.height = height / self.factor
+        self.root.expand = False
+
+    def set_factor(self, factor: float) -> None:
+        """Change the zoom factor, apply it and persist it to settings."""
+        self.factor = min(max(factor, ZOOM_LEVELS[0]), ZOOM_LEVELS[-1])
+        settings = {**self.tab_manager.settings, "ui_scale": self.factor}
+        self.tab_manager.settings = settings
+        get_storage_manager(self.page).save_app_settings(settings)
+        if self.on_change:
+            self.on_change()
+        self.apply()
+
+    def zoom_in(self) -> None:
+        """Increase the zoom to the next level."""
+        self.set_factor(step_zoom(self.factor, 1))
+
+    def zoom_out(self) -> None:
+        """Decrease the zoom to the previous level."""
+        self.set_factor(step_zoom(self.factor, -1))
+
+    def reset(self) -> None:
+        """Restore the default zoom."""
+        self.set_factor(DEFAULT_ZOOM)
@@ -0,0 +1,142 @@
+"""Update checks for Ren Browser.
+
+Asks the GitHub releases of Ren Browser, at most once a day, whether a
+newer version is out. This is the only request the browser makes outside
+Reticulum, so it is off unless turned on under Settings. A release is
+announced once; after that it is only shown again from the update page.
+"""
+
+import json
+import re
+import time
+import urllib.request
+from collections.abc import Callable
+from dataclasses import dataclass
+
+RELEASES_URL = "https://api.github.com/repos/Sudo-Ivan/Ren-Browser/releases/latest"
+CHECK_INTERVAL = 24 * 60 * 60
+# How often to look whether a check is due, so one is not missed by much
+# when the browser was asleep
+POLL_INTERVAL = 60 * 60
+TIMEOUT = 10
+
+
+@dataclass(frozen=True)
+class Release:
+    """A published release of Ren Browser."""
+
+    version: str
+    notes: str = ""
+    url: str = ""
+
+
+def parse_version(text: str) -> tuple[int, ...]:
+    """Return the numeric parts of a version such as ``v0.6.0``.
+
+    Anything after the numbers, like a pre-release tag, is ignored, and
+    text without numbers gives an empty tuple.
+    """
+    match = re.match(r"\s*v?(\d+(?:\.\d+)*)", text or "")
+    return tuple(int(part) for part in match.group(1).split(".")) if match else ()
+
+
+def is_newer(candidate: str, current: str) -> bool:
+    """Return whether version candidate is newer than version current.
+
+    An unknown current version, as when running from a checkout, is never
+    reported as outdated.
+    """
+    new, old = parse_version(candidate), parse_version(current)
+    if not new or not old:
+        return False
+    width = max(len(new), len(old))
+    return new + (0,) * (width - len(new)) > old + (0,) * (width - len(old))
+
+
+def fetch_latest_release(timeout: float = TIMEOUT) -> Release:
+    """Ask GitHub for the latest release.
+
+    Raises:
+        OSError: If GitHub cannot be reached.
+        ValueError: If the answer is not a release.
+
+    """
+    request = urllib.request.Request(
+        RELEASES_URL,
+        headers={"Accept": "application/vnd.github+json"},
+    )
+    with urllib.request.urlopen(request, timeout=timeout) as response:  # noqa: S310
+        data = json.load(response)
+    if not isinstance(data, dict) or not data.get("tag_name"):
+        raise ValueError("GitHub did not answer with a release")
+    return Release(
+        str(data["tag_name"]),
+        str(data.get("body") or ""),
+        str(data.get("html_url") or ""),
+    )
+
+
+class UpdateChecker:
+    """Decides when to check for updates and remembers what was found."""
+
+    def __init__(
+        self,
+        storage,
+        current_version: str,
+        fetch: Callable[[], Release] = fetch_latest_release,
+    ):
+        """Initialize the checker.
+
+        Args:
+            storage: StorageManager the last check is kept in.
+            current_version: Version of the running browser.
+            fetch: Returns the latest release.
+
+        """
+        self.storage = storage
+        self.current_version = current_version
+        self.fetch = fetch
+        self.state = storage.load_update_state()
+
+    @property
+    def available(self) -> Release | None:
+        """The newest release found that is newer than the running browser."""
+        found = self.state.get("latest")
+        if not isinstance(found, dict) or not is_newer(
+            found.get("version", ""),
+            self.current_version,
+        ):
+            return None
+        return Release(found["version"], found.get("notes", ""), found.get("url", ""))
+
+    def due(self, now: float | None = None) -> bool:
+        """Return whether the last check is more than a day ago."""
+        now = time.time() if now is None else now
+        return now - float(self.state.get("checked_at", 0)) >= CHECK_INTERVAL
+
+    def check(self, now: float | None = None) -> Release | None:
+        """Check for a newer release now.
+
+        Returns:
+            The release if it is newer and was not announced before, else None.
+
+        Raises:
+            OSError: If GitHub cannot be reached.
+            ValueError: If the answer is not a release.
+
+        """
+        release = self.fetch()
+        self.state["checked_at"] = time.time() if now is None else now
+        self.state["latest"] = {
+            "version": release.version,
+            "notes": release.notes,
+            "url": release.url,
+        }
+        announce = is_newer(
+            release.version,
+            self.current_version,
+        ) and release.version != self.state.get("announced")
+        if announce:
+            self.state["announced"] = release.version
+        self.storage.save_update_state(self.state)
+        return release if announce else None
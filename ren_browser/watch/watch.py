@@ -0,0 +1,205 @@
+"""Page change watcher for Ren Browser.
+
+Keeps a list of pages the user asked to watch, re-fetches them every so
+often and compares a hash of their content with the last version seen, so
+the browser can say when a page changed.
+"""
+
+import hashlib
+import threading
+import time
+from collections.abc import Callable
+from dataclasses import asdict, dataclass
+
+WATCH_INTERVAL = 900
+
+
+def content_hash(content: str) -> str:
+    """Return the hash pages are compared by."""
+    return hashlib.sha256(content.encode("utf-8")).hexdigest()
+
+
+@dataclass
+class WatchedPage:
+    """A page being watched for changes.
+
+    Contains the address, title, hash of the last content seen, when it was
+    last checked and changed, and whether the change has been looked at.
+    """
+
+    address: str
+    title: str
+    content_hash: str | None = None
+    checked_at: float = 0.0
+    changed_at: float | None = None
+    unseen: bool = False
+    error: str | None = None
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "WatchedPage | None":
+        """Build a watched page from stored data, or None if it is malformed."""
+        if not isinstance(data, dict) or not data.get("address"):
+            return None
+        try:
+            checked_at = float(data.get("checked_at") or 0)
+            changed_at = data.get("changed_at")
+            changed_at = float(changed_at) if changed_at is not None else None
+        except (TypeError, ValueError):
+            checked_at, changed_at = 0.0, None
+        return cls(
+            address=data["address"],
+            title=data.get("title") or data["address"],
+            content_hash=data.get("content_hash"),
+            checked_at=checked_at,
+            changed_at=changed_at,
+            unseen=bool(data.get("unseen", False)),
+        )
+
+
+class PageWatcher:
+    """Watches pages for changes and persists the list through storage."""
+
+    def __init__(
+        self,
+        storage,
+        fetch: Callable[[str], str],
+        interval: float = WATCH_INTERVAL,
+    ):
+        """Load the watched pages.
+
+        Args:
+            storage: StorageManager used to load and save the watched pages.
+            fetch: Called with an address to download its current content.
+            interval: Seconds between background checks.
+
+        """
+        self.storage = storage
+        self.fetch = fetch
+        self.interval = interval
+        self._pages: list[WatchedPage] = []
+        self._lock = threading.Lock()
+        try:
+            stored = storage.load_watched_pages()
+        except Exception:  # noqa: BLE001
+            stored = []
+        for data in stored if isinstance(stored, list) else []:
+            watched = WatchedPage.from_dict(data)
+            if watched is not None and self.get(watched.address) is None:
+                self._pages.append(watched)
+
+    def get(self, address: str) -> WatchedPage | None:
+        """Return the watched page for an address, if any."""
+        return next((p for p in self._pages if p.address == address), None)
+
+    def pages(self) -> list[WatchedPage]:
+        """Return the watched pages, changed ones first."""
+        return sorted(
+            self._pages,
+            key=lambda p: (not p.unseen, -(p.changed_at or p.checked_at)),
+        )
+
+    def unseen_count(self) -> int:
+        """Return how many watched pages changed since they were last seen."""
+        return sum(1 for p in self._pages if p.unseen)
+
+    def watch(self, address: str, title: str, content: str | None = None) -> WatchedPage:
+        """Start watching a page.
+
+        Args:
+            address: Normalized page address.
+            title: Title shown in the watch list.
+            content: Current content, used as the version to compare against.
+
+        Returns:
+            WatchedPage: The watched page.
+
+        """
+        watched = self.get(address)
+        if watched is None:
+            watched = WatchedPage(address, title or address)
+            self._pages.append(watched)
+        if content is not None:
+            watched.content_hash = content_hash(content)
+            watched.checked_at = time.time()
+        self._save()
+        return watched
+
+    def unwatch(self, address: str) -> None:
+        """Stop watching a page."""
+        watched = self.get(address)
+        if watched is not None:
+            self._pages.remove(watched)
+            self._save()
+
+    def seen(self, address: str, content: str | None = None) -> None:
+        """Record that the user has looked at a watched page.
+
+        Args:
+            address: Normalized page address.
+            content: Content the user saw, which becomes the version compared
+                against.
+
+        """
+        watched = self.get(address)
+        if watched is None:
+            return
+        watched.unseen = False
+        if content is not None:
+            watched.content_hash = content_hash(content)
+        self._save()
+
+    def check(self, address: str, now: float | None = None) -> bool:
+        """Fetch a watched page and compare it with the last version seen.
+
+        Args:
+            address: Normalized page address.
+            now: Current time, for tests.
+
+        Returns:
+            bool: Whether the page changed.
+
+        """
+        watched = self.get(address)
+        if watched is None:
+            return False
+        try:
+            content = self.fetch(address)
+        except Exception as exc:  # noqa: BLE001
+            watched.error = str(exc)
+            return False
+        now = now if now is not None else time.time()
+        new_hash = content_hash(content)
+        changed = watched.content_hash is not None and new_hash != watched.content_hash
+        watched.error = None
+        watched.checked_at = now
+        watched.content_hash = new_hash
+        if changed:
+            watched.changed_at = now
+            watched.unseen = True
+        self._save()
+        return changed
+
+    def check_all(self, now: float | None = None) -> list[WatchedPage]:
+        """Check every watched page, one at a time.
+
+        Returns:
+            The pages that changed.
+
+        """
+        with self._lock:
+            return [
+                watched
+                for watched in list(self._pages)
+                if self.check(watched.address, now)
+            ]
+
+    def _save(self) -> None:
+        try:
+            self.storage.save_watched_pages(
+                [
+                    {k: v for k, v in asdict(p).items() if k != "error"}
+                    for p in self._pages
+                ],
+            )
+        except Exception:  # noqa: BLE001
+            pass
@@ -3,6 +3,8 @@ from unittest.mock import MagicMock, Mock
 import flet as ft
 import pytest
 
+from tests.support.mock_network import MockNetwork
+
 
 @pytest.fixture
 def mock_page():
@@ -17,6 +19,8 @@ def mock_page():
     page.drawer = Mock()
     page.window = Mock()
     page.width = 1024
+    page.route = "/"
+    page.views = [Mock()]
     page.snack_bar = None
     page.on_resized = None
     page.on_keyboard_event = None
@@ -81,6 +85,9 @@ def mock_storage_manager():
         "page_bgcolor": "#000000",
     }
     mock_storage.save_app_settings.return_value = True
+    mock_storage.profile = "default"
+    mock_storage.list_profiles.return_value = ["default"]
+    mock_storage.startup_profile.return_value = "default"
     mock_storage.get_storage_info.return_value = {
         "storage_dir": "/mock/storage",
         "config_path": "/mock/storage/config.txt",
@@ -90,3 +97,10 @@ def mock_storage_manager():
         "has_client_storage": True,
     }
     return mock_storage
+
+
+@pytest.fixture
+def mock_network():
+    """Stand in for the Reticulum network with in-process mock nodes."""
+    with MockNetwork() as network:
+        yield network
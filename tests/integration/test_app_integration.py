@@ -18,6 +18,8 @@ class TestAppIntegration:
         mock_page.controls = Mock()
         mock_page.controls.clear = Mock()
         mock_page.width = 1024
+        mock_page.route = "/"
+        mock_page.views = [Mock()]
         mock_page.window = Mock()
         mock_page.window.maximized = False
         mock_page.appbar = Mock()
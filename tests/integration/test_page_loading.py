@@ -0,0 +1,195 @@
+from unittest.mock import patch
+
+import pytest
+
+from ren_browser import rns
+from ren_browser.announces.announces import AnnounceService
+from ren_browser.pages.page_request import PageFetcher, PageFetchError, PageRequest
+from ren_browser.tabs.tabs import TabsManager
+from tests.support.mock_network import (
+    FAIL_LINK,
+    FAIL_REQUEST,
+    FAIL_TIMEOUT,
+    MockInterface,
+    MockNetwork,
+    MockNode,
+    MockPage,
+)
+
+NODE_HASH = "ab" * 16
+INDEX = "/page/index.mu"
+
+
+def _node(**kwargs):
+    kwargs.setdefault("pages", {INDEX: b">Welcome"})
+    return MockNode(NODE_HASH, **kwargs)
+
+
+def _fetch(path=INDEX, on_progress=None):
+    return PageFetcher.fetch_page(PageRequest(NODE_HASH, path), on_progress)
+
+
+class TestFetchPage:
+    """Test cases for fetching pages from nodes on the mock network."""
+
+    def test_fetches_page(self, mock_network):
+        """Test that a page is fetched over a link and decoded."""
+        node = mock_network.add(_node())
+
+        assert _fetch() == ">Welcome"
+        assert node.requests == [(INDEX, None)]
+        assert mock_network.path_requests == []
+
+    def test_reports_phases_in_order(self, mock_network):
+        """Test that the load phases are reported as the fetch goes on."""
+        mock_network.add(
+            _node(
+                path_known=False,
+                path_delay=0.05,
+                pages={INDEX: MockPage(b"page", latency=0.1, progress=(0.5,))},
+            ),
+        )
+        phases = []
+
+        _fetch(on_progress=lambda phase, fraction: phases.append((phase, fraction)))
+
+        assert phases == [
+            ("path", None),
+            ("link", None),
+            ("transfer", 0.0),
+            ("transfer", 0.5),
+        ]
+
+    def test_no_path(self):
+        """Test that a node that never answers path requests is no_path."""
+        with MockNetwork([_node(path_known=False, path_delay=None)], path_timeout=0.2):
+            with pytest.raises(PageFetchError) as error:
+                _fetch()
+
+        assert error.value.kind == "no_path"
+
+    def test_unknown_identity(self, mock_network):
+        """Test that a node whose identity cannot be recalled is reported."""
+        mock_network.add(_node(identity_known=False))
+
+        with pytest.raises(PageFetchError) as error:
+            _fetch()
+
+        assert error.value.kind == "unknown_identity"
+
+    @pytest.mark.parametrize(
+        "node",
+        [
+            _node(link_fails=True),
+            _node(pages={INDEX: MockPage(fail=FAIL_LINK)}),
+            _node(pages={INDEX: MockPage(fail=FAIL_REQUEST, latency=0.05)}),
+            _node(pages={}),
+        ],
+    )
+    def test_link_failed(self, mock_network, node):
+        """Test that closed links and failed requests are link_failed."""
+        mock_network.add(node)
+
+        with pytest.raises(PageFetchError) as error:
+            _fetch()
+
+        assert error.value.kind == "link_failed"
+
+    def test_timeout(self):
+        """Test that a node that never answers the request times out."""
+        node = _node(pages={INDEX: MockPage(fail=FAIL_TIMEOUT)})
+        with MockNetwork([node], page_timeout=0.2):
+            with pytest.raises(PageFetchError) as error:
+                _fetch()
+
+        assert error.value.kind == "timeout"
+        assert node.requests == [(INDEX, None)]
+
+    def test_slow_page_within_timeout(self, mock_network):
+        """Test that scripted latency under the timeout still loads the page."""
+        mock_network.add(_node(link_delay=0.1, pages={INDEX: MockPage(b"slow", 0.2)}))
+
+        assert _fetch() == "slow"
+
+    def test_fetch_file_with_metadata(self, mock_network):
+        """Test that a file is returned with the name the node sent."""
+        mock_network.add(
+            _node(pages={"/file/a.bin": [b"\x00\x01", {b"name": b"a.bin"}]}),
+        )
+
+        data, name = PageFetcher.fetch_file(PageRequest(NODE_HASH, "/file/a.bin"))
+
+        assert data == b"\x00\x01"
+        assert name == "a.bin"
+
+
+class TestTabsOnMockNetwork:
+    """Test cases for loading pages into tabs from the mock network."""
+
+    @pytest.fixture
+    def tabs_manager(self, mock_page, mock_network):
+        """Create a tab manager whose loads run at once on the test thread."""
+        mock_page.run_thread = lambda action: action()
+        with patch("ren_browser.app.RENDERER", "plaintext"):
+            manager = TabsManager(mock_page)
+        manager.page_cache.clear()
+        return manager
+
+    def load(self, tabs_manager, url):
+        tab = tabs_manager.manager.tabs[tabs_manager.manager.index]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, tabs_manager.manager.index)
+        return tab
+
+    def test_second_load_served_from_cache(self, tabs_manager, mock_network):
+        """Test that loading a page again uses the cached copy."""
+        node = mock_network.add(_node())
+        url = f"{NODE_HASH}:{INDEX}"
+
+        self.load(tabs_manager, url)
+        self.load(tabs_manager, url)
+
+        assert node.requests == [(INDEX, None)]
+        assert tabs_manager.page_cache.get(url).content == ">Welcome"
+
+    def test_reload_skips_cache(self, tabs_manager, mock_network):
+        """Test that a reload fetches the page from the node again."""
+        node = mock_network.add(_node())
+        self.load(tabs_manager, f"{NODE_HASH}:{INDEX}")
+
+        tabs_manager.reload(tabs_manager.manager.index)
+
+        assert len(node.requests) == 2
+
+    def test_failed_load_is_not_cached(self, tabs_manager, mock_network):
+        """Test that a page that fails to load leaves nothing in the cache."""
+        mock_network.add(_node(pages={INDEX: MockPage(fail=FAIL_REQUEST)}))
+        url = f"{NODE_HASH}:{INDEX}"
+
+        tab = self.load(tabs_manager, url)
+
+        assert tabs_manager.page_cache.get(url) is None
+        assert tab["address"] == url
+
+
+class TestNetworkStatus:
+    """Test cases for announces and interfaces of the mock network."""
+
+    def test_announces_reach_service(self, mock_network):
+        """Test that announced nodes are collected by the announce service."""
+        service = AnnounceService(update_callback=None)
+
+        mock_network.announce(_node(name="Mock Node"))
+
+        assert [ann.display_name for ann in service.announces] == ["Mock Node"]
+        assert service.announces[0].destination_hash == NODE_HASH
+
+    def test_interface_statuses(self):
+        """Test that the interfaces of the transport are reported."""
+        interfaces = [MockInterface("RNode", bitrate=1200), MockInterface("TCP", False)]
+        with MockNetwork(interfaces=interfaces):
+            statuses = rns.get_interface_statuses()
+
+        assert [status["name"] for status in statuses] == ["RNode", "TCP"]
+        assert [status["online"] for status in statuses] == [True, False]
+        assert statuses[0]["bitrate"] == 1200
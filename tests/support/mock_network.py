@@ -0,0 +1,310 @@
+"""In-process stand-in for the Reticulum network.
+
+MockNetwork replaces the RNS module seen by page fetching, announce
+handling and interface status with fakes driven by MockNode and MockPage,
+so tests can load pages, see them fail and time out without a Reticulum
+instance. Latency is real but scripted, and the timeouts of page fetching
+are shortened while the network is in use.
+"""
+
+import threading
+import time
+from dataclasses import dataclass, field
+from types import SimpleNamespace
+from unittest.mock import patch
+
+# Ways a page can fail: the link closes once it is asked for, the request
+# fails, or the node never answers.
+FAIL_LINK = "link"
+FAIL_REQUEST = "request"
+FAIL_TIMEOUT = "timeout"
+
+
+@dataclass
+class MockPage:
+    """A page served by a MockNode.
+
+    Attributes:
+        content: Response sent for the page, bytes, str or [data, metadata].
+        latency: Seconds between the request and the response.
+        fail: One of FAIL_LINK, FAIL_REQUEST or FAIL_TIMEOUT, None to succeed.
+        progress: Transfer progress reported before the response, in order.
+
+    """
+
+    content: object = b""
+    latency: float = 0.0
+    fail: str | None = None
+    progress: tuple[float, ...] = ()
+
+
+@dataclass
+class MockNode:
+    """A node on the mock network.
+
+    Attributes:
+        hash_hex: Destination hash of the node.
+        pages: Pages by path, str or bytes values served at once.
+        name: Display name announced by the node.
+        path_known: Whether a path to the node is known before one is asked for.
+        path_delay: Seconds a path request takes to be answered, None if never.
+        identity_known: Whether the node's identity can be recalled.
+        link_delay: Seconds a link takes to be established.
+        link_fails: Whether links to the node close instead of being
+            established.
+        requests: Paths and field data of the requests received, in order.
+
+    """
+
+    hash_hex: str
+    pages: dict[str, object] = field(default_factory=dict)
+    name: str = "Mock Node"
+    path_known: bool = True
+    path_delay: float | None = 0.0
+    identity_known: bool = True
+    link_delay: float = 0.0
+    link_fails: bool = False
+    requests: list[tuple[str, object]] = field(default_factory=list)
+
+    @property
+    def hash(self) -> bytes:
+        """Destination hash of the node as bytes."""
+        return bytes.fromhex(self.hash_hex)
+
+    def page(self, path: str) -> MockPage:
+        """Return the page at path, a failing page if there is none."""
+        page = self.pages.get(path)
+        if page is None:
+            return MockPage(fail=FAIL_REQUEST)
+        if isinstance(page, MockPage):
+            return page
+        return MockPage(content=page)
+
+
+@dataclass
+class MockInterface:
+    """A transport interface as the status page reads it."""
+
+    name: str
+    online: bool = True
+    bitrate: int | None = None
+
+
+class _Receipt(SimpleNamespace):
+    """Request receipt handed to the response and progress callbacks."""
+
+
+class _Destination:
+    OUT = "out"
+    SINGLE = "single"
+
+    def __init__(self, identity, direction, kind, *aspects):
+        self.node = identity.node
+        self.aspects = aspects
+
+
+class _Link:
+    def __init__(self, network: "MockNetwork", destination):
+        self.network = network
+        self.node: MockNode = destination.node
+        self.established_callback = None
+        self.closed_callback = None
+        self.closed = threading.Event()
+        self._started = False
+        network.links.append(self)
+
+    def set_link_established_callback(self, callback):
+        self.established_callback = callback
+        self._start()
+
+    def set_link_closed_callback(self, callback):
+        self.closed_callback = callback
+        self._start()
+
+    def _start(self):
+        # Establish only once both callbacks are set, so none is missed
+        if self._started or not (self.established_callback and self.closed_callback):
+            return
+        self._started = True
+        self.network.later(self.node.link_delay, self._establish)
+
+    def _establish(self):
+        if self.closed.is_set():
+            return
+        if self.node.link_fails:
+            self.teardown()
+            return
+        if self.established_callback:
+            self.established_callback(self)
+
+    def request(
+        self,
+        path,
+        data=None,
+        response_callback=None,
+        failed_callback=None,
+        progress_callback=None,
+    ):
+        self.node.requests.append((path, data))
+        page = self.node.page(path)
+        if page.fail == FAIL_LINK:
+            self.network.later(page.latency, self.teardown)
+            return
+        if page.fail == FAIL_TIMEOUT:
+            return
+        receipt = _Receipt(response=None, progress=0.0)
+
+        def answer():
+            steps = page.progress or ()
+            for progress in steps:
+                if self.closed.is_set():
+                    return
+                receipt.progress = progress
+                if progress_callback:
+                    progress_callback(receipt)
+                time.sleep(page.latency / (len(steps) + 1))
+            if self.closed.is_set():
+                return
+            if page.fail == FAIL_REQUEST:
+                if failed_callback:
+                    failed_callback(receipt)
+                return
+            receipt.progress = 1.0
+            receipt.response = page.content
+            if response_callback:
+                response_callback(receipt)
+
+        self.network.later(0 if page.progress else page.latency, answer)
+
+    def teardown(self):
+        if self.closed.is_set():
+            return
+        self.closed.set()
+        if self.closed_callback:
+            self.closed_callback(self)
+
+
+class MockNetwork:
+    """Fake Reticulum network serving MockNode pages in-process.
+
+    Use as a context manager: while it is entered, page fetching,
+    announce handling and interface status talk to the fake. Page fetching
+    waits path_timeout and page_timeout seconds instead of its defaults.
+    """
+
+    def __init__(
+        self,
+        nodes: list[MockNode] | None = None,
+        interfaces: list[MockInterface] | None = None,
+        path_timeout: float = 1.0,
+        page_timeout: float = 1.0,
+    ):
+        """Prepare the network.
+
+        Args:
+            nodes: Nodes reachable on the network.
+            interfaces: Interfaces reported by the transport.
+            path_timeout: Seconds page fetching waits for a path.
+            page_timeout: Seconds page fetching waits for a page.
+
+        """
+        self.nodes = {node.hash: node for node in nodes or []}
+        self.path_timeout = path_timeout
+        self.page_timeout = page_timeout
+        self.path_requests: list[bytes] = []
+        self.links: list[_Link] = []
+        self.handlers: list = []
+        self._path_answers: dict[bytes, float] = {}
+        self._threads: list[threading.Thread] = []
+        self.rns = self._build_rns(interfaces or [])
+        self._patches = []
+
+    def add(self, node: MockNode) -> MockNode:
+        """Put another node on the network."""
+        self.nodes[node.hash] = node
+        return node
+
+    def announce(
+        self,
+        node: MockNode,
+        app_data: bytes | None = None,
+        aspect: str = "nomadnetwork.node",
+    ) -> None:
+        """Deliver an announce of node to the handlers of its aspect."""
+        app_data = node.name.encode("utf-8") if app_data is None else app_data
+        identity = SimpleNamespace(hash=node.hash)
+        for handler in list(self.handlers):
+            if getattr(handler, "aspect_filter", None) == aspect:
+                handler.received_announce(node.hash, identity, app_data)
+
+    def later(self, delay: float, action) -> None:
+        """Run action on a thread of the network after delay seconds."""
+
+        def run():
+            if delay:
+                time.sleep(delay)
+            action()
+
+        thread = threading.Thread(target=run, daemon=True)
+        self._threads.append(thread)
+        thread.start()
+
+    def _has_path(self, destination_hash: bytes) -> bool:
+        node = self.nodes.get(destination_hash)
+        if node is None:
+            return False
+        if node.path_known:
+            return True
+        answered = self._path_answers.get(destination_hash)
+        return answered is not None and time.monotonic() >= answered
+
+    def _request_path(self, destination_hash: bytes) -> None:
+        self.path_requests.append(destination_hash)
+        node = self.nodes.get(destination_hash)
+        if node is not None and node.path_delay is not None:
+            self._path_answers.setdefault(
+                destination_hash,
+                time.monotonic() + node.path_delay,
+            )
+
+    def _recall(self, destination_hash: bytes):
+        node = self.nodes.get(destination_hash)
+        if node is None or not node.identity_known:
+            return None
+        return SimpleNamespace(hash=node.hash, node=node)
+
+    def _build_rns(self, interfaces: list[MockInterface]) -> SimpleNamespace:
+        network = self
+        return SimpleNamespace(
+            log=lambda *args, **kwargs: None,
+            Transport=SimpleNamespace(
+                has_path=self._has_path,
+                request_path=self._request_path,
+                register_announce_handler=self.handlers.append,
+                interfaces=interfaces,
+            ),
+            Identity=SimpleNamespace(recall=self._recall),
+            Destination=_Destination,
+            Link=lambda destination: _Link(network, destination),
+        )
+
+    def __enter__(self) -> "MockNetwork":
+        self._patches = [
+            patch("ren_browser.pages.page_request.RNS", self.rns),
+            patch("ren_browser.pages.page_request.PATH_TIMEOUT", self.path_timeout),
+            patch("ren_browser.pages.page_request.PAGE_TIMEOUT", self.page_timeout),
+            patch("ren_browser.announces.announces.RNS", self.rns),
+            patch("ren_browser.rns.RNS", self.rns),
+        ]
+        for active in self._patches:
+            active.start()
+        return self
+
+    def __exit__(self, *exc_info) -> None:
+        for link in self.links:
+            link.teardown()
+        for active in reversed(self._patches):
+            active.stop()
+        self._patches = []
+        for thread in self._threads:
+            thread.join(timeout=1.0)
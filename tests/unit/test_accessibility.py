@@ -0,0 +1,23 @@
+from ren_browser.accessibility.accessibility import link_label, node_label, tab_label
+
+
+class TestAccessibilityLabels:
+    """Test cases for the labels read out by screen readers."""
+
+    def test_tab_label(self):
+        """Test that a tab says where it is, what it shows and its state."""
+        assert tab_label("Home", 0, 1) == "Tab 1 of 1, Home"
+        assert (
+            tab_label("News", 1, 3, active=True, private=True, loading=True)
+            == "Tab 2 of 3, News, private, loading, selected"
+        )
+
+    def test_node_label(self):
+        """Test that nodes fall back to their hash without a name."""
+        assert node_label("Hilltop", "abc") == "Node Hilltop, opens its index page"
+        assert node_label(None, "abc") == "Node abc, opens its index page"
+
+    def test_link_label(self):
+        """Test that links without their own text read out the target."""
+        assert link_label("About", ":/page/about.mu") == "Link, About"
+        assert link_label("", "abc:/page/a.mu") == "Link to abc:/page/a.mu"
@@ -0,0 +1,181 @@
+from unittest.mock import Mock, patch
+
+from ren_browser.announces.activity import HOUR, AnnounceActivity, hour_of_day
+from ren_browser.announces.announces import Announce
+from ren_browser.ui.activity import build_activity_page, peak_hours
+
+NOW = 1_700_000_000 // HOUR * HOUR
+
+
+class _Storage:
+    """Storage keeping the saved announce counts in memory."""
+
+    def __init__(self):
+        self.saved = {}
+        self.saves = 0
+
+    def load_announce_activity(self):
+        return self.saved
+
+    def save_announce_activity(self, activity):
+        self.saved = activity
+        self.saves += 1
+
+
+def _announce(destination_hash, timestamp, name=None, aspect="nomadnetwork.node"):
+    return Announce(destination_hash, name, int(timestamp), aspect=aspect)
+
+
+class TestAnnounceActivity:
+    """Test cases for counting the announces heard from nodes."""
+
+    def test_counts_persist(self):
+        """Test that counts and names are loaded again."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW, "Solar"))
+        activity.record(_announce("aa", NOW + 60))
+        activity.save()
+
+        loaded = AnnounceActivity(activity.storage)
+
+        assert loaded.timeline(NOW, NOW, HOUR, "aa") == [2]
+        assert loaded.name("aa") == "Solar"
+
+    def test_other_aspects_are_not_counted(self):
+        """Test that only Nomad Network node announces are counted."""
+        activity = AnnounceActivity(_Storage())
+
+        activity.record(_announce("bb", NOW, aspect="lxmf.delivery"))
+
+        assert len(activity) == 0
+
+    def test_saves_at_most_once_a_minute(self):
+        """Test that announces arriving together are saved once."""
+        storage = _Storage()
+        activity = AnnounceActivity(storage)
+        with patch("time.monotonic", side_effect=[100.0, 100.0, 130.0, 161.0, 161.0]):
+            activity.record(_announce("aa", NOW))
+            activity.record(_announce("aa", NOW))
+            activity.record(_announce("aa", NOW))
+
+        assert storage.saves == 2
+
+    def test_timeline_buckets(self):
+        """Test that announces are counted in the span they were heard in."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW))
+        activity.record(_announce("bb", NOW + 5 * HOUR))
+        activity.record(_announce("bb", NOW + 7 * HOUR))
+
+        assert activity.timeline(NOW, NOW + 11 * HOUR, 6 * HOUR) == [2, 1]
+        assert activity.timeline(NOW, NOW + 11 * HOUR, 6 * HOUR, "bb") == [1, 1]
+
+    def test_by_hour_of_day(self):
+        """Test that announces on different days add up by hour of the day."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW - 24 * HOUR))
+        activity.record(_announce("aa", NOW))
+
+        counts = activity.by_hour_of_day(NOW - 48 * HOUR)
+
+        assert counts[hour_of_day(NOW // HOUR)] == 2
+        assert sum(counts) == 2
+
+    def test_nodes_most_active_first(self):
+        """Test that nodes are listed by how often they were heard."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW - 3 * 24 * HOUR))
+        activity.record(_announce("bb", NOW))
+        activity.record(_announce("bb", NOW + HOUR))
+        activity.record(_announce("cc", NOW))
+
+        nodes = activity.nodes(NOW - HOUR)
+
+        assert [n.destination_hash for n in nodes] == ["bb", "cc"]
+        assert nodes[0].count == 2
+        assert nodes[0].last_heard == NOW + HOUR
+
+    def test_old_counts_are_dropped(self):
+        """Test that hours older than a month are forgotten."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW - 31 * 24 * HOUR, "Old"))
+
+        activity.record(_announce("bb", NOW))
+
+        assert [n.destination_hash for n in activity.nodes(0)] == ["bb"]
+        assert activity.name("aa") is None
+
+    def test_malformed_counts_are_skipped(self):
+        """Test that stored counts that are not numbers are left out."""
+        storage = _Storage()
+        storage.saved = {
+            "nodes": {
+                "aa": {"name": "A", "hours": {str(NOW // HOUR): 3, "x": 1}},
+                "bb": "nope",
+            },
+        }
+
+        activity = AnnounceActivity(storage)
+
+        assert len(activity) == 1
+        assert activity.timeline(NOW, NOW, HOUR) == [3]
+
+
+class TestActivityPage:
+    """Test cases for the about:activity page."""
+
+    def _page(self, activity, on_change=None):
+        return build_activity_page(
+            activity,
+            on_open=Mock(),
+            on_change=on_change or Mock(),
+            now=lambda: NOW + HOUR,
+        )
+
+    def test_without_announces_shows_help(self):
+        """Test that an empty history explains how it fills up."""
+        page = self._page(AnnounceActivity(_Storage()))
+
+        body = page.controls[2]
+        assert len(body.controls) == 1
+
+    def test_charts_and_lists_nodes(self):
+        """Test that the charts are drawn and every node is listed."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW, "Solar"))
+        activity.record(_announce("bb", NOW))
+
+        page = self._page(activity)
+
+        body = page.controls[2]
+        assert body.controls[0].value == "2 announces from 2 nodes"
+        nodes = body.controls[-2:]
+        assert [n.content.controls[1].controls[0].value for n in nodes] == [
+            "Solar",
+            "bb",
+        ]
+
+    def test_selecting_a_node_charts_it(self):
+        """Test that clicking a node shows only its announces."""
+        activity = AnnounceActivity(_Storage())
+        activity.record(_announce("aa", NOW, "Solar"))
+        activity.record(_announce("aa", NOW))
+        activity.record(_announce("bb", NOW))
+        on_change = Mock()
+        page = self._page(activity, on_change)
+
+        page.controls[2].controls[-2].on_click(None)
+
+        node_dropdown = page.controls[1].controls[1]
+        assert node_dropdown.value == "aa"
+        assert page.controls[2].controls[0].value == "2 announces from Solar"
+        on_change.assert_called_once()
+
+    def test_peak_hours(self):
+        """Test that every hour with the most announces is returned."""
+        counts = [0] * 24
+        counts[9] = counts[13] = 4
+        counts[10] = 1
+
+        assert peak_hours(counts) == [9, 13]
+        assert peak_hours([0] * 24) == []
@@ -0,0 +1,275 @@
+from pathlib import Path
+
+import pytest
+
+from ren_browser.pages.address import (
+    Address,
+    AddressError,
+    clipboard_address,
+    extract_address,
+    file_address,
+    find_address,
+    internal_page_name,
+    is_destination_hash,
+    local_file_path,
+    local_link,
+    lxmf_address,
+    normalize_address,
+    parse_address,
+    resolve_link,
+)
+from ren_browser.pages.aliases import aliases, expand_alias, keyword_error
+
+HASH = "0123456789abcdef0123456789abcdef"
+
+
+class TestParseAddress:
+    """Test cases for address parsing and normalization."""
+
+    def test_bare_hash(self):
+        """Test that a bare hash opens the node index page."""
+        assert parse_address(HASH) == Address(HASH, "/page/index.mu")
+
+    def test_hash_with_colon_path(self):
+        """Test the canonical hash:/path form."""
+        assert normalize_address(f"{HASH}:/page/about.mu") == f"{HASH}:/page/about.mu"
+
+    def test_hash_with_slash_path(self):
+        """Test that hash/page/... is accepted."""
+        assert normalize_address(f"{HASH}/page/about.mu") == f"{HASH}:/page/about.mu"
+
+    def test_missing_leading_slash(self):
+        """Test that a relative path after the colon is made absolute."""
+        assert normalize_address(f"{HASH}:page/about.mu") == f"{HASH}:/page/about.mu"
+
+    def test_url_round_trips(self):
+        """Test that the ren:// link form parses back to the same address."""
+        address = Address(HASH, "/page/about.mu")
+
+        assert address.url() == f"ren://{HASH}/page/about.mu"
+        assert parse_address(address.url()) == address
+
+    def test_ren_url(self):
+        """Test that ren://hash/path links from other applications are accepted."""
+        assert normalize_address(f"ren://{HASH}/page/about.mu") == (
+            f"{HASH}:/page/about.mu"
+        )
+        assert normalize_address(f"REN://{HASH}") == f"{HASH}:/page/index.mu"
+
+    def test_uppercase_hash_and_whitespace(self):
+        """Test that hashes are lowercased and whitespace trimmed."""
+        assert normalize_address(f"  {HASH.upper()}  ") == f"{HASH}:/page/index.mu"
+
+    def test_hash_in_angle_brackets(self):
+        """Test that hashes pasted as Reticulum prints them are accepted."""
+        assert normalize_address(f"<{HASH}>") == f"{HASH}:/page/index.mu"
+        assert normalize_address(f"< {HASH} >:/page/about.mu") == (
+            f"{HASH}:/page/about.mu"
+        )
+
+    def test_colon_separated_hash(self):
+        """Test that a hash written as colon separated bytes is accepted."""
+        spaced = ":".join(HASH[i : i + 2] for i in range(0, len(HASH), 2))
+
+        assert normalize_address(f"<{spaced}>") == f"{HASH}:/page/index.mu"
+        assert normalize_address(f"{spaced}:/page/about.mu") == (
+            f"{HASH}:/page/about.mu"
+        )
+
+    def test_longer_hex_is_not_cut_to_a_hash(self):
+        """Test that a hash with extra digits is still reported as too long."""
+        with pytest.raises(AddressError, match="has 33"):
+            parse_address(f"{HASH}0")
+
+    def test_node_name(self):
+        """Test that node names resolve through the lookup."""
+        names = {"weather station": HASH}
+        address = parse_address(
+            "Weather Station",
+            resolve_name=lambda name: names.get(name.lower()),
+        )
+        assert address == Address(HASH, "/page/index.mu")
+
+    def test_node_name_with_path(self):
+        """Test that node names can be followed by a path."""
+        address = parse_address(
+            "wx/page/radar.mu",
+            resolve_name=lambda name: HASH if name == "wx" else None,
+        )
+        assert address == Address(HASH, "/page/radar.mu")
+
+    def test_empty_input(self):
+        """Test that empty input produces a friendly error."""
+        with pytest.raises(AddressError, match="Enter a destination hash"):
+            parse_address("   ")
+
+    def test_short_hash(self):
+        """Test that truncated hashes report their length."""
+        with pytest.raises(AddressError, match="has 8"):
+            parse_address("01234567")
+
+    def test_unknown_name(self):
+        """Test that unknown names are reported."""
+        with pytest.raises(AddressError, match="not a destination hash"):
+            parse_address("nowhere", resolve_name=lambda name: None)
+
+    def test_missing_hash(self):
+        """Test that a path without a node is rejected."""
+        with pytest.raises(AddressError, match="missing a destination hash"):
+            parse_address(":/page/index.mu")
+
+    def test_is_destination_hash(self):
+        """Test hash detection."""
+        assert is_destination_hash(HASH)
+        assert not is_destination_hash(HASH[:-1])
+        assert not is_destination_hash("z" * 32)
+
+
+class TestResolveLink:
+    """Test cases for resolving links found on pages."""
+
+    def test_relative_link(self):
+        """Test that :/path links are resolved against the current node."""
+        assert resolve_link(":/page/next.mu", HASH) == f"{HASH}:/page/next.mu"
+
+    def test_absolute_link_unchanged(self):
+        """Test that absolute links are returned untouched."""
+        assert resolve_link(f"{HASH}:/page/a.mu", "ffff") == f"{HASH}:/page/a.mu"
+
+    def test_relative_link_without_node(self):
+        """Test that relative links without a current node are kept."""
+        assert resolve_link(":/page/next.mu") == ":/page/next.mu"
+
+
+class TestExtractAddress:
+    """Test cases for picking addresses out of pasted text."""
+
+    def test_extracts_address_from_sentence(self):
+        """Test that an address shared in a chat message is found."""
+        text = f"have a look ({HASH}:/page/news.mu)."
+
+        assert extract_address(text) == f"{HASH}:/page/news.mu"
+
+    def test_plain_text_is_trimmed(self):
+        """Test that text without a hash is passed through trimmed."""
+        assert extract_address("  My Node \n") == "My Node"
+        assert find_address("  My Node \n") is None
+
+    def test_longer_hex_runs_are_not_split(self):
+        """Test that hex runs longer than a hash are not treated as one."""
+        text = f"{HASH}00"
+
+        assert extract_address(text) == text
+
+
+class TestClipboardAddress:
+    """Test cases for recognizing addresses copied to the clipboard."""
+
+    @pytest.mark.parametrize(
+        ("text", "address"),
+        [
+            (HASH.upper(), f"{HASH}:/page/index.mu"),
+            (f"ren://{HASH}/page/news.mu", f"{HASH}:/page/news.mu"),
+            (f"my node: <{HASH}>\n", f"{HASH}:/page/index.mu"),
+        ],
+    )
+    def test_addresses(self, text, address):
+        """Test that hashes and links, alone or in a short note, are found."""
+        assert clipboard_address(text) == address
+
+    @pytest.mark.parametrize(
+        "text",
+        ["", "My Node", f"lxmf@{HASH}", f"{'word ' * 80}{HASH}", f"{HASH}00"],
+    )
+    def test_other_text(self, text):
+        """Test that names, LXMF addresses and long messages are not offered."""
+        assert clipboard_address(text) is None
+
+
+class TestLxmfAddress:
+    """Test cases for recognizing LXMF links."""
+
+    @pytest.mark.parametrize(
+        "link",
+        [f"lxmf@{HASH}", f"LXMF:{HASH.upper()}", f"lxmf://<{HASH}>", f" lxmf@{HASH} "],
+    )
+    def test_lxmf_links(self, link):
+        """Test that the forms pages use for LXMF addresses are recognized."""
+        assert lxmf_address(link) == HASH
+
+    @pytest.mark.parametrize("link", [HASH, f"{HASH}:/page/lxmf.mu", "lxmf@abc"])
+    def test_other_links(self, link):
+        """Test that page addresses and short hashes are not LXMF links."""
+        assert lxmf_address(link) is None
+
+
+class TestInternalPageName:
+    """Test cases for recognizing internal about: pages."""
+
+    def test_internal_page_name(self):
+        """Test extracting the name of an about: page."""
+        assert internal_page_name("about:history") == "history"
+        assert internal_page_name("  About:History ") == "history"
+        assert internal_page_name(f"{HASH}:/page/index.mu") is None
+
+
+class TestLocalFiles:
+    """Test cases for addresses of pages on disk."""
+
+    def test_local_file_path(self, tmp_path):
+        """Test that file:// addresses give back the file they point to."""
+        path = tmp_path / "my pages" / "index.mu"
+
+        assert local_file_path(file_address(path)) == path.resolve()
+        assert local_file_path("  FILE:///tmp/a.mu ") == Path("/tmp/a.mu")
+
+    def test_other_addresses_are_not_files(self):
+        """Test that node addresses and remote file URLs are not local files."""
+        assert local_file_path(f"{HASH}:/page/index.mu") is None
+        assert local_file_path("file://example.org/a.mu") is None
+        assert local_file_path("file://") is None
+
+    def test_local_link(self, tmp_path):
+        """Test that /page/ links resolve to files beside the local page."""
+        expected = file_address(tmp_path / "about.mu")
+
+        assert local_link(":/page/about.mu", tmp_path) == expected
+        assert local_link("/page/about.mu", tmp_path) == expected
+        assert local_link("/page/docs/a.mu", tmp_path) == file_address(
+            tmp_path / "docs" / "a.mu",
+        )
+
+    def test_local_link_keeps_other_links(self, tmp_path):
+        """Test that links to nodes or out of the pages folder are left alone."""
+        assert local_link(f"{HASH}:/page/about.mu", tmp_path) is None
+        assert local_link(":/file/notes.txt", tmp_path) is None
+        assert local_link(":/page/../secret.mu", tmp_path) is None
+
+
+class TestAddressAliases:
+    """Test cases for keywords expanded by the address bar."""
+
+    def test_expand_alias(self):
+        """Test that a keyword expands to its address whatever its case."""
+        settings = {"address_aliases": {"weather": f"{HASH}:/page/wx/index.mu"}}
+
+        assert expand_alias(" Weather ", settings) == f"{HASH}:/page/wx/index.mu"
+        assert expand_alias("weather/today", settings) is None
+        assert expand_alias("weather", {}) is None
+
+    def test_invalid_aliases_are_ignored(self):
+        """Test that stored aliases with a bad keyword or address are skipped."""
+        settings = {
+            "address_aliases": {"ok": "about:history", "no spaces": "x", "b": 1},
+        }
+
+        assert aliases(settings) == {"ok": "about:history"}
+
+    @pytest.mark.parametrize("keyword", ["", "a b", "-x", HASH, "x" * 33])
+    def test_keyword_error(self, keyword):
+        """Test that keywords that could not be typed as aliases are refused."""
+        assert keyword_error(keyword) is not None
+
+    def test_keyword_error_accepts_names(self):
+        """Test that plain names are valid keywords."""
+        assert keyword_error("net-night_2.bbs") is None
@@ -0,0 +1,232 @@
+from unittest.mock import Mock
+
+from ren_browser.annotations.annotations import (
+    Annotation,
+    AnnotationStore,
+    quote_error,
+)
+from ren_browser.renderer.micron import Mark, mark_blocks, parse_micron
+from ren_browser.ui.annotations import AnnotationDialog, build_annotations_page
+
+PAGE_TEXT = "Antennas\nA dipole is cut to half the wavelength.\nKeep it high."
+
+
+class _Storage:
+    """Storage keeping the saved annotations in memory."""
+
+    def __init__(self):
+        self.saved = []
+
+    def load_annotations(self):
+        return self.saved
+
+    def save_annotations(self, annotations):
+        self.saved = annotations
+
+
+def _reloaded(store):
+    return AnnotationStore(store.storage)
+
+
+class TestAnnotationStore:
+    """Test cases for keeping the highlights of pages."""
+
+    def test_annotations_persist(self):
+        """Test that highlights are loaded again with their note and colour."""
+        store = AnnotationStore(_Storage())
+        store.add("abc:/page/a.mu", "Antennas", " half the wave ", "Check", "blue")
+
+        loaded = _reloaded(store).for_page("abc:/page/a.mu")
+
+        assert len(loaded) == 1
+        assert loaded[0].quote == "half the wave"
+        assert loaded[0].note == "Check"
+        assert loaded[0].color == "blue"
+
+    def test_update_and_remove(self):
+        """Test that highlights can be changed and deleted."""
+        store = AnnotationStore(_Storage())
+        annotation = store.add("abc:/page/a.mu", "Antennas", "dipole")
+
+        store.update(annotation, "Note", "pink")
+        assert _reloaded(store).for_page("abc:/page/a.mu")[0].color == "pink"
+
+        store.remove(annotation)
+        assert len(_reloaded(store)) == 0
+
+    def test_pages_most_recently_annotated_first(self):
+        """Test that pages are listed by their newest highlight."""
+        store = AnnotationStore(_Storage())
+        first = store.add("abc:/page/a.mu", "A", "one")
+        store.add("def:/page/b.mu", "B", "two")
+        later = store.add("abc:/page/a.mu", "A", "three")
+        first.created_at, later.created_at = 1.0, 3.0
+        store.for_page("def:/page/b.mu")[0].created_at = 2.0
+
+        pages = store.pages()
+
+        assert [address for address, _ in pages] == [
+            "abc:/page/a.mu",
+            "def:/page/b.mu",
+        ]
+        assert [a.quote for a in pages[0][1]] == ["one", "three"]
+
+    def test_malformed_annotations_are_skipped(self):
+        """Test that stored entries without an address or passage are left out."""
+        assert Annotation.from_dict({"address": "abc:/page/a.mu"}) is None
+        assert Annotation.from_dict("nope") is None
+        annotation = Annotation.from_dict(
+            {"address": "abc:/page/a.mu", "quote": "dipole", "color": "orange"},
+        )
+        assert annotation.color == "yellow"
+        assert annotation.title == "abc:/page/a.mu"
+
+
+class TestQuoteError:
+    """Test cases for checking a passage can be highlighted."""
+
+    def test_passage_on_the_page(self):
+        """Test that passages on the page are accepted, line by line."""
+        assert quote_error("dipole is cut\nKeep it", PAGE_TEXT) is None
+
+    def test_passage_not_on_the_page(self):
+        """Test that empty passages and ones not on the page are refused."""
+        assert quote_error("  ", PAGE_TEXT) == "Enter the passage to highlight"
+        assert quote_error("monopole", PAGE_TEXT) == "This passage is not on the page"
+
+
+class TestMarkBlocks:
+    """Test cases for highlighting passages of a page."""
+
+    def test_marks_the_passage(self):
+        """Test that only the passage is highlighted, with the note."""
+        blocks = parse_micron("A dipole is cut to half the wavelength.")
+
+        marked = mark_blocks(blocks, (Mark("half the", "255,235,59", "Check"),))
+
+        spans = [span for span in marked[0].parts if span.get("highlight")]
+        assert "".join(span["text"] for span in spans) == "half the"
+        assert spans[0]["bgcolor"] == "255,235,59"
+        assert spans[0]["note"] == "Check"
+
+    def test_cached_blocks_are_unchanged(self):
+        """Test that the parse cache keeps the blocks without highlights."""
+        blocks = parse_micron("A dipole is cut to half the wavelength.")
+
+        mark_blocks(blocks, (Mark("dipole", "255,235,59"),))
+
+        assert parse_micron("A dipole is cut to half the wavelength.") == blocks
+        assert not any(span.get("highlight") for span in blocks[0].parts)
+
+    def test_without_marks_returns_the_blocks(self):
+        """Test that pages without highlights are not copied."""
+        blocks = parse_micron("Hello")
+
+        assert mark_blocks(blocks, ()) is blocks
+        assert mark_blocks(blocks, (Mark("missing", "255,235,59"),))[0] is blocks[0]
+
+
+class TestAnnotationDialog:
+    """Test cases for the dialog highlighting a passage."""
+
+    def _dialog(self, mock_page):
+        tab_manager = Mock()
+        tab_manager.annotations = AnnotationStore(_Storage())
+        tab_manager.manager.tabs = [{"address": "abc:/page/a.mu", "title": "Antennas"}]
+        tab_manager.page_text.return_value = PAGE_TEXT
+        return AnnotationDialog(mock_page, tab_manager), tab_manager
+
+    def test_prefills_copied_passage(self, mock_page):
+        """Test that text copied from the page is filled in."""
+        mock_page.get_clipboard = Mock(return_value="half the wavelength")
+        dialog, _ = self._dialog(mock_page)
+
+        dialog.annotate(0)
+
+        assert dialog.quote_field.value == "half the wavelength"
+        assert dialog.delete_btn.visible is False
+
+    def test_ignores_copied_text_from_elsewhere(self, mock_page):
+        """Test that copied text not on the page is not filled in."""
+        mock_page.get_clipboard = Mock(return_value="something else")
+        dialog, _ = self._dialog(mock_page)
+
+        dialog.annotate(0)
+
+        assert dialog.quote_field.value == ""
+
+    def test_save_adds_highlight(self, mock_page):
+        """Test that saving highlights the passage on the page."""
+        mock_page.get_clipboard = Mock(return_value="")
+        dialog, tab_manager = self._dialog(mock_page)
+        dialog.annotate(0)
+        dialog.quote_field.value = "Keep it high."
+        dialog.note_field.value = "Mast"
+        dialog.color_dropdown.value = "green"
+
+        dialog.apply()
+
+        annotation = tab_manager.annotations.for_page("abc:/page/a.mu")[0]
+        assert (annotation.quote, annotation.note, annotation.color) == (
+            "Keep it high.",
+            "Mast",
+            "green",
+        )
+        tab_manager.annotations_changed.assert_called_once_with("abc:/page/a.mu")
+
+    def test_save_refuses_passage_not_on_page(self, mock_page):
+        """Test that passages not on the page show an error instead."""
+        mock_page.get_clipboard = Mock(return_value="")
+        dialog, tab_manager = self._dialog(mock_page)
+        dialog.annotate(0)
+        dialog.quote_field.value = "monopole"
+
+        dialog.apply()
+
+        assert dialog.quote_field.error_text == "This passage is not on the page"
+        assert len(tab_manager.annotations) == 0
+
+    def test_edit_and_delete(self, mock_page):
+        """Test that a highlight's note can be changed and it can be deleted."""
+        dialog, tab_manager = self._dialog(mock_page)
+        annotation = tab_manager.annotations.add("abc:/page/a.mu", "A", "dipole")
+
+        dialog.edit(annotation)
+        assert dialog.quote_field.read_only is True
+        dialog.note_field.value = "Longer"
+        dialog.apply()
+        assert annotation.note == "Longer"
+
+        dialog.edit(annotation)
+        dialog.delete()
+        assert len(tab_manager.annotations) == 0
+
+
+class TestAnnotationsPage:
+    """Test cases for the about:annotations page."""
+
+    def test_lists_highlights_by_page(self):
+        """Test that highlights are listed under their page."""
+        store = AnnotationStore(_Storage())
+        store.add("abc:/page/a.mu", "Antennas", "dipole", "Check")
+        opened = []
+
+        page = build_annotations_page(store, opened.append, Mock(), Mock())
+
+        assert len(page.controls) == 3
+        page.controls[1].on_click(None)
+        assert opened == ["abc:/page/a.mu"]
+
+    def test_remove_redraws_the_page(self):
+        """Test that deleting a highlight leaves the help text."""
+        store = AnnotationStore(_Storage())
+        store.add("abc:/page/a.mu", "Antennas", "dipole")
+        on_change = Mock()
+        page = build_annotations_page(store, Mock(), Mock(), on_change)
+
+        remove = page.controls[2].content.controls[3]
+        remove.on_click(None)
+
+        assert len(store) == 0
+        assert len(page.controls) == 2
+        on_change.assert_called_once()
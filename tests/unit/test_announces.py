@@ -1,4 +1,13 @@
-from ren_browser.announces.announces import Announce
+from unittest.mock import Mock, patch
+
+from ren_browser.announces.announces import (
+    Announce,
+    AnnounceService,
+    PathInfo,
+    decode_display_name,
+    path_info,
+    request_path,
+)
 
 
 class TestAnnounce:
@@ -52,3 +61,101 @@ class TestAnnounceService:
         filtered = [ann for ann in announces if ann.destination_hash == "hash1"]
         assert len(filtered) == 1
         assert filtered[0].display_name == "Node1"
+
+    def test_repeat_announces_are_counted(self):
+        """Test that a node announcing again keeps its first-seen time."""
+        callback = Mock()
+        with patch("ren_browser.announces.announces.RNS"):
+            service = AnnounceService(update_callback=callback)
+        identity = Mock(hash=bytes.fromhex("ff00"))
+
+        with patch("ren_browser.announces.announces.time.time", return_value=100):
+            service.received_announce(bytes.fromhex("abcd"), identity, b"Hilltop")
+        with patch("ren_browser.announces.announces.time.time", return_value=250):
+            service.received_announce(bytes.fromhex("abcd"), identity, b"Hilltop")
+
+        [announce] = service.get_announces()
+        assert announce.timestamp == 250
+        assert announce.first_seen == 100
+        assert announce.announce_count == 2
+        assert announce.identity_hash == "ff00"
+        assert announce.aspect == "nomadnetwork.node"
+
+    def test_log_keeps_repeats_and_notifies_listeners(self):
+        """Test that every announce is logged and passed to listeners."""
+        with patch("ren_browser.announces.announces.RNS"):
+            service = AnnounceService(update_callback=None)
+        listener = Mock()
+        service.add_listener(listener)
+        service.add_listener(listener)
+
+        service.received_announce(bytes.fromhex("abcd"), None, b"Hilltop")
+        service.received_announce(bytes.fromhex("abcd"), None, b"Hilltop")
+        service.remove_listener(listener)
+        service.received_announce(bytes.fromhex("ef01"), None, b"Valley")
+
+        assert len(service.get_announces()) == 2
+        assert [ann.display_name for ann in service.log] == [
+            "Hilltop",
+            "Hilltop",
+            "Valley",
+        ]
+        assert listener.call_count == 2
+
+
+    def test_other_aspects_are_received(self):
+        """Test that LXMF announces are heard with their aspect and name."""
+        with patch("ren_browser.announces.announces.RNS") as rns:
+            service = AnnounceService(update_callback=None)
+        register = rns.Transport.register_announce_handler
+        handlers = [c.args[0] for c in register.mock_calls]
+        aspects = [handler.aspect_filter for handler in handlers]
+        assert aspects == ["nomadnetwork.node", "lxmf.delivery", "lxmf.propagation"]
+
+        app_data = b"\x92\xc4\x03Kai\xc0"
+        handlers[1].received_announce(bytes.fromhex("abcd"), None, app_data)
+
+        [announce] = service.get_announces()
+        assert announce.aspect == "lxmf.delivery"
+        assert announce.display_name == "Kai"
+
+
+class TestDecodeDisplayName:
+    """Test cases for decode_display_name."""
+
+    def test_formats(self):
+        """Test plain text names, msgpack names and unreadable data."""
+        assert decode_display_name(b"Hilltop") == "Hilltop"
+        assert decode_display_name(b"\x92\xa3Kai\x00") == "Kai"
+        assert decode_display_name(b"\x91\xc0") is None
+        assert decode_display_name(b"\xff\xfe") is None
+        assert decode_display_name(None) is None
+
+
+class TestPathInfo:
+    """Test cases for path_info."""
+
+    def test_request_path(self):
+        """Test that a path request is sent for a hex hash."""
+        with patch("ren_browser.announces.announces.RNS") as rns:
+            assert request_path("abcd")
+            rns.Transport.request_path.assert_called_once_with(bytes.fromhex("abcd"))
+
+        assert not request_path("not hex")
+
+    def test_known_path(self):
+        """Test that the hop count and interface are reported."""
+        with patch("ren_browser.announces.announces.RNS") as rns:
+            rns.Transport.has_path.return_value = True
+            rns.Transport.hops_to.return_value = 3
+            rns.Transport.next_hop_interface.return_value = "AutoInterface"
+
+            assert path_info("abcd") == PathInfo(3, "AutoInterface")
+
+    def test_unknown_path(self):
+        """Test that a missing path or a bad hash reports nothing."""
+        with patch("ren_browser.announces.announces.RNS") as rns:
+            rns.Transport.has_path.return_value = False
+
+            assert path_info("abcd") == PathInfo()
+            assert path_info("not hex") == PathInfo()
@@ -1,4 +1,5 @@
-from unittest.mock import patch
+from pathlib import Path
+from unittest.mock import Mock, patch
 
 import flet as ft
 import pytest
@@ -17,6 +18,9 @@ class TestApp:
             patch("ren_browser.rns.get_reticulum_instance"),
             patch("ren_browser.rns.get_config_path", return_value="/tmp/.reticulum"),
             patch("ren_browser.app.build_ui"),
+            patch("ren_browser.app.HandoffServer"),
+            patch("ren_browser.app.RemoteControlServer"),
+            patch("ren_browser.app._install_crash_handler"),
         ):
             await app.main(mock_page)
 
@@ -33,6 +37,9 @@ class TestApp:
             patch("ren_browser.rns.get_reticulum_instance"),
             patch("ren_browser.rns.get_config_path"),
             patch("ren_browser.app.build_ui"),
+            patch("ren_browser.app.HandoffServer"),
+            patch("ren_browser.app.RemoteControlServer"),
+            patch("ren_browser.app._install_crash_handler"),
         ):
             await app.main(mock_page)
 
@@ -87,6 +94,211 @@ class TestApp:
 
             assert app.RENDERER == "micron"
 
+    def test_run_hands_address_to_running_instance(self, mock_rns):
+        """Test that a ren:// argument is passed to an already running browser."""
+        link = "ren://0123456789abcdef0123456789abcdef/page/index.mu"
+        with (
+            patch("sys.argv", ["ren-browser", link]),
+            patch("ren_browser.app.hand_off", return_value=True) as mock_hand_off,
+            patch("flet.app") as mock_ft_app,
+        ):
+            app.run()
+
+        assert mock_hand_off.call_args[0][1] == link
+        mock_ft_app.assert_not_called()
+
+    def test_run_opens_address_in_new_instance(self, mock_rns):
+        """Test that the address is kept for startup when no browser is running."""
+        link = "ren://0123456789abcdef0123456789abcdef/page/index.mu"
+        with (
+            patch("sys.argv", ["ren-browser", link]),
+            patch("ren_browser.app.hand_off", return_value=False),
+            patch("flet.app") as mock_ft_app,
+        ):
+            app.run()
+
+        assert app.START_ADDRESSES == [link]
+        mock_ft_app.assert_called_once()
+        app.START_ADDRESSES = []
+
+    def test_run_hands_every_address_to_running_instance(self, mock_rns):
+        """Test that each of several addresses is passed to the running browser."""
+        links = ["ren://abc/page/index.mu", "def:/page/about.mu"]
+        with (
+            patch("sys.argv", ["ren-browser", *links]),
+            patch("ren_browser.app.hand_off", return_value=True) as mock_hand_off,
+            patch("flet.app") as mock_ft_app,
+        ):
+            app.run()
+
+        assert [call[0][1] for call in mock_hand_off.call_args_list] == links
+        mock_ft_app.assert_not_called()
+        app.START_ADDRESSES = []
+
+    def test_run_keeps_every_address_for_new_instance(self, mock_rns):
+        """Test that several addresses all open when no browser is running."""
+        links = ["ren://abc/page/index.mu", "def:/page/about.mu"]
+        with (
+            patch("sys.argv", ["ren-browser", *links]),
+            patch("ren_browser.app.hand_off", return_value=False) as mock_hand_off,
+            patch("flet.app"),
+        ):
+            app.run()
+
+        assert app.START_ADDRESSES == links
+        mock_hand_off.assert_called_once()
+        app.START_ADDRESSES = []
+
+    def test_run_with_profile(self, mock_rns):
+        """Test that --profile picks the profile whose data is used."""
+        with (
+            patch("sys.argv", ["ren-browser", "--profile", "testing"]),
+            patch("ren_browser.app.set_profile") as mock_set_profile,
+            patch("flet.app"),
+        ):
+            app.run()
+
+        mock_set_profile.assert_called_once_with("testing")
+
+    def test_run_with_portable_flag(self, mock_rns):
+        """Test that --portable keeps the data beside the executable."""
+        with (
+            patch("sys.argv", ["ren-browser", "--portable"]),
+            patch("ren_browser.app.set_portable") as mock_set_portable,
+            patch("flet.app"),
+        ):
+            app.run()
+
+        mock_set_portable.assert_called_once_with(True)
+
+    def test_run_rejects_invalid_profile(self, mock_rns):
+        """Test that profile names that are not plain names are refused."""
+        with (
+            patch("sys.argv", ["ren-browser", "--profile", "../home"]),
+            patch("flet.app") as mock_ft_app,
+            pytest.raises(SystemExit),
+        ):
+            app.run()
+
+        mock_ft_app.assert_not_called()
+
+    def test_run_register_scheme(self, mock_rns):
+        """Test that --register-scheme registers the handler without launching."""
+        with (
+            patch("sys.argv", ["ren-browser", "--register-scheme"]),
+            patch("ren_browser.app.register_url_scheme") as mock_register,
+            patch("flet.app") as mock_ft_app,
+        ):
+            mock_register.return_value = "Registered"
+            app.run()
+
+        mock_register.assert_called_once()
+        mock_ft_app.assert_not_called()
+
+    def test_run_fetch_command(self, mock_rns):
+        """Test that the fetch command runs without launching the window."""
+        with (
+            patch("sys.argv", ["ren-browser", "fetch", "a" * 32, "--render"]),
+            patch("ren_browser.app.fetch", return_value=0) as mock_fetch,
+            patch("flet.app") as mock_ft_app,
+            pytest.raises(SystemExit) as exit_info,
+        ):
+            app.run()
+
+        assert exit_info.value.code == 0
+        mock_fetch.assert_called_once_with(["a" * 32, "--render"])
+        mock_ft_app.assert_not_called()
+
+    def test_run_remote_command(self, mock_rns):
+        """Test that the remote command runs without launching the window."""
+        with (
+            patch("sys.argv", ["ren-browser", "remote", "status"]),
+            patch("ren_browser.app.remote", return_value=0) as mock_remote,
+            patch("flet.app") as mock_ft_app,
+            pytest.raises(SystemExit) as exit_info,
+        ):
+            app.run()
+
+        assert exit_info.value.code == 0
+        mock_remote.assert_called_once_with(["status"])
+        mock_ft_app.assert_not_called()
+
+    @pytest.mark.asyncio
+    async def test_main_opens_start_address(self, mock_page, mock_rns):
+        """Test that command line addresses open in tabs once the UI is built."""
+        with (
+            patch("ren_browser.rns.initialize_reticulum", return_value=True),
+            patch("ren_browser.rns.get_reticulum_instance"),
+            patch("ren_browser.rns.get_config_path"),
+            patch("ren_browser.app.build_ui") as mock_build_ui,
+            patch("ren_browser.app.HandoffServer") as mock_server,
+            patch("ren_browser.app.RemoteControlServer"),
+            patch("ren_browser.app._install_crash_handler"),
+            patch("ren_browser.app.START_ADDRESSES", ["ren://abc", "def:/"]),
+        ):
+            await app.main(mock_page)
+
+        tab_manager = mock_build_ui.return_value
+        assert [
+            call[0][0] for call in tab_manager.open_link_in_new_tab.call_args_list
+        ] == ["ren://abc", "def:/"]
+        mock_server.return_value.start.assert_called_once()
+
+    @pytest.mark.asyncio
+    async def test_main_offers_recovery_after_crash(self, mock_page, mock_rns):
+        """Test that a crash report left by the last run prompts to restore tabs."""
+        with (
+            patch("ren_browser.rns.initialize_reticulum", return_value=True),
+            patch("ren_browser.rns.get_reticulum_instance"),
+            patch("ren_browser.rns.get_config_path"),
+            patch("ren_browser.app.build_ui") as mock_build_ui,
+            patch("ren_browser.app.HandoffServer"),
+            patch("ren_browser.app.RemoteControlServer"),
+            patch("ren_browser.app._install_crash_handler") as mock_install,
+            patch(
+                "ren_browser.app.take_pending_report",
+                return_value=Path("/tmp/crash.txt"),
+            ),
+            patch("ren_browser.app.CrashPrompt") as mock_prompt,
+        ):
+            await app.main(mock_page)
+
+        tab_manager = mock_build_ui.return_value
+        mock_prompt.assert_called_once_with(
+            mock_page,
+            tab_manager,
+            Path("/tmp/crash.txt"),
+        )
+        mock_prompt.return_value.show.assert_called_once()
+        tab_manager.settings = {"crash_report_tabs": False}
+        assert mock_install.return_value.tabs() is None
+
+    @pytest.mark.asyncio
+    async def test_main_opens_address_in_route(self, mock_page, mock_rns):
+        """Test that an address in the app's route opens in a new tab."""
+        hash_ = "0123456789abcdef0123456789abcdef"
+        mock_page.route = f"/{hash_}/page/about.mu"
+        with (
+            patch("ren_browser.rns.initialize_reticulum", return_value=True),
+            patch("ren_browser.rns.get_reticulum_instance"),
+            patch("ren_browser.rns.get_config_path"),
+            patch("ren_browser.app.build_ui") as mock_build_ui,
+            patch("ren_browser.app.HandoffServer"),
+            patch("ren_browser.app.RemoteControlServer"),
+            patch("ren_browser.app._install_crash_handler"),
+        ):
+            await app.main(mock_page)
+
+            tab_manager = mock_build_ui.return_value
+            tab_manager.open_link_in_new_tab.assert_called_once_with(
+                f"{hash_}/page/about.mu",
+            )
+
+            mock_page.on_route_change(Mock(route="/"))
+            mock_page.on_route_change(Mock(route=f"/{hash_}"))
+        assert tab_manager.open_link_in_new_tab.call_count == 2
+        tab_manager.open_link_in_new_tab.assert_called_with(hash_)
+
     def test_web_function(self, mock_rns):
         """Test web() entry point function."""
         with patch("flet.app") as mock_ft_app:
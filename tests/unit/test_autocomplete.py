@@ -0,0 +1,160 @@
+from unittest.mock import Mock
+
+from ren_browser.announces.announces import Announce
+from ren_browser.controls.autocomplete import (
+    AddressAutocomplete,
+    Suggestion,
+    bookmark_suggestions,
+    history_suggestions,
+    node_suggestions,
+    petname_suggestions,
+    rank_suggestions,
+)
+from ren_browser.petnames.petnames import Petname
+
+
+class TestSuggestionSources:
+    """Test cases for building suggestions from stored data."""
+
+    def test_history_suggestions(self):
+        """Test that history entries become suggestions."""
+        history = [
+            {"address": "abc:/page/index.mu", "title": "Home Node"},
+            {"title": "missing address"},
+            "def:/page/index.mu",
+        ]
+        suggestions = history_suggestions(history)
+
+        assert len(suggestions) == 2
+        assert suggestions[0] == Suggestion("abc:/page/index.mu", "Home Node", "history")
+        assert suggestions[1].label == "def:/page/index.mu"
+
+    def test_history_suggestions_match_node_name(self):
+        """Test that visits can be suggested by the name of their node."""
+        history = [
+            {"address": "abc:/page/news.mu", "title": "News", "node_hash": "abc"},
+        ]
+        suggestions = history_suggestions(history, {"abc": "Hilltop Relay"}.get)
+
+        assert suggestions[0].keywords == "Hilltop Relay"
+        assert rank_suggestions("hilltop", suggestions) == suggestions
+
+    def test_bookmark_suggestions(self):
+        """Test that bookmarks become suggestions."""
+        suggestions = bookmark_suggestions([{"address": "abc:/page/a.mu"}])
+
+        assert suggestions[0].source == "bookmark"
+        assert suggestions[0].label == "abc:/page/a.mu"
+
+    def test_petname_suggestions(self):
+        """Test that petnames are suggested as ~name, also by their note."""
+        petnames = [Petname("alice", "0123456789abcdef0123456789abcdef", "Forum")]
+        suggestions = petname_suggestions(petnames)
+
+        assert suggestions[0] == Suggestion("~alice", "~alice", "petname", "Forum")
+        assert rank_suggestions("forum", suggestions) == suggestions
+
+    def test_node_suggestions(self):
+        """Test that announced nodes suggest their index page."""
+        announces = [Announce("abcd", "Weather", 1), Announce("ef01", None, 2)]
+        suggestions = node_suggestions(announces)
+
+        assert suggestions[0].address == "abcd:/page/index.mu"
+        assert suggestions[0].label == "Weather"
+        assert suggestions[1].label == "ef01"
+
+
+class TestRankSuggestions:
+    """Test cases for suggestion ranking."""
+
+    def test_empty_query_returns_nothing(self):
+        """Test that no suggestions are offered for an empty query."""
+        candidates = [Suggestion("abc:/page/index.mu", "Node", "node")]
+        assert rank_suggestions("  ", candidates) == []
+
+    def test_prefix_matches_rank_first(self):
+        """Test that prefix matches outrank substring matches."""
+        candidates = [
+            Suggestion("111:/page/index.mu", "The Weather", "bookmark"),
+            Suggestion("222:/page/index.mu", "Weather BBS", "node"),
+        ]
+        results = rank_suggestions("weather", candidates)
+
+        assert [r.address for r in results] == [
+            "222:/page/index.mu",
+            "111:/page/index.mu",
+        ]
+
+    def test_duplicates_keep_best_source(self):
+        """Test that duplicate addresses are collapsed."""
+        candidates = [
+            Suggestion("abc:/page/index.mu", "Node", "node"),
+            Suggestion("abc:/page/index.mu", "My Node", "bookmark"),
+        ]
+        results = rank_suggestions("abc", candidates)
+
+        assert len(results) == 1
+        assert results[0].source == "bookmark"
+
+    def test_limit(self):
+        """Test that results are capped at the limit."""
+        candidates = [
+            Suggestion(f"{i:04x}:/page/index.mu", "Node", "node") for i in range(20)
+        ]
+        assert len(rank_suggestions("node", candidates, limit=5)) == 5
+
+
+class TestAddressAutocomplete:
+    """Test cases for the AddressAutocomplete dropdown."""
+
+    def _autocomplete(self, mock_page):
+        on_select = Mock()
+        autocomplete = AddressAutocomplete(mock_page, on_select=on_select)
+        autocomplete.add_source(
+            lambda: [
+                Suggestion("aaa:/page/index.mu", "Alpha", "node"),
+                Suggestion("aab:/page/index.mu", "Alpine", "node"),
+            ],
+        )
+        return autocomplete, on_select
+
+    def test_update_query_opens_panel(self, mock_page):
+        """Test that matching text shows the dropdown."""
+        autocomplete, _ = self._autocomplete(mock_page)
+        autocomplete.update_query("al")
+
+        assert autocomplete.is_open
+        assert len(autocomplete.list_column.controls) == 2
+
+    def test_move_and_accept(self, mock_page):
+        """Test keyboard selection of a suggestion."""
+        autocomplete, _ = self._autocomplete(mock_page)
+        autocomplete.update_query("al")
+        autocomplete.move(1)
+        autocomplete.move(1)
+
+        assert autocomplete.accept() == "aab:/page/index.mu"
+        assert not autocomplete.is_open
+
+    def test_accept_without_selection(self, mock_page):
+        """Test that Enter without a highlighted row keeps the typed text."""
+        autocomplete, _ = self._autocomplete(mock_page)
+        autocomplete.update_query("al")
+
+        assert autocomplete.accept() is None
+
+    def test_click_selects(self, mock_page):
+        """Test that clicking a suggestion calls on_select."""
+        autocomplete, on_select = self._autocomplete(mock_page)
+        autocomplete.update_query("alpha")
+        autocomplete.list_column.controls[0].on_click(None)
+
+        on_select.assert_called_once_with("aaa:/page/index.mu")
+
+    def test_failing_source_is_ignored(self, mock_page):
+        """Test that a broken suggestion source does not break the dropdown."""
+        autocomplete, _ = self._autocomplete(mock_page)
+        autocomplete.add_source(Mock(side_effect=RuntimeError("boom")))
+        autocomplete.update_query("al")
+
+        assert len(autocomplete.suggestions) == 2
@@ -0,0 +1,64 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.bookmarks.bookmarks import BookmarkManager
+from ren_browser.controls.bookmark_editor import BookmarkEditor
+
+
+class TestBookmarkEditor:
+    """Test cases for the inline bookmark editor."""
+
+    @pytest.fixture
+    def bookmarks(self):
+        """Create a bookmark manager without persistent storage."""
+        return BookmarkManager(Mock(load_bookmarks=Mock(return_value=[])))
+
+    @pytest.fixture
+    def editor(self, mock_page, bookmarks):
+        """Create a bookmark editor for testing."""
+        return BookmarkEditor(mock_page, bookmarks, on_change=Mock())
+
+    def test_open_bookmarks_page(self, editor, bookmarks):
+        """Test that opening the editor bookmarks the page."""
+        editor.open("abc:/page/index.mu", "Node Home")
+
+        assert editor.is_open
+        assert bookmarks.get("abc:/page/index.mu").title == "Node Home"
+        assert editor.title_field.value == "Node Home"
+        editor.on_change.assert_called_once()
+
+    def test_open_existing_bookmark_keeps_details(self, editor, bookmarks):
+        """Test that an existing bookmark is edited rather than replaced."""
+        bookmarks.add("abc:/page/index.mu", "Saved", "Mesh", "chat")
+
+        editor.open("abc:/page/index.mu", "Node Home")
+
+        assert editor.title_field.value == "Saved"
+        assert editor.folder_field.value == "Mesh"
+        assert editor.tags_field.value == "chat"
+        editor.on_change.assert_not_called()
+
+    def test_save(self, editor, bookmarks):
+        """Test saving the edited title, folder and tags."""
+        editor.open("abc:/page/index.mu", "Node Home")
+        editor.title_field.value = "Renamed"
+        editor.folder_field.value = "Mesh"
+        editor.tags_field.value = "chat, wiki"
+
+        editor.save()
+
+        bookmark = bookmarks.get("abc:/page/index.mu")
+        assert bookmark.title == "Renamed"
+        assert bookmark.folder == "Mesh"
+        assert bookmark.tags == ["chat", "wiki"]
+        assert not editor.is_open
+
+    def test_remove(self, editor, bookmarks):
+        """Test removing the bookmark from the editor."""
+        editor.open("abc:/page/index.mu", "Node Home")
+
+        editor.remove()
+
+        assert bookmarks.get("abc:/page/index.mu") is None
+        assert not editor.is_open
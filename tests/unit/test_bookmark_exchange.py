@@ -0,0 +1,145 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.bookmarks.bookmarks import Bookmark, BookmarkManager
+from ren_browser.bookmarks.exchange import (
+    BookmarkFormatError,
+    from_json,
+    from_netscape_html,
+    parse_bookmarks,
+    to_json,
+    to_netscape_html,
+)
+from ren_browser.ui.bookmarks import BookmarkFiles
+
+SAMPLE = [
+    Bookmark("a:/page/index.mu", "Node A", "Mesh", ["chat"], 1700000000.0),
+    Bookmark("b:/page/index.mu", "Node B & Co", "Mesh/Wikis", [], 1700000001.0),
+    Bookmark("c:/page/index.mu", "Node C", "", ["wiki", "lora"], 1700000002.0),
+]
+
+
+class TestJsonFormat:
+    """Test cases for the JSON bookmark format."""
+
+    def test_round_trip(self):
+        """Test that exported JSON imports back unchanged."""
+        assert from_json(to_json(SAMPLE)) == SAMPLE
+
+    def test_bare_list(self):
+        """Test importing the list kept in the storage directory."""
+        imported = from_json('[{"address": "a:/page/index.mu", "title": "A"}]')
+
+        assert [b.title for b in imported] == ["A"]
+
+    def test_invalid_json(self):
+        """Test that malformed files are reported."""
+        with pytest.raises(BookmarkFormatError):
+            from_json("{not json")
+        with pytest.raises(BookmarkFormatError):
+            from_json('{"bookmarks": 5}')
+
+
+class TestNetscapeFormat:
+    """Test cases for the Netscape bookmark file format."""
+
+    def test_round_trip(self):
+        """Test that exported HTML imports back with folders and tags."""
+        imported = from_netscape_html(to_netscape_html(SAMPLE))
+
+        assert sorted(imported, key=lambda b: b.address) == SAMPLE
+
+    def test_export_escapes_and_nests(self):
+        """Test that titles are escaped and folders are nested."""
+        exported = to_netscape_html(SAMPLE)
+
+        assert exported.startswith("<!DOCTYPE NETSCAPE-Bookmark-file-1>")
+        assert "Node B &amp; Co" in exported
+        assert exported.index("<H3>Mesh</H3>") < exported.index("<H3>Wikis</H3>")
+        assert 'TAGS="wiki,lora"' in exported
+
+    def test_import_browser_export(self):
+        """Test importing a file written by another browser."""
+        text = """<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<DL><p>
+    <DT><H3 ADD_DATE="1">Nodes</H3>
+    <DL><p>
+        <DT><A HREF="abc:/page/index.mu" ADD_DATE="1700000000">Home</A>
+    </DL><p>
+    <DT><A HREF="def:/page/index.mu">Other</A>
+</DL><p>
+"""
+        imported = from_netscape_html(text)
+
+        assert [(b.address, b.folder) for b in imported] == [
+            ("abc:/page/index.mu", "Nodes"),
+            ("def:/page/index.mu", ""),
+        ]
+        assert imported[0].created == 1700000000.0
+
+    def test_parse_bookmarks_detects_format(self):
+        """Test that the format is recognized from the content."""
+        assert parse_bookmarks(to_json(SAMPLE)) == SAMPLE
+        assert len(parse_bookmarks(to_netscape_html(SAMPLE))) == 3
+        with pytest.raises(BookmarkFormatError):
+            parse_bookmarks("just some text")
+
+
+class TestBookmarkFiles:
+    """Test cases for importing and exporting bookmark files."""
+
+    @pytest.fixture
+    def bookmarks(self):
+        """Create a bookmark manager without persistent storage."""
+        return BookmarkManager(Mock(load_bookmarks=Mock(return_value=[])))
+
+    @pytest.fixture
+    def files(self, mock_page, bookmarks):
+        """Create the bookmark file dialogs for testing."""
+        mock_page.overlay = []
+        return BookmarkFiles(mock_page, bookmarks, on_change=Mock())
+
+    def test_export_then_import(self, files, bookmarks, tmp_path):
+        """Test exporting to a file and importing it into another manager."""
+        bookmarks.merge(SAMPLE)
+        path = tmp_path / "bookmarks.html"
+
+        assert files.export_to(path, "html")
+
+        other = BookmarkManager(Mock(load_bookmarks=Mock(return_value=[])))
+        other_files = BookmarkFiles(files.page, other, on_change=Mock())
+        assert other_files.import_from(path) == 3
+        other_files.on_change.assert_called_once()
+
+    def test_import_skips_saved_bookmarks(self, files, bookmarks, tmp_path):
+        """Test that importing does not duplicate saved bookmarks."""
+        bookmarks.add("a:/page/index.mu", "Mine")
+        path = tmp_path / "bookmarks.json"
+        path.write_text(to_json(SAMPLE), encoding="utf-8")
+
+        assert files.import_from(path) == 2
+        assert bookmarks.get("a:/page/index.mu").title == "Mine"
+        assert "1 already saved" in files.page.overlay[-1].content.value
+
+    def test_import_invalid_file(self, files, tmp_path):
+        """Test that unreadable files are reported and nothing is imported."""
+        path = tmp_path / "notes.txt"
+        path.write_text("nothing here", encoding="utf-8")
+
+        assert files.import_from(path) == 0
+        assert "Failed to import" in files.page.overlay[-1].content.value
+        files.on_change.assert_not_called()
+
+    def test_picker_result_exports(self, files, bookmarks, tmp_path):
+        """Test that choosing a save location writes the chosen format."""
+        bookmarks.merge(SAMPLE)
+        path = tmp_path / "out.json"
+        files.picker = Mock()
+
+        files.choose_export("json")
+        files.picker.save_file.assert_called_once()
+        files._on_result(Mock(path=str(path)))
+
+        exported = from_json(path.read_text(encoding="utf-8"))
+        assert sorted(exported, key=lambda b: b.address) == SAMPLE
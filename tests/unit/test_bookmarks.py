@@ -0,0 +1,137 @@
+from unittest.mock import Mock
+
+from ren_browser.bookmarks.bookmarks import (
+    Bookmark,
+    BookmarkManager,
+    folder_label,
+    parse_tags,
+)
+
+
+def _storage(bookmarks=None):
+    storage = Mock()
+    storage.load_bookmarks.return_value = bookmarks or []
+    return storage
+
+
+class TestBookmarkManager:
+    """Test cases for the BookmarkManager class."""
+
+    def test_add_saves_bookmark(self):
+        """Test that adding a bookmark persists it."""
+        storage = _storage()
+        bookmarks = BookmarkManager(storage)
+
+        bookmark = bookmarks.add("abc:/page/index.mu", "Home", "Mesh", "chat, Wiki")
+
+        assert bookmark.folder == "Mesh"
+        assert bookmark.tags == ["chat", "wiki"]
+        saved = storage.save_bookmarks.call_args[0][0]
+        assert saved[0]["address"] == "abc:/page/index.mu"
+        assert saved[0]["tags"] == ["chat", "wiki"]
+
+    def test_add_existing_address_updates(self):
+        """Test that bookmarking an address twice keeps one bookmark."""
+        bookmarks = BookmarkManager(_storage())
+        bookmarks.add("abc:/page/index.mu", "Home")
+
+        bookmarks.add("abc:/page/index.mu", "Renamed", "Mesh")
+
+        assert len(bookmarks.bookmarks()) == 1
+        assert bookmarks.get("abc:/page/index.mu").title == "Renamed"
+
+    def test_loads_stored_bookmarks(self):
+        """Test loading bookmarks, including the older plain address form."""
+        bookmarks = BookmarkManager(
+            _storage(
+                [
+                    {"address": "a:/page/index.mu", "title": "A", "tags": ["x"]},
+                    "b:/page/index.mu",
+                    {"title": "no address"},
+                    {"address": "a:/page/index.mu", "title": "Duplicate"},
+                ],
+            ),
+        )
+
+        assert [b.address for b in bookmarks.bookmarks()] == [
+            "a:/page/index.mu",
+            "b:/page/index.mu",
+        ]
+        assert bookmarks.get("a:/page/index.mu").title == "A"
+
+    def test_folders_tags_and_grouping(self):
+        """Test listing folders and tags and grouping by folder."""
+        bookmarks = BookmarkManager(_storage())
+        bookmarks.add("a:/page/index.mu", "A", "Wikis", "wiki")
+        bookmarks.add("b:/page/index.mu", "B", tags="chat")
+        bookmarks.add("c:/page/index.mu", "C", "Chat", "chat")
+
+        assert bookmarks.folders() == ["Chat", "Wikis"]
+        assert bookmarks.tags() == ["chat", "wiki"]
+        groups = bookmarks.by_folder()
+        assert [folder for folder, _ in groups] == ["", "Chat", "Wikis"]
+        assert [b.title for _, group in bookmarks.by_folder("chat") for b in group] == [
+            "B",
+            "C",
+        ]
+
+    def test_move_and_remove(self):
+        """Test refiling and deleting bookmarks."""
+        storage = _storage()
+        bookmarks = BookmarkManager(storage)
+        bookmarks.add("a:/page/index.mu", "A")
+
+        bookmarks.move("a:/page/index.mu", "Mesh")
+        assert bookmarks.get("a:/page/index.mu").folder == "Mesh"
+
+        bookmarks.remove("a:/page/index.mu")
+        assert bookmarks.bookmarks() == []
+        storage.save_bookmarks.assert_called_with([])
+
+    def test_clear_since(self):
+        """Test that clearing a time range keeps older bookmarks."""
+        bookmarks = BookmarkManager(
+            _storage(
+                [
+                    {"address": "a:/page/index.mu", "title": "Old", "created": 100},
+                    {"address": "b:/page/index.mu", "title": "New", "created": 200},
+                ],
+            ),
+        )
+
+        bookmarks.clear(since=150.0)
+        assert [b.title for b in bookmarks.bookmarks()] == ["Old"]
+
+        bookmarks.clear()
+        assert bookmarks.bookmarks() == []
+
+    def test_unreadable_storage_starts_empty(self):
+        """Test that a storage failure does not break the browser."""
+        storage = Mock()
+        storage.load_bookmarks.side_effect = TypeError("bad data")
+
+        assert BookmarkManager(storage).bookmarks() == []
+
+
+class TestBookmarkHelpers:
+    """Test cases for the bookmark helper functions."""
+
+    def test_parse_tags(self):
+        """Test normalizing tags typed by the user."""
+        assert parse_tags(" Chat, wiki,,chat ") == ["chat", "wiki"]
+        assert parse_tags(["A", "b"]) == ["a", "b"]
+
+    def test_folder_label(self):
+        """Test the heading used for unsorted bookmarks."""
+        assert folder_label("") == "Unsorted"
+        assert folder_label("Mesh") == "Mesh"
+
+    def test_from_dict_ignores_bad_fields(self):
+        """Test that malformed fields fall back to defaults."""
+        bookmark = Bookmark.from_dict(
+            {"address": "a:/page/index.mu", "tags": 5, "created": "x"},
+        )
+
+        assert bookmark.title == "a:/page/index.mu"
+        assert bookmark.tags == []
+        assert bookmark.created == 0.0
@@ -0,0 +1,413 @@
+import threading
+from unittest.mock import patch
+
+from ren_browser.pages.address import parse_address
+from ren_browser.pages.cache import CachedPage, PageCache, cache_limits
+from ren_browser.pages.disk_cache import DiskCache, disk_cache_limit
+from ren_browser.pages.prefetch import Prefetcher, prefetch_budget, prefetch_targets
+
+
+class TestPageCache:
+    """Test cases for the PageCache class."""
+
+    def test_put_and_get(self):
+        """Test storing and reading back a page."""
+        cache = PageCache()
+        cache.put("abc:/page/index.mu", "Hello")
+
+        entry = cache.get("abc:/page/index.mu")
+        assert entry.content == "Hello"
+        assert len(cache) == 1
+        assert cache.get("abc:/page/other.mu") is None
+
+    def test_expired_entries_only_returned_when_stale_allowed(self):
+        """Test that old pages are kept for stale reads only."""
+        cache = PageCache(max_age=10)
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            cache.put("abc:/page/index.mu", "Hello")
+
+        with patch("ren_browser.pages.cache.time.time", return_value=1011.0):
+            assert cache.get("abc:/page/index.mu") is None
+            stale = cache.get("abc:/page/index.mu", allow_stale=True)
+
+        assert stale.content == "Hello"
+
+    def test_revalidate_keeps_unchanged_page(self):
+        """Test that an unchanged page is kept and counts as fresh again."""
+        cache = PageCache(max_age=10)
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            cache.put("abc:/page/index.mu", "Hello")
+        entry = cache.get("abc:/page/index.mu", allow_stale=True)
+
+        with patch("ren_browser.pages.cache.time.time", return_value=1011.0):
+            assert cache.revalidate("abc:/page/index.mu", "Hello") is False
+            assert cache.get("abc:/page/index.mu") is entry
+            assert cache.revalidate("abc:/page/index.mu", "Hello again") is True
+            changed = cache.get("abc:/page/index.mu")
+
+        assert changed.content == "Hello again"
+        assert changed.digest != entry.digest
+        assert cache.revalidate("abc:/page/other.mu", "Other") is True
+
+    def test_hit_statistics(self):
+        """Test that fresh lookups count as hits and the rest as misses."""
+        cache = PageCache(max_age=10)
+        assert cache.hit_ratio() is None
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            cache.put("a:/page/index.mu", "A")
+            cache.put("b:/page/index.mu", "B")
+            cache.get("a:/page/index.mu")
+            cache.get("a:/page/index.mu")
+            cache.get("c:/page/index.mu")
+            cache.get("b:/page/index.mu", record=False)
+        with patch("ren_browser.pages.cache.time.time", return_value=1011.0):
+            cache.get("b:/page/index.mu")
+            cache.get("b:/page/index.mu", allow_stale=True)
+
+        assert (cache.hits, cache.misses) == (2, 2)
+        assert cache.hit_ratio() == 0.5
+        assert [(address, entry.hits) for address, entry in cache.entries()] == [
+            ("b:/page/index.mu", 0),
+            ("a:/page/index.mu", 2),
+        ]
+
+    def test_clear_since(self):
+        """Test that clearing a time range keeps older pages."""
+        cache = PageCache()
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            cache.put("abc:/page/old.mu", "Old")
+        with patch("ren_browser.pages.cache.time.time", return_value=2000.0):
+            cache.put("abc:/page/new.mu", "New")
+
+        cache.clear(since=1500.0)
+
+        assert cache.get("abc:/page/old.mu", allow_stale=True) is not None
+        assert cache.get("abc:/page/new.mu", allow_stale=True) is None
+
+    def test_remove_and_clear(self):
+        """Test forgetting cached pages."""
+        cache = PageCache()
+        cache.put("a:/page/index.mu", "A")
+        cache.put("b:/page/index.mu", "B")
+
+        cache.remove("a:/page/index.mu")
+        assert cache.get("a:/page/index.mu") is None
+
+        cache.clear()
+        assert len(cache) == 0
+
+    def test_pages_are_held_compressed(self):
+        """Test that a repetitive page is stored compressed and read back."""
+        art = "=-" * 2000 + "\n"
+        cache = PageCache()
+        cache.put("a:/page/index.mu", art)
+        cache.put("b:/page/index.mu", "Hi")
+
+        assert cache.get("a:/page/index.mu").content == art
+        assert cache.get("a:/page/index.mu").compressed
+        assert not cache.get("b:/page/index.mu").compressed
+        assert cache.size() < len(art) // 5
+
+    def test_cached_page_age(self):
+        """Test computing the age of a cached page."""
+        assert CachedPage("x", 100.0).age(now=130.0) == 30.0
+
+    def test_evicts_least_recently_used_over_page_limit(self):
+        """Test that the page least recently read is dropped first."""
+        cache = PageCache(max_entries=2)
+        cache.put("a:/page/index.mu", "A")
+        cache.put("b:/page/index.mu", "B")
+        cache.get("a:/page/index.mu")
+
+        cache.put("c:/page/index.mu", "C")
+
+        assert cache.get("b:/page/index.mu", allow_stale=True) is None
+        assert cache.get("a:/page/index.mu") is not None
+        assert len(cache) == 2
+
+    def test_evicts_over_byte_limit_and_tracks_size(self):
+        """Test that the cache keeps its total size under the byte limit."""
+        cache = PageCache(max_bytes=10)
+        cache.put("a:/page/index.mu", "12345")
+        cache.put("b:/page/index.mu", "é2345")
+        assert cache.size() == 6
+        assert cache.get("a:/page/index.mu") is None
+
+        cache.put("b:/page/index.mu", "1")
+        assert cache.size() == 1
+        cache.remove("b:/page/index.mu")
+        assert cache.size() == 0
+
+    def test_keeps_a_page_larger_than_the_limit(self):
+        """Test that the newest page stays cached even when over the limit."""
+        cache = PageCache(max_bytes=2)
+        cache.put("a:/page/index.mu", "Hello")
+
+        assert cache.get("a:/page/index.mu").content == "Hello"
+
+    def test_configure_shrinks_cache(self):
+        """Test that lowering the limits evicts at once, and 0 means none."""
+        cache = PageCache()
+        for name in "abc":
+            cache.put(f"{name}:/page/index.mu", name)
+
+        cache.configure(max_age=60, max_entries=1, max_bytes=0)
+
+        assert len(cache) == 1
+        assert cache.max_age == 60
+        assert cache.get("c:/page/index.mu") is not None
+
+    def test_cache_limits_from_settings(self):
+        """Test reading the limits from the settings, ignoring bad values."""
+        assert cache_limits(
+            {"cache_max_age": 60, "cache_max_pages": 10, "cache_max_mb": 2},
+        ) == (60, 10, 2_000_000)
+        assert cache_limits({"cache_max_age": "soon", "cache_max_pages": -1}) == (
+            300,
+            500,
+            20_000_000,
+        )
+
+    def test_disabled_cache_keeps_nothing(self):
+        """Test that turning the cache off forgets pages and stores no new ones."""
+        cache = PageCache()
+        cache.put("a:/page/index.mu", "Hello")
+
+        cache.set_enabled(False)
+        cache.put("b:/page/index.mu", "World")
+
+        assert len(cache) == 0
+        assert cache.get("b:/page/index.mu") is None
+
+        cache.set_enabled(True)
+        cache.put("b:/page/index.mu", "World")
+        assert cache.get("b:/page/index.mu").content == "World"
+
+
+class TestDiskCache:
+    """Test cases for the DiskCache class."""
+
+    def test_pages_survive_a_restart(self, tmp_path):
+        """Test that stored pages are read back by a new cache."""
+        disk = DiskCache(tmp_path)
+        disk.put("a:/page/index.mu", "Hello", 1000.0)
+        disk.flush()
+
+        restarted = DiskCache(tmp_path)
+
+        assert restarted.get("a:/page/index.mu") == ("Hello", 1000.0)
+        assert len(restarted) == 1
+        assert restarted.size() == 5
+
+    def test_compressed_pages_survive_a_restart(self, tmp_path):
+        """Test that pages are compressed on disk and read back after a restart."""
+        art = "#" * 5000
+        disk = DiskCache(tmp_path)
+        disk.put("a:/page/index.mu", art, 1000.0)
+        disk.flush()
+
+        restarted = DiskCache(tmp_path)
+
+        assert restarted.path("a:/page/index.mu").stat().st_size < 100
+        assert restarted.size() < 100
+        assert restarted.get("a:/page/index.mu") == (art, 1000.0)
+
+    def test_corrupt_compressed_page_is_dropped(self, tmp_path):
+        """Test that a compressed file that cannot be read is forgotten."""
+        disk = DiskCache(tmp_path)
+        disk.put("a:/page/index.mu", "#" * 5000, 1000.0)
+        disk.flush()
+        disk.path("a:/page/index.mu").write_bytes(b"not zlib")
+
+        assert DiskCache(tmp_path).get("a:/page/index.mu") is None
+
+    def test_evicts_least_recently_used_over_size_cap(self, tmp_path):
+        """Test that the least recently used pages are dropped first."""
+        disk = DiskCache(tmp_path, max_bytes=10)
+        disk.put("a:/page/index.mu", "AAAA", 1.0)
+        disk.put("b:/page/index.mu", "BBBB", 2.0)
+        disk.get("a:/page/index.mu")
+        disk.put("c:/page/index.mu", "CCCC", 3.0)
+        disk.flush()
+
+        assert disk.get("b:/page/index.mu") is None
+        assert not disk.path("b:/page/index.mu").exists()
+        assert disk.get("a:/page/index.mu") == ("AAAA", 1.0)
+        assert disk.size() == 8
+
+    def test_reading_without_use_keeps_eviction_order(self, tmp_path):
+        """Test that a page read without using it is still evicted first."""
+        disk = DiskCache(tmp_path, max_bytes=10)
+        disk.put("a:/page/index.mu", "AAAA", 1.0)
+        disk.put("b:/page/index.mu", "BBBB", 2.0)
+
+        assert disk.get("a:/page/index.mu", use=False) == ("AAAA", 1.0)
+        assert disk.addresses() == ["b:/page/index.mu", "a:/page/index.mu"]
+        disk.put("c:/page/index.mu", "CCCC", 3.0)
+        disk.flush()
+
+        assert disk.get("a:/page/index.mu") is None
+
+    def test_touch_survives_restart(self, tmp_path):
+        """Test that revalidating a page stores its new fetch time."""
+        cache = PageCache(disk=DiskCache(tmp_path))
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            cache.put("a:/page/index.mu", "Hello")
+        with patch("ren_browser.pages.cache.time.time", return_value=2000.0):
+            cache.revalidate("a:/page/index.mu", "Hello")
+        cache.disk.flush()
+
+        assert DiskCache(tmp_path).get("a:/page/index.mu") == ("Hello", 2000.0)
+
+    def test_get_before_written(self, tmp_path):
+        """Test that a page still being written is read from memory."""
+        written = threading.Event()
+        disk = DiskCache(tmp_path)
+        with patch(
+            "ren_browser.pages.disk_cache._write",
+            side_effect=lambda path, data: written.wait(5),
+        ):
+            disk.put("a:/page/index.mu", "Hello", 1.0)
+
+            assert disk.get("a:/page/index.mu") == ("Hello", 1.0)
+            written.set()
+            disk.flush()
+
+    def test_clear_since_and_missing_files(self, tmp_path):
+        """Test that clearing keeps older pages and lost files are forgotten."""
+        disk = DiskCache(tmp_path)
+        disk.put("a:/page/old.mu", "Old", 1000.0)
+        disk.put("a:/page/new.mu", "New", 2000.0)
+        disk.put("a:/page/gone.mu", "Gone", 500.0)
+        disk.clear(since=1500.0)
+        disk.flush()
+        disk.path("a:/page/gone.mu").unlink()
+
+        assert disk.get("a:/page/new.mu") is None
+        assert disk.get("a:/page/gone.mu") is None
+        assert disk.get("a:/page/old.mu") == ("Old", 1000.0)
+        assert len(disk) == 1
+
+    def test_no_size_means_memory_only(self, tmp_path):
+        """Test that a cap of 0 drops stored pages and stores no new ones."""
+        disk = DiskCache(tmp_path)
+        disk.put("a:/page/index.mu", "Hello", 1.0)
+
+        disk.configure(0)
+        disk.put("b:/page/index.mu", "World", 2.0)
+        disk.flush()
+
+        assert len(DiskCache(tmp_path)) == 0
+        assert disk_cache_limit({"cache_disk_mb": 0}) == 0
+        assert disk_cache_limit({"cache_disk_mb": "big"}) == 100_000_000
+
+    def test_page_cache_reads_from_disk(self, tmp_path):
+        """Test that pages missing from memory are loaded from disk."""
+        cache = PageCache(max_age=60, disk=DiskCache(tmp_path))
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            cache.put("a:/page/index.mu", "Hello")
+        cache.disk.flush()
+
+        restarted = PageCache(max_age=60, disk=DiskCache(tmp_path))
+        with patch("ren_browser.pages.cache.time.time", return_value=1030.0):
+            entry = restarted.get("a:/page/index.mu")
+        assert entry.content == "Hello"
+        assert entry.fetched_at == 1000.0
+        assert len(restarted) == 1
+
+        restarted.set_enabled(False)
+        restarted.disk.flush()
+        assert len(DiskCache(tmp_path)) == 0
+
+
+NODE = "0123456789abcdef0123456789abcdef"
+
+
+class TestPrefetcher:
+    """Test cases for prefetching linked pages."""
+
+    def test_budget_from_settings(self):
+        """Test that prefetching is off unless turned on."""
+        assert prefetch_budget({}) == 0
+        assert prefetch_budget({"prefetch_links": True}) == 500_000
+        assert prefetch_budget({"prefetch_links": True, "prefetch_kb": 20}) == 20_000
+        assert prefetch_budget({"prefetch_links": True, "prefetch_kb": "x"}) == 500_000
+
+    def test_targets_on_same_node(self):
+        """Test that only pages on the same node are prefetched, once each."""
+        other = "f" * 32
+        address = parse_address(f"{NODE}:/page/index.mu")
+        content = (
+            "`[News`:/page/news.mu] `[Again`:/page/news.mu]\n"
+            f"`[Elsewhere`{other}:/page/index.mu]\n"
+            "`[Home`:/page/index.mu] `[File`:/file/notes.txt]\n"
+            f"`[Absolute`{NODE}:/page/about.mu]"
+        )
+
+        assert prefetch_targets(address, content) == [
+            f"{NODE}:/page/news.mu",
+            f"{NODE}:/page/about.mu",
+        ]
+
+    def test_fetches_uncached_pages_into_cache(self):
+        """Test that linked pages are fetched once into the cache."""
+        cache = PageCache()
+        cache.put(f"{NODE}:/page/a.mu", "Cached")
+        fetched = []
+
+        def fetch(address):
+            fetched.append(address)
+            return f"Page {address}"
+
+        prefetcher = Prefetcher(fetch, cache, lambda work: work(), budget=1000)
+        prefetcher.prefetch([f"{NODE}:/page/a.mu", f"{NODE}:/page/b.mu"])
+
+        assert fetched == [f"{NODE}:/page/b.mu"]
+        assert cache.get(f"{NODE}:/page/b.mu").content == f"Page {NODE}:/page/b.mu"
+        assert prefetcher.pending() == 0
+
+    def test_stops_at_budget_and_skips_failures(self):
+        """Test that fetching stops once the budget is spent."""
+        fetched = []
+
+        def fetch(address):
+            fetched.append(address)
+            if address.endswith("a.mu"):
+                raise OSError("no path")
+            return "x" * 600
+
+        prefetcher = Prefetcher(fetch, PageCache(), lambda work: work(), budget=1000)
+        prefetcher.prefetch([f"{NODE}:/page/{name}.mu" for name in "abcd"])
+
+        assert [address[-4:] for address in fetched] == ["a.mu", "b.mu", "c.mu"]
+
+    def test_limits_concurrent_fetches(self):
+        """Test that no more workers start than the concurrency limit."""
+        workers = []
+        prefetcher = Prefetcher(
+            lambda address: "",
+            PageCache(),
+            workers.append,
+            budget=1000,
+            max_concurrent=2,
+        )
+        prefetcher.prefetch([f"{NODE}:/page/{name}.mu" for name in "abc"])
+        prefetcher.prefetch([f"{NODE}:/page/{name}.mu" for name in "de"])
+
+        assert len(workers) == 2
+        assert prefetcher.pending() == 2
+        prefetcher.cancel()
+        workers[0]()
+        assert prefetcher.pending() == 0
+
+    def test_nothing_fetched_when_off(self):
+        """Test that a zero budget or disabled cache fetches nothing."""
+        workers = []
+        cache = PageCache()
+        prefetcher = Prefetcher(lambda address: "", cache, workers.append)
+        prefetcher.prefetch([f"{NODE}:/page/a.mu"])
+        prefetcher.configure(1000)
+        cache.set_enabled(False)
+        prefetcher.prefetch([f"{NODE}:/page/a.mu"])
+
+        assert workers == []
@@ -0,0 +1,61 @@
+from unittest.mock import Mock
+
+from ren_browser.pages.charset import decode_text, detect_charset
+from ren_browser.ui.encoding import AUTOMATIC, EncodingDialog
+
+
+class TestCharset:
+    """Test cases for detecting and decoding page encodings."""
+
+    def test_utf_8_is_detected_first(self):
+        """Test that valid UTF-8 is taken as UTF-8."""
+        assert detect_charset("Grüße".encode()) == "utf-8"
+        assert detect_charset(b"plain") == "utf-8"
+
+    def test_legacy_encodings(self):
+        """Test that other text is taken as Windows-1252, else Latin-1."""
+        assert detect_charset("café “quoted”".encode("cp1252")) == "cp1252"
+        assert detect_charset(b"\x81\xe9") == "iso-8859-1"
+
+    def test_byte_order_marks(self):
+        """Test that a byte order mark names the encoding and is dropped."""
+        assert decode_text("﻿Hi".encode()) == "Hi"
+        assert decode_text("Hi".encode("utf-16")) == "Hi"
+
+    def test_decoding_is_lossy(self):
+        """Test that bytes not in the chosen encoding are replaced."""
+        assert decode_text(b"caf\xe9", "utf-8") == "caf�"
+        assert decode_text(b"caf\xe9") == "café"
+        assert decode_text(b"caf\xe9", "no-such-codec") == "café"
+
+
+class TestEncodingDialog:
+    """Test cases for choosing the encoding of a tab."""
+
+    def _dialog(self, mock_page, tab):
+        tab_manager = Mock()
+        tab_manager.manager.tabs = [tab]
+        return EncodingDialog(mock_page, tab_manager), tab_manager
+
+    def test_open_shows_the_tab_encoding(self, mock_page):
+        """Test that the tab's encoding, or automatic, is checked."""
+        dialog, _ = self._dialog(mock_page, {"encoding": "koi8-r"})
+        dialog.open(0)
+        assert dialog.choices.value == "koi8-r"
+
+        dialog, _ = self._dialog(mock_page, {})
+        dialog.open(0)
+        assert dialog.choices.value == AUTOMATIC
+
+    def test_choosing_sets_the_encoding(self, mock_page):
+        """Test that a choice is applied to the tab, automatic as None."""
+        dialog, tab_manager = self._dialog(mock_page, {})
+        dialog.open(0)
+
+        dialog.choices.value = "cp1251"
+        dialog.apply()
+        tab_manager.set_encoding.assert_called_with(0, "cp1251")
+
+        dialog.choices.value = AUTOMATIC
+        dialog.apply()
+        tab_manager.set_encoding.assert_called_with(0, None)
@@ -0,0 +1,113 @@
+from types import SimpleNamespace
+from unittest.mock import Mock
+
+import flet as ft
+import pytest
+
+from ren_browser.controls.chrome_navigation import ChromeNavigator
+from ren_browser.controls.dialogs import close_top_dialog, open_dialog
+
+
+@pytest.fixture
+def tab_manager():
+    tab_manager = Mock(keyboard=None)
+    tab_manager.manager = SimpleNamespace(
+        tabs=[{"url_field": Mock()}, {"url_field": Mock()}, {"url_field": Mock()}],
+        index=0,
+    )
+    tab_manager.tab_bar.content.controls = [ft.Container() for _ in range(3)]
+
+    def select_tab(index):
+        tab_manager.manager.index = index
+
+    tab_manager.select_tab = Mock(side_effect=select_tab)
+    return tab_manager
+
+
+class TestChromeNavigator:
+    """Test cases for moving the keyboard around the window."""
+
+    def test_f6_cycles_through_regions(self, tab_manager):
+        """Test that the regions follow each other and wrap around."""
+        navigator = ChromeNavigator(tab_manager)
+
+        navigator.cycle(1)
+        assert navigator.region == "address"
+        tab_manager.manager.tabs[0]["url_field"].focus.assert_called_once()
+        navigator.cycle(1)
+        navigator.cycle(1)
+        assert navigator.region == "nodes"
+        tab_manager.sidebar.set_collapsed.assert_called_once_with(False)
+        tab_manager.announce_list.move_highlight.assert_called_once_with(0)
+        navigator.cycle(1)
+        assert navigator.region == "page"
+        tab_manager.announce_list.clear_highlight.assert_called_once()
+        navigator.cycle(-1)
+        assert navigator.region == "nodes"
+
+    def test_nodes_skipped_without_sidebar(self, tab_manager):
+        """Test that the node list is not a region when there is none."""
+        tab_manager.announce_list = None
+        navigator = ChromeNavigator(tab_manager)
+
+        assert navigator.regions() == ["page", "address", "tabs"]
+
+    def test_arrow_keys_switch_tabs(self, tab_manager):
+        """Test that the tab bar switches tabs and outlines the shown one."""
+        navigator = ChromeNavigator(tab_manager)
+        navigator.enter("tabs")
+        containers = tab_manager.tab_bar.content.controls
+
+        assert navigator.handle_key("Arrow Left")
+        assert tab_manager.manager.index == 2
+        assert navigator.handle_key("Home")
+        assert tab_manager.manager.index == 0
+        assert containers[0].border is not None
+        assert not navigator.handle_key("a")
+
+        assert navigator.handle_key("Delete")
+        tab_manager._on_close_click.assert_called_once_with(None)
+        assert navigator.handle_key("Escape")
+        assert navigator.region == "page"
+
+    def test_node_list_keys(self, tab_manager):
+        """Test that the node list moves its outline and opens nodes."""
+        navigator = ChromeNavigator(tab_manager)
+        navigator.enter("nodes")
+        announce_list = tab_manager.announce_list
+
+        navigator.handle_key("Arrow Down")
+        navigator.handle_key("Page Up")
+        navigator.handle_key("Enter")
+
+        moves = announce_list.move_highlight.call_args_list
+        assert [call.args for call in moves] == [(0,), (1,), (-10,)]
+        announce_list.activate_highlight.assert_called_once()
+
+    def test_keys_left_alone_on_page_and_in_fields(self, tab_manager):
+        """Test that keys are not taken on the page or while a field is typed in."""
+        navigator = ChromeNavigator(tab_manager)
+
+        assert not navigator.handle_key("Arrow Down")
+
+        navigator.enter("tabs")
+        tab_manager.keyboard = Mock(focused=Mock())
+        assert not navigator.handle_key("Arrow Left")
+
+
+class TestDialogs:
+    """Test cases for closing dialogs with Escape."""
+
+    def test_escape_closes_top_dialog(self):
+        """Test that the dialog opened last is closed first."""
+        page = Mock()
+        first, second = Mock(open=True), Mock(open=True)
+        open_dialog(page, first)
+        open_dialog(page, second)
+
+        assert close_top_dialog(page)
+        page.close.assert_called_once_with(second)
+
+        first.open = False
+        assert not close_top_dialog(page)
+        assert page.open.call_count == 2
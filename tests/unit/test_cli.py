@@ -0,0 +1,133 @@
+import io
+from unittest.mock import Mock, patch
+
+import pytest
+
+from ren_browser.cli.fetch import EXIT_FAILED, EXIT_USAGE, fetch
+
+NODE = "a" * 32
+
+
+class TestFetchCommand:
+    """Test cases for fetching pages without the window."""
+
+    @pytest.fixture
+    def reticulum(self):
+        """Start and stop a fake Reticulum."""
+        with (
+            patch(
+                "ren_browser.cli.fetch.rns.initialize_reticulum",
+                return_value=True,
+            ) as start,
+            patch("ren_browser.cli.fetch.rns.shutdown_reticulum") as stop,
+        ):
+            yield start, stop
+
+    def run(self, argv, fetch_page=None, fetch_file=None):
+        stdout = io.StringIO()
+        stderr = io.StringIO()
+        with (
+            patch(
+                "ren_browser.cli.fetch.PageFetcher.fetch_page",
+                fetch_page or Mock(return_value=">Title\n`!Bold`! text\n"),
+            ),
+            patch(
+                "ren_browser.cli.fetch.PageFetcher.fetch_file",
+                fetch_file or Mock(return_value=(b"file data", "notes.txt")),
+            ),
+        ):
+            status = fetch(argv, stdout=stdout, stderr=stderr)
+        return status, stdout.getvalue(), stderr.getvalue()
+
+    def test_prints_raw_page(self, reticulum):
+        """Test that the page markup is written to stdout as fetched."""
+        status, out, err = self.run([f"{NODE}:/page/index.mu"])
+
+        assert status == 0
+        assert out == ">Title\n`!Bold`! text\n"
+        assert err == ""
+        start, stop = reticulum
+        start.assert_called_once_with(None)
+        stop.assert_called_once()
+
+    def test_render_prints_plain_text(self, reticulum):
+        """Test that --render prints micron pages as the text they read as."""
+        status, out, _ = self.run([f"{NODE}:/page/index.mu", "--render"])
+
+        assert status == 0
+        assert "`" not in out
+        assert "Title" in out
+        assert "Bold text" in out
+
+    def test_fetches_files(self, reticulum):
+        """Test that file addresses are downloaded instead of fetched as pages."""
+        status, out, _ = self.run([f"ren://{NODE}/file/notes.txt"])
+
+        assert status == 0
+        assert out == "file data"
+
+    def test_passes_options_to_fetch(self, reticulum):
+        """Test that the config directory and path refresh are used."""
+        fetch_page = Mock(return_value="page")
+        self.run(
+            [NODE, "-c", "/tmp/rns", "--refresh-path"],
+            fetch_page=fetch_page,
+        )
+
+        reticulum[0].assert_called_once_with("/tmp/rns")
+        req = fetch_page.call_args[0][0]
+        assert req.destination_hash == NODE
+        assert req.page_path == "/page/index.mu"
+        assert fetch_page.call_args[1]["refresh_path"] is True
+
+    def test_verbose_reports_phases(self, reticulum):
+        """Test that --verbose reports load progress on stderr only."""
+
+        def fetch_page(req, on_progress=None, refresh_path=False):
+            on_progress("link", None)
+            return "page"
+
+        status, out, err = self.run([NODE, "-v"], fetch_page=fetch_page)
+
+        assert status == 0
+        assert out == "page"
+        assert err.strip()
+
+    def test_bad_address_is_usage_error(self, reticulum):
+        """Test that an address that does not parse exits with a usage error."""
+        status, out, err = self.run(["not a node"])
+
+        assert status == EXIT_USAGE
+        assert out == ""
+        assert "ren-browser fetch:" in err
+        reticulum[0].assert_not_called()
+
+    def test_fetch_failure(self, reticulum):
+        """Test that a failed fetch is reported and Reticulum still shut down."""
+        status, out, err = self.run(
+            [NODE],
+            fetch_page=Mock(side_effect=RuntimeError("Node unreachable")),
+        )
+
+        assert status == EXIT_FAILED
+        assert out == ""
+        assert "Node unreachable" in err
+        reticulum[1].assert_called_once()
+
+    def test_reticulum_failure(self):
+        """Test that the command fails when Reticulum does not start."""
+        with (
+            patch(
+                "ren_browser.cli.fetch.rns.initialize_reticulum",
+                return_value=False,
+            ),
+            patch(
+                "ren_browser.cli.fetch.rns.get_last_error",
+                return_value="No interfaces",
+            ),
+        ):
+            status, out, err = self.run([NODE])
+
+        assert status == EXIT_FAILED
+        assert out == ""
+        assert "No interfaces" in err
@@ -0,0 +1,143 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.controls.context_menu import ContextMenu, build_actions
+
+
+class TestBuildActions:
+    """Test cases for choosing context menu entries."""
+
+    def test_link_actions_need_a_link(self):
+        """Test that link entries are disabled away from links."""
+        actions = {a.action: a.enabled for a in build_actions(None, False)}
+
+        assert actions["copy"] is True
+        assert actions["select_all"] is True
+        assert actions["copy_link"] is False
+        assert actions["open_link"] is False
+        assert actions["view_source"] is False
+        assert actions["encoding"] is False
+        assert actions["read_later"] is False
+        assert actions["annotate"] is False
+        assert actions["save_page"] is False
+        assert actions["export_pdf"] is False
+        assert actions["print"] is False
+        assert actions["pin_speed_dial"] is False
+        assert actions["watch"] is False
+        assert actions["follow"] is False
+        assert actions["show_qr"] is False
+
+    def test_link_and_source_actions_enabled(self):
+        """Test that link and source entries are enabled when applicable."""
+        actions = {a.action: a.enabled for a in build_actions(":/page/a.mu", True)}
+
+        assert actions["copy_link"] is True
+        assert actions["open_link"] is True
+        assert actions["view_source"] is True
+        assert actions["encoding"] is True
+        assert actions["read_later"] is True
+        assert actions["annotate"] is True
+        assert actions["save_page"] is True
+        assert actions["export_pdf"] is True
+        assert actions["print"] is True
+        assert actions["pin_speed_dial"] is True
+        assert actions["watch"] is True
+        assert actions["follow"] is True
+        assert actions["show_qr"] is True
+
+    def test_watch_label_follows_state(self):
+        """Test that watched pages offer to stop watching."""
+        labels = {
+            a.action: a.label
+            for a in build_actions(None, True, watched=True, following=True)
+        }
+
+        assert labels["watch"] == "Stop Watching"
+        assert labels["follow"] == "Unfollow Node"
+
+
+class TestContextMenu:
+    """Test cases for the ContextMenu class."""
+
+    @pytest.fixture
+    def tab_manager(self):
+        """Create a mock tab manager showing a fetched page."""
+        manager = Mock()
+        manager.manager.index = 0
+        manager.manager.tabs = [{"source": "Hello", "address": "abc:/page/index.mu"}]
+        manager.hovered_link = None
+        manager.current_hash.return_value = "abc"
+        manager.page_text.return_value = "Hello"
+        return manager
+
+    @pytest.fixture
+    def context_menu(self, mock_page, tab_manager):
+        """Create a ContextMenu instance for testing."""
+        mock_page.overlay = []
+        return ContextMenu(mock_page, tab_manager)
+
+    def test_show_positions_menu(self, context_menu, mock_page):
+        """Test that the menu opens at the pointer position."""
+        context_menu.show(120, 80)
+
+        assert context_menu.is_open is True
+        assert context_menu.menu.left == 120
+        assert context_menu.menu.top == 80
+        assert len(context_menu.menu.content.controls) == 15
+        assert context_menu.overlay in mock_page.overlay
+
+    def test_copy_link_resolves_relative_links(self, context_menu, tab_manager):
+        """Test that Copy Link Address copies the absolute address."""
+        tab_manager.hovered_link = ":/page/about.mu"
+        context_menu.show(0, 0)
+
+        context_menu.run("copy_link")
+
+        context_menu.page.set_clipboard.assert_called_once_with("abc:/page/about.mu")
+        assert context_menu.is_open is False
+
+    def test_open_link_in_new_tab(self, context_menu, tab_manager):
+        """Test that Open Link in New Tab hands the link to the tab manager."""
+        tab_manager.hovered_link = "def:/page/index.mu"
+        context_menu.show(0, 0)
+
+        context_menu.run("open_link")
+
+        tab_manager.open_link_in_new_tab.assert_called_once_with("def:/page/index.mu")
+
+    def test_copy_and_view_source(self, context_menu, tab_manager):
+        """Test copying page text and viewing the page source."""
+        context_menu.show(0, 0)
+        context_menu.run("copy")
+        tab_manager.copy_page_text.assert_called_once_with(0)
+
+        context_menu.run("view_source")
+        tab_manager.view_source.assert_called_once_with(0)
+
+        context_menu.run("read_later")
+        tab_manager.read_later.assert_called_once_with(0)
+
+        context_menu.run("save_page")
+        tab_manager.save_page_as.assert_called_once_with(0)
+
+        context_menu.run("export_pdf")
+        tab_manager.export_pdf.assert_called_once_with(0)
+
+        context_menu.run("print")
+        tab_manager.print_page.assert_called_once_with(0)
+
+        context_menu.run("pin_speed_dial")
+        tab_manager.pin_to_speed_dial.assert_called_once_with(0)
+
+        context_menu.run("watch")
+        tab_manager.toggle_watch.assert_called_once_with(0)
+
+        context_menu.run("follow")
+        tab_manager.toggle_follow.assert_called_once_with(0)
+
+        context_menu.run("annotate")
+        tab_manager.annotate_page.assert_called_once_with(0)
+
+        context_menu.run("encoding")
+        tab_manager.choose_encoding.assert_called_once_with(0)
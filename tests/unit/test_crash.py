@@ -0,0 +1,166 @@
+import datetime
+import sys
+import threading
+from unittest.mock import Mock, patch
+
+from ren_browser.crash.crash import (
+    MAX_REPORTS,
+    CrashHandler,
+    format_report,
+    open_addresses,
+    take_pending_report,
+    write_report,
+)
+from ren_browser.logs import LogEntry
+
+NOW = datetime.datetime(2026, 10, 14, 12, 30, 5)
+
+
+def _error():
+    try:
+        raise RuntimeError("render failed")
+    except RuntimeError as exc:
+        return type(exc), exc, exc.__traceback__
+
+
+def _tab_manager(settings, tabs):
+    tab_manager = Mock(settings=settings)
+    tab_manager.manager.tabs = tabs
+    return tab_manager
+
+
+class TestCrashReports:
+    """Test cases for writing crash reports."""
+
+    def test_report_has_traceback_version_and_logs(self):
+        """Test that a report describes the error and what led up to it."""
+        entries = [LogEntry("12:30:04", "error", "app", "Page failed")]
+        with (
+            patch("ren_browser.crash.crash.recent_logs", return_value=entries),
+            patch("ren_browser.crash.crash.browser_version", return_value="0.2.2"),
+        ):
+            text = format_report(*_error(), ["abc:/page/index.mu"], now=NOW)
+
+        assert text.startswith("Ren Browser crash report\n")
+        assert "Time: 2026-10-14T12:30:05" in text
+        assert "Version: 0.2.2" in text
+        assert "RuntimeError: render failed" in text
+        assert "Traceback (most recent call last)" in text
+        assert "  abc:/page/index.mu" in text
+        assert "[12:30:04] ERROR app: Page failed" in text
+
+    def test_tabs_left_out_without_consent(self):
+        """Test that open tabs are only included when allowed."""
+        with patch("ren_browser.crash.crash.recent_logs", return_value=[]):
+            text = format_report(*_error(), None, now=NOW)
+
+        assert "(not included, see Settings → Privacy)" in text
+
+    def test_open_addresses_needs_consent(self):
+        """Test that tab addresses are given only if allowed, never private ones."""
+        tabs = [
+            {"address": "abc:/page/index.mu"},
+            {"address": "def:/page/secret.mu", "private": True},
+            {"address": None},
+        ]
+
+        assert open_addresses(_tab_manager({}, tabs)) is None
+        assert open_addresses(_tab_manager({"crash_report_tabs": True}, tabs)) == [
+            "abc:/page/index.mu",
+        ]
+
+    def test_pending_report_is_offered_once(self, tmp_path):
+        """Test that the next launch finds the last report only once."""
+        path = write_report(tmp_path, "report", now=NOW)
+
+        assert path.name == "crash-20261014-123005.txt"
+        assert path.read_text(encoding="utf-8") == "report"
+        assert take_pending_report(tmp_path) == path
+        assert take_pending_report(tmp_path) is None
+        assert path.exists()
+
+    def test_old_reports_are_removed(self, tmp_path):
+        """Test that only the newest reports are kept."""
+        for minute in range(MAX_REPORTS + 2):
+            write_report(tmp_path, "report", now=NOW.replace(minute=minute))
+
+        reports = sorted(tmp_path.glob("crash-*.txt"))
+        assert len(reports) == MAX_REPORTS
+        assert reports[0].name == "crash-20261014-120205.txt"
+
+    def test_no_pending_report(self, tmp_path):
+        """Test that a browser that never crashed has nothing to offer."""
+        assert take_pending_report(tmp_path / "crashes") is None
+
+
+class TestCrashHandler:
+    """Test cases for reporting errors nothing caught."""
+
+    def test_install_hooks_and_uninstall(self, tmp_path):
+        """Test that the hooks are replaced and put back."""
+        hook, thread_hook = sys.excepthook, threading.excepthook
+        handler = CrashHandler(tmp_path)
+
+        handler.install()
+        try:
+            assert sys.excepthook == handler._on_exception
+            assert threading.excepthook == handler._on_thread_exception
+        finally:
+            handler.uninstall()
+
+        assert sys.excepthook is hook
+        assert threading.excepthook is thread_hook
+
+    def test_uncaught_error_writes_report(self, tmp_path):
+        """Test that an error nothing caught is reported and passed on."""
+        handler = CrashHandler(tmp_path)
+        handler.tabs = lambda: ["abc:/page/index.mu"]
+        previous = Mock()
+        handler._previous_hook = previous
+
+        handler._on_exception(*_error())
+
+        report = take_pending_report(tmp_path)
+        assert "RuntimeError: render failed" in report.read_text(encoding="utf-8")
+        assert "abc:/page/index.mu" in report.read_text(encoding="utf-8")
+        previous.assert_called_once()
+
+    def test_thread_errors_are_reported(self, tmp_path):
+        """Test that errors ending other threads are reported too."""
+        handler = CrashHandler(tmp_path)
+        handler._previous_thread_hook = Mock()
+        exc_type, exc, tb = _error()
+
+        handler._on_thread_exception(
+            Mock(exc_type=exc_type, exc_value=exc, exc_traceback=tb),
+        )
+
+        assert take_pending_report(tmp_path) is not None
+
+    def test_interrupts_are_not_crashes(self, tmp_path):
+        """Test that stopping the browser with Ctrl+C writes no report."""
+        handler = CrashHandler(tmp_path)
+
+        assert handler.report(KeyboardInterrupt, KeyboardInterrupt(), None) is None
+        assert take_pending_report(tmp_path) is None
+
+    def test_failing_tab_list_still_reports(self, tmp_path):
+        """Test that a crash is reported even if the tabs cannot be listed."""
+        handler = CrashHandler(tmp_path)
+        handler.tabs = Mock(side_effect=RuntimeError("tabs gone"))
+
+        report = handler.report(*_error())
+
+        assert "(not included" in report.read_text(encoding="utf-8")
+
+    def test_event_loop_errors_are_reported(self, tmp_path):
+        """Test that errors nothing caught in the event loop are reported."""
+        handler = CrashHandler(tmp_path)
+        loop = Mock()
+        _, exc, _ = _error()
+        context = {"message": "Task exception was never retrieved", "exception": exc}
+
+        handler.handle_loop_exception(loop, context)
+
+        assert take_pending_report(tmp_path) is not None
+        loop.default_exception_handler.assert_called_once_with(context)
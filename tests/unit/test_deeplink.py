@@ -0,0 +1,88 @@
+import json
+import threading
+from unittest.mock import patch
+
+import pytest
+
+from ren_browser.deeplink.deeplink import HandoffServer, hand_off, register_url_scheme
+
+
+class TestHandoff:
+    """Test cases for handing addresses to a running instance."""
+
+    @pytest.fixture
+    def server(self, tmp_path):
+        """Start a handoff server that records the addresses it receives."""
+        received = []
+        done = threading.Event()
+
+        def on_address(address):
+            received.append(address)
+            done.set()
+
+        server = HandoffServer(tmp_path / "instance.json", on_address)
+        assert server.start()
+        server.received = received
+        server.done = done
+        yield server
+        server.stop()
+
+    def test_address_reaches_running_instance(self, server):
+        """Test that a later launch hands its address to the running browser."""
+        assert hand_off(server.path, "ren://abc/page/index.mu") is True
+
+        assert server.done.wait(2)
+        assert server.received == ["ren://abc/page/index.mu"]
+
+    def test_wrong_token_is_refused(self, server):
+        """Test that connections without the published token are ignored."""
+        server.path.write_text(
+            json.dumps({"port": server.port, "token": "guess"}),
+            encoding="utf-8",
+        )
+
+        assert hand_off(server.path, "ren://abc") is False
+        assert server.received == []
+
+    def test_no_running_instance(self, tmp_path):
+        """Test that handing off fails cleanly when nothing is running."""
+        assert hand_off(tmp_path / "instance.json", "ren://abc") is False
+
+    def test_stop_withdraws_published_port(self, tmp_path):
+        """Test that stopping the server removes its instance file."""
+        server = HandoffServer(tmp_path / "instance.json", lambda address: None)
+        assert server.start()
+
+        server.stop()
+
+        assert not server.path.exists()
+        assert hand_off(server.path, "ren://abc") is False
+
+
+class TestRegisterUrlScheme:
+    """Test cases for registering the ren:// scheme handler."""
+
+    def test_writes_desktop_entry_on_linux(self, tmp_path, monkeypatch):
+        """Test that Linux gets a desktop entry handling x-scheme-handler/ren."""
+        monkeypatch.setenv("XDG_DATA_HOME", str(tmp_path))
+        with (
+            patch("sys.platform", "linux"),
+            patch("shutil.which", lambda name: f"/usr/bin/{name}"),
+            patch("subprocess.run") as mock_run,
+        ):
+            message = register_url_scheme()
+
+        entry = tmp_path / "applications" / "ren-browser.desktop"
+        text = entry.read_text(encoding="utf-8")
+        assert "MimeType=x-scheme-handler/ren;" in text
+        assert "Exec=/usr/bin/ren-browser %U" in text
+        assert str(entry) in message
+        assert mock_run.call_args[0][0][-2:] == [
+            "ren-browser.desktop",
+            "x-scheme-handler/ren",
+        ]
+
+    def test_macos_needs_app_bundle(self):
+        """Test that macOS reports the scheme comes from the app bundle."""
+        with patch("sys.platform", "darwin"), pytest.raises(OSError):
+            register_url_scheme()
@@ -0,0 +1,208 @@
+import threading
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.downloads.downloads import (
+    DownloadManager,
+    is_file_address,
+    unique_path,
+)
+from ren_browser.pages.page_request import PageFetchError
+
+HASH = "0123456789abcdef0123456789abcdef"
+
+
+def _storage(downloads=None):
+    storage = Mock()
+    storage.load_downloads.return_value = downloads or []
+    return storage
+
+
+class TestDownloadHelpers:
+    """Test cases for the download helper functions."""
+
+    def test_is_file_address(self):
+        """Test recognizing file paths served by nodes."""
+        assert is_file_address("/file/archive.zip")
+        assert not is_file_address("/page/index.mu")
+
+    def test_unique_path(self, tmp_path):
+        """Test that existing files are not overwritten."""
+        (tmp_path / "a.zip").write_bytes(b"")
+        (tmp_path / "a (1).zip").write_bytes(b"")
+
+        assert unique_path(tmp_path, "a.zip") == tmp_path / "a (2).zip"
+        assert unique_path(tmp_path, "b.zip") == tmp_path / "b.zip"
+
+
+class TestDownloadManager:
+    """Test cases for the DownloadManager class."""
+
+    @pytest.fixture
+    def fetch_file(self):
+        """Create a fake transfer that reports progress and returns data."""
+
+        def fetch(req, on_progress=None, cancel=None):
+            on_progress("transfer", 0.5)
+            return b"file data", None
+
+        return Mock(side_effect=fetch)
+
+    @pytest.fixture
+    def manager(self, tmp_path, fetch_file):
+        """Create a download manager that runs transfers synchronously."""
+        return DownloadManager(
+            _storage(),
+            tmp_path,
+            run_thread=lambda fn: fn(),
+            fetch_file=fetch_file,
+        )
+
+    def test_download_completes(self, manager, tmp_path):
+        """Test that a finished download is written to the directory."""
+        listener = Mock()
+        manager.add_listener(listener)
+
+        download = manager.start(f"{HASH}:/file/notes.txt")
+
+        assert download.status == "completed"
+        assert download.progress == 1.0
+        assert download.size == 9
+        assert (tmp_path / "notes.txt").read_bytes() == b"file data"
+        assert download.path == str(tmp_path / "notes.txt")
+        assert listener.call_count >= 3
+
+    def test_completion_listeners(self, manager, fetch_file):
+        """Test that completion listeners hear of completed downloads only."""
+        listener = Mock()
+        manager.add_completion_listener(listener)
+
+        download = manager.start(f"{HASH}:/file/notes.txt")
+        fetch_file.side_effect = PageFetchError("timeout", "Timed out")
+        manager.start(f"{HASH}:/file/other.txt")
+
+        listener.assert_called_once_with(download)
+
+    def test_failure_listeners(self, manager, fetch_file):
+        """Test that failure listeners hear of failed downloads only."""
+        listener = Mock()
+        manager.add_failure_listener(listener)
+
+        manager.start(f"{HASH}:/file/notes.txt")
+        fetch_file.side_effect = PageFetchError("timeout", "Timed out")
+        failed = manager.start(f"{HASH}:/file/other.txt")
+
+        listener.assert_called_once_with(failed)
+
+    def test_uses_name_sent_by_node(self, manager, fetch_file, tmp_path):
+        """Test that the file name from the node's metadata is used."""
+        fetch_file.side_effect = lambda req, **kwargs: (b"x", "report.pdf")
+
+        download = manager.start(f"{HASH}:/file/1234")
+
+        assert download.name == "report.pdf"
+        assert (tmp_path / "report.pdf").exists()
+
+    def test_download_to_chosen_file(self, manager, tmp_path):
+        """Test that a file chosen before the transfer is written to."""
+        target = tmp_path / "saved" / "copy.bin"
+
+        download = manager.start(f"{HASH}:/file/notes.txt", target)
+
+        assert download.name == "copy.bin"
+        assert download.path == str(target)
+        assert target.read_bytes() == b"file data"
+
+    def test_configure_directory(self, manager, tmp_path):
+        """Test that a chosen folder replaces the default until cleared."""
+        manager.configure(str(tmp_path / "elsewhere"))
+        manager.start(f"{HASH}:/file/notes.txt")
+        assert (tmp_path / "elsewhere" / "notes.txt").exists()
+
+        manager.configure("")
+        assert manager.directory == tmp_path
+
+    def test_failed_download(self, manager, fetch_file):
+        """Test that transfer errors are recorded on the download."""
+        fetch_file.side_effect = PageFetchError("timeout", "Timed out")
+
+        download = manager.start(f"{HASH}:/file/notes.txt")
+
+        assert download.status == "failed"
+        assert download.error == "Timed out"
+
+    def test_pause_resume_and_cancel(self, tmp_path):
+        """Test controlling a download that is still transferring."""
+        workers = []
+        fetch_file = Mock(return_value=(b"data", None))
+        manager = DownloadManager(
+            _storage(),
+            tmp_path,
+            run_thread=workers.append,
+            fetch_file=fetch_file,
+        )
+        download = manager.start(f"{HASH}:/file/notes.txt")
+        first_cancel = download.cancel
+
+        manager.pause(download.id)
+        assert download.status == "paused"
+        assert first_cancel.is_set()
+        workers.pop(0)()
+        assert download.status == "paused"
+
+        manager.resume(download.id)
+        assert download.status == "downloading"
+        assert not download.cancel.is_set()
+
+        manager.cancel(download.id)
+        assert download.status == "cancelled"
+        workers.pop(0)()
+        assert not (tmp_path / "notes.txt").exists()
+
+    def test_history_is_persisted(self, tmp_path, fetch_file):
+        """Test that downloads are saved and interrupted ones marked failed."""
+        storage = _storage()
+        manager = DownloadManager(
+            storage,
+            tmp_path,
+            run_thread=lambda fn: None,
+            fetch_file=fetch_file,
+        )
+        manager.start(f"{HASH}:/file/notes.txt")
+        saved = storage.save_downloads.call_args[0][0]
+        assert saved[0]["status"] == "downloading"
+
+        restored = DownloadManager(
+            _storage(saved),
+            tmp_path,
+            run_thread=lambda fn: None,
+            fetch_file=fetch_file,
+        )
+
+        download = restored.downloads()[0]
+        assert download.status == "failed"
+        assert download.error == "Interrupted"
+        assert isinstance(download.cancel, threading.Event)
+        assert restored.start(f"{HASH}:/file/b.txt").id == download.id + 1
+
+    def test_remove_and_clear_finished(self, manager):
+        """Test removing finished downloads from the history."""
+        first = manager.start(f"{HASH}:/file/a.txt")
+        manager.start(f"{HASH}:/file/b.txt")
+
+        manager.remove(first.id)
+        assert [d.name for d in manager.downloads()] == ["b.txt"]
+
+        manager.clear_finished()
+        assert manager.downloads() == []
+
+    def test_clear_finished_since(self, manager):
+        """Test that clearing a time range keeps older downloads."""
+        old = manager.start(f"{HASH}:/file/a.txt")
+        manager.start(f"{HASH}:/file/b.txt")
+        old.started = 100.0
+
+        manager.clear_finished(since=150.0)
+
+        assert [d.name for d in manager.downloads()] == ["a.txt"]
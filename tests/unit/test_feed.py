@@ -0,0 +1,154 @@
+from unittest.mock import Mock
+
+from ren_browser.feed.feed import NodeFeed, node_pages
+
+NODE = "abc"
+INDEX = f"{NODE}:/page/index.mu"
+INDEX_SOURCE = (
+    ">Hilltop\n"
+    "`[News`:/page/news.mu]\n"
+    "`[Weather`:/page/weather.mu]\n"
+    "`[Elsewhere`def:/page/index.mu]\n"
+    "`[Report`:/file/report.pdf]\n"
+    "`[News again`:/page/news.mu]\n"
+)
+
+
+def _storage(feed=None):
+    return Mock(load_feed=Mock(return_value=feed or {}))
+
+
+def _feed(pages, storage=None):
+    """Build a feed serving content from a dict of address to content."""
+    feed = NodeFeed(storage or _storage(), lambda address: pages[address])
+    feed.follow(NODE, "Hilltop")
+    return feed
+
+
+def _pages():
+    return {
+        INDEX: INDEX_SOURCE,
+        f"{NODE}:/page/news.mu": ">Today's News\nNothing yet",
+        f"{NODE}:/page/weather.mu": "Sunny",
+    }
+
+
+class TestNodePages:
+    """Test cases for finding a node's pages on its index."""
+
+    def test_only_pages_on_the_same_node(self):
+        """Test that other nodes, files and duplicates are left out."""
+        assert node_pages(NODE, INDEX_SOURCE, 10) == [
+            (f"{NODE}:/page/news.mu", "News"),
+            (f"{NODE}:/page/weather.mu", "Weather"),
+        ]
+
+    def test_limit(self):
+        """Test that at most limit pages are returned."""
+        assert len(node_pages(NODE, INDEX_SOURCE, 1)) == 1
+
+
+class TestNodeFeed:
+    """Test cases for the NodeFeed class."""
+
+    def test_first_check_records_baseline(self):
+        """Test that existing pages are not reported when a node is followed."""
+        feed = _feed(_pages())
+
+        assert feed.check(NODE, now=100.0) == []
+        assert len(feed.get(NODE).pages) == 3
+        assert feed.get(NODE).checked_at == 100.0
+
+    def test_new_and_changed_pages(self):
+        """Test that new links and changed content are added to the feed."""
+        pages = _pages()
+        feed = _feed(pages)
+        feed.check(NODE, now=100.0)
+        pages[INDEX] = INDEX_SOURCE + "`[Events`:/page/events.mu]\n"
+        pages[f"{NODE}:/page/events.mu"] = ">Market Day"
+        pages[f"{NODE}:/page/news.mu"] = ">Today's News\nThe bridge is open"
+
+        found = feed.check(NODE, now=200.0)
+
+        kinds = {item.title: item.kind for item in found}
+        assert kinds == {
+            "Hilltop": "changed",
+            "Today's News": "changed",
+            "Market Day": "new",
+        }
+        assert feed.unread_count() == 3
+
+    def test_items_newest_first_and_replaced(self):
+        """Test that a page changing again moves to the top of the feed."""
+        pages = _pages()
+        feed = _feed(pages)
+        feed.check(NODE, now=100.0)
+        pages[f"{NODE}:/page/news.mu"] = "Update 1"
+        feed.check(NODE, now=200.0)
+        pages[f"{NODE}:/page/weather.mu"] = "Rain"
+        feed.check(NODE, now=300.0)
+        pages[f"{NODE}:/page/news.mu"] = "Update 2"
+        feed.check(NODE, now=400.0)
+
+        assert [(i.address, i.found_at) for i in feed.items()] == [
+            (f"{NODE}:/page/news.mu", 400.0),
+            (f"{NODE}:/page/weather.mu", 300.0),
+        ]
+
+    def test_mark_read(self):
+        """Test that reading the feed clears the unread count."""
+        pages = _pages()
+        feed = _feed(pages)
+        feed.check(NODE, now=100.0)
+        pages[f"{NODE}:/page/news.mu"] = "Update"
+        feed.check(NODE, now=200.0)
+
+        feed.mark_read(now=250.0)
+
+        assert feed.unread_count() == 0
+
+    def test_failed_index_fetch_is_recorded(self):
+        """Test that an unreachable node keeps its known pages."""
+        feed = _feed({})
+
+        assert feed.check_all() == []
+        assert feed.get(NODE).error is not None
+
+    def test_unfollow_drops_items(self):
+        """Test that unfollowing a node removes its items from the feed."""
+        pages = _pages()
+        feed = _feed(pages)
+        feed.check(NODE, now=100.0)
+        pages[f"{NODE}:/page/news.mu"] = "Update"
+        feed.check(NODE, now=200.0)
+
+        feed.unfollow(NODE)
+
+        assert feed.nodes() == []
+        assert feed.items() == []
+
+    def test_round_trips_through_storage(self):
+        """Test that followed nodes and items are restored on the next run."""
+        storage = _storage()
+        pages = _pages()
+        feed = _feed(pages, storage)
+        feed.check(NODE, now=100.0)
+        pages[f"{NODE}:/page/news.mu"] = "Update"
+        feed.check(NODE, now=200.0)
+        saved = storage.save_feed.call_args[0][0]
+
+        restored = NodeFeed(_storage(saved), Mock())
+
+        assert restored.get(NODE).name == "Hilltop"
+        assert restored.get(NODE).pages == feed.get(NODE).pages
+        assert [i.address for i in restored.items()] == [f"{NODE}:/page/news.mu"]
+
+    def test_malformed_storage_is_ignored(self):
+        """Test that a corrupt feed file starts with nothing followed."""
+        feed = NodeFeed(
+            _storage({"nodes": ["oops", {"name": "no hash"}], "items": [None]}),
+            Mock(),
+        )
+
+        assert feed.nodes() == []
+        assert feed.items() == []
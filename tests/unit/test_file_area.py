@@ -0,0 +1,58 @@
+from types import SimpleNamespace
+
+from ren_browser.file_area.file_area import NodeFile, node_files, page_links
+
+NODE = "ab" * 16
+OTHER = "cd" * 16
+
+
+class TestNodeFiles:
+    """Test cases for gathering the files a node links to."""
+
+    def test_files_linked_from_pages(self):
+        """Test that /file/ links on the node's pages are listed once each."""
+        index = "`[Manual`:/file/manual.pdf]\n`[About`:/page/a.mu]"
+        pages = [
+            (f"{NODE}:/page/index.mu", index),
+            (f"{NODE}:/page/a.mu", f"`[Again`{NODE}:/file/manual.pdf]"),
+        ]
+
+        files = node_files(NODE, pages)
+
+        assert files == [NodeFile(f"{NODE}:/file/manual.pdf", "manual.pdf", "Manual")]
+
+    def test_other_nodes_are_left_out(self):
+        """Test that files of other nodes and their pages are not listed."""
+        pages = [
+            (f"{NODE}:/page/index.mu", f"`[Theirs`{OTHER}:/file/x.zip]"),
+            (f"{OTHER}:/page/index.mu", "`[Mine`:/file/y.zip]"),
+        ]
+
+        assert node_files(NODE, pages) == []
+
+    def test_sizes_from_downloads(self):
+        """Test that files downloaded before show their size."""
+        address = f"{NODE}:/file/map.png"
+        downloads = [
+            SimpleNamespace(address=address, status="completed", size=2048),
+            SimpleNamespace(address=address, status="failed", size=None),
+        ]
+        pages = [(f"{NODE}:/page/index.mu", "`[`:/file/map.png]")]
+
+        files = node_files(NODE, pages, downloads)
+
+        assert files[0].size == 2048
+
+    def test_files_are_sorted_by_name(self):
+        """Test that the list is ordered by file name."""
+        content = "`[`:/file/b.txt]\n`[`:/file/A.txt]"
+
+        files = node_files(NODE, [(f"{NODE}:/page/index.mu", content)])
+
+        assert [file.name for file in files] == ["A.txt", "b.txt"]
+
+    def test_markdown_pages(self):
+        """Test that links on Markdown pages are found too."""
+        links = page_links("[Notes](../file/notes.txt)", "/page/readme.md")
+
+        assert links == [("Notes", ":/file/notes.txt")]
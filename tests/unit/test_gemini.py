@@ -0,0 +1,147 @@
+import hashlib
+from unittest.mock import Mock, patch
+
+import pytest
+
+from ren_browser.gemini.gemini import (
+    GeminiClient,
+    GeminiError,
+    GeminiInput,
+    host_key,
+    is_gemini_address,
+    join_url,
+    parse_header,
+)
+
+URL = "gemini://example.org/index.gmi"
+
+
+def _client(*responses):
+    """Build a client answering each request with the next response."""
+    client = GeminiClient()
+    client.request = Mock(side_effect=list(responses))
+    return client
+
+
+class TestGeminiAddresses:
+    """Test cases for Gemini address handling."""
+
+    def test_is_gemini_address(self):
+        """Test that only gemini:// addresses are recognized."""
+        assert is_gemini_address(" GEMINI://example.org/")
+        assert not is_gemini_address("abc:/page/index.mu")
+
+    def test_join_url_resolves_relative_links(self):
+        """Test that relative links resolve against the page address."""
+        assert join_url(URL, "about.gmi") == "gemini://example.org/about.gmi"
+        assert join_url(URL, "/") == "gemini://example.org/"
+
+    def test_host_key_defaults_port(self):
+        """Test that certificates are remembered per host and port."""
+        assert host_key(URL) == "example.org:1965"
+        assert host_key("gemini://example.org:1966/") == "example.org:1966"
+
+    def test_parse_header(self):
+        """Test that the status and meta are split from the header."""
+        assert parse_header(b"20 text/gemini\r") == (20, "text/gemini")
+
+    def test_parse_header_rejects_garbage(self):
+        """Test that an invalid header is reported as a Gemini error."""
+        with pytest.raises(GeminiError):
+            parse_header(b"HTTP/1.1 200 OK")
+
+
+class TestGeminiClient:
+    """Test cases for the GeminiClient class."""
+
+    def test_fetch_decodes_body(self):
+        """Test that a success response is decoded with its charset."""
+        body = "Grüße".encode("latin-1")
+        client = _client((20, "text/gemini; charset=latin-1", body))
+
+        response = client.fetch(URL)
+
+        assert response.url == URL
+        assert response.body == "Grüße"
+
+    def test_fetch_detects_undeclared_charset(self):
+        """Test that a body without a charset is decoded as detected."""
+        client = _client((20, "text/gemini", "Grüße".encode("cp1252")))
+
+        assert client.fetch(URL).body == "Grüße"
+
+    def test_fetch_in_chosen_encoding(self):
+        """Test that a chosen encoding wins over the charset of the server."""
+        body = "Привет".encode("koi8-r")
+        client = _client((20, "text/gemini; charset=utf-8", body))
+
+        assert client.fetch(URL, "koi8-r").body == "Привет"
+
+    def test_fetch_follows_redirects(self):
+        """Test that redirects are followed to the final address."""
+        client = _client((31, "/moved.gmi", b""), (20, "text/gemini", b"# Moved"))
+
+        response = client.fetch(URL)
+
+        assert response.url == "gemini://example.org/moved.gmi"
+        assert response.body == "# Moved"
+
+    def test_fetch_stops_redirect_loops(self):
+        """Test that a page redirecting forever fails."""
+        client = _client(*[(30, URL, b"")] * 10)
+
+        with pytest.raises(GeminiError, match="too many"):
+            client.fetch(URL)
+
+    def test_fetch_asks_for_input(self):
+        """Test that an input status is raised with its prompt."""
+        client = _client((11, "Password", b""))
+
+        with pytest.raises(GeminiInput) as exc_info:
+            client.fetch(URL)
+
+        assert exc_info.value.prompt == "Password"
+        assert exc_info.value.sensitive is True
+
+    def test_fetch_reports_failures(self):
+        """Test that an error status is reported with the server's message."""
+        client = _client((51, "Not found", b""))
+
+        with pytest.raises(GeminiError, match="51 Not found"):
+            client.fetch(URL)
+
+    def test_first_certificate_is_trusted(self):
+        """Test that the certificate seen first is remembered."""
+        on_trust = Mock()
+        client = GeminiClient(on_trust=on_trust)
+
+        client._check_certificate(URL, b"cert")
+
+        fingerprint = hashlib.sha256(b"cert").hexdigest()
+        on_trust.assert_called_once_with({"example.org:1965": fingerprint})
+
+    def test_changed_certificate_is_refused(self):
+        """Test that a different certificate than the remembered one fails."""
+        client = GeminiClient({"example.org:1965": "other"})
+
+        with pytest.raises(GeminiError) as exc_info:
+            client._check_certificate(URL, b"cert")
+
+        assert exc_info.value.kind == "certificate"
+
+    def test_forget_trusts_next_certificate(self):
+        """Test that a forgotten host accepts its new certificate."""
+        client = GeminiClient({"example.org:1965": "other"})
+
+        client.forget("example.org:1965")
+        client._check_certificate(URL, b"cert")
+
+        assert client.known_hosts["example.org:1965"] != "other"
+
+    def test_unreachable_server(self):
+        """Test that a connection failure is reported as a Gemini error."""
+        with patch(
+            "ren_browser.gemini.gemini.socket.create_connection",
+            side_effect=ConnectionRefusedError("refused"),
+        ), pytest.raises(GeminiError, match="Cannot reach example.org"):
+            GeminiClient().request(URL)
@@ -0,0 +1,74 @@
+from types import SimpleNamespace
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.controls.gestures import PULL_DISTANCE, TouchGestures
+
+
+@pytest.fixture
+def tab_manager():
+    manager = Mock()
+    manager.manager = SimpleNamespace(tabs=[{}, {}, {}], index=1)
+    return manager
+
+
+class TestTouchGestures:
+    """Test cases for swipe and pull gestures."""
+
+    def test_swiping_content_goes_back_and_forward(self, tab_manager):
+        """Test that fast sideways swipes on the page navigate its history."""
+        gestures = TouchGestures(tab_manager)
+
+        gestures._on_content_swipe(Mock(primary_velocity=1200.0))
+        tab_manager.go_back.assert_called_once_with(1)
+
+        gestures._on_content_swipe(Mock(primary_velocity=-1200.0))
+        tab_manager.go_forward.assert_called_once_with(1)
+
+        gestures._on_content_swipe(Mock(primary_velocity=100.0))
+        assert tab_manager.go_back.call_count == 1
+
+    def test_swiping_tab_bar_switches_tabs(self, tab_manager):
+        """Test that swiping the tab bar selects the neighbouring tab."""
+        gestures = TouchGestures(tab_manager)
+
+        gestures._on_tab_bar_swipe(Mock(primary_velocity=-900.0))
+        tab_manager.select_tab.assert_called_with(2)
+
+        gestures._on_tab_bar_swipe(Mock(primary_velocity=900.0))
+        tab_manager.select_tab.assert_called_with(0)
+
+        gestures._on_tab_bar_swipe(Mock(primary_velocity=None))
+        assert tab_manager.select_tab.call_count == 2
+
+    def test_pulling_past_top_reloads_once(self, tab_manager):
+        """Test that pulling the page down past its top reloads it once."""
+        gestures = TouchGestures(tab_manager)
+
+        def over(overscroll):
+            gestures.on_scroll(
+                Mock(event_type="over", pixels=0.0, overscroll=overscroll),
+            )
+
+        over(-PULL_DISTANCE / 2)
+        tab_manager.reload.assert_not_called()
+        over(-PULL_DISTANCE / 2)
+        tab_manager.reload.assert_called_once_with(1)
+        over(-PULL_DISTANCE)
+        assert tab_manager.reload.call_count == 1
+
+        gestures.on_scroll(Mock(event_type="end", pixels=0.0))
+        gestures.on_scroll(Mock(event_type="update", pixels=-PULL_DISTANCE - 1))
+        assert tab_manager.reload.call_count == 2
+
+    def test_scrolling_down_does_not_reload(self, tab_manager):
+        """Test that ordinary scrolling and overscroll at the end are ignored."""
+        gestures = TouchGestures(tab_manager)
+
+        gestures.on_scroll(Mock(event_type="update", pixels=300.0))
+        gestures.on_scroll(
+            Mock(event_type="over", pixels=900.0, overscroll=PULL_DISTANCE * 2),
+        )
+
+        tab_manager.reload.assert_not_called()
@@ -0,0 +1,217 @@
+from datetime import date, datetime
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.history.history import (
+    HistoryEntry,
+    HistoryManager,
+    NodeStats,
+    day_label,
+    group_by_day,
+    parse_day,
+    visit_summary,
+)
+
+
+def _storage(history=None):
+    storage = Mock()
+    storage.load_history.return_value = history or []
+    return storage
+
+
+class TestHistoryManager:
+    """Test cases for the HistoryManager class."""
+
+    def test_record_saves_entry(self):
+        """Test that recording a visit persists it."""
+        storage = _storage()
+        history = HistoryManager(storage)
+
+        history.record("abc:/page/index.mu", "Home", "abc", timestamp=100.0)
+
+        storage.save_history.assert_called_once_with(
+            [
+                {
+                    "address": "abc:/page/index.mu",
+                    "title": "Home",
+                    "timestamp": 100.0,
+                    "node_hash": "abc",
+                },
+            ],
+        )
+
+    def test_loads_stored_entries_and_skips_malformed(self):
+        """Test loading history saved by a previous session."""
+        history = HistoryManager(
+            _storage(
+                [
+                    {"address": "a:/page/index.mu", "title": "A", "timestamp": 1},
+                    {"title": "no address"},
+                    "not a dict",
+                ],
+            ),
+        )
+
+        entries = history.entries()
+        assert len(entries) == 1
+        assert entries[0].address == "a:/page/index.mu"
+
+    def test_entries_most_recent_first(self):
+        """Test that entries are ordered newest first."""
+        history = HistoryManager(_storage())
+        history.record("a:/page/index.mu", "A", timestamp=1.0)
+        history.record("b:/page/index.mu", "B", timestamp=2.0)
+
+        assert [e.title for e in history.entries()] == ["B", "A"]
+
+    def test_history_is_capped(self):
+        """Test that only the most recent visits are kept."""
+        history = HistoryManager(_storage(), max_entries=2)
+        for i in range(3):
+            history.record(f"{i}:/page/index.mu", str(i), timestamp=float(i))
+
+        assert [e.title for e in history.entries()] == ["2", "1"]
+
+    def test_clear(self):
+        """Test forgetting all history."""
+        storage = _storage()
+        history = HistoryManager(storage)
+        history.record("a:/page/index.mu", "A")
+
+        history.clear()
+
+        assert history.entries() == []
+        storage.save_history.assert_called_with([])
+
+    def test_clear_since(self):
+        """Test that clearing a time range keeps older visits."""
+        history = HistoryManager(_storage())
+        history.record("a:/page/index.mu", "Old", timestamp=100.0)
+        history.record("b:/page/index.mu", "New", timestamp=200.0)
+
+        history.clear(since=150.0)
+
+        assert [e.title for e in history.entries()] == ["Old"]
+
+    def test_unreadable_storage_starts_empty(self):
+        """Test that a storage failure does not break the browser."""
+        storage = Mock()
+        storage.load_history.side_effect = TypeError("bad data")
+
+        assert HistoryManager(storage).entries() == []
+
+
+class TestNodeStats:
+    """Test cases for per-node visit statistics."""
+
+    def test_node_stats_counts_visits_per_node(self):
+        """Test that visits are counted and the latest one is kept."""
+        history = HistoryManager(_storage())
+        history.record("abc:/page/index.mu", "Home", "abc", timestamp=100.0)
+        history.record("abc:/page/about.mu", "About", "abc", timestamp=300.0)
+        history.record("def:/page/index.mu", "Other", "def", timestamp=200.0)
+        history.record("about:history", "History", None, timestamp=400.0)
+
+        stats = history.node_stats()
+
+        assert stats == {
+            "abc": NodeStats(visits=2, last_visit=300.0),
+            "def": NodeStats(visits=1, last_visit=200.0),
+        }
+
+    def test_visit_summary(self):
+        """Test the visit line shown under a node's name."""
+        assert visit_summary(None) is None
+        assert visit_summary(NodeStats(1, 1000.0), now=1030.0) == "1 visit, last just now"
+        assert visit_summary(NodeStats(3, 1000.0), now=1000.0 + 7200) == (
+            "3 visits, last 2 h ago"
+        )
+        assert visit_summary(NodeStats(2, 0.0), now=3 * 86400) == (
+            "2 visits, last 3 d ago"
+        )
+
+
+class TestHistorySearch:
+    """Test cases for searching the history."""
+
+    def _history(self):
+        history = HistoryManager(_storage())
+        history.record(
+            "abc:/page/index.mu",
+            "Weather Report",
+            "abc",
+            timestamp=datetime(2024, 3, 1, 9).timestamp(),
+        )
+        history.record(
+            "def:/page/news.mu",
+            "Daily News",
+            "def",
+            timestamp=datetime(2024, 3, 5, 9).timestamp(),
+        )
+        return history
+
+    def test_search_by_title_and_address(self):
+        """Test that every word must match the title or address."""
+        history = self._history()
+
+        assert [e.title for e in history.search("weather")] == ["Weather Report"]
+        assert [e.title for e in history.search("news.mu")] == ["Daily News"]
+        assert history.search("weather news") == []
+        assert len(history.search("")) == 2
+
+    def test_search_by_node_name(self):
+        """Test that visits can be found by the name of the node."""
+        history = self._history()
+        names = {"def": "Hilltop Relay"}
+
+        results = history.search("hilltop", node_name=names.get)
+
+        assert [e.address for e in results] == ["def:/page/news.mu"]
+
+    def test_search_by_date_range(self):
+        """Test that start and end days are inclusive."""
+        history = self._history()
+
+        assert [e.title for e in history.search(start=date(2024, 3, 2))] == [
+            "Daily News",
+        ]
+        assert [e.title for e in history.search(end=date(2024, 3, 1))] == [
+            "Weather Report",
+        ]
+        assert len(history.search(start=date(2024, 3, 1), end=date(2024, 3, 5))) == 2
+
+    def test_parse_day(self):
+        """Test parsing days typed into the history filter."""
+        assert parse_day(" 2024-03-01 ") == date(2024, 3, 1)
+        assert parse_day("") is None
+        with pytest.raises(ValueError):
+            parse_day("March 1st")
+
+
+class TestHistoryGrouping:
+    """Test cases for grouping history by day."""
+
+    def test_group_by_day(self):
+        """Test that visits on the same day are grouped together."""
+        day_one = datetime(2024, 5, 1, 9, 0).timestamp()
+        day_one_later = datetime(2024, 5, 1, 18, 0).timestamp()
+        day_two = datetime(2024, 5, 2, 12, 0).timestamp()
+        entries = [
+            HistoryEntry("c", "C", day_two),
+            HistoryEntry("b", "B", day_one_later),
+            HistoryEntry("a", "A", day_one),
+        ]
+
+        groups = group_by_day(entries)
+
+        assert [day for day, _ in groups] == [date(2024, 5, 2), date(2024, 5, 1)]
+        assert [e.title for e in groups[1][1]] == ["B", "A"]
+
+    def test_day_label(self):
+        """Test the headings used for days of history."""
+        today = date(2024, 5, 2)
+
+        assert day_label(date(2024, 5, 2), today) == "Today"
+        assert day_label(date(2024, 5, 1), today) == "Yesterday"
+        assert day_label(date(2024, 4, 30), today) == "Tuesday, 30 April 2024"
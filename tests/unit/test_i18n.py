@@ -0,0 +1,81 @@
+import tomllib
+
+import pytest
+
+from ren_browser.history.history import NodeStats, visit_summary
+from ren_browser.i18n import i18n
+from ren_browser.i18n.i18n import (
+    available_languages,
+    current_language,
+    ntr,
+    set_language,
+    tr,
+)
+
+
+@pytest.fixture
+def german():
+    """Translate into German for one test."""
+    assert set_language("de") == "de"
+    yield
+    set_language("en")
+
+
+class TestTranslations:
+    """Test cases for translating interface strings."""
+
+    def test_english_is_the_code_text(self):
+        """Test that English strings are returned unchanged and filled in."""
+        assert tr("Settings") == "Settings"
+        assert tr("Loading {url}", url="abc:/") == "Loading abc:/"
+        assert ntr("1 page", "{count} pages", 1) == "1 page"
+        assert ntr("1 page", "{count} pages", 3) == "3 pages"
+
+    def test_german(self, german):
+        """Test that strings, placeholders and plurals are translated."""
+        assert current_language() == "de"
+        assert tr("Settings") == "Einstellungen"
+        assert tr("Loading {url}", url="abc:/") == "Lade abc:/"
+        assert ntr("1 page", "{count} pages", 1) == "1 Seite"
+        assert ntr("1 page", "{count} pages", 3) == "3 Seiten"
+        assert visit_summary(NodeStats(2, 1000.0), now=1030.0) == (
+            "2 Besuche, zuletzt gerade eben"
+        )
+
+    def test_missing_translation_shows_english(self, german):
+        """Test that strings the catalog lacks fall back to the English text."""
+        assert tr("Not translated {n}", n=1) == "Not translated 1"
+        assert ntr("1 apple", "{count} apples", 2) == "2 apples"
+
+    def test_unknown_language_falls_back_to_english(self):
+        """Test that a language without translations means English."""
+        assert set_language("xx") == "en"
+        assert tr("Settings") == "Settings"
+
+    def test_system_language(self, monkeypatch):
+        """Test that the system default follows the environment."""
+        monkeypatch.setenv("LANGUAGE", "de_DE.UTF-8")
+        try:
+            assert set_language("") == "de"
+        finally:
+            set_language("en")
+
+    def test_available_languages(self):
+        """Test that the shipped catalogs are listed by their own names."""
+        languages = available_languages()
+
+        assert languages["en"] == "English"
+        assert languages["de"] == "Deutsch"
+
+    def test_catalogs_keep_placeholders(self):
+        """Test that translations use the same placeholders as the English."""
+        for path in i18n.LOCALES_DIR.glob("*.toml"):
+            catalog = tomllib.loads(path.read_text(encoding="utf-8"))
+            for message, translated in catalog.items():
+                if message == "meta" or not isinstance(translated, str):
+                    continue
+                assert _fields(translated) == _fields(message), message
+
+
+def _fields(text: str) -> set[str]:
+    return {part.split("}")[0] for part in text.split("{")[1:]}
@@ -0,0 +1,67 @@
+import struct
+
+import pytest
+
+from ren_browser.images.images import image_format, image_size, is_image_name
+
+PNG = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR" + struct.pack(">II", 640, 480)
+GIF = b"GIF89a" + struct.pack("<HH", 16, 8)
+# A JPEG with an APP0 segment before its start of frame
+JPEG = (
+    b"\xff\xd8\xff\xe0\x00\x04ab"
+    + b"\xff\xc0\x00\x11\x08"
+    + struct.pack(">HH", 200, 300)
+)
+
+
+def webp(chunk: bytes, payload: bytes) -> bytes:
+    return b"RIFF\x00\x00\x00\x00WEBP" + chunk + b"\x00\x00\x00\x00" + payload
+
+
+class TestImages:
+    """Test cases for recognising images and reading their size."""
+
+    @pytest.mark.parametrize(
+        ("name", "expected"),
+        [
+            ("map.png", True),
+            ("abc:/file/Photo.JPG", True),
+            ("scan.webp", True),
+            ("notes.txt", False),
+            ("archive", False),
+        ],
+    )
+    def test_is_image_name(self, name, expected):
+        """Test that images are recognised by their extension."""
+        assert is_image_name(name) is expected
+
+    @pytest.mark.parametrize(
+        ("data", "size"),
+        [
+            (PNG, (640, 480)),
+            (GIF, (16, 8)),
+            (JPEG, (300, 200)),
+            (
+                webp(b"VP8 ", b"\x00\x00\x00\x9d\x01\x2a" + struct.pack("<HH", 50, 70)),
+                (50, 70),
+            ),
+            (webp(b"VP8X", b"\x00" * 4 + (99).to_bytes(3, "little") * 2), (100, 100)),
+        ],
+    )
+    def test_image_size(self, data, size):
+        """Test that the size is read from the header of each format."""
+        assert image_size(data) == size
+
+    def test_lossless_webp_size(self):
+        """Test that the packed size of a lossless WebP is read."""
+        bits = (10 - 1) | (20 - 1) << 14
+        data = webp(b"VP8L", b"\x2f" + bits.to_bytes(4, "little"))
+
+        assert image_format(data) == "webp"
+        assert image_size(data) == (10, 20)
+
+    def test_unreadable_data(self):
+        """Test that other or truncated data has no size."""
+        assert image_size(b"hello") is None
+        assert image_size(PNG[:12]) is None
+        assert image_size(b"\xff\xd8\xff\xe0") is None
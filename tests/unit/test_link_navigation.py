@@ -0,0 +1,74 @@
+from types import SimpleNamespace
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.controls.link_navigation import LinkNavigator, page_links
+from ren_browser.renderer.micron import render_micron
+
+
+@pytest.fixture
+def tab():
+    content = render_micron("`[One`:/page/1.mu]\nText\n`[Two`:/page/2.mu] `[Three`:/3]")
+    tab = {"content_control": content, "content": Mock()}
+    for link in page_links(content):
+        link.focus = Mock()
+    return tab
+
+
+@pytest.fixture
+def navigator(tab):
+    tab_manager = Mock(keyboard=None)
+    tab_manager.manager = SimpleNamespace(tabs=[tab], index=0)
+    return LinkNavigator(tab_manager)
+
+
+class TestLinkNavigator:
+    """Test cases for moving between links with the keyboard."""
+
+    def test_page_links_in_reading_order(self, tab):
+        """Test that links are found in the order they appear."""
+        links = page_links(tab["content_control"])
+
+        assert [link.data for link in links] == [":/page/1.mu", ":/page/2.mu", ":/3"]
+
+    def test_tab_cycles_through_links(self, navigator, tab):
+        """Test that moving forward focuses each link and wraps around."""
+        links = page_links(tab["content_control"])
+
+        for expected in (0, 1, 2, 0):
+            assert navigator.move(1)
+            assert tab["focused_link"] is links[expected]
+        assert links[0].focus.call_count == 2
+        tab["content"].scroll_to.assert_called_with(key=links[0].key, duration=200)
+
+    def test_shift_tab_goes_back(self, navigator, tab):
+        """Test that moving backward starts from the last link."""
+        links = page_links(tab["content_control"])
+
+        navigator.move(-1)
+        assert tab["focused_link"] is links[2]
+        navigator.move(-1)
+        assert tab["focused_link"] is links[1]
+
+    def test_clicked_link_is_the_starting_point(self, navigator, tab):
+        """Test that a link focused by other means is moved on from."""
+        links = navigator.links(tab)
+        links[1].on_focus(None)
+
+        navigator.move(1)
+
+        assert tab["focused_link"] is links[2]
+
+    def test_focused_field_keeps_tab(self, navigator, tab):
+        """Test that Tab is left alone while a field on the page has focus."""
+        navigator.tab_manager.keyboard = Mock(focused=(Mock(), None))
+
+        assert navigator.move(1) is False
+        assert "focused_link" not in tab
+
+    def test_page_without_links(self, navigator, tab):
+        """Test that pages without links do not take the focus."""
+        tab["content_control"] = render_micron("Just text")
+
+        assert navigator.move(1) is False
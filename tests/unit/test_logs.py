@@ -1,4 +1,5 @@
 import datetime
+import logging
 from unittest.mock import Mock, patch
 
 from ren_browser import logs
@@ -12,6 +13,11 @@ class TestLogsModule:
         logs.APP_LOGS.clear()
         logs.ERROR_LOGS.clear()
         logs.RET_LOGS.clear()
+        logs.RECENT.clear()
+
+    def teardown_method(self):
+        """Stop writing any log file a test opened."""
+        logs.close_log_file()
 
     def test_initial_state(self):
         """Test that logs start empty."""
@@ -141,3 +147,101 @@ class TestLogsModule:
             logs.log_app(special_msg)
 
             assert logs.APP_LOGS[0] == f"[2023-01-01T12:00:00] {special_msg}"
+
+    def test_recent_entries_keep_level_and_source(self, mock_rns):
+        """Test that messages are kept with their level and source."""
+        logs._original_rns_log = Mock()
+        logs.log_app("Started")
+        logs.log_error("Page failed")
+        logs.log_ret("Path request timed out", 2)
+        logs.log_ret("Packet received", level=6)
+
+        assert [(e.level, e.source) for e in logs.RECENT] == [
+            ("info", "app"),
+            ("error", "app"),
+            ("warning", "reticulum"),
+            ("debug", "reticulum"),
+        ]
+        assert logs.RECENT[1].format().endswith("] ERROR app: Page failed")
+
+    def test_recent_logs_filters_by_level_and_text(self):
+        """Test that recent entries are filtered by level and search text."""
+        logs.log_app("Opened the settings")
+        logs.log_error("Failed to open the node")
+        logs.log_app("Closed a tab")
+
+        assert [e.message for e in logs.recent_logs({"info"}, "")] == [
+            "Opened the settings",
+            "Closed a tab",
+        ]
+        assert [e.message for e in logs.recent_logs(None, " OPEN ")] == [
+            "Opened the settings",
+            "Failed to open the node",
+        ]
+        assert logs.recent_logs(set(), "") == []
+
+    def test_recent_buffer_is_bounded(self):
+        """Test that only the latest messages are kept."""
+        for i in range(logs.MAX_RECENT + 5):
+            logs.log_app(f"Message {i}")
+
+        assert len(logs.RECENT) == logs.MAX_RECENT
+        assert logs.RECENT[0].message == "Message 5"
+
+    def test_log_file_gets_messages_from_its_level(self, tmp_path, mock_rns):
+        """Test that only messages of the level chosen or above are written."""
+        logs._original_rns_log = Mock()
+        path = logs.configure_log_file(tmp_path / "logs", "warning")
+        logs.log_app("Opened a tab")
+        logs.log_error("Failed to load page")
+        logs.log_ret("Path request timed out", 2)
+        logs.close_log_file()
+
+        assert path == tmp_path / "logs" / logs.LOG_FILE_NAME
+        lines = path.read_text(encoding="utf-8").splitlines()
+        assert [line.split("] ", 1)[1] for line in lines] == [
+            "ERROR app: Failed to load page",
+            "WARNING reticulum: Path request timed out",
+        ]
+
+    def test_log_file_is_rotated(self, tmp_path):
+        """Test that a full log file is rotated and old files are limited."""
+        logs.configure_log_file(tmp_path, "info", max_kb=1, count=1)
+        for i in range(40):
+            logs.log_app(f"Message {i} " + "x" * 50)
+        logs.close_log_file()
+
+        assert sorted(p.name for p in tmp_path.iterdir()) == [
+            "ren_browser.log",
+            "ren_browser.log.1",
+        ]
+        assert (tmp_path / "ren_browser.log").stat().st_size <= 1024
+
+    def test_log_file_off(self, tmp_path):
+        """Test that no file is written when the log file is off."""
+        assert logs.configure_log_file(tmp_path, "off") is None
+        logs.log_error("Failed to load page")
+
+        assert logs.log_file_path() is None
+        assert list(tmp_path.iterdir()) == []
+
+    def test_log_file_reconfigured_only_on_change(self, tmp_path):
+        """Test that applying the same options keeps the open file."""
+        logs.configure_log_file(tmp_path, "info")
+        handler = logs._log_file
+        logs.configure_log_file(tmp_path, "info")
+        assert logs._log_file is handler
+
+        logs.configure_log_file(tmp_path, "error")
+        assert logs._log_file is not handler
+        assert isinstance(logs._log_file, logging.Handler)
+
+    def test_log_file_options_use_defaults(self):
+        """Test that unknown log file settings fall back to the defaults."""
+        assert logs.log_file_options({}) == ("warning", 1024, 3)
+        assert logs.log_file_options(
+            {"log_file_level": "debug", "log_file_kb": 256, "log_file_count": 10},
+        ) == ("debug", 256, 10)
+        assert logs.log_file_options(
+            {"log_file_level": "loud", "log_file_kb": 7, "log_file_count": "3"},
+        ) == ("warning", 1024, 3)
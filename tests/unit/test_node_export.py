@@ -0,0 +1,103 @@
+import csv
+import io
+import json
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.announces.announces import Announce
+from ren_browser.nodes.export import FIELDS, node_records, to_csv, to_json
+from ren_browser.nodes.nodes import NodeMetadata
+from ren_browser.ui.node_export import NodeExport
+
+
+@pytest.fixture
+def nodes():
+    """Create node metadata with an alias, a note and an unheard favorite."""
+    nodes = NodeMetadata(Mock(load_node_metadata=Mock(return_value={})))
+    nodes.set_alias("abc", "Bakery", "NomadNet Node")
+    nodes.set_note("abc", 'sells "bread", mostly')
+    nodes.set_favorite("zzz", True, "Old Friend")
+    return nodes
+
+
+ANNOUNCES = [
+    Announce("abc", "NomadNet Node", 1700000100, first_seen=1700000000),
+    Announce("def", None, 1700000200, aspect="lxmf.delivery", announce_count=3),
+]
+
+
+class TestNodeRecords:
+    """Test cases for building node export records."""
+
+    def test_heard_then_recorded_nodes(self, nodes):
+        """Test that heard nodes come first, then ones only in the metadata."""
+        records = node_records(ANNOUNCES, nodes)
+
+        assert [r["destination_hash"] for r in records] == ["abc", "def", "zzz"]
+        assert list(records[0]) == list(FIELDS)
+        assert records[0]["alias"] == "Bakery"
+        assert records[0]["first_seen"] < records[0]["last_announce"]
+        assert records[1]["aspect"] == "lxmf.delivery"
+        assert records[1]["announce_count"] == 3
+        assert records[2]["name"] == "Old Friend"
+        assert records[2]["favorite"] is True
+        assert records[2]["last_announce"] is None
+
+
+class TestFormats:
+    """Test cases for the JSON and CSV formats."""
+
+    def test_json(self, nodes):
+        """Test that JSON is tagged with its format and keeps the records."""
+        records = node_records(ANNOUNCES, nodes)
+
+        data = json.loads(to_json(records))
+
+        assert data["format"] == "ren-browser-nodes"
+        assert data["nodes"] == records
+
+    def test_csv_quotes_and_blanks(self, nodes):
+        """Test that CSV has a header, quotes notes and leaves None blank."""
+        rows = list(csv.DictReader(io.StringIO(to_csv(node_records(ANNOUNCES, nodes)))))
+
+        assert len(rows) == 3
+        assert rows[0]["note"] == 'sells "bread", mostly'
+        assert rows[1]["name"] == ""
+        assert rows[2]["favorite"] == "True"
+
+
+class TestNodeExport:
+    """Test cases for the node export file dialog."""
+
+    @pytest.fixture
+    def export(self, mock_page, nodes):
+        """Create the export dialog for testing."""
+        mock_page.overlay = []
+        return NodeExport(mock_page, lambda: ANNOUNCES, nodes)
+
+    def test_picker_result_exports(self, export, tmp_path):
+        """Test that choosing a save location writes the chosen format."""
+        path = tmp_path / "out.csv"
+        export.picker = Mock()
+
+        export.choose_export("csv")
+        export._on_result(Mock(path=str(path)))
+
+        assert path.read_text(encoding="utf-8").startswith("destination_hash,name")
+        assert "Exported 3 nodes" in export.page.overlay[-1].content.value
+
+    def test_cancelled_picker_writes_nothing(self, export, tmp_path):
+        """Test that nothing is written when the save dialog is cancelled."""
+        export.picker = Mock()
+
+        export.choose_export("json")
+        export._on_result(Mock(path=None))
+
+        assert export.export_format is None
+        assert list(tmp_path.iterdir()) == []
+
+    def test_write_failure_is_reported(self, export, tmp_path):
+        """Test that a failed write is reported."""
+        assert not export.export_to(tmp_path / "missing" / "nodes.json", "json")
+        assert "Failed to export" in export.page.overlay[-1].content.value
@@ -0,0 +1,213 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.nodes.nodes import NodeInfo, NodeMetadata, NodeOverrides
+
+
+def _storage(metadata=None):
+    return Mock(load_node_metadata=Mock(return_value=metadata or {}))
+
+
+class TestNodeMetadata:
+    """Test cases for the NodeMetadata class."""
+
+    def test_set_favorite_saves(self):
+        """Test that favorites are persisted by destination hash."""
+        storage = _storage()
+        nodes = NodeMetadata(storage)
+
+        nodes.set_favorite("abc", True, "Hilltop")
+
+        assert nodes.is_favorite("abc")
+        storage.save_node_metadata.assert_called_once_with(
+            {
+                "groups": [],
+                "nodes": {
+                    "abc": {
+                        "name": "Hilltop",
+                        "favorite": True,
+                        "group": None,
+                        "note": None,
+                        "blocked": False,
+                        "added": False,
+                        "alias": None,
+                        "overrides": {
+                            "plain_text": False,
+                            "zoom": None,
+                            "bypass_cache": False,
+                        },
+                    },
+                },
+            },
+        )
+
+    def test_unfavorite_forgets_empty_nodes(self):
+        """Test that nodes with nothing recorded are not kept."""
+        storage = _storage()
+        nodes = NodeMetadata(storage)
+        nodes.set_favorite("abc", True)
+
+        nodes.set_favorite("abc", False)
+
+        assert not nodes.is_favorite("abc")
+        assert storage.save_node_metadata.call_args[0][0] == {
+            "groups": [],
+            "nodes": {},
+        }
+
+    def test_favorites_sorted_by_name(self):
+        """Test that favorites are listed by name, falling back to the hash."""
+        nodes = NodeMetadata(_storage())
+        nodes.set_favorite("ccc", True, "beta")
+        nodes.set_favorite("aaa", True, "Alpha")
+        nodes.set_favorite("bbb", True)
+
+        assert [h for h, _ in nodes.favorites()] == ["aaa", "bbb", "ccc"]
+
+    def test_loads_stored_metadata_and_skips_malformed(self):
+        """Test loading metadata saved by a previous session."""
+        nodes = NodeMetadata(
+            _storage(
+                {
+                    "nodes": {
+                        "abc": {"name": "Hilltop", "favorite": True},
+                        "def": "not a dict",
+                    },
+                },
+            ),
+        )
+
+        assert nodes.favorites() == [("abc", NodeInfo("Hilltop", True))]
+        assert nodes.get("def") == NodeInfo()
+
+    def test_unreadable_storage_starts_empty(self):
+        """Test that failing storage does not break loading."""
+        storage = Mock()
+        storage.load_node_metadata.side_effect = OSError("disk gone")
+
+        assert NodeMetadata(storage).favorites() == []
+
+    def test_groups_keep_their_nodes(self):
+        """Test filing nodes under groups, renaming and deleting a group."""
+        storage = _storage()
+        nodes = NodeMetadata(storage)
+        nodes.add_group("Friends")
+        nodes.add_group("Mirrors")
+        nodes.set_group("abc", "Friends", "Hilltop")
+        nodes.set_group("def", "Mirrors")
+
+        nodes.rename_group("Friends", "Neighbours")
+
+        assert [g.name for g in nodes.groups()] == ["Neighbours", "Mirrors"]
+        assert nodes.members("Neighbours") == [
+            ("abc", NodeInfo("Hilltop", group="Neighbours")),
+        ]
+        nodes.remove_group("Mirrors")
+        assert nodes.get("def") == NodeInfo()
+        saved = storage.save_node_metadata.call_args[0][0]
+        assert saved["groups"] == [{"name": "Neighbours", "collapsed": False}]
+        assert list(saved["nodes"]) == ["abc"]
+
+    def test_group_names_must_be_unique(self):
+        """Test that empty and duplicate group names are rejected."""
+        nodes = NodeMetadata(_storage())
+        nodes.add_group("Friends")
+
+        with pytest.raises(ValueError):
+            nodes.add_group(" Friends ")
+        with pytest.raises(ValueError):
+            nodes.add_group("  ")
+        nodes.add_group("Mirrors")
+        with pytest.raises(ValueError):
+            nodes.rename_group("Mirrors", "Friends")
+
+    def test_set_group_ignores_unknown_groups(self):
+        """Test that nodes cannot be filed under a group that does not exist."""
+        nodes = NodeMetadata(_storage())
+
+        nodes.set_group("abc", "Nowhere")
+
+        assert nodes.get("abc").group is None
+
+    def test_loads_groups_and_drops_dangling_members(self):
+        """Test that collapsed state loads and missing groups are ignored."""
+        nodes = NodeMetadata(
+            _storage(
+                {
+                    "groups": [{"name": "Friends", "collapsed": True}, "bad"],
+                    "nodes": {
+                        "abc": {"group": "Friends"},
+                        "def": {"group": "Gone", "favorite": True},
+                    },
+                },
+            ),
+        )
+
+        assert nodes.group("Friends").collapsed
+        assert [h for h, _ in nodes.members("Friends")] == ["abc"]
+        assert nodes.get("def").group is None
+
+    def test_notes_are_trimmed_and_cleared(self):
+        """Test that notes are saved trimmed and blank notes are removed."""
+        storage = _storage()
+        nodes = NodeMetadata(storage)
+
+        nodes.set_note("abc", "  runs the weather BBS\n", "Hilltop")
+        nodes.set_note("abc", "runs the weather BBS")
+
+        assert nodes.get("abc") == NodeInfo("Hilltop", note="runs the weather BBS")
+        assert storage.save_node_metadata.call_count == 1
+        nodes.set_note("abc", "   ")
+        assert nodes.get("abc") == NodeInfo()
+
+    def test_add_node_by_hash(self):
+        """Test that nodes can be added by a valid hash and removed again."""
+        nodes = NodeMetadata(_storage())
+        node = "0123456789ABCDEF0123456789ABCDEF"
+
+        added = nodes.add_node(f" {node} ", " Hilltop ")
+
+        assert added == node.lower()
+        assert nodes.added() == [(added, NodeInfo("Hilltop", added=True))]
+        with pytest.raises(ValueError, match="32 hexadecimal"):
+            nodes.add_node("abc")
+        nodes.remove_node(added)
+        assert nodes.added() == []
+        assert nodes.get(added) == NodeInfo()
+
+    def test_alias_overrides_announced_name(self):
+        """Test that aliases are trimmed, shown instead of names and cleared."""
+        storage = _storage()
+        nodes = NodeMetadata(storage)
+
+        nodes.set_alias("abc", "  Bakery ", "NomadNet Node")
+
+        assert nodes.display_name("abc", "NomadNet Node") == "Bakery"
+        assert nodes.display_name("def", "Mesa") == "Mesa"
+        assert nodes.aliases() == {"abc": "Bakery"}
+        nodes.set_alias("abc", "")
+        assert nodes.display_name("abc", "NomadNet Node") == "NomadNet Node"
+        assert nodes.get("abc") == NodeInfo()
+        assert storage.save_node_metadata.call_count == 2
+
+    def test_overrides_are_kept_per_node(self):
+        """Test that page overrides are saved, loaded and forgotten when reset."""
+        storage = _storage()
+        nodes = NodeMetadata(storage)
+
+        nodes.set_overrides("abc", NodeOverrides(plain_text=True, zoom=1.5))
+
+        saved = storage.save_node_metadata.call_args[0][0]
+        reloaded = NodeMetadata(_storage(saved))
+        assert reloaded.overrides("abc") == NodeOverrides(plain_text=True, zoom=1.5)
+        assert reloaded.overrides("def") == NodeOverrides()
+        nodes.set_overrides("abc", NodeOverrides())
+        assert nodes.known() == []
+
+    def test_malformed_overrides_are_ignored(self):
+        """Test that stored overrides with bad values fall back to none."""
+        stored = {"abc": {"favorite": True, "overrides": {"zoom": "big"}}}
+        nodes = NodeMetadata(_storage({"nodes": stored}))
+
+        assert nodes.overrides("abc") == NodeOverrides()
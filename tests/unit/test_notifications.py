@@ -0,0 +1,44 @@
+from unittest.mock import Mock
+
+from ren_browser.notifications.notifications import Notification, NotificationCenter
+
+
+class TestNotificationCenter:
+    """Test cases for the NotificationCenter class."""
+
+    def test_events_are_listed_newest_first(self):
+        """Test that events are kept newest first and counted as unread."""
+        center = NotificationCenter()
+        center.add("downloads", "a.zip downloaded", now=1.0)
+        center.add("watched", "Notes has changed", "abc:/page/notes.mu", now=2.0)
+
+        assert center.entries() == [
+            Notification("watched", "Notes has changed", 2.0, "abc:/page/notes.mu"),
+            Notification("downloads", "a.zip downloaded", 1.0),
+        ]
+        assert center.unread == 2
+
+    def test_oldest_events_are_dropped(self):
+        """Test that only the latest events are kept."""
+        center = NotificationCenter(max_entries=2)
+        for n in range(4):
+            center.add("error", f"Error {n}")
+
+        assert [entry.message for entry in center.entries()] == ["Error 3", "Error 2"]
+        assert center.unread == 2
+
+    def test_mark_read_and_clear(self):
+        """Test that listeners hear of reading and clearing the events."""
+        center = NotificationCenter()
+        listener = Mock()
+        center.add("feed", "New in your feed: News")
+        center.add_listener(listener)
+
+        center.mark_read()
+        center.mark_read()
+        assert center.unread == 0
+        assert listener.call_count == 1
+
+        center.clear()
+        assert center.entries() == []
+        assert listener.call_count == 2
@@ -1,4 +1,17 @@
-from ren_browser.pages.page_request import PageRequest
+import threading
+from unittest.mock import patch
+
+import pytest
+
+from ren_browser.pages.page_request import (
+    LOAD_PHASES,
+    PageFetcher,
+    PageFetchError,
+    PageRequest,
+    phase_label,
+    phase_progress,
+)
+from ren_browser.pages.trace import PageTrace, to_json
 
 
 class TestPageRequest:
@@ -74,3 +87,145 @@ class TestPageFetcher:
         # Test requests without form data
         simple_requests = [req for req in requests if req.field_data is None]
         assert len(simple_requests) == 2
+
+
+class TestLoadPhases:
+    """Test cases for load phase progress reporting."""
+
+    def test_phase_progress_is_monotonic(self):
+        """Test that phases map onto increasing overall progress."""
+        values = [
+            phase_progress(phase, fraction)
+            for phase in LOAD_PHASES
+            for fraction in (0.0, 0.5, 1.0)
+        ]
+        assert values == sorted(values)
+        assert values[0] == 0.0
+        assert values[-1] == 1.0
+
+    def test_phase_progress_clamps_fraction(self):
+        """Test that out of range fractions are clamped."""
+        assert phase_progress("transfer", 2.0) == phase_progress("transfer", 1.0)
+        assert phase_progress("transfer", None) == phase_progress("transfer", 0.0)
+
+    def test_phase_label(self):
+        """Test phase labels, including transfer percentages."""
+        assert phase_label("path") == "Requesting path..."
+        assert phase_label("transfer", 0.42) == "Receiving page... 42%"
+
+
+class TestPageFetchErrors:
+    """Test cases for classified fetch failures."""
+
+    def test_unknown_identity_raises_classified_error(self):
+        """Test that a missing identity is reported with its kind."""
+        req = PageRequest(destination_hash="00" * 16, page_path="/page/index.mu")
+
+        with patch("ren_browser.pages.page_request.RNS") as mock_rns:
+            mock_rns.Transport.has_path.return_value = True
+            mock_rns.Identity.recall.return_value = None
+            with pytest.raises(PageFetchError) as exc_info:
+                PageFetcher.fetch_page(req)
+
+        assert exc_info.value.kind == "unknown_identity"
+
+    def test_refresh_path_requests_known_path(self):
+        """Test that refresh_path sends a path request even with a known path."""
+        req = PageRequest(destination_hash="00" * 16, page_path="/page/index.mu")
+
+        with patch("ren_browser.pages.page_request.RNS") as mock_rns:
+            mock_rns.Transport.has_path.return_value = True
+            mock_rns.Identity.recall.return_value = None
+            with pytest.raises(PageFetchError):
+                PageFetcher.fetch_page(req, refresh_path=True)
+
+        mock_rns.Transport.request_path.assert_called_once_with(bytes(16))
+
+
+class TestFetchFile:
+    """Test cases for downloading files from nodes."""
+
+    def test_fetch_file_with_metadata(self):
+        """Test that a file name sent with the file is returned."""
+        req = PageRequest(destination_hash="00" * 16, page_path="/file/1")
+
+        with patch(
+            "ren_browser.pages.page_request._request",
+            return_value=[b"data", {b"name": b"notes.txt"}],
+        ):
+            assert PageFetcher.fetch_file(req) == (b"data", "notes.txt")
+
+    def test_fetch_file_plain_bytes(self):
+        """Test a file sent without metadata."""
+        req = PageRequest(destination_hash="00" * 16, page_path="/file/a.zip")
+
+        with patch("ren_browser.pages.page_request._request", return_value=b"zip"):
+            assert PageFetcher.fetch_file(req) == (b"zip", None)
+
+    def test_fetch_file_cancelled(self):
+        """Test that a cancelled download stops before contacting the node."""
+        req = PageRequest(destination_hash="00" * 16, page_path="/file/a.zip")
+        cancel = threading.Event()
+        cancel.set()
+
+        with patch("ren_browser.pages.page_request.RNS") as mock_rns:
+            mock_rns.Transport.has_path.return_value = False
+            with pytest.raises(PageFetchError) as exc_info:
+                PageFetcher.fetch_file(req, cancel=cancel)
+
+        assert exc_info.value.kind == "cancelled"
+
+
+class TestFetchPageDecoding:
+    """Test cases for decoding fetched pages."""
+
+    def test_latin_1_page_is_detected(self):
+        """Test that a page that is not UTF-8 loads instead of failing."""
+        req = PageRequest(destination_hash="00" * 16, page_path="/page/index.mu")
+
+        with (
+            patch("ren_browser.pages.page_request.RNS"),
+            patch(
+                "ren_browser.pages.page_request._request",
+                return_value="Grüße".encode("latin-1"),
+            ),
+        ):
+            assert PageFetcher.fetch_page(req) == "Grüße"
+            assert PageFetcher.fetch_page(req, encoding="utf-8") == "Gr\ufffd\ufffde"
+
+
+class TestPageTrace:
+    """Test cases for page load traces."""
+
+    def test_phases_are_timed_in_load_order(self):
+        """Test that the time spent in each phase adds up."""
+        trace = PageTrace("a:/page/index.mu", {"page_path": "/page/index.mu"}, 0.0)
+        trace.enter("link", now=0.5)
+        trace.enter("transfer", now=1.0)
+        trace.enter("transfer", now=1.5)
+        trace.enter("parse", now=3.0)
+        trace.enter("render", now=3.25)
+        trace.enter(None, now=3.5)
+
+        assert trace.timings() == [
+            ("link", 0.5),
+            ("transfer", 2.0),
+            ("parse", 0.25),
+            ("render", 0.25),
+        ]
+        assert trace.total() == 3.5
+
+    def test_response_and_failure(self):
+        """Test that what came back, or the error, is recorded."""
+        trace = PageTrace("a:/page/index.mu", {})
+        trace.respond("cache", "Hé\nthere")
+        assert trace.response == {"origin": "cache", "bytes": 9, "lines": 2}
+
+        trace.fail(PageFetchError("timeout", "Timed out"))
+        assert trace.response == {"error": "timeout", "message": "Timed out"}
+        trace.fail(ValueError("Bad data"))
+        assert trace.response["error"] == "ValueError"
+
+    def test_to_json(self):
+        """Test that requests are shown as sorted, indented JSON."""
+        assert to_json({"b": None, "a": 1}) == '{\n  "a": 1,\n  "b": null\n}'
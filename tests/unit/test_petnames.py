@@ -0,0 +1,180 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.pages.address import AddressError
+from ren_browser.petnames.petnames import (
+    Petname,
+    PetnameDirectory,
+    PetnameFormatError,
+    expand_petname,
+    from_text,
+    petname_error,
+    to_text,
+)
+from ren_browser.ui.petnames import PetnameFiles, build_petnames_page
+
+ALICE = "0123456789abcdef0123456789abcdef"
+BOB = "fedcba9876543210fedcba9876543210"
+
+
+class _Storage:
+    """Storage keeping the saved petnames in memory."""
+
+    def __init__(self, saved=None):
+        self.saved = saved or []
+
+    def load_petnames(self):
+        return self.saved
+
+    def save_petnames(self, petnames):
+        self.saved = petnames
+
+
+class TestPetnameDirectory:
+    """Test cases for naming destinations."""
+
+    def test_petnames_persist(self):
+        """Test that petnames are saved and loaded again, matched loosely."""
+        storage = _Storage()
+        PetnameDirectory(storage).set("~Alice ", ALICE.upper(), " Forum ")
+
+        directory = PetnameDirectory(storage)
+
+        assert directory.get("ALICE") == Petname("alice", ALICE, "Forum")
+        assert directory.name_for(ALICE) == "alice"
+
+    def test_malformed_entries_are_skipped(self):
+        """Test that stored petnames that are not valid are left out."""
+        directory = PetnameDirectory(
+            _Storage(
+                [
+                    {"name": "alice", "destination_hash": ALICE},
+                    {"name": "bad name", "destination_hash": BOB},
+                    {"name": "bob", "destination_hash": "short"},
+                    "bob",
+                ],
+            ),
+        )
+
+        assert [p.name for p in directory.petnames()] == ["alice"]
+
+    def test_invalid_petnames_are_refused(self):
+        """Test that names and hashes are checked before they are saved."""
+        directory = PetnameDirectory(_Storage())
+
+        with pytest.raises(ValueError, match="letters, digits"):
+            directory.set("al ice", ALICE)
+        with pytest.raises(ValueError, match="destination hash"):
+            directory.set("alice", "abc")
+        assert petname_error(ALICE) == "A destination hash cannot be a petname"
+        assert len(directory) == 0
+
+    def test_merge_keeps_local_names(self):
+        """Test that imported names already used locally are not replaced."""
+        directory = PetnameDirectory(_Storage())
+        directory.set("alice", ALICE)
+
+        added, conflicts = directory.merge(
+            [Petname("alice", BOB), Petname("alice", ALICE), Petname("bob", BOB)],
+        )
+
+        assert (added, conflicts) == (1, 1)
+        assert directory.resolve("alice") == ALICE
+        assert directory.resolve("bob") == BOB
+
+
+class TestNamingFile:
+    """Test cases for sharing petnames as a text file."""
+
+    def test_round_trip(self):
+        """Test that exported petnames are imported with their notes."""
+        petnames = [Petname("alice", ALICE, "Radio forum"), Petname("bob", BOB)]
+
+        assert from_text(to_text(petnames)) == petnames
+
+    def test_invalid_lines_are_skipped(self):
+        """Test that comments and lines that are not petnames are ignored."""
+        text = f"# Shared names\n\nalice {ALICE}\nnot a petname\nbob\n"
+
+        assert from_text(text) == [Petname("alice", ALICE)]
+
+    def test_file_without_petnames_is_refused(self):
+        """Test that a file with no valid line is reported."""
+        with pytest.raises(PetnameFormatError):
+            from_text("just some text\n")
+
+    def test_import_reports_conflicts(self, tmp_path):
+        """Test that an import says how many names were kept."""
+        directory = PetnameDirectory(_Storage())
+        directory.set("alice", ALICE)
+        path = tmp_path / "petnames.txt"
+        path.write_text(f"alice {BOB}\ncarol {BOB}\n", encoding="utf-8")
+        page = Mock(overlay=[])
+        on_change = Mock()
+
+        added = PetnameFiles(page, directory, on_change).import_from(path)
+
+        assert added == 1
+        snack = page.overlay[-1]
+        assert snack.content.value == (
+            "Imported 1 petname "
+            "(1 name already used for another destination was kept)"
+        )
+        on_change.assert_called_once()
+
+
+class TestExpandPetname:
+    """Test cases for petnames in the address bar."""
+
+    @pytest.fixture
+    def directory(self):
+        directory = PetnameDirectory(_Storage())
+        directory.set("alice", ALICE)
+        return directory
+
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            ("~alice", f"{ALICE}:/page/index.mu"),
+            ("~Alice/page/board.mu", f"{ALICE}:/page/board.mu"),
+            ("ren://~alice:/page/board.mu", f"{ALICE}:/page/board.mu"),
+            (f"{BOB}:/page/index.mu", f"{BOB}:/page/index.mu"),
+        ],
+    )
+    def test_expand(self, directory, text, expected):
+        """Test that a leading petname is replaced with its hash."""
+        assert expand_petname(text, directory) == expected
+
+    def test_unknown_petname(self, directory):
+        """Test that a petname that is not in the directory is an error."""
+        with pytest.raises(AddressError, match="no petname ~bob"):
+            expand_petname("~bob/page/index.mu", directory)
+
+
+class TestPetnamesPage:
+    """Test cases for the about:petnames page."""
+
+    def test_add_edit_and_delete(self):
+        """Test that petnames are added, renamed and deleted from the page."""
+        directory = PetnameDirectory(_Storage())
+        page = build_petnames_page(directory, Mock(), Mock())
+        form = page.controls[2].controls
+        name_field, hash_field, _, save_btn, _ = form
+
+        name_field.value, hash_field.value = "alice", "abc"
+        save_btn.on_click(None)
+        assert hash_field.error_text == "Enter a 32 character destination hash"
+
+        hash_field.value = ALICE
+        save_btn.on_click(None)
+        assert directory.resolve("alice") == ALICE
+
+        entry = page.controls[3].content.controls
+        entry[2].on_click(None)
+        name_field.value = "alice2"
+        save_btn.on_click(None)
+        assert [p.name for p in directory.petnames()] == ["alice2"]
+
+        page.controls[3].content.controls[3].on_click(None)
+        assert len(directory) == 0
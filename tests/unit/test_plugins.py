@@ -0,0 +1,136 @@
+from unittest.mock import Mock, patch
+
+import flet as ft
+
+from ren_browser.plugins import plugins
+from ren_browser.plugins.plugins import PluginRegistry, load_plugins
+
+GEMINI_PLUGIN = '''
+import flet as ft
+
+
+def register(plugin):
+    plugin.add_renderer("gmi", lambda content, path, on_link: ft.Text(content))
+    plugin.add_transform(lambda content, path: content.upper())
+    plugin.add_panel("Notes", lambda tab_manager: ft.Text("notes"))
+'''
+
+
+class TestPluginRegistry:
+    """Test cases for registering plugin hooks."""
+
+    def test_load_records_hooks(self):
+        """Test that a plugin's transforms, renderers and panels are added."""
+        registry = PluginRegistry()
+
+        def register(plugin):
+            plugin.add_transform(lambda content, path: content + "!")
+            plugin.add_renderer(".GMI", lambda content, path, on_link: ft.Text(content))
+            plugin.add_panel("Notes", lambda tab_manager: ft.Text("notes"))
+
+        info = registry.load("gemini", "test", lambda: register)
+
+        assert info.error is None
+        assert info.hooks == [
+            "page transform",
+            "renderer for .gmi",
+            "sidebar panel 'Notes'",
+        ]
+        assert registry.transform("page", "/page/index.gmi") == "page!"
+        assert registry.renderer_for("/page/INDEX.gmi")[0] == "gemini"
+        assert registry.renderer_for("/page/index.mu") is None
+        assert [title for title, _ in registry.build_panels(Mock())] == ["Notes"]
+
+    def test_failed_register_adds_nothing(self):
+        """Test that a plugin whose register raises is listed with its error."""
+        registry = PluginRegistry()
+
+        def register(plugin):
+            plugin.add_transform(lambda content, path: "")
+            raise ValueError("bad plugin")
+
+        info = registry.load("broken", "test", lambda: register)
+
+        assert info.error == "ValueError: bad plugin"
+        assert registry.transforms == []
+        assert registry.plugins == [info]
+
+    def test_failing_hooks_are_skipped(self):
+        """Test that hooks raising errors leave the page as the browser shows it."""
+        registry = PluginRegistry()
+
+        def fail(*args):
+            raise RuntimeError("hook failed")
+
+        def register(plugin):
+            plugin.add_transform(fail)
+            plugin.add_renderer(".gmi", fail)
+            plugin.add_panel("Broken", fail)
+
+        registry.load("broken", "test", lambda: register)
+
+        assert registry.transform("page", "/page/index.gmi") == "page"
+        assert registry.render("page", "/page/index.gmi", Mock()) is None
+        assert registry.build_panels(Mock()) == []
+
+    def test_render_returns_plugin_name(self):
+        """Test that rendering with a plugin says which plugin rendered it."""
+        registry = PluginRegistry()
+        text = ft.Text("page")
+        registry.load(
+            "gemini",
+            "test",
+            lambda: lambda plugin: plugin.add_renderer(
+                ".gmi",
+                lambda content, path, on_link: text,
+            ),
+        )
+
+        assert registry.render("page", "/page/index.gmi", Mock()) == ("gemini", text)
+
+
+class TestLoadPlugins:
+    """Test cases for finding and loading plugins."""
+
+    def test_loads_files_from_folder(self, tmp_path):
+        """Test that plugin files in the folder are imported and registered."""
+        (tmp_path / "gemini.py").write_text(GEMINI_PLUGIN, encoding="utf-8")
+        (tmp_path / "broken.py").write_text("import nothing_here\n", encoding="utf-8")
+        (tmp_path / "notes.txt").write_text("not a plugin", encoding="utf-8")
+
+        with patch("ren_browser.plugins.plugins.entry_points", return_value=[]):
+            registry = load_plugins(tmp_path)
+
+        assert [info.name for info in registry.plugins] == ["broken", "gemini"]
+        assert registry.plugins[0].error.startswith("ModuleNotFoundError")
+        assert registry.plugins[1].error is None
+        assert registry.transform("page", "/page/index.gmi") == "PAGE"
+        assert plugins.loaded_plugins() == registry.plugins
+
+    def test_loads_installed_plugins(self):
+        """Test that packages with a ren_browser.plugins entry point are loaded."""
+        registered = []
+
+        def register(plugin):
+            registered.append(plugin.name)
+
+        entry_point = Mock(value="gemini_plugin:register", load=lambda: register)
+        entry_point.name = "gemini"
+
+        with patch(
+            "ren_browser.plugins.plugins.entry_points",
+            return_value=[entry_point],
+        ) as found:
+            registry = load_plugins(None)
+
+        found.assert_called_once_with(group="ren_browser.plugins")
+        assert registry.plugins[0].name == "gemini"
+        assert registry.plugins[0].source == "gemini_plugin:register"
+        assert registered == ["gemini"]
+
+    def test_missing_folder(self, tmp_path):
+        """Test that a plugin folder that does not exist loads nothing."""
+        with patch("ren_browser.plugins.plugins.entry_points", return_value=[]):
+            registry = load_plugins(tmp_path / "plugins")
+
+        assert registry.plugins == []
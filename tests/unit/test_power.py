@@ -0,0 +1,128 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.power.power import (
+    BatteryStatus,
+    PowerMonitor,
+    _sysfs_status,
+    saving_power,
+)
+
+
+def _supply(root, name, **files):
+    supply = root / name
+    supply.mkdir()
+    for filename, value in files.items():
+        (supply / filename).write_text(f"{value}\n")
+
+
+@pytest.fixture
+def tab_manager():
+    manager = Mock()
+    manager.settings = {}
+    manager.idle = False
+    manager.announce_list.refresh_interval = 60
+    manager.status_bar.poll_interval = 3
+    return manager
+
+
+class TestBatteryStatus:
+    """Test cases for reading the battery state."""
+
+    def test_sysfs_battery_discharging(self, tmp_path):
+        """Test that a discharging battery without a charger is on battery."""
+        _supply(tmp_path, "AC", type="Mains", online=0)
+        _supply(tmp_path, "BAT0", type="Battery", status="Discharging", capacity=40)
+
+        assert _sysfs_status(tmp_path) == BatteryStatus(True, 40)
+
+    def test_sysfs_charger_online(self, tmp_path):
+        """Test that a connected charger means the device is plugged in."""
+        _supply(tmp_path, "AC", type="Mains", online=1)
+        _supply(tmp_path, "BAT0", type="Battery", status="Charging", capacity=80)
+
+        assert _sysfs_status(tmp_path) == BatteryStatus(False, 80)
+
+    def test_sysfs_without_battery(self, tmp_path):
+        """Test that a desktop without a battery reports no state."""
+        _supply(tmp_path, "AC", type="Mains", online=1)
+
+        assert _sysfs_status(tmp_path) is None
+        assert _sysfs_status(tmp_path / "missing") is None
+
+    def test_saving_power_thresholds(self):
+        """Test that power is saved on battery at or below the chosen level."""
+        settings = {"battery_saver_percent": 30}
+
+        assert saving_power(BatteryStatus(True, 30), settings)
+        assert saving_power(BatteryStatus(True, None), settings)
+        assert not saving_power(BatteryStatus(True, 31), settings)
+        assert not saving_power(BatteryStatus(False, 10), settings)
+        assert not saving_power(None, settings)
+        assert not saving_power(
+            BatteryStatus(True, 10),
+            {**settings, "battery_saver": False},
+        )
+
+
+class TestPowerMonitor:
+    """Test cases for slowing the browser down on battery."""
+
+    def test_battery_stretches_intervals_and_pauses_prefetch(self, tab_manager):
+        """Test that running on battery slows refreshing and stops prefetching."""
+        monitor = PowerMonitor(Mock(), tab_manager)
+
+        monitor.apply(BatteryStatus(True, 50))
+
+        assert monitor.saving
+        assert tab_manager.announce_list.interval_factor == 3
+        assert tab_manager.status_bar.interval_factor == 3
+        assert tab_manager.prefetch_paused
+        tab_manager.prefetcher.cancel.assert_called_once()
+        tab_manager.announce_list.start_refresh.assert_called_once()
+        tab_manager.status_bar.start.assert_called_once()
+
+    def test_plugging_in_restores_intervals(self, tab_manager):
+        """Test that the usual intervals come back once the device is plugged in."""
+        monitor = PowerMonitor(Mock(), tab_manager)
+        monitor.apply(BatteryStatus(True, 50))
+
+        monitor.apply(BatteryStatus(False, 50))
+
+        assert not monitor.saving
+        assert tab_manager.announce_list.interval_factor == 1
+        assert tab_manager.status_bar.interval_factor == 1
+        assert not tab_manager.prefetch_paused
+        assert tab_manager.announce_list.start_refresh.call_count == 2
+
+    def test_unchanged_state_does_not_restart_refresh(self, tab_manager):
+        """Test that repeated checks with the same result restart nothing."""
+        monitor = PowerMonitor(Mock(), tab_manager)
+        monitor.apply(BatteryStatus(True, 50))
+
+        monitor.apply(BatteryStatus(True, 45))
+
+        tab_manager.announce_list.start_refresh.assert_called_once()
+        tab_manager.status_bar.start.assert_called_once()
+
+    def test_idle_window_is_not_restarted(self, tab_manager):
+        """Test that an idle window only takes the new intervals on focus."""
+        tab_manager.idle = True
+        monitor = PowerMonitor(Mock(), tab_manager)
+
+        monitor.apply(BatteryStatus(True, 50))
+
+        assert tab_manager.status_bar.interval_factor == 3
+        tab_manager.announce_list.start_refresh.assert_not_called()
+        tab_manager.status_bar.start.assert_not_called()
+
+    def test_prefetch_kept_when_not_paused_in_settings(self, tab_manager):
+        """Test that prefetching can be kept on while saving power."""
+        tab_manager.settings = {"battery_pause_prefetch": False}
+        monitor = PowerMonitor(Mock(), tab_manager)
+
+        monitor.apply(BatteryStatus(True, 50))
+
+        assert not tab_manager.prefetch_paused
+        tab_manager.prefetcher.cancel.assert_not_called()
@@ -0,0 +1,107 @@
+from unittest.mock import Mock
+from urllib.error import HTTPError
+from urllib.request import urlopen
+
+import pytest
+
+from ren_browser.pages.cache import PageCache
+from ren_browser.profiler.metrics import MetricsServer, metrics_text
+from ren_browser.profiler.profiler import (
+    UPDATE_WINDOW,
+    PerformanceMonitor,
+    memory_usage,
+    watch_updates,
+)
+
+
+class TestPerformanceMonitor:
+    """Test cases for the PerformanceMonitor class."""
+
+    def test_update_rate_over_window(self):
+        """Test that only recent updates count towards the rate."""
+        monitor = PerformanceMonitor()
+        assert monitor.update_rate(now=100.0) == (0.0, None)
+
+        monitor.record_update(0.5, now=90.0)
+        monitor.record_update(0.01, now=99.0)
+        monitor.record_update(0.03, now=100.0)
+
+        rate, latency = monitor.update_rate(now=100.0)
+        assert rate == 2 / UPDATE_WINDOW
+        assert round(latency, 3) == 0.02
+
+    def test_record_fetch(self):
+        """Test that the last fetch is remembered."""
+        monitor = PerformanceMonitor()
+        monitor.record_fetch("a:/page/index.mu", 1.5)
+        monitor.record_fetch("b:/page/index.mu", 0.25)
+
+        assert monitor.last_fetch.address == "b:/page/index.mu"
+        assert monitor.last_fetch.seconds == 0.25
+
+    def test_watch_updates_times_page_updates(self):
+        """Test that wrapped page updates still run and are timed."""
+        monitor = PerformanceMonitor()
+        update = Mock()
+        page = Mock(update=update)
+
+        watch_updates(page, monitor)
+        page.update("control")
+
+        update.assert_called_once_with("control")
+        assert monitor.update_rate()[0] == 1 / UPDATE_WINDOW
+
+    def test_memory_usage(self):
+        """Test that the process memory is reported in bytes."""
+        memory = memory_usage()
+        assert memory is None or memory > 0
+
+
+class TestMetrics:
+    """Test cases for the metrics endpoint."""
+
+    def _tab_manager(self):
+        monitor = PerformanceMonitor()
+        monitor.record_fetch("a:/page/index.mu", 1.5)
+        monitor.record_fetch_failure()
+        cache = PageCache()
+        cache.put("a:/page/index.mu", "Hello")
+        cache.get("a:/page/index.mu")
+        cache.get("b:/page/index.mu")
+        return Mock(
+            performance=monitor,
+            page_cache=cache,
+            manager=Mock(tabs=[{}, {}]),
+            downloads=Mock(downloads=Mock(return_value=[])),
+            prefetcher=Mock(pending=Mock(return_value=3)),
+        )
+
+    def test_metrics_text(self):
+        """Test that fetches, cache use and tabs are reported."""
+        text = metrics_text(self._tab_manager())
+
+        assert "# TYPE ren_browser_page_fetches_total counter" in text
+        assert "ren_browser_page_fetches_total 1\n" in text
+        assert "ren_browser_page_fetch_failures_total 1\n" in text
+        assert "ren_browser_page_fetch_seconds_sum 1.5\n" in text
+        assert "ren_browser_cache_hits_total 1\n" in text
+        assert "ren_browser_cache_misses_total 1\n" in text
+        assert "ren_browser_cache_pages 1\n" in text
+        assert "ren_browser_tabs 2\n" in text
+        assert "ren_browser_prefetch_queued 3\n" in text
+        assert "disk_cache_bytes" not in text
+
+    def test_server_serves_metrics(self):
+        """Test that metrics are served on /metrics and nothing else."""
+        server = MetricsServer(0, lambda: "ren_browser_tabs 1\n")
+        assert server.start()
+        try:
+            url = f"http://127.0.0.1:{server.port}"
+            with urlopen(f"{url}/metrics", timeout=5) as response:
+                assert response.read() == b"ren_browser_tabs 1\n"
+                assert response.headers["Content-Type"].startswith("text/plain")
+            with pytest.raises(HTTPError):
+                urlopen(f"{url}/other", timeout=5)
+        finally:
+            server.stop()
+        assert server.port is None
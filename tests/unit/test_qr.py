@@ -0,0 +1,154 @@
+import struct
+import zlib
+
+import pytest
+
+from ren_browser.qr.qr import (
+    _read_segments,
+    correct_errors,
+    decode,
+    encode,
+    error_correction,
+    format_bits,
+    to_png,
+    version_bits,
+)
+from ren_browser.qr.scan import read_image, read_png, scan
+
+
+def _format_copies(modules):
+    size = len(modules)
+    first = [modules[i][8] for i in range(6)]
+    first += [modules[7][8], modules[8][8], modules[8][7]]
+    first += [modules[8][14 - i] for i in range(9, 15)]
+    second = [modules[8][size - 1 - i] for i in range(8)]
+    second += [modules[size - 15 + i][8] for i in range(8, 15)]
+    return [sum(bit << i for i, bit in enumerate(copy)) for copy in (first, second)]
+
+
+class TestQrEncoder:
+    """Test cases for the QR code encoder."""
+
+    def test_error_correction_matches_reference(self):
+        """Test Reed-Solomon codewords against the published 1-M example."""
+        data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17]
+
+        expected = [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+
+        assert error_correction(data, 10) == expected
+
+    def test_format_and_version_bits(self):
+        """Test the BCH coded format and version information."""
+        assert format_bits(0) == 0b101010000010010
+        assert version_bits(7) == 0b000111110010010100
+
+    def test_smallest_version_is_chosen(self):
+        """Test that short text fits version 1 and an address version 4."""
+        assert len(encode("HELLO")) == 21
+        assert len(encode(f"ren://{'a' * 32}/page/index.mu")) == 33
+
+    def test_finder_patterns_and_format(self):
+        """Test the fixed patterns and that both format copies agree."""
+        modules = encode("ren://0123456789abcdef0123456789abcdef/page/index.mu")
+        size = len(modules)
+
+        for x, y in ((0, 0), (size - 7, 0), (0, size - 7)):
+            assert all(modules[y][x + i] for i in range(7))
+            assert all(modules[y + 2][x + 2 + i] for i in range(3))
+            assert not modules[y + 1][x + 1]
+        assert [modules[6][i] for i in range(8, size - 8)] == [
+            i % 2 == 0 for i in range(8, size - 8)
+        ]
+        first, second = _format_copies(modules)
+        assert first == second
+        assert first in {format_bits(mask) for mask in range(8)}
+        assert modules[size - 8][8]
+
+    def test_too_long_text_is_rejected(self):
+        """Test that text beyond a version 10 code raises."""
+        with pytest.raises(ValueError):
+            encode("x" * 214)
+
+    def test_png_dimensions(self):
+        """Test that the PNG has the quiet zone and scale applied."""
+        png = to_png(encode("HELLO"), scale=4, border=2)
+
+        assert png.startswith(b"\x89PNG\r\n\x1a\n")
+        width, height = struct.unpack(">II", png[16:24])
+        assert width == height == (21 + 4) * 4
+        idat = png.index(b"IDAT")
+        length = struct.unpack(">I", png[idat - 4 : idat])[0]
+        raw = zlib.decompress(png[idat + 4 : idat + 4 + length])
+        assert len(raw) == height * (width + 1)
+        assert raw[1] == 255
+        assert raw[(width + 1) * 8 + 1 + 8] == 0
+
+
+class TestQrDecoder:
+    """Test cases for reading QR codes."""
+
+    def test_decode_reads_encoded_text(self):
+        """Test that encoded text of every supported size is read back."""
+        for text in ("HELLO", "ren://0123456789abcdef0123456789abcdef", "x" * 200):
+            assert decode(encode(text)) == text
+
+    def test_decode_corrects_damaged_modules(self):
+        """Test that a few flipped data modules are corrected."""
+        url = "ren://0123456789abcdef0123456789abcdef/page/index.mu"
+        modules = [list(row) for row in encode(url)]
+        for x, y in ((12, 12), (20, 15), (14, 25), (25, 20)):
+            modules[y][x] = not modules[y][x]
+
+        assert decode(modules) == url
+
+    def test_correct_errors_repairs_reference_block(self):
+        """Test Reed-Solomon correction on the published 1-M example."""
+        data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17]
+        block = data + error_correction(data, 10)
+        for i, flip in ((0, 0x55), (5, 1), (13, 0xFF), (20, 9)):
+            block[i] ^= flip
+
+        assert correct_errors(block, 10)[:16] == data
+        assert _read_segments(data, 1) == "HELLO WORLD"
+
+    def test_correct_errors_rejects_heavy_damage(self):
+        """Test that a block with too many errors raises."""
+        data = list(range(16))
+        block = data + error_correction(data, 10)
+        for i in range(8):
+            block[i] ^= 0xA5
+
+        with pytest.raises(ValueError):
+            correct_errors(block, 10)
+
+
+class TestQrScan:
+    """Test cases for finding QR codes in images."""
+
+    def test_scan_png(self):
+        """Test that a code is read from a PNG, turned or at a small scale."""
+        url = "ren://0123456789abcdef0123456789abcdef/page/index.mu"
+        modules = encode(url)
+        turned = [list(row) for row in zip(*modules[::-1], strict=True)]
+
+        assert scan(to_png(modules)) == url
+        assert scan(to_png(turned, scale=3)) == url
+
+    def test_read_png_grayscale(self):
+        """Test that the grayscale pixels of a rendered code are read."""
+        image = read_png(to_png(encode("HELLO"), scale=2, border=1))
+
+        assert (image.width, image.height) == (46, 46)
+        assert image.row(0)[0] == 255
+        assert image.row(2)[2] == 0
+
+    def test_scan_without_code(self):
+        """Test that an image without a code gives None."""
+        blank = to_png([[False] * 21 for _ in range(21)])
+
+        assert scan(blank) is None
+
+    def test_unreadable_image_raises(self):
+        """Test that data that is no image raises."""
+        with pytest.raises(ValueError):
+            read_image(b"GIF89a not really")
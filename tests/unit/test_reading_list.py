@@ -0,0 +1,80 @@
+import json
+
+from ren_browser.reading_list.reading_list import ReadingList
+
+
+class TestReadingList:
+    """Test cases for the ReadingList class."""
+
+    def test_save_and_load(self, tmp_path):
+        """Test that saved pages are kept unchanged on disk."""
+        reading_list = ReadingList(tmp_path)
+
+        item = reading_list.save(
+            "abc:/page/index.mu",
+            "Home",
+            ">Heading\nBody",
+            "/page/index.mu",
+        )
+
+        assert item.file.endswith(".mu")
+        assert (tmp_path / item.file).read_text(encoding="utf-8") == ">Heading\nBody"
+        assert reading_list.load("abc:/page/index.mu") == ">Heading\nBody"
+
+    def test_persists_across_sessions(self, tmp_path):
+        """Test that the reading list is loaded from its index."""
+        ReadingList(tmp_path).save("abc:/file/notes.txt", "Notes", "x", "/file/notes.txt")
+
+        reading_list = ReadingList(tmp_path)
+
+        items = reading_list.items()
+        assert [item.title for item in items] == ["Notes"]
+        assert items[0].file.endswith(".txt")
+
+    def test_saving_again_replaces_copy(self, tmp_path):
+        """Test that saving a page twice keeps only the newest copy."""
+        reading_list = ReadingList(tmp_path)
+        reading_list.save("abc:/page/index.mu", "Old", "old", "/page/index.mu")
+
+        reading_list.save("abc:/page/index.mu", "New", "new", "/page/index.mu")
+
+        assert len(reading_list.items()) == 1
+        assert reading_list.get("abc:/page/index.mu").title == "New"
+        assert reading_list.load("abc:/page/index.mu") == "new"
+
+    def test_remove_deletes_file(self, tmp_path):
+        """Test that removing an item deletes its saved copy."""
+        reading_list = ReadingList(tmp_path)
+        item = reading_list.save("abc:/page/index.mu", "Home", "x", "/page/index.mu")
+
+        reading_list.remove("abc:/page/index.mu")
+
+        assert not (tmp_path / item.file).exists()
+        assert reading_list.load("abc:/page/index.mu") is None
+        assert json.loads((tmp_path / "index.json").read_text()) == []
+
+    def test_clear_since(self, tmp_path):
+        """Test that clearing a time range deletes only newer copies."""
+        reading_list = ReadingList(tmp_path)
+        old = reading_list.save("a:/page/index.mu", "Old", "old", "/page/index.mu")
+        new = reading_list.save("b:/page/index.mu", "New", "new", "/page/index.mu")
+        old.saved_at = 100.0
+        new.saved_at = 200.0
+
+        reading_list.clear(since=150.0)
+
+        assert [item.title for item in reading_list.items()] == ["Old"]
+        assert not (tmp_path / new.file).exists()
+
+    def test_malformed_index(self, tmp_path):
+        """Test that a damaged index starts an empty reading list."""
+        (tmp_path / "index.json").write_text("{broken", encoding="utf-8")
+
+        assert ReadingList(tmp_path).items() == []
+
+    def test_missing_directory(self, tmp_path):
+        """Test that nothing is created until a page is saved."""
+        reading_list = ReadingList(tmp_path / "missing")
+
+        assert reading_list.items() == []
+        assert not (tmp_path / "missing").exists()
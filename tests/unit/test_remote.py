@@ -0,0 +1,259 @@
+import io
+import json
+import socket
+from unittest.mock import Mock, patch
+
+import pytest
+
+from ren_browser.cli.remote import EXIT_NOT_RUNNING, remote
+from ren_browser.remote.remote import (
+    INVALID_PARAMS,
+    INVALID_REQUEST,
+    METHOD_NOT_FOUND,
+    NOT_RUNNING,
+    PARSE_ERROR,
+    RemoteControlServer,
+    RemoteError,
+    browser_methods,
+    call,
+)
+
+
+def _tab(title, address, **extra):
+    return {"title": title, "address": address, **extra}
+
+
+@pytest.fixture
+def tab_manager():
+    """Create a tab manager with two tabs, the second one active."""
+    manager = Mock()
+    manager.manager.tabs = [
+        _tab("Home", None),
+        _tab("Node", "abc:/page/index.mu", load_progress=0.5),
+    ]
+    manager.manager.index = 1
+
+    def open_link_in_new_tab(address, select=True):
+        manager.manager.tabs.append(_tab(address, address))
+
+    manager.open_link_in_new_tab = Mock(side_effect=open_link_in_new_tab)
+    return manager
+
+
+class TestRemoteControlServer:
+    """Test cases for answering remote control requests."""
+
+    @pytest.fixture
+    def server(self, tmp_path):
+        """Start a server with a few test methods."""
+        server = RemoteControlServer(
+            tmp_path / "remote.json",
+            {
+                "echo": lambda text: text,
+                "add": lambda a, b=0: a + b,
+                "fail": Mock(side_effect=RemoteError(INVALID_PARAMS, "Bad value")),
+            },
+        )
+        assert server.start()
+        yield server
+        server.stop()
+
+    def request(self, server, **message):
+        return server.handle(
+            {"jsonrpc": "2.0", "id": 7, "token": server.token, **message},
+        )
+
+    def test_call_returns_result(self, server):
+        """Test that a client gets the result of the method it called."""
+        assert call(server.path, "echo", ["hello"]) == "hello"
+        assert call(server.path, "add", {"a": 2, "b": 3}) == 5
+
+    def test_wrong_token_is_refused(self, server):
+        """Test that requests without the published token are not run."""
+        response = self.request(server, method="echo", params=["x"], token="guess")
+
+        assert response["error"]["code"] == INVALID_REQUEST
+        assert "result" not in response
+
+    def test_unknown_method(self, server):
+        """Test that calling a method that does not exist is an error."""
+        with pytest.raises(RemoteError) as error:
+            call(server.path, "explode")
+
+        assert error.value.code == METHOD_NOT_FOUND
+
+    def test_wrong_params(self, server):
+        """Test that params not matching the method are an error."""
+        response = self.request(server, method="echo", params=[])
+
+        assert response["error"]["code"] == INVALID_PARAMS
+        assert response["id"] == 7
+
+    def test_method_errors_are_returned(self, server):
+        """Test that a method raising RemoteError answers with its code."""
+        with pytest.raises(RemoteError, match="Bad value") as error:
+            call(server.path, "fail")
+
+        assert error.value.code == INVALID_PARAMS
+
+    def test_notifications_get_no_response(self, server):
+        """Test that requests without an id run but are not answered."""
+        echo = Mock(return_value="x")
+        server.methods["echo"] = echo
+
+        response = server.handle(
+            {
+                "jsonrpc": "2.0",
+                "method": "echo",
+                "params": ["x"],
+                "token": server.token,
+            },
+        )
+
+        assert response is None
+        echo.assert_called_once_with("x")
+
+    def test_unreadable_request(self, server):
+        """Test that a connection sending something other than JSON is told so."""
+        published = json.loads(server.path.read_text(encoding="utf-8"))
+        with socket.create_connection(("127.0.0.1", published["port"])) as conn:
+            conn.sendall(b"not json\n")
+            response = json.loads(conn.recv(4096))
+
+        assert response["error"]["code"] == PARSE_ERROR
+
+    def test_no_running_instance(self, tmp_path):
+        """Test that calling fails cleanly when nothing is running."""
+        with pytest.raises(RemoteError) as error:
+            call(tmp_path / "remote.json", "status")
+
+        assert error.value.code == NOT_RUNNING
+
+    def test_stop_withdraws_published_port(self, tmp_path):
+        """Test that stopping the server removes its remote file."""
+        server = RemoteControlServer(tmp_path / "remote.json", {})
+        assert server.start()
+
+        server.stop()
+
+        assert not server.path.exists()
+
+
+class TestBrowserMethods:
+    """Test cases for the methods acting on the browser's tabs."""
+
+    def test_open_adds_tab(self, tab_manager):
+        """Test that open loads the address in a new tab."""
+        result = browser_methods(tab_manager)["open"]("abc:/page/about.mu")
+
+        assert result == {"tab": 2}
+        tab_manager.open_link_in_new_tab.assert_called_once_with(
+            "abc:/page/about.mu",
+            select=True,
+        )
+
+    def test_open_in_background(self, tab_manager):
+        """Test that background opens leave the active tab selected."""
+        browser_methods(tab_manager)["open"]("abc", background=True)
+
+        assert tab_manager.open_link_in_new_tab.call_args[1]["select"] is False
+
+    def test_open_needs_address(self, tab_manager):
+        """Test that open refuses an empty address."""
+        with pytest.raises(RemoteError) as error:
+            browser_methods(tab_manager)["open"]("  ")
+
+        assert error.value.code == INVALID_PARAMS
+
+    def test_tabs_lists_tabs(self, tab_manager):
+        """Test that tabs describes every tab and marks the active one."""
+        tabs = browser_methods(tab_manager)["tabs"]()
+
+        assert [tab["title"] for tab in tabs] == ["Home", "Node"]
+        assert [tab["active"] for tab in tabs] == [False, True]
+        assert tabs[1]["address"] == "abc:/page/index.mu"
+        assert tabs[1]["loading"] is True
+        assert tabs[0]["loading"] is False
+
+    def test_private_tabs_are_not_described(self, tab_manager):
+        """Test that what is open in private tabs is left out."""
+        tab_manager.manager.tabs[1]["private"] = True
+
+        tab = browser_methods(tab_manager)["tabs"]()[1]
+
+        assert tab["private"] is True
+        assert tab["title"] is None
+        assert tab["address"] is None
+
+    def test_reload_defaults_to_active_tab(self, tab_manager):
+        """Test that reload without a tab reloads the active one."""
+        methods = browser_methods(tab_manager)
+
+        assert methods["reload"]() == {"tab": 1}
+        tab_manager.reload.assert_called_once_with(1, hard=False)
+
+        methods["reload"](0, hard=True)
+        tab_manager.reload.assert_called_with(0, hard=True)
+
+    def test_reload_rejects_missing_tab(self, tab_manager):
+        """Test that reloading a tab that does not exist is an error."""
+        with pytest.raises(RemoteError) as error:
+            browser_methods(tab_manager)["reload"](5)
+
+        assert error.value.code == INVALID_PARAMS
+        tab_manager.reload.assert_not_called()
+
+    def test_status(self, tab_manager):
+        """Test that status reports Reticulum, the tab count and the active tab."""
+        with patch(
+            "ren_browser.remote.remote.rns.get_reticulum_instance",
+            return_value=object(),
+        ):
+            status = browser_methods(tab_manager)["status"]()
+
+        assert status["reticulum"] is True
+        assert status["tabs"] == 2
+        assert status["active"]["address"] == "abc:/page/index.mu"
+
+
+class TestRemoteCommand:
+    """Test cases for the ren-browser remote command."""
+
+    def run(self, argv):
+        stdout = io.StringIO()
+        stderr = io.StringIO()
+        status = remote(argv, stdout=stdout, stderr=stderr)
+        return status, stdout.getvalue(), stderr.getvalue()
+
+    def test_calls_running_browser(self, tab_manager):
+        """Test that the command prints the result of the running browser."""
+        from ren_browser.storage.storage import get_storage_manager
+
+        server = RemoteControlServer(
+            get_storage_manager().get_remote_path(),
+            browser_methods(tab_manager),
+        )
+        assert server.start()
+        try:
+            status, out, _ = self.run(["open", "abc:/page/about.mu", "--background"])
+            tabs_status, tabs_out, _ = self.run(["tabs"])
+        finally:
+            server.stop()
+
+        assert status == 0
+        assert json.loads(out) == {"tab": 2}
+        assert tab_manager.open_link_in_new_tab.call_args[1]["select"] is False
+        assert tabs_status == 0
+        assert len(json.loads(tabs_out)) == 3
+
+    def test_nothing_running(self):
+        """Test that the command reports when no browser is running."""
+        with patch(
+            "ren_browser.cli.remote.call",
+            side_effect=RemoteError(NOT_RUNNING, "Ren Browser is not running"),
+        ):
+            status, out, err = self.run(["status"])
+
+        assert status == EXIT_NOT_RUNNING
+        assert out == ""
+        assert "not running" in err
@@ -1,7 +1,44 @@
 import flet as ft
 
-from ren_browser.renderer.micron import render_micron
-from ren_browser.renderer.plaintext import render_plaintext
+from unittest.mock import Mock, patch
+
+from ren_browser.renderer import micron
+from ren_browser.renderer.code import (
+    LANGUAGES,
+    code_language,
+    highlight,
+    render_code,
+)
+from ren_browser.renderer.gemtext import (
+    gemtext_title,
+    gemtext_to_text,
+    parse_gemtext,
+    render_gemtext,
+)
+from ren_browser.renderer.markdown import (
+    markdown_to_text,
+    parse_inline,
+    parse_markdown,
+    render_markdown,
+    resolve_markdown_link,
+)
+from ren_browser.renderer.micron import (
+    is_rtl,
+    micron_links,
+    micron_to_text,
+    render_micron,
+    row_alignment,
+)
+from ren_browser.renderer.pdf import (
+    Block,
+    Run,
+    layout_micron,
+    layout_plaintext,
+    page_to_pdf,
+    paginate,
+)
+from ren_browser.renderer.plaintext import render_plaintext, render_source
+from ren_browser.renderer.title import extract_title
 
 
 class TestPlaintextRenderer:
@@ -18,6 +55,10 @@ class TestPlaintextRenderer:
         assert result.font_family == "monospace"
         assert result.expand is True
 
+    def test_render_plaintext_font(self):
+        """Test that a chosen font replaces the monospace font."""
+        assert render_plaintext("Hello", "serif").font_family == "serif"
+
     def test_render_plaintext_multiline(self):
         """Test plaintext rendering with multiline content."""
         content = "Line 1\nLine 2\nLine 3"
@@ -54,6 +95,20 @@ class TestPlaintextRenderer:
         assert result.value == content
         assert result.selectable is True
 
+    def test_render_source_numbers_lines(self):
+        """Test that page source is shown with its lines numbered."""
+        content = "\n".join(f"line {n}" for n in range(1, 11))
+        numbers, text = render_source(content, min_size=18).controls
+
+        assert numbers.value.split("\n")[:2] == [" 1", " 2"]
+        assert numbers.value.split("\n")[-1] == "10"
+        assert numbers.selectable is not True
+        assert text.value == content
+        assert text.selectable is True
+        assert text.font_family == "monospace"
+        assert text.no_wrap is True
+        assert numbers.size == text.size == 18
+
 
 class TestMicronRenderer:
     """Test cases for the micron renderer.
@@ -87,6 +142,194 @@ class TestMicronRenderer:
         assert isinstance(result, ft.Column)
         assert len(result.controls) > 0
 
+    def test_micron_to_text_strips_markup(self):
+        """Test converting micron markup to readable text."""
+        content = "# comment\n`c>Welcome\n`!Bold`! text\n-\nGo `[home`:/page/index.mu] now"
+
+        assert micron_to_text(content) == "Welcome\nBold text\n\nGo home now"
+
+    def test_micron_to_text_follows_rendered_lines(self):
+        """Test that the text keeps the rendered lines, empty headings left out."""
+        content = "`ccentered\n>\n\n`Ff00red`f and `_under`_\n`a`B333dark`b"
+
+        assert micron_to_text(content) == "centered\n\nred and under\ndark"
+
+    def test_micron_links(self):
+        """Test listing the links in micron markup."""
+        content = "# `[Hide`:/page/x.mu]\n`[Home`:/page/index.mu] or `[`abc:/page/a.mu]"
+
+        assert micron_links(content) == [
+            ("Home", ":/page/index.mu"),
+            ("", "abc:/page/a.mu"),
+        ]
+
+    def test_render_micron_reports_link_hover(self):
+        """Test that hovering a link reports its target."""
+        hovered = []
+        result = render_micron("`[About`:/page/about.mu]", on_link_hover=hovered.append)
+        link_button = result.controls[0].content.controls[0]
+
+        link_button.on_hover(type("Event", (), {"data": "true"})())
+        link_button.on_hover(type("Event", (), {"data": "false"})())
+
+        assert hovered == [":/page/about.mu", None]
+
+    def test_render_micron_links_are_labelled(self):
+        """Test that screen readers hear links announced as links."""
+        result = render_micron("`[About`:/page/about.mu]")
+        link_button = result.controls[0].content.controls[0]
+
+        assert link_button.content.value == "About"
+        assert link_button.content.semantics_label == "Link, About"
+
+    def test_render_micron_font(self):
+        """Test that headings and text are rendered in a chosen font."""
+        result = render_micron(">Title\nBody", font_family="serif")
+
+        assert result.controls[0].content.font_family == "serif"
+        assert result.controls[1].content.controls[0].font_family == "serif"
+
+    def test_render_micron_text_scale(self):
+        """Test that a text scale zooms headings and text."""
+        result = render_micron(">Title\nBody", text_scale=1.5)
+
+        assert result.controls[0].content.size == 27
+        assert result.controls[1].content.controls[0].size == 21
+        assert render_micron("Body").controls[0].content.controls[0].size is None
+
+    def test_render_micron_high_contrast(self):
+        """Test that high contrast drops page colours and underlines links."""
+        content = "`Ff00`Bbbbred text\n`[About`:/page/about.mu]"
+        plain = render_micron(content)
+        result = render_micron(content, high_contrast=True)
+        text = result.controls[0].content.controls[0]
+        link_button = result.controls[1].content.controls[0]
+
+        assert plain.controls[0].content.controls[0].color == "rgb(255,0,0)"
+        assert text.color is None
+        assert text.bgcolor is None
+        assert link_button.style.text_style.decoration == ft.TextDecoration.UNDERLINE
+
+    def test_is_rtl_uses_first_strong_letter(self):
+        """Test that a paragraph's first letter decides its direction."""
+        assert is_rtl("שלום world")
+        assert is_rtl("123 مرحبا")
+        assert not is_rtl("Hello שלום")
+        assert not is_rtl("123 !")
+
+    def test_render_micron_right_to_left_paragraphs(self):
+        """Test that Hebrew and Arabic lines are laid out from the right."""
+        result = render_micron(">שלום\n>>\nمرحبا بكم\nHello")
+        heading, text, english = result.controls
+
+        assert heading.rtl is True
+        assert heading.alignment == ft.alignment.center_right
+        assert text.rtl is True
+        assert text.padding.right == 40
+        assert text.content.alignment == ft.MainAxisAlignment.START
+        assert english.rtl is False
+        assert english.padding.left == 40
+
+    def test_row_alignment_mirrors_explicit_sides(self):
+        """Test that explicit left and right swap in right-to-left rows."""
+        start, end = ft.MainAxisAlignment.START, ft.MainAxisAlignment.END
+
+        assert row_alignment(ft.TextAlign.START, True) == start
+        assert row_alignment(ft.TextAlign.RIGHT, False) == end
+        assert row_alignment(ft.TextAlign.RIGHT, True) == start
+        assert row_alignment(ft.TextAlign.LEFT, True) == end
+        assert row_alignment(ft.TextAlign.CENTER, True) == ft.MainAxisAlignment.CENTER
+
+    def test_render_micron_min_size(self):
+        """Test that a minimum size raises text the page and zoom shrink."""
+        result = render_micron(">>>>>Fine print\nBody", text_scale=0.5, min_size=16)
+
+        assert result.controls[0].content.size == 16
+        assert result.controls[1].content.controls[0].size == 16
+        assert render_micron(">Title", min_size=16).controls[0].content.size == 18
+        assert render_plaintext("Body", min_size=16).size == 16
+
+    def test_render_micron_middle_click_opens_new_tab(self):
+        """Test that middle-clicking a link reports it for a new tab."""
+        opened = []
+        result = render_micron("`[About`:/page/about.mu]", on_link_new_tab=opened.append)
+        link = result.controls[0].content.controls[0]
+
+        assert isinstance(link, ft.GestureDetector)
+        link.on_tertiary_tap_down(None)
+
+        assert opened == [":/page/about.mu"]
+
+
+class TestMicronParseCache:
+    """Test cases for reusing parsed micron pages."""
+
+    def setup_method(self):
+        """Start every test with no parsed pages."""
+        micron.clear_parse_cache()
+
+    def test_same_content_parsed_once(self):
+        """Test that rendering known content again skips parsing."""
+        content = ">Title\n`!Bold`! text\n`[About`:/page/about.mu]"
+        with patch.object(
+            micron,
+            "parse_micron_line",
+            wraps=micron.parse_micron_line,
+        ) as parse:
+            first = render_micron(content)
+            calls = parse.call_count
+            second = render_micron(content, text_scale=1.5)
+
+        assert calls > 0
+        assert parse.call_count == calls
+        assert len(first.controls) == len(second.controls)
+        assert second.controls[1].content.controls[0].value == "Bold"
+        assert second.controls[1].content.controls[0].size == 21
+
+    def test_rendered_controls_are_new(self):
+        """Test that each render builds its own controls with its callbacks."""
+        clicked = []
+        content = "`[About`:/page/about.mu]"
+        render_micron(content)
+        link = render_micron(content, on_link_click=clicked.append)
+        link.controls[0].content.controls[0].on_click(None)
+
+        assert clicked == [":/page/about.mu"]
+
+    def test_least_recently_used_dropped(self):
+        """Test that the oldest parsed pages are forgotten past the limit."""
+        with patch.object(micron, "PARSE_CACHE_SIZE", 2):
+            first = micron.parse_micron("one")
+            second = micron.parse_micron("two")
+            micron.parse_micron("one")
+            micron.parse_micron("three")
+
+            assert micron.parse_micron("one") is first
+            assert micron.parse_micron("two") is not second
+
+
+class TestMicronView:
+    """Test cases for building long micron pages as they are scrolled."""
+
+    def test_long_page_rendered_in_chunks(self):
+        """Test that only the first chunk is built until the end is near."""
+        content = "\n".join(f"Line {number}" for number in range(700))
+        result = render_micron(content)
+
+        assert isinstance(result, micron.MicronView)
+        assert len(result.controls) == micron.RENDER_CHUNK
+        with patch.object(result, "update") as update:
+            result.on_scroll(Mock(pixels=0.0, max_scroll_extent=10_000.0))
+            update.assert_not_called()
+            result.on_scroll(Mock(pixels=9_000.0, max_scroll_extent=10_000.0))
+            update.assert_called_once()
+
+        assert len(result.controls) == 2 * micron.RENDER_CHUNK
+        assert result.render_more() is True
+        assert result.render_more() is False
+        assert len(result.controls) == 700
+        assert result.controls[-1].content.controls[0].value == "Line 699"
+
 
 class TestRendererComparison:
     """Test cases comparing both renderers."""
@@ -118,3 +361,290 @@ class TestRendererComparison:
 
         assert plaintext_result.expand is True
         assert micron_result.expand is True
+
+
+class TestCodeViewer:
+    """Test cases for the syntax highlighted code viewer."""
+
+    def test_code_language_by_extension(self):
+        """Test that the language is picked by the file extension."""
+        assert code_language("/page/tool.py").name == "Python"
+        assert code_language("/page/Config.TOML").name == "Config"
+        assert code_language("/page/index.mu") is None
+        assert code_language("/page/v1.2/readme") is None
+
+    def test_highlight_python(self):
+        """Test that keywords, strings, numbers and comments are picked out."""
+        source = 'def f():\n    return "# x", 42  # done'
+        tokens = highlight(source, LANGUAGES["python"])
+
+        kinds = {text: kind for text, kind in tokens if kind}
+        assert kinds == {
+            "def": "keyword",
+            "return": "keyword",
+            '"# x"': "string",
+            "42": "number",
+            "# done": "comment",
+        }
+        assert "".join(text for text, _ in tokens) == source
+
+    def test_block_comments_span_lines(self):
+        """Test that a block comment is highlighted across its lines."""
+        tokens = highlight("/* one\ntwo */ fn", LANGUAGES["rust"])
+
+        assert tokens[0] == ("/* one\ntwo */", "comment")
+        assert tokens[-1] == ("fn", "keyword")
+
+    def test_rust_lifetimes_are_not_strings(self):
+        """Test that lifetimes are not mistaken for the start of a string."""
+        tokens = highlight("&'a str, 'x'", LANGUAGES["rust"])
+
+        assert [text for text, kind in tokens if kind == "string"] == ["'x'"]
+
+    def test_config_sections(self):
+        """Test that section headers of config files stand out."""
+        tokens = highlight("[interfaces]\nenabled = yes", code_language("a.conf"))
+
+        assert tokens[0] == ("[interfaces]", "section")
+        assert ("yes", "keyword") in tokens
+
+    def test_render_code_numbers_lines(self):
+        """Test that the viewer numbers each line beside the highlighted code."""
+        result = render_code("a = 1\nb = 2", LANGUAGES["python"])
+
+        numbers, code = result.controls
+        assert numbers.value == "1\n2"
+        assert "".join(span.text for span in code.spans) == "a = 1\nb = 2"
+        assert code.selectable is True
+
+
+class TestGemtextRenderer:
+    """Test cases for the gemtext renderer."""
+
+    PAGE = (
+        "# Station\n"
+        "=> /about.gmi About us\n"
+        "=> gemini://other.org/\n"
+        "* item\n"
+        "> quoted\n"
+        "```\n"
+        "# not a heading\n"
+        "```\n"
+        "\n"
+        "Plain"
+    )
+
+    def test_parse_gemtext_line_types(self):
+        """Test that each gemtext line type maps to a micron block."""
+        blocks = parse_gemtext(self.PAGE, "gemini://example.org/dir/index.gmi")
+
+        assert [b.kind for b in blocks] == [
+            "heading",
+            "links",
+            "links",
+            "text",
+            "text",
+            "text",
+            "blank",
+            "text",
+        ]
+        assert blocks[0].level == 1
+        assert blocks[1].parts[0] == ("About us", "gemini://example.org/about.gmi")
+        assert blocks[2].parts[0] == ("", "gemini://other.org/")
+        assert blocks[3].parts[0]["text"] == "• item"
+        assert blocks[4].parts[0]["italic"] is True
+        assert blocks[5].parts[0]["monospace"] is True
+
+    def test_preformatted_text_is_monospace(self):
+        """Test that preformatted lines render in a monospace font."""
+        result = render_gemtext("```\ncode\n```", "gemini://example.org/")
+
+        assert result.controls[0].content.controls[0].font_family == "monospace"
+
+    def test_gemtext_title_skips_preformatted(self):
+        """Test that the title is the first heading outside preformatted text."""
+        assert gemtext_title("```\n# art\n```\n## Real") == "Real"
+        assert gemtext_title("no heading") is None
+
+    def test_gemtext_to_text(self):
+        """Test that the page text shows links by their label."""
+        text = gemtext_to_text(self.PAGE)
+
+        assert text.splitlines()[:3] == ["Station", "About us", "gemini://other.org/"]
+
+
+class TestMarkdownRenderer:
+    """Test cases for the Markdown renderer."""
+
+    PAGE = (
+        "# Notes\n"
+        "Some *light* and **strong** text\n"
+        "joined into one paragraph.\n"
+        "\n"
+        "- first [About](about.md)\n"
+        "  1. nested\n"
+        "> quoted\n"
+        "---\n"
+        "```\n"
+        "# not a heading\n"
+        "```"
+    )
+
+    def test_parse_markdown_block_types(self):
+        """Test that each Markdown construct maps to a micron block."""
+        blocks = parse_markdown(self.PAGE, "/page/docs/index.md")
+
+        assert [b.kind for b in blocks] == [
+            "heading",
+            "text",
+            "blank",
+            "links",
+            "text",
+            "text",
+            "divider",
+            "text",
+        ]
+        assert blocks[3].parts[-1] == ("About", ":/page/docs/about.md")
+        assert blocks[4].level == 1
+        assert blocks[4].parts[0]["text"] == "1. "
+        assert blocks[5].parts[0]["italic"] is True
+        assert blocks[7].parts[0]["monospace"] is True
+
+    def test_paragraph_lines_are_joined(self):
+        """Test that the lines of a paragraph form one block."""
+        blocks = parse_markdown("one\ntwo")
+
+        assert len(blocks) == 1
+        assert markdown_to_text("one\ntwo") == "one two"
+
+    def test_inline_emphasis_and_code(self):
+        """Test that emphasis and inline code become styled spans."""
+        parts = parse_inline("a *b* **c** `d`")
+
+        assert [(p["text"], p["bold"], p["italic"]) for p in parts[:4]] == [
+            ("a ", False, False),
+            ("b", False, True),
+            (" ", False, False),
+            ("c", True, False),
+        ]
+        assert parts[-1]["monospace"] is True
+
+    def test_resolve_markdown_link(self):
+        """Test that relative links stay on the node and anchors are dropped."""
+        assert resolve_markdown_link("../a.md", "/page/docs/b.md") == ":/page/a.md"
+        assert resolve_markdown_link("/file/x.zip", "/page/b.md") == ":/file/x.zip"
+        assert resolve_markdown_link("abc:/page/index.mu", "/page/b.md") == (
+            "abc:/page/index.mu"
+        )
+        assert resolve_markdown_link("#top", "/page/b.md") is None
+
+    def test_render_markdown_links(self):
+        """Test that rendered links call back with their address."""
+        on_click = Mock()
+        result = render_markdown(
+            "[Home](index.md)",
+            "/page/b.md",
+            on_link_click=on_click,
+        )
+
+        result.controls[0].content.controls[0].on_click(None)
+
+        on_click.assert_called_once_with(":/page/index.md")
+
+
+class TestTitleExtraction:
+    """Test cases for page title extraction."""
+
+    def test_micron_first_heading(self):
+        """Test that the first micron heading becomes the title."""
+        content = "#!c=0\nIntro text\n> Node News\n>> Older"
+        assert extract_title(content, "/page/index.mu") == "Node News"
+
+    def test_micron_heading_strips_formatting(self):
+        """Test that formatting tags are removed from heading titles."""
+        content = "`c>`!Weather`! Station"
+        assert extract_title(content, "/page/index.mu") == "Weather Station"
+
+    def test_html_title(self):
+        """Test that the HTML title element is used for HTML pages."""
+        content = "<html><head><title> My &amp; Page </title></head></html>"
+        assert extract_title(content, "/index.html") == "My & Page"
+
+    def test_markdown_heading(self):
+        """Test that Markdown pages use their first heading."""
+        assert extract_title("intro\n## Guide *one*", "/page/readme.md") == (
+            "Guide one"
+        )
+
+    def test_no_title(self):
+        """Test that pages without headings have no title."""
+        assert extract_title("just some text", "/page/index.mu") is None
+        assert extract_title("", "/page/index.mu") is None
+
+
+class TestPdfExport:
+    """Test cases for exporting pages as PDF."""
+
+    def test_layout_micron(self):
+        """Test that headings, styles, dividers and literals are laid out."""
+        content = ">Bulletin\n`!Bold`! and `Ff00red`f\n-\n`=\ncode line\n`="
+
+        blocks = layout_micron(content)
+
+        assert [b.kind for b in blocks] == ["heading", "text", "divider", "text"]
+        assert blocks[0].runs[0].text == "Bulletin"
+        assert [(r.text, r.font) for r in blocks[1].runs] == [
+            ("Bold", "F2"),
+            (" and ", "F1"),
+            ("red", "F1"),
+        ]
+        assert blocks[1].runs[2].color == (1.0, 0.0, 0.0)
+        assert blocks[3].runs[0].font == "F5"
+
+    def test_pale_colors_dropped(self):
+        """Test that colors meant for a dark background print in black."""
+        blocks = layout_micron("`FfffWhite text")
+
+        assert blocks[0].runs[0].color is None
+
+    def test_long_lines_wrap_and_paginate(self):
+        """Test that long content is wrapped and spread over pages."""
+        blocks = [Block("text", [Run("word " * 200)]) for _ in range(20)]
+
+        pages = paginate(blocks)
+
+        assert len(pages) > 1
+        assert all(page.count("BT") > 1 for page in pages)
+
+    def test_monochrome_prints_in_black(self):
+        """Test that colored text turns black when laid out for printing."""
+        blocks = layout_micron("`Ff00red")
+
+        assert "1.000 0.000 0.000 rg" in paginate(blocks)[0]
+        assert "1.000 0.000 0.000 rg" not in paginate(blocks, monochrome=True)[0]
+        assert "0.000 0.000 0.000 rg" in paginate(blocks, monochrome=True)[0]
+
+    def test_page_to_pdf(self):
+        """Test the structure of a generated PDF."""
+        pdf = page_to_pdf("Some (notes)\nline two", "/file/notes.txt", "Notes")
+
+        assert pdf.startswith(b"%PDF-1.4")
+        assert pdf.rstrip().endswith(b"%%EOF")
+        assert b"/BaseFont /Courier" in pdf
+        assert b"(notes\\)" in pdf
+        assert b"/Count 1" in pdf
+        startxref = int(pdf.rsplit(b"startxref", 1)[1].split()[0])
+        assert pdf[startxref:].startswith(b"xref")
+
+    def test_layout_plaintext(self):
+        """Test that plain text pages are printed in monospace."""
+        blocks = layout_plaintext("a\n\nb")
+
+        assert [b.kind for b in blocks] == ["text", "blank", "text"]
+        assert blocks[0].runs[0].font == "F5"
+
+    def test_malformed_micron_falls_back_to_plaintext(self):
+        """Test that markup the parser rejects is still exported."""
+        pdf = page_to_pdf("`Fxyzbroken", "/page/index.mu")
+
+        assert b"/F5 9.5 Tf" in pdf
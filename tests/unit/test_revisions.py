@@ -0,0 +1,100 @@
+from ren_browser.pages.revisions import (
+    ADDED,
+    REMOVED,
+    SAME,
+    PageRevisions,
+    diff_lines,
+)
+from ren_browser.ui.changes import build_diff_view
+
+URL = "0123456789abcdef0123456789abcdef:/page/board.mu"
+
+
+class TestPageRevisions:
+    """Test cases for keeping earlier versions of pages."""
+
+    def test_changed_page_keeps_earlier_version(self, tmp_path):
+        """Test that the version before a change is returned with its time."""
+        revisions = PageRevisions(tmp_path)
+
+        assert revisions.record(URL, "one", now=100.0) is False
+        assert revisions.record(URL, "one", now=200.0) is False
+        assert revisions.record(URL, "two", now=300.0) is True
+
+        previous = revisions.previous(URL, "two")
+        assert (previous.content, previous.fetched_at) == ("one", 200.0)
+
+    def test_versions_survive_a_restart(self, tmp_path):
+        """Test that the versions are read again from disk."""
+        revisions = PageRevisions(tmp_path)
+        revisions.record(URL, "one")
+        revisions.record(URL, "two")
+        revisions.latest.flush()
+        revisions.earlier.flush()
+
+        assert PageRevisions(tmp_path).previous(URL, "two").content == "one"
+
+    def test_old_copies_have_no_earlier_version(self, tmp_path):
+        """Test that only the version last fetched is compared."""
+        revisions = PageRevisions(tmp_path)
+        revisions.record(URL, "one")
+
+        assert revisions.previous(URL, "one") is None
+        revisions.record(URL, "two")
+        assert revisions.previous(URL, "one") is None
+
+    def test_clear_forgets_versions(self, tmp_path):
+        """Test that clearing the cache also clears the versions."""
+        revisions = PageRevisions(tmp_path)
+        revisions.record(URL, "one")
+        revisions.record(URL, "two")
+
+        revisions.clear()
+        revisions.record(URL, "three")
+
+        assert revisions.previous(URL, "three") is None
+
+
+class TestDiffLines:
+    """Test cases for comparing versions of a page."""
+
+    def test_replaced_line(self):
+        """Test that a changed line is removed, then added."""
+        assert diff_lines("Board\nOld post\nEnd", "Board\nNew post\nEnd") == [
+            (SAME, "Board"),
+            (REMOVED, "Old post"),
+            (ADDED, "New post"),
+            (SAME, "End"),
+        ]
+
+    def test_added_lines(self):
+        """Test that new posts at the top are added lines."""
+        assert diff_lines("Post 1", "Post 2\nPost 1") == [
+            (ADDED, "Post 2"),
+            (SAME, "Post 1"),
+        ]
+
+
+class TestDiffView:
+    """Test cases for showing what changed."""
+
+    def test_unchanged_lines_are_folded(self):
+        """Test that only lines near a change are shown."""
+        old = "\n".join(f"Line {n}" for n in range(20))
+        new = old.replace("Line 10", "Line ten")
+
+        view = build_diff_view(diff_lines(old, new))
+
+        shown = [c.content.controls[1].value for c in view.controls[1:-1]]
+        assert shown[0] == "Line 7"
+        assert shown[-1] == "Line 13"
+        assert view.controls[0].content.value == "7 unchanged lines"
+        assert view.controls[-1].content.value == "6 unchanged lines"
+
+    def test_unchanged_text_says_so(self):
+        """Test that a page whose markup alone changed says its text did not."""
+        view = build_diff_view(diff_lines("Same", "Same"))
+
+        assert view.controls[0].value == (
+            "The readable text of the page did not change."
+        )
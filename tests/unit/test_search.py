@@ -0,0 +1,118 @@
+from unittest.mock import Mock
+
+from ren_browser.history.history import HistoryEntry
+from ren_browser.pages.cache import PageCache
+from ren_browser.pages.disk_cache import DiskCache
+from ren_browser.reading_list.reading_list import ReadingItem
+from ren_browser.search.search import (
+    SearchDocument,
+    build_documents,
+    highlight,
+    search,
+    snippet,
+)
+from ren_browser.ui.search import build_search_page
+
+BOARD = SearchDocument(
+    "abc:/page/board.mu",
+    "Mesh Board",
+    "Welcome to the board. Post about antennas and mesh routing here.",
+)
+WEATHER = SearchDocument(
+    "def:/page/weather.mu",
+    "Weather",
+    "Forecast for the mesh: rain. " * 3,
+)
+
+
+class TestSearch:
+    """Test cases for searching the pages kept on this device."""
+
+    def test_title_matches_rank_first(self):
+        """Test that pages with the words in their title come first."""
+        results = search([WEATHER, BOARD], "mesh")
+
+        assert [result.document for result in results] == [BOARD, WEATHER]
+        assert results[0].score > results[1].score
+
+    def test_every_word_has_to_match(self):
+        """Test that pages missing a word of the query are left out."""
+        results = search([WEATHER, BOARD], "Mesh ANTENNAS")
+
+        assert [result.document for result in results] == [BOARD]
+        assert ("antennas", True) in results[0].snippet
+        assert search([WEATHER, BOARD], "   ") == []
+
+    def test_highlight(self):
+        """Test that the words of the query are marked in any case."""
+        assert highlight("Mesh and mesh", ["mesh"]) == [
+            ("Mesh", True),
+            (" and ", False),
+            ("mesh", True),
+        ]
+        assert highlight("Mesh", []) == [("Mesh", False)]
+
+    def test_snippet_around_first_match(self):
+        """Test that long texts are cut around the first matching word."""
+        text = "filler " * 40 + "antenna tuning notes " + "more " * 40
+
+        part = snippet(text, ["antenna"], length=60)
+
+        assert part.startswith("…filler")
+        assert part.endswith("…")
+        assert "antenna tuning" in part
+        assert len(part) <= 62
+
+    def test_build_documents(self, tmp_path):
+        """Test that cached, saved and visited pages are merged by address."""
+        cache = PageCache(disk=DiskCache(tmp_path))
+        cache.put("abc:/page/board.mu", ">Mesh Board\nPost about `!antennas`!")
+        cache.disk.put("old:/page/index.mu", ">Old Page\nArchived", 1.0)
+        reading_list = Mock()
+        reading_list.items.return_value = [
+            ReadingItem("ghi:/page/saved.mu", "Saved", 1.0, "saved.mu"),
+        ]
+        reading_list.load.return_value = "Saved text"
+        history = Mock()
+        history.entries.return_value = [
+            HistoryEntry("abc:/page/board.mu", "Board", 2.0),
+            HistoryEntry("jkl:/page/index.mu", "jkl:/page/index.mu", 3.0),
+        ]
+
+        documents = {
+            document.address: document
+            for document in build_documents(cache, reading_list, history)
+        }
+
+        board = documents["abc:/page/board.mu"]
+        assert board.title == "Mesh Board"
+        assert "Post about antennas" in board.text
+        assert board.sources == ["cache", "history"]
+        assert documents["old:/page/index.mu"].title == "Old Page"
+        assert documents["ghi:/page/saved.mu"].text == "Saved text"
+        assert documents["jkl:/page/index.mu"].title == "jkl:/page/index.mu"
+
+
+class TestSearchPage:
+    """Test cases for the about:search page."""
+
+    def test_pages_are_read_on_first_search(self):
+        """Test that pages are only collected once a query is typed."""
+        documents = Mock(return_value=[BOARD, WEATHER])
+        on_open = Mock()
+        on_change = Mock()
+        page = build_search_page(documents, on_open, on_change)
+        field = page.controls[1]
+        results = page.controls[2]
+        documents.assert_not_called()
+
+        field.value = "antennas"
+        field.on_change(None)
+        field.value = "board"
+        field.on_change(None)
+
+        documents.assert_called_once()
+        assert on_change.call_count == 2
+        result = results.controls[1]
+        result.on_click(None)
+        on_open.assert_called_once_with("abc:/page/board.mu")
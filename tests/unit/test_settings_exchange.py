@@ -0,0 +1,162 @@
+import json
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.storage.exchange import (
+    SettingsFormatError,
+    describe_changes,
+    from_json,
+    import_bundle,
+    to_json,
+)
+from ren_browser.storage.schema import DEFAULT_APP_SETTINGS
+from ren_browser.themes.themes import CustomTheme, ThemeLibrary
+from ren_browser.ui.settings_transfer import SettingsFiles
+
+DUSK = CustomTheme("Dusk", background="#101020", tab="#303060")
+
+
+def export(settings=None, themes=(), **extra):
+    data = json.loads(to_json(settings or {}, list(themes)))
+    data.update(extra)
+    return json.dumps(data)
+
+
+class TestSettingsExchange:
+    """Test cases for reading and writing settings files."""
+
+    def test_round_trip(self):
+        """Test that exported settings and themes read back unchanged."""
+        settings = {
+            **DEFAULT_APP_SETTINGS,
+            "theme": "custom:Dusk",
+            "keybindings": {"history": ["Ctrl+Y"]},
+        }
+
+        bundle = from_json(to_json(settings, [DUSK]))
+
+        assert bundle.settings == settings
+        assert bundle.themes == [DUSK]
+        assert bundle.ignored == []
+
+    def test_unknown_settings_are_skipped(self):
+        """Test that settings from a newer release are listed, not applied."""
+        bundle = from_json(export({"theme": "light", "warp_drive": True}))
+
+        assert bundle.settings == {"theme": "light"}
+        assert bundle.ignored == ["warp_drive"]
+
+    @pytest.mark.parametrize(
+        "text",
+        [
+            "not json",
+            json.dumps({"settings": {}}),
+            export(version=99),
+            export({"show_status_bar": "yes"}),
+            export({"sidebar_width": True}),
+            export({"keybindings": {"history": ["Q"]}}),
+            export({"address_aliases": {"weather": 3}}),
+            export(themes=[CustomTheme("Bad", accent="blue")]),
+        ],
+    )
+    def test_invalid_files_are_rejected(self, text):
+        """Test that files that are not valid settings exports are refused."""
+        with pytest.raises(SettingsFormatError):
+            from_json(text)
+
+    def test_describe_changes(self, tmp_path):
+        """Test that the preview lists changed settings, shortcuts and themes."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("Dusk"))
+        current = {**DEFAULT_APP_SETTINGS}
+        bundle = from_json(
+            export(
+                {
+                    "theme": "light",
+                    "show_status_bar": False,
+                    "home_page": "",
+                    "keybindings": {"history": ["Ctrl+Y"]},
+                },
+                [DUSK, CustomTheme("Dawn")],
+            ),
+        )
+
+        assert describe_changes(current, bundle, library) == [
+            "Theme: dark → light",
+            "Show status bar: on → off",
+            "Shortcut for Open history: Ctrl+H → Ctrl+Y",
+            "Updated theme: Dusk",
+            "New theme: Dawn",
+        ]
+
+    def test_import_bundle_saves_themes(self, tmp_path):
+        """Test that importing replaces themes of the same name."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("Dusk"))
+        bundle = from_json(export({"theme": "custom:Dusk"}, [DUSK]))
+
+        settings = import_bundle(bundle, {"ui_scale": 1.5}, library)
+
+        assert settings == {"ui_scale": 1.5, "theme": "custom:Dusk"}
+        assert ThemeLibrary(tmp_path).get("Dusk") == DUSK
+        assert len(list(tmp_path.glob("*.toml"))) == 1
+
+
+class TestSettingsFiles:
+    """Test cases for the settings import and export dialogs."""
+
+    @pytest.fixture
+    def files(self, mock_page, tmp_path):
+        """Create the settings file dialogs for testing."""
+        mock_page.overlay = []
+        tab_manager = Mock()
+        tab_manager.settings = {**DEFAULT_APP_SETTINGS}
+        tab_manager.themes = ThemeLibrary(tmp_path / "themes")
+        return SettingsFiles(mock_page, tab_manager, Mock(), on_import=Mock())
+
+    def test_export_then_import(self, files, tmp_path):
+        """Test that an export previews and imports another machine's setup."""
+        files.tab_manager.themes.save(DUSK)
+        files.tab_manager.settings["theme"] = "custom:Dusk"
+        path = tmp_path / "settings.json"
+        assert files.export_to(path)
+
+        other = SettingsFiles(
+            files.page,
+            Mock(settings={**DEFAULT_APP_SETTINGS}),
+            Mock(),
+            on_import=Mock(),
+        )
+        other.tab_manager.themes = ThemeLibrary(tmp_path / "other")
+        assert other.preview(path)
+        lines = [text.value for text in other.changes.controls]
+        assert lines == ["Theme: dark → custom:Dusk", "New theme: Dusk"]
+
+        other.apply()
+
+        saved = other.storage.save_app_settings.call_args[0][0]
+        assert saved["theme"] == "custom:Dusk"
+        other.tab_manager.apply_settings.assert_called_once_with(saved)
+        assert other.tab_manager.themes.get("Dusk") == DUSK
+        other.on_import.assert_called_once()
+
+    def test_cancel_imports_nothing(self, files, tmp_path):
+        """Test that closing the preview leaves the settings alone."""
+        path = tmp_path / "settings.json"
+        path.write_text(export({"theme": "light"}), encoding="utf-8")
+
+        assert files.preview(path)
+        files.cancel()
+        files.apply()
+
+        files.storage.save_app_settings.assert_not_called()
+        files.on_import.assert_not_called()
+
+    def test_invalid_file_is_reported(self, files, tmp_path):
+        """Test that a file that is not a settings export is not previewed."""
+        path = tmp_path / "bookmarks.json"
+        path.write_text("[]", encoding="utf-8")
+
+        assert not files.preview(path)
+        assert "Failed to import" in files.page.overlay[-1].content.value
@@ -2,7 +2,14 @@ from unittest.mock import Mock
 
 import pytest
 
-from ren_browser.controls.shortcuts import Shortcuts
+from ren_browser.controls.shortcuts import (
+    ACTION_CATEGORIES,
+    ACTIONS,
+    Shortcuts,
+    find_conflicts,
+    keybindings,
+    parse_chord,
+)
 
 
 class TestShortcuts:
@@ -17,6 +24,7 @@ class TestShortcuts:
         manager._on_add_click = Mock()
         manager._on_close_click = Mock()
         manager.select_tab = Mock()
+        manager.chrome_navigator.handle_key.return_value = False
         return manager
 
     @pytest.fixture
@@ -57,6 +65,19 @@ class TestShortcuts:
 
         mock_tab_manager._on_add_click.assert_called_once_with(None)
 
+    def test_reopen_closed_tab_ctrl_shift_t(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+Shift+T shortcut for reopening the last closed tab."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "T"
+        event.shift = True
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.reopen_closed_tab.assert_called_once_with()
+        mock_tab_manager._on_add_click.assert_not_called()
+
     def test_close_tab_shortcut_ctrl_w(self, shortcuts, mock_tab_manager):
         """Test Ctrl+W shortcut for close tab."""
         event = Mock()
@@ -84,25 +105,196 @@ class TestShortcuts:
 
         shortcuts.on_keyboard(event)
 
+        mock_tab_manager.reveal_address_bar.assert_called_once()
         url_field.focus.assert_called_once()
         shortcuts.page.update.assert_called_once()
 
-    def test_show_announces_drawer_ctrl_a(self, shortcuts):
-        """Test Ctrl+A shortcut for showing announces drawer."""
+    def test_show_announces_sidebar_ctrl_a(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+A shortcut for expanding the announces sidebar."""
         event = Mock()
         event.ctrl = True
         event.meta = False
         event.key = "a"
         event.shift = False
 
-        shortcuts.page.drawer = Mock()
-        shortcuts.page.drawer.open = False
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.sidebar.set_collapsed.assert_called_once_with(False)
+        shortcuts.page.update.assert_called_once()
+
+    def test_toggle_sidebar_ctrl_b(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+B shortcut for collapsing and expanding the sidebar."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "b"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.sidebar.toggle.assert_called_once()
+        shortcuts.page.update.assert_called_once()
+
+    def test_zoom_shortcuts(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+Plus, Ctrl+Minus and Ctrl+0 zoom shortcuts."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.shift = False
+
+        for key in ("=", "-", "0"):
+            event.key = key
+            shortcuts.on_keyboard(event)
+
+        mock_tab_manager.zoom.zoom_in.assert_called_once()
+        mock_tab_manager.zoom.zoom_out.assert_called_once()
+        mock_tab_manager.zoom.reset.assert_called_once()
+
+    def test_paste_and_go_ctrl_shift_v(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+Shift+V shortcut for paste and go."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "V"
+        event.shift = True
 
         shortcuts.on_keyboard(event)
 
-        assert shortcuts.page.drawer.open is True
+        mock_tab_manager.paste_and_go.assert_called_once()
         shortcuts.page.update.assert_called_once()
 
+    def test_open_bookmarks_ctrl_shift_o(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+Shift+O shortcut for opening the bookmarks manager."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "O"
+        event.shift = True
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.open_link_in_new_tab.assert_called_once_with(
+            "about:bookmarks",
+        )
+
+    def test_command_palette_ctrl_shift_p(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+Shift+P opens the command palette rather than exporting."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "P"
+        event.shift = True
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.command_palette.open.assert_called_once()
+        mock_tab_manager.export_pdf.assert_not_called()
+
+    def test_f1_shows_shortcut_sheet(self, shortcuts, mock_tab_manager):
+        """Test F1 opens the keyboard shortcut cheat sheet."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.key = "F1"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.shortcut_sheet.open.assert_called_once()
+
+    def test_palette_only_actions(self, shortcuts, mock_tab_manager):
+        """Test the actions that have no default chord run when dispatched."""
+        shortcuts.dispatch("switch_renderer")
+        shortcuts.dispatch("clear_cache")
+
+        mock_tab_manager.toggle_plain_text.assert_called_once_with(0)
+        mock_tab_manager.clear_page_cache.assert_called_once()
+
+    def test_copy_page_text_ctrl_shift_c(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+Shift+C shortcut for copying the page as text."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "C"
+        event.shift = True
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.copy_page_text.assert_called_once_with(0)
+
+    def test_toggle_downloads_ctrl_j(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+J shortcut for showing the downloads panel."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "j"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.downloads_panel.toggle.assert_called_once()
+
+    def test_open_file_ctrl_o(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+O shortcut for opening a page from disk."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "o"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.file_opener.choose.assert_called_once()
+
+    def test_save_page_ctrl_s(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+S shortcut for saving the current page."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "s"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.save_page_as.assert_called_once_with(0)
+
+    def test_view_source_ctrl_u(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+U shortcut for viewing the source of the current page."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "u"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.view_source.assert_called_once_with(0)
+
+    def test_export_pdf_ctrl_p(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+P shortcut for exporting the current page as PDF."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "p"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.export_pdf.assert_called_once_with(0)
+
+    def test_open_history_ctrl_h(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+H shortcut for opening the history page."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "h"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.open_link_in_new_tab.assert_called_once_with("about:history")
+
     def test_cycle_tabs_forward_ctrl_tab(self, shortcuts, mock_tab_manager):
         """Test Ctrl+Tab for cycling tabs forward."""
         event = Mock()
@@ -165,6 +357,19 @@ class TestShortcuts:
 
         mock_tab_manager.select_tab.assert_called_once_with(2)  # Wrap to last
 
+    def test_tab_moves_between_links(self, shortcuts, mock_tab_manager):
+        """Test that Tab and Shift+Tab without Ctrl move between page links."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock(ctrl=False, meta=False, alt=False, key="Tab", shift=False)
+
+        shortcuts.on_keyboard(event)
+        event.shift = True
+        shortcuts.on_keyboard(event)
+
+        moves = mock_tab_manager.link_navigator.move.call_args_list
+        assert [call.args for call in moves] == [(1,), (-1,)]
+        mock_tab_manager.select_tab.assert_not_called()
+
     def test_no_ctrl_or_meta_key_returns_early(self, shortcuts, mock_tab_manager):
         """Test that shortcuts without Ctrl or Meta key don't trigger actions."""
         event = Mock()
@@ -178,6 +383,158 @@ class TestShortcuts:
         mock_tab_manager._on_add_click.assert_not_called()
         shortcuts.page.update.assert_not_called()
 
+    def test_arrow_keys_navigate_open_autocomplete(self, shortcuts, mock_tab_manager):
+        """Test that arrow keys move through open address suggestions."""
+        mock_tab_manager.autocomplete.is_open = True
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.key = "Arrow Down"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.autocomplete.move.assert_called_once_with(1)
+        shortcuts.page.update.assert_called_once()
+
+    def test_escape_dismisses_autocomplete(self, shortcuts, mock_tab_manager):
+        """Test that Escape closes open address suggestions."""
+        mock_tab_manager.autocomplete.is_open = True
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.key = "Escape"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.autocomplete.dismiss.assert_called_once()
+
+    def test_f11_toggles_fullscreen(self, shortcuts, mock_tab_manager):
+        """Test that F11 toggles distraction-free fullscreen mode."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.key = "F11"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.toggle_fullscreen.assert_called_once()
+
+    def test_ctrl_shift_f12_toggles_performance_overlay(
+        self,
+        shortcuts,
+        mock_tab_manager,
+    ):
+        """Test that Ctrl+Shift+F12 shows or hides the performance overlay."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.alt = False
+        event.key = "F12"
+        event.shift = True
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.performance_overlay.toggle.assert_called_once()
+
+    def test_f12_toggles_developer_tools(self, shortcuts, mock_tab_manager):
+        """Test that F12 shows or hides the developer tools."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.alt = False
+        event.key = "F12"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.devtools.toggle.assert_called_once()
+        mock_tab_manager.performance_overlay.toggle.assert_not_called()
+
+    def test_ctrl_r_reloads_and_ctrl_shift_r_hard_refreshes(
+        self,
+        shortcuts,
+        mock_tab_manager,
+    ):
+        """Test that Ctrl+R reloads the tab and Ctrl+Shift+R hard refreshes it."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.alt = False
+        event.key = "R"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+        mock_tab_manager.reload.assert_called_once_with(0)
+
+        event.shift = True
+        shortcuts.on_keyboard(event)
+        mock_tab_manager.reload.assert_called_with(0, hard=True)
+
+    def test_alt_home_goes_home(self, shortcuts, mock_tab_manager):
+        """Test that Alt+Home navigates the active tab to the home page."""
+        mock_tab_manager.autocomplete.is_open = False
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.alt = True
+        event.key = "Home"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.go_home.assert_called_once()
+        mock_tab_manager.toggle_fullscreen.assert_not_called()
+
+    def test_escape_leaves_fullscreen(self, shortcuts, mock_tab_manager):
+        """Test that Escape restores the normal layout from fullscreen."""
+        mock_tab_manager.autocomplete.is_open = False
+        mock_tab_manager.bookmark_editor.is_open = False
+        mock_tab_manager.fullscreen = True
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.key = "Escape"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.set_fullscreen.assert_called_once_with(False)
+
+    def test_escape_closes_bookmark_editor(self, shortcuts, mock_tab_manager):
+        """Test that Escape closes the bookmark editor first."""
+        mock_tab_manager.autocomplete.is_open = False
+        mock_tab_manager.bookmark_editor.is_open = True
+        mock_tab_manager.fullscreen = True
+        event = Mock()
+        event.ctrl = False
+        event.meta = False
+        event.key = "Escape"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.bookmark_editor.close.assert_called_once()
+        mock_tab_manager.set_fullscreen.assert_not_called()
+
+    def test_bookmark_page_ctrl_d(self, shortcuts, mock_tab_manager):
+        """Test Ctrl+D shortcut for bookmarking the current page."""
+        event = Mock()
+        event.ctrl = True
+        event.meta = False
+        event.key = "d"
+        event.shift = False
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.bookmark_current_page.assert_called_once()
+
     def test_unknown_key_returns_early(self, shortcuts, mock_tab_manager):
         """Test that unknown key combinations don't trigger actions."""
         event = Mock()
@@ -238,3 +595,74 @@ class TestShortcuts:
         shortcuts.on_keyboard(event)
 
         mock_tab_manager.select_tab.assert_called_once_with(0)  # Stay on same tab
+
+    def test_rebound_shortcut(self, shortcuts, mock_tab_manager):
+        """Test that rebinding an action moves it to the new chord."""
+        mock_tab_manager.settings = {"keybindings": {"new_tab": ["Ctrl+N"]}}
+        event = Mock(ctrl=True, meta=False, alt=False, shift=False, key="t")
+
+        shortcuts.on_keyboard(event)
+        mock_tab_manager._on_add_click.assert_not_called()
+
+        event.key = "n"
+        shortcuts.on_keyboard(event)
+        mock_tab_manager._on_add_click.assert_called_once_with(None)
+
+    def test_unused_modifiers_are_ignored(self, shortcuts, mock_tab_manager):
+        """Test that Shift with Ctrl+= zooms in while Ctrl+Shift+T reopens."""
+        mock_tab_manager.settings = {}
+        event = Mock(ctrl=True, meta=False, alt=False, shift=True, key="=")
+
+        shortcuts.on_keyboard(event)
+
+        mock_tab_manager.zoom.zoom_in.assert_called_once()
+        assert shortcuts.action_for("Ctrl+Shift+T") == "reopen_tab"
+        assert shortcuts.action_for("Ctrl+Alt+T") == "new_tab"
+
+
+class TestKeybindings:
+    """Test cases for parsing and checking key chords."""
+
+    def test_parse_chord(self):
+        """Test that chords are put in their normal form."""
+        assert parse_chord("shift+ctrl+t") == "Ctrl+Shift+T"
+        assert parse_chord("Cmd + l") == "Ctrl+L"
+        assert parse_chord("Ctrl++") == "Ctrl++"
+        assert parse_chord("alt+arrow left") == "Alt+Arrow Left"
+        assert parse_chord("F5") == "F5"
+
+    def test_parse_chord_rejects_bad_chords(self):
+        """Test that chords that cannot work are refused."""
+        for chord in ("", "Ctrl+", "Shift+K", "Hyper+K"):
+            with pytest.raises(ValueError):
+                parse_chord(chord)
+
+    def test_keybindings_apply_valid_overrides(self):
+        """Test that stored overrides replace the defaults they name."""
+        bindings = keybindings(
+            {
+                "history": ["ctrl+y", "nonsense+", "Ctrl+Y"],
+                "downloads": [],
+                "unknown": ["Ctrl+U"],
+            },
+        )
+
+        assert bindings["history"] == ["Ctrl+Y"]
+        assert bindings["downloads"] == []
+        assert bindings["new_tab"] == ["Ctrl+T"]
+        assert "unknown" not in bindings
+        assert keybindings("bad") == keybindings(None)
+
+    def test_every_action_has_one_category(self):
+        """Test that the categories hold each action exactly once."""
+        grouped = [a for actions in ACTION_CATEGORIES.values() for a in actions]
+
+        assert sorted(grouped) == sorted(ACTIONS)
+
+    def test_find_conflicts(self):
+        """Test that chords bound twice are reported with their actions."""
+        assert find_conflicts(keybindings(None)) == {}
+
+        bindings = keybindings({"history": ["Ctrl+T"]})
+
+        assert find_conflicts(bindings) == {"Ctrl+T": ["new_tab", "history"]}
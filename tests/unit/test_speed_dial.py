@@ -0,0 +1,116 @@
+from unittest.mock import Mock
+
+from ren_browser.history.history import HistoryEntry
+from ren_browser.speed_dial.speed_dial import SpeedDial, Tile, top_sites
+
+
+def _storage(tiles=None):
+    return Mock(load_speed_dial=Mock(return_value=tiles or []))
+
+
+def _visits(*addresses):
+    """Build history entries, most recent first, for the given addresses."""
+    return [
+        HistoryEntry(address, f"Title {address}", float(len(addresses) - n))
+        for n, address in enumerate(addresses)
+    ]
+
+
+class TestTopSites:
+    """Test cases for picking top sites from history."""
+
+    def test_most_visited_first(self):
+        """Test that pages are ordered by visit count, then by recency."""
+        entries = _visits("b:/page/index.mu", "a:/page/index.mu", "a:/page/index.mu")
+
+        tiles = top_sites(entries, 5)
+
+        assert [tile.address for tile in tiles] == [
+            "a:/page/index.mu",
+            "b:/page/index.mu",
+        ]
+        assert not tiles[0].pinned
+
+    def test_excludes_pinned_and_internal_pages(self):
+        """Test that pinned addresses and about: pages are left out."""
+        entries = _visits("about:history", "a:/page/index.mu", "b:/page/index.mu")
+
+        tiles = top_sites(entries, 5, exclude={"a:/page/index.mu"})
+
+        assert [tile.address for tile in tiles] == ["b:/page/index.mu"]
+
+    def test_limit(self):
+        """Test that no more than the requested number of tiles is returned."""
+        entries = _visits("a:/page/index.mu", "b:/page/index.mu", "c:/page/index.mu")
+
+        assert len(top_sites(entries, 2)) == 2
+        assert top_sites(entries, 0) == []
+
+
+class TestSpeedDial:
+    """Test cases for the SpeedDial class."""
+
+    def test_loads_pinned_tiles(self):
+        """Test that stored tiles are loaded, skipping malformed and duplicates."""
+        storage = _storage(
+            [
+                {"address": "a:/page/index.mu", "label": "A"},
+                {"address": "a:/page/index.mu", "label": "Again"},
+                {"label": "no address"},
+                "junk",
+            ],
+        )
+
+        dial = SpeedDial(storage)
+
+        assert dial.pinned() == [Tile("a:/page/index.mu", "A")]
+
+    def test_pin_rename_and_unpin(self):
+        """Test that tiles can be pinned, renamed and unpinned."""
+        storage = _storage()
+        dial = SpeedDial(storage)
+
+        dial.pin("a:/page/index.mu", "Node A")
+        dial.rename("a:/page/index.mu", "Weather")
+
+        assert dial.get("a:/page/index.mu").label == "Weather"
+        storage.save_speed_dial.assert_called_with(
+            [{"address": "a:/page/index.mu", "label": "Weather"}],
+        )
+
+        dial.unpin("a:/page/index.mu")
+        assert dial.pinned() == []
+
+    def test_move_reorders_tiles(self):
+        """Test that moving a tile changes its position."""
+        dial = SpeedDial(_storage())
+        for name in "abc":
+            dial.pin(f"{name}:/page/index.mu", name)
+
+        dial.move("c:/page/index.mu", 0)
+
+        assert [tile.label for tile in dial.pinned()] == ["c", "a", "b"]
+
+    def test_tiles_fill_with_top_sites(self):
+        """Test that free tiles are filled with the most visited pages."""
+        dial = SpeedDial(_storage(), max_tiles=2)
+        dial.pin("a:/page/index.mu", "A")
+        entries = _visits("a:/page/index.mu", "b:/page/index.mu", "c:/page/index.mu")
+
+        tiles = dial.tiles(entries)
+
+        assert [(tile.address, tile.pinned) for tile in tiles] == [
+            ("a:/page/index.mu", True),
+            ("b:/page/index.mu", False),
+        ]
+
+    def test_storage_errors_are_swallowed(self):
+        """Test that failing storage does not break the speed dial."""
+        storage = Mock()
+        storage.load_speed_dial.side_effect = OSError("disk gone")
+        storage.save_speed_dial.side_effect = OSError("disk gone")
+
+        dial = SpeedDial(storage)
+        dial.pin("a:/page/index.mu", "A")
+
+        assert len(dial.pinned()) == 1
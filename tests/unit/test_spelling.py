@@ -0,0 +1,161 @@
+from unittest.mock import Mock, patch
+
+import flet as ft
+import pytest
+
+from ren_browser.renderer.gemtext import build_input_page
+from ren_browser.spelling.spelling import (
+    Misspelling,
+    SpellCheck,
+    SpellChecker,
+    find_dictionaries,
+    pick_dictionary,
+    read_dictionary,
+    replace_word,
+)
+from ren_browser.ui.spelling import SpellCheckedField, finished_misspellings
+
+WORDS = {"hello", "mesh", "node", "nodes", "there", "don't"}
+
+
+class TestSpellChecker:
+    """Test cases for checking words against a dictionary."""
+
+    def test_read_hunspell_dictionary(self, tmp_path):
+        """Test that the word count and affix flags are left out."""
+        path = tmp_path / "en_US.dic"
+        path.write_text("3\nHello/MS\nmesh\n# comment\nnode/S\n", encoding="utf-8")
+
+        assert read_dictionary(path) == {"hello", "mesh", "node"}
+
+    def test_find_and_pick_dictionaries(self, tmp_path):
+        """Test that dictionaries are found by name and picked by language."""
+        own = tmp_path / "own"
+        system = tmp_path / "system"
+        own.mkdir()
+        system.mkdir()
+        (own / "de_DE.txt").write_text("hallo", encoding="utf-8")
+        (system / "de_DE.dic").write_text("1\nhallo", encoding="utf-8")
+        (system / "en_US.dic").write_text("1\nhello", encoding="utf-8")
+        (system / "en_US.aff").write_text("", encoding="utf-8")
+
+        found = find_dictionaries(own, system, tmp_path / "missing")
+
+        assert found == {"de_DE": own / "de_DE.txt", "en_US": system / "en_US.dic"}
+        assert pick_dictionary(found, "de") == own / "de_DE.txt"
+        assert pick_dictionary(found, "en_US") == system / "en_US.dic"
+        assert pick_dictionary(found, "fr") is None
+
+    def test_misspellings(self):
+        """Test that unknown words are found with their position."""
+        checker = SpellChecker(WORDS)
+
+        found = checker.misspellings("Hello thre, NASA nodes dont know 42")
+
+        assert [m.word for m in found] == ["thre", "dont", "know"]
+        assert found[0] == Misspelling("thre", 6, 10)
+        assert checker.misspellings("Don't") == []
+
+    def test_suggestions(self):
+        """Test that close words are suggested in the case of the word."""
+        checker = SpellChecker(WORDS)
+
+        assert checker.suggestions("noed") == ["node"]
+        assert checker.suggestions("Meshh") == ["Mesh"]
+        assert checker.suggestions("helo") == ["hello"]
+        assert checker.suggestions("zzzzzzzzzzzz") == []
+
+    def test_ignore(self):
+        """Test that an ignored word is no longer misspelled."""
+        checker = SpellChecker(WORDS)
+        checker.ignore("Reticulum")
+
+        assert checker.misspellings("reticulum") == []
+
+    def test_replace_word(self):
+        """Test that a misspelled word is replaced in place."""
+        assert replace_word("a thre b", Misspelling("thre", 2, 6), "there") == (
+            "a there b"
+        )
+
+
+class TestSpellCheck:
+    """Test cases for spell checking following the settings."""
+
+    def test_configure_and_add_dictionary(self, tmp_path):
+        """Test that the dictionary follows the language settings."""
+        spelling = SpellCheck(tmp_path / "dictionaries")
+        download = tmp_path / "pt_BR.dic"
+        download.write_text("1\nolá", encoding="utf-8")
+
+        with patch("ren_browser.spelling.spelling.SYSTEM_DICTIONARY_DIRS", ()):
+            spelling.configure({}, "pt")
+            assert spelling.checker() is None
+
+            assert spelling.add_dictionary(download) == "pt_BR"
+            spelling.configure({}, "pt")
+            assert spelling.path == tmp_path / "dictionaries" / "pt_BR.dic"
+            assert spelling.checker().known("Olá")
+
+            spelling.configure({"spell_check_language": "en_US"}, "pt")
+            assert spelling.checker() is None
+            spelling.configure({"spell_check": False}, "pt")
+            assert spelling.checker() is None
+
+    def test_add_wrong_file(self, tmp_path):
+        """Test that files that are not dictionaries are refused."""
+        with pytest.raises(ValueError):
+            SpellCheck(tmp_path).add_dictionary(tmp_path / "notes.pdf")
+
+
+class TestSpellCheckedField:
+    """Test cases for listing the misspelled words of a field."""
+
+    def _field(self, mock_page):
+        spelling = Mock()
+        spelling.checker.return_value = SpellChecker(WORDS)
+        on_change = Mock()
+        field = ft.TextField(on_change=on_change)
+        return SpellCheckedField(mock_page, field, spelling), on_change
+
+    def test_word_being_typed_is_not_marked(self):
+        """Test that the last word is only checked once it is finished."""
+        checker = SpellChecker(WORDS)
+
+        assert finished_misspellings(checker, "hello thr") == []
+        assert [m.word for m in finished_misspellings(checker, "hello thr ")] == [
+            "thr",
+        ]
+
+    def test_replace_suggestion(self, mock_page):
+        """Test that picking a suggestion replaces the word in the field."""
+        checked, on_change = self._field(mock_page)
+        checked.field.value = "hello noed mesh"
+
+        checked.field.on_change(None)
+
+        on_change.assert_called_once_with(None)
+        assert checked.marks.visible
+        mark = checked.marks.controls[0]
+        mark.items[0].on_click(None)
+        assert checked.field.value == "hello node mesh"
+        assert not checked.marks.visible
+
+    def test_ignore_word(self, mock_page):
+        """Test that an ignored word is no longer listed."""
+        checked, _ = self._field(mock_page)
+        checked.field.value = "qqq mesh"
+        checked.check()
+
+        checked.marks.controls[0].items[-1].on_click(None)
+
+        assert checked.marks.controls == []
+
+    def test_sensitive_input_is_not_checked(self):
+        """Test that Gemini password input is never spell checked."""
+        spell_check = Mock(side_effect=lambda field: ft.Column(controls=[field]))
+
+        build_input_page("Password?", True, Mock(), spell_check=spell_check)
+        spell_check.assert_not_called()
+        build_input_page("Search?", False, Mock(), spell_check=spell_check)
+        spell_check.assert_called_once()
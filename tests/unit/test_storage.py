@@ -5,6 +5,9 @@ from unittest.mock import Mock, patch
 
 import pytest
 
+from ren_browser.storage import portable, schema
+from ren_browser.storage.profiles import ProfileError
+from ren_browser.storage.schema import SETTINGS_VERSION, VERSION_KEY
 from ren_browser.storage.storage import (
     StorageManager,
     get_storage_manager,
@@ -344,6 +347,124 @@ class TestStorageManager:
             loaded_history = storage.load_history()
             assert loaded_history == history
 
+    def test_save_and_load_downloads(self):
+        """Test that the download history round-trips through storage."""
+        with tempfile.TemporaryDirectory() as temp_dir:
+            storage = StorageManager()
+            storage._storage_dir = Path(temp_dir)
+
+            downloads = [{"address": "abc:/file/a.zip", "status": "completed"}]
+            assert storage.save_downloads(downloads) is True
+
+            assert storage.load_downloads() == downloads
+
+    def test_save_and_load_update_state(self):
+        """Test that the last update check round-trips through storage."""
+        with tempfile.TemporaryDirectory() as temp_dir:
+            storage = StorageManager()
+            storage._storage_dir = Path(temp_dir)
+
+            assert storage.load_update_state() == {}
+            state = {"checked_at": 1.5, "latest": {"version": "v0.6.0"}}
+            assert storage.save_update_state(state) is True
+
+            assert storage.load_update_state() == state
+
+    def test_save_and_load_speed_dial(self):
+        """Test that pinned speed dial tiles round-trip through storage."""
+        with tempfile.TemporaryDirectory() as temp_dir:
+            storage = StorageManager()
+            storage._storage_dir = Path(temp_dir)
+
+            tiles = [{"address": "abc:/page/index.mu", "label": "Home node"}]
+            assert storage.save_speed_dial(tiles) is True
+
+            assert storage.load_speed_dial() == tiles
+
+    def test_save_and_load_session(self):
+        """Test that the open tabs round-trip through storage."""
+        with tempfile.TemporaryDirectory() as temp_dir:
+            storage = StorageManager()
+            storage._storage_dir = Path(temp_dir)
+
+            assert storage.load_session() == {}
+            session = {"tabs": ["abc:/page/index.mu", None], "index": 1}
+            assert storage.save_session(session) is True
+
+            assert storage.load_session() == session
+
+    def test_app_settings_are_versioned(self):
+        """Test that old files are migrated and unknown settings are kept."""
+        with tempfile.TemporaryDirectory() as temp_dir:
+            storage = StorageManager()
+            storage._storage_dir = Path(temp_dir)
+            path = storage.get_app_settings_path()
+            path.write_text(
+                json.dumps({"theme": "light", "warp_drive": True}),
+                encoding="utf-8",
+            )
+
+            settings = storage.load_app_settings()
+            assert settings["theme"] == "light"
+            assert settings["warp_drive"] is True
+            assert VERSION_KEY not in settings
+
+            assert storage.save_app_settings(settings) is True
+            stored = json.loads(path.read_text(encoding="utf-8"))
+            assert stored[VERSION_KEY] == SETTINGS_VERSION
+            assert stored["warp_drive"] is True
+
+            # A newer release's file keeps its version when saved again
+            path.write_text(
+                json.dumps({VERSION_KEY: SETTINGS_VERSION + 1}),
+                encoding="utf-8",
+            )
+            storage.save_app_settings(storage.load_app_settings())
+            stored = json.loads(path.read_text(encoding="utf-8"))
+            assert stored[VERSION_KEY] == SETTINGS_VERSION + 1
+
+    def test_settings_migrations_run_in_order(self, monkeypatch):
+        """Test that settings pass through every migration since their version."""
+        monkeypatch.setattr(schema, "SETTINGS_VERSION", 3)
+        monkeypatch.setattr(
+            schema,
+            "MIGRATIONS",
+            {
+                0: lambda s: s,
+                1: lambda s: {**s, "steps": [*s.get("steps", []), 1]},
+                2: lambda s: {**s, "steps": [*s["steps"], 2]},
+            },
+        )
+
+        assert schema.migrate_settings({VERSION_KEY: 1}) == {"steps": [1, 2]}
+        assert schema.migrate_settings({VERSION_KEY: 2, "steps": []}) == {
+            "steps": [2],
+        }
+        assert schema.migrate_settings({VERSION_KEY: 5, "a": 1}) == {"a": 1}
+        assert schema.migrate_settings([]) == []
+
+    def test_custom_page_background_becomes_page_colours(self):
+        """Test that an old page background is kept as custom page colours."""
+        assert schema.migrate_settings(
+            {VERSION_KEY: 1, "page_bgcolor": "#f0f0f0"},
+        ) == {
+            "page_bgcolor": "#f0f0f0",
+            "page_colors": "custom",
+            "page_fgcolor": "#000000",
+        }
+        assert schema.migrate_settings({VERSION_KEY: 1, "page_bgcolor": "#000000"}) == {
+            "page_bgcolor": "#000000",
+        }
+
+    def test_get_downloads_path_fallback(self):
+        """Test that downloads go to the storage directory without ~/Downloads."""
+        with tempfile.TemporaryDirectory() as temp_dir:
+            storage = StorageManager()
+            storage._storage_dir = Path(temp_dir)
+
+            with patch("pathlib.Path.home", return_value=Path(temp_dir) / "home"):
+                assert storage.get_downloads_path() == Path(temp_dir) / "downloads"
+
     def test_get_storage_info(self):
         """Test getting storage information."""
         with tempfile.TemporaryDirectory() as temp_dir:
@@ -384,6 +505,106 @@ class TestStorageManager:
                 assert storage._storage_dir == expected_fallback
 
 
+class TestProfiles:
+    """Test cases for keeping the data of several profiles apart."""
+
+    @pytest.fixture
+    def base(self, tmp_path):
+        """Use a temporary folder as the storage directory."""
+        with patch.object(
+            StorageManager,
+            "_get_storage_directory",
+            return_value=tmp_path,
+        ):
+            yield tmp_path
+
+    def test_profiles_have_their_own_data(self, base):
+        """Test that a profile's files live in a folder of its own."""
+        default = StorageManager()
+        testing = StorageManager(profile="testing")
+        testing.save_app_settings({"theme": "light"})
+
+        assert default.get_app_settings_path() == base / "settings.json"
+        assert testing.get_app_settings_path().parent == base / "profiles" / "testing"
+        assert default.load_app_settings()["theme"] == "dark"
+        assert testing.load_app_settings()["theme"] == "light"
+        assert testing.get_reticulum_config_path() == (
+            base / "profiles" / "testing" / "reticulum"
+        )
+        assert default.list_profiles() == ["default", "testing"]
+
+    def test_create_profile(self, base):
+        """Test that profiles need a new, plain name."""
+        storage = StorageManager()
+
+        assert storage.create_profile(" work ") == "work"
+        assert (base / "profiles" / "work").is_dir()
+        for name in ("work", "default", "../up", "", "a b"):
+            with pytest.raises(ProfileError):
+                storage.create_profile(name)
+
+    def test_startup_profile(self, base):
+        """Test that the chosen profile is used by later launches."""
+        storage = StorageManager()
+        storage.create_profile("work")
+        storage.set_startup_profile("work")
+
+        assert StorageManager().profile == "work"
+        assert StorageManager(profile="default").profile == "default"
+
+        # A removed profile falls back to the default one
+        (base / "profiles" / "work").rmdir()
+        assert StorageManager().profile == "default"
+
+    def test_client_storage_keys_per_profile(self, base):
+        """Test that web storage keys are kept apart for other profiles."""
+        page = Mock()
+        page.client_storage.get.return_value = None
+        StorageManager(page, "testing").save_bookmarks([])
+
+        assert page.client_storage.set.call_args[0][0] == (
+            "ren_browser_bookmarks:testing"
+        )
+
+
+class TestPortableMode:
+    """Test cases for keeping all data beside the executable."""
+
+    @pytest.fixture
+    def app_dir(self, tmp_path):
+        """Pretend the browser was started from a temporary folder."""
+        with patch(
+            "ren_browser.storage.portable.app_directory",
+            return_value=tmp_path,
+        ):
+            yield tmp_path
+        portable.set_portable(False)
+
+    def test_marker_file_turns_on_portable_mode(self, app_dir):
+        """Test that a portable file beside the executable is enough."""
+        assert portable.portable_directory() is None
+        assert StorageManager().portable is False
+
+        (app_dir / "portable").write_text("")
+        storage = StorageManager()
+
+        data = app_dir / "ren_browser_data"
+        assert storage.portable is True
+        assert storage.get_app_settings_path() == data / "settings.json"
+        assert storage.get_reticulum_config_path() == data / "reticulum"
+        assert storage.get_downloads_path() == data / "downloads"
+        assert storage.get_storage_info()["portable"] is True
+
+    def test_flag_turns_on_portable_mode(self, app_dir):
+        """Test that portable mode can be turned on without a marker file."""
+        portable.set_portable(True)
+        storage = StorageManager(profile="testing")
+
+        assert storage.get_app_settings_path().parent == (
+            app_dir / "ren_browser_data" / "profiles" / "testing"
+        )
+
+
 class TestStorageGlobalFunctions:
     """Test cases for global storage functions."""
 
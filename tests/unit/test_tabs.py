@@ -4,135 +4,1855 @@ from unittest.mock import Mock, patch
 import flet as ft
 import pytest
 
+from ren_browser.bookmarks.bookmarks import BookmarkManager
+from ren_browser.downloads.downloads import DownloadManager
+from ren_browser.feed.feed import NodeFeed
+from ren_browser.gemini.gemini import GeminiError, GeminiInput, GeminiResponse
+from ren_browser.history.history import HistoryManager
+from ren_browser.nodes.nodes import NodeMetadata, NodeOverrides
+from ren_browser.pages.disk_cache import DiskCache
+from ren_browser.pages.revisions import PageRevisions
+from ren_browser.reading_list.reading_list import ReadingList
+from ren_browser.speed_dial.speed_dial import SpeedDial
+from ren_browser.tabs.auto_refresh import interval_label, parse_interval
+from ren_browser.tabs.groups import GROUP_COLORS, TabGroup, next_color
+from ren_browser.tabs.session import SessionManager
 from ren_browser.tabs.tabs import TabsManager
+from ren_browser.themes.themes import ThemeLibrary
+from ren_browser.ui.fonts import READABLE_FONT
+from ren_browser.updates.updates import Release
+from ren_browser.watch.watch import PageWatcher
+
+PNG_HEADER = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x04\x00\x00\x00\x03"
 
 
 class TestTabsManager:
     """Test cases for the TabsManager class."""
 
-    @pytest.fixture
-    def tabs_manager(self, mock_page):
-        """Create a TabsManager instance for testing."""
-        mock_page.width = 800  # Simulate page width for adaptive logic
-        with (
-            patch("ren_browser.app.RENDERER", "plaintext"),
-            patch("ren_browser.renderer.plaintext.render_plaintext") as mock_render,
+    @pytest.fixture
+    def tabs_manager(self, mock_page, tmp_path):
+        """Create a TabsManager instance for testing."""
+        mock_page.width = 800  # Simulate page width for adaptive logic
+        storage = Mock(
+            load_history=Mock(return_value=[]),
+            load_bookmarks=Mock(return_value=[]),
+            load_downloads=Mock(return_value=[]),
+            load_session=Mock(return_value={}),
+            load_speed_dial=Mock(return_value=[]),
+            load_watched_pages=Mock(return_value=[]),
+            load_feed=Mock(return_value={}),
+            load_node_metadata=Mock(return_value={}),
+        )
+        with (
+            patch("ren_browser.app.RENDERER", "plaintext"),
+            patch("ren_browser.renderer.plaintext.render_plaintext") as mock_render,
+            patch(
+                "ren_browser.tabs.tabs.HistoryManager",
+                lambda _: HistoryManager(storage),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.BookmarkManager",
+                lambda _: BookmarkManager(storage),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.SpeedDial",
+                lambda _: SpeedDial(storage),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.SessionManager",
+                lambda _: SessionManager(storage),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.PageWatcher",
+                lambda _, fetch: PageWatcher(storage, fetch),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.NodeFeed",
+                lambda _, fetch: NodeFeed(storage, fetch),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.NodeMetadata",
+                lambda _: NodeMetadata(storage),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.ReadingList",
+                lambda _: ReadingList(tmp_path / "reading_list"),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.ThemeLibrary",
+                lambda _: ThemeLibrary(tmp_path / "themes"),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.DiskCache",
+                lambda _, limit: DiskCache(tmp_path / "cache", limit),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.PageRevisions",
+                lambda _: PageRevisions(tmp_path / "revisions"),
+            ),
+            patch(
+                "ren_browser.tabs.tabs.DownloadManager",
+                lambda _, __, run_thread: DownloadManager(
+                    storage,
+                    tmp_path / "downloads",
+                    run_thread=run_thread,
+                ),
+            ),
+        ):
+            mock_render.return_value = Mock(spec=ft.Text)
+            return TabsManager(mock_page)
+
+    def test_tabs_manager_init(self, mock_page):
+        """Test TabsManager initialization."""
+        with (
+            patch("ren_browser.app.RENDERER", "plaintext"),
+            patch("ren_browser.renderer.plaintext.render_plaintext") as mock_render,
+        ):
+            mock_render.return_value = Mock(spec=ft.Text)
+            manager = TabsManager(mock_page)
+
+            assert manager.page == mock_page
+            assert isinstance(manager.manager, SimpleNamespace)
+            assert len(manager.manager.tabs) == 1
+            assert manager.manager.index == 0
+            assert isinstance(manager.tab_bar, ft.Container)
+            assert isinstance(manager.tab_bar.content, ft.Row)
+            assert manager.overflow_menu is None
+            assert isinstance(manager.content_container, ft.Container)
+
+    def test_tabs_manager_init_uses_given_settings(self, mock_page):
+        """Test that settings passed in are used instead of loaded again."""
+        with (
+            patch("ren_browser.app.RENDERER", "plaintext"),
+            patch("ren_browser.tabs.tabs.get_storage_manager") as get_storage,
+        ):
+            manager = TabsManager(mock_page, {"startup": "restore"})
+
+        assert manager.settings == {"startup": "restore"}
+        get_storage.return_value.load_app_settings.assert_not_called()
+
+    def test_tabs_manager_init_micron_renderer(self, mock_page):
+        """Test TabsManager initialization with micron renderer."""
+        with patch("ren_browser.app.RENDERER", "micron"):
+            manager = TabsManager(mock_page)
+
+            # Verify that micron renderer was selected and TabsManager was created
+            assert manager.page == mock_page
+            assert len(manager.manager.tabs) == 1
+
+    def test_add_tab_internal(self, tabs_manager):
+        """Test adding a tab internally."""
+        content = Mock(spec=ft.Text)
+        tabs_manager._add_tab_internal("Test Tab", content)
+
+        assert len(tabs_manager.manager.tabs) == 2
+        new_tab = tabs_manager.manager.tabs[1]
+        assert new_tab["title"] == "Test Tab"
+        assert new_tab["content_control"] == content
+
+    def test_on_add_click(self, tabs_manager):
+        """Test adding a new tab via button click."""
+        with (
+            patch("ren_browser.app.RENDERER", "plaintext"),
+            patch("ren_browser.renderer.plaintext.render_plaintext") as mock_render,
+        ):
+            mock_render.return_value = Mock(spec=ft.Text)
+            initial_count = len(tabs_manager.manager.tabs)
+
+            tabs_manager._on_add_click(None)
+
+            assert len(tabs_manager.manager.tabs) == initial_count + 1
+            assert tabs_manager.manager.index == initial_count
+            tabs_manager.page.update.assert_called()
+
+    def test_on_close_click_multiple_tabs(self, tabs_manager):
+        """Test closing a tab when multiple tabs exist."""
+        tabs_manager._add_tab_internal("Tab 2", Mock())
+        tabs_manager._add_tab_internal("Tab 3", Mock())
+        tabs_manager.select_tab(1)
+
+        initial_count = len(tabs_manager.manager.tabs)
+        tabs_manager._on_close_click(None)
+
+        assert len(tabs_manager.manager.tabs) == initial_count - 1
+        tabs_manager.page.update.assert_called()
+
+    def test_on_close_click_single_tab(self, tabs_manager):
+        """Test closing a tab when only one tab exists (should not close)."""
+        initial_count = len(tabs_manager.manager.tabs)
+        tabs_manager._on_close_click(None)
+
+        assert len(tabs_manager.manager.tabs) == initial_count
+
+    def test_closed_tab_is_listed_and_reopened(self, tabs_manager):
+        """Test that a closed tab is remembered and can be reopened."""
+        tabs_manager.open_link_in_new_tab("about:history")
+        tabs_manager._on_close_click(None)
+
+        assert [entry.address for entry in tabs_manager.closed_tabs] == [
+            "about:history",
+        ]
+        assert tabs_manager.closed_tabs[0].title == "History"
+
+        tabs_manager.reopen_closed_tab()
+
+        assert tabs_manager.closed_tabs == []
+        assert tabs_manager.manager.tabs[-1]["address"] == "about:history"
+
+    def test_new_tab_page_lists_recently_closed(self, tabs_manager):
+        """Test that the new tab page shows recently closed tabs."""
+        tabs_manager.open_link_in_new_tab("about:history")
+        tabs_manager._on_close_click(None)
+
+        tabs_manager._on_add_click(None)
+
+        content = tabs_manager.manager.tabs[-1]["content_control"]
+        closed = content.controls[-1].content.controls
+        assert closed[0].value == "Recently closed"
+        closed[1].on_click(None)
+        assert tabs_manager.manager.tabs[-1]["address"] == "about:history"
+
+    def test_playground_keeps_its_markup(self, tabs_manager):
+        """Test that the playground renders micron and keeps what was typed."""
+        tabs_manager.open_link_in_new_tab("about:playground")
+        page = tabs_manager.manager.tabs[-1]["content_control"]
+        editor = page.controls[2].controls[0].content
+        editor.value = ">Draft"
+        editor.on_change(None)
+        assert tabs_manager.playground_source == ">Draft"
+
+        tabs_manager.open_link_in_new_tab("about:playground")
+        page = tabs_manager.manager.tabs[-1]["content_control"]
+        assert page.controls[2].controls[0].content.value == ">Draft"
+        assert tabs_manager.manager.tabs[-1]["title"] == "Micron Playground"
+
+    def test_closed_tabs_are_capped(self, tabs_manager):
+        """Test that only the most recent closed tabs are kept."""
+        for _ in range(12):
+            tabs_manager.open_link_in_new_tab("about:bookmarks")
+            tabs_manager._on_close_click(None)
+
+        assert len(tabs_manager.closed_tabs) == 10
+
+    def test_blank_tab_is_not_remembered(self, tabs_manager):
+        """Test that closing a tab that never loaded a page is not recorded."""
+        tabs_manager._on_add_click(None)
+        tabs_manager._on_close_click(None)
+
+        assert tabs_manager.closed_tabs == []
+        tabs_manager.reopen_closed_tab()
+        assert len(tabs_manager.manager.tabs) == 1
+
+    def test_pin_to_speed_dial(self, tabs_manager):
+        """Test that the current page can be pinned to the new tab page."""
+        address = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(address, ">Node page")
+        tabs_manager.manager.tabs[0]["url_field"].value = address
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.pin_to_speed_dial(0)
+
+        tile = tabs_manager.speed_dial.pinned()[0]
+        assert tile.address == address
+        tabs_manager.speed_dial.storage.save_speed_dial.assert_called()
+
+    def test_opens_local_micron_file(self, tabs_manager, tmp_path):
+        """Test that a file:// address renders the page from disk."""
+        page = tmp_path / "index.mu"
+        page.write_text(">Preview\nHello", encoding="utf-8")
+        tabs_manager.page.run_thread = Mock()
+
+        tabs_manager.open_link_in_new_tab(page.as_uri())
+
+        tab = tabs_manager.manager.tabs[-1]
+        assert tab["address"] == page.as_uri()
+        assert tab["title"] == "Preview"
+        assert tab["origin"] == "local"
+        assert tab["source"] == ">Preview\nHello"
+        tabs_manager.page.run_thread.assert_not_called()
+
+    def test_opens_local_markdown_file(self, tabs_manager, tmp_path):
+        """Test that a Markdown file is rendered and its relative links resolve."""
+        (tmp_path / "readme.md").write_text("# Readme\n[Next](next.md)", "utf-8")
+        (tmp_path / "next.md").write_text("# Next", encoding="utf-8")
+        with patch("ren_browser.tabs.tabs.render_markdown") as mock_render:
+            tabs_manager.open_link_in_new_tab((tmp_path / "readme.md").as_uri())
+            assert tabs_manager.manager.tabs[-1]["title"] == "Readme"
+            mock_render.call_args.kwargs["on_link_click"](":/page/next.md")
+
+        tab = tabs_manager.manager.tabs[-1]
+        assert tab["address"] == (tmp_path / "next.md").as_uri()
+        assert tab["title"] == "Next"
+
+    def test_opens_local_code_file(self, tabs_manager, tmp_path):
+        """Test that a config file is shown in the code viewer."""
+        config = tmp_path / "config.toml"
+        config.write_text("[reticulum]\nshare_instance = Yes", encoding="utf-8")
+
+        with patch("ren_browser.tabs.tabs.render_code") as mock_render:
+            tabs_manager.open_link_in_new_tab(config.as_uri())
+
+        assert mock_render.call_args[0][1].name == "Config"
+
+    def test_lxmf_links_show_the_address_card(self, tabs_manager):
+        """Test that LXMF links open the address card instead of a fetch."""
+        node = "0123456789abcdef0123456789abcdef"
+        tabs_manager.lxmf_card.show = Mock()
+        tab_count = len(tabs_manager.manager.tabs)
+
+        with patch("ren_browser.tabs.tabs.PageFetcher") as fetcher:
+            tabs_manager._follow_link(0, f"lxmf@{node}", node)
+            tabs_manager.open_link_in_new_tab(f"lxmf:{node}")
+
+        assert tabs_manager.lxmf_card.show.call_count == 2
+        tabs_manager.lxmf_card.show.assert_called_with(node)
+        assert len(tabs_manager.manager.tabs) == tab_count
+        fetcher.assert_not_called()
+
+    def test_downloads_are_kept_as_notifications(self, tabs_manager):
+        """Test that finished and failed downloads are kept in the center."""
+        tabs_manager._on_download_complete(
+            SimpleNamespace(name="a.zip", path="/tmp/a.zip"),
+        )
+        tabs_manager._on_download_failed(
+            SimpleNamespace(name="b.zip", address="abc:/file/b.zip", error="No path"),
+        )
+
+        failed, done = tabs_manager.notifications.entries()
+        assert (done.kind, done.message, done.address) == (
+            "downloads",
+            "a.zip downloaded",
+            None,
+        )
+        assert failed.kind == "error"
+        assert failed.address == "abc:/file/b.zip"
+        assert "No path" in failed.message
+
+    def test_downloaded_image_opens_in_viewer(self, tabs_manager, tmp_path):
+        """Test that a finished image download is shown in a background tab."""
+        image = tmp_path / "map.png"
+        image.write_bytes(PNG_HEADER)
+        tabs_manager.page.run_thread = lambda fn: fn()
+
+        tabs_manager._on_download_complete(
+            SimpleNamespace(name="map.png", path=str(image)),
+        )
+
+        tab = tabs_manager.manager.tabs[-1]
+        assert tabs_manager.manager.index == 0
+        assert tab["address"] == image.resolve().as_uri()
+        assert tab["title"] == "map.png"
+        assert tab["source"] is None
+        viewer_bar = tab["content_control"].controls[0]
+        assert "4 × 3" in viewer_bar.controls[0].value
+
+    def test_local_page_links_open_files_beside_it(self, tabs_manager, tmp_path):
+        """Test that /page/ links on a local page open the sibling files."""
+        (tmp_path / "index.mu").write_text("`[About`:/page/about.mu]", "utf-8")
+        (tmp_path / "about.mu").write_text(">About", encoding="utf-8")
+        with patch("ren_browser.tabs.tabs.render_micron") as mock_render:
+            tabs_manager.open_link_in_new_tab((tmp_path / "index.mu").as_uri())
+            mock_render.call_args.kwargs["on_link_click"](":/page/about.mu")
+
+        tab = tabs_manager.manager.tabs[-1]
+        assert tab["address"] == (tmp_path / "about.mu").as_uri()
+        assert tab["back"] == [(tmp_path / "index.mu").as_uri()]
+
+    def test_missing_local_file_is_reported(self, tabs_manager, tmp_path):
+        """Test that a file that cannot be read is reported in the address bar."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = (tmp_path / "missing.mu").as_uri()
+
+        tabs_manager._on_tab_go(None, 0)
+
+        assert "Cannot open" in tab["url_field"].error_text
+
+    def test_opens_gemini_page(self, tabs_manager):
+        """Test that a gemini:// address is fetched and rendered as gemtext."""
+        url = "gemini://example.org/"
+        tabs_manager.page.run_thread = lambda action: action()
+        tabs_manager.gemini.fetch = Mock(
+            return_value=GeminiResponse(url, "text/gemini", "# Station\nHello"),
+        )
+
+        tabs_manager.open_link_in_new_tab(url)
+
+        tab = tabs_manager.manager.tabs[-1]
+        tabs_manager.gemini.fetch.assert_called_once_with(url, None)
+        assert tab["title"] == "Station"
+        assert tab["origin"] == "gemini"
+        assert tabs_manager.page_text(len(tabs_manager.manager.tabs) - 1) == (
+            "Station\nHello"
+        )
+
+    def test_new_release_is_announced(self, tabs_manager):
+        """Test that a new release is announced with a link to its notes."""
+        tabs_manager.updates.check = Mock(return_value=Release("v0.6.0"))
+
+        with patch("ren_browser.tabs.tabs.show_snack") as mock_snack:
+            tabs_manager.check_for_updates()
+
+        assert "v0.6.0" in mock_snack.call_args[0][1]
+        with patch.object(tabs_manager, "open_link_in_new_tab") as mock_open:
+            mock_snack.call_args.kwargs["on_action"](None)
+        mock_open.assert_called_once_with("about:update")
+
+    def test_failed_update_check_reported_when_asked(self, tabs_manager):
+        """Test that only a check asked for reports that it failed."""
+        tabs_manager.updates.check = Mock(side_effect=OSError("offline"))
+        done = Mock()
+
+        with patch("ren_browser.tabs.tabs.show_snack") as mock_snack:
+            tabs_manager.check_for_updates()
+            mock_snack.assert_not_called()
+            tabs_manager.check_for_updates(done, manual=True)
+
+        assert mock_snack.call_args.kwargs["success"] is False
+        done.assert_called_once()
+
+    def test_gemini_input_loads_answer(self, tabs_manager):
+        """Test that the answer to a Gemini prompt is sent as the query."""
+        url = "gemini://example.org/search"
+        tabs_manager.page.run_thread = lambda action: action()
+        tabs_manager.gemini.fetch = Mock(side_effect=GeminiInput(url, "Query?"))
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+
+        with patch("ren_browser.tabs.tabs.build_input_page") as mock_page:
+            tabs_manager._on_tab_go(None, 0)
+            mock_page.call_args[0][2]("two words")
+
+        tabs_manager.gemini.fetch.assert_called_with(f"{url}?two%20words", None)
+
+    def test_changed_gemini_certificate_can_be_trusted(self, tabs_manager):
+        """Test that the error page offers to trust a changed certificate."""
+        url = "gemini://example.org/"
+        tabs_manager.page.run_thread = lambda action: action()
+        tabs_manager.gemini.fetch = Mock(
+            side_effect=GeminiError("changed", "certificate"),
+        )
+        tabs_manager.gemini.forget = Mock()
+        tabs_manager.manager.tabs[0]["url_field"].value = url
+
+        with patch("ren_browser.tabs.tabs.build_error_page") as mock_page:
+            tabs_manager._on_tab_go(None, 0)
+            assert mock_page.call_args[0][0] == "certificate"
+            mock_page.call_args.kwargs["on_trust"]()
+
+        tabs_manager.gemini.forget.assert_called_once_with("example.org:1965")
+        assert tabs_manager.gemini.fetch.call_count == 2
+
+    def test_petname_addresses_open_their_destination(self, tabs_manager):
+        """Test that ~name in the address bar loads the destination it names."""
+        url = "0123456789abcdef0123456789abcdef:/page/board.mu"
+        tabs_manager.petnames.set("alice", url.split(":")[0])
+        tabs_manager.page_cache.put(url, "Board")
+        tab = tabs_manager.manager.tabs[0]
+
+        tab["url_field"].value = "~alice/page/board.mu"
+        tabs_manager._on_tab_go(None, 0)
+        assert tab["address"] == url
+
+        tab["url_field"].value = "~bob"
+        tabs_manager._on_tab_go(None, 0)
+        assert tab["url_field"].error_text == "There is no petname ~bob."
+
+    def test_internal_pages_are_not_pinned(self, tabs_manager):
+        """Test that about: pages cannot be pinned to the speed dial."""
+        tabs_manager.manager.tabs[0]["url_field"].value = "about:history"
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.pin_to_speed_dial(0)
+
+        assert tabs_manager.speed_dial.pinned() == []
+
+    def test_select_tab(self, tabs_manager):
+        """Test selecting a tab."""
+        tabs_manager._add_tab_internal("Tab 2", Mock())
+
+        tabs_manager.select_tab(1)
+
+        assert tabs_manager.manager.index == 1
+        tabs_manager.page.update.assert_called()
+
+    def test_select_tab_updates_background_colors(self, tabs_manager):
+        """Test that selecting a tab updates background colors correctly."""
+        tabs_manager._add_tab_internal("Tab 2", Mock())
+
+        tab_controls = tabs_manager.tab_bar.content.controls[
+            1:-2
+        ]  # Exclude the tab switcher and add/close buttons
+
+        tabs_manager.select_tab(1)
+
+        assert tab_controls[0].bgcolor == ft.Colors.SURFACE_CONTAINER_HIGHEST
+        assert tab_controls[1].bgcolor == ft.Colors.PRIMARY_CONTAINER
+
+    def test_set_tab_title(self, tabs_manager):
+        """Test renaming a tab updates its label and the window title."""
+        tabs_manager.set_tab_title(0, "Node News")
+
+        tab = tabs_manager.manager.tabs[0]
+        assert tab["title"] == "Node News"
+        assert tab["label"].value == "Node News"
+        assert tabs_manager.page.title == "Node News - Ren Browser"
+
+    def test_set_tab_title_falls_back_to_default(self, tabs_manager):
+        """Test that a missing page title restores the original tab title."""
+        tabs_manager.set_tab_title(0, "Node News")
+        tabs_manager.set_tab_title(0, None)
+
+        assert tabs_manager.manager.tabs[0]["title"] == "Home"
+
+    def test_reveal_address_bar_prefills_current_address(self, tabs_manager):
+        """Test that revealing the address bar restores the current address."""
+        tabs_manager.address_bar = Mock(visible=False)
+        tab = tabs_manager.manager.tabs[0]
+        tab["address"] = "abc123:/page/index.mu"
+        tab["url_field"].value = "half typed"
+
+        tabs_manager.reveal_address_bar()
+
+        assert tabs_manager.address_bar.visible is True
+        assert tab["url_field"].value == "abc123:/page/index.mu"
+
+    def test_auto_hide_address_bar_respects_setting(self, tabs_manager):
+        """Test that the address bar only hides when not pinned visible."""
+        tabs_manager.address_bar = Mock(visible=True)
+
+        tabs_manager.settings = {"always_show_address_bar": True}
+        tabs_manager._auto_hide_address_bar()
+        assert tabs_manager.address_bar.visible is True
+
+        tabs_manager.settings = {"always_show_address_bar": False}
+        tabs_manager._auto_hide_address_bar()
+        assert tabs_manager.address_bar.visible is False
+
+    def test_apply_settings_switches_theme(self, tabs_manager):
+        """Test that applying settings switches the page theme."""
+        tabs_manager.apply_settings({**tabs_manager.settings, "theme": "light"})
+
+        assert tabs_manager.page.theme_mode == ft.ThemeMode.LIGHT
+        assert tabs_manager.content_container.bgcolor == ft.Colors.SURFACE
+
+    def test_apply_settings_sets_page_colours(self, tabs_manager):
+        """Test that pages can be shown in sepia under a dark theme."""
+        tabs_manager.apply_settings({**tabs_manager.settings, "page_colors": "sepia"})
+
+        assert tabs_manager.page.theme_mode == ft.ThemeMode.DARK
+        assert tabs_manager.content_container.bgcolor == "#f4ecd8"
+        assert tabs_manager.content_container.theme_mode == ft.ThemeMode.LIGHT
+
+    def test_apply_settings_configures_cache(self, tabs_manager):
+        """Test that applying settings changes the page cache limits."""
+        tabs_manager.apply_settings(
+            {**tabs_manager.settings, "cache_max_age": 0, "cache_max_pages": 3},
+        )
+
+        assert tabs_manager.page_cache.max_age == 0
+        assert tabs_manager.page_cache.max_entries == 3
+
+    def test_content_font_rerenders_open_pages(self, tabs_manager):
+        """Test that changing the page font redraws pages in the new font."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Bulletin")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        before = tab["content_control"]
+
+        tabs_manager.apply_settings({**tabs_manager.settings, "ui_font": "serif"})
+        assert tab["content_control"] is before
+        assert tabs_manager.page.theme.font_family == "serif"
+
+        tabs_manager.apply_settings(
+            {**tabs_manager.settings, "content_font": "monospace"},
+        )
+
+        heading = tab["content_control"].controls[0].content
+        assert heading.value == "Bulletin"
+        assert heading.font_family == "monospace"
+
+    def test_accessibility_settings_rerender_open_pages(self, tabs_manager):
+        """Test that a minimum size and the readable font redraw pages."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">>>>>Fine print")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.apply_settings(
+            {**tabs_manager.settings, "min_font_size": 16, "readable_font": True},
+        )
+
+        heading = tab["content_control"].controls[0].content
+        assert heading.size == 16
+        assert heading.font_family == READABLE_FONT
+
+    def test_node_overrides(self, tabs_manager):
+        """Test that a node's overrides change how its pages load and render."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        node = url.split(":")[0]
+        tabs_manager.page_cache.put(url, ">Bulletin")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.set_node_overrides(node, NodeOverrides(plain_text=True, zoom=2))
+        assert tab["content_control"].value == ">Bulletin"
+        assert tab["content_control"].size == 28
+
+        tabs_manager.set_node_overrides(node, NodeOverrides(bypass_cache=True))
+        tabs_manager.page.run_thread = Mock()
+        tabs_manager._on_tab_go(None, 0)
+        tabs_manager.page.run_thread.assert_called_once()
+
+    def test_toggle_plain_text(self, tabs_manager):
+        """Test that switching the renderer flips the node's plain text override."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        node = url.split(":")[0]
+        tabs_manager.page_cache.put(url, ">Bulletin")
+        tabs_manager.manager.tabs[0]["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.toggle_plain_text(0)
+        assert tabs_manager.nodes.overrides(node).plain_text is True
+        assert tabs_manager.manager.tabs[0]["content_control"].value == ">Bulletin"
+
+        tabs_manager.toggle_plain_text(0)
+        assert tabs_manager.nodes.overrides(node).plain_text is False
+
+    def test_clear_page_cache(self, tabs_manager):
+        """Test that clearing the page cache forgets every page."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Bulletin")
+
+        tabs_manager.clear_page_cache()
+
+        assert tabs_manager.page_cache.get(url) is None
+
+    def test_fullscreen_hides_browser_chrome(self, tabs_manager):
+        """Test that fullscreen shows only content and restores the layout."""
+        tabs_manager.sidebar = Mock(collapsed=False, drawer_mode=False)
+        tabs_manager.status_bar = Mock()
+        tabs_manager.settings = {"show_status_bar": True}
+
+        tabs_manager.toggle_fullscreen()
+
+        assert tabs_manager.fullscreen is True
+        assert tabs_manager.tab_bar.visible is False
+        assert tabs_manager.page.appbar.visible is False
+        assert tabs_manager.sidebar.control.visible is False
+        assert tabs_manager.status_bar.control.visible is False
+        assert tabs_manager.page.window.full_screen is True
+
+        tabs_manager.set_fullscreen(False)
+
+        assert tabs_manager.tab_bar.visible is True
+        assert tabs_manager.page.appbar.visible is True
+        assert tabs_manager.sidebar.control.visible is True
+        assert tabs_manager.sidebar.divider.visible is True
+        assert tabs_manager.status_bar.control.visible is True
+        assert tabs_manager.page.window.full_screen is False
+
+    def test_open_link_in_new_tab(self, tabs_manager):
+        """Test opening a relative link in a new foreground tab."""
+        tabs_manager.open_link_in_new_tab(
+            ":/page/about.mu",
+            "0123456789abcdef0123456789abcdef",
+        )
+
+        assert len(tabs_manager.manager.tabs) == 2
+        assert tabs_manager.manager.index == 1
+        new_tab = tabs_manager.manager.tabs[1]
+        assert new_tab["address"] == "0123456789abcdef0123456789abcdef:/page/about.mu"
+        tabs_manager.page.run_thread.assert_called_once()
+
+    def test_open_link_in_background_tab(self, tabs_manager):
+        """Test that background tabs load without stealing focus."""
+        tabs_manager.open_link_in_new_tab(
+            "0123456789abcdef0123456789abcdef:/page/a.mu",
+            select=False,
+        )
+
+        assert len(tabs_manager.manager.tabs) == 2
+        assert tabs_manager.manager.index == 0
+        tabs_manager.page.run_thread.assert_called_once()
+
+    def test_copy_page_text(self, tabs_manager):
+        """Test that the page's readable text is copied to the clipboard."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["source"] = ">Title\n`[Home`:/page/index.mu] and `*more`*"
+        tab["page_path"] = "/page/index.mu"
+
+        tabs_manager.copy_page_text(0)
+
+        tabs_manager.page.set_clipboard.assert_called_once_with("Title\nHome and more")
+
+    def test_copy_page_text_without_page(self, tabs_manager):
+        """Test that nothing is copied from a tab without a page."""
+        tabs_manager.copy_page_text(0)
+
+        tabs_manager.page.set_clipboard.assert_not_called()
+
+    def test_page_text_and_view_source(self, tabs_manager):
+        """Test reading page text and opening the page source."""
+        tab = tabs_manager.manager.tabs[0]
+        assert tabs_manager.page_text(0) == ""
+
+        tab["source"] = ">Title\n`!Hello`!"
+        tab["page_path"] = "/page/index.mu"
+        assert tabs_manager.page_text(0) == "Title\nHello"
+
+        tabs_manager.view_source(0)
+        assert len(tabs_manager.manager.tabs) == 2
+        assert tabs_manager.manager.tabs[1]["title"] == "Source: Home"
+        assert tabs_manager.manager.index == 1
+        numbers, source = tabs_manager.manager.tabs[1]["content_control"].controls
+        assert numbers.value == "1\n2"
+        assert source.value == ">Title\n`!Hello`!"
+
+    def test_on_tab_go_empty_url(self, tabs_manager):
+        """Test tab go with empty URL."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = ""
+
+        tabs_manager._on_tab_go(None, 0)
+
+        # Should not change anything for empty URL
+        assert len(tabs_manager.manager.tabs) == 1
+
+    def test_on_tab_go_with_url(self, tabs_manager):
+        """Test tab go with valid URL."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "test://example"
+
+        tabs_manager._on_tab_go(None, 0)
+
+        # Verify that the tab content was updated and page was refreshed
+        tabs_manager.page.update.assert_called()
+
+    def test_on_tab_go_invalid_address_shows_inline_error(self, tabs_manager):
+        """Test that malformed addresses are reported on the address field."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "1234"
+
+        tabs_manager._on_tab_go(None, 0)
+
+        assert "hexadecimal" in tab["url_field"].error_text
+        tabs_manager.page.run_thread.assert_not_called()
+
+    def test_on_tab_go_normalizes_address(self, tabs_manager):
+        """Test that addresses are normalized before fetching."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "0123456789ABCDEF0123456789ABCDEF/page/a.mu"
+
+        tabs_manager._on_tab_go(None, 0)
+
+        assert tab["url_field"].value == "0123456789abcdef0123456789abcdef:/page/a.mu"
+        assert tab["url_field"].error_text is None
+        tabs_manager.page.run_thread.assert_called_once()
+
+    def test_load_progress_follows_active_tab(self, tabs_manager):
+        """Test that the progress bar reflects the active tab's load."""
+        tab = tabs_manager.manager.tabs[0]
+
+        tabs_manager._set_load_phase(tab, "transfer", 0.5)
+        assert tabs_manager.progress_bar.visible is True
+        assert 0.25 < tabs_manager.progress_bar.value < 0.85
+
+        tabs_manager._add_tab_internal("Tab 2", Mock())
+        tabs_manager.select_tab(1)
+        assert tabs_manager.progress_bar.visible is False
+
+        tabs_manager.select_tab(0)
+        tabs_manager._set_load_phase(tab, None)
+        assert tabs_manager.progress_bar.visible is False
+
+    def test_tab_spinner_shown_while_loading(self, tabs_manager):
+        """Test that the tab strip spinner follows the tab's load."""
+        tab = tabs_manager.manager.tabs[0]
+        assert tab["spinner"].visible is False
+
+        tabs_manager._set_load_phase(tab, "path")
+        assert tab["spinner"].visible is True
+
+        tabs_manager._set_load_phase(tab, None)
+        assert tab["spinner"].visible is False
+
+    def test_loading_view_has_spinner(self, tabs_manager):
+        """Test that the content area shows a spinner while loading."""
+        tab = tabs_manager.manager.tabs[0]
+        view = tabs_manager._build_loading_view(tab, "abc:/page/index.mu")
+
+        assert isinstance(view.controls[0], ft.ProgressRing)
+
+    def test_tabs_are_labelled_for_screen_readers(self, tabs_manager):
+        """Test that tab labels follow the tab count and the selected tab."""
+        tabs_manager._add_tab_internal("News", Mock())
+        tabs_manager.select_tab(1)
+
+        labels = [tab["label"].semantics_label for tab in tabs_manager.manager.tabs]
+
+        assert labels == ["Tab 1 of 2, Home", "Tab 2 of 2, News, selected"]
+
+    def test_reduced_motion_stops_spinners(self, tabs_manager):
+        """Test that reduced motion shows no spinning indicators."""
+        tabs_manager.settings = {"reduced_motion": True}
+        tab = tabs_manager.manager.tabs[0]
+
+        tabs_manager._set_load_phase(tab, "path")
+        view = tabs_manager._build_loading_view(tab, "abc:/page/index.mu")
+
+        assert tab["spinner"].visible is False
+        assert isinstance(view.controls[0], ft.Icon)
+
+    def test_reduced_motion_skips_hover_redraws(self, tabs_manager):
+        """Test that hovering a link does not redraw with reduced motion."""
+        tabs_manager.settings = {"reduced_motion": True}
+        tabs_manager.page.update.reset_mock()
+
+        tabs_manager._on_link_hover("abc:/page/index.mu")
+
+        assert tabs_manager.hovered_link == "abc:/page/index.mu"
+        tabs_manager.page.update.assert_not_called()
+
+    def test_on_tab_go_serves_fresh_cache(self, tabs_manager):
+        """Test that cached pages are shown without fetching."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Cached Title")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.page.run_thread.assert_not_called()
+        assert tab["origin"] == "cache"
+        assert tab["title"] == "Cached Title"
+
+    def test_failed_fetch_shows_error_page(self, tabs_manager):
+        """Test that fetch failures render an error page with actions."""
+        from ren_browser.pages.page_request import PageFetchError
+
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            side_effect=PageFetchError("no_path", "No path"),
+        ):
+            tabs_manager._on_tab_go(None, 0)
+
+        assert tab["load_failed"] is True
+        assert tab["load_progress"] is None
+        error_column = tab["content_control"].content
+        assert error_column.controls[1].value == "No path to node"
+        assert error_column.controls[3].value == url
+        buttons = error_column.controls[5].controls
+        assert buttons[2].disabled is True
+        assert tab["trace"].response == {"error": "no_path", "message": "No path"}
+
+    def test_page_loads_are_traced(self, tabs_manager):
+        """Test that a page load records its request, response and phases."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        def fetch_page(req, on_progress=None, refresh_path=False, encoding=None):
+            on_progress("transfer", 0.5)
+            return ">Hello"
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            staticmethod(fetch_page),
+        ):
+            tabs_manager._on_tab_go(None, 0, use_cache=False)
+
+        trace = tab["trace"]
+        assert trace.address == url
+        assert trace.request["page_path"] == "/page/index.mu"
+        assert trace.request["use_cache"] is False
+        assert trace.response == {"origin": "network", "bytes": 6, "lines": 1}
+        assert trace.renderer == "micron"
+        assert [phase for phase, _ in trace.timings()] == [
+            "link",
+            "transfer",
+            "parse",
+            "render",
+        ]
+        assert trace.finished is not None
+
+        tabs_manager._open_address("about:history")
+        assert tab["trace"] is None
+
+    def test_plugins_transform_and_render_pages(self, tabs_manager):
+        """Test that plugin hooks change pages and render their page formats."""
+        rendered = ft.Text("gemtext")
+
+        def register(plugin):
+            plugin.add_transform(lambda content, path: content.replace("a", "o"))
+            plugin.add_renderer(".gmi", lambda content, path, on_link: rendered)
+
+        tabs_manager.plugins.load("gemini", "test", lambda: register)
+        tab = tabs_manager.manager.tabs[0]
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        tab["url_field"].value = "0123456789abcdef0123456789abcdef:/page/index.gmi"
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            return_value="# Gemtext",
+        ):
+            tabs_manager._on_tab_go(None, 0)
+
+        assert tab["content_control"] is rendered
+        assert tab["trace"].renderer == "plugin:gemini"
+        assert tab["source"] == "# Gemtext"
+
+        tab["url_field"].value = "0123456789abcdef0123456789abcdef:/page/plain.txt"
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            return_value="banana",
+        ):
+            tabs_manager._on_tab_go(None, 0)
+
+        assert tab["trace"].renderer == "plaintext"
+        assert tab["content_control"].value == "bonono"
+
+    def test_reload_skips_cache_and_hard_refresh_requests_path(self, tabs_manager):
+        """Test that reloading fetches again and a hard refresh asks for a path."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, "Cached")
+        tab = tabs_manager.manager.tabs[0]
+        tab["address"] = url
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        with (
+            patch(
+                "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+                return_value="Fresh",
+            ) as fetch,
+            patch("ren_browser.tabs.tabs.show_snack") as snack,
+        ):
+            tabs_manager.reload(0)
+            assert fetch.call_args.kwargs["refresh_path"] is False
+            snack.assert_not_called()
+
+            tabs_manager.reload(0, hard=True)
+            assert fetch.call_args.kwargs["refresh_path"] is True
+            snack.assert_called_once()
+
+        assert tab["source"] == "Fresh"
+        assert tabs_manager.page_cache.get(url).content == "Fresh"
+
+    def test_back_and_forward_walk_tab_history(self, tabs_manager):
+        """Test that a tab goes back and forward through the pages it showed."""
+        first = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        second = "0123456789abcdef0123456789abcdef:/page/two.mu"
+        tabs_manager.page_cache.put(first, "First")
+        tabs_manager.page_cache.put(second, "Second")
+        tab = tabs_manager.manager.tabs[0]
+        for url in (first, second):
+            tab["url_field"].value = url
+            tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.go_back(0)
+        assert tab["address"] == first
+        assert tab["source"] == "First"
+        tabs_manager.go_back(0)
+        assert tab["address"] == first
+
+        tabs_manager.go_forward(0)
+        assert tab["address"] == second
+        assert tab["source"] == "Second"
+
+        tabs_manager.go_back(0)
+        tabs_manager._open_address("about:history")
+        assert tab["back"] == [first]
+        assert tab["forward"] == []
+
+    def test_blocked_node_refuses_to_load(self, tabs_manager):
+        """Test that pages of blocked nodes are not fetched, even from cache."""
+        node = "0123456789abcdef0123456789abcdef"
+        url = f"{node}:/page/index.mu"
+        tabs_manager.page_cache.put(url, "Cached")
+        tabs_manager.nodes.set_blocked(node, True)
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+
+        tabs_manager._on_tab_go(None, 0)
+
+        tabs_manager.page.run_thread.assert_not_called()
+        error_column = tab["content_control"].content
+        assert error_column.controls[1].value == "Node blocked"
+        [unblock] = error_column.controls[5].controls
+        unblock.on_click(None)
+        assert not tabs_manager.nodes.is_blocked(node)
+        assert tabs_manager.page.run_thread.called
+
+    def test_error_page_offers_stale_cached_copy(self, tabs_manager):
+        """Test that a stale cached copy can be opened after a failure."""
+        from ren_browser.pages.page_request import PageFetchError
+
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, "Old copy")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            side_effect=PageFetchError("timeout", "Timed out"),
+        ):
+            tabs_manager._on_tab_go(None, 0, use_cache=False)
+
+        buttons = tab["content_control"].content.controls[5].controls
+        assert buttons[2].disabled is False
+        assert buttons[2].text.startswith("Show Cached Copy from")
+        buttons[2].on_click(None)
+
+        assert tab["origin"] == "cache"
+        assert tab["source"] == "Old copy"
+        banner, page = tab["content"].controls
+        assert page is tab["content_control"]
+        assert "cached" in banner.content.controls[1].value
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            return_value="New copy",
+        ):
+            banner.content.controls[2].on_click(None)
+
+        assert tab["source"] == "New copy"
+        assert tab["content"].controls == [tab["content_control"]]
+
+    def test_set_auto_refresh_starts_and_stops(self, tabs_manager):
+        """Test that an interval starts a refresh task and 0 turns it off."""
+        tabs_manager.set_auto_refresh(0, 30)
+
+        tab = tabs_manager.manager.tabs[0]
+        assert tab["refresh_interval"] == 30
+        tabs_manager.page.run_task.assert_called_once()
+        assert tabs_manager.auto_refresh.button.icon_color == ft.Colors.PRIMARY
+
+        tabs_manager.set_auto_refresh(0, 0)
+
+        assert tab["refresh_interval"] == 0
+        tabs_manager.page.run_task.assert_called_once()
+        assert tabs_manager.auto_refresh.button.icon_color == ft.Colors.ON_SURFACE
+
+    def test_refresh_silently_updates_changed_page(self, tabs_manager):
+        """Test that a silent refresh bypasses the cache and keeps the scroll."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, "Reading: 1")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        visits = len(tabs_manager.history.entries())
+        tab["scroll_offset"] = 420.0
+        tab["content"].scroll_to = Mock()
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            return_value="Reading: 2",
+        ):
+            tabs_manager.refresh_silently(tab)
+
+        assert tab["source"] == "Reading: 2"
+        assert tabs_manager.page_cache.get(url).content == "Reading: 2"
+        assert len(tabs_manager.history.entries()) == visits
+        tab["content"].scroll_to.assert_called_once_with(offset=420.0, duration=0)
+
+    def test_refresh_silently_skips_unchanged_page(self, tabs_manager):
+        """Test that an unchanged page is not redrawn."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, "Same")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        control = tab["content_control"]
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        with patch("ren_browser.tabs.tabs.PageFetcher.fetch_page", return_value="Same"):
+            tabs_manager.refresh_silently(tab)
+
+        assert tab["content_control"] is control
+
+    def test_expired_page_shown_then_revalidated(self, tabs_manager):
+        """Test that an expired copy is shown while the page is fetched again."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        with patch("ren_browser.pages.cache.time.time", return_value=1000.0):
+            tabs_manager.page_cache.put(url, "Old")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        shown = []
+
+        def fetch_page(req, **kwargs):
+            shown.append(tab["source"])
+            return "New"
+
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            side_effect=fetch_page,
+        ):
+            tabs_manager._on_tab_go(None, 0)
+
+        assert shown == ["Old"]
+        assert tab["source"] == "New"
+        assert tabs_manager.page_cache.get(url).content == "New"
+
+    def test_scrolling_near_end_grows_long_page(self, tabs_manager):
+        """Test that scrolling a long page near its end builds more of it."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, "\n".join(["Line"] * 1000))
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        control = tab["content_control"]
+        rendered = len(control.controls)
+
+        tab["content"].on_scroll(Mock(pixels=100.0, max_scroll_extent=200.0))
+
+        assert tab["scroll_offset"] == 100.0
+        assert len(control.controls) > rendered
+
+    def test_inactive_tab_view_dropped_and_rebuilt(self, tabs_manager):
+        """Test that a page's controls are dropped while its tab is hidden."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Title\nBody")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        tab["scroll_offset"] = 120.0
+        tab["content"].scroll_to = Mock()
+
+        tabs_manager._on_add_click(None)
+
+        assert tab["view_released"] is True
+        assert isinstance(tab["content_control"], ft.Container)
+        assert tab["source"] == ">Title\nBody"
+
+        tabs_manager.select_tab(0)
+
+        assert tab["view_released"] is False
+        assert tab["content_control"].controls[0].content.value == "Title"
+        tab["content"].scroll_to.assert_called_once_with(offset=120.0, duration=0)
+
+    def test_suspend_releases_views_and_resume_rebuilds_active_tab(
+        self,
+        tabs_manager,
+    ):
+        """Test that going to the background drops pages and returning redraws."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Title\nBody")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        tabs_manager.prefetcher.cancel = Mock()
+
+        with patch.object(tabs_manager.session, "save") as save:
+            tabs_manager.suspend()
+
+        assert tabs_manager.suspended is True
+        assert tab["view_released"] is True
+        tabs_manager.prefetcher.cancel.assert_called_once()
+        save.assert_called_once_with([url], 0)
+
+        tabs_manager.resume()
+
+        assert tabs_manager.suspended is False
+        assert tab["view_released"] is False
+        assert tab["content_control"].controls[0].content.value == "Title"
+
+    def test_toggle_watch_and_badge(self, tabs_manager):
+        """Test watching a page and being told when it changes."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, "Version 1")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        tabs_manager.page.overlay = []
+
+        tabs_manager.toggle_watch(0)
+        assert tabs_manager.watcher.get(url) is not None
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            return_value="Version 2",
+        ):
+            tabs_manager.check_watched_pages()
+
+        assert tabs_manager.watcher.unseen_count() == 1
+        assert tabs_manager.watch_btn.badge.text == "1"
+
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            return_value="Version 2",
+        ):
+            tabs_manager._on_tab_go(None, 0, use_cache=False)
+
+        assert tabs_manager.watcher.unseen_count() == 0
+        assert tabs_manager.watch_btn.badge is None
+
+        tabs_manager.toggle_watch(0)
+        assert tabs_manager.watcher.get(url) is None
+
+    def test_desktop_notifications(self, tabs_manager):
+        """Test that changed pages and finished downloads notify the desktop."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.settings["system_notifications"] = True
+        tabs_manager.watcher.watch(url, "Notes", "Version 1")
+
+        with (
+            patch(
+                "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+                return_value="Version 2",
+            ),
+            patch("ren_browser.ui.notify.system_notify") as send,
+        ):
+            tabs_manager.check_watched_pages()
+            tabs_manager._on_download_complete(
+                SimpleNamespace(name="a.zip", path="/tmp/a.zip"),
+            )
+
+        assert [call.args[1] for call in send.call_args_list] == [
+            "Notes has changed",
+            "a.zip downloaded",
+        ]
+
+    def test_prefetches_links_after_load(self, tabs_manager):
+        """Test that links on a loaded page are prefetched when turned on."""
+        node = "0123456789abcdef0123456789abcdef"
+        url = f"{node}:/page/index.mu"
+        tabs_manager.apply_settings({**tabs_manager.settings, "prefetch_links": True})
+        tabs_manager.page_cache.put(url, "`[News`:/page/news.mu]")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+
+        with patch.object(tabs_manager.prefetcher, "prefetch") as prefetch:
+            tabs_manager._on_tab_go(None, 0)
+            tab["private"] = True
+            tabs_manager._on_tab_go(None, 0)
+
+        prefetch.assert_called_once_with([f"{node}:/page/news.mu"])
+
+    def test_toggle_follow_and_feed_badge(self, tabs_manager):
+        """Test following a node and reading its feed."""
+        node = "0123456789abcdef0123456789abcdef"
+        url = f"{node}:/page/index.mu"
+        tabs_manager.page_cache.put(url, "`[News`:/page/news.mu]")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        tabs_manager.page.overlay = []
+        tabs_manager.node_name = {node: "Hilltop"}.get
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+        pages = {url: "`[News`:/page/news.mu]", f"{node}:/page/news.mu": "Old"}
+
+        with patch.object(tabs_manager.feed, "fetch", pages.get):
+            tabs_manager.toggle_follow(0)
+            assert tabs_manager.feed.get(node).name == "Hilltop"
+            pages[f"{node}:/page/news.mu"] = "New"
+            tabs_manager.check_feed()
+
+        assert tabs_manager.feed_btn.badge.text == "1"
+
+        tab["url_field"].value = "about:feed"
+        tabs_manager._on_tab_go(None, 0)
+
+        assert tabs_manager.feed_btn.badge is None
+
+        tabs_manager.manager.tabs[0]["address"] = url
+        tabs_manager.toggle_follow(0)
+        assert tabs_manager.feed.get(node) is None
+
+    def test_announce_ticker_replaces_listener(self, tabs_manager):
+        """Test that reopening about:announces detaches the previous ticker."""
+        service = Mock(log=[])
+        tabs_manager.announce_service = service
+        tab = tabs_manager.manager.tabs[0]
+
+        tab["url_field"].value = "about:announces"
+        tabs_manager._on_tab_go(None, 0)
+        first = tabs_manager.announce_ticker
+        tabs_manager._on_tab_go(None, 0)
+
+        assert tabs_manager.announce_ticker is not first
+        service.remove_listener.assert_called_once_with(first.on_announce)
+        assert service.add_listener.call_count == 2
+
+    def test_read_later_and_open_offline(self, tabs_manager):
+        """Test saving a page to the reading list and reading it offline."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Bulletin")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager._on_tab_go(None, 0)
+        tabs_manager.page.overlay = []
+
+        tabs_manager.read_later(0)
+
+        assert tabs_manager.reading_list.get(url).title == "Bulletin"
+        tabs_manager._on_add_click(None)
+        tabs_manager.open_offline_copy(url)
+        new_tab = tabs_manager.manager.tabs[1]
+        assert new_tab["source"] == ">Bulletin"
+        assert new_tab["origin"] == "offline"
+        assert new_tab["address"] == url
+
+    def test_error_page_offers_reading_list_copy(self, tabs_manager):
+        """Test that a saved copy can be opened when the node is unreachable."""
+        from ren_browser.pages.page_request import PageFetchError
+
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.reading_list.save(url, "Saved", "Saved copy", "/page/index.mu")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = url
+        tabs_manager.page.run_thread.side_effect = lambda fn: fn()
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher.fetch_page",
+            side_effect=PageFetchError("no_path", "No path"),
         ):
-            mock_render.return_value = Mock(spec=ft.Text)
-            return TabsManager(mock_page)
+            tabs_manager._on_tab_go(None, 0)
 
-    def test_tabs_manager_init(self, mock_page):
-        """Test TabsManager initialization."""
-        with (
-            patch("ren_browser.app.RENDERER", "plaintext"),
-            patch("ren_browser.renderer.plaintext.render_plaintext") as mock_render,
+        buttons = tab["content_control"].content.controls[5].controls
+        buttons[2].on_click(None)
+
+        assert tab["origin"] == "offline"
+        assert tab["source"] == "Saved copy"
+
+    def test_save_page_as(self, tabs_manager):
+        """Test that Save Page As offers the fetched source."""
+        tabs_manager.page_saver.choose = Mock()
+        tab = tabs_manager.manager.tabs[0]
+        tabs_manager.save_page_as(0)
+        tabs_manager.page_saver.choose.assert_not_called()
+
+        tab["source"] = ">Title"
+        tab["page_path"] = "/page/news.mu"
+        tabs_manager.save_page_as(0)
+
+        tabs_manager.page_saver.choose.assert_called_once_with(">Title", "/page/news.mu")
+
+    def test_show_qr_code(self, tabs_manager):
+        """Test that the page address is shown as a ren:// QR code."""
+        tabs_manager.qr_code.show = Mock()
+        tab = tabs_manager.manager.tabs[0]
+        tab["address"] = "0123456789abcdef0123456789abcdef:/page/news.mu"
+
+        tabs_manager.show_qr_code(0)
+
+        tabs_manager.qr_code.show.assert_called_once_with(
+            "ren://0123456789abcdef0123456789abcdef/page/news.mu",
+            tab["title"],
+        )
+
+    def test_export_pdf(self, tabs_manager):
+        """Test that the PDF export uses the page source and title."""
+        tabs_manager.page_saver.choose_pdf = Mock()
+        tab = tabs_manager.manager.tabs[0]
+        tab["source"] = ">Title"
+        tab["page_path"] = "/page/news.mu"
+
+        tabs_manager.export_pdf(0)
+
+        tabs_manager.page_saver.choose_pdf.assert_called_once_with(
+            ">Title",
+            "/page/news.mu",
+            "Home",
+        )
+
+    def test_file_address_starts_download(self, tabs_manager):
+        """Test that file addresses are downloaded instead of displayed."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["address"] = "abc:/page/index.mu"
+        tab["url_field"].value = "0123456789abcdef0123456789abcdef:/file/a.zip"
+
+        tabs_manager._on_tab_go(None, 0)
+
+        downloads = tabs_manager.downloads.downloads()
+        assert [d.name for d in downloads] == ["a.zip"]
+        assert tabs_manager.downloads_panel.control.visible is True
+        assert tab["address"] == "abc:/page/index.mu"
+        assert tab["url_field"].value == "abc:/page/index.mu"
+
+    def test_download_asks_where_to_save(self, tabs_manager, tmp_path):
+        """Test that downloads open a save dialog when asking is turned on."""
+        tabs_manager.settings = {
+            **tabs_manager.settings,
+            "ask_download_location": True,
+            "download_dir": str(tmp_path / "files"),
+        }
+        tabs_manager.apply_settings(tabs_manager.settings)
+        picker = tabs_manager.save_location.picker
+        picker.save_file = Mock()
+
+        tabs_manager.download_file("0123456789abcdef0123456789abcdef:/file/a.zip")
+
+        assert tabs_manager.downloads.downloads() == []
+        kwargs = picker.save_file.call_args.kwargs
+        assert kwargs["file_name"] == "a.zip"
+        assert kwargs["initial_directory"] == str(tmp_path / "files")
+        assert tabs_manager.page_saver.directory == tmp_path / "files"
+
+        picker.on_result(Mock(path=str(tmp_path / "chosen.zip")))
+
+        (download,) = tabs_manager.downloads.downloads()
+        assert download.name == "chosen.zip"
+        assert download.target == str(tmp_path / "chosen.zip")
+
+    def test_paste_and_go(self, tabs_manager):
+        """Test navigating to an address taken from the clipboard."""
+        tabs_manager.page.get_clipboard = Mock(
+            return_value="see 0123456789ABCDEF0123456789ABCDEF/page/a.mu",
+        )
+
+        tabs_manager.paste_and_go()
+
+        tab = tabs_manager.manager.tabs[0]
+        assert tab["address"] == "0123456789abcdef0123456789abcdef:/page/a.mu"
+        tabs_manager.page.run_thread.assert_called_once()
+
+    def test_paste_and_go_ignores_empty_clipboard(self, tabs_manager):
+        """Test that an empty clipboard does nothing."""
+        tabs_manager.page.get_clipboard = Mock(return_value="")
+
+        tabs_manager.paste_and_go()
+
+        tabs_manager.page.run_thread.assert_not_called()
+
+    def test_successful_load_is_recorded_in_history(self, tabs_manager):
+        """Test that opened pages are added to the browsing history."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Node Home")
+        tabs_manager.manager.tabs[0]["url_field"].value = url
+
+        tabs_manager._on_tab_go(None, 0)
+
+        entries = tabs_manager.history.entries()
+        assert len(entries) == 1
+        assert entries[0].address == url
+        assert entries[0].title == "Node Home"
+        assert entries[0].node_hash == "0123456789abcdef0123456789abcdef"
+
+    def test_history_recording_can_be_turned_off(self, tabs_manager):
+        """Test that no visits are recorded with the history setting off."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Node Home")
+        tabs_manager.settings = {**tabs_manager.settings, "record_history": False}
+        tabs_manager.manager.tabs[0]["url_field"].value = url
+
+        tabs_manager._on_tab_go(None, 0)
+
+        assert tabs_manager.history.entries() == []
+
+    def test_private_tabs_leave_no_traces(self, tabs_manager):
+        """Test that private tabs skip the history, cache, session and closed tabs."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.settings = {**tabs_manager.settings, "private_tabs": True}
+        tabs_manager.page.run_thread = lambda fn: fn()
+        tabs_manager.open_link_in_new_tab("about:history")
+        tab = tabs_manager.manager.tabs[-1]
+        assert tab["private"] is True
+
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher",
+            return_value=Mock(fetch_page=Mock(return_value=">Secret")),
         ):
-            mock_render.return_value = Mock(spec=ft.Text)
-            manager = TabsManager(mock_page)
+            tab["url_field"].value = url
+            tabs_manager._on_tab_go(None, len(tabs_manager.manager.tabs) - 1)
 
-            assert manager.page == mock_page
-            assert isinstance(manager.manager, SimpleNamespace)
-            assert len(manager.manager.tabs) == 1
-            assert manager.manager.index == 0
-            assert isinstance(manager.tab_bar, ft.Container)
-            assert isinstance(manager.tab_bar.content, ft.Row)
-            assert manager.overflow_menu is None
-            assert isinstance(manager.content_container, ft.Container)
+        assert tab["source"] == ">Secret"
+        assert tabs_manager.history.entries() == []
+        assert tabs_manager.page_cache.get(url) is None
+        tabs_manager.session.storage.save_session.assert_called_with(
+            {"tabs": [None], "index": 0},
+        )
 
-    def test_tabs_manager_init_micron_renderer(self, mock_page):
-        """Test TabsManager initialization with micron renderer."""
-        with patch("ren_browser.app.RENDERER", "micron"):
-            manager = TabsManager(mock_page)
+        tabs_manager._on_close_click(None)
+        assert tabs_manager.closed_tabs == []
 
-            # Verify that micron renderer was selected and TabsManager was created
-            assert manager.page == mock_page
-            assert len(manager.manager.tabs) == 1
+    def test_private_tab_uses_its_own_cache(self, tabs_manager):
+        """Test that a private tab caches pages apart and drops them on close."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page_cache.put(url, ">Shared")
+        tabs_manager.page.run_thread = lambda fn: fn()
+        tabs_manager.open_private_tab()
+        tab = tabs_manager.manager.tabs[-1]
+        idx = len(tabs_manager.manager.tabs) - 1
+        assert tab["private"] is True
+        tab_control = tabs_manager.tab_bar.content.controls[1 + idx]
+        assert tab_control.bgcolor == ft.Colors.TERTIARY_CONTAINER
 
-    def test_add_tab_internal(self, tabs_manager):
-        """Test adding a tab internally."""
-        content = Mock(spec=ft.Text)
-        tabs_manager._add_tab_internal("Test Tab", content)
+        fetcher = Mock(fetch_page=Mock(return_value=">Secret"))
+        with patch("ren_browser.tabs.tabs.PageFetcher", return_value=fetcher):
+            tab["url_field"].value = url
+            tabs_manager._on_tab_go(None, idx)
+            tabs_manager._on_tab_go(None, idx)
 
-        assert len(tabs_manager.manager.tabs) == 2
-        new_tab = tabs_manager.manager.tabs[1]
-        assert new_tab["title"] == "Test Tab"
-        assert new_tab["content_control"] == content
+        assert tab["source"] == ">Secret"
+        fetcher.fetch_page.assert_called_once()
+        assert tabs_manager.page_cache.get(url).content == ">Shared"
 
-    def test_on_add_click(self, tabs_manager):
-        """Test adding a new tab via button click."""
-        with (
-            patch("ren_browser.app.RENDERER", "plaintext"),
-            patch("ren_browser.renderer.plaintext.render_plaintext") as mock_render,
+        cache = tab["cache"]
+        tabs_manager.close_tab(idx)
+        assert len(cache) == 0
+
+    def test_encoding_override_refetches_page(self, tabs_manager):
+        """Test that a tab's encoding decodes its page, kept out of the cache."""
+        url = "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page.run_thread = lambda fn: fn()
+        tab = tabs_manager.manager.tabs[0]
+        fetcher = Mock(
+            fetch_page=Mock(side_effect=[">Detected", ">Chosen", ">Chosen"]),
+        )
+        with patch("ren_browser.tabs.tabs.PageFetcher", return_value=fetcher):
+            tab["url_field"].value = url
+            tabs_manager._on_tab_go(None, 0)
+            tabs_manager.set_encoding(0, "koi8-r")
+            # The page is fetched again rather than shown from the cache
+            tabs_manager._on_tab_go(None, 0)
+
+        assert fetcher.fetch_page.call_count == 3
+        assert fetcher.fetch_page.call_args[1]["encoding"] == "koi8-r"
+        assert tab["source"] == ">Chosen"
+        assert tabs_manager.page_cache.get(url).content == ">Detected"
+
+    def test_changed_page_offers_what_changed(self, tabs_manager):
+        """Test that a page that changed can be switched to its changes."""
+        url = "0123456789abcdef0123456789abcdef:/page/board.mu"
+        tabs_manager.page.run_thread = lambda fn: fn()
+        tab = tabs_manager.manager.tabs[0]
+        fetcher = Mock(
+            fetch_page=Mock(side_effect=["Welcome\nOld post", "Welcome\nNew post"]),
+        )
+        with patch("ren_browser.tabs.tabs.PageFetcher", return_value=fetcher):
+            tab["url_field"].value = url
+            tabs_manager._on_tab_go(None, 0)
+            assert tab["content"].controls == [tab["content_control"]]
+            tabs_manager.reload(0)
+
+        banner, page = tab["content"].controls
+        assert banner.content.controls[2].value is False
+
+        banner.content.controls[2].on_change(None)
+
+        banner, diff = tab["content"].controls
+        assert banner.content.controls[2].value is True
+        assert [row.content.controls[0].value for row in diff.controls] == [
+            " ",
+            "-",
+            "+",
+        ]
+        tabs_manager.toggle_changes(0)
+        assert tab["content_control"] is not diff
+
+    def test_private_tabs_keep_no_versions(self, tabs_manager):
+        """Test that pages of private tabs are not kept to compare."""
+        url = "0123456789abcdef0123456789abcdef:/page/board.mu"
+        tabs_manager.page.run_thread = lambda fn: fn()
+        tab = tabs_manager.manager.tabs[0]
+        tab["private"] = True
+        with patch(
+            "ren_browser.tabs.tabs.PageFetcher",
+            return_value=Mock(fetch_page=Mock(side_effect=["One", "Two"])),
         ):
-            mock_render.return_value = Mock(spec=ft.Text)
-            initial_count = len(tabs_manager.manager.tabs)
+            tab["url_field"].value = url
+            tabs_manager._on_tab_go(None, 0, use_cache=False)
+            tabs_manager._on_tab_go(None, 0, use_cache=False)
 
-            tabs_manager._on_add_click(None)
+        assert tab["content"].controls == [tab["content_control"]]
+        assert tabs_manager.revisions.latest.get(url) is None
 
-            assert len(tabs_manager.manager.tabs) == initial_count + 1
-            assert tabs_manager.manager.index == initial_count
-            tabs_manager.page.update.assert_called()
+    def test_go_home_opens_configured_page(self, tabs_manager):
+        """Test that go_home navigates the active tab to the home page setting."""
+        tabs_manager.settings = {"home_page": "0123456789abcdef0123456789abcdef"}
+        tab = tabs_manager.manager.tabs[0]
 
-    def test_on_close_click_multiple_tabs(self, tabs_manager):
-        """Test closing a tab when multiple tabs exist."""
-        tabs_manager._add_tab_internal("Tab 2", Mock())
-        tabs_manager._add_tab_internal("Tab 3", Mock())
-        tabs_manager.select_tab(1)
+        tabs_manager.go_home()
 
-        initial_count = len(tabs_manager.manager.tabs)
-        tabs_manager._on_close_click(None)
+        assert tab["address"] == "0123456789abcdef0123456789abcdef:/page/index.mu"
+        tabs_manager.page.run_thread.assert_called_once()
 
-        assert len(tabs_manager.manager.tabs) == initial_count - 1
-        tabs_manager.page.update.assert_called()
+    def test_go_home_without_setting_shows_new_tab_page(self, tabs_manager):
+        """Test that go_home shows the new tab page when no home page is set."""
+        tabs_manager.settings = {"home_page": ""}
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "about:history"
+        tabs_manager._on_tab_go(None, 0)
 
-    def test_on_close_click_single_tab(self, tabs_manager):
-        """Test closing a tab when only one tab exists (should not close)."""
-        initial_count = len(tabs_manager.manager.tabs)
-        tabs_manager._on_close_click(None)
+        tabs_manager.go_home()
 
-        assert len(tabs_manager.manager.tabs) == initial_count
+        assert tab["address"] is None
+        assert tab["url_field"].value == ""
+        assert tab["title"] == "Home"
+        tabs_manager.page.run_thread.assert_not_called()
 
-    def test_select_tab(self, tabs_manager):
-        """Test selecting a tab."""
-        tabs_manager._add_tab_internal("Tab 2", Mock())
+    def test_navigation_saves_session(self, tabs_manager):
+        """Test that loading a page saves the open tabs for the next launch."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "about:history"
 
-        tabs_manager.select_tab(1)
+        tabs_manager._on_tab_go(None, 0)
 
-        assert tabs_manager.manager.index == 1
-        tabs_manager.page.update.assert_called()
+        tabs_manager.session.storage.save_session.assert_called_with(
+            {"tabs": ["about:history"], "index": 0},
+        )
 
-    def test_select_tab_updates_background_colors(self, tabs_manager):
-        """Test that selecting a tab updates background colors correctly."""
-        tabs_manager._add_tab_internal("Tab 2", Mock())
+    def test_apply_startup_restores_session(self, tabs_manager):
+        """Test that the restore startup setting reopens the saved tabs."""
+        tabs_manager.settings = {"startup": "restore"}
+        tabs_manager.session.tabs = ["about:history", None, "about:bookmarks"]
+        tabs_manager.session.index = 2
 
-        tab_controls = tabs_manager.tab_bar.content.controls[
-            :-2
-        ]  # Exclude add/close buttons
+        tabs_manager.apply_startup()
 
-        tabs_manager.select_tab(1)
+        tabs = tabs_manager.manager.tabs
+        assert [tab.get("address") for tab in tabs] == [
+            "about:history",
+            None,
+            "about:bookmarks",
+        ]
+        assert tabs_manager.manager.index == 2
 
-        assert tab_controls[0].bgcolor == ft.Colors.GREY_800
-        assert tab_controls[1].bgcolor == ft.Colors.BLUE_900
+    def test_apply_startup_loads_only_selected_tab(self, tabs_manager):
+        """Test that restored tabs load only once they are selected."""
+        tabs_manager.settings = {"startup": "restore"}
+        tabs_manager.session.tabs = ["about:history", "about:bookmarks"]
+        tabs_manager.session.index = 1
 
-    def test_on_tab_go_empty_url(self, tabs_manager):
-        """Test tab go with empty URL."""
+        with patch.object(tabs_manager, "_on_tab_go") as go:
+            tabs_manager.apply_startup()
+            go.assert_called_once_with(None, 1)
+
+            tabs_manager.select_tab(0)
+            go.assert_called_with(None, 0)
+            tabs_manager.select_tab(1)
+            tabs_manager.select_tab(0)
+
+        assert go.call_count == 2
+
+    def test_apply_startup_opens_home_page(self, tabs_manager):
+        """Test that the home startup setting opens the home page."""
+        tabs_manager.settings = {"startup": "home", "home_page": "about:history"}
+        tabs_manager.session.tabs = ["about:bookmarks"]
+
+        tabs_manager.apply_startup()
+
+        assert len(tabs_manager.manager.tabs) == 1
+        assert tabs_manager.manager.tabs[0]["address"] == "about:history"
+
+    def test_apply_startup_new_tab_ignores_session(self, tabs_manager):
+        """Test that the default startup setting keeps a single new tab."""
+        tabs_manager.settings = {"startup": "new_tab"}
+        tabs_manager.session.tabs = ["about:history", "about:bookmarks"]
+
+        tabs_manager.apply_startup()
+
+        assert len(tabs_manager.manager.tabs) == 1
+        assert tabs_manager.manager.tabs[0].get("address") is None
+
+    def test_restore_session_after_open_tabs(self, tabs_manager):
+        """Test that restoring later adds the saved tabs after the open ones."""
+        tabs_manager.settings = {"startup": "home", "home_page": "about:history"}
+        tabs_manager.session.tabs = ["about:bookmarks", "about:cache"]
+        tabs_manager.session.index = 1
+        tabs_manager.apply_startup()
+
+        tabs_manager.restore_session()
+
+        assert [tab.get("address") for tab in tabs_manager.manager.tabs] == [
+            "about:history",
+            "about:bookmarks",
+            "about:cache",
+        ]
+        assert tabs_manager.manager.index == 2
+
+    def test_address_alias_is_expanded(self, tabs_manager):
+        """Test that a keyword typed in the address bar opens its address."""
+        tabs_manager.settings = {"address_aliases": {"hist": "about:history"}}
         tab = tabs_manager.manager.tabs[0]
-        tab["url_field"].value = ""
+        tab["url_field"].value = "Hist"
 
         tabs_manager._on_tab_go(None, 0)
 
-        # Should not change anything for empty URL
+        assert tab["address"] == "about:history"
+        assert tab["url_field"].value == "about:history"
+
+    def test_tab_groups_keep_their_tabs_together(self, tabs_manager):
+        """Test that a grouped tab moves next to its group behind a chip."""
+        for address in ("about:history", "about:bookmarks", "about:cache"):
+            tabs_manager.open_link_in_new_tab(address, defer=True)
+        tabs = tabs_manager.manager.tabs
+        research = TabGroup("Research", "green")
+
+        tabs_manager.add_to_group(1, research)
+        tabs_manager.select_tab(3)
+        tabs_manager.add_to_group(3, research)
+
+        assert [tab.get("address") for tab in tabs] == [
+            None,
+            "about:history",
+            "about:cache",
+            "about:bookmarks",
+        ]
+        assert tabs_manager.manager.index == 2
+        controls = tabs_manager.tab_bar.content.controls
+        chip = controls[2]
+        assert isinstance(chip, ft.PopupMenuButton)
+        assert chip.content.controls[1].value == "Research"
+        assert controls[3:5] == tabs_manager._tab_containers()[1:3]
+
+        tabs_manager.select_tab(0)
+        tabs_manager.toggle_group(research)
+        containers = tabs_manager._tab_containers()
+        assert [container.visible for container in containers] == [
+            True,
+            False,
+            False,
+            True,
+        ]
+
+        tabs_manager.remove_from_group(1)
+        assert tabs[2].get("address") == "about:history"
+        assert "group" not in tabs[2]
+        assert tabs_manager.tab_groups == [research]
+
+    def test_close_tab_group(self, tabs_manager):
+        """Test that closing a group closes its tabs and forgets the group."""
+        tabs_manager.open_link_in_new_tab("about:history", defer=True)
+        tabs_manager.open_link_in_new_tab("about:bookmarks", defer=True)
+        group = TabGroup("Tmp")
+        tabs_manager.add_to_group(1, group)
+        tabs_manager.add_to_group(2, group)
+
+        tabs_manager.close_group(group)
+
+        assert [tab.get("address") for tab in tabs_manager.manager.tabs] == [None]
+        assert tabs_manager.tab_groups == []
+        assert not any(
+            isinstance(control, ft.PopupMenuButton)
+            for control in tabs_manager.tab_bar.content.controls
+        )
+
+    def test_close_group_holding_every_tab(self, tabs_manager):
+        """Test that a new tab is left open when a group held every tab."""
+        group = TabGroup("All")
+        tabs_manager.add_to_group(0, group)
+
+        tabs_manager.close_group(group)
+
         assert len(tabs_manager.manager.tabs) == 1
+        assert "group" not in tabs_manager.manager.tabs[0]
 
-    def test_on_tab_go_with_url(self, tabs_manager):
-        """Test tab go with valid URL."""
+    def test_workspace_round_trip(self, tabs_manager):
+        """Test that a workspace keeps the tab order and reopens its tabs."""
+        tabs_manager.open_link_in_new_tab("about:history", defer=True)
+        tabs_manager.open_link_in_new_tab("about:bookmarks", defer=True)
+        tabs_manager.open_private_tab()
+        tabs_manager.select_tab(2)
+
+        workspace = tabs_manager.current_workspace("Net Night")
+
+        assert [tab.address for tab in workspace.tabs] == [
+            "about:history",
+            "about:bookmarks",
+        ]
+        assert workspace.index == 1
+
+        for idx in range(len(tabs_manager.manager.tabs) - 1, 0, -1):
+            tabs_manager.select_tab(idx)
+            tabs_manager._on_close_click(None)
+        with patch.object(tabs_manager, "_on_tab_go"):
+            tabs_manager.open_workspace(workspace)
+
+        assert [tab.get("address") for tab in tabs_manager.manager.tabs] == [
+            "about:history",
+            "about:bookmarks",
+        ]
+        assert tabs_manager.manager.index == 1
+
+    def test_about_history_internal_page(self, tabs_manager):
+        """Test that about:history opens the history page in the tab."""
         tab = tabs_manager.manager.tabs[0]
-        tab["url_field"].value = "test://example"
+        tab["url_field"].value = "about:history"
 
         tabs_manager._on_tab_go(None, 0)
 
-        # Verify that the tab content was updated and page was refreshed
-        tabs_manager.page.update.assert_called()
+        assert tab["address"] == "about:history"
+        assert tab["title"] == "History"
+        assert tab["origin"] == "local"
+        assert tabs_manager.current_hash(tab) is None
+        tabs_manager.page.run_thread.assert_not_called()
+
+    def test_about_bookmarks_internal_page(self, tabs_manager):
+        """Test that about:bookmarks lists saved bookmarks."""
+        tabs_manager.bookmarks.add("abc:/page/index.mu", "Saved Node")
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "about:bookmarks"
+
+        tabs_manager._on_tab_go(None, 0)
+
+        assert tab["title"] == "Bookmarks"
+        entry = tab["content_control"].controls[-1]
+        assert entry.content.controls[1].content.controls[0].value == "Saved Node"
+
+    def test_bookmark_current_page(self, tabs_manager):
+        """Test that the star bookmarks the page with its title pre-filled."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["address"] = "abc:/page/index.mu"
+        tab["title"] = "Node Home"
+
+        tabs_manager.bookmark_current_page()
+
+        bookmark = tabs_manager.bookmarks.get("abc:/page/index.mu")
+        assert bookmark.title == "Node Home"
+        assert tabs_manager.bookmark_editor.is_open
+        assert tabs_manager.bookmark_editor.title_field.value == "Node Home"
+        assert tabs_manager.bookmark_btn.icon == ft.Icons.STAR
+
+    def test_bookmark_without_address_does_nothing(self, tabs_manager):
+        """Test that a tab without a loaded page cannot be bookmarked."""
+        tabs_manager.bookmark_current_page()
+
+        assert tabs_manager.bookmarks.bookmarks() == []
+        assert not tabs_manager.bookmark_editor.is_open
+
+    def test_switching_tabs_updates_star(self, tabs_manager):
+        """Test that the star reflects the newly selected tab."""
+        tabs_manager.manager.tabs[0]["address"] = "abc:/page/index.mu"
+        tabs_manager.bookmarks.add("abc:/page/index.mu", "Saved")
+        tabs_manager._on_add_click(None)
+        assert tabs_manager.bookmark_btn.icon == ft.Icons.STAR_BORDER
+
+        tabs_manager.select_tab(0)
+
+        assert tabs_manager.bookmark_btn.icon == ft.Icons.STAR
+
+    def test_bookmarks_offered_as_suggestions(self, tabs_manager):
+        """Test that bookmarks feed the address autocomplete."""
+        tabs_manager.bookmarks.add("abc:/page/index.mu", "Saved Node")
+
+        tabs_manager.autocomplete.update_query("saved")
+
+        assert [s.address for s in tabs_manager.autocomplete.suggestions] == [
+            "abc:/page/index.mu",
+        ]
+
+    def test_unknown_internal_page_shows_error(self, tabs_manager):
+        """Test that unknown about: pages are reported inline."""
+        tab = tabs_manager.manager.tabs[0]
+        tab["url_field"].value = "about:nothing"
+
+        tabs_manager._on_tab_go(None, 0)
+
+        assert "about:nothing" in tab["url_field"].error_text
 
     def test_on_tab_go_micron_renderer(self, tabs_manager):
         """Test tab go with micron renderer."""
@@ -148,7 +1868,7 @@ class TestTabsManager:
     def test_tab_container_properties(self, tabs_manager):
         """Test that tab container has correct properties."""
         assert tabs_manager.content_container.expand is True
-        assert tabs_manager.content_container.bgcolor in (ft.Colors.BLACK, "#000000")
+        assert tabs_manager.content_container.bgcolor == ft.Colors.SURFACE
         assert tabs_manager.content_container.padding == ft.padding.all(16)
 
     def test_tab_bar_controls(self, tabs_manager):
@@ -198,8 +1918,8 @@ class TestTabsManager:
         tabs_manager._add_tab_internal("Tab 2", Mock())
 
         tab_controls = tabs_manager.tab_bar.content.controls[
-            :-2
-        ]  # Exclude add/close buttons
+            1:-2
+        ]  # Exclude the tab switcher and add/close buttons
 
         for i, control in enumerate(tab_controls):
             assert control.on_click is not None
@@ -271,3 +1991,128 @@ class TestTabsManager:
 
         assert visible_tabs_large == 11
         assert tabs_manager.overflow_menu is None
+
+    def test_compact_tabs_hide_strip_for_switcher(self, tabs_manager):
+        """Test that compact mode hides every tab and shows the switcher."""
+        for i in range(10):
+            tabs_manager._add_tab_internal(f"Tab {i + 2}", Mock())
+
+        tabs_manager.set_compact_tabs(True)
+
+        assert tabs_manager.overflow_menu is None
+        assert not any(
+            c.visible
+            for c in tabs_manager.tab_bar.content.controls
+            if isinstance(c, ft.Container)
+        )
+        assert tabs_manager.tab_switcher.button.visible is True
+        assert tabs_manager.tab_switcher.button.badge.text == "11"
+
+        tabs_manager.set_compact_tabs(False)
+
+        assert tabs_manager.tab_switcher.button.visible is False
+        assert tabs_manager.overflow_menu is not None
+
+    def test_close_tab_behind_active_keeps_it_selected(self, tabs_manager):
+        """Test that closing another tab leaves the active tab selected."""
+        tabs_manager._add_tab_internal("Tab 2", Mock())
+        tabs_manager._add_tab_internal("Tab 3", Mock())
+        tabs_manager.select_tab(2)
+        active = tabs_manager.manager.tabs[2]
+
+        tabs_manager.close_tab(0)
+
+        assert tabs_manager.manager.index == 1
+        assert tabs_manager.manager.tabs[1] is active
+        assert tabs_manager.content_container.content is active["content"]
+        assert [t["title"] for t in tabs_manager.manager.tabs] == ["Tab 2", "Tab 3"]
+
+
+class TestSessionManager:
+    """Test cases for the SessionManager class."""
+
+    def test_loads_saved_session(self):
+        """Test that the previous session is read at startup."""
+        storage = Mock()
+        storage.load_session.return_value = {
+            "tabs": ["abc:/page/index.mu", None, ""],
+            "index": 1,
+        }
+
+        session = SessionManager(storage)
+
+        assert session.tabs == ["abc:/page/index.mu", None, None]
+        assert session.index == 1
+
+    def test_malformed_session_is_ignored(self):
+        """Test that a corrupt session file starts with no tabs."""
+        storage = Mock()
+        storage.load_session.return_value = {"tabs": "oops", "index": 5}
+
+        session = SessionManager(storage)
+
+        assert session.tabs == []
+        assert session.index == 0
+
+    def test_out_of_range_index_resets(self):
+        """Test that an active index past the last tab falls back to the first."""
+        storage = Mock()
+        storage.load_session.return_value = {"tabs": ["abc:/page/a.mu"], "index": 3}
+
+        assert SessionManager(storage).index == 0
+
+    def test_save_keeps_startup_session(self):
+        """Test that saving writes to storage without replacing the loaded tabs."""
+        storage = Mock()
+        storage.load_session.return_value = {"tabs": ["abc:/page/a.mu"], "index": 0}
+        session = SessionManager(storage)
+
+        session.save([None], 0)
+
+        storage.save_session.assert_called_once_with({"tabs": [None], "index": 0})
+        assert session.tabs == ["abc:/page/a.mu"]
+
+    def test_storage_errors_are_swallowed(self):
+        """Test that failing storage does not break loading or saving."""
+        storage = Mock()
+        storage.load_session.side_effect = OSError("disk gone")
+        storage.save_session.side_effect = OSError("disk gone")
+
+        session = SessionManager(storage)
+        session.save([None], 0)
+
+        assert session.tabs == []
+
+
+class TestAutoRefreshIntervals:
+    """Test cases for auto-refresh intervals."""
+
+    def test_parse_interval(self):
+        """Test parsing custom intervals with and without units."""
+        assert parse_interval("90") == 90
+        assert parse_interval(" 45s ") == 45
+        assert parse_interval("10m") == 600
+        assert parse_interval("1H") == 3600
+        with pytest.raises(ValueError):
+            parse_interval("soon")
+        with pytest.raises(ValueError):
+            parse_interval("2")
+
+    def test_interval_label(self):
+        """Test describing preset and custom intervals."""
+        assert interval_label(0) == "Off"
+        assert interval_label(60) == "Every minute"
+        assert interval_label(600) == "Every 10 min"
+        assert interval_label(90) == "Every 90 s"
+
+
+class TestTabGroups:
+    """Test cases for tab group colours."""
+
+    def test_next_color(self):
+        """Test that new groups get a colour no other group has."""
+        groups = [TabGroup("A", "blue"), TabGroup("B", "red")]
+
+        assert next_color([]) == "blue"
+        assert next_color(groups) == "green"
+        assert next_color([TabGroup(str(i), c) for i, c in enumerate(GROUP_COLORS)])
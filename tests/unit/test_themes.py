@@ -0,0 +1,100 @@
+import pytest
+
+from ren_browser.themes.themes import CustomTheme, ThemeLibrary, is_color, mix
+
+
+class TestCustomTheme:
+    """Test cases for the CustomTheme class."""
+
+    def test_toml_round_trip(self):
+        """Test that a theme survives being written and read back."""
+        theme = CustomTheme('Night "Owl"', accent="#ff8800", tab="#223344")
+
+        assert CustomTheme.from_toml(theme.to_toml()) == theme
+
+    def test_from_toml_keeps_defaults_for_bad_colors(self):
+        """Test that missing or invalid colours fall back to the defaults."""
+        theme = CustomTheme.from_toml(
+            'name = "Mine"\n[colors]\naccent = "#ABCDEF"\ntext = "white"\n',
+        )
+
+        assert theme.accent == "#abcdef"
+        assert theme.text == CustomTheme("").text
+
+    def test_from_toml_rejects_malformed_files(self):
+        """Test that files without a name or with bad TOML are ignored."""
+        assert CustomTheme.from_toml("name = ") is None
+        assert CustomTheme.from_toml('[colors]\naccent = "#000000"') is None
+
+    def test_is_dark(self):
+        """Test telling dark backgrounds from light ones."""
+        assert CustomTheme("a", background="#101010").is_dark()
+        assert not CustomTheme("a", background="#f0f0f0").is_dark()
+
+    def test_colors(self):
+        """Test colour validation and blending."""
+        assert is_color("#1e88e5")
+        assert is_color("#ff1e88e5")
+        assert not is_color("1e88e5")
+        assert not is_color("#12345")
+        assert mix("#000000", "#ffffff", 0.5) == "#808080"
+        assert mix("#ff000000", "#ffffff", 0) == "#000000"
+
+
+class TestThemeLibrary:
+    """Test cases for the ThemeLibrary class."""
+
+    def test_save_and_reload(self, tmp_path):
+        """Test that saved themes are kept as TOML files."""
+        ThemeLibrary(tmp_path).save(CustomTheme("Solar Flare", accent="#ff5500"))
+
+        assert (tmp_path / "solar-flare.toml").exists()
+        library = ThemeLibrary(tmp_path)
+        assert [theme.name for theme in library.themes()] == ["Solar Flare"]
+        assert library.get("Solar Flare").accent == "#ff5500"
+
+    def test_save_rejects_bad_themes(self, tmp_path):
+        """Test that empty names, duplicates and bad colours are refused."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("One"))
+
+        with pytest.raises(ValueError, match="Enter a name"):
+            library.save(CustomTheme("  "))
+        with pytest.raises(ValueError, match="already a theme"):
+            library.save(CustomTheme("One"))
+        with pytest.raises(ValueError, match="Links"):
+            library.save(CustomTheme("Two", link="blue"))
+        assert [theme.name for theme in library.themes()] == ["One"]
+
+    def test_rename_keeps_file(self, tmp_path):
+        """Test that renaming a theme rewrites its file under the new name."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("Old"))
+
+        library.save(CustomTheme("New", accent="#123456"), previous="Old")
+
+        assert library.get("Old") is None
+        assert [file.name for file in tmp_path.iterdir()] == ["old.toml"]
+        assert ThemeLibrary(tmp_path).get("New").accent == "#123456"
+
+    def test_names_sharing_a_file_name(self, tmp_path):
+        """Test that names with the same file name get separate files."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("Deep Sea"))
+        library.save(CustomTheme("deep-sea"))
+
+        assert sorted(file.name for file in tmp_path.iterdir()) == [
+            "deep-sea-2.toml",
+            "deep-sea.toml",
+        ]
+        assert len(ThemeLibrary(tmp_path).themes()) == 2
+
+    def test_remove(self, tmp_path):
+        """Test that removing a theme deletes its file."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("Gone"))
+
+        library.remove("Gone")
+
+        assert library.get("Gone") is None
+        assert list(tmp_path.iterdir()) == []
@@ -1,9 +1,140 @@
-from unittest.mock import Mock, patch
+import asyncio
+import json
+import os
+import time
+from pathlib import Path
+from unittest.mock import AsyncMock, Mock, patch
 
 import flet as ft
+import pytest
 
-from ren_browser.ui.settings import open_settings_tab
+from ren_browser import logs
+from ren_browser.announces.announces import Announce, PathInfo
+from ren_browser.bookmarks.bookmarks import BookmarkManager
+from ren_browser.downloads.downloads import Download
+from ren_browser.feed.feed import NodeFeed
+from ren_browser.history.history import HistoryManager
+from ren_browser.nodes.nodes import NodeInfo, NodeMetadata, NodeOverrides
+from ren_browser.notifications.notifications import NotificationCenter
+from ren_browser.pages.cache import PageCache
+from ren_browser.pages.trace import PageTrace
+from ren_browser.plugins.plugins import PluginInfo
+from ren_browser.qr.qr import encode, to_png
+from ren_browser.reading_list.reading_list import ReadingList
+from ren_browser.speed_dial.speed_dial import SpeedDial
+from ren_browser.spelling.spelling import SpellCheck
+from ren_browser.storage.schema import DEFAULT_APP_SETTINGS
+from ren_browser.storage.storage import StorageManager
+from ren_browser.tabs.groups import TabGroup
+from ren_browser.tabs.session import ClosedTab
+from ren_browser.ui.announce_list import (
+    PAGE_SIZE,
+    AnnounceList,
+    freshness,
+    fuzzy_score,
+    node_matches,
+    sort_announces,
+)
+from ren_browser.ui.announce_ticker import AnnounceTicker, announce_matches
+from ren_browser.ui.avatars import build_node_avatar, node_color, update_node_avatar
+from ren_browser.ui.back_button import BackNavigation
+from ren_browser.ui.bookmarks import build_bookmarks_page
+from ren_browser.ui.cache import build_cache_page, format_age
+from ren_browser.ui.clear_data import ClearDataDialog, clear_browsing_data
+from ren_browser.ui.clipboard_hint import ClipboardHint
+from ren_browser.ui.command_palette import CommandPalette, palette_matches
+from ren_browser.ui.crash_prompt import CrashPrompt
+from ren_browser.ui.devtools import DevToolsPane, block_tree
+from ren_browser.ui.downloads import DownloadsPanel, download_summary, format_size
+from ren_browser.ui.error_page import ERROR_DETAILS, build_error_page
+from ren_browser.ui.favorite_alerts import (
+    OFFLINE_SECONDS,
+    STARTUP_GRACE,
+    FavoriteAlerts,
+)
+from ren_browser.ui.feed import build_feed_page
+from ren_browser.ui.file_area import FileArea
+from ren_browser.ui.fonts import (
+    OTHER_FONT,
+    READABLE_FONT,
+    UI_FONT_PRESETS,
+    FontPicker,
+    local_fonts,
+    min_font_size,
+    page_font,
+    register_fonts,
+)
+from ren_browser.ui.history import build_history_page
+from ren_browser.ui.image_viewer import IMAGE_ZOOM_LEVELS, ImageViewer
+from ren_browser.ui.interfaces import InterfacePanel, interface_details
+from ren_browser.ui.keyboard import KeyboardInsets
+from ren_browser.ui.lifecycle import AppLifecycle
+from ren_browser.ui.link_preview import LinkPreview, preview_lines
+from ren_browser.ui.log_viewer import build_logs_page
+from ren_browser.ui.lxmf_card import LxmfAddressCard
+from ren_browser.ui.node_details import NodeDetails, detail_rows
+from ren_browser.ui.notification_center import NotificationPanel
+from ren_browser.ui.notify import count_badge, desktop_notify, system_notify
+from ren_browser.ui.open_file import FileOpener
+from ren_browser.ui.performance import PerformanceOverlay, performance_lines
+from ren_browser.ui.playground import SNIPPETS, STARTER, build_playground_page
+from ren_browser.ui.print_page import PagePrinter, print_command
+from ren_browser.ui.qr_scan import QrScanner
+from ren_browser.ui.reading_list import build_reading_list_page
+from ren_browser.ui.recently_closed import build_recently_closed
+from ren_browser.ui.save_page import PageSaver, suggested_file_name
+from ren_browser.ui.settings import (
+    _build_accessibility_section,
+    _build_aliases_section,
+    _build_cache_section,
+    _build_downloads_section,
+    _build_logging_section,
+    _build_plugins_section,
+    _build_privacy_section,
+    _build_profiles_section,
+    _build_shortcuts_section,
+    check_color,
+    check_directory,
+    check_home_page,
+    check_number,
+    filter_settings,
+    open_settings_tab,
+    parse_chord_list,
+    setting_labels,
+)
+from ren_browser.ui.settings_watcher import SettingsWatcher
+from ren_browser.ui.shortcut_sheet import ShortcutSheet, sheet_groups
+from ren_browser.ui.sidebar import (
+    COLLAPSED_WIDTH,
+    MAX_SIDEBAR_WIDTH,
+    MIN_SIDEBAR_WIDTH,
+    SIDEBAR_WIDTH,
+    Sidebar,
+)
+from ren_browser.ui.speed_dial import build_speed_dial
+from ren_browser.ui.status_bar import StatusBar, connection_summary, request_summary
+from ren_browser.themes.themes import CustomTheme, ThemeLibrary
+from ren_browser.ui.tab_groups import TabGroupDialog, build_group_chip
+from ren_browser.ui.tab_switcher import TabSwitcher
+from ren_browser.ui.theme import (
+    HIGH_CONTRAST_SCHEME,
+    PAGE_COLOR_PRESETS,
+    apply_content_colors,
+    apply_theme,
+    content_bgcolor,
+    is_high_contrast,
+    page_colors,
+    theme_options,
+)
+from ren_browser.ui.theme_editor import ThemeEditor
+from ren_browser.ui.watched import build_watched_page
+from ren_browser.ui.tray import TrayIcon, tray_lines
+from ren_browser.ui.update_page import build_update_page
+from ren_browser.ui.window import WindowState, WindowTracker, close_warning
+from ren_browser.ui.zoom import ZOOM_LEVELS, UiZoom, step_zoom
 from ren_browser.ui.ui import build_ui
+from ren_browser.updates.updates import Release, UpdateChecker
+from ren_browser.watch.watch import PageWatcher
 
 
 class TestBuildUI:
@@ -43,6 +174,7 @@ class TestBuildUI:
         mock_tab_manager.manager.index = 0
         mock_tab_manager.tab_bar = Mock()
         mock_tab_manager.content_container = Mock()
+        mock_tab_manager.plugins.build_panels.return_value = []
 
         build_ui(mock_page)
 
@@ -55,7 +187,7 @@ class TestBuildUI:
     @patch("ren_browser.pages.page_request.PageFetcher")
     @patch("ren_browser.tabs.tabs.TabsManager")
     @patch("ren_browser.controls.shortcuts.Shortcuts")
-    def test_build_ui_drawer_setup(
+    def test_build_ui_sidebar_setup(
         self,
         mock_shortcuts,
         mock_tabs,
@@ -63,18 +195,21 @@ class TestBuildUI:
         mock_announce_service,
         mock_page,
     ):
-        """Test that build_ui sets up the drawer correctly."""
+        """Test that build_ui docks the announce sidebar next to the content."""
         mock_tab_manager = Mock()
         mock_tabs.return_value = mock_tab_manager
         mock_tab_manager.manager.tabs = [{"url_field": Mock(), "go_btn": Mock()}]
         mock_tab_manager.manager.index = 0
         mock_tab_manager.tab_bar = Mock()
         mock_tab_manager.content_container = Mock()
+        mock_tab_manager.plugins.build_panels.return_value = []
 
         build_ui(mock_page)
 
-        assert mock_page.drawer is not None
-        assert isinstance(mock_page.drawer, ft.NavigationDrawer)
+        layout = mock_page.add.call_args[0][0].content.controls[0]
+        sidebar_control = layout.controls[0]
+        assert isinstance(sidebar_control, ft.Container)
+        assert sidebar_control.width in (SIDEBAR_WIDTH, COLLAPSED_WIDTH)
 
     def test_ui_basic_functionality(self, mock_page):
         """Test basic UI functionality without complex mocking."""
@@ -90,6 +225,812 @@ class TestBuildUI:
         assert mock_page.window.maximized is True
 
 
+class TestWindowState:
+    """Test cases for remembering the window size and position."""
+
+    def test_from_dict_drops_invalid_values(self):
+        """Test that unusable sizes and wrong types are left unset."""
+        state = WindowState.from_dict(
+            {"width": 10, "height": 700, "left": "x", "top": 20, "maximized": 1},
+        )
+
+        assert state == WindowState(height=700.0, top=20.0)
+        assert WindowState.from_dict(None) == WindowState()
+
+    def test_restore_applies_stored_state(self, mock_page):
+        """Test that the window opens where it was closed."""
+        WindowTracker(
+            mock_page,
+            Mock(
+                settings={
+                    "window": {
+                        "width": 1200,
+                        "height": 800,
+                        "left": 40,
+                        "top": 30,
+                        "maximized": False,
+                    },
+                },
+            ),
+            Mock(),
+        )
+
+        assert mock_page.window.width == 1200
+        assert mock_page.window.left == 40
+        assert mock_page.window.maximized is False
+        assert mock_page.window.prevent_close is True
+
+    def test_first_launch_is_maximized(self, mock_page):
+        """Test that without a stored state the window is maximized."""
+        WindowTracker(mock_page, Mock(settings={}), Mock())
+
+        assert mock_page.window.maximized is True
+
+    def test_close_saves_resized_window(self, mock_page):
+        """Test that the last size and position are saved on close."""
+        storage = Mock()
+        tracker = WindowTracker(
+            mock_page,
+            Mock(settings={"confirm_close": False}),
+            storage,
+        )
+        window = mock_page.window
+        window.maximized = window.full_screen = False
+        window.width, window.height, window.left, window.top = 900, 600, 5, 6
+        window.on_event(Mock(type=ft.WindowEventType.UNMAXIMIZE))
+        window.on_event(Mock(type=ft.WindowEventType.RESIZED))
+        window.maximized = True
+        window.width = 1920
+        window.on_event(Mock(type=ft.WindowEventType.MAXIMIZE))
+        window.on_event(Mock(type=ft.WindowEventType.RESIZED))
+
+        window.on_event(Mock(type=ft.WindowEventType.CLOSE))
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved == {
+            "confirm_close": False,
+            "window": {
+                "width": 900.0,
+                "height": 600.0,
+                "left": 5.0,
+                "top": 6.0,
+                "maximized": True,
+            },
+        }
+        assert tracker.tab_manager.settings is saved
+        window.destroy.assert_called_once()
+
+
+class TestCloseConfirmation:
+    """Test cases for asking before the window closes."""
+
+    def _tab_manager(self, tabs=1, statuses=(), **settings):
+        tab_manager = Mock(settings=settings)
+        tab_manager.manager.tabs = [{} for _ in range(tabs)]
+        tab_manager.downloads.downloads.return_value = [
+            Mock(status=status) for status in statuses
+        ]
+        return tab_manager
+
+    def test_close_warning(self):
+        """Test that several tabs and running downloads are described."""
+        assert close_warning(self._tab_manager()) is None
+        assert close_warning(self._tab_manager(statuses=["completed"])) is None
+        assert close_warning(self._tab_manager(tabs=3)) == "3 tabs are open."
+        assert close_warning(
+            self._tab_manager(tabs=2, statuses=["downloading", "paused"]),
+        ) == "2 tabs are open. 1 download is still running."
+
+    def test_asks_before_closing_several_tabs(self, mock_page):
+        """Test that closing with several tabs waits for confirmation."""
+        tracker = WindowTracker(mock_page, self._tab_manager(tabs=2), Mock())
+
+        mock_page.window.on_event(Mock(type=ft.WindowEventType.CLOSE))
+
+        mock_page.open.assert_called_once_with(tracker.dialog)
+        mock_page.window.destroy.assert_not_called()
+
+        tracker.dialog.actions[0].on_click(None)
+        mock_page.window.destroy.assert_not_called()
+
+        tracker.ask_again.value = True
+        tracker.dialog.actions[1].on_click(None)
+        mock_page.window.destroy.assert_called_once()
+        saved = tracker.storage.save_app_settings.call_args[0][0]
+        assert saved["confirm_close"] is False
+
+    def test_setting_turns_off_asking(self, mock_page):
+        """Test that the window closes at once with the setting off."""
+        WindowTracker(
+            mock_page,
+            self._tab_manager(tabs=4, confirm_close=False),
+            Mock(),
+        )
+
+        mock_page.window.on_event(Mock(type=ft.WindowEventType.CLOSE))
+
+        mock_page.open.assert_not_called()
+        mock_page.window.destroy.assert_called_once()
+
+
+class TestTrayIcon:
+    """Test cases for the system tray icon."""
+
+    def _tray(self, mock_page, **settings):
+        tab_manager = Mock(settings=settings)
+        tab_manager.announce_service.get_announces.return_value = [Mock(), Mock()]
+        tab_manager.watcher.unseen_count.return_value = 1
+        tab_manager.feed.unread_count.return_value = 0
+        mock_page.web = False
+        on_quit = Mock()
+        return TrayIcon(mock_page, tab_manager, on_quit), on_quit
+
+    def test_tray_lines(self, mock_page):
+        """Test that the menu shows announced nodes and pending notifications."""
+        tray, _ = self._tray(mock_page)
+
+        assert tray_lines(tray.tab_manager) == [
+            "2 nodes announced",
+            "1 pending notification",
+        ]
+
+    def test_setting_shows_and_removes_icon(self, mock_page):
+        """Test that the icon follows the tray_icon setting."""
+        tray, on_quit = self._tray(mock_page)
+        pystray = Mock()
+        with (
+            patch.dict("sys.modules", {"pystray": pystray}),
+            patch("ren_browser.ui.tray._icon_image"),
+        ):
+            tray.apply_settings({"tray_icon": True})
+
+        assert tray.running
+        pystray.Icon.return_value.run_detached.assert_called_once()
+
+        tray.quit()
+        assert not tray.running
+        pystray.Icon.return_value.stop.assert_called_once()
+        on_quit.assert_called_once()
+
+    def test_without_pystray(self, mock_page):
+        """Test that the icon is left out when pystray is not installed."""
+        tray, _ = self._tray(mock_page)
+
+        with patch.dict("sys.modules", {"pystray": None}):
+            assert not tray.start()
+
+        assert not tray.running
+
+    def test_close_to_tray(self, mock_page):
+        """Test that closing hides the window while the icon is shown."""
+        tracker = WindowTracker(
+            mock_page,
+            Mock(settings={"close_to_tray": True, "confirm_close": False}),
+            Mock(),
+        )
+        tracker.tray, _ = self._tray(mock_page)
+        tracker.tray.icon = Mock()
+
+        mock_page.window.on_event(Mock(type=ft.WindowEventType.CLOSE))
+
+        mock_page.window.destroy.assert_not_called()
+        assert mock_page.window.visible is False
+
+        tracker.tray.show_window()
+        assert mock_page.window.visible is True
+        mock_page.window.to_front.assert_called_once()
+
+
+def _card_name(control):
+    return control.content.content.controls[1].controls[0].value
+
+
+class TestAnnounceList:
+    """Test cases for the sidebar announce list."""
+
+    def _announce(self, destination_hash, name, timestamp=None):
+        return Mock(
+            destination_hash=destination_hash,
+            display_name=name,
+            timestamp=time.time() if timestamp is None else timestamp,
+            aspect="nomadnetwork.node",
+        )
+
+    def _tab_manager(self, settings=None):
+        tab_manager = Mock()
+        tab_manager.settings = settings or {}
+        tab_manager.history = self._history()
+        tab_manager.nodes = NodeMetadata(Mock(load_node_metadata=Mock(return_value={})))
+        return tab_manager
+
+    def _history(self):
+        storage = Mock()
+        storage.load_history.return_value = []
+        history = HistoryManager(storage)
+        history.record("bbb:/page/index.mu", "B", "bbb", timestamp=100.0)
+        history.record("bbb:/page/about.mu", "B", "bbb", timestamp=200.0)
+        history.record("ccc:/page/index.mu", "C", "ccc", timestamp=300.0)
+        return history
+
+    def test_sort_most_visited(self):
+        """Test that nodes visited most come first, unvisited ones last."""
+        announces = [
+            self._announce("aaa", "A"),
+            self._announce("ccc", "C"),
+            self._announce("bbb", "B"),
+        ]
+        stats = self._history().node_stats()
+
+        recent = sort_announces(announces, "recent", stats)
+        visited = sort_announces(announces, "most_visited", stats)
+
+        assert [a.destination_hash for a in recent] == ["aaa", "ccc", "bbb"]
+        assert [a.destination_hash for a in visited] == ["bbb", "ccc", "aaa"]
+
+    def test_sort_by_name_and_first_seen(self):
+        """Test sorting by name, anonymous last, and by first seen."""
+        announces = [
+            Announce("aaa", None, 300, first_seen=50),
+            Announce("bbb", "beta", 200, first_seen=200),
+            Announce("ccc", "Alpha", 100, first_seen=100),
+        ]
+
+        by_name = sort_announces(announces, "name", {})
+        by_first_seen = sort_announces(announces, "first_seen", {})
+
+        assert [a.destination_hash for a in by_name] == ["ccc", "bbb", "aaa"]
+        assert [a.destination_hash for a in by_first_seen] == ["aaa", "ccc", "bbb"]
+
+    def test_cards_show_visit_summary(self):
+        """Test that visited nodes show their visit count."""
+        announce_list = AnnounceList(Mock(), self._tab_manager())
+
+        announce_list.update([self._announce("bbb", "B"), self._announce("aaa", "A")])
+
+        cards = [c.content for c in announce_list.list_view.controls]
+        assert len(cards) == 2
+        summary = cards[0].content.controls[1].controls[1]
+        assert summary.value.startswith("2 visits")
+        assert len(cards[1].content.controls[1].controls) == 1
+
+    def test_keyboard_highlight_moves_and_opens(self):
+        """Test that the arrow keys outline cards one by one and Enter opens one."""
+        announce_list = AnnounceList(Mock(), self._tab_manager())
+        announce_list.update([self._announce("bbb", "B"), self._announce("aaa", "A")])
+        first, second = announce_list.navigable_rows()
+        border = first.border
+        announce_list.open_node = Mock()
+        announce_list.list_view.scroll_to = Mock()
+
+        assert announce_list.move_highlight(0)
+        assert announce_list.highlighted is first
+        assert first.border != border
+        announce_list.move_highlight(5)
+        assert announce_list.highlighted is second
+        assert first.border is border
+
+        assert announce_list.activate_highlight()
+        assert announce_list.open_node.call_args.args[0].destination_hash == "aaa"
+
+        announce_list.clear_highlight()
+        assert announce_list.highlighted is None
+        assert not announce_list.activate_highlight()
+
+    def test_update_rebuilds_only_changed_cards(self):
+        """Test that a refresh reuses the cards of nodes that did not change."""
+        page = Mock()
+        announce_list = AnnounceList(page, self._tab_manager())
+        now = time.time()
+        announce_list.update([Announce("aaa", "A", now), Announce("bbb", "B", now)])
+        first, second = announce_list.list_view.controls
+
+        announce_list.update([Announce("bbb", "Bee", now), Announce("aaa", "A", now)])
+
+        renamed, kept = announce_list.list_view.controls
+        assert kept is first
+        assert renamed is not second
+        assert _card_name(renamed) == "Bee"
+
+    def test_update_skips_unchanged_announces(self):
+        """Test that the same announces again are not rendered a second time."""
+        page = Mock()
+        announce_list = AnnounceList(page, self._tab_manager())
+        announces = [Announce("aaa", "A", time.time())]
+        announce_list.update(announces)
+        page.update.reset_mock()
+
+        with patch.object(announce_list, "render") as render:
+            announce_list.update(list(announces))
+
+        render.assert_not_called()
+        page.update.assert_not_called()
+
+    def test_set_sort_is_saved(self):
+        """Test that changing the sort order persists it."""
+        tab_manager = self._tab_manager({"sidebar_sort": "recent"})
+        announce_list = AnnounceList(Mock(), tab_manager)
+        announce_list.update([self._announce("aaa", "A"), self._announce("bbb", "B")])
+
+        with patch("ren_browser.ui.announce_list.get_storage_manager") as get_storage:
+            announce_list.set_sort("most_visited")
+
+        assert tab_manager.settings["sidebar_sort"] == "most_visited"
+        get_storage.return_value.save_app_settings.assert_called_once()
+        first = announce_list.list_view.controls[0].content.content.controls[1]
+        assert first.controls[0].value == "B"
+
+    def test_node_matches_name_hash_and_note(self):
+        """Test that a search matches any of the name, hash or note."""
+        ann = Announce("abcdef", "Hilltop", 1)
+
+        assert node_matches(ann, None, "  ")
+        assert node_matches(ann, None, "HILL")
+        assert node_matches(ann, None, "cde")
+        assert node_matches(ann, "admin is Kai", "kai")
+        assert not node_matches(ann, None, "kai")
+
+    def test_search_finds_nodes_by_note(self):
+        """Test that notes saved in the detail panel are searchable."""
+        page = Mock()
+        tab_manager = self._tab_manager()
+        announce_list = AnnounceList(page, tab_manager)
+        now = time.time()
+        ann = Announce("bbb", "B", now)
+        announce_list.update([Announce("aaa", "A", now), ann])
+
+        with patch("ren_browser.ui.node_details.path_info", return_value=PathInfo()):
+            announce_list.details.show(ann)
+        announce_list.details.note_field.value = "admin is Kai"
+        announce_list.details.close()
+        announce_list.search_field.on_submit(Mock(control=Mock(value="kai")))
+
+        assert tab_manager.nodes.get("bbb").note == "admin is Kai"
+        assert [_card_name(c) for c in announce_list.list_view.controls] == ["B"]
+
+    def test_fuzzy_search_ranks_best_matches_first(self):
+        """Test that names match as subsequences, closest match first."""
+        announce_list = AnnounceList(Mock(), self._tab_manager())
+        announce_list.update(
+            [
+                self._announce("aaa", "Chillout"),
+                self._announce("bbb", "Cafe on the Hill"),
+                self._announce("ccc", "Hilltop"),
+                self._announce("ddd", "Mesa"),
+            ],
+        )
+
+        announce_list.set_query("hill")
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["Hilltop", "Cafe on the Hill", "Chillout"]
+        announce_list.set_query("hltp")
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["Hilltop"]
+
+        assert fuzzy_score("hill", "hilltop") > fuzzy_score("hlt", "hilltop")
+        assert fuzzy_score("xyz", "hilltop") is None
+        assert not node_matches(Announce("0a1b2c3d", None, 0), None, "abc")
+
+    def test_search_waits_for_typing_to_pause(self):
+        """Test that only the last query typed in quick succession is applied."""
+        page = Mock()
+        announce_list = AnnounceList(page, self._tab_manager())
+        announce_list.update([self._announce("aaa", "A"), self._announce("bbb", "B")])
+
+        for value in ("A", "B"):
+            announce_list.search_field.on_change(Mock(control=Mock(value=value)))
+        first, second = (c.args[0] for c in page.run_task.call_args_list)
+        with patch("ren_browser.ui.announce_list.asyncio.sleep", new=AsyncMock()):
+            asyncio.run(first())
+            assert announce_list.query == ""
+            asyncio.run(second())
+
+        assert [_card_name(c) for c in announce_list.list_view.controls] == ["B"]
+
+    def test_node_list_refreshes_while_shown(self):
+        """Test that the list is rebuilt on a timer, skipped while hidden."""
+        page = Mock()
+        tab_manager = self._tab_manager({"node_refresh_seconds": 10})
+        tab_manager.fullscreen = False
+        tab_manager.sidebar.hidden = True
+        announce_list = AnnounceList(page, tab_manager)
+        announce_list.refresh = Mock()
+        announce_list.start_refresh()
+        announce_list.start_refresh()
+        (call,) = page.run_task.call_args_list
+        ticks = []
+
+        async def sleep(seconds):
+            ticks.append(seconds)
+            if len(ticks) == 2:
+                tab_manager.sidebar.hidden = False
+            elif len(ticks) == 3:
+                tab_manager.settings = {"node_refresh_seconds": 0}
+                announce_list.start_refresh()
+
+        with patch("ren_browser.ui.announce_list.asyncio.sleep", new=sleep):
+            asyncio.run(call.args[0]())
+
+        assert ticks == [10, 10, 10]
+        announce_list.refresh.assert_called_once()
+        assert page.run_task.call_count == 1
+
+    def test_aspect_chips_filter_nodes(self):
+        """Test that page-serving nodes are shown by default and chips switch it."""
+        tab_manager = self._tab_manager()
+        announce_list = AnnounceList(Mock(), tab_manager)
+        now = time.time()
+        announce_list.update(
+            [
+                Announce("aaa", "Hilltop", now),
+                Announce("bbb", "Kai", now, aspect="lxmf.delivery"),
+            ],
+        )
+        assert [_card_name(c) for c in announce_list.list_view.controls] == [
+            "Hilltop",
+        ]
+
+        chips = announce_list.aspect_chips.controls
+        assert [chip.label.value for chip in chips] == [
+            "All",
+            "Pages",
+            "Messaging",
+            "Propagation",
+        ]
+        with patch("ren_browser.ui.announce_list.get_storage_manager"):
+            chips[0].on_select(None)
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["Hilltop", "Kai"]
+        assert tab_manager.settings["sidebar_aspect"] == ""
+        assert announce_list.aspect_chips.controls[0].selected
+        assert AnnounceList(Mock(), tab_manager).aspect is None
+
+    def test_hide_anonymous_nodes(self):
+        """Test that nameless nodes can be hidden and the choice is saved."""
+        tab_manager = self._tab_manager()
+        announce_list = AnnounceList(Mock(), tab_manager)
+        announce_list.update([self._announce("aaa", None), self._announce("bbb", "B")])
+
+        with patch("ren_browser.ui.announce_list.get_storage_manager") as get_storage:
+            announce_list.anonymous_button.on_click(None)
+
+        assert [_card_name(c) for c in announce_list.list_view.controls] == ["B"]
+        assert tab_manager.settings["hide_anonymous"] is True
+        get_storage.return_value.save_app_settings.assert_called_once()
+        assert AnnounceList(Mock(), tab_manager).hide_anonymous
+
+    def test_favorites_pinned_to_top(self):
+        """Test that favorites get their own section, even when not heard."""
+        tab_manager = self._tab_manager()
+        tab_manager.nodes.set_favorite("zzz", True, "Old Friend")
+        announce_list = AnnounceList(Mock(), tab_manager)
+        announce_list.update([self._announce("aaa", "A"), self._announce("bbb", "B")])
+
+        star = announce_list.list_view.controls[4].content.content.controls[4]
+        star.on_click(None)
+
+        controls = announce_list.list_view.controls
+        assert controls[0].content.value == "Favorites"
+        names = [_card_name(c) for c in controls[1:3]]
+        assert names == ["B", "Old Friend"]
+        assert controls[3].content.content.value == "All nodes"
+        assert _card_name(controls[4]) == "A"
+        assert tab_manager.nodes.is_favorite("bbb")
+
+    def test_freshness_levels(self):
+        """Test that announce age maps to fresh, aging and stale."""
+        now = 100_000.0
+        hour = 3600
+
+        assert freshness(Announce("a", "A", now - 60), now, 6 * hour) == "fresh"
+        assert freshness(Announce("a", "A", now - hour), now, 6 * hour) == "aging"
+        assert freshness(Announce("a", "A", now - 7 * hour), now, 6 * hour) == "stale"
+        assert freshness(Announce("a", "A", 0), now, 6 * hour) is None
+
+    def test_stale_nodes_move_to_collapsed_section(self):
+        """Test that quiet nodes go to a collapsed Stale section by default."""
+        announce_list = AnnounceList(Mock(), self._tab_manager())
+        old = time.time() - 7 * 3600
+
+        announce_list.update(
+            [self._announce("aaa", "A"), self._announce("bbb", "B", old)],
+        )
+
+        controls = announce_list.list_view.controls
+        assert _card_name(controls[0]) == "A"
+        assert controls[1].content.controls[1].value == "Stale (1)"
+        assert len(controls) == 2
+        card = controls[0].content
+        assert card.tooltip == "Announced recently"
+
+        controls[1].on_click(None)
+        assert _card_name(announce_list.list_view.controls[2]) == "B"
+
+    def test_stale_nodes_can_be_hidden(self):
+        """Test the stale threshold and hiding mode come from the settings."""
+        settings = {"stale_after_hours": 1, "stale_nodes": "hide"}
+        announce_list = AnnounceList(Mock(), self._tab_manager(settings))
+        quiet = time.time() - 2 * 3600
+
+        announce_list.update(
+            [self._announce("aaa", "A"), self._announce("bbb", "B", quiet)],
+        )
+
+        assert [_card_name(c) for c in announce_list.list_view.controls] == ["A"]
+
+    def test_add_node_by_hash(self):
+        """Test that a node added by hash is listed and a path is requested."""
+        page = Mock()
+        tab_manager = self._tab_manager()
+        announce_list = AnnounceList(page, tab_manager)
+        announce_list.update([self._announce("aaa", "A")])
+        node = "0123456789abcdef0123456789abcdef"
+
+        announce_list.open_add_node_dialog()
+        announce_list.node_hash_field.value = "abc"
+        announce_list.apply_add_node_dialog()
+        assert "32 hexadecimal" in announce_list.node_hash_field.error_text
+        announce_list.node_hash_field.value = node
+        announce_list.node_name_field.value = "Hilltop"
+        with patch("ren_browser.ui.announce_list.request_path") as request:
+            announce_list.apply_add_node_dialog()
+
+        request.assert_called_once_with(node)
+        page.close.assert_called_once_with(announce_list.add_node_dialog)
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["A", "Hilltop"]
+
+        with patch("ren_browser.ui.node_details.path_info", return_value=PathInfo()):
+            announce_list.details.show(announce_list.added_announces()[0])
+        assert announce_list.details.remove_button.visible
+        announce_list.details.remove()
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["A"]
+
+    def test_copy_node_hash(self):
+        """Test that the copy button puts the full hash on the clipboard."""
+        page = Mock()
+        page.overlay = []
+        announce_list = AnnounceList(page, self._tab_manager())
+        node = "0123456789abcdef0123456789abcdef"
+        announce_list.update([Announce(node, "Hilltop", time.time())])
+
+        copy = announce_list.list_view.controls[0].content.content.controls[3]
+        copy.on_click(None)
+
+        page.set_clipboard.assert_called_once_with(node)
+
+    def test_blocked_nodes_are_hidden(self):
+        """Test that blocking a node from its details removes it."""
+        page = Mock()
+        tab_manager = self._tab_manager()
+        announce_list = AnnounceList(page, tab_manager)
+        now = time.time()
+        announce_list.update([Announce("aaa", "A", now), Announce("bbb", "B", now)])
+
+        with patch("ren_browser.ui.node_details.path_info", return_value=PathInfo()):
+            announce_list.details.show(announce_list.announces[1])
+        announce_list.details.block()
+
+        assert tab_manager.nodes.blocked() == [("bbb", NodeInfo("B", blocked=True))]
+        assert [_card_name(c) for c in announce_list.list_view.controls] == ["A"]
+
+    def test_info_icon_opens_details(self):
+        """Test that the info icon shows the node's detail panel."""
+        page = Mock()
+        announce_list = AnnounceList(page, self._tab_manager())
+        ann = Announce("bbb", "B", time.time(), identity_hash="fff", announce_count=3)
+        announce_list.update([ann])
+
+        info = announce_list.list_view.controls[0].content.content.controls[2]
+        with patch("ren_browser.ui.node_details.path_info", return_value=PathInfo()):
+            info.on_click(None)
+
+        page.open.assert_called_once_with(announce_list.details.dialog)
+        values = [row.controls[1].value for row in announce_list.details.rows.controls]
+        assert values[:2] == ["bbb", "fff"]
+        assert "3" in values
+        assert values[-1].startswith("2 visits")
+
+    def test_alias_replaces_announced_name(self):
+        """Test that an alias set in the details is shown, sorted and searched."""
+        tab_manager = self._tab_manager({"sidebar_sort": "name"})
+        announce_list = AnnounceList(Mock(), tab_manager)
+        now = time.time()
+        ann = Announce("bbb", "NomadNet Node", now)
+        announce_list.update([Announce("aaa", "Mesa", now), ann])
+
+        with patch("ren_browser.ui.node_details.path_info", return_value=PathInfo()):
+            announce_list.details.show(ann)
+        announce_list.details.alias_field.value = " Bakery "
+        announce_list.details.close()
+
+        assert tab_manager.nodes.get("bbb").alias == "Bakery"
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["Bakery", "Mesa"]
+        announce_list.set_query("bake")
+        names = [_card_name(c) for c in announce_list.list_view.controls]
+        assert names == ["Bakery"]
+
+        tab_manager.manager.tabs = [{"url_field": Mock()}]
+        announce_list.open_node(ann)
+        tab_manager._add_tab_internal.assert_called_once()
+        assert tab_manager._add_tab_internal.call_args[0][0] == "Bakery"
+
+    def test_long_lists_are_built_in_batches(self):
+        """Test that only a batch of cards is built until more are needed."""
+        announce_list = AnnounceList(Mock(), self._tab_manager())
+        total = PAGE_SIZE * 2 + 5
+        announce_list.update(
+            [self._announce(f"{i:03d}", f"N{i}") for i in range(total)],
+        )
+
+        controls = announce_list.list_view.controls
+        assert len(controls) == PAGE_SIZE + 1
+        assert controls[-1] is announce_list.more_button
+        assert announce_list.more_button.text == f"Show {PAGE_SIZE} more"
+
+        announce_list.list_view.on_scroll(Mock(pixels=900, max_scroll_extent=1000))
+        assert len(announce_list.list_view.controls) == PAGE_SIZE * 2 + 1
+        assert announce_list.more_button.text == "Show 5 more"
+        announce_list.more_button.on_click(None)
+        controls = announce_list.list_view.controls
+        assert len(controls) == total
+        assert _card_name(controls[-1]) == f"N{total - 1}"
+
+        announce_list.set_query("N1")
+        assert announce_list.limit == PAGE_SIZE
+
+    def test_groups_collect_dropped_nodes(self):
+        """Test that nodes dropped on a group header are listed under it."""
+        tab_manager = self._tab_manager()
+        page = Mock()
+        announce_list = AnnounceList(page, tab_manager)
+        announce_list.update([self._announce("aaa", "A"), self._announce("bbb", "B")])
+        announce_list.group_field.value = "Friends"
+        announce_list.apply_group_dialog()
+
+        header = announce_list.list_view.controls[0]
+        page.get_control.return_value = announce_list.list_view.controls[3]
+        header.on_accept(Mock(page=page, src_id="card"))
+
+        controls = announce_list.list_view.controls
+        assert controls[0].content.content.controls[1].value == "Friends (1)"
+        assert _card_name(controls[1]) == "B"
+        assert controls[2].content.content.value == "All nodes"
+        assert [_card_name(c) for c in controls[3:]] == ["A"]
+        assert tab_manager.nodes.get("bbb").group == "Friends"
+
+    def test_collapsed_group_hides_nodes(self):
+        """Test that clicking a group header collapses and expands it."""
+        tab_manager = self._tab_manager()
+        tab_manager.nodes.add_group("Friends")
+        tab_manager.nodes.set_group("aaa", "Friends", "A")
+        announce_list = AnnounceList(Mock(), tab_manager)
+        announce_list.update([self._announce("aaa", "A")])
+
+        announce_list.list_view.controls[0].content.on_click(None)
+
+        assert len(announce_list.list_view.controls) == 2
+        assert tab_manager.nodes.group("Friends").collapsed
+        announce_list.list_view.controls[0].content.on_click(None)
+        assert len(announce_list.list_view.controls) == 3
+
+    def test_duplicate_group_name_is_rejected(self):
+        """Test that the group dialog shows an error for a name in use."""
+        tab_manager = self._tab_manager()
+        tab_manager.nodes.add_group("Friends")
+        page = Mock()
+        announce_list = AnnounceList(page, tab_manager)
+
+        announce_list.open_group_dialog()
+        announce_list.group_field.value = "Friends"
+        announce_list.apply_group_dialog()
+
+        assert announce_list.group_field.error_text
+        page.close.assert_not_called()
+        assert len(tab_manager.nodes.groups()) == 1
+
+
+class TestNodeDetails:
+    """Test cases for the node detail panel."""
+
+    def test_rows_for_node_not_heard(self):
+        """Test the details of a favorite that has not announced yet."""
+        rows = dict(detail_rows(Announce("abc", "Hilltop", 0), None, PathInfo()))
+
+        assert rows["Identity"] == "Unknown"
+        assert rows["Last announce"] == "Not heard this session"
+        assert rows["Path"] == "No known path"
+        assert rows["Visits"] == "Never visited"
+
+    def test_path_row(self):
+        """Test that the hop count and interface are described."""
+        ann = Announce("abc", "Hilltop", 1000, first_seen=900)
+
+        one = dict(detail_rows(ann, None, PathInfo(1, "RNodeInterface")))
+        many = dict(detail_rows(ann, None, PathInfo(4)))
+
+        assert one["Path"] == "1 hop via RNodeInterface"
+        assert many["Path"] == "4 hops"
+
+    def test_page_overrides(self, mock_page):
+        """Test that the page settings of a node are saved as overrides."""
+        tab_manager = Mock()
+        details = NodeDetails(mock_page, tab_manager, on_open=Mock())
+        details.announce = Announce("abc", "Hilltop", 1000)
+
+        details.plain_text_switch.value = True
+        details.zoom_dropdown.value = "1.25"
+        details.zoom_dropdown.on_change(None)
+
+        tab_manager.set_node_overrides.assert_called_once_with(
+            "abc",
+            NodeOverrides(plain_text=True, zoom=1.25),
+            "Hilltop",
+        )
+
+
+class TestFileArea:
+    """Test cases for the node file area dialog."""
+
+    NODE = "ab" * 16
+
+    def tab_manager(self, content=None):
+        tab_manager = Mock()
+        tab_manager.manager.tabs = []
+        tab_manager.downloads.downloads.return_value = []
+        entries = []
+        if content is not None:
+            entries.append((f"{self.NODE}:/page/index.mu", Mock(content=content)))
+        tab_manager.page_cache.entries.return_value = entries
+        tab_manager.page_cache.get.return_value = entries[0][1] if entries else None
+        return tab_manager
+
+    def test_lists_cached_files(self, mock_page):
+        """Test that files linked from cached pages are listed without fetching."""
+        tab_manager = self.tab_manager("`[Map`:/file/map.png]")
+        area = FileArea(mock_page, tab_manager)
+
+        area.show(self.NODE, "Hilltop")
+
+        assert len(area.files.controls) == 1
+        assert area.files.controls[0].subtitle.value == "Map · Size unknown"
+        tab_manager.fetch_source.assert_not_called()
+
+    def test_fetches_index_when_nothing_is_known(self, mock_page):
+        """Test that the index page is fetched for a node never visited."""
+        mock_page.run_thread = lambda action: action()
+        tab_manager = self.tab_manager()
+        tab_manager.fetch_source.return_value = "`[`:/file/notes.txt]"
+        area = FileArea(mock_page, tab_manager)
+
+        area.show(self.NODE, "Hilltop")
+
+        tab_manager.fetch_source.assert_called_once_with(f"{self.NODE}:/page/index.mu")
+        assert area.files.controls[0].title.value == "notes.txt"
+
+    def test_download_uses_download_manager(self, mock_page):
+        """Test that choosing a file downloads it through the tab manager."""
+        tab_manager = self.tab_manager("`[`:/file/map.png]")
+        area = FileArea(mock_page, tab_manager)
+        area.show(self.NODE, "Hilltop")
+
+        area.files.controls[0].trailing.on_click(None)
+
+        tab_manager.download_file.assert_called_once_with(f"{self.NODE}:/file/map.png")
+
+    def test_opened_from_node_details(self, mock_page):
+        """Test that the node panel opens the file area of its node."""
+        details = NodeDetails(mock_page, Mock(), on_open=Mock())
+        details.announce = Announce(self.NODE, "Hilltop", 1000)
+        details.tab_manager.nodes.get.return_value.alias = None
+        details.file_area = Mock()
+
+        details.show_files()
+
+        details.file_area.show.assert_called_once_with(self.NODE, "Hilltop")
+
+
 class TestOpenSettingsTab:
     """Test cases for the open_settings_tab function."""
 
@@ -97,6 +1038,7 @@ class TestOpenSettingsTab:
         """Test opening settings tab with basic functionality."""
         mock_tab_manager = Mock()
         mock_tab_manager.manager.tabs = []
+        mock_tab_manager.spelling.dictionaries.return_value = {}
         mock_tab_manager._add_tab_internal = Mock()
         mock_tab_manager.select_tab = Mock()
 
@@ -122,6 +1064,7 @@ class TestOpenSettingsTab:
         """Test opening settings tab when config file cannot be read."""
         mock_tab_manager = Mock()
         mock_tab_manager.manager.tabs = []
+        mock_tab_manager.spelling.dictionaries.return_value = {}
         mock_tab_manager._add_tab_internal = Mock()
         mock_tab_manager.select_tab = Mock()
 
@@ -149,6 +1092,7 @@ class TestOpenSettingsTab:
         """Test saving config successfully in settings."""
         mock_tab_manager = Mock()
         mock_tab_manager.manager.tabs = []
+        mock_tab_manager.spelling.dictionaries.return_value = {}
         mock_tab_manager._add_tab_internal = Mock()
         mock_tab_manager.select_tab = Mock()
 
@@ -190,6 +1134,7 @@ class TestOpenSettingsTab:
         """Test saving config error path does not crash."""
         mock_tab_manager = Mock()
         mock_tab_manager.manager.tabs = []
+        mock_tab_manager.spelling.dictionaries.return_value = {}
         mock_tab_manager._add_tab_internal = Mock()
         mock_tab_manager.select_tab = Mock()
 
@@ -227,6 +1172,7 @@ class TestOpenSettingsTab:
         """Ensure the status navigation button is present."""
         mock_tab_manager = Mock()
         mock_tab_manager.manager.tabs = []
+        mock_tab_manager.spelling.dictionaries.return_value = {}
         mock_tab_manager._add_tab_internal = Mock()
         mock_tab_manager.select_tab = Mock()
 
@@ -252,3 +1198,3006 @@ class TestOpenSettingsTab:
                 if hasattr(ctrl, "text")
             ]
             assert "Status" in button_labels
+
+    def test_unblock_from_settings(self, mock_page, mock_storage_manager):
+        """Test that blocked nodes are listed and can be unblocked."""
+        mock_tab_manager = Mock()
+        mock_tab_manager.manager.tabs = []
+        mock_tab_manager.spelling.dictionaries.return_value = {}
+        mock_tab_manager.nodes = NodeMetadata(
+            Mock(load_node_metadata=Mock(return_value={})),
+        )
+        mock_tab_manager.nodes.set_blocked("abc", True, "Spammer")
+        mock_tab_manager.set_node_blocked.side_effect = (
+            mock_tab_manager.nodes.set_blocked
+        )
+        mock_page.overlay = []
+
+        with (
+            patch(
+                "ren_browser.ui.settings.get_storage_manager",
+                return_value=mock_storage_manager,
+            ),
+            patch(
+                "ren_browser.ui.settings.rns.get_config_path", return_value="/tmp/rns",
+            ),
+            patch("pathlib.Path.read_text", return_value="config"),
+        ):
+            open_settings_tab(mock_page, mock_tab_manager)
+
+        settings_content = mock_tab_manager._add_tab_internal.call_args[0][1]
+        nav_buttons = settings_content.controls[1].content.controls
+        next(b for b in nav_buttons if b.text == "Blocked Nodes").on_click(None)
+        section = settings_content.controls[2].content.content
+        row = section.controls[1]
+        assert row.controls[0].controls[0].value == "Spammer"
+
+        row.controls[1].on_click(None)
+
+        mock_tab_manager.set_node_blocked.assert_called_once_with("abc", False)
+        assert not mock_tab_manager.nodes.blocked()
+        assert len(section.controls) == 2
+
+
+class TestAliasSettings:
+    """Test cases for editing address aliases in the settings."""
+
+    def _section(self, mock_page, settings=None):
+        tab_manager = Mock(resolve_node_name=None)
+        tab_manager.settings = settings or {}
+        storage = Mock()
+        section, refresh = _build_aliases_section(mock_page, tab_manager, storage)
+        refresh()
+        keyword_field, address_field, add_button = section.controls[-1].controls
+        return tab_manager, section, keyword_field, address_field, add_button
+
+    def test_add_and_remove_alias(self, mock_page):
+        """Test that an added alias is saved, listed and can be removed."""
+        tab_manager, section, keyword, address, add = self._section(mock_page)
+        keyword.value = "Weather"
+        address.value = "0123456789abcdef0123456789abcdef:/page/wx/index.mu"
+
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            add.on_click(None)
+
+        assert tab_manager.settings["address_aliases"] == {
+            "weather": "0123456789abcdef0123456789abcdef:/page/wx/index.mu",
+        }
+        row = section.controls[2].controls[0]
+        assert row.controls[0].value == "weather"
+
+        row.controls[2].on_click(None)
+
+        assert tab_manager.settings["address_aliases"] == {}
+
+    def test_invalid_alias_is_not_saved(self, mock_page):
+        """Test that a bad keyword or address shows an error instead."""
+        tab_manager, _, keyword, address, add = self._section(mock_page)
+        keyword.value = "two words"
+        address.value = ""
+
+        add.on_click(None)
+
+        assert keyword.error_text
+        assert address.error_text
+        assert "address_aliases" not in tab_manager.settings
+
+
+class TestShortcutSettings:
+    """Test cases for editing keyboard shortcuts in the settings."""
+
+    def _section(self, mock_page, settings=None):
+        tab_manager = Mock()
+        tab_manager.settings = settings or {}
+        storage = Mock()
+        section, refresh = _build_shortcuts_section(mock_page, tab_manager, storage)
+        refresh()
+        fields = {
+            field.label: field
+            for field in section.controls
+            if isinstance(field, ft.TextField)
+        }
+        buttons = section.controls[-1].controls
+        return tab_manager, storage, fields, buttons
+
+    def test_parse_chord_list(self):
+        """Test that commas separate chords unless they are the key."""
+        assert parse_chord_list("ctrl+t, Ctrl+N") == ["Ctrl+T", "Ctrl+N"]
+        assert parse_chord_list("Ctrl+,, Ctrl++") == ["Ctrl+,", "Ctrl++"]
+        assert parse_chord_list("") == []
+
+    def test_saves_only_changed_shortcuts(self, mock_page):
+        """Test that saving stores the actions that differ from the defaults."""
+        tab_manager, storage, fields, buttons = self._section(
+            mock_page,
+            {"keybindings": {"history": ["Ctrl+Y"]}},
+        )
+        assert fields["Open history"].value == "Ctrl+Y"
+
+        fields["New tab"].value = "Ctrl+T, Ctrl+N"
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            buttons[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved["keybindings"] == {
+            "history": ["Ctrl+Y"],
+            "new_tab": ["Ctrl+T", "Ctrl+N"],
+        }
+        assert tab_manager.settings is saved
+
+    def test_conflicts_block_saving(self, mock_page):
+        """Test that a chord used twice is flagged and nothing is saved."""
+        _, storage, fields, buttons = self._section(mock_page)
+
+        fields["Open history"].value = "Ctrl+T"
+        fields["Open history"].on_change(None)
+
+        assert "New tab" in fields["Open history"].error_text
+        assert "Open history" in fields["New tab"].error_text
+        with patch("ren_browser.ui.settings.notify.show_snack") as snack:
+            buttons[0].on_click(None)
+        storage.save_app_settings.assert_not_called()
+        assert snack.call_args.kwargs["success"] is False
+
+        buttons[1].on_click(None)
+        assert fields["Open history"].value == "Ctrl+H"
+        assert fields["New tab"].error_text is None
+
+
+class TestSettingsSearch:
+    """Test cases for searching the settings."""
+
+    def _section(self):
+        switch = ft.Switch(label="Show status bar")
+        dropdown = ft.Dropdown(
+            label="Theme",
+            options=[ft.dropdown.Option("light", "Light")],
+        )
+        cache = ft.Column(
+            controls=[ft.Text("Page Cache"), ft.TextField(label="Most pages kept")],
+        )
+        save = ft.ElevatedButton("Save Appearance")
+        section = ft.Column(controls=[switch, dropdown, cache, save])
+        return section, switch, dropdown, cache, save
+
+    def test_setting_labels(self):
+        """Test that labels, captions and option names are searched."""
+        _, _, dropdown, cache, _ = self._section()
+
+        assert setting_labels(dropdown) == ["Theme", "Light"]
+        assert setting_labels(cache) == ["Page Cache", "Most pages kept"]
+
+    def test_filter_rows(self):
+        """Test that only matching rows stay, with the section's buttons."""
+        section, switch, dropdown, cache, save = self._section()
+
+        assert filter_settings(section, "LIGHT")
+        assert dropdown.visible is True
+        assert switch.visible is False
+        assert cache.visible is False
+        assert save.visible is True
+
+        assert not filter_settings(section, "nothing like this")
+        assert save.visible is False
+
+    def test_heading_shows_its_rows(self):
+        """Test that a matching heading shows everything under it."""
+        section, switch, _, cache, _ = self._section()
+
+        filter_settings(section, "cache")
+        assert cache.visible is True
+        assert cache.controls[1].visible is True
+        assert switch.visible is False
+
+        assert filter_settings(section, "")
+        assert switch.visible is True
+
+    def test_search_in_settings_tab(self, mock_page, mock_storage_manager, tmp_path):
+        """Test that searching lists matching sections until cleared."""
+        tab_manager = Mock()
+        tab_manager.manager.tabs = []
+        tab_manager.settings = {}
+        tab_manager.fonts = []
+        tab_manager.themes = ThemeLibrary(tmp_path)
+        tab_manager.spelling = SpellCheck(tmp_path / "dictionaries")
+        tab_manager.nodes.blocked.return_value = []
+        mock_page.overlay = []
+
+        with (
+            patch(
+                "ren_browser.ui.settings.get_storage_manager",
+                return_value=mock_storage_manager,
+            ),
+            patch("pathlib.Path.read_text", return_value="config"),
+        ):
+            open_settings_tab(mock_page, tab_manager)
+
+        settings_content = tab_manager._add_tab_internal.call_args[0][1]
+        search_field = settings_content.controls[0].content.controls[1]
+        placeholder = settings_content.controls[2].content
+        config_field = placeholder.content
+
+        search_field.value = "status bar"
+        search_field.on_change(None)
+
+        headings = [result.controls[0].value for result in placeholder.content.controls]
+        assert headings == ["Appearance"]
+
+        search_field.value = ""
+        search_field.on_change(None)
+        assert placeholder.content is config_field
+        assert config_field.expand is True
+
+
+class TestCacheSettings:
+    """Test cases for the page cache settings."""
+
+    def _section(self, mock_page, settings):
+        tab_manager = Mock()
+        tab_manager.settings = settings
+        tab_manager.page_cache = PageCache()
+        tab_manager.page_cache.put("a:/page/index.mu", "Hello")
+        storage = Mock()
+        section, refresh = _build_cache_section(mock_page, tab_manager, storage)
+        refresh()
+        dropdown = section.controls[1]
+        pages_field, mb_field, _ = section.controls[2].controls
+        usage, button = section.controls[5], section.controls[6].controls[0]
+        return tab_manager, storage, dropdown, pages_field, mb_field, usage, button
+
+    def test_shows_limits_and_usage(self, mock_page):
+        """Test that the section shows the stored limits and cache usage."""
+        _, _, dropdown, pages_field, mb_field, usage, _ = self._section(
+            mock_page,
+            {"cache_max_age": 3600, "cache_max_pages": 50, "cache_max_mb": 5},
+        )
+
+        assert dropdown.value == "3600"
+        assert pages_field.value == "50"
+        assert mb_field.value == "5"
+        assert usage.value == "1 page cached, 5 B"
+
+    def test_save_applies_limits(self, mock_page):
+        """Test that saving stores the limits and reconfigures the cache."""
+        tab_manager, storage, dropdown, pages_field, mb_field, _, button = (
+            self._section(mock_page, {})
+        )
+        dropdown.value = "0"
+        pages_field.value = "0"
+        mb_field.value = "1"
+
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            button.on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved["cache_max_age"] == 0
+        assert saved["cache_max_pages"] == 0
+        assert tab_manager.page_cache.max_age == 0
+        assert tab_manager.page_cache.max_bytes == 1_000_000
+
+    def test_save_applies_prefetch(self, mock_page):
+        """Test that saving turns on prefetching with its budget."""
+        tab_manager = Mock(settings={}, page_cache=PageCache())
+        storage = Mock()
+        section, refresh = _build_cache_section(mock_page, tab_manager, storage)
+        refresh()
+        switch, kb_field = section.controls[3], section.controls[4]
+        assert switch.value is False
+        assert kb_field.value == "500"
+        switch.value = True
+        kb_field.value = "50"
+
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[6].controls[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved["prefetch_links"] is True
+        assert saved["prefetch_kb"] == 50
+        tab_manager.prefetcher.configure.assert_called_once_with(50_000)
+
+    def test_invalid_limit_is_not_saved(self, mock_page):
+        """Test that a limit that is not a whole number is flagged."""
+        _, storage, _, pages_field, _, _, button = self._section(mock_page, {})
+        pages_field.value = "lots"
+
+        button.on_click(None)
+
+        assert pages_field.error_text
+        storage.save_app_settings.assert_not_called()
+
+    def test_restore_defaults(self, mock_page):
+        """Test that restoring the defaults fills in the default limits."""
+        storage = Mock()
+        section, _ = _build_cache_section(mock_page, Mock(), storage)
+        dropdown = section.controls[1]
+        pages_field, mb_field, _ = section.controls[2].controls
+        pages_field.value = "nonsense"
+
+        section.controls[6].controls[1].on_click(None)
+
+        assert dropdown.value == "300"
+        assert pages_field.value == "500"
+        assert pages_field.error_text is None
+        assert mb_field.value == "20"
+        storage.save_app_settings.assert_not_called()
+
+
+class TestPrivacySettings:
+    """Test cases for the privacy settings."""
+
+    def test_save_applies_switches(self, mock_page):
+        """Test that the switches show the settings and save changes."""
+        tab_manager = Mock(settings={"record_history": False})
+        storage = Mock()
+        section, refresh = _build_privacy_section(mock_page, tab_manager, storage)
+        refresh()
+        history, cache, private, crash_tabs, updates, clipboard = section.controls[1:7]
+        assert (history.value, cache.value, private.value) == (False, True, False)
+        assert crash_tabs.value is False
+        assert updates.value is False
+        assert clipboard.value is False
+
+        private.value = True
+        crash_tabs.value = True
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[8].controls[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved == {
+            "record_history": False,
+            "cache_pages": True,
+            "private_tabs": True,
+            "crash_report_tabs": True,
+            "check_updates": False,
+            "clipboard_hints": False,
+        }
+        tab_manager.apply_settings.assert_called_once_with(saved)
+
+        section.controls[8].controls[1].on_click(None)
+        assert history.value is True
+        assert private.value is False
+        assert crash_tabs.value is False
+
+
+class TestAccessibilitySettings:
+    """Test cases for the accessibility settings."""
+
+    def test_section_saves_settings(self, mock_page):
+        """Test that the minimum text size and readable font are saved."""
+        tab_manager = Mock(settings={"min_font_size": 15})
+        storage = Mock()
+        section, refresh = _build_accessibility_section(mock_page, tab_manager, storage)
+        refresh()
+        size, readable = section.controls[1], section.controls[3]
+        assert (size.value, readable.value) == ("0", False)
+
+        size.value = "18"
+        readable.value = True
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[5].controls[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved == {"min_font_size": 18, "readable_font": True}
+        tab_manager.apply_settings.assert_called_once_with(saved)
+
+        section.controls[5].controls[1].on_click(None)
+        assert (size.value, readable.value) == ("0", False)
+
+
+class TestLoggingSettings:
+    """Test cases for the logging settings."""
+
+    def test_section_saves_settings(self, mock_page):
+        """Test that the log file level, size and count are saved."""
+        tab_manager = Mock(settings={"log_file_level": "loud", "log_file_kb": 1})
+        storage = Mock()
+        section, refresh = _build_logging_section(mock_page, tab_manager, storage)
+        refresh()
+        level, _, size, count = section.controls[1:5]
+        assert (level.value, size.value, count.value) == ("warning", "1024", "3")
+
+        level.value = "debug"
+        size.value = "256"
+        count.value = "10"
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[6].controls[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved == {
+            "log_file_level": "debug",
+            "log_file_kb": 256,
+            "log_file_count": 10,
+        }
+        tab_manager.apply_settings.assert_called_once_with(saved)
+
+        section.controls[6].controls[1].on_click(None)
+        assert (level.value, size.value, count.value) == ("warning", "1024", "3")
+
+
+class TestCrashPrompt:
+    """Test cases for the prompt shown after a crash."""
+
+    def _tab_manager(self, settings=None, session=None):
+        tab_manager = Mock(settings=settings or {})
+        tab_manager.session.tabs = session or []
+        return tab_manager
+
+    def test_offers_to_restore_session(self, mock_page):
+        """Test that the prompt restores the previous tabs when asked."""
+        tab_manager = self._tab_manager(session=["abc:/page/index.mu"])
+        prompt = CrashPrompt(mock_page, tab_manager, Path("/data/crash.txt"))
+
+        with patch("ren_browser.ui.crash_prompt.open_dialog") as mock_open:
+            prompt.show()
+        mock_open.assert_called_once_with(mock_page, prompt.dialog)
+        assert "/data/crash.txt" in prompt.dialog.content.controls[0].value
+
+        prompt.dialog.actions[1].on_click(None)
+        tab_manager.restore_session.assert_called_once()
+        mock_page.close.assert_called_once_with(prompt.dialog)
+
+    def test_not_now_keeps_tabs(self, mock_page):
+        """Test that dismissing the prompt restores nothing."""
+        tab_manager = self._tab_manager(session=["abc:/page/index.mu"])
+        prompt = CrashPrompt(mock_page, tab_manager, Path("/data/crash.txt"))
+
+        prompt.dialog.actions[0].on_click(None)
+
+        tab_manager.restore_session.assert_not_called()
+        mock_page.close.assert_called_once_with(prompt.dialog)
+
+    def test_only_reports_when_nothing_to_restore(self, mock_page):
+        """Test that a restored or empty session only says where the report is."""
+        for tab_manager in (
+            self._tab_manager({"startup": "restore"}, ["abc:/page/index.mu"]),
+            self._tab_manager(session=[None]),
+        ):
+            prompt = CrashPrompt(mock_page, tab_manager, Path("/data/crash.txt"))
+            with (
+                patch("ren_browser.ui.crash_prompt.open_dialog") as mock_open,
+                patch("ren_browser.ui.crash_prompt.show_snack") as mock_snack,
+            ):
+                prompt.show()
+
+            mock_open.assert_not_called()
+            assert "/data/crash.txt" in mock_snack.call_args[0][1]
+
+
+class TestPluginSettings:
+    """Test cases for the plugin settings."""
+
+    def test_section_lists_plugins_and_saves(self, mock_page):
+        """Test that loaded plugins are listed and loading them can be turned on."""
+        tab_manager = Mock(settings={})
+        storage = Mock()
+        plugins = [
+            PluginInfo("gemini", "/plugins/gemini.py", hooks=["renderer for .gmi"]),
+            PluginInfo("broken", "/plugins/broken.py", error="ImportError: nope"),
+        ]
+        with patch("ren_browser.ui.settings.loaded_plugins", return_value=plugins):
+            section, refresh = _build_plugins_section(mock_page, tab_manager, storage)
+            refresh()
+        switch = section.controls[1]
+        listed = section.controls[4].controls
+        assert switch.value is False
+        assert [entry.controls[0].value for entry in listed] == ["gemini", "broken"]
+        assert listed[0].controls[2].value == "renderer for .gmi"
+        assert "ImportError: nope" in listed[1].controls[2].value
+
+        switch.value = True
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[5].controls[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved == {"plugins_enabled": True}
+        tab_manager.apply_settings.assert_called_once_with(saved)
+
+    def test_section_without_plugins(self, mock_page):
+        """Test that the section says when no plugins are loaded."""
+        with patch("ren_browser.ui.settings.loaded_plugins", return_value=[]):
+            section, refresh = _build_plugins_section(
+                mock_page,
+                Mock(settings={}),
+                Mock(),
+            )
+            refresh()
+
+        assert section.controls[4].controls[0].value == "No plugins are loaded."
+
+
+class TestProfileSettings:
+    """Test cases for the profile settings."""
+
+    def test_create_and_choose_startup_profile(self, mock_page, tmp_path):
+        """Test that a created profile can be picked for the next launch."""
+        with patch.object(
+            StorageManager,
+            "_get_storage_directory",
+            return_value=tmp_path,
+        ):
+            storage = StorageManager()
+        section, refresh = _build_profiles_section(mock_page, storage)
+        refresh()
+        dropdown = section.controls[2]
+        name_field, create_button = section.controls[3].controls
+        assert [option.key for option in dropdown.options] == ["default"]
+
+        name_field.value = "no spaces"
+        create_button.on_click(None)
+        assert name_field.error_text
+
+        name_field.value = "testing"
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            create_button.on_click(None)
+            assert dropdown.value == "testing"
+            section.controls[5].controls[0].on_click(None)
+
+        assert storage.startup_profile() == "testing"
+        assert [option.key for option in dropdown.options] == ["default", "testing"]
+
+
+class TestDownloadSettings:
+    """Test cases for the download settings."""
+
+    def test_save_and_choose_folder(self, mock_page, tmp_path):
+        """Test that a picked folder and the ask switch are saved."""
+        mock_page.overlay = []
+        tab_manager = Mock(settings={})
+        storage = Mock()
+        section, refresh = _build_downloads_section(mock_page, tab_manager, storage)
+        refresh()
+        directory, picker_button = section.controls[1].controls
+        ask = section.controls[2]
+        assert (directory.value, ask.value) == ("", False)
+
+        picker = mock_page.overlay[-1]
+        picker.get_directory_path = Mock()
+        picker_button.on_click(None)
+        picker.get_directory_path.assert_called_once()
+        picker.on_result(Mock(path=str(tmp_path)))
+        assert directory.value == str(tmp_path)
+        ask.value = True
+
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[4].controls[0].on_click(None)
+
+        saved = storage.save_app_settings.call_args[0][0]
+        assert saved == {"download_dir": str(tmp_path), "ask_download_location": True}
+        tab_manager.apply_settings.assert_called_once_with(saved)
+
+        section.controls[4].controls[1].on_click(None)
+        assert (directory.value, ask.value) == ("", False)
+
+    def test_invalid_folder_is_not_saved(self, mock_page, tmp_path):
+        """Test that a relative path or a file is refused."""
+        mock_page.overlay = []
+        storage = Mock()
+        section, _ = _build_downloads_section(mock_page, Mock(settings={}), storage)
+        directory = section.controls[1].controls[0]
+
+        directory.value = "downloads"
+        with patch("ren_browser.ui.settings.notify.show_snack"):
+            section.controls[4].controls[0].on_click(None)
+
+        storage.save_app_settings.assert_not_called()
+        assert directory.error_text
+
+        (tmp_path / "file").write_text("")
+        directory.value = str(tmp_path / "file")
+        assert check_directory(directory) is None
+        directory.value = str(tmp_path / "new")
+        assert check_directory(directory) == str(tmp_path / "new")
+
+
+class TestSettingsValidation:
+    """Test cases for checking typed settings."""
+
+    def test_check_number(self):
+        """Test that numbers outside the range are flagged."""
+        field = ft.TextField(value=" 250 ")
+        assert check_number(field, 200, 600) == 250
+        assert field.error_text is None
+
+        field.value = "1"
+        assert check_number(field, 200, 600) is None
+        assert field.error_text == "Enter a number from 200 to 600"
+
+        field.value = "wide"
+        assert check_number(field, 200, 600) is None
+        assert field.error_text == "Enter a whole number"
+
+    def test_check_color(self):
+        """Test that only hex colours are accepted."""
+        field = ft.TextField(value="#1A2b3c")
+        assert check_color(field) == "#1A2b3c"
+
+        field.value = "blue"
+        assert check_color(field) is None
+        assert field.error_text
+
+    def test_check_home_page(self):
+        """Test that the home page must be empty, internal or an address."""
+        field = ft.TextField(value="")
+        assert check_home_page(field) == ""
+        field.value = "about:bookmarks"
+        assert check_home_page(field) == "about:bookmarks"
+        field.value = " 0123456789abcdef0123456789abcdef:/page/index.mu "
+        assert check_home_page(field) == (
+            "0123456789abcdef0123456789abcdef:/page/index.mu"
+        )
+
+        field.value = "not a node"
+        assert check_home_page(field) is None
+        assert field.error_text
+
+    def _open(self, mock_page, mock_storage_manager, tmp_path):
+        tab_manager = Mock()
+        tab_manager.manager.tabs = []
+        tab_manager.settings = {**DEFAULT_APP_SETTINGS}
+        tab_manager.fonts = []
+        tab_manager.themes = ThemeLibrary(tmp_path)
+        tab_manager.spelling = SpellCheck(tmp_path / "dictionaries")
+        tab_manager.resolve_node_name = None
+        tab_manager.page_cache = PageCache()
+        mock_page.overlay = []
+        mock_storage_manager.load_app_settings.return_value = {**DEFAULT_APP_SETTINGS}
+        with (
+            patch(
+                "ren_browser.ui.settings.get_storage_manager",
+                return_value=mock_storage_manager,
+            ),
+            patch("pathlib.Path.read_text", return_value="config"),
+        ):
+            open_settings_tab(mock_page, tab_manager)
+        settings_content = tab_manager._add_tab_internal.call_args[0][1]
+        settings_content.controls[1].content.controls[1].on_click(None)
+        return tab_manager, settings_content.controls[2].content.content
+
+    def _find(self, control, label):
+        if getattr(control, "label", None) == label:
+            return control
+        if getattr(control, "text", None) == label:
+            return control
+        for child in [
+            *(getattr(control, "controls", None) or []),
+            getattr(control, "content", None),
+        ]:
+            found = child is not None and self._find(child, label)
+            if found:
+                return found
+        return None
+
+    def test_invalid_appearance_is_not_saved(
+        self,
+        mock_page,
+        mock_storage_manager,
+        tmp_path,
+    ):
+        """Test that an out of range sidebar width is flagged, not saved."""
+        _, appearance = self._open(mock_page, mock_storage_manager, tmp_path)
+        width = self._find(appearance, "Sidebar width")
+        width.value = "1"
+        width.on_change(None)
+        assert width.error_text
+
+        self._find(appearance, "Save Appearance").on_click(None)
+        mock_storage_manager.save_app_settings.assert_not_called()
+
+        width.value = "420"
+        self._find(appearance, "Save Appearance").on_click(None)
+        saved = mock_storage_manager.save_app_settings.call_args[0][0]
+        assert saved["sidebar_width"] == 420
+
+    def test_restore_appearance_defaults(
+        self,
+        mock_page,
+        mock_storage_manager,
+        tmp_path,
+    ):
+        """Test that restoring the defaults fills in the default appearance."""
+        _, appearance = self._open(mock_page, mock_storage_manager, tmp_path)
+        width = self._find(appearance, "Sidebar width")
+        width.value = "1"
+        self._find(appearance, "Theme").value = "light"
+
+        self._find(appearance, "Restore Defaults").on_click(None)
+
+        assert width.value == "300"
+        assert width.error_text is None
+        assert self._find(appearance, "Theme").value == "dark"
+        mock_storage_manager.save_app_settings.assert_not_called()
+
+    def test_reset_all_settings(self, mock_page, mock_storage_manager, tmp_path):
+        """Test that the global reset saves and applies the defaults."""
+        tab_manager, _ = self._open(mock_page, mock_storage_manager, tmp_path)
+        tab_manager.settings = {
+            **DEFAULT_APP_SETTINGS,
+            "theme": "light",
+            "keybindings": {"history": ["Ctrl+Y"]},
+        }
+        settings_content = tab_manager._add_tab_internal.call_args[0][1]
+        settings_content.controls[1].content.controls[3].on_click(None)
+        storage_view = settings_content.controls[2].content.content
+        self._find(storage_view, "Reset All Settings…").on_click(None)
+        dialog = mock_page.open.call_args[0][0]
+
+        dialog.actions[1].on_click(None)
+
+        saved = mock_storage_manager.save_app_settings.call_args[0][0]
+        assert saved == DEFAULT_APP_SETTINGS
+        tab_manager.apply_settings.assert_called_once_with(saved)
+
+
+class TestSettingsWatcher:
+    """Test cases for reloading the settings file while running."""
+
+    def _watcher(self, mock_page, tmp_path):
+        path = tmp_path / "settings.json"
+        path.write_text(json.dumps({"theme": "dark"}), encoding="utf-8")
+        storage = Mock(last_saved_settings=None)
+        storage.get_app_settings_path.return_value = path
+        tab_manager = Mock(settings={**DEFAULT_APP_SETTINGS})
+        mock_page.overlay = []
+        return SettingsWatcher(mock_page, tab_manager, storage), path
+
+    def _edit(self, path, settings):
+        path.write_text(json.dumps(settings), encoding="utf-8")
+        stat = path.stat()
+        os.utime(path, (stat.st_atime, stat.st_mtime + 10))
+
+    def test_edit_is_applied(self, mock_page, tmp_path):
+        """Test that a hand edit is applied with a notification."""
+        watcher, path = self._watcher(mock_page, tmp_path)
+        assert not watcher.check()
+
+        self._edit(path, {"theme": "light", "sidebar_width": 400})
+
+        assert watcher.check()
+        watcher.tab_manager.apply_settings.assert_called_once_with(
+            {**DEFAULT_APP_SETTINGS, "theme": "light", "sidebar_width": 400},
+        )
+        assert "reloaded" in mock_page.overlay[-1].content.value
+        assert not watcher.check()
+
+    def test_invalid_edit_keeps_settings(self, mock_page, tmp_path):
+        """Test that a broken or mistyped file is reported, not applied."""
+        watcher, path = self._watcher(mock_page, tmp_path)
+
+        path.write_text('{"theme": ', encoding="utf-8")
+        os.utime(path, (0, path.stat().st_mtime + 10))
+        assert not watcher.check()
+        assert "Could not reload" in mock_page.overlay[-1].content.value
+
+        self._edit(path, {"sidebar_width": "wide"})
+        assert not watcher.check()
+        watcher.tab_manager.apply_settings.assert_not_called()
+
+    def test_own_saves_are_ignored(self, mock_page, tmp_path):
+        """Test that settings the browser saved itself are not reapplied."""
+        watcher, path = self._watcher(mock_page, tmp_path)
+        saved = {**DEFAULT_APP_SETTINGS, "theme": "light"}
+        watcher.storage.last_saved_settings = saved
+
+        self._edit(path, saved)
+
+        assert not watcher.check()
+        assert mock_page.overlay == []
+
+
+class TestNodeAvatar:
+    """Test cases for node avatars."""
+
+    def test_node_color_is_deterministic(self):
+        """Test that the same hash always maps to the same color."""
+        dest = "0123456789abcdef0123456789abcdef"
+        assert node_color(dest) == node_color(dest)
+        assert node_color(dest) == node_color(dest.upper())
+        assert node_color(dest).startswith("#")
+        assert len(node_color(dest)) == 7
+
+    def test_node_color_differs_between_hashes(self):
+        """Test that different hashes usually get different colors."""
+        colors = {node_color(f"{i:032x}") for i in range(20)}
+        assert len(colors) > 10
+
+    def test_build_node_avatar(self):
+        """Test building an avatar for a node."""
+        avatar = build_node_avatar("abcdef0123456789abcdef0123456789", size=24)
+
+        assert avatar.visible is True
+        assert avatar.width == 24
+        assert avatar.content.value == "AB"
+        assert avatar.bgcolor == node_color("abcdef0123456789abcdef0123456789")
+
+    def test_update_node_avatar_hides_without_hash(self):
+        """Test that avatars are hidden for pages without a node."""
+        avatar = build_node_avatar("abcdef0123456789abcdef0123456789")
+        update_node_avatar(avatar, None)
+
+        assert avatar.visible is False
+
+
+class TestFavoriteAlerts:
+    """Test cases for favorite node announce notifications."""
+
+    def _alerts(self, settings=None):
+        page = Mock()
+        page.overlay = []
+        tab_manager = Mock()
+        tab_manager.settings = settings or {}
+        tab_manager.nodes = NodeMetadata(Mock(load_node_metadata=Mock(return_value={})))
+        tab_manager.nodes.set_favorite("abc", True, "Hilltop")
+        on_open = Mock()
+        alerts = FavoriteAlerts(page, tab_manager, on_open)
+        alerts.started = 1000.0
+        return alerts, page, on_open
+
+    def test_notifies_when_favorite_returns(self):
+        """Test that a favorite announcing after a quiet hour is announced."""
+        alerts, page, on_open = self._alerts()
+
+        alerts.on_announce(Announce("abc", "Hilltop", 1010))
+        alerts.on_announce(Announce("abc", "Hilltop", 1600))
+        assert page.overlay == []
+        alerts.on_announce(Announce("abc", "Hilltop", 1600 + OFFLINE_SECONDS))
+
+        [snack] = page.overlay
+        assert snack.content.value == "Hilltop is back online"
+        assert snack.action == "Open"
+        snack.on_action(None)
+        on_open.assert_called_once_with("abc:/page/index.mu")
+
+    def test_ignores_other_nodes_and_respects_settings(self):
+        """Test that only favorites notify, and only when enabled."""
+        alerts, page, _ = self._alerts({"favorite_notifications": False})
+
+        alerts.on_announce(Announce("def", "Mesa", 5000))
+        alerts.on_announce(Announce("abc", "Hilltop", 5000))
+
+        assert page.overlay == []
+        assert "def" not in alerts.last_heard
+
+    def test_first_announce_after_startup_grace(self):
+        """Test that a favorite first heard well after startup notifies."""
+        alerts, page, _ = self._alerts({"system_notifications": True})
+
+        with patch("ren_browser.ui.notify.system_notify") as notify:
+            alerts.on_announce(Announce("abc", None, 1000 + STARTUP_GRACE))
+
+        assert page.overlay[0].content.value == "Hilltop is back online"
+        notify.assert_called_once_with("Ren Browser", "Hilltop is back online")
+
+
+class TestDesktopNotify:
+    """Test cases for desktop notifications."""
+
+    def test_respects_global_and_category_settings(self):
+        """Test that a notification needs both its switches on."""
+        with patch("ren_browser.ui.notify.system_notify", return_value=True) as send:
+            assert not desktop_notify({}, "watched", "Changed")
+            assert not desktop_notify(
+                {"system_notifications": True, "notify_watched": False},
+                "watched",
+                "Changed",
+            )
+            assert desktop_notify({"system_notifications": True}, "watched", "Changed")
+
+        send.assert_called_once_with("Ren Browser", "Changed")
+
+    def test_windows_toast(self):
+        """Test that Windows notifications are raised through PowerShell."""
+        with (
+            patch("ren_browser.ui.notify.sys.platform", "win32"),
+            patch("ren_browser.ui.notify.shutil.which", return_value="powershell"),
+            patch("ren_browser.ui.notify.subprocess.Popen") as popen,
+        ):
+            assert system_notify("Ren Browser", "Done")
+
+        args = popen.call_args.args[0]
+        assert args[0] == "powershell"
+        assert "ToastNotification" in args[-1]
+        env = popen.call_args.kwargs["env"]
+        assert (env["REN_TITLE"], env["REN_MESSAGE"]) == ("Ren Browser", "Done")
+
+    def test_no_command(self):
+        """Test that nothing is sent without a notification command."""
+        with patch("ren_browser.ui.notify.shutil.which", return_value=None):
+            assert not system_notify("Ren Browser", "Done")
+
+
+class TestStatusBar:
+    """Test cases for the status bar."""
+
+    def _tab_manager(self, tab=None, settings=None):
+        tab_manager = Mock()
+        tab_manager.settings = settings or {}
+        tab_manager.hovered_link = None
+        tab_manager.manager.tabs = [tab or {}]
+        tab_manager.manager.index = 0
+        return tab_manager
+
+    def test_connection_summary(self):
+        """Test summarizing interface statuses."""
+        assert connection_summary([]) == ("No active interfaces", False)
+        statuses = [{"online": True}, {"online": False}]
+        assert connection_summary(statuses) == ("1/2 interfaces online", True)
+
+    def test_request_summary(self):
+        """Test describing the request state of a tab."""
+        loading = {"load_progress": 0.3, "load_label": "Receiving page..."}
+        failed = {"address": "a:/page/index.mu", "load_failed": True}
+
+        assert request_summary({}) == "Ready"
+        assert request_summary(loading) == "Receiving page..."
+        assert request_summary({"address": "a:/page/index.mu"}) == "Loaded"
+        assert request_summary(failed) == "Failed"
+
+    def test_status_bar_shows_active_tab(self, mock_page):
+        """Test that the status bar reflects the active tab and hovered link."""
+        tab = {"address": "a:/page/index.mu", "origin": "network"}
+        tab_manager = self._tab_manager(tab)
+        tab_manager.hovered_link = "b:/page/about.mu"
+
+        with patch("ren_browser.rns.get_interface_statuses", return_value=[]):
+            status_bar = StatusBar(mock_page, tab_manager)
+
+        assert status_bar.request_text.value == "Loaded"
+        assert status_bar.link_text.value == "b:/page/about.mu"
+        assert status_bar.origin_text.value == "Origin: network"
+        assert status_bar.connection_text.value == "No active interfaces"
+
+    def test_status_bar_respects_setting(self, mock_page):
+        """Test that the status bar can be hidden from settings."""
+        tab_manager = self._tab_manager(settings={"show_status_bar": False})
+
+        with patch("ren_browser.rns.get_interface_statuses", return_value=[]):
+            status_bar = StatusBar(mock_page, tab_manager)
+        assert status_bar.control.visible is False
+
+        status_bar.apply_settings({"show_status_bar": True})
+        assert status_bar.control.visible is True
+
+    def test_poll_interval_setting(self, mock_page):
+        """Test that changing the poll interval replaces the polling loop."""
+        tab_manager = self._tab_manager(settings={"status_poll_seconds": 10})
+        mock_page.run_task = Mock()
+        with patch("ren_browser.rns.get_interface_statuses", return_value=[]):
+            status_bar = StatusBar(mock_page, tab_manager)
+            status_bar.start()
+            assert status_bar.poll_interval == 10
+
+            status_bar.apply_settings({"status_poll_seconds": 10})
+            assert mock_page.run_task.call_count == 1
+            status_bar.apply_settings({"status_poll_seconds": 7})
+            assert status_bar.poll_interval == 5
+            assert mock_page.run_task.call_count == 2
+
+            old_loop = mock_page.run_task.call_args_list[0].args[0]
+            with patch("ren_browser.ui.status_bar.asyncio.sleep", new=AsyncMock()):
+                asyncio.run(old_loop())
+
+    def test_connection_indicator_opens_interfaces(self, mock_page):
+        """Test that clicking the connection state lists the interfaces."""
+        radio = {
+            "name": "RNode LoRa",
+            "type": "RNodeInterface",
+            "online": True,
+            "bitrate": 1200,
+            "rxb": 2048,
+            "txb": 512,
+            "rssi": -97,
+            "snr": 6.5,
+        }
+        with patch("ren_browser.rns.get_interface_statuses", return_value=[radio]):
+            status_bar = StatusBar(mock_page, self._tab_manager())
+            indicator = status_bar.control.content.controls[0]
+            indicator.on_click(None)
+
+        panel = status_bar.interface_panel
+        assert panel.is_open is True
+        assert len(panel.rows.controls) == 1
+        assert interface_details(radio) == [
+            "RNodeInterface",
+            "Online",
+            "1 kbps",
+            "RSSI -97 dBm",
+            "SNR 6.5 dB",
+        ]
+
+    def test_interface_panel_shows_traffic_rates(self, mock_page):
+        """Test that rates are worked out between status checks while open."""
+        panel = InterfacePanel(mock_page)
+        status = {"name": "TCP", "type": "TCPClientInterface", "online": False}
+        panel.set_statuses([{**status, "rxb": 100, "txb": 0}])
+        assert panel.rows.controls == []
+
+        with (
+            patch("ren_browser.rns.get_interface_statuses", return_value=[]),
+            patch("ren_browser.ui.interfaces.time.monotonic", side_effect=[8, 10, 12]),
+        ):
+            panel.open()
+            assert panel.rows.controls[0].value == "No interfaces detected"
+            panel.set_statuses([{**status, "rxb": 1000, "txb": 0}])
+            panel.set_statuses([{**status, "rxb": 3000, "txb": 100}])
+
+        assert panel._rates["TCP"] == (1000, 50)
+        traffic = panel.rows.controls[0].content.controls[1].controls[2]
+        assert traffic.value == "Received 3.0 KB (1.0 KB/s), sent 100 B (50 B/s)"
+
+        panel.close()
+        assert panel.is_open is False
+
+
+class TestSidebar:
+    """Test cases for the collapsible sidebar."""
+
+    def _tab_manager(self, settings=None):
+        tab_manager = Mock()
+        tab_manager.settings = settings or {}
+        return tab_manager
+
+    def test_sidebar_starts_from_setting(self, mock_page):
+        """Test that the sidebar restores its collapsed state."""
+        sidebar = Sidebar(
+            mock_page,
+            self._tab_manager({"sidebar_collapsed": True}),
+            ft.ListView(),
+        )
+
+        assert sidebar.collapsed is True
+        assert sidebar.control.width == COLLAPSED_WIDTH
+        assert sidebar.control.content is sidebar.collapsed_view
+
+    def test_sidebar_toggle_persists_state(self, mock_page):
+        """Test that toggling the sidebar saves the new state."""
+        tab_manager = self._tab_manager({"page_bgcolor": "#000000"})
+        sidebar = Sidebar(mock_page, tab_manager, ft.ListView())
+        storage = Mock()
+
+        with patch("ren_browser.ui.sidebar.get_storage_manager", return_value=storage):
+            sidebar.toggle()
+
+        assert sidebar.collapsed is True
+        assert sidebar.control.width == COLLAPSED_WIDTH
+        storage.save_app_settings.assert_called_once_with(
+            {"page_bgcolor": "#000000", "sidebar_collapsed": True},
+        )
+        assert tab_manager.settings["sidebar_collapsed"] is True
+
+        with patch("ren_browser.ui.sidebar.get_storage_manager", return_value=storage):
+            sidebar.toggle()
+
+        assert sidebar.control.width == SIDEBAR_WIDTH
+        assert sidebar.control.content is sidebar.expanded_view
+
+    def test_sidebar_restores_saved_width(self, mock_page):
+        """Test that the sidebar uses the stored width within limits."""
+        sidebar = Sidebar(mock_page, self._tab_manager({"sidebar_width": 420}), None)
+        assert sidebar.control.width == 420
+
+        sidebar = Sidebar(mock_page, self._tab_manager({"sidebar_width": 5000}), None)
+        assert sidebar.control.width == MAX_SIDEBAR_WIDTH
+
+    def test_sidebar_drag_resizes_and_persists(self, mock_page):
+        """Test that dragging the divider resizes live and saves on release."""
+        tab_manager = self._tab_manager()
+        sidebar = Sidebar(mock_page, tab_manager, ft.ListView())
+        storage = Mock()
+
+        sidebar._on_drag_update(Mock(delta_x=40))
+        assert sidebar.control.width == SIDEBAR_WIDTH + 40
+        storage.save_app_settings.assert_not_called()
+
+        sidebar._on_drag_update(Mock(delta_x=-1000))
+        assert sidebar.control.width == MIN_SIDEBAR_WIDTH
+
+        with patch("ren_browser.ui.sidebar.get_storage_manager", return_value=storage):
+            sidebar._on_drag_end(Mock())
+
+        storage.save_app_settings.assert_called_once_with(
+            {"sidebar_width": MIN_SIDEBAR_WIDTH},
+        )
+
+    def test_divider_hidden_when_collapsed(self, mock_page):
+        """Test that the resize handle is only shown for the expanded sidebar."""
+        sidebar = Sidebar(
+            mock_page,
+            self._tab_manager({"sidebar_collapsed": True}),
+            ft.ListView(),
+        )
+        assert sidebar.divider.visible is False
+
+    def test_narrow_window_turns_sidebar_into_drawer(self, mock_page):
+        """Test that a narrow window moves the sidebar into a slide-in drawer."""
+        tab_manager = self._tab_manager({"sidebar_collapsed": True})
+        sidebar = Sidebar(mock_page, tab_manager, ft.ListView())
+        storage = Mock()
+
+        sidebar.fit(300)
+
+        assert sidebar.drawer_mode is True
+        assert sidebar.total_width == 0
+        assert sidebar.control.visible is False
+        assert sidebar.drawer.content is sidebar.expanded_view
+        assert sidebar.drawer.width == 255
+        assert sidebar.hidden is True
+
+        with patch("ren_browser.ui.sidebar.get_storage_manager", return_value=storage):
+            sidebar.toggle()
+        assert sidebar.drawer_open is True
+        assert sidebar.scrim.visible is True
+        assert sidebar.hidden is False
+        storage.save_app_settings.assert_not_called()
+
+        sidebar.scrim.on_click(None)
+        assert sidebar.drawer_open is False
+        assert sidebar.scrim.visible is False
+
+        sidebar.fit(1200)
+
+        assert sidebar.drawer_mode is False
+        assert sidebar.control.visible is True
+        assert sidebar.control.content is sidebar.collapsed_view
+        assert sidebar.drawer.content is None
+
+    def test_sidebar_shows_connection_when_collapsed(self, mock_page):
+        """Test that the collapsed strip shows the connection state."""
+        sidebar = Sidebar(mock_page, self._tab_manager(), ft.ListView())
+
+        sidebar.set_connection([{"online": True}])
+        assert sidebar.connection_icon.color == ft.Colors.GREEN
+        assert sidebar.connection_icon.tooltip == "1/1 interfaces online"
+
+        sidebar.set_connection([])
+        assert sidebar.connection_icon.color == ft.Colors.ERROR
+
+    def test_plugin_panels_become_tabs(self, mock_page):
+        """Test that panels added by plugins are shown as tabs beside the nodes."""
+        nodes = ft.ListView()
+        panel = ft.Text("Notes")
+        sidebar = Sidebar(mock_page, self._tab_manager(), nodes)
+
+        sidebar.set_panels([("Notes", panel)])
+
+        tabs = sidebar.expanded_view.controls[-1]
+        assert isinstance(tabs, ft.Tabs)
+        assert [tab.text for tab in tabs.tabs] == ["Nodes", "Notes"]
+        assert tabs.tabs[0].content is nodes
+        assert tabs.tabs[1].content is panel
+
+        sidebar.set_panels([])
+        assert sidebar.expanded_view.controls[-1] is nodes
+
+
+class TestUiZoom:
+    """Test cases for interface zoom."""
+
+    def _zoom(self, mock_page, settings=None):
+        tab_manager = Mock()
+        tab_manager.settings = settings or {}
+        mock_page.height = 800
+        mock_page.appbar = Mock(visible=True)
+        zoom = UiZoom(mock_page, tab_manager)
+        zoom.root = ft.Container(expand=True)
+        return zoom
+
+    def test_step_zoom(self):
+        """Test stepping between zoom levels."""
+        assert step_zoom(1.0, 1) == 1.1
+        assert step_zoom(1.0, -1) == 0.9
+        assert step_zoom(ZOOM_LEVELS[-1], 1) == ZOOM_LEVELS[-1]
+        assert step_zoom(ZOOM_LEVELS[0], -1) == ZOOM_LEVELS[0]
+
+    def test_zoom_scales_root_to_fill_window(self, mock_page):
+        """Test that zooming scales the body and keeps it filling the window."""
+        zoom = self._zoom(mock_page)
+        storage = Mock()
+
+        with patch("ren_browser.ui.zoom.get_storage_manager", return_value=storage):
+            zoom.set_factor(2.0)
+
+        assert zoom.root.scale.scale == 2.0
+        assert zoom.root.width == mock_page.width / 2
+        assert zoom.root.height == (800 - 56) / 2
+        assert zoom.viewport_width() == mock_page.width / 2
+        storage.save_app_settings.assert_called_once_with({"ui_scale": 2.0})
+
+        with patch("ren_browser.ui.zoom.get_storage_manager", return_value=storage):
+            zoom.reset()
+
+        assert zoom.root.scale is None
+        assert zoom.root.expand is True
+
+    def test_zoom_restores_saved_factor(self, mock_page):
+        """Test that the saved zoom is clamped and restored."""
+        assert self._zoom(mock_page, {"ui_scale": 1.25}).factor == 1.25
+        assert self._zoom(mock_page, {"ui_scale": 10}).factor == ZOOM_LEVELS[-1]
+        assert self._zoom(mock_page, {"ui_scale": "bad"}).factor == 1.0
+
+
+class TestTheme:
+    """Test cases for theme selection."""
+
+    def test_apply_theme_sets_mode(self, mock_page):
+        """Test that each theme choice sets the matching theme mode."""
+        mock_page.appbar = Mock()
+        for theme, mode in (
+            ("dark", ft.ThemeMode.DARK),
+            ("light", ft.ThemeMode.LIGHT),
+            ("system", ft.ThemeMode.SYSTEM),
+            ("unknown", ft.ThemeMode.DARK),
+        ):
+            apply_theme(mock_page, theme)
+            assert mock_page.theme_mode == mode
+
+        assert mock_page.bgcolor == ft.Colors.SURFACE
+        assert mock_page.appbar.bgcolor == ft.Colors.SURFACE_CONTAINER
+
+    def test_content_bgcolor_follows_theme_at_default(self):
+        """Test that only chosen page colours override the theme."""
+        assert content_bgcolor({}) == ft.Colors.SURFACE
+        assert content_bgcolor({"page_bgcolor": "#112233"}) == ft.Colors.SURFACE
+        assert content_bgcolor({"page_colors": "sepia"}) == "#f4ecd8"
+        settings = {
+            "page_colors": "custom",
+            "page_bgcolor": "#112233",
+            "page_fgcolor": "#eeeeee",
+        }
+        assert content_bgcolor(settings) == "#112233"
+
+    def test_custom_page_colors(self):
+        """Test that custom page colours take links suiting the background."""
+        settings = {
+            "page_colors": "custom",
+            "page_bgcolor": "#fafafa",
+            "page_fgcolor": "#333333",
+        }
+        colors = page_colors(settings)
+
+        assert (colors.background, colors.text) == ("#fafafa", "#333333")
+        assert colors.link == PAGE_COLOR_PRESETS["light"].link
+        assert page_colors({**settings, "page_fgcolor": "grey"}) is None
+        assert page_colors({"page_colors": "theme"}) is None
+        assert page_colors({"theme": "high_contrast", "page_colors": "sepia"}) is None
+
+    def test_apply_content_colors(self):
+        """Test that the page area gets a nested theme in the page colours."""
+        container = ft.Container()
+
+        apply_content_colors(container, {"theme": "dark", "page_colors": "sepia"})
+
+        assert container.bgcolor == "#f4ecd8"
+        assert container.theme_mode == ft.ThemeMode.LIGHT
+        assert container.theme.color_scheme.on_surface == "#5b4636"
+        assert container.theme.color_scheme.tertiary == "#1a5fb4"
+
+        apply_content_colors(container, {"theme": "light", "page_colors": "theme"})
+
+        assert container.bgcolor == ft.Colors.SURFACE
+        assert container.theme is None
+        assert container.theme_mode is None
+
+    def test_high_contrast_theme(self, mock_page):
+        """Test that High Contrast is pure black and white with black pages."""
+        mock_page.appbar = None
+
+        assert theme_options(None)["high_contrast"] == "High Contrast"
+
+        apply_theme(mock_page, "high_contrast")
+
+        assert mock_page.theme_mode == ft.ThemeMode.DARK
+        assert mock_page.dark_theme is mock_page.theme
+        assert mock_page.theme.color_scheme is HIGH_CONTRAST_SCHEME
+        assert HIGH_CONTRAST_SCHEME.surface == ft.Colors.BLACK
+        assert HIGH_CONTRAST_SCHEME.on_surface == ft.Colors.WHITE
+        settings = {"theme": "high_contrast", "page_bgcolor": "#112233"}
+        assert is_high_contrast(settings)
+        assert content_bgcolor(settings) == ft.Colors.SURFACE
+        assert not is_high_contrast({"theme": "dark"})
+
+    def test_custom_theme(self, mock_page, tmp_path):
+        """Test that a custom theme is offered and sets its own palette."""
+        library = ThemeLibrary(tmp_path)
+        library.save(CustomTheme("Paper", background="#fafafa", text="#202020"))
+        mock_page.appbar = None
+
+        assert theme_options(library)["custom:Paper"] == "Paper"
+
+        apply_theme(mock_page, "custom:Paper", library)
+
+        assert mock_page.theme_mode == ft.ThemeMode.LIGHT
+        assert mock_page.dark_theme is mock_page.theme
+
+        apply_theme(mock_page, "custom:Missing", library)
+
+        assert mock_page.theme_mode == ft.ThemeMode.DARK
+
+
+class TestFonts:
+    """Test cases for the font settings."""
+
+    def test_register_local_fonts(self, mock_page, tmp_path):
+        """Test that font files in the fonts folder are made available."""
+        (tmp_path / "Terminus.ttf").write_bytes(b"")
+        (tmp_path / "Fira Code.OTF").write_bytes(b"")
+        (tmp_path / "readme.txt").write_text("")
+        mock_page.fonts = {"Other": "other.ttf"}
+
+        assert register_fonts(mock_page, tmp_path) == ["Fira Code", "Terminus"]
+        assert mock_page.fonts["Terminus"] == str(tmp_path / "Terminus.ttf")
+        assert mock_page.fonts["Other"] == "other.ttf"
+        assert local_fonts(tmp_path / "missing") == {}
+
+    def test_page_font_and_minimum_size(self):
+        """Test that the readable font replaces the page font when chosen."""
+        assert page_font({"content_font": "serif"}) == "serif"
+        assert page_font({"content_font": ""}) is None
+        assert page_font({"content_font": "serif", "readable_font": True}) == (
+            READABLE_FONT
+        )
+        assert min_font_size({"min_font_size": 16}) == 16
+        assert min_font_size({"min_font_size": 3}) == 0
+        assert min_font_size({}) == 0
+
+    def test_font_picker(self, mock_page):
+        """Test choosing listed fonts and system fonts by name."""
+        picker = FontPicker(mock_page, "Font", "Ubuntu", UI_FONT_PRESETS)
+
+        assert picker.dropdown.value == OTHER_FONT
+        assert picker.name_field.visible is True
+        assert picker.value == "Ubuntu"
+
+        picker.set_families(["Ubuntu", "serif"])
+
+        assert picker.dropdown.value == "Ubuntu"
+        assert picker.name_field.visible is False
+        assert [o.key for o in picker.dropdown.options].count("serif") == 1
+
+        picker.dropdown.value = OTHER_FONT
+        picker.name_field.value = "  DejaVu Sans "
+        assert picker.value == "DejaVu Sans"
+
+        picker.set_value("")
+        assert picker.value == ""
+
+
+class TestThemeEditor:
+    """Test cases for the custom theme editor."""
+
+    def test_preview_follows_typed_colors(self, mock_page, tmp_path):
+        """Test that the preview updates, ignoring half-typed colours."""
+        editor = ThemeEditor(mock_page, ThemeLibrary(tmp_path))
+        editor.open()
+
+        editor.color_fields["link"].value = "#00ff00"
+        editor.color_fields["accent"].value = "#12"
+        editor.update_preview()
+
+        assert editor.preview_link.color == "#00ff00"
+        assert editor.preview_heading.color == CustomTheme("").accent
+        assert editor.swatches["link"].bgcolor == "#00ff00"
+
+    def test_save_rename_and_delete(self, mock_page, tmp_path):
+        """Test editing a theme through the dialog."""
+        library = ThemeLibrary(tmp_path)
+        on_change = Mock()
+        editor = ThemeEditor(mock_page, library, on_change)
+        editor.open()
+
+        editor.save()
+        assert editor.error.visible is True
+        on_change.assert_not_called()
+
+        editor.name_field.value = "Mine"
+        editor.save()
+        on_change.assert_called_with(None, "Mine")
+        assert editor.delete_button.visible is True
+
+        editor.name_field.value = "Ours"
+        editor.save()
+        on_change.assert_called_with("Mine", "Ours")
+        assert [theme.name for theme in library.themes()] == ["Ours"]
+
+        editor.delete()
+        on_change.assert_called_with("Ours", None)
+        assert library.themes() == []
+
+
+class TestLinkPreview:
+    """Test cases for the link preview popover."""
+
+    NODE = "a" * 32
+
+    def _preview(self):
+        page = Mock()
+        page.overlay = []
+        page.run_thread.side_effect = lambda handler: handler()
+        tab_manager = Mock()
+        tab_manager.resolve_node_name = None
+        tab_manager.page_cache = PageCache()
+        tab_manager.nodes = NodeMetadata(Mock(load_node_metadata=Mock(return_value={})))
+        return LinkPreview(page, tab_manager, delay=0), tab_manager
+
+    def test_preview_lines(self):
+        """Test that markup and blank lines are left out of the preview."""
+        content = ">Heading\n\nFirst line\n`!Bold`! line\n" + "more\n" * 10
+
+        lines = preview_lines(content, "/page/index.mu")
+
+        assert lines[:3] == ["Heading", "First line", "Bold line"]
+        assert len(lines) == 6
+
+    def test_blocked_node_is_not_previewed(self):
+        """Test that hovering a link to a blocked node fetches nothing."""
+        preview, tab_manager = self._preview()
+        tab_manager.nodes.set_blocked(self.NODE, True)
+
+        with patch("ren_browser.ui.link_preview.PageFetcher") as fetcher:
+            preview.hover(f"{self.NODE}:/page/news.mu", None)
+
+        fetcher.assert_not_called()
+        assert preview.body.controls[0].value == "This node is blocked."
+
+    def test_preview_from_cache(self):
+        """Test that cached pages are previewed without fetching."""
+        preview, tab_manager = self._preview()
+        url = f"{self.NODE}:/page/news.mu"
+        tab_manager.page_cache.put(url, "Latest news\nMore text")
+
+        with patch("ren_browser.ui.link_preview.PageFetcher") as fetcher:
+            preview.hover(":/page/news.mu", self.NODE)
+
+        fetcher.assert_not_called()
+        assert preview.is_open
+        assert preview.address == url
+        assert [t.value for t in preview.body.controls] == ["Latest news", "More text"]
+
+    def test_preview_fetches_and_caches(self):
+        """Test that uncached pages are fetched and kept for later."""
+        preview, tab_manager = self._preview()
+        url = f"{self.NODE}:/page/index.mu"
+
+        with patch("ren_browser.ui.link_preview.PageFetcher") as fetcher:
+            fetcher.return_value.fetch_page.return_value = "Welcome"
+            preview.hover(url)
+
+        assert tab_manager.page_cache.get(url).content == "Welcome"
+        assert preview.body.controls[0].value == "Welcome"
+
+    def test_failed_fetch_shows_message(self):
+        """Test that a failed fetch explains why there is no preview."""
+        preview, _ = self._preview()
+
+        with patch("ren_browser.ui.link_preview.PageFetcher") as fetcher:
+            fetcher.return_value.fetch_page.side_effect = Exception("timed out")
+            preview.hover(f"{self.NODE}:/page/index.mu")
+
+        assert preview.body.controls[0].value == "No preview: timed out"
+
+    def test_leaving_link_hides_preview(self):
+        """Test that moving off the link hides the popover."""
+        preview, tab_manager = self._preview()
+        url = f"{self.NODE}:/page/index.mu"
+        tab_manager.page_cache.put(url, "Welcome")
+        preview.hover(url)
+
+        preview.hover(None)
+
+        assert not preview.is_open
+        assert preview.address is None
+
+    def test_internal_and_file_links_are_not_previewed(self):
+        """Test that only remote pages are previewed."""
+        preview, _ = self._preview()
+
+        preview.hover("about:history")
+        preview.hover(f"{self.NODE}:/file/report.pdf")
+
+        preview.page.run_thread.assert_not_called()
+
+
+class TestErrorPage:
+    """Test cases for load error pages."""
+
+    def test_error_page_explains_kind(self):
+        """Test that the error page shows the explanation for its kind."""
+        page = build_error_page(
+            "timeout",
+            "abc:/page/index.mu",
+            "Timed out",
+            Mock(),
+            Mock(),
+        )
+        column = page.content
+
+        assert column.controls[1].value == ERROR_DETAILS["timeout"][1]
+        assert column.controls[3].value == "abc:/page/index.mu"
+        assert column.controls[4].value == "Timed out"
+
+    def test_error_page_unknown_kind_falls_back(self):
+        """Test that unexpected errors get the generic explanation."""
+        page = build_error_page("weird", "abc:/page/index.mu", "Boom", Mock(), Mock())
+
+        assert page.content.controls[1].value == ERROR_DETAILS["unknown"][1]
+
+    def test_error_page_actions(self):
+        """Test that the action buttons call their handlers."""
+        on_retry, on_request_path, on_open_cached = Mock(), Mock(), Mock()
+        page = build_error_page(
+            "no_path",
+            "abc:/page/index.mu",
+            "No path",
+            on_retry,
+            on_request_path,
+            on_open_cached,
+        )
+        retry, request_path, open_cached = page.content.controls[5].controls
+
+        retry.on_click(None)
+        request_path.on_click(None)
+        open_cached.on_click(None)
+
+        on_retry.assert_called_once()
+        on_request_path.assert_called_once()
+        on_open_cached.assert_called_once()
+        assert open_cached.disabled is False
+
+
+class TestClearBrowsingData:
+    """Test cases for the clear browsing data dialog."""
+
+    def test_clears_only_selected_kinds_in_range(self):
+        """Test that the chosen stores are cleared from the start of the range."""
+        tab_manager = Mock()
+
+        clear_browsing_data(tab_manager, ["cache", "history"], "hour", now=10000.0)
+
+        tab_manager.page_cache.clear.assert_called_once_with(6400.0)
+        tab_manager.history.clear.assert_called_once_with(6400.0)
+        tab_manager.bookmarks.clear.assert_not_called()
+        tab_manager.downloads.clear_finished.assert_not_called()
+
+    def test_everything_clears_without_a_start(self):
+        """Test that the Everything range clears all entries."""
+        tab_manager = Mock()
+
+        clear_browsing_data(tab_manager, ["bookmarks", "downloads"], "all")
+
+        tab_manager.bookmarks.clear.assert_called_once_with(None)
+        tab_manager.downloads.clear_finished.assert_called_once_with(None)
+        tab_manager._on_bookmarks_imported.assert_called_once()
+
+    def test_dialog_clears_ticked_data(self, mock_page):
+        """Test that the dialog clears what is ticked and closes."""
+        tab_manager = Mock()
+        dialog = ClearDataDialog(mock_page, tab_manager)
+        dialog.checkboxes["history"].value = False
+        dialog.checkboxes["reading_list"].value = True
+        dialog.range_group.value = "day"
+
+        dialog.clear()
+
+        assert dialog.selected_kinds() == ["cache", "reading_list"]
+        tab_manager.page_cache.clear.assert_called_once()
+        tab_manager.reading_list.clear.assert_called_once()
+        tab_manager.history.clear.assert_not_called()
+        mock_page.close.assert_called_once_with(dialog.dialog)
+
+    def test_dialog_with_nothing_ticked(self, mock_page):
+        """Test that clearing with no kinds selected does nothing."""
+        tab_manager = Mock()
+        dialog = ClearDataDialog(mock_page, tab_manager)
+        for box in dialog.checkboxes.values():
+            box.value = False
+
+        dialog.clear()
+
+        tab_manager.page_cache.clear.assert_not_called()
+        tab_manager.history.clear.assert_not_called()
+
+
+class TestHistoryPage:
+    """Test cases for the history page."""
+
+    def test_history_page_groups_and_opens_entries(self):
+        """Test that entries are listed under day headings and reopen on click."""
+        history = HistoryManager(Mock(load_history=Mock(return_value=[])))
+        history.record("a:/page/index.mu", "Node A", "a")
+        on_open = Mock()
+
+        page = build_history_page(history, on_open)
+
+        results = page.controls[2].controls
+        assert results[0].content.value == "Today"
+        entry = results[1]
+        assert entry.content.controls[1].value == "Node A"
+        entry.on_click(None)
+        on_open.assert_called_once_with("a:/page/index.mu")
+
+    def test_empty_history_page(self):
+        """Test the history page with nothing recorded."""
+        history = HistoryManager(Mock(load_history=Mock(return_value=[])))
+
+        page = build_history_page(history, Mock())
+
+        results = page.controls[2].controls
+        assert results[0].value == "Pages you visit will be listed here."
+
+    def test_history_page_search(self):
+        """Test that typing in the search field filters the list in place."""
+        history = HistoryManager(Mock(load_history=Mock(return_value=[])))
+        history.record("a:/page/index.mu", "Node A", "a")
+        history.record("b:/page/index.mu", "Node B", "b")
+        on_change = Mock()
+        page = build_history_page(history, Mock(), on_change, {"b": "Relay"}.get)
+        search_field = page.controls[1].controls[0]
+
+        search_field.value = "relay"
+        search_field.on_change(None)
+
+        results = page.controls[2].controls
+        assert len(results) == 2
+        assert results[1].content.controls[1].value == "Node B"
+        on_change.assert_called_once()
+
+    def test_history_page_rejects_bad_dates(self):
+        """Test that an invalid day shows an error instead of filtering."""
+        history = HistoryManager(Mock(load_history=Mock(return_value=[])))
+        history.record("a:/page/index.mu", "Node A", "a")
+        page = build_history_page(history, Mock())
+        start_field = page.controls[1].controls[1]
+
+        start_field.value = "yesterday"
+        start_field.on_submit(None)
+
+        assert start_field.error_text == "Use YYYY-MM-DD"
+        assert page.controls[2].controls == []
+
+
+class TestRecentlyClosed:
+    """Test cases for the recently closed tabs section."""
+
+    def test_lists_entries_and_restores_on_click(self):
+        """Test that each closed tab is listed and restored when clicked."""
+        entry = ClosedTab("a:/page/index.mu", "Node A", closed_at=0)
+        on_restore = Mock()
+
+        section = build_recently_closed([entry], on_restore)
+
+        row = section.content.controls[1]
+        assert row.content.controls[1].value == "Node A"
+        row.on_click(None)
+        on_restore.assert_called_once_with(entry)
+
+    def test_no_section_without_closed_tabs(self):
+        """Test that nothing is shown before any tab is closed."""
+        assert build_recently_closed([], Mock()) is None
+
+
+def _speed_dial_tiles(section):
+    return section.content.controls[-1].controls
+
+
+class TestSpeedDialGrid:
+    """Test cases for the speed dial grid."""
+
+    @pytest.fixture
+    def history(self):
+        """Create a history with one visited page."""
+        history = HistoryManager(Mock(load_history=Mock(return_value=[])))
+        history.record("b:/page/index.mu", "Node B")
+        return history
+
+    def test_tiles_open_their_address(self, history):
+        """Test that pinned tiles come first and open when clicked."""
+        dial = SpeedDial(Mock(load_speed_dial=Mock(return_value=[])))
+        dial.pin("a:/page/index.mu", "Node A")
+        on_open = Mock()
+
+        section = build_speed_dial(dial, history, on_open, Mock())
+
+        tiles = _speed_dial_tiles(section)
+        assert len(tiles) == 2
+        card = tiles[0].content.content
+        assert card.content.controls[0].controls[1].value == "Node A"
+        card.on_click(None)
+        on_open.assert_called_once_with("a:/page/index.mu")
+
+    def test_dropping_a_top_site_pins_it(self, history):
+        """Test that dragging a history tile onto a position pins it there."""
+        dial = SpeedDial(Mock(load_speed_dial=Mock(return_value=[])))
+        dial.pin("a:/page/index.mu", "Node A")
+        on_change = Mock()
+        section = build_speed_dial(dial, history, Mock(), on_change)
+        source = Mock(data="b:/page/index.mu")
+        event = Mock(src_id="b")
+        event.page.get_control.return_value = source
+
+        _speed_dial_tiles(section)[0].on_accept(event)
+
+        assert [tile.address for tile in dial.pinned()] == [
+            "b:/page/index.mu",
+            "a:/page/index.mu",
+        ]
+        on_change.assert_called_once()
+
+    def test_rename_tile_in_place(self, history):
+        """Test that Rename swaps the label for a field that saves on submit."""
+        dial = SpeedDial(Mock(load_speed_dial=Mock(return_value=[])))
+        dial.pin("a:/page/index.mu", "Node A")
+        section = build_speed_dial(dial, history, Mock(), Mock())
+        menu = _speed_dial_tiles(section)[0].content.content.content.controls[1]
+
+        menu.items[0].on_click(None)
+        card = _speed_dial_tiles(section)[0].content.content
+        field = card.content.controls[0].controls[1]
+        field.on_submit(Mock(control=Mock(value="Weather")))
+
+        assert dial.get("a:/page/index.mu").label == "Weather"
+
+
+def _bookmark_title(entry):
+    return entry.content.controls[1].content.controls[0].value
+
+
+class TestBookmarksPage:
+    """Test cases for the bookmarks manager page."""
+
+    @pytest.fixture
+    def bookmarks(self):
+        """Create a bookmark manager with a couple of saved pages."""
+        manager = BookmarkManager(Mock(load_bookmarks=Mock(return_value=[])))
+        manager.add("a:/page/index.mu", "Node A", folder="Mesh", tags="chat")
+        manager.add("b:/page/index.mu", "Node B")
+        return manager
+
+    def test_bookmarks_grouped_by_folder(self, bookmarks):
+        """Test that unsorted bookmarks come first, then each folder."""
+        page = build_bookmarks_page(bookmarks, Mock(), Mock())
+
+        assert page.controls[2].content.controls[1].value == "Unsorted"
+        assert _bookmark_title(page.controls[3]) == "Node B"
+        assert page.controls[4].content.controls[1].value == "Mesh"
+        assert _bookmark_title(page.controls[5]) == "Node A"
+
+    def test_tag_filter(self, bookmarks):
+        """Test that choosing a tag only lists bookmarks with it."""
+        on_change = Mock()
+        page = build_bookmarks_page(bookmarks, Mock(), on_change)
+
+        chat_chip = page.controls[1].controls[1]
+        chat_chip.on_click(None)
+
+        assert len(page.controls) == 4
+        assert _bookmark_title(page.controls[3]) == "Node A"
+        on_change.assert_called_once()
+
+    def test_remove_and_move(self, bookmarks):
+        """Test deleting and refiling bookmarks from the page."""
+        page = build_bookmarks_page(bookmarks, Mock(), Mock())
+        entry = page.controls[-1]
+        delete_btn = entry.content.controls[3]
+
+        delete_btn.on_click(None)
+
+        assert bookmarks.get("a:/page/index.mu") is None
+        entry = page.controls[-1]
+        folder_dropdown = entry.content.controls[2]
+        folder_dropdown.on_change(Mock(control=Mock(value="Mesh")))
+        assert bookmarks.get("b:/page/index.mu").folder == "Mesh"
+
+    def test_open_bookmark(self, bookmarks):
+        """Test that clicking a bookmark opens its address."""
+        on_open = Mock()
+        page = build_bookmarks_page(bookmarks, on_open, Mock())
+
+        page.controls[-1].content.controls[1].on_click(None)
+
+        on_open.assert_called_once_with("a:/page/index.mu")
+
+
+class TestReadingListPage:
+    """Test cases for the reading list page."""
+
+    def test_lists_and_removes_saved_pages(self, tmp_path):
+        """Test that saved pages are listed, opened and deleted."""
+        reading_list = ReadingList(tmp_path)
+        reading_list.save("a:/page/index.mu", "Node A", "Hello", "/page/index.mu")
+        on_open = Mock()
+        on_change = Mock()
+
+        page = build_reading_list_page(reading_list, on_open, on_change)
+
+        entry = page.controls[1].content.controls
+        assert entry[1].content.controls[0].value == "Node A"
+        entry[1].on_click(None)
+        on_open.assert_called_once_with("a:/page/index.mu")
+
+        entry[2].on_click(None)
+        assert reading_list.items() == []
+        assert len(page.controls) == 2
+        on_change.assert_called_once()
+
+
+class TestWatchedPage:
+    """Test cases for the watched pages page."""
+
+    def test_lists_checks_and_unwatches_pages(self):
+        """Test that watched pages are listed, checked and removed."""
+        storage = Mock(load_watched_pages=Mock(return_value=[]))
+        watcher = PageWatcher(storage, lambda address: "Changed")
+        watcher.watch("a:/page/index.mu", "Node A", "Hello")
+        on_open = Mock()
+        on_change = Mock()
+
+        def on_check(done):
+            assert page.controls[0].controls[1].disabled is True
+            watcher.check_all()
+            done()
+
+        page = build_watched_page(watcher, on_open, on_check, on_change)
+        page.controls[0].controls[1].on_click(None)
+
+        entry = page.controls[1].content.controls
+        assert entry[1].content.controls[0].weight == ft.FontWeight.BOLD
+        assert "Changed" in entry[1].content.controls[1].value
+        entry[1].on_click(None)
+        on_open.assert_called_once_with("a:/page/index.mu")
+
+        entry[2].on_click(None)
+        assert watcher.pages() == []
+        assert len(page.controls) == 2
+
+    def test_count_badge(self):
+        """Test that the badge only shows when something changed."""
+        assert count_badge(0) is None
+        assert count_badge(3).text == "3"
+
+
+class TestCachePage:
+    """Test cases for the cache inspector page."""
+
+    def test_lists_stats_and_removes_pages(self):
+        """Test that cached pages are listed with their stats and removed."""
+        cache = PageCache()
+        cache.put("a:/page/index.mu", "Hello")
+        cache.put("b:/page/index.mu", "Hi")
+        cache.get("a:/page/index.mu")
+        cache.get("c:/page/index.mu")
+        on_open = Mock()
+        on_change = Mock()
+
+        page = build_cache_page(cache, on_open, on_change)
+
+        assert page.controls[1].value == (
+            "2 pages, 7 B  ·  50% served from the cache (1 hits, 1 misses)"
+        )
+        first = page.controls[2].content.controls
+        assert first[1].content.controls[0].value == "a:/page/index.mu"
+        assert first[1].content.controls[1].value == "5 B  ·  0 s old  ·  1 hit"
+        first[1].on_click(None)
+        on_open.assert_called_once_with("a:/page/index.mu")
+
+        first[2].on_click(None)
+        assert cache.get("a:/page/index.mu", record=False) is None
+        assert len(page.controls) == 3
+        on_change.assert_called_once()
+
+    def test_format_age(self):
+        """Test that ages are shown in their largest whole unit."""
+        assert format_age(42) == "42 s"
+        assert format_age(125) == "2 min"
+        assert format_age(7200) == "2 h"
+        assert format_age(200000) == "2 d"
+
+
+class TestLogsPage:
+    """Test cases for the log viewer page."""
+
+    def setup_method(self):
+        """Start each test with a known set of messages."""
+        logs.RECENT.clear()
+        logs.RECENT.extend(
+            [
+                logs.LogEntry("t1", "info", "app", "Opened a tab"),
+                logs.LogEntry("t2", "error", "app", "Failed to load page"),
+                logs.LogEntry("t3", "debug", "reticulum", "Packet received"),
+            ],
+        )
+
+    def teardown_method(self):
+        """Leave no messages behind for other tests."""
+        logs.RECENT.clear()
+
+    def test_lists_newest_first_and_filters(self):
+        """Test that messages are listed newest first and filtered."""
+        on_change = Mock()
+        page = build_logs_page(Mock(), on_change)
+        summary, entries = page.controls[2], page.controls[3]
+
+        assert summary.value == "3 messages"
+        assert [t.value for t in entries.controls] == [
+            "[t3] DEBUG reticulum: Packet received",
+            "[t2] ERROR app: Failed to load page",
+            "[t1] INFO app: Opened a tab",
+        ]
+        assert entries.controls[1].color == ft.Colors.ERROR
+
+        search_field, chips = page.controls[1].controls
+        chips.controls[3].on_select(None)
+        assert summary.value == "2 messages"
+        assert not chips.controls[3].selected
+
+        search_field.value = "FAILED"
+        search_field.on_change(None)
+        assert summary.value == "1 message"
+        assert on_change.call_count == 2
+
+        search_field.value = "nothing like it"
+        search_field.on_change(None)
+        assert entries.controls[0].value == "No messages match."
+
+    def test_copies_messages_shown(self):
+        """Test that the messages shown are copied oldest first."""
+        on_copy = Mock()
+        page = build_logs_page(on_copy, Mock())
+        page.controls[1].controls[1].controls[0].on_select(None)
+
+        page.controls[0].controls[2].on_click(None)
+
+        on_copy.assert_called_once_with(
+            "[t1] INFO app: Opened a tab\n[t3] DEBUG reticulum: Packet received",
+        )
+
+    def test_refresh_shows_new_messages(self):
+        """Test that the refresh button lists messages logged since."""
+        page = build_logs_page(Mock(), Mock())
+        logs.log_app("Closed a tab")
+
+        page.controls[0].controls[1].on_click(None)
+
+        assert page.controls[2].value == "4 messages"
+
+
+class TestTabSwitcher:
+    """Test cases for the tab switcher shown on phones."""
+
+    def _tab_manager(self, count=3):
+        tab_manager = Mock()
+        tab_manager.manager = Mock(
+            tabs=[
+                {"title": f"Tab {i}", "address": f"{i:032x}:/page/index.mu"}
+                for i in range(count)
+            ],
+            index=1,
+        )
+        tab_manager.current_hash.return_value = None
+        return tab_manager
+
+    def test_open_lists_every_tab(self):
+        """Test that opening the switcher shows a tile for each tab."""
+        page = Mock()
+        switcher = TabSwitcher(page, self._tab_manager())
+
+        switcher.open()
+
+        assert switcher.is_open
+        assert switcher.title.value == "3 tabs"
+        assert len(switcher.grid.controls) == 3
+        assert all(isinstance(c, ft.Dismissible) for c in switcher.grid.controls)
+        assert page.overlay.append.call_args.args[0] is switcher.panel
+
+    def test_tap_activates_and_swipe_closes(self):
+        """Test that tapping a tile selects its tab and swiping closes it."""
+        tab_manager = self._tab_manager()
+        switcher = TabSwitcher(Mock(), tab_manager)
+        switcher.open()
+
+        tab_manager.close_tab.side_effect = tab_manager.manager.tabs.pop
+        switcher.grid.controls[0].on_dismiss(None)
+        tab_manager.close_tab.assert_called_once_with(0)
+        assert len(switcher.grid.controls) == 2
+        assert switcher.is_open
+
+        switcher.grid.controls[1].content.on_click(None)
+        tab_manager.select_tab.assert_called_once_with(1)
+        assert not switcher.is_open
+
+    def test_last_tab_cannot_be_swiped_away(self):
+        """Test that a single tab's tile is shown without swipe to close."""
+        switcher = TabSwitcher(Mock(), self._tab_manager(count=1))
+
+        switcher.render()
+
+        assert switcher.title.value == "1 tab"
+        assert isinstance(switcher.grid.controls[0], ft.Container)
+
+
+class TestBackNavigation:
+    """Test cases for the system back action."""
+
+    def _back(self, tabs):
+        page = Mock(views=[Mock()])
+        tab_manager = Mock(fullscreen=False)
+        tab_manager.tab_switcher.is_open = False
+        tab_manager.sidebar.drawer_open = False
+        tab_manager.manager = Mock(tabs=tabs, index=len(tabs) - 1)
+        return BackNavigation(page, tab_manager), page.views[0], tab_manager
+
+    def test_back_walks_history_then_closes_tabs(self):
+        """Test that back goes back in the tab, then closes it, then leaves."""
+        tabs = [{}, {"back": ["a"]}]
+        back, view, tab_manager = self._back(tabs)
+        assert view.can_pop is False
+        assert view.on_confirm_pop == back.on_confirm_pop
+
+        back.on_confirm_pop(None)
+        tab_manager.go_back.assert_called_once_with(1)
+        view.confirm_pop.assert_called_with(False)
+
+        tabs[1]["back"] = []
+        tab_manager.close_tab.side_effect = lambda idx: tabs.pop(idx)
+        back.on_confirm_pop(None)
+        tab_manager.close_tab.assert_called_once_with(1)
+        view.confirm_pop.assert_called_with(False)
+
+        tab_manager.manager.index = 0
+        back.on_confirm_pop(None)
+        view.confirm_pop.assert_called_with(True)
+
+    def test_back_closes_overlays_first(self):
+        """Test that back closes the drawer and fullscreen before navigating."""
+        back, _, tab_manager = self._back([{"back": ["a"]}])
+        tab_manager.sidebar.drawer_open = True
+        tab_manager.fullscreen = True
+
+        assert back.back() is True
+        tab_manager.sidebar.set_drawer_open.assert_called_once_with(False)
+        tab_manager.set_fullscreen.assert_not_called()
+
+        tab_manager.sidebar.drawer_open = False
+        assert back.back() is True
+        tab_manager.set_fullscreen.assert_called_once_with(False)
+        tab_manager.go_back.assert_not_called()
+
+
+class TestKeyboardInsets:
+    """Test cases for making room for the on-screen keyboard."""
+
+    def test_keyboard_pads_layout_and_lifts_overlays(self):
+        """Test that the keyboard inset pads the root and lifts overlays."""
+        page = Mock()
+        zoom = Mock(root=ft.Container(), factor=2.0)
+        overlay = ft.Container(bottom=36)
+        keyboard = KeyboardInsets(page, zoom)
+        keyboard.anchor(overlay)
+        assert page.on_media_change == keyboard.on_media_change
+
+        keyboard.on_media_change(Mock(view_insets=Mock(bottom=300.0)))
+
+        assert zoom.root.padding == ft.padding.only(bottom=150.0)
+        assert overlay.bottom == 336.0
+        page.update.assert_called_once()
+
+        keyboard.on_media_change(Mock(view_insets=Mock(bottom=0.0)))
+
+        assert zoom.root.padding is None
+        assert overlay.bottom == 36
+
+    def test_focused_field_scrolled_into_view(self):
+        """Test that a focused field in a page is scrolled to above the keyboard."""
+        keyboard = KeyboardInsets(Mock(), Mock(root=None))
+        field = ft.TextField()
+        scrollable = Mock()
+        keyboard.follow_fields(
+            ft.Column(controls=[ft.Container(content=field)]),
+            scrollable,
+        )
+
+        field.on_focus(None)
+        scrollable.scroll_to.assert_not_called()
+
+        keyboard.set_height(280)
+        scrollable.scroll_to.assert_called_once_with(key=field.key, duration=200)
+
+        field.on_blur(None)
+        assert keyboard.focused is None
+
+
+class TestClipboardHint:
+    """Test cases for offering to open copied addresses."""
+
+    HASH = "0123456789abcdef0123456789abcdef"
+
+    def _hint(self, mock_page, clipboard, enabled=True):
+        mock_page.get_clipboard = Mock(return_value=clipboard)
+        tab_manager = Mock(settings={"clipboard_hints": enabled})
+        tab_manager.manager.tabs = [{"address": None}]
+        tab_manager.manager.index = 0
+        tab_manager.node_name = None
+        return ClipboardHint(mock_page, tab_manager), tab_manager
+
+    def test_offers_copied_address(self, mock_page):
+        """Test that a copied link shows the chip, which opens it."""
+        hint, tab_manager = self._hint(mock_page, f"ren://{self.HASH}/page/a.mu")
+
+        hint.check()
+
+        assert hint.chip.visible is True
+        assert hint.label.value == "Open 01234567…?"
+        hint.open()
+        tab_manager._open_address.assert_called_once_with(f"{self.HASH}:/page/a.mu")
+        assert hint.chip.visible is False
+
+    def test_off_by_default(self, mock_page):
+        """Test that the clipboard is not read unless the hint is turned on."""
+        hint, _ = self._hint(mock_page, self.HASH, enabled=False)
+
+        hint.check()
+
+        mock_page.get_clipboard.assert_not_called()
+        assert hint.chip.visible is False
+
+    def test_dismissed_address_is_not_offered_again(self, mock_page):
+        """Test that the same clipboard is offered only once."""
+        hint, _ = self._hint(mock_page, self.HASH)
+        hint.check()
+
+        hint.dismiss()
+        hint.check()
+
+        assert hint.chip.visible is False
+
+    def test_open_page_and_other_text_are_not_offered(self, mock_page):
+        """Test that the page already shown and plain text give no chip."""
+        hint, tab_manager = self._hint(mock_page, self.HASH)
+        tab_manager.manager.tabs[0]["address"] = f"{self.HASH}:/page/index.mu"
+        hint.check()
+        assert hint.chip.visible is False
+
+        mock_page.get_clipboard.return_value = "see you at the meetup"
+        hint.check()
+        assert hint.chip.visible is False
+
+    def test_window_focus_checks_clipboard(self, mock_page):
+        """Test that the clipboard is looked at when the window gains focus."""
+        tab_manager = Mock(settings={})
+        WindowTracker(mock_page, tab_manager, Mock())
+
+        mock_page.window.on_event(Mock(type=ft.WindowEventType.FOCUS))
+
+        tab_manager.clipboard_hint.check.assert_called_once()
+
+
+class TestAppLifecycle:
+    """Test cases for suspending the browser in the background."""
+
+    def test_pause_and_resume(self):
+        """Test that background work stops on pause and restarts on resume."""
+        page = Mock()
+        tab_manager = Mock()
+        watcher = Mock()
+        lifecycle = AppLifecycle(page, tab_manager, watcher)
+        assert page.on_app_lifecycle_state_change == lifecycle.on_change
+
+        lifecycle.on_change(Mock(state=ft.AppLifecycleState.INACTIVE))
+        tab_manager.suspend.assert_not_called()
+
+        lifecycle.on_change(Mock(state=ft.AppLifecycleState.HIDE))
+        lifecycle.on_change(Mock(state=ft.AppLifecycleState.PAUSE))
+        tab_manager.suspend.assert_called_once()
+        tab_manager.announce_list.pause.assert_called_once()
+        tab_manager.status_bar.stop.assert_called_once()
+        tab_manager.performance_overlay.pause.assert_called_once()
+        watcher.stop.assert_called_once()
+
+        lifecycle.on_change(Mock(state=ft.AppLifecycleState.SHOW))
+        lifecycle.on_change(Mock(state=ft.AppLifecycleState.RESUME))
+        tab_manager.resume.assert_called_once()
+        tab_manager.announce_list.resume.assert_called_once()
+        tab_manager.status_bar.start.assert_called_once()
+        watcher.start.assert_called_once()
+        page.update.assert_called_once()
+
+    def test_unfocused_window_idles(self):
+        """Test that refreshing stops on blur and starts again on focus."""
+        page = Mock()
+        tab_manager = Mock(settings={})
+        lifecycle = AppLifecycle(page, tab_manager)
+
+        lifecycle.on_window_event(Mock(type=ft.WindowEventType.BLUR))
+        lifecycle.on_window_event(Mock(type=ft.WindowEventType.MINIMIZE))
+        assert tab_manager.idle is True
+        tab_manager.prefetcher.cancel.assert_called_once()
+        tab_manager.announce_list.pause.assert_called_once()
+        tab_manager.status_bar.stop.assert_called_once()
+        tab_manager.performance_overlay.pause.assert_called_once()
+        tab_manager.suspend.assert_not_called()
+
+        lifecycle.on_window_event(Mock(type=ft.WindowEventType.FOCUS))
+        assert tab_manager.idle is False
+        tab_manager.announce_list.resume.assert_called_once()
+        tab_manager.status_bar.start.assert_called_once()
+        tab_manager.performance_overlay.resume.assert_called_once()
+
+    def test_unfocused_window_keeps_running_when_turned_off(self):
+        """Test that the idle pause can be turned off in the settings."""
+        tab_manager = Mock(settings={"pause_unfocused": False})
+        lifecycle = AppLifecycle(Mock(), tab_manager)
+
+        lifecycle.on_window_event(Mock(type=ft.WindowEventType.BLUR))
+
+        assert not lifecycle.idle
+        tab_manager.status_bar.stop.assert_not_called()
+
+    def test_announce_list_paused_renders_on_resume(self):
+        """Test that announces heard in the background are shown on resume."""
+        page = Mock()
+        announce_list = AnnounceList(page, Mock(settings={}, fullscreen=False))
+        announce_list.pause()
+
+        with patch.object(announce_list, "render") as render:
+            announce_list.update([Announce("aaa", "A", time.time())])
+            render.assert_not_called()
+            announce_list.resume()
+
+        render.assert_called_once()
+        assert [ann.destination_hash for ann in announce_list.announces] == ["aaa"]
+
+
+class TestPlaygroundPage:
+    """Test cases for the micron playground."""
+
+    def test_renders_edits_and_snippets(self):
+        """Test that the preview follows the editor and snippets are added."""
+        render = Mock(side_effect=lambda text: ft.Text(text))
+        on_edit = Mock()
+        on_change = Mock()
+        page = build_playground_page("", render, on_edit, Mock(), on_change)
+        editor_box, preview = page.controls[2].controls
+        editor = editor_box.content
+
+        assert editor.value == STARTER
+        assert preview.content.value == STARTER
+
+        editor.value = ">Title"
+        editor.on_change(None)
+        assert preview.content.value == ">Title"
+        on_edit.assert_called_with(">Title")
+        on_change.assert_called_once()
+
+        page.controls[1].controls[1].on_click(None)
+        assert editor.value == ">Title\n" + SNIPPETS["Headings"]
+        assert preview.content.value == editor.value
+
+    def test_export(self):
+        """Test that the export button hands over the markup."""
+        on_export = Mock()
+        page = build_playground_page(">Mine", ft.Text, Mock(), on_export, Mock())
+
+        page.controls[0].controls[1].on_click(None)
+
+        on_export.assert_called_once_with(">Mine")
+
+
+class TestDevTools:
+    """Test cases for the developer tools pane."""
+
+    def test_block_tree(self):
+        """Test that parsed blocks are listed with their spans and links."""
+        from ren_browser.renderer.micron import parse_micron
+
+        blocks = parse_micron(">Title\n`cPlain `!bold`!\n\nSee `[Home`:/page/index.mu]")
+
+        assert block_tree(blocks) == [
+            "heading level 1 'Title'",
+            f"text level 1 align={ft.TextAlign.CENTER}",
+            "  span 'Plain '",
+            "  span 'bold' bold",
+            "blank",
+            "links level 1",
+            "  span 'See '",
+            "  link 'Home' -> :/page/index.mu",
+        ]
+
+    def test_pane_describes_active_tab(self, mock_page):
+        """Test that the pane shows the trace of the active tab's load."""
+        trace = PageTrace("a:/page/index.mu", {"page_path": "/page/index.mu"}, 0.0)
+        trace.enter("transfer", now=0.0)
+        trace.enter(None, now=1.5)
+        trace.respond("network", ">Hi")
+        trace.renderer = "micron"
+        tab_manager = Mock()
+        tab_manager.manager.index = 0
+        tab_manager.manager.tabs = [{"trace": trace, "source": ">Hi"}, {}]
+        pane = DevToolsPane(mock_page, tab_manager)
+
+        pane.refresh()
+        assert pane.summary.value == ""
+
+        pane.toggle()
+        assert pane.visible
+        assert pane.summary.value == "a:/page/index.mu  ·  renderer: micron"
+        assert pane.request_text.value == '{\n  "page_path": "/page/index.mu"\n}'
+        assert '"origin": "network"' in pane.response_text.value
+        assert pane.timing_text.value == "Receiving page: 1500 ms\nTotal: 1500 ms"
+        assert pane.tree_text.value == "heading level 1 'Hi'"
+
+        tab_manager.manager.index = 1
+        pane.refresh()
+        assert pane.summary.value == "No page was loaded from a node in this tab."
+        assert pane.tree_text.value == ""
+
+        pane.toggle()
+        assert not pane.visible
+
+
+class TestPerformanceOverlay:
+    """Test cases for the performance overlay."""
+
+    def _tab_manager(self):
+        from ren_browser.profiler.profiler import PerformanceMonitor
+
+        tab_manager = Mock()
+        tab_manager.performance = PerformanceMonitor()
+        tab_manager.page_cache = PageCache()
+        tab_manager.page_cache.put("a:/page/index.mu", "Hello")
+        tab_manager.manager.tabs = [{"load_progress": 0.5}, {}]
+        tab_manager.downloads.downloads.return_value = [
+            Mock(status="downloading"),
+            Mock(status="completed"),
+        ]
+        tab_manager.prefetcher.pending.return_value = 3
+        return tab_manager
+
+    def test_lines(self):
+        """Test that the measurements are described."""
+        tab_manager = self._tab_manager()
+        tab_manager.performance.record_fetch("a:/page/index.mu", 1.25)
+
+        with patch("ren_browser.ui.performance.memory_usage", return_value=None):
+            lines = performance_lines(tab_manager)
+
+        assert lines == [
+            "Updates: 0.0/s",
+            "Memory: unknown",
+            "Cache: 1 pages, 5 B",
+            "In flight: 1 pages, 1 downloads, 3 prefetches queued",
+            "Last fetch: 1.25 s, a:/page/index.mu",
+        ]
+
+    def test_toggle_times_updates_and_refreshes(self, mock_page):
+        """Test that showing the overlay starts timing and refreshing."""
+        mock_page.overlay = []
+        update = mock_page.update
+        overlay = PerformanceOverlay(mock_page, self._tab_manager())
+        assert mock_page.overlay == [overlay.control]
+        assert overlay.control.visible is False
+
+        overlay.toggle()
+
+        assert overlay.control.visible is True
+        assert "Last fetch: none yet" in overlay.text.value
+        mock_page.run_task.assert_called_once()
+        assert mock_page.update is not update
+        mock_page.update()
+        update.assert_called_once()
+
+        overlay.toggle()
+        assert overlay.control.visible is False
+
+
+class TestFeedPage:
+    """Test cases for the followed-nodes feed page."""
+
+    def test_lists_items_and_followed_nodes(self):
+        """Test that feed items and followed nodes are listed."""
+        pages = {"abc:/page/index.mu": "`[News`:/page/news.mu]"}
+        storage = Mock(load_feed=Mock(return_value={}))
+        feed = NodeFeed(storage, lambda address: pages.get(address, "Old news"))
+        feed.follow("abc", "Hilltop")
+        feed.check("abc", now=100.0)
+        pages["abc:/page/news.mu"] = ">Fresh news"
+        feed.check("abc", now=200.0)
+        on_open = Mock()
+        on_change = Mock()
+
+        page = build_feed_page(feed, on_open, lambda done: done(), on_change)
+
+        item = page.controls[1]
+        title, details = item.content.controls[1].controls
+        assert title.value == "Fresh news"
+        assert details.value.startswith("Hilltop")
+        item.on_click(None)
+        on_open.assert_called_once_with("abc:/page/news.mu")
+        assert page.controls[2].content.value == "Following"
+
+        page.controls[3].content.controls[2].on_click(None)
+        assert feed.nodes() == []
+        assert feed.items() == []
+        on_change.assert_called_once()
+
+    def test_empty_feed_explains_following(self):
+        """Test that an empty feed says how to follow nodes."""
+        feed = NodeFeed(Mock(load_feed=Mock(return_value={})), Mock())
+
+        page = build_feed_page(feed, Mock(), Mock(), Mock())
+
+        assert "Follow Node" in page.controls[1].value
+        assert page.controls[0].controls[1].disabled is True
+
+
+class TestAnnounceTicker:
+    """Test cases for the live announce ticker."""
+
+    def _ticker(self):
+        service = Mock(log=[], add_listener=Mock(), remove_listener=Mock())
+        on_open = Mock()
+        ticker = AnnounceTicker(service, on_open, Mock())
+        return ticker, service, on_open
+
+    def test_shows_newest_first_and_opens_node(self):
+        """Test that announces are listed newest first and open on click."""
+        ticker, service, on_open = self._ticker()
+        assert ticker.rows.controls[0].value == "Waiting for announces…"
+        service.log.append(Announce("abc", "Hilltop", 100))
+        service.log.append(Announce("def", None, 200))
+
+        ticker.on_announce(service.log[-1])
+
+        names = [row.content.controls[2].value for row in ticker.rows.controls]
+        assert names == ["Anonymous", "Hilltop"]
+        ticker.rows.controls[1].on_click(None)
+        on_open.assert_called_once_with("abc:/page/index.mu")
+        ticker.on_change.assert_called_once()
+
+    def test_pause_holds_new_announces(self):
+        """Test that pausing keeps the list still and counts what arrives."""
+        ticker, service, _ = self._ticker()
+        service.log.append(Announce("abc", "Hilltop", 100))
+        ticker.render()
+        ticker.toggle_pause()
+        service.log.append(Announce("def", "Valley", 200))
+
+        ticker.on_announce(service.log[-1])
+
+        assert len(ticker.rows.controls) == 1
+        assert ticker.status.value == "Paused, 1 new"
+        assert ticker.pause_button.text == "Resume"
+        ticker.toggle_pause()
+        assert len(ticker.rows.controls) == 2
+        assert ticker.status.value == "Live"
+
+    def test_not_live_redraws_only_when_asked(self):
+        """Test that with reduced motion announces wait until Show new."""
+        ticker, service, _ = self._ticker()
+        ticker.set_live(False)
+        service.log.append(Announce("abc", "Hilltop", 100))
+
+        ticker.on_announce(service.log[-1])
+
+        ticker.on_change.assert_not_called()
+        assert ticker.rows.controls[0].value == "Waiting for announces…"
+        assert ticker.pause_button.text == "Show new"
+        ticker.toggle_pause()
+        assert ticker.rows.controls[0].content.controls[2].value == "Hilltop"
+        assert ticker.status.value == "Not updated live"
+
+    def test_filter_and_clear(self):
+        """Test that the filter matches names and aspects, and clear empties it."""
+        ticker, service, _ = self._ticker()
+        service.log.append(Announce("abc", "Hilltop", 100))
+        service.log.append(Announce("def", "Valley", 200, aspect="lxmf.delivery"))
+
+        ticker.set_query("LXMF")
+        names = [row.content.controls[2].value for row in ticker.rows.controls]
+        assert names == ["Valley"]
+
+        ticker.clear()
+        assert ticker.rows.controls[0].value == "Waiting for announces…"
+
+    def test_attach_and_detach_register_listener(self):
+        """Test that the ticker subscribes to and unsubscribes from the service."""
+        ticker, service, _ = self._ticker()
+
+        ticker.attach()
+        ticker.detach()
+
+        service.add_listener.assert_called_once_with(ticker.on_announce)
+        service.remove_listener.assert_called_once_with(ticker.on_announce)
+
+    def test_announce_matches_hash(self):
+        """Test that the filter matches the start of a destination hash."""
+        ann = Announce("abcdef", None, 0)
+        assert announce_matches(ann, " ABC ")
+        assert not announce_matches(ann, "xyz")
+
+
+class TestDownloadsPanel:
+    """Test cases for the downloads panel."""
+
+    def test_download_summary(self):
+        """Test the status line shown for downloads."""
+        active = Download(1, "a:/file/x", "x", progress=0.25)
+        done = Download(2, "a:/file/y", "y", status="completed", size=2048)
+        failed = Download(3, "a:/file/z", "z", status="failed", error="No path")
+
+        assert download_summary(active) == "Downloading... 25%"
+        assert download_summary(done) == "Completed · 2.0 KB"
+        assert download_summary(failed) == "Failed: No path"
+        assert format_size(512) == "512 B"
+        assert format_size(3_500_000) == "3.5 MB"
+
+    def test_rows_offer_matching_actions(self, mock_page):
+        """Test that each download shows controls for its state."""
+        downloads = Mock()
+        downloads.downloads.return_value = [
+            Download(1, "a:/file/x", "x"),
+            Download(2, "a:/file/y", "y", status="completed", path="/tmp/d/y"),
+        ]
+        panel = DownloadsPanel(mock_page, downloads)
+
+        active_row, done_row = panel.list_column.controls
+        active_actions = active_row.content.controls[2:]
+        assert [a.tooltip for a in active_actions] == ["Pause", "Cancel"]
+        active_actions[0].on_click(None)
+        downloads.pause.assert_called_once_with(1)
+
+        done_actions = done_row.content.controls[2:]
+        assert [a.tooltip for a in done_actions] == [
+            "Open containing folder",
+            "Remove from list",
+        ]
+        done_actions[0].on_click(None)
+        mock_page.launch_url.assert_called_once_with("file:///tmp/d")
+
+    def test_downloaded_images_can_be_viewed(self, mock_page):
+        """Test that finished image downloads offer to open the image viewer."""
+        downloads = Mock()
+        downloads.downloads.return_value = [
+            Download(1, "a:/file/m.png", "m.png", status="completed", path="/d/m.png"),
+        ]
+        on_view = Mock()
+        panel = DownloadsPanel(mock_page, downloads, on_view=on_view)
+
+        view = panel.list_column.controls[0].content.controls[2]
+        assert view.tooltip == "View image"
+        view.on_click(None)
+        on_view.assert_called_once_with(Path("/d/m.png"))
+
+    def test_refreshes_when_downloads_change(self, mock_page):
+        """Test that the panel redraws from the manager's listener."""
+        downloads = Mock()
+        downloads.downloads.return_value = []
+        panel = DownloadsPanel(mock_page, downloads)
+        listener = downloads.add_listener.call_args[0][0]
+
+        downloads.downloads.return_value = [Download(1, "a:/file/x", "x")]
+        listener()
+
+        assert len(panel.list_column.controls) == 1
+        assert panel.list_column.controls[0].content.controls[1].controls[0].value == "x"
+
+
+class TestSavePage:
+    """Test cases for saving pages to disk."""
+
+    def test_suggested_file_name(self):
+        """Test the default names offered in the save dialog."""
+        assert suggested_file_name("/page/index.mu") == "index.mu"
+        assert suggested_file_name("/file/notes.txt") == "notes.txt"
+        assert suggested_file_name("/page/status") == "status.txt"
+
+    def test_save_writes_source_bytes(self, mock_page, tmp_path):
+        """Test that the chosen file receives the unrendered source."""
+        mock_page.overlay = []
+        saver = PageSaver(mock_page)
+        saver.picker = Mock()
+        path = tmp_path / "index.mu"
+
+        saver.choose(">Grüße\n`!bold`!", "/page/index.mu")
+        saver.picker.save_file.assert_called_once()
+        saver._on_result(Mock(path=str(path)))
+
+        assert path.read_bytes() == ">Grüße\n`!bold`!".encode()
+        assert saver.pending is None
+
+    def test_export_pdf(self, mock_page, tmp_path):
+        """Test that a PDF export is offered with a matching file name."""
+        mock_page.overlay = []
+        saver = PageSaver(mock_page)
+        saver.picker = Mock()
+        path = tmp_path / "index.pdf"
+
+        saver.choose_pdf(">Heading", "/page/index.mu", "Home")
+        assert saver.picker.save_file.call_args.kwargs["file_name"] == "index.pdf"
+        saver._on_result(Mock(path=str(path)))
+
+        assert path.read_bytes().startswith(b"%PDF")
+
+
+class TestPagePrinter:
+    """Test cases for printing pages."""
+
+    def test_sends_pdf_to_print(self, mock_page, tmp_path):
+        """Test that the page is written as a PDF and handed to the system."""
+        mock_page.web = False
+        mock_page.overlay = []
+        printer = PagePrinter(mock_page)
+
+        with (
+            patch("tempfile.tempdir", str(tmp_path)),
+            patch("ren_browser.ui.print_page.print_file", return_value=True) as send,
+        ):
+            assert printer.print_page(">Bulletin", "/page/index.mu", "Home")
+
+        path = send.call_args.args[0]
+        assert path.parent == tmp_path
+        assert path.read_bytes().startswith(b"%PDF")
+        assert mock_page.overlay[-1].content.value == "Sent Home to print"
+
+    def test_not_on_web(self, mock_page):
+        """Test that the web build says printing is not available."""
+        mock_page.web = True
+        mock_page.overlay = []
+
+        with patch("ren_browser.ui.print_page.print_file") as send:
+            assert not PagePrinter(mock_page).print_page("x", "/page/a.mu", "A")
+
+        send.assert_not_called()
+
+    def test_print_command(self, tmp_path):
+        """Test the command used to print on Linux and macOS."""
+        path = tmp_path / "page.pdf"
+        with (
+            patch("ren_browser.ui.print_page.sys.platform", "linux"),
+            patch("ren_browser.ui.print_page.shutil.which", return_value="xdg-open"),
+        ):
+            assert print_command(path) == ["xdg-open", str(path)]
+        with (
+            patch("ren_browser.ui.print_page.sys.platform", "darwin"),
+            patch("ren_browser.ui.print_page.shutil.which", return_value="osascript"),
+        ):
+            args = print_command(path)
+        assert "with print dialog" in args[-1]
+        with patch("ren_browser.ui.print_page.shutil.which", return_value=None):
+            assert print_command(path) is None
+
+
+class TestCommandPalette:
+    """Test cases for finding and running actions by name."""
+
+    def _palette(self, mock_page, keybindings=None):
+        tab_manager = Mock()
+        tab_manager.settings = {"keybindings": keybindings or {}}
+        run = Mock()
+        return CommandPalette(mock_page, tab_manager, run), run
+
+    def test_lists_every_action_but_itself(self):
+        """Test that an empty query lists all actions in registry order."""
+        actions = palette_matches("")
+
+        assert actions[0] == "new_tab"
+        assert "command_palette" not in actions
+        assert "clear_cache" in actions
+
+    def test_fuzzy_search(self):
+        """Test that typing part of a label finds the action first."""
+        assert palette_matches("tog side")[0] == "toggle_sidebar"
+        assert palette_matches("clr cache")[0] == "clear_cache"
+        assert palette_matches("zzzz") == []
+
+    def test_shows_keybinding_hints(self, mock_page):
+        """Test that each action shows its chords, rebound ones included."""
+        palette, _ = self._palette(mock_page, {"history": ["Ctrl+Y"]})
+
+        palette.open()
+
+        hints = {
+            tile.title.value: tile.trailing.value for tile in palette.results.controls
+        }
+        assert hints["Open history"] == "Ctrl+Y"
+        assert hints["Reload page"] == "Ctrl+R, F5"
+        assert hints["Open settings"] == ""
+
+    def test_enter_runs_best_match(self, mock_page):
+        """Test that submitting the query runs the top action and closes."""
+        palette, run = self._palette(mock_page)
+        palette.open()
+
+        palette.filter("bookmark page")
+        palette.run_first()
+
+        run.assert_called_once_with("bookmark_page")
+        mock_page.close.assert_called_once_with(palette.dialog)
+
+    def test_no_matches(self, mock_page):
+        """Test that a query matching nothing says so and runs nothing."""
+        palette, run = self._palette(mock_page)
+
+        palette.filter("zzzz")
+        palette.run_first()
+
+        assert palette.results.controls[0].value == "No matching commands"
+        run.assert_not_called()
+
+
+class TestShortcutSheet:
+    """Test cases for the keyboard shortcut cheat sheet."""
+
+    def test_groups_current_bindings(self):
+        """Test that rebound chords are listed and unbound actions left out."""
+        groups = dict(sheet_groups({"history": ["Ctrl+Y"], "new_tab": []}))
+
+        assert list(groups)[0] == "General"
+        assert ("Open history", "Ctrl+Y") in groups["Window"]
+        assert ("Reload page", "Ctrl+R, F5") in groups["Navigation"]
+        assert "New tab" not in dict(groups["Tabs"])
+        assert "Open settings" not in dict(groups["General"])
+
+    def test_open_lists_shortcuts(self, mock_page):
+        """Test that opening the sheet shows each category and its rows."""
+        tab_manager = Mock(settings={"keybindings": {}})
+        sheet = ShortcutSheet(mock_page, tab_manager)
+
+        sheet.open()
+
+        controls = sheet.groups.controls
+        assert controls[0].value == "General"
+        assert controls[1].controls[0].value == "Command palette"
+        assert controls[1].controls[1].value == "Ctrl+Shift+P"
+        mock_page.open.assert_called_once_with(sheet.dialog)
+
+
+class TestFileOpener:
+    """Test cases for opening pages from disk."""
+
+    def test_chosen_file_is_opened(self, mock_page, tmp_path):
+        """Test that the chosen page is opened by its file:// address."""
+        mock_page.overlay = []
+        on_open = Mock()
+        opener = FileOpener(mock_page, on_open)
+        opener.picker = Mock()
+        path = tmp_path / "index.mu"
+
+        opener.choose()
+        assert "mu" in opener.picker.pick_files.call_args.kwargs["allowed_extensions"]
+        opener._on_result(Mock(files=[Mock(path=str(path))]))
+
+        on_open.assert_called_once_with(path.resolve().as_uri())
+
+    def test_file_without_path_is_ignored(self, mock_page):
+        """Test that files the web build gives without a path are not opened."""
+        mock_page.overlay = []
+        on_open = Mock()
+        opener = FileOpener(mock_page, on_open)
+
+        opener._on_result(Mock(files=[Mock(path=None)]))
+        opener._on_result(Mock(files=None))
+
+        on_open.assert_not_called()
+
+
+class TestQrScanner:
+    """Test cases for scanning QR codes into the address bar."""
+
+    def _scanner(self, mock_page):
+        mock_page.overlay = []
+        mock_page.web = False
+        mock_page.platform = ft.PagePlatform.ANDROID
+        on_open = Mock()
+        return QrScanner(mock_page, on_open), on_open
+
+    def test_button_only_on_phones(self, mock_page):
+        """Test that the Scan button is shown on camera-equipped devices only."""
+        scanner, _ = self._scanner(mock_page)
+        assert scanner.button.visible is True
+
+        mock_page.platform = ft.PagePlatform.LINUX
+        assert QrScanner(mock_page, Mock()).button.visible is False
+
+    def test_scanned_address_is_opened(self, mock_page, tmp_path):
+        """Test that the address in a scanned code is opened."""
+        scanner, on_open = self._scanner(mock_page)
+        image = tmp_path / "code.png"
+        image.write_bytes(to_png(encode(f"ren://{'ab' * 16}/page/about.mu")))
+
+        scanner._on_result(Mock(files=[Mock(path=str(image))]))
+        mock_page.run_thread.assert_called_once_with(scanner.scan_file, image)
+        scanner.scan_file(image)
+
+        on_open.assert_called_once_with(f"{'ab' * 16}/page/about.mu")
+
+    def test_code_without_address_is_reported(self, mock_page, tmp_path):
+        """Test that codes holding anything but an address are not opened."""
+        scanner, on_open = self._scanner(mock_page)
+        image = tmp_path / "code.png"
+        image.write_bytes(to_png(encode("HELLO")))
+
+        with patch("ren_browser.ui.qr_scan.show_snack") as snack:
+            assert scanner.scan_file(image) is None
+
+        on_open.assert_not_called()
+        assert "HELLO" in snack.call_args.args[1]
+
+
+class TestUpdatePage:
+    """Test cases for the about:update page."""
+
+    def test_check_shows_release_and_notes(self):
+        """Test that checking finds a release and shows its notes."""
+        storage = Mock(load_update_state=Mock(return_value={}))
+        release = Release("v0.6.0", "## Changes", "https://example.org/v0.6.0")
+        updates = UpdateChecker(storage, "0.5.0", fetch=lambda: release)
+        on_open_release = Mock()
+        on_change = Mock()
+
+        def on_check(done):
+            assert page.controls[2].controls[0].disabled is True
+            updates.check(now=0)
+            done()
+
+        page = build_update_page(updates, on_check, on_open_release, on_change)
+        assert page.controls[0].value == "Ren Browser is up to date"
+        page.controls[2].controls[0].on_click(None)
+
+        assert "v0.6.0" in page.controls[0].value
+        assert "0.5.0" in page.controls[1].value
+        assert isinstance(page.controls[-2], ft.Divider)
+        page.controls[2].controls[0].on_click(None)
+        on_open_release.assert_called_once_with(release.url)
+        on_change.assert_called()
+
+
+class TestImageViewer:
+    """Test cases for the image viewer."""
+
+    def test_zoom_and_fit(self):
+        """Test that the image is fitted first and zoomed to real sizes."""
+        data = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR" + bytes([0, 0, 0, 200] * 2)
+        on_change = Mock()
+        viewer = ImageViewer(data, "map.png", on_change=on_change)
+        assert viewer.image.expand is True
+        assert viewer.zoom_text.value == "Fitted"
+
+        viewer.step(1)
+        assert viewer.zoom == 1.5
+        assert (viewer.image.width, viewer.image.height) == (300, 300)
+        viewer.set_zoom(IMAGE_ZOOM_LEVELS[-1])
+        assert viewer.zoom_in_button.disabled is True
+        viewer.set_zoom(None)
+        assert viewer.image.width is None
+        assert on_change.call_count == 3
+
+    def test_unknown_size_only_fits(self):
+        """Test that images of unknown size cannot be zoomed."""
+        viewer = ImageViewer(b"not an image", "broken.png")
+
+        assert viewer.zoom_in_button.disabled is True
+        assert viewer.zoom_out_button.disabled is True
+
+
+class TestNotificationPanel:
+    """Test cases for the notification center panel."""
+
+    def test_lists_events_and_opens_them(self, mock_page):
+        """Test that the bell counts new events and the list opens them."""
+        center = NotificationCenter()
+        on_open = Mock()
+        panel = NotificationPanel(mock_page, center, on_open)
+        assert panel.button.badge is None
+
+        center.add("downloads", "a.zip downloaded", now=100.0)
+        center.add("watched", "Notes has changed", "abc:/page/notes.mu", now=160.0)
+        assert panel.button.badge.text == "2"
+
+        with patch("ren_browser.ui.notification_center.open_dialog"):
+            panel.show()
+        panel.render(now=220.0)
+        watched, download = panel.list_column.controls
+        assert watched.subtitle.value == "Watched page · 1 min ago"
+        assert download.on_click is None
+        assert panel.button.badge is None
+
+        watched.on_click(None)
+        on_open.assert_called_once_with("abc:/page/notes.mu")
+
+    def test_clear_all(self, mock_page):
+        """Test that clearing empties the list."""
+        center = NotificationCenter()
+        center.add("error", "Update check failed")
+        panel = NotificationPanel(mock_page, center, Mock())
+
+        panel.clear()
+
+        assert center.entries() == []
+        assert len(panel.list_column.controls) == 1
+
+
+class TestLxmfAddressCard:
+    """Test cases for the LXMF address card."""
+
+    def test_shows_announced_name_and_copies(self, mock_page):
+        """Test that the card names the address and copies its hash."""
+        node = "ab" * 16
+        tab_manager = Mock()
+        tab_manager.announce_service.get_announces.return_value = [
+            Announce(node, "Hilltop node", 1),
+            Announce(node, "Hilltop operator", 2, aspect="lxmf.delivery"),
+        ]
+        card = LxmfAddressCard(mock_page, tab_manager)
+
+        with patch("ren_browser.ui.lxmf_card.open_dialog") as mock_open:
+            card.show(node)
+        with patch("ren_browser.ui.lxmf_card.show_snack"):
+            card.copy()
+
+        mock_open.assert_called_once_with(mock_page, card.dialog)
+        assert card.name_text.value == "Hilltop operator"
+        assert card.hash_text.value == f"<{node}>"
+        mock_page.set_clipboard.assert_called_once_with(node)
+
+
+class TestTabGroupDialog:
+    """Test cases for putting tabs in groups."""
+
+    def test_new_group(self, mock_page):
+        """Test that a named group is made for the active tab."""
+        tab_manager = Mock(tab_groups=[])
+        tab_manager.manager.index = 2
+        dialog = TabGroupDialog(mock_page, tab_manager)
+        dialog.add_tab()
+        assert not dialog.group_dropdown.visible
+        assert dialog.color_dropdown.value == "blue"
+
+        dialog.name_field.value = " Research "
+        dialog.apply()
+
+        idx, group = tab_manager.add_to_group.call_args[0]
+        assert idx == 2
+        assert (group.name, group.color) == ("Research", "blue")
+
+    def test_existing_group(self, mock_page):
+        """Test that the active tab can join a group that already exists."""
+        research = TabGroup("Research", "green")
+        tab_manager = Mock(tab_groups=[research])
+        tab_manager.manager.index = 0
+        dialog = TabGroupDialog(mock_page, tab_manager)
+        dialog.add_tab()
+
+        dialog.group_dropdown.value = "0"
+        dialog.apply()
+
+        tab_manager.add_to_group.assert_called_once_with(0, research)
+
+    def test_edit_group(self, mock_page):
+        """Test that a group can be renamed and recoloured."""
+        research = TabGroup("Research", "green")
+        tab_manager = Mock(tab_groups=[research])
+        dialog = TabGroupDialog(mock_page, tab_manager)
+        dialog.edit(research)
+        assert dialog.name_field.value == "Research"
+
+        dialog.name_field.value = ""
+        dialog.apply()
+        assert dialog.name_field.error_text
+        dialog.name_field.value = "Node A"
+        dialog.color_dropdown.value = "red"
+        dialog.apply()
+
+        tab_manager.edit_group.assert_called_once_with(research, "Node A", "red")
+
+    def test_group_chip_menu(self):
+        """Test that the chip menu collapses and closes its group."""
+        research = TabGroup("Research", "green")
+        tab_manager = Mock()
+        chip = build_group_chip(research, tab_manager)
+        collapse, _, ungroup, close = chip.items
+
+        collapse.on_click(None)
+        ungroup.on_click(None)
+        close.on_click(None)
+
+        tab_manager.toggle_group.assert_called_once_with(research)
+        tab_manager.ungroup.assert_called_once_with(research)
+        tab_manager.close_group.assert_called_once_with(research)
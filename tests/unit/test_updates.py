@@ -0,0 +1,94 @@
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.updates.updates import (
+    CHECK_INTERVAL,
+    Release,
+    UpdateChecker,
+    is_newer,
+    parse_version,
+)
+
+
+def make_checker(release, current="0.5.0", state=None):
+    storage = Mock(load_update_state=Mock(return_value=dict(state or {})))
+    return UpdateChecker(storage, current, fetch=Mock(return_value=release))
+
+
+class TestVersions:
+    """Test cases for comparing release versions."""
+
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            ("v0.6.0", (0, 6, 0)),
+            ("1.2", (1, 2)),
+            ("0.6.0-rc1", (0, 6, 0)),
+            ("unknown", ()),
+            ("", ()),
+        ],
+    )
+    def test_parse_version(self, text, expected):
+        """Test that the numeric parts of a version are read."""
+        assert parse_version(text) == expected
+
+    def test_newer_versions(self):
+        """Test that versions are compared part by part."""
+        assert is_newer("v0.10.0", "0.9.1")
+        assert is_newer("0.6", "0.5.9")
+        assert not is_newer("0.6.0", "0.6")
+        assert not is_newer("0.5.0", "0.6.0")
+
+    def test_unknown_version_is_never_outdated(self):
+        """Test that a checkout without a version is not told to update."""
+        assert not is_newer("v0.6.0", "unknown")
+
+
+class TestUpdateChecker:
+    """Test cases for the UpdateChecker class."""
+
+    def test_due_once_a_day(self):
+        """Test that a check is due a day after the last one."""
+        checker = make_checker(Release("v0.6.0"), state={"checked_at": 1000})
+
+        assert not checker.due(1000 + CHECK_INTERVAL - 1)
+        assert checker.due(1000 + CHECK_INTERVAL)
+
+    def test_new_release_is_announced_once(self):
+        """Test that a newer release is returned by the first check only."""
+        release = Release("v0.6.0", "## Changes", "https://example.org/v0.6.0")
+        checker = make_checker(release)
+
+        assert checker.check(now=5) == release
+        assert checker.check(now=6) is None
+        assert checker.available == release
+
+    def test_check_is_saved(self):
+        """Test that the time and the release found are stored."""
+        checker = make_checker(Release("v0.6.0", "notes"))
+
+        checker.check(now=42)
+
+        saved = checker.storage.save_update_state.call_args[0][0]
+        assert saved["checked_at"] == 42
+        assert saved["latest"]["version"] == "v0.6.0"
+        assert saved["announced"] == "v0.6.0"
+
+    def test_current_release_is_not_announced(self):
+        """Test that nothing is announced when the browser is up to date."""
+        checker = make_checker(Release("v0.5.0"))
+
+        assert checker.check(now=1) is None
+        assert checker.available is None
+
+    def test_failed_check_keeps_state(self):
+        """Test that a failed check is not recorded as done."""
+        checker = make_checker(Release("v0.6.0"), state={"checked_at": 7})
+        checker.fetch.side_effect = OSError("offline")
+
+        with pytest.raises(OSError):
+            checker.check(now=CHECK_INTERVAL * 2)
+
+        assert checker.due(CHECK_INTERVAL * 2)
+        checker.storage.save_update_state.assert_not_called()
@@ -0,0 +1,111 @@
+from unittest.mock import Mock
+
+from ren_browser.watch.watch import PageWatcher, WatchedPage, content_hash
+
+URL = "abc:/page/index.mu"
+
+
+def _storage(pages=None):
+    return Mock(load_watched_pages=Mock(return_value=pages or []))
+
+
+def _watcher(pages, storage=None):
+    """Build a watcher serving content from a dict of address to content."""
+    return PageWatcher(storage or _storage(), lambda address: pages[address])
+
+
+class TestPageWatcher:
+    """Test cases for the PageWatcher class."""
+
+    def test_watch_saves_page(self):
+        """Test that watching a page persists it with its content hash."""
+        storage = _storage()
+        watcher = _watcher({}, storage)
+
+        watcher.watch(URL, "Home", "Hello")
+
+        saved = storage.save_watched_pages.call_args[0][0]
+        assert saved[0]["address"] == URL
+        assert saved[0]["content_hash"] == content_hash("Hello")
+        assert "error" not in saved[0]
+
+    def test_check_detects_changes(self):
+        """Test that a different content hash marks the page as changed."""
+        pages = {URL: "Hello"}
+        watcher = _watcher(pages)
+        watcher.watch(URL, "Home", "Hello")
+
+        assert watcher.check(URL, now=100.0) is False
+        pages[URL] = "Hello again"
+        assert watcher.check(URL, now=200.0) is True
+
+        watched = watcher.get(URL)
+        assert watched.unseen is True
+        assert watched.changed_at == 200.0
+        assert watcher.unseen_count() == 1
+
+    def test_first_check_sets_baseline(self):
+        """Test that a page watched without content is not reported as changed."""
+        watcher = _watcher({URL: "Hello"})
+        watcher.watch(URL, "Home")
+
+        assert watcher.check(URL) is False
+        assert watcher.get(URL).content_hash == content_hash("Hello")
+
+    def test_seen_clears_change(self):
+        """Test that opening a changed page takes its content as the baseline."""
+        pages = {URL: "Hello"}
+        watcher = _watcher(pages)
+        watcher.watch(URL, "Home", "Hello")
+        pages[URL] = "Changed"
+        watcher.check(URL)
+
+        watcher.seen(URL, "Changed")
+
+        assert watcher.unseen_count() == 0
+        assert watcher.check(URL) is False
+
+    def test_failed_check_keeps_state(self):
+        """Test that fetch failures are recorded without losing the baseline."""
+
+        def fetch(address):
+            raise OSError("no path")
+
+        watcher = PageWatcher(_storage(), fetch)
+        watcher.watch(URL, "Home", "Hello")
+
+        assert watcher.check_all() == []
+        assert watcher.get(URL).error == "no path"
+        assert watcher.get(URL).content_hash == content_hash("Hello")
+
+    def test_changed_pages_listed_first(self):
+        """Test that unseen changes come before other watched pages."""
+        watcher = _watcher({})
+        watcher.watch("a:/page/a.mu", "A")
+        watcher.watch("b:/page/b.mu", "B").unseen = True
+
+        assert [p.title for p in watcher.pages()] == ["B", "A"]
+
+    def test_loads_stored_pages_and_skips_malformed(self):
+        """Test loading watched pages saved by a previous session."""
+        watcher = _watcher(
+            {},
+            _storage(
+                [
+                    {"address": URL, "title": "Home", "unseen": True},
+                    {"title": "no address"},
+                    "not a dict",
+                ],
+            ),
+        )
+
+        assert watcher.pages() == [WatchedPage(URL, "Home", unseen=True)]
+
+    def test_unwatch(self):
+        """Test that unwatching forgets the page."""
+        watcher = _watcher({})
+        watcher.watch(URL, "Home")
+
+        watcher.unwatch(URL)
+
+        assert watcher.get(URL) is None
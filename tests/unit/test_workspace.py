@@ -0,0 +1,103 @@
+import json
+from unittest.mock import Mock
+
+import pytest
+
+from ren_browser.tabs.workspace import (
+    Workspace,
+    WorkspaceFormatError,
+    WorkspaceTab,
+    file_name,
+    from_json,
+    to_json,
+)
+from ren_browser.ui.workspaces import WorkspaceFiles
+
+NET_NIGHT = Workspace(
+    "Net Night",
+    [
+        WorkspaceTab("abc:/page/index.mu", "Alpha BBS"),
+        WorkspaceTab("def:/page/board.mu", "Delta Board"),
+    ],
+    1,
+)
+
+
+class TestWorkspaceFormat:
+    """Test cases for reading and writing workspace files."""
+
+    def test_round_trip(self):
+        """Test that a saved workspace reads back unchanged."""
+        assert from_json(to_json(NET_NIGHT)) == NET_NIGHT
+
+    def test_file_name(self):
+        """Test that the default file name is made from the workspace name."""
+        assert file_name("Net Night!") == "net-night.json"
+        assert file_name("…") == "workspace.json"
+
+    def test_bad_tabs_are_skipped(self):
+        """Test that tabs without an address are left out."""
+        data = json.loads(to_json(NET_NIGHT))
+        data["tabs"] = [{"address": ""}, "abc", {"address": "ghi:/", "title": 3}]
+        data["index"] = 5
+
+        workspace = from_json(json.dumps(data))
+
+        assert workspace.tabs == [WorkspaceTab("ghi:/", "")]
+        assert workspace.index == 0
+
+    @pytest.mark.parametrize(
+        "text",
+        [
+            "not json",
+            json.dumps({"format": "ren-browser-settings", "version": 1}),
+            json.dumps({"format": "ren-browser-workspace", "version": 99}),
+            json.dumps({"format": "ren-browser-workspace", "version": 1, "tabs": []}),
+        ],
+    )
+    def test_unreadable_files(self, text):
+        """Test that files that are not usable workspaces are refused."""
+        with pytest.raises(WorkspaceFormatError):
+            from_json(text)
+
+
+class TestWorkspaceFiles:
+    """Test cases for the workspace save and open dialogs."""
+
+    def test_save_then_open(self, mock_page, tmp_path):
+        """Test that a saved workspace opens its tabs again."""
+        tab_manager = Mock()
+        tab_manager.current_workspace.return_value = NET_NIGHT
+        files = WorkspaceFiles(mock_page, tab_manager)
+        files.picker = Mock()
+        path = tmp_path / "net-night.json"
+
+        files.name_field.value = "Net Night"
+        files.choose_file()
+        tab_manager.current_workspace.assert_called_once_with("Net Night")
+        files._on_result(Mock(path=str(path)))
+        assert path.exists()
+
+        assert files.open_from(path)
+        tab_manager.open_workspace.assert_called_once_with(NET_NIGHT)
+
+    def test_name_is_required(self, mock_page):
+        """Test that a workspace is not saved without a name."""
+        files = WorkspaceFiles(mock_page, Mock())
+        files.picker = Mock()
+        files.name_field.value = "  "
+
+        files.choose_file()
+
+        assert files.name_field.error_text
+        files.picker.save_file.assert_not_called()
+
+    def test_open_invalid_file(self, mock_page, tmp_path):
+        """Test that an invalid file opens no tabs."""
+        tab_manager = Mock()
+        files = WorkspaceFiles(mock_page, tab_manager)
+        path = tmp_path / "settings.json"
+        path.write_text("{}", encoding="utf-8")
+
+        assert not files.open_from(path)
+        tab_manager.open_workspace.assert_not_called()